@@ -0,0 +1,82 @@
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_cli::extensions::compare::compare_pragmatic_solutions;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const FIRST_SOLUTION_ARG_NAME: &str = "first-solution";
+const SECOND_SOLUTION_ARG_NAME: &str = "second-solution";
+const MATRIX_ARG_NAME: &str = "matrix";
+
+pub fn get_compare_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("compare")
+        .about("Provides the way to explain which of two solutions is better by each objective")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets input file which contains a VRP definition")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(FIRST_SOLUTION_ARG_NAME)
+                .help("Sets the first solution file")
+                .short("a")
+                .long(FIRST_SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SECOND_SOLUTION_ARG_NAME)
+                .help("Sets the second solution file")
+                .short("b")
+                .long(SECOND_SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short("m")
+                .long(MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_compare(matches: &ArgMatches) {
+    let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    let open_reader =
+        |arg_name: &str, description: &str| BufReader::new(open_file(matches.value_of(arg_name).unwrap(), description));
+    let matrix_files = matches
+        .values_of(MATRIX_ARG_NAME)
+        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+
+    let result = match input_format {
+        "pragmatic" => compare_pragmatic_solutions(
+            open_reader(PROBLEM_ARG_NAME, "problem"),
+            open_reader(FIRST_SOLUTION_ARG_NAME, "first solution"),
+            open_reader(SECOND_SOLUTION_ARG_NAME, "second solution"),
+            matrix_files,
+        ),
+        _ => Err(format!("unknown format: '{}'", input_format)),
+    };
+
+    match result {
+        Ok(explanation) => println!("{}", explanation),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
@@ -6,18 +6,33 @@ use std::io::{BufReader, BufWriter, Write};
 use std::process;
 use std::sync::Arc;
 use vrp_cli::extensions::check::check_pragmatic_solution;
-use vrp_cli::extensions::solve::config::create_builder_from_config_file;
+use vrp_cli::extensions::solve::config::*;
+use vrp_cli::extensions::solve::trajectory::write_trajectory;
 use vrp_cli::{get_errors_serialized, get_locations_serialized};
+use vrp_core::construction::heuristics::INSERTION_NEIGHBORS_KEY;
+use vrp_core::models::common::{IdDimension, ValueDimension};
 use vrp_core::models::{Problem, Solution};
-use vrp_core::solver::{Builder, Metrics, Telemetry, TelemetryMode};
+use vrp_core::solver::termination::TerminationReport;
+use vrp_core::solver::{analyze_usability, create_scheduled_solution, estimate_schedule_feasibility, Metrics};
+use vrp_core::utils::{DefaultRandom, Random, StreamRandom};
+use vrp_pragmatic::format::problem::{
+    with_attribute_rules, with_auto_weights, Matrix, Problem as ApiProblem, Relation,
+};
+use vrp_pragmatic::format::FormatError;
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
+const FLEET_ARG_NAME: &str = "fleet";
+const JOBS_ARG_NAME: &str = "jobs";
 const MATRIX_ARG_NAME: &str = "matrix";
+const DISTANCE_MATRIX_ARG_NAME: &str = "distance-matrix";
+const DURATION_MATRIX_ARG_NAME: &str = "duration-matrix";
+const DUMP_MATRIX_ARG_NAME: &str = "dump-matrix";
 const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const COST_VARIATION_ARG_NAME: &str = "cost-variation";
 const GEO_JSON_ARG_NAME: &str = "geo-json";
+const ROUTE_CARD_ARG_NAME: &str = "route-card";
 
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
@@ -26,27 +41,128 @@ const CONFIG_ARG_NAME: &str = "config";
 const LOG_ARG_NAME: &str = "log";
 const CHECK_ARG_NAME: &str = "check";
 const RANDOM_SEED_NAME: &str = "seed";
+const EMBED_CONFIG_ARG_NAME: &str = "embed-config";
+const REQUIRE_ALL_ASSIGNED_ARG_NAME: &str = "require-all-assigned";
+const FROZEN_PREFIX_ARG_NAME: &str = "frozen-prefix";
+const ROUTE_HINTS_ARG_NAME: &str = "route-hints";
+const SCHEDULE_ONLY_ARG_NAME: &str = "schedule-only";
+const PROFILE_ARG_NAME: &str = "profile";
+const SERVICE_TIME_SAMPLES_ARG_NAME: &str = "service-time-samples";
+const INSERTION_NEIGHBORS_ARG_NAME: &str = "insertion-neighbors";
+const TRAJECTORY_ARG_NAME: &str = "trajectory";
+const AUTO_WEIGHTS_ARG_NAME: &str = "auto-weights";
+const DELTA_AGAINST_ARG_NAME: &str = "delta-against";
+const CONSTRUCTION_RETRIES_ARG_NAME: &str = "construction-retries";
+const HISTORY_ARG_NAME: &str = "history";
+const MIN_FLEET_ARG_NAME: &str = "min-fleet";
+const SERVICE_TARGET_ARG_NAME: &str = "service-target";
+const RULE_ARG_NAME: &str = "rule";
+const CHECK_USABILITY_ARG_NAME: &str = "check-usability";
 
-#[allow(clippy::type_complexity)]
-struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
+/// An exit code used when final solution has unassigned jobs and all jobs are required to be assigned.
+const UNASSIGNED_JOBS_EXIT_CODE: i32 = 2;
+
+/// Specifies amount of first activities in each tour of initial solution which should not be changed.
+struct FrozenPrefix {
+    solution: File,
+    sizes: HashMap<String, usize>,
+    default_size: usize,
+}
+
+/// Specifies relations created from tours of initial solution.
+enum InitRelations {
+    /// Keeps first activities of each tour unchanged.
+    FrozenPrefix(FrozenPrefix),
+    /// Prefers order of jobs in each tour: each violated order is penalized.
+    RouteHints { solution: File, penalty: f64 },
+}
+
+/// Specifies routing matrix files: either combined ones or distances and durations separately.
+enum MatrixFiles {
+    Combined(Vec<File>),
+    Split { distances: Vec<File>, durations: Vec<File> },
+}
+
+/// Specifies options applied to the problem when it is read.
+struct ReaderOptions {
+    matrices: Option<MatrixFiles>,
+    init_relations: Option<InitRelations>,
+    dump_matrix: Option<Vec<File>>,
+    auto_weights: bool,
+    history: Option<File>,
+    rules: Vec<String>,
+}
+
+impl ReaderOptions {
+    /// Returns an error when some of the options are used, except the supported ones.
+    fn ensure_supported(&self, format: &str, supported: &[&str]) -> Result<(), String> {
+        let matrices = self.matrices.as_ref().map(|matrices| match matrices {
+            MatrixFiles::Combined(_) => MATRIX_ARG_NAME,
+            MatrixFiles::Split { .. } => DISTANCE_MATRIX_ARG_NAME,
+        });
+        let init_relations = self.init_relations.as_ref().map(|init_relations| match init_relations {
+            InitRelations::FrozenPrefix(_) => FROZEN_PREFIX_ARG_NAME,
+            InitRelations::RouteHints { .. } => ROUTE_HINTS_ARG_NAME,
+        });
+
+        ensure_supported_options(
+            format,
+            &[
+                matrices,
+                init_relations,
+                self.dump_matrix.as_ref().map(|_| DUMP_MATRIX_ARG_NAME),
+                Some(AUTO_WEIGHTS_ARG_NAME).filter(|_| self.auto_weights),
+                self.history.as_ref().map(|_| HISTORY_ARG_NAME),
+                Some(RULE_ARG_NAME).filter(|_| !self.rules.is_empty()),
+            ],
+            supported,
+        )
+    }
+}
+
+/// Specifies options applied to the solution when it is written.
+struct WriterOptions<'a> {
+    config: Option<&'a Config>,
+    geojson: Option<BufWriter<Box<dyn Write>>>,
+    route_card: Option<BufWriter<Box<dyn Write>>>,
+    baseline: Option<File>,
+}
+
+impl WriterOptions<'_> {
+    /// Returns an error when any of the options is used.
+    fn ensure_empty(&self, format: &str) -> Result<(), String> {
+        ensure_supported_options(
+            format,
+            &[
+                self.config.map(|_| EMBED_CONFIG_ARG_NAME),
+                self.geojson.as_ref().map(|_| GEO_JSON_ARG_NAME),
+                self.route_card.as_ref().map(|_| ROUTE_CARD_ARG_NAME),
+                self.baseline.as_ref().map(|_| DELTA_AGAINST_ARG_NAME),
+            ],
+            &[],
+        )
+    }
+}
+
+fn ensure_supported_options(format: &str, options: &[Option<&str>], supported: &[&str]) -> Result<(), String> {
+    options
+        .iter()
+        .flatten()
+        .find(|option| !supported.contains(option))
+        .map_or(Ok(()), |option| Err(format!("option '{}' is not supported for format '{}'", option, format)))
+}
+
+struct ProblemReader(pub Box<dyn Fn(Vec<File>, ReaderOptions) -> Result<Problem, String>>);
 
 struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Result<Solution, String>>);
 
 #[allow(clippy::type_complexity)]
 struct SolutionWriter(
-    pub  Box<
-        dyn Fn(
-            &Problem,
-            Solution,
-            Option<Metrics>,
-            BufWriter<Box<dyn Write>>,
-            Option<BufWriter<Box<dyn Write>>>,
-        ) -> Result<(), String>,
-    >,
+    pub Box<dyn Fn(&Problem, Solution, Option<Metrics>, BufWriter<Box<dyn Write>>, WriterOptions) -> Result<(), String>>,
 );
 
 #[allow(clippy::type_complexity)]
-struct LocationWriter(pub Box<dyn Fn(File, BufWriter<Box<dyn Write>>) -> Result<(), String>>);
+struct LocationWriter(pub Box<dyn Fn(Vec<File>, BufWriter<Box<dyn Write>>) -> Result<(), String>>);
 
 #[allow(clippy::type_complexity)]
 type FormatMap<'a> = HashMap<&'a str, (ProblemReader, InitSolutionReader, SolutionWriter, LocationWriter)>;
@@ -60,24 +176,30 @@ fn add_scientific(formats: &mut FormatMap) {
         formats.insert(
             "solomon",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                    assert!(matrices.is_none());
-                    BufReader::new(problem).read_solomon()
+                ProblemReader(Box::new(|problem: Vec<File>, options: ReaderOptions| {
+                    options.ensure_supported("solomon", &[])?;
+                    BufReader::new(get_single_file(problem)?).read_solomon()
                 })),
                 InitSolutionReader(Box::new(|file, problem| read_init_solomon(BufReader::new(file), problem))),
-                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_solomon(writer))),
+                SolutionWriter(Box::new(|_, solution, _, writer, options| {
+                    options.ensure_empty("solomon")?;
+                    solution.write_solomon(writer)
+                })),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
         formats.insert(
             "lilim",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                    assert!(matrices.is_none());
-                    BufReader::new(problem).read_lilim()
+                ProblemReader(Box::new(|problem: Vec<File>, options: ReaderOptions| {
+                    options.ensure_supported("lilim", &[])?;
+                    BufReader::new(get_single_file(problem)?).read_lilim()
                 })),
                 InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
-                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_lilim(writer))),
+                SolutionWriter(Box::new(|_, solution, _, writer, options| {
+                    options.ensure_empty("lilim")?;
+                    solution.write_lilim(writer)
+                })),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
@@ -85,38 +207,38 @@ fn add_scientific(formats: &mut FormatMap) {
 }
 
 fn add_pragmatic(formats: &mut FormatMap) {
-    use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
+    use vrp_pragmatic::format::problem::PragmaticProblem;
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
-    use vrp_pragmatic::format::solution::PragmaticSolution;
 
     formats.insert(
         "pragmatic",
         (
-            ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                if let Some(matrices) = matrices {
-                    let matrices = matrices.into_iter().map(BufReader::new).collect();
-                    (BufReader::new(problem), matrices).read_pragmatic()
+            ProblemReader(Box::new(|problem: Vec<File>, options: ReaderOptions| {
+                let problem = read_pragmatic_problem(problem, map_format_errors)?;
+                let problem = if options.auto_weights { with_auto_weights(problem) } else { problem };
+                let problem =
+                    if let Some(history) = options.history { with_history(problem, history)? } else { problem };
+                let problem = with_attribute_rules(problem, options.rules.as_slice());
+                let problem = match options.init_relations {
+                    Some(InitRelations::FrozenPrefix(frozen_prefix)) => with_frozen_prefix(problem, frozen_prefix)?,
+                    Some(InitRelations::RouteHints { solution, penalty }) => {
+                        with_route_hints(problem, solution, penalty)?
+                    }
+                    None => problem,
+                };
+                let matrices = options.matrices.map(read_pragmatic_matrices).transpose()?;
+
+                if let Some(dump_matrix) = options.dump_matrix {
+                    read_pragmatic_with_matrix_dump(problem, matrices, dump_matrix)
                 } else {
-                    BufReader::new(problem).read_pragmatic()
+                    (problem, matrices).read_pragmatic().map_err(map_format_errors)
                 }
-                .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
             })),
             InitSolutionReader(Box::new(|file, problem| read_init_pragmatic(BufReader::new(file), problem))),
-            SolutionWriter(Box::new(|problem, solution, metrics, default_writer, geojson_writer| {
-                geojson_writer
-                    .map_or(Ok(()), |geojson_writer| solution.write_geo_json(problem, geojson_writer))
-                    .and_then(|_| {
-                        if let Some(metrics) = metrics {
-                            (solution, metrics).write_pragmatic_json(problem, default_writer)
-                        } else {
-                            solution.write_pragmatic_json(problem, default_writer)
-                        }
-                    })
-            })),
+            SolutionWriter(Box::new(write_pragmatic_solution)),
             LocationWriter(Box::new(|problem, writer| {
                 let mut writer = writer;
-                deserialize_problem(BufReader::new(problem))
-                    .map_err(|errors| get_errors_serialized(&errors))
+                read_pragmatic_problem(problem, |errors| get_errors_serialized(&errors))
                     .and_then(|problem| get_locations_serialized(&problem))
                     .and_then(|locations| writer.write_all(locations.as_bytes()).map_err(|err| err.to_string()))
             })),
@@ -124,11 +246,202 @@ fn add_pragmatic(formats: &mut FormatMap) {
     );
 }
 
+fn add_csv(formats: &mut FormatMap) {
+    if cfg!(feature = "csv-format") {
+        use vrp_cli::extensions::import::read_csv_problem;
+        use vrp_pragmatic::format::problem::PragmaticProblem;
+        use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
+
+        let read_problem = |problem: Vec<File>| match problem.as_slice() {
+            [jobs, vehicles] => read_csv_problem(BufReader::new(jobs), BufReader::new(vehicles))
+                .map_err(|err| format!("cannot read csv: {}", err)),
+            _ => Err("csv format expects two files with jobs and vehicles".to_string()),
+        };
+
+        formats.insert(
+            "csv",
+            (
+                ProblemReader(Box::new(move |problem: Vec<File>, options: ReaderOptions| {
+                    options.ensure_supported(
+                        "csv",
+                        &[
+                            MATRIX_ARG_NAME,
+                            DISTANCE_MATRIX_ARG_NAME,
+                            DUMP_MATRIX_ARG_NAME,
+                            AUTO_WEIGHTS_ARG_NAME,
+                            HISTORY_ARG_NAME,
+                            RULE_ARG_NAME,
+                        ],
+                    )?;
+
+                    let problem = read_problem(problem)?;
+                    let problem = if options.auto_weights { with_auto_weights(problem) } else { problem };
+                    let problem =
+                        if let Some(history) = options.history { with_history(problem, history)? } else { problem };
+                    let problem = with_attribute_rules(problem, options.rules.as_slice());
+                    // NOTE routing matrix is approximated from job and vehicle coordinates when it is omitted
+                    let matrices = options.matrices.map(read_pragmatic_matrices).transpose()?;
+
+                    if let Some(dump_matrix) = options.dump_matrix {
+                        read_pragmatic_with_matrix_dump(problem, matrices, dump_matrix)
+                    } else {
+                        (problem, matrices).read_pragmatic().map_err(map_format_errors)
+                    }
+                })),
+                InitSolutionReader(Box::new(|file, problem| read_init_pragmatic(BufReader::new(file), problem))),
+                SolutionWriter(Box::new(write_pragmatic_solution)),
+                LocationWriter(Box::new(move |problem, writer| {
+                    let mut writer = writer;
+                    read_problem(problem)
+                        .and_then(|problem| get_locations_serialized(&problem))
+                        .and_then(|locations| writer.write_all(locations.as_bytes()).map_err(|err| err.to_string()))
+                })),
+            ),
+        );
+    }
+}
+
+fn write_pragmatic_solution(
+    problem: &Problem,
+    solution: Solution,
+    metrics: Option<Metrics>,
+    default_writer: BufWriter<Box<dyn Write>>,
+    options: WriterOptions,
+) -> Result<(), String> {
+    let WriterOptions { config, geojson: geojson_writer, route_card: route_card_writer, baseline } = options;
+
+    use vrp_pragmatic::format::solution::{create_solution, create_solution_delta, deserialize_solution};
+    use vrp_pragmatic::format::solution::{serialize_solution, serialize_solution_delta, Extras, PragmaticSolution};
+
+    geojson_writer.map_or(Ok(()), |geojson_writer| solution.write_geo_json(problem, geojson_writer))?;
+    route_card_writer.map_or(Ok(()), |route_card_writer| solution.write_route_card(problem, route_card_writer))?;
+
+    if config.is_some() || baseline.is_some() {
+        let mut solution = create_solution(problem, &solution, metrics.as_ref());
+
+        if let Some(config) = config {
+            let config = serde_json::to_value(config).map_err(|err| err.to_string())?;
+            solution
+                .extras
+                .get_or_insert(Extras { metrics: None, config: None, termination: None, monetary: None, bbox: None })
+                .config = Some(config);
+        }
+
+        if let Some(baseline) = baseline {
+            let baseline = deserialize_solution(BufReader::new(baseline))
+                .map_err(|err| format!("cannot read baseline solution: {}", err))?;
+            let delta = create_solution_delta(&baseline, &solution);
+            serialize_solution_delta(default_writer, &delta).map_err(|err| err.to_string())
+        } else {
+            serialize_solution(default_writer, &solution).map_err(|err| err.to_string())
+        }
+    } else if let Some(metrics) = metrics {
+        (solution, metrics).write_pragmatic_json(problem, default_writer)
+    } else {
+        solution.write_pragmatic_json(problem, default_writer)
+    }
+}
+
+/// Reads pragmatic problem either from a single file or from separate jobs and fleet files.
+fn read_pragmatic_problem<F>(problem: Vec<File>, map_errors: F) -> Result<ApiProblem, String>
+where
+    F: Fn(Vec<FormatError>) -> String,
+{
+    use vrp_pragmatic::format::problem::{deserialize_problem, deserialize_split_problem};
+
+    let mut files = problem.into_iter();
+    match (files.next(), files.next(), files.next()) {
+        (Some(problem), None, None) => deserialize_problem(BufReader::new(problem)).map_err(map_errors),
+        (Some(jobs), Some(fleet), None) => {
+            deserialize_split_problem(BufReader::new(fleet), BufReader::new(jobs)).map_err(map_errors)
+        }
+        _ => Err("expected one problem file or jobs and fleet files".to_string()),
+    }
+}
+
+fn with_frozen_prefix(problem: ApiProblem, frozen_prefix: FrozenPrefix) -> Result<ApiProblem, String> {
+    use vrp_pragmatic::format::solution::{create_frozen_prefix_relations, deserialize_solution};
+
+    let solution = deserialize_solution(BufReader::new(frozen_prefix.solution))
+        .map_err(|err| format!("cannot deserialize initial solution: '{}'", err))?;
+    let relations = create_frozen_prefix_relations(&solution, &frozen_prefix.sizes, frozen_prefix.default_size);
+
+    Ok(with_relations(problem, relations))
+}
+
+fn with_route_hints(problem: ApiProblem, solution: File, penalty: f64) -> Result<ApiProblem, String> {
+    use vrp_pragmatic::format::solution::{create_route_hint_relations, deserialize_solution};
+
+    let solution = deserialize_solution(BufReader::new(solution))
+        .map_err(|err| format!("cannot deserialize initial solution: '{}'", err))?;
+    let relations = create_route_hint_relations(&solution, Some(penalty));
+
+    Ok(with_relations(problem, relations))
+}
+
+fn with_history(problem: ApiProblem, history: File) -> Result<ApiProblem, String> {
+    use vrp_pragmatic::format::problem::with_history_affinity;
+
+    let affinities: HashMap<String, String> = serde_json::from_reader(BufReader::new(history))
+        .map_err(|err| format!("cannot deserialize history: '{}'", err))?;
+
+    Ok(with_history_affinity(problem, &affinities))
+}
+
+fn with_relations(problem: ApiProblem, relations: Vec<Relation>) -> ApiProblem {
+    let mut problem = problem;
+    problem.plan.relations =
+        Some(problem.plan.relations.take().unwrap_or_default().into_iter().chain(relations).collect());
+
+    problem
+}
+
+fn read_pragmatic_with_matrix_dump(
+    problem: ApiProblem,
+    matrices: Option<Vec<Matrix>>,
+    dump_matrix: Vec<File>,
+) -> Result<Problem, String> {
+    use vrp_pragmatic::format::problem::{create_approx_matrices, serialize_matrix, PragmaticProblem};
+
+    let matrices = matrices.unwrap_or_else(|| create_approx_matrices(&problem));
+    if matrices.len() != dump_matrix.len() {
+        return Err(format!("expected {} files to dump routing matrix, got: {}", matrices.len(), dump_matrix.len()));
+    }
+
+    let problem = (problem, matrices.clone()).read_pragmatic().map_err(map_format_errors)?;
+
+    matrices.iter().zip(dump_matrix).try_for_each(|(matrix, file)| {
+        serialize_matrix(BufWriter::new(file), matrix).map_err(|err| format!("cannot dump routing matrix: '{}'", err))
+    })?;
+
+    Ok(problem)
+}
+
+fn read_pragmatic_matrices(matrices: MatrixFiles) -> Result<Vec<Matrix>, String> {
+    use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_split_matrices};
+
+    match matrices {
+        MatrixFiles::Combined(matrices) => {
+            matrices.into_iter().map(|matrix| deserialize_matrix(BufReader::new(matrix))).collect()
+        }
+        MatrixFiles::Split { distances, durations } => deserialize_split_matrices(
+            distances.into_iter().map(BufReader::new).collect(),
+            durations.into_iter().map(BufReader::new).collect(),
+        ),
+    }
+    .map_err(map_format_errors)
+}
+
+fn map_format_errors(errors: Vec<FormatError>) -> String {
+    errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n")
+}
+
 fn get_formats<'a>() -> FormatMap<'a> {
     let mut formats = FormatMap::default();
 
     add_scientific(&mut formats);
     add_pragmatic(&mut formats);
+    add_csv(&mut formats);
 
     formats
 }
@@ -140,10 +453,34 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
             Arg::with_name(FORMAT_ARG_NAME)
                 .help("Specifies the problem type")
                 .required(true)
-                .possible_values(&["solomon", "lilim", "pragmatic"])
+                .possible_values(&["solomon", "lilim", "pragmatic", "csv"])
                 .index(1),
         )
-        .arg(Arg::with_name(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets the problem file to use (csv format expects two files: jobs and vehicles)")
+                .required_unless(JOBS_ARG_NAME)
+                .multiple(true)
+                .index(2),
+        )
+        .arg(
+            Arg::with_name(FLEET_ARG_NAME)
+                .help("Specifies path to file with fleet, used together with jobs file instead of problem (pragmatic format only)")
+                .long(FLEET_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .requires(JOBS_ARG_NAME)
+                .conflicts_with(PROBLEM_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(JOBS_ARG_NAME)
+                .help("Specifies path to file with jobs, used together with fleet file instead of problem (pragmatic format only)")
+                .long(JOBS_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .requires(FLEET_ARG_NAME)
+                .conflicts_with_all(&[PROBLEM_ARG_NAME, CHECK_ARG_NAME]),
+        )
         .arg(
             Arg::with_name(GENERATIONS_ARG_NAME)
                 .help("Specifies maximum number of generations")
@@ -185,6 +522,37 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(DISTANCE_MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing distances, used together with duration matrix")
+                .long(DISTANCE_MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true)
+                .requires(DURATION_MATRIX_ARG_NAME)
+                .conflicts_with_all(&[MATRIX_ARG_NAME, CHECK_ARG_NAME]),
+        )
+        .arg(
+            Arg::with_name(DURATION_MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing durations, used together with distance matrix")
+                .long(DURATION_MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true)
+                .requires(DISTANCE_MATRIX_ARG_NAME)
+                .conflicts_with_all(&[MATRIX_ARG_NAME, CHECK_ARG_NAME]),
+        )
+        .arg(
+            Arg::with_name(DUMP_MATRIX_ARG_NAME)
+                .help(
+                    "Specifies path to file where routing matrix used by solver is written, one per profile \
+                     (pragmatic and csv formats only)",
+                )
+                .long(DUMP_MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(OUT_RESULT_ARG_NAME)
                 .help("Specifies path to file for result output")
@@ -208,6 +576,24 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(ROUTE_CARD_ARG_NAME)
+                .help("Specifies path to solution output in plain text route card format")
+                .long(ROUTE_CARD_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(DELTA_AGAINST_ARG_NAME)
+                .help(
+                    "Specifies path to baseline solution: the output contains only changes against it \
+                     (pragmatic and csv formats only)",
+                )
+                .long(DELTA_AGAINST_ARG_NAME)
+                .required(false)
+                .conflicts_with(CHECK_ARG_NAME)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(CONFIG_ARG_NAME)
                 .help("Specifies path to algorithm configuration file")
@@ -237,6 +623,179 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(EMBED_CONFIG_ARG_NAME)
+                .help("Specifies whether algorithm configuration should be embedded into solution output")
+                .long(EMBED_CONFIG_ARG_NAME)
+                .required(false)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name(FROZEN_PREFIX_ARG_NAME)
+                .help(
+                    "Specifies amount of first activities in each tour of initial solution which should not be \
+                     changed in form \"size\" or \"vehicle_id=size,..\" (pragmatic format only)",
+                )
+                .long(FROZEN_PREFIX_ARG_NAME)
+                .required(false)
+                .requires(INIT_SOLUTION_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(ROUTE_HINTS_ARG_NAME)
+                .help(
+                    "Specifies whether order of jobs in each tour of initial solution should be kept unless \
+                     a better one is found, with given penalty per each violated order (pragmatic format only)",
+                )
+                .long(ROUTE_HINTS_ARG_NAME)
+                .required(false)
+                .requires(INIT_SOLUTION_ARG_NAME)
+                .conflicts_with(FROZEN_PREFIX_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SCHEDULE_ONLY_ARG_NAME)
+                .help(
+                    "Specifies whether initial solution should be only scheduled without any optimization: \
+                     vehicle assignments and order of activities are kept as is",
+                )
+                .long(SCHEDULE_ONLY_ARG_NAME)
+                .required(false)
+                .requires(INIT_SOLUTION_ARG_NAME)
+                .conflicts_with_all(&[FROZEN_PREFIX_ARG_NAME, ROUTE_HINTS_ARG_NAME])
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name(PROFILE_ARG_NAME)
+                .help("Specifies whether time spent in each solver phase should be printed at the end")
+                .long(PROFILE_ARG_NAME)
+                .required(false)
+                .conflicts_with(SCHEDULE_ONLY_ARG_NAME)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name(CONSTRUCTION_RETRIES_ARG_NAME)
+                .help(
+                    "Specifies how many times construction of initial solution is retried with shuffled job order \
+                     and relaxed soft constraints when it leaves jobs unassigned",
+                )
+                .long(CONSTRUCTION_RETRIES_ARG_NAME)
+                .required(false)
+                .conflicts_with(SCHEDULE_ONLY_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SERVICE_TIME_SAMPLES_ARG_NAME)
+                .help(
+                    "Specifies amount of samples of random job service times used to estimate how often \
+                     solution schedule holds. Jobs without service time distribution keep their durations",
+                )
+                .long(SERVICE_TIME_SAMPLES_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(INSERTION_NEIGHBORS_ARG_NAME)
+                .help(
+                    "Specifies amount of used routes nearest to the job which are evaluated for its insertion. \
+                     Routes without jobs are always evaluated. By default, all routes are evaluated",
+                )
+                .long(INSERTION_NEIGHBORS_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(TRAJECTORY_ARG_NAME)
+                .help(
+                    "Specifies path to file where objective value trajectory is written as json array: \
+                     best and mean population cost per generation",
+                )
+                .long(TRAJECTORY_ARG_NAME)
+                .required(false)
+                .conflicts_with(SCHEDULE_ONLY_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(AUTO_WEIGHTS_ARG_NAME)
+                .help(
+                    "Specifies whether default objectives should use weights adapted to problem size: the smaller \
+                     the problem, the higher weight of tour amount. Ignored when objectives are specified in the \
+                     problem (pragmatic and csv formats only)",
+                )
+                .long(AUTO_WEIGHTS_ARG_NAME)
+                .required(false)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name(MIN_FLEET_ARG_NAME)
+                .help(
+                    "Specifies vehicle type id to find the minimum amount of its vehicles needed to serve all jobs \
+                     instead of solving the problem: it is solved repeatedly with decreasing fleet size until some \
+                     jobs are unassigned (pragmatic format only)",
+                )
+                .long(MIN_FLEET_ARG_NAME)
+                .required(false)
+                .conflicts_with_all(&[INIT_SOLUTION_ARG_NAME, GET_LOCATIONS_ARG_NAME, CHECK_ARG_NAME])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SERVICE_TARGET_ARG_NAME)
+                .help(
+                    "Specifies target share of served jobs, e.g. 0.95, to report service level of the solution \
+                     instead of solving the problem: when it is below the target, the minimal amount of vehicles \
+                     to add is suggested (pragmatic format only)",
+                )
+                .long(SERVICE_TARGET_ARG_NAME)
+                .required(false)
+                .conflicts_with_all(&[
+                    INIT_SOLUTION_ARG_NAME,
+                    GET_LOCATIONS_ARG_NAME,
+                    CHECK_ARG_NAME,
+                    MIN_FLEET_ARG_NAME,
+                ])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(HISTORY_ARG_NAME)
+                .help(
+                    "Specifies path to json file with historical assignments as a map of job ids to vehicle ids: \
+                     among equally good solutions, ones which keep jobs on their vehicles are preferred \
+                     (pragmatic and csv formats only)",
+                )
+                .long(HISTORY_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(RULE_ARG_NAME)
+                .help(
+                    "Specifies a rule over job and vehicle attributes which should hold for each assignment, e.g. \
+                     \"!(job.tag == 'fragile' && vehicle.type_id == 'construction')\", can be repeated \
+                     (pragmatic and csv formats only)",
+                )
+                .long(RULE_ARG_NAME)
+                .required(false)
+                .multiple(true)
+                .number_of_values(1)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(CHECK_USABILITY_ARG_NAME)
+                .help(
+                    "Specifies whether vehicles which cannot serve any job and jobs which cannot be served by any \
+                     vehicle should be reported as warnings before solving",
+                )
+                .long(CHECK_USABILITY_ARG_NAME)
+                .required(false)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name(REQUIRE_ALL_ASSIGNED_ARG_NAME)
+                .help("Specifies whether solver should exit with error code when some jobs are not assigned")
+                .long(REQUIRE_ALL_ASSIGNED_ARG_NAME)
+                .required(false)
+                .takes_value(false),
+        )
 }
 
 /// Runs solver commands.
@@ -244,18 +803,24 @@ pub fn run_solve(matches: &ArgMatches) {
     let formats = get_formats();
 
     // required
-    let problem_path = matches.value_of(PROBLEM_ARG_NAME).unwrap();
     let problem_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
-    let problem_file = open_file(problem_path, "problem");
+    let problem_paths = match (matches.value_of(JOBS_ARG_NAME), matches.value_of(FLEET_ARG_NAME)) {
+        (Some(jobs), Some(fleet)) => {
+            if problem_format != "pragmatic" {
+                eprintln!("separate jobs and fleet files are supported only for pragmatic format");
+                process::exit(1);
+            }
+
+            vec![jobs, fleet]
+        }
+        _ => matches.values_of(PROBLEM_ARG_NAME).unwrap().collect::<Vec<_>>(),
+    };
+    let problem_files = problem_paths.iter().map(|path| open_file(path, "problem")).collect::<Vec<_>>();
 
     // optional
     let max_generations = parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations");
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time");
-    let telemetry = Telemetry::new(if matches.is_present(LOG_ARG_NAME) {
-        TelemetryMode::OnlyLogging { logger: Arc::new(|msg| println!("{}", msg)), log_best: 100, log_population: 1000 }
-    } else {
-        TelemetryMode::None
-    });
+    let is_logging_requested = matches.is_present(LOG_ARG_NAME);
     let is_check_requested = matches.is_present(CHECK_ARG_NAME);
 
     let cost_variation = matches.value_of(COST_VARIATION_ARG_NAME).map(|arg| {
@@ -269,27 +834,146 @@ pub fn run_solve(matches: &ArgMatches) {
         }
     });
     let init_solution = matches.value_of(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
+    let frozen_prefix = matches.value_of(FROZEN_PREFIX_ARG_NAME).map(|arg| {
+        if problem_format != "pragmatic" {
+            eprintln!("frozen prefix is supported only for pragmatic format");
+            process::exit(1);
+        }
+
+        let solution = open_file(matches.value_of(INIT_SOLUTION_ARG_NAME).unwrap(), "init solution");
+        parse_frozen_prefix(arg, solution).map(InitRelations::FrozenPrefix).unwrap_or_else(|err| {
+            eprintln!("cannot parse frozen prefix: '{}'", err);
+            process::exit(1);
+        })
+    });
+    let route_hints = matches.value_of(ROUTE_HINTS_ARG_NAME).map(|arg| {
+        if problem_format != "pragmatic" {
+            eprintln!("route hints are supported only for pragmatic format");
+            process::exit(1);
+        }
+
+        let solution = open_file(matches.value_of(INIT_SOLUTION_ARG_NAME).unwrap(), "init solution");
+        match arg.parse::<f64>() {
+            Ok(penalty) if penalty >= 0. => InitRelations::RouteHints { solution, penalty },
+            _ => {
+                eprintln!("cannot parse route hints penalty: '{}'", arg);
+                process::exit(1);
+            }
+        }
+    });
+    let init_relations = frozen_prefix.or(route_hints);
     let config = matches.value_of(CONFIG_ARG_NAME).map(|path| open_file(path, "config"));
     let matrix_files = get_matrix_files(matches);
+    let dump_matrix = matches.values_of(DUMP_MATRIX_ARG_NAME).map(|paths: Values| {
+        if problem_format != "pragmatic" && problem_format != "csv" {
+            eprintln!("matrix dump is supported only for pragmatic and csv formats");
+            process::exit(1);
+        }
+
+        paths.map(|path| create_file(path, "dump matrix")).collect::<Vec<_>>()
+    });
     let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
+    let out_route_card = matches.value_of(ROUTE_CARD_ARG_NAME).map(|path| create_file(path, "out route card"));
+    let out_trajectory = matches.value_of(TRAJECTORY_ARG_NAME).map(|path| create_file(path, "out trajectory"));
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
     let seed = parse_int_value::<u64>(matches, RANDOM_SEED_NAME, "seed");
+    let is_embed_config_set = matches.is_present(EMBED_CONFIG_ARG_NAME);
+    let is_all_assigned_required = matches.is_present(REQUIRE_ALL_ASSIGNED_ARG_NAME);
+    let is_schedule_only_set = matches.is_present(SCHEDULE_ONLY_ARG_NAME);
+    let is_profile_set = matches.is_present(PROFILE_ARG_NAME);
+    let is_check_usability_set = matches.is_present(CHECK_USABILITY_ARG_NAME);
+    let construction_retries =
+        parse_int_value::<usize>(matches, CONSTRUCTION_RETRIES_ARG_NAME, "construction retries").unwrap_or(0);
+    let service_time_samples = parse_int_value::<usize>(matches, SERVICE_TIME_SAMPLES_ARG_NAME, "service time samples");
+    let insertion_neighbors = parse_int_value::<usize>(matches, INSERTION_NEIGHBORS_ARG_NAME, "insertion neighbors");
+    let is_auto_weights_set = matches.is_present(AUTO_WEIGHTS_ARG_NAME);
+    if is_auto_weights_set && problem_format != "pragmatic" && problem_format != "csv" {
+        eprintln!("auto weights are supported only for pragmatic and csv formats");
+        process::exit(1);
+    }
+    let history = matches.value_of(HISTORY_ARG_NAME).map(|path| {
+        if problem_format != "pragmatic" && problem_format != "csv" {
+            eprintln!("history affinity is supported only for pragmatic and csv formats");
+            process::exit(1);
+        }
+
+        open_file(path, "history")
+    });
+    let rules = matches.values_of(RULE_ARG_NAME).map_or_else(Vec::new, |rules| {
+        if problem_format != "pragmatic" && problem_format != "csv" {
+            eprintln!("attribute rules are supported only for pragmatic and csv formats");
+            process::exit(1);
+        }
+
+        rules.map(|rule| rule.to_string()).collect()
+    });
+    let delta_against = matches.value_of(DELTA_AGAINST_ARG_NAME).map(|path| {
+        if problem_format != "pragmatic" && problem_format != "csv" {
+            eprintln!("delta output is supported only for pragmatic and csv formats");
+            process::exit(1);
+        }
+
+        open_file(path, "baseline solution")
+    });
+
+    let config = if let Some(config) = config {
+        read_config(BufReader::new(config)).unwrap_or_else(|err| {
+            eprintln!("cannot read config: '{}'", err);
+            process::exit(1);
+        })
+    } else {
+        create_config_from_args(max_generations, max_time, cost_variation, is_logging_requested, seed)
+    };
+
+    if let Some(type_id) = matches.value_of(MIN_FLEET_ARG_NAME) {
+        if problem_format != "pragmatic" {
+            eprintln!("min fleet search is supported only for pragmatic format");
+            process::exit(1);
+        }
+
+        print_min_fleet_size(problem_files, matrix_files, type_id, &config);
+        return;
+    }
+
+    if let Some(target) = parse_float_value::<f64>(matches, SERVICE_TARGET_ARG_NAME, "service target") {
+        if problem_format != "pragmatic" {
+            eprintln!("service level evaluation is supported only for pragmatic format");
+            process::exit(1);
+        }
+
+        print_service_level(problem_files, matrix_files, target, &config);
+        return;
+    }
 
     match formats.get(problem_format) {
         Some((problem_reader, init_reader, solution_writer, locations_writer)) => {
             let out_buffer = create_write_buffer(out_result);
             let geo_buffer = out_geojson.map(|geojson| create_write_buffer(Some(geojson)));
+            let route_card_buffer = out_route_card.map(|route_card| create_write_buffer(Some(route_card)));
 
             if is_get_locations_set {
-                locations_writer.0(problem_file, out_buffer).unwrap_or_else(|err| {
+                locations_writer.0(problem_files, out_buffer).unwrap_or_else(|err| {
                     eprintln!("cannot get locations '{}'", err);
                     process::exit(1);
                 });
             } else {
-                match problem_reader.0(problem_file, matrix_files) {
+                let reader_options = ReaderOptions {
+                    matrices: matrix_files,
+                    init_relations,
+                    dump_matrix,
+                    auto_weights: is_auto_weights_set,
+                    history,
+                    rules,
+                };
+
+                match problem_reader.0(problem_files, reader_options) {
                     Ok(problem) => {
-                        let problem = Arc::new(problem);
+                        let problem = Arc::new(with_insertion_neighbors(problem, insertion_neighbors));
+                        if is_check_usability_set {
+                            print_usability_warnings(problem.clone());
+                        }
+
                         let solutions = init_solution.map_or_else(Vec::new, |file| {
                             init_reader.0(file, problem.clone())
                                 .map_err(|err| {
@@ -300,39 +984,81 @@ pub fn run_solve(matches: &ArgMatches) {
                                 .unwrap()
                         });
 
-                        let builder = if let Some(config) = config {
-                            create_builder_from_config_file(problem.clone(), BufReader::new(config)).unwrap_or_else(
-                                |err| {
-                                    eprintln!("cannot read config: '{}'", err);
-                                    process::exit(1);
-                                },
-                            )
+                        let (solution, metrics) = if is_schedule_only_set {
+                            let solution = solutions.into_iter().next().expect("init solution is required");
+                            (create_scheduled_solution(problem.clone(), solution), None)
                         } else {
-                            Builder::new(problem.clone())
-                                .with_max_generations(max_generations)
-                                .with_max_time(max_time)
-                                .with_cost_variation(cost_variation)
-                                .with_telemetry(telemetry)
-                                .with_seed(seed)
+                            let solver_config =
+                                if out_trajectory.is_some() { with_metrics(&config) } else { config.clone() };
+                            let builder =
+                                create_builder_from_config(problem.clone(), &solver_config).unwrap_or_else(|err| {
+                                    eprintln!("cannot create solver from config: '{}'", err);
+                                    process::exit(1);
+                                });
+
+                            let (solution, _, metrics) = builder
+                                .with_init_solutions(solutions)
+                                .with_profile(is_profile_set)
+                                .with_construction_retries(construction_retries)
+                                .build()
+                                .and_then(|solver| solver.solve())
+                                .unwrap_or_else(|err| {
+                                    eprintln!("cannot find any solution: '{}'", err);
+                                    process::exit(1);
+                                });
+
+                            if let Some((metrics, file)) = metrics.as_ref().zip(out_trajectory) {
+                                write_trajectory(metrics, create_write_buffer(Some(file))).unwrap_or_else(|err| {
+                                    eprintln!("{}", err);
+                                    process::exit(1);
+                                });
+                            }
+
+                            // NOTE metrics enabled only to collect trajectory are not written with solution
+                            (solution, metrics.filter(|_| has_metrics(&config)))
                         };
 
-                        let (solution, _, metrics) = builder
-                            .with_init_solutions(solutions)
-                            .build()
-                            .and_then(|solver| solver.solve())
-                            .unwrap_or_else(|err| {
-                                eprintln!("cannot find any solution: '{}'", err);
-                                process::exit(1);
-                            });
+                        let config = if is_embed_config_set { Some(&config) } else { None };
+                        let unassigned_ids = get_unassigned_job_ids(&solution);
+
+                        if is_profile_set {
+                            print_phase_timings(&solution);
+                        }
+
+                        if let Some(samples) = service_time_samples {
+                            print_schedule_feasibility(problem.as_ref(), &solution, samples, seed);
+                        }
+
+                        let writer_options = WriterOptions {
+                            config,
+                            geojson: geo_buffer,
+                            route_card: route_card_buffer,
+                            baseline: delta_against,
+                        };
 
-                        solution_writer.0(&problem, solution, metrics, out_buffer, geo_buffer).unwrap();
+                        solution_writer.0(&problem, solution, metrics, out_buffer, writer_options).unwrap_or_else(
+                            |err| {
+                                eprintln!("cannot write solution: '{}'", err);
+                                process::exit(1);
+                            },
+                        );
 
                         if is_check_requested {
                             check_solution(matches);
                         }
+
+                        if is_all_assigned_required && !unassigned_ids.is_empty() {
+                            eprintln!("solution has unassigned jobs: '{}'", unassigned_ids.join(", "));
+                            process::exit(UNASSIGNED_JOBS_EXIT_CODE);
+                        }
                     }
                     Err(error) => {
-                        eprintln!("cannot read {} problem from '{}': '{}'", problem_format, problem_path, error);
+                        eprintln!(
+                            "cannot read {} problem from '{}': '{}'",
+                            problem_format,
+                            problem_paths.join(", "),
+                            error
+                        );
                         process::exit(1);
                     }
                 };
@@ -345,10 +1071,235 @@ pub fn run_solve(matches: &ArgMatches) {
     }
 }
 
-fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
-    matches
-        .values_of(MATRIX_ARG_NAME)
-        .map(|paths: Values| paths.map(|path| open_file(path, "routing matrix")).collect())
+fn create_config_from_args(
+    max_generations: Option<usize>,
+    max_time: Option<usize>,
+    cost_variation: Option<(usize, f64)>,
+    is_logging_requested: bool,
+    seed: Option<u64>,
+) -> Config {
+    Config {
+        termination: Some(TerminationConfig {
+            max_time,
+            max_generations,
+            variation: cost_variation.map(|(sample, cv)| VariationConfig { sample, cv }),
+        }),
+        telemetry: if is_logging_requested {
+            Some(TelemetryConfig {
+                logging: Some(LoggingConfig { enabled: true, log_best: Some(100), log_population: Some(1000) }),
+                metrics: None,
+            })
+        } else {
+            None
+        },
+        seed,
+        ..Config::default()
+    }
+}
+
+fn has_metrics(config: &Config) -> bool {
+    matches!(config.telemetry.as_ref().and_then(|telemetry| telemetry.metrics.as_ref()), Some(metrics) if metrics.enabled)
+}
+
+fn with_metrics(config: &Config) -> Config {
+    let telemetry = config.telemetry.clone().unwrap_or(TelemetryConfig { logging: None, metrics: None });
+    let track_population = telemetry.metrics.as_ref().and_then(|metrics| metrics.track_population);
+
+    Config {
+        telemetry: Some(TelemetryConfig {
+            metrics: Some(MetricsConfig { enabled: true, track_population }),
+            ..telemetry
+        }),
+        ..config.clone()
+    }
+}
+
+fn with_insertion_neighbors(problem: Problem, insertion_neighbors: Option<usize>) -> Problem {
+    match insertion_neighbors {
+        Some(neighbors) => {
+            let mut extras = problem.extras.as_ref().clone();
+            extras.insert(INSERTION_NEIGHBORS_KEY.to_string(), Arc::new(neighbors));
+
+            Problem { extras: Arc::new(extras), ..problem }
+        }
+        None => problem,
+    }
+}
+
+fn parse_frozen_prefix(arg: &str, solution: File) -> Result<FrozenPrefix, String> {
+    let parse_size = |size: &str| size.trim().parse::<usize>().map_err(|err| format!("'{}': {}", size, err));
+
+    arg.split(',').try_fold(FrozenPrefix { solution, sizes: HashMap::default(), default_size: 0 }, |mut acc, entry| {
+        if let Some((vehicle_id, size)) = entry.split_once('=') {
+            acc.sizes.insert(vehicle_id.trim().to_string(), parse_size(size)?);
+        } else {
+            acc.default_size = parse_size(entry)?;
+        }
+
+        Ok(acc)
+    })
+}
+
+fn get_single_file(files: Vec<File>) -> Result<File, String> {
+    let mut files = files;
+    if files.len() == 1 {
+        Ok(files.swap_remove(0))
+    } else {
+        Err(format!("expected one problem file, got: {}", files.len()))
+    }
+}
+
+fn get_matrix_files(matches: &ArgMatches) -> Option<MatrixFiles> {
+    let get_files = |name: &str, description: &str| {
+        matches.values_of(name).map(|paths: Values| paths.map(|path| open_file(path, description)).collect())
+    };
+
+    match (
+        get_files(MATRIX_ARG_NAME, "routing matrix"),
+        get_files(DISTANCE_MATRIX_ARG_NAME, "distance matrix"),
+        get_files(DURATION_MATRIX_ARG_NAME, "duration matrix"),
+    ) {
+        (Some(matrices), _, _) => Some(MatrixFiles::Combined(matrices)),
+        (None, Some(distances), Some(durations)) => Some(MatrixFiles::Split { distances, durations }),
+        _ => None,
+    }
+}
+
+fn get_unassigned_job_ids(solution: &Solution) -> Vec<String> {
+    // NOTE jobs bound to specific vehicle, such as breaks, are not reported as unassigned
+    let mut ids = solution
+        .unassigned
+        .iter()
+        .map(|(job, _)| job)
+        .filter(|job| job.dimens().get_value::<String>("vehicle_id").is_none())
+        .filter_map(|job| job.dimens().get_id().cloned())
+        .collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+fn print_usability_warnings(problem: Arc<Problem>) {
+    // NOTE jobs bound to specific vehicle, such as breaks, are not analyzed
+    let jobs =
+        problem.jobs.all().filter(|job| job.dimens().get_value::<String>("vehicle_id").is_none()).collect::<Vec<_>>();
+    let report = analyze_usability(problem, jobs.as_slice());
+
+    report.unusable_vehicles.iter().for_each(|vehicle_id| {
+        eprintln!("warning: vehicle '{}' cannot serve any job", vehicle_id);
+    });
+    report.unservable_jobs.iter().for_each(|job_id| {
+        eprintln!("warning: job '{}' cannot be served by any vehicle", job_id);
+    });
+}
+
+fn print_phase_timings(solution: &Solution) {
+    let report = solution.extras.get("termination").and_then(|report| report.downcast_ref::<TerminationReport>());
+
+    if let Some((timings, total)) = report.and_then(|r| r.phase_timings.as_ref().map(|timings| (timings, r.duration))) {
+        let phases = [
+            ("construction", timings.construction),
+            ("selection", timings.selection),
+            ("mutation", timings.mutation),
+            ("evaluation", timings.evaluation),
+            ("other", timings.other),
+        ];
+
+        eprintln!("phase timings:");
+        phases.iter().for_each(|(name, time)| {
+            let share = if total > 0. { time / total * 100. } else { 0. };
+            eprintln!("  {:<12} {:>10.3}s {:>6.1}%", name, time, share);
+        });
+        eprintln!("  {:<12} {:>10.3}s", "total", total);
+    }
+}
+
+fn print_min_fleet_size(problem: Vec<File>, matrices: Option<MatrixFiles>, type_id: &str, config: &Config) {
+    use vrp_cli::extensions::solve::min_fleet::find_min_fleet_size;
+    use vrp_pragmatic::format::problem::PragmaticProblem;
+
+    let result = read_pragmatic_problem(problem, map_format_errors)
+        .and_then(|problem| Ok((problem, matrices.map(read_pragmatic_matrices).transpose()?)))
+        .and_then(|(problem, matrices)| {
+            find_min_fleet_size(&problem, type_id, |problem| {
+                let problem = Arc::new((problem, matrices.clone()).read_pragmatic().map_err(map_format_errors)?);
+
+                create_builder_from_config(problem, config)
+                    .and_then(|builder| builder.build())
+                    .and_then(|solver| solver.solve())
+                    .map(|(solution, _, _)| solution)
+            })
+        });
+
+    match result {
+        Ok(Some(size)) => println!("minimum fleet size of '{}': {}", type_id, size),
+        Ok(None) => {
+            eprintln!("cannot serve all jobs with the whole fleet of '{}'", type_id);
+            process::exit(UNASSIGNED_JOBS_EXIT_CODE);
+        }
+        Err(err) => {
+            eprintln!("cannot find min fleet size: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn print_service_level(problem: Vec<File>, matrices: Option<MatrixFiles>, target: f64, config: &Config) {
+    use vrp_cli::extensions::solve::service_level::evaluate_service_level;
+    use vrp_pragmatic::format::problem::PragmaticProblem;
+
+    let result = read_pragmatic_problem(problem, map_format_errors)
+        .and_then(|problem| Ok((problem, matrices.map(read_pragmatic_matrices).transpose()?)))
+        .and_then(|(problem, matrices)| {
+            evaluate_service_level(&problem, target, |problem| {
+                let problem = Arc::new((problem, matrices.clone()).read_pragmatic().map_err(map_format_errors)?);
+
+                create_builder_from_config(problem, config)
+                    .and_then(|builder| builder.build())
+                    .and_then(|solver| solver.solve())
+                    .map(|(solution, _, _)| solution)
+            })
+        });
+
+    match result {
+        Ok(report) => {
+            println!("service level: {:.1}% (target: {:.1}%)", report.service_level * 100., target * 100.);
+
+            match report.scaling {
+                Some(scaling) => println!(
+                    "suggested fleet addition: {} vehicle(s) of '{}', service level: {:.1}%",
+                    scaling.extra_vehicles,
+                    scaling.type_id,
+                    scaling.service_level * 100.
+                ),
+                None if report.service_level < target => {
+                    eprintln!("cannot reach service level target by adding vehicles of one type");
+                    process::exit(UNASSIGNED_JOBS_EXIT_CODE);
+                }
+                None => {}
+            }
+        }
+        Err(err) => {
+            eprintln!("cannot evaluate service level: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn print_schedule_feasibility(problem: &Problem, solution: &Solution, samples: usize, seed: Option<u64>) {
+    let random: Box<dyn Random> = match seed {
+        Some(seed) => Box::new(StreamRandom::new(seed, "service_times")),
+        None => Box::new(DefaultRandom::default()),
+    };
+
+    let feasibility = estimate_schedule_feasibility(problem, solution, samples, random.as_ref());
+
+    eprintln!(
+        "schedule feasibility: {:.1}% ({} of {} samples)",
+        feasibility.probability() * 100.,
+        feasibility.feasible,
+        feasibility.samples
+    );
 }
 
 fn check_solution(matches: &ArgMatches) {
@@ -0,0 +1,82 @@
+//! A helper module which contains functionality to compare two solutions by problem objectives.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/compare/compare_test.rs"]
+mod compare_test;
+
+use std::cmp::Ordering;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::models::problem::ObjectiveLevel;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
+use vrp_pragmatic::format::solution::{compare_solutions, read_init_solution, SolutionComparison};
+use vrp_pragmatic::format::FormatError;
+
+/// Compares two pragmatic solutions by each objective term of the problem and returns a plain text
+/// explanation: values of each term for both solutions, which solution is better by the term and
+/// which one is better overall.
+pub fn compare_pragmatic_solutions<F: Read>(
+    problem_reader: BufReader<F>,
+    first_reader: BufReader<F>,
+    second_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<String, String> {
+    let problem = deserialize_problem(problem_reader)
+        .map_err(|errs| format!("cannot read problem: '{}'", FormatError::format_many(&errs, ",")))?;
+
+    let matrices = matrices_readers
+        .map(|matrices| {
+            matrices
+                .into_iter()
+                .map(|matrix| {
+                    deserialize_matrix(matrix)
+                        .map_err(|errs| format!("cannot read matrix: '{}'", FormatError::format_many(&errs, ",")))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+
+    let core_problem = Arc::new(
+        (problem.clone(), matrices)
+            .read_pragmatic()
+            .map_err(|errs| format!("cannot read pragmatic problem: '{}'", FormatError::format_many(&errs, ",")))?,
+    );
+
+    let first = read_init_solution(first_reader, core_problem.clone())?;
+    let second = read_init_solution(second_reader, core_problem.clone())?;
+
+    Ok(format_comparison(&compare_solutions(core_problem, &problem, first, second)))
+}
+
+fn format_comparison(comparison: &SolutionComparison) -> String {
+    let get_winner = |order: &Ordering| match order {
+        Ordering::Less => "first",
+        Ordering::Greater => "second",
+        Ordering::Equal => "tie",
+    };
+    let get_level = |level: &ObjectiveLevel| match level {
+        ObjectiveLevel::Primary => "primary",
+        ObjectiveLevel::Secondary => "secondary",
+        ObjectiveLevel::TieBreak => "tie-break",
+    };
+
+    let header = format!("{:<28} {:<10} {:>14} {:>14}  {}", "objective", "level", "first", "second", "winner");
+    let terms = comparison.terms.iter().map(|term| {
+        format!(
+            "{:<28} {:<10} {:>14.3} {:>14.3}  {}",
+            term.name,
+            get_level(&term.level),
+            term.values.0,
+            term.values.1,
+            get_winner(&term.order)
+        )
+    });
+    let summary = match comparison.level.as_ref() {
+        Some(level) => {
+            format!("winner: {}, decided by {} objectives", get_winner(&comparison.order), get_level(level))
+        }
+        None => "winner: tie, solutions are equal by all objectives".to_string(),
+    };
+
+    std::iter::once(header).chain(terms).chain(std::iter::once(summary)).collect::<Vec<_>>().join("\n")
+}
@@ -28,8 +28,16 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
                 costs: get_random_item(costs.as_slice(), &rnd).expect("cannot find any costs").clone(),
                 shifts: get_random_item(shifts.as_slice(), &rnd).expect("cannot find any shifts").clone(),
                 capacity: get_random_item(capacities.as_slice(), &rnd).expect("cannot find any capacity").clone(),
+                aggregate_capacity: None,
+                consumption: None,
                 skills: get_random_item(skills.as_slice(), &rnd).expect("cannot find any skills").clone(),
                 limits: get_random_item(limits.as_slice(), &rnd).expect("cannot find any limits").clone(),
+                range: None,
+                crew_size: None,
+                loading_discipline: None,
+                reload_handling_time: None,
+                maneuvering_time: None,
+                capacity_decay: None,
             }
         })
         .collect();
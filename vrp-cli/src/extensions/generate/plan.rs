@@ -36,6 +36,11 @@ pub(crate) fn generate_plan(
                             location: get_location_fn(&rnd),
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
+                            relative_times: None,
+                            opening_times: None,
+                            grace: None,
+                            time_penalties: None,
+                            skill_times: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -45,6 +50,8 @@ pub(crate) fn generate_plan(
                     },
 
                     tag: None,
+                    type_durations: None,
+                    variants: None,
                 })
                 .collect::<Vec<_>>()
         })
@@ -66,6 +73,15 @@ pub(crate) fn generate_plan(
                 services: generate_tasks(&job_proto.services, true),
                 priority: job_proto.priority,
                 skills: job_proto.skills.clone(),
+                hard_time_window: job_proto.hard_time_window,
+                companions: None,
+                transfer_from: None,
+                service_time_distribution: None,
+                corridor: None,
+                crew_size: None,
+                workload: None,
+                vehicle_affinity: None,
+                age: None,
             }
         })
         .collect();
@@ -70,9 +70,16 @@ mod actual {
                 location: Location::Coordinate { lat: job.lat, lng: job.lng },
                 duration: job.duration as f64 * 60.,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
+                relative_times: None,
+                opening_times: None,
+                grace: None,
+                time_penalties: None,
+                skill_times: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             tag: None,
+            type_durations: None,
+            variants: None,
         };
 
         let get_tasks = |jobs: &Vec<&CsvJob>, filter: Box<dyn Fn(&CsvJob) -> bool>| {
@@ -99,6 +106,15 @@ mod actual {
                 services: get_tasks(&tasks, Box::new(|j| j.demand == 0)),
                 priority: None,
                 skills: None,
+                hard_time_window: None,
+                companions: None,
+                transfer_from: None,
+                service_time_distribution: None,
+                corridor: None,
+                crew_size: None,
+                workload: None,
+                vehicle_affinity: None,
+                age: None,
             })
             .collect();
 
@@ -115,21 +131,44 @@ mod actual {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                     profile: vehicle.profile,
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    costs: VehicleCosts {
+                        fixed: Some(25.),
+                        distance: 0.0002,
+                        time: 0.005,
+                        distance_tiers: None,
+                        max_daily_cost: None,
+                        load_distance: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
                             latest: None,
                             location: depot_location.clone(),
+                            alternatives: None,
+                            dock_slot: None,
                         },
-                        end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: vehicle.tw_end,
+                            location: depot_location,
+                            max_overtime: None,
+                        }),
                         depots: None,
                         breaks: None,
                         reloads: None,
+                        anchors: None,
                     }],
                     capacity: vec![vehicle.capacity],
+                    aggregate_capacity: None,
+                    consumption: None,
                     skills: None,
                     limits: None,
+                    range: None,
+                    crew_size: None,
+                    loading_discipline: None,
+                    reload_handling_time: None,
+                    maneuvering_time: None,
+                    capacity_decay: None,
                 }
             })
             .collect();
@@ -333,9 +333,16 @@ mod deserialize {
                 location: to_pragmatic_loc(&place.location),
                 duration: place.duration,
                 times: place.times.clone(),
+                relative_times: None,
+                opening_times: None,
+                grace: None,
+                time_penalties: None,
+                skill_times: None,
             }],
             demand: Some(job.demand.clone()),
             tag: place.tag.clone(),
+            type_durations: None,
+            variants: None,
         };
 
         let multi_job_place_mapper = |places: &Vec<models::MultiJobPlace>| {
@@ -350,9 +357,16 @@ mod deserialize {
                                 location: to_pragmatic_loc(&place.location),
                                 duration: place.duration,
                                 times: place.times.clone(),
+                                relative_times: None,
+                                opening_times: None,
+                                grace: None,
+                                time_penalties: None,
+                                skill_times: None,
                             }],
                             demand: Some(place.demand.clone()),
                             tag: place.tag.clone(),
+                            type_durations: None,
+                            variants: None,
                         })
                         .collect(),
                 )
@@ -372,6 +386,15 @@ mod deserialize {
                         services: None,
                         priority: job.priority.as_ref().copied(),
                         skills: job.skills.clone(),
+                        hard_time_window: None,
+                        companions: None,
+                        transfer_from: None,
+                        service_time_distribution: None,
+                        corridor: None,
+                        crew_size: None,
+                        workload: None,
+                        vehicle_affinity: None,
+                        age: None,
                     },
                     models::JobVariant::Multi(job) => Job {
                         id: job.id.clone(),
@@ -381,6 +404,15 @@ mod deserialize {
                         services: None,
                         priority: job.priority.as_ref().copied(),
                         skills: job.skills.clone(),
+                        hard_time_window: None,
+                        companions: None,
+                        transfer_from: None,
+                        service_time_distribution: None,
+                        corridor: None,
+                        crew_size: None,
+                        workload: None,
+                        vehicle_affinity: None,
+                        age: None,
                     },
                 })
                 .collect(),
@@ -394,8 +426,9 @@ mod deserialize {
                             models::RelationType::Tour => RelationType::Any,
                         },
                         jobs: r.jobs.clone(),
-                        vehicle_id: r.vehicle_id.clone(),
+                        vehicle_id: Some(r.vehicle_id.clone()),
                         shift_index: r.shift_index,
+                        penalty: None,
                     })
                     .collect()
             }),
@@ -411,7 +444,14 @@ mod deserialize {
                     type_id: v.id.clone(),
                     vehicle_ids: (1..=v.amount).map(|seq| format!("{}_{}", v.id, seq)).collect(),
                     profile: v.profile.clone(),
-                    costs: VehicleCosts { fixed: v.costs.fixed, distance: v.costs.distance, time: v.costs.time },
+                    costs: VehicleCosts {
+                        fixed: v.costs.fixed,
+                        distance: v.costs.distance,
+                        time: v.costs.time,
+                        distance_tiers: None,
+                        max_daily_cost: None,
+                        load_distance: None,
+                    },
                     shifts: v
                         .shifts
                         .iter()
@@ -420,11 +460,14 @@ mod deserialize {
                                 earliest: shift.start.time.clone(),
                                 latest: None,
                                 location: to_pragmatic_loc(&shift.start.location),
+                                alternatives: None,
+                                dock_slot: None,
                             },
                             end: shift.end.as_ref().map(|end| ShiftEnd {
                                 earliest: None,
                                 latest: end.time.clone(),
                                 location: to_pragmatic_loc(&end.location),
+                                max_overtime: None,
                             }),
                             depots: shift.depots.as_ref().map(|depots| {
                                 depots
@@ -458,15 +501,29 @@ mod deserialize {
                                     })
                                     .collect()
                             }),
+                            anchors: None,
                         })
                         .collect(),
                     capacity: v.capacity.clone(),
+                    aggregate_capacity: None,
+                    consumption: None,
                     skills: v.skills.clone(),
                     limits: v.limits.as_ref().map(|l| VehicleLimits {
                         max_distance: l.max_distance,
                         shift_time: l.shift_time,
                         allowed_areas: None,
+                        max_time_window_jobs: None,
+                        max_reloads: None,
+                        max_hourly_time_window_jobs: None,
+                        max_late_stops: None,
+                        max_first_leg_distance: None,
                     }),
+                    range: None,
+                    crew_size: None,
+                    loading_discipline: None,
+                    reload_handling_time: None,
+                    maneuvering_time: None,
+                    capacity_decay: None,
                 })
                 .collect(),
             profiles: fleet
@@ -603,15 +660,21 @@ mod serialize {
             relations: plan.relations.as_ref().map(|relations| {
                 relations
                     .iter()
-                    .map(|relation| Relation {
-                        type_field: match relation.type_field {
+                    // NOTE soft precedence has no analogue in hre format
+                    .filter_map(|relation| {
+                        let type_field = match relation.type_field {
                             vrp_pragmatic::format::problem::RelationType::Strict => RelationType::Sequence,
                             vrp_pragmatic::format::problem::RelationType::Sequence => RelationType::Flexible,
                             vrp_pragmatic::format::problem::RelationType::Any => RelationType::Tour,
-                        },
-                        jobs: relation.jobs.clone(),
-                        vehicle_id: relation.vehicle_id.clone(),
-                        shift_index: None,
+                            vrp_pragmatic::format::problem::RelationType::SoftPrecedence => return None,
+                        };
+
+                        relation.vehicle_id.clone().map(|vehicle_id| Relation {
+                            type_field,
+                            jobs: relation.jobs.clone(),
+                            vehicle_id,
+                            shift_index: None,
+                        })
                     })
                     .collect()
             }),
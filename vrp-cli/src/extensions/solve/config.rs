@@ -8,7 +8,7 @@ mod config_test;
 
 extern crate serde_json;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
@@ -16,11 +16,12 @@ use vrp_core::models::common::SingleDimLoad;
 use vrp_core::models::Problem;
 use vrp_core::solver::mutation::*;
 use vrp_core::solver::selection::NaiveSelection;
-use vrp_core::solver::{Builder, Telemetry, TelemetryMode};
+use vrp_core::solver::{Builder, PhaseSwitch, Telemetry, TelemetryMode};
 use vrp_core::utils::get_cpus;
 
 /// An algorithm configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Config {
     /// Specifies population configuration.
     pub population: Option<PopulationConfig>,
@@ -32,10 +33,20 @@ pub struct Config {
     pub termination: Option<TerminationConfig>,
     /// Specifies telemetry configuration.
     pub telemetry: Option<TelemetryConfig>,
+    /// Specifies when the initial objective phase (tours minimization) is switched to the main one.
+    pub phase_switch: Option<PhaseSwitchConfig>,
+    /// Specifies randomization seed.
+    pub seed: Option<u64>,
+    /// Specifies a limit of total soft penalty (e.g. lateness): a solution above it sheds jobs.
+    pub penalty_limit: Option<f64>,
+    /// Specifies whether unassigned jobs of the best solution are inserted after evolution where it is feasible.
+    pub final_sweep: Option<bool>,
+    /// Specifies whether each mutation operator uses its own random stream derived from the seed.
+    pub operator_streams: Option<bool>,
 }
 
 /// A population configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct PopulationConfig {
     initial: Option<InitialConfig>,
@@ -43,7 +54,7 @@ pub struct PopulationConfig {
 }
 
 /// An initial solution configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct InitialConfig {
     pub size: Option<usize>,
@@ -51,7 +62,7 @@ pub struct InitialConfig {
 }
 
 /// A selection configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 pub struct SelectionConfig {
     /// A name of used selection from the collection.
     name: String,
@@ -60,11 +71,11 @@ pub struct SelectionConfig {
 }
 
 /// A selection operator configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 #[serde(tag = "type")]
 #[serde(rename_all = "camelCase")]
 pub enum SelectionType {
-    #[serde(rename(deserialize = "naive"))]
+    #[serde(rename = "naive")]
     Naive {
         /// A name of selection operator.
         name: String,
@@ -74,7 +85,7 @@ pub enum SelectionType {
 }
 
 /// A mutation configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 pub struct MutationConfig {
     /// A name of used mutation from the collection.
     name: String,
@@ -83,11 +94,11 @@ pub struct MutationConfig {
 }
 
 /// A mutation operator configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 #[serde(tag = "type")]
 pub enum MutationType {
     /// A naive branching metaheurstic settings.
-    #[serde(rename(deserialize = "naive-branching"))]
+    #[serde(rename = "naive-branching")]
     NaiveBranching {
         /// A name of metaheurisic instance.
         name: String,
@@ -103,7 +114,7 @@ pub enum MutationType {
 
     /// A metaheuristic which is composition of other metaheuristics with their
     /// probability weights.
-    #[serde(rename(deserialize = "weighted-composite"))]
+    #[serde(rename = "weighted-composite")]
     WeightedComposite {
         /// A name of metaheurisic instance.
         name: String,
@@ -112,7 +123,7 @@ pub enum MutationType {
     },
 
     /// A ruin and recreate metaheuristic settings.
-    #[serde(rename(deserialize = "ruin-recreate"))]
+    #[serde(rename = "ruin-recreate")]
     RuinRecreate {
         /// A name of metaheurisic instance.
         name: String,
@@ -124,98 +135,113 @@ pub enum MutationType {
 }
 
 /// A ruin method configuration
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 pub struct ConfigRuinGroup {
     methods: Vec<RuinMethod>,
     weight: usize,
 }
 
 /// Specifies ruin methods with their probability weight and specific parameters.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 #[serde(tag = "type")]
 pub enum RuinMethod {
     /// Adjusted string removal method.
-    #[serde(rename(deserialize = "adjusted-string"))]
+    #[serde(rename = "adjusted-string")]
     AdjustedString { probability: f64, lmax: usize, cavg: usize, alpha: f64 },
     /// Neighbour jobs method
-    #[serde(rename(deserialize = "neighbour"))]
+    #[serde(rename = "neighbour")]
     Neighbour { probability: f64, min: usize, max: usize, threshold: f64 },
     /// Random job removal method.
-    #[serde(rename(deserialize = "random-job"))]
+    #[serde(rename = "random-job")]
     RandomJob { probability: f64, min: usize, max: usize, threshold: f64 },
     /// Random route removal method.
-    #[serde(rename(deserialize = "random-route"))]
+    #[serde(rename = "random-route")]
     RandomRoute { probability: f64, min: usize, max: usize, threshold: f64 },
     /// Worst job removal method.
-    #[serde(rename(deserialize = "worst-job"))]
+    #[serde(rename = "worst-job")]
     WorstJob { probability: f64, min: usize, max: usize, threshold: f64, skip: usize },
+    /// Worst route removal method.
+    #[serde(rename = "worst-route")]
+    WorstRoute { probability: f64, neighbours: usize },
     /// Clustered jobs removal method.
-    #[serde(rename(deserialize = "cluster"))]
+    #[serde(rename = "cluster")]
     Cluster { probability: f64, min: usize, max: usize, threshold: f64, cmin: usize, cmax: usize },
 }
 
 /// Specifies recreate methods with their probability weight and specific parameters.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 #[serde(tag = "type")]
 pub enum RecreateMethod {
     /// Cheapest insertion method.
-    #[serde(rename(deserialize = "cheapest"))]
+    #[serde(rename = "cheapest")]
     Cheapest { weight: usize },
     /// Regret insertion method.
-    #[serde(rename(deserialize = "regret"))]
+    #[serde(rename = "regret")]
     Regret { weight: usize, start: usize, end: usize },
-    #[serde(rename(deserialize = "blinks"))]
+    #[serde(rename = "blinks")]
     /// Insertion with blinks method.
     Blinks { weight: usize },
-    #[serde(rename(deserialize = "gaps"))]
+    #[serde(rename = "gaps")]
     /// Insertion with gaps method.
     Gaps { weight: usize, min: usize },
     /// Nearest neighbour method.
-    #[serde(rename(deserialize = "nearest"))]
+    #[serde(rename = "nearest")]
     Nearest { weight: usize },
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TerminationConfig {
-    max_time: Option<usize>,
-    max_generations: Option<usize>,
-    variation: Option<VariationConfig>,
+    pub max_time: Option<usize>,
+    pub max_generations: Option<usize>,
+    pub variation: Option<VariationConfig>,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 pub struct VariationConfig {
-    sample: usize,
-    cv: f64,
+    pub sample: usize,
+    pub cv: f64,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+/// An objective phase switch configuration.
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(tag = "type")]
+pub enum PhaseSwitchConfig {
+    /// Switches at the given generation.
+    #[serde(rename = "generation")]
+    Generation { generation: usize },
+    /// Switches when the best known solution is not improved during given amount of generations.
+    #[serde(rename = "stagnation")]
+    Stagnation { generations: usize },
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug)]
 pub struct TelemetryConfig {
-    logging: Option<LoggingConfig>,
-    metrics: Option<MetricsConfig>,
+    pub logging: Option<LoggingConfig>,
+    pub metrics: Option<MetricsConfig>,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct LoggingConfig {
     /// Specifies whether logging is enabled. Default is false.
-    enabled: bool,
+    pub enabled: bool,
     /// Specifies how often best individual is logged. Default is 100 (generations).
-    log_best: Option<usize>,
+    pub log_best: Option<usize>,
     /// Specifies how often population is logged. Default is 1000 (generations).
-    log_population: Option<usize>,
+    pub log_population: Option<usize>,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct MetricsConfig {
     /// Specifies whether metrics collection is enabled. Default is false.
-    enabled: bool,
+    pub enabled: bool,
     /// Specifies how often population is tracked. Default is 1000 (generations).
-    track_population: Option<usize>,
+    pub track_population: Option<usize>,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 
 pub struct BranchingConfig {
     pub chance: BranchingChance,
@@ -223,20 +249,20 @@ pub struct BranchingConfig {
     pub generations: MinMaxConfig,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Deserialize, Serialize, Debug)]
 pub struct BranchingChance {
     pub normal: f64,
     pub intensive: f64,
     pub threshold: f64,
 }
 
-#[derive(Clone, Deserialize, Debug, Eq, PartialEq)]
+#[derive(Clone, Deserialize, Serialize, Debug, Eq, PartialEq)]
 pub struct MinMaxConfig {
     pub min: usize,
     pub max: usize,
 }
 
-#[derive(Clone, Deserialize, Debug, Eq, PartialEq)]
+#[derive(Clone, Deserialize, Serialize, Debug, Eq, PartialEq)]
 pub struct NameWeight {
     pub name: String,
     pub weight: usize,
@@ -244,7 +270,18 @@ pub struct NameWeight {
 
 impl Default for Config {
     fn default() -> Self {
-        Self { population: None, selection: None, mutation: None, termination: None, telemetry: None }
+        Self {
+            population: None,
+            selection: None,
+            mutation: None,
+            termination: None,
+            telemetry: None,
+            phase_switch: None,
+            seed: None,
+            penalty_limit: None,
+            final_sweep: None,
+            operator_streams: None,
+        }
     }
 }
 
@@ -363,6 +400,18 @@ fn configure_from_termination(
     Ok(builder)
 }
 
+fn configure_from_phase_switch(
+    builder: Builder,
+    phase_switch_config: &Option<PhaseSwitchConfig>,
+) -> Result<Builder, String> {
+    let phase_switch = phase_switch_config.as_ref().map(|config| match config {
+        PhaseSwitchConfig::Generation { generation } => PhaseSwitch::Generation(*generation),
+        PhaseSwitchConfig::Stagnation { generations } => PhaseSwitch::Stagnation(*generations),
+    });
+
+    Ok(builder.with_phase_switch(phase_switch))
+}
+
 fn create_recreate_method(method: &RecreateMethod) -> (Box<dyn Recreate + Send + Sync>, usize) {
     match method {
         RecreateMethod::Cheapest { weight } => (Box::new(RecreateWithCheapest::default()), *weight),
@@ -394,6 +443,9 @@ fn create_ruin_method(problem: &Arc<Problem>, method: &RuinMethod) -> (Arc<dyn R
         RuinMethod::WorstJob { probability, min, max, threshold, skip: worst_skip } => {
             (Arc::new(WorstJobRemoval::new(*worst_skip, JobRemovalLimit::new(*min, *max, *threshold))), *probability)
         }
+        RuinMethod::WorstRoute { probability, neighbours } => {
+            (Arc::new(WorstRouteRemoval::new(*neighbours)), *probability)
+        }
         RuinMethod::Cluster { probability, min, max, threshold, cmin, cmax } => (
             Arc::new(ClusterRemoval::new(problem.clone(), *cmin..*cmax, JobRemovalLimit::new(*min, *max, *threshold))),
             *probability,
@@ -465,6 +517,11 @@ pub fn create_builder_from_config(problem: Arc<Problem>, config: &Config) -> Res
     builder = configure_from_selection(builder, &config.selection)?;
     builder = configure_from_mutation(builder, &config.mutation)?;
     builder = configure_from_termination(builder, &config.termination)?;
+    builder = configure_from_phase_switch(builder, &config.phase_switch)?;
+    builder = builder.with_seed(config.seed);
+    builder = builder.with_penalty_limit(config.penalty_limit);
+    builder = builder.with_final_sweep(config.final_sweep.unwrap_or(false));
+    builder = builder.with_operator_streams(config.operator_streams.unwrap_or(false));
 
     Ok(builder)
 }
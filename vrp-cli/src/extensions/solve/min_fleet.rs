@@ -0,0 +1,72 @@
+//! Minimum fleet size search.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/min_fleet_test.rs"]
+mod min_fleet_test;
+
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::Solution;
+use vrp_pragmatic::format::problem::Problem as ApiProblem;
+
+/// Finds the minimum amount of vehicles of given type needed to serve all jobs. The problem is
+/// solved repeatedly using given function with decreasing amount of vehicle ids of the type until
+/// some jobs are left unassigned. Returns `None` when all jobs cannot be served even with the whole
+/// fleet.
+///
+/// NOTE the result is only as good as the solver is: a smaller fleet might be still feasible.
+pub fn find_min_fleet_size<F>(problem: &ApiProblem, type_id: &str, solve: F) -> Result<Option<usize>, String>
+where
+    F: Fn(ApiProblem) -> Result<Solution, String>,
+{
+    let fleet_size = problem
+        .fleet
+        .vehicles
+        .iter()
+        .find(|vehicle| vehicle.type_id == type_id)
+        .map(|vehicle| vehicle.vehicle_ids.len())
+        .ok_or_else(|| format!("cannot find vehicle type '{}'", type_id))?;
+
+    let solution = solve(problem.clone())?;
+    if !solution.unassigned.is_empty() {
+        return Ok(None);
+    }
+
+    let mut min_size = get_used_vehicles(&solution, type_id).min(fleet_size);
+    let has_other_types = problem.fleet.vehicles.iter().any(|vehicle| vehicle.type_id != type_id);
+
+    while min_size > 0 && (min_size > 1 || has_other_types) {
+        let solution = solve(with_fleet_size(problem, type_id, min_size - 1))?;
+
+        if !solution.unassigned.is_empty() {
+            break;
+        }
+
+        min_size = get_used_vehicles(&solution, type_id).min(min_size - 1);
+    }
+
+    Ok(Some(min_size))
+}
+
+/// Returns a copy of the problem where vehicle type has only given amount of first vehicle ids.
+fn with_fleet_size(problem: &ApiProblem, type_id: &str, size: usize) -> ApiProblem {
+    let mut problem = problem.clone();
+
+    problem.fleet.vehicles.iter_mut().filter(|vehicle| vehicle.type_id == type_id).for_each(|vehicle| {
+        vehicle.vehicle_ids.truncate(size);
+    });
+    problem.fleet.vehicles.retain(|vehicle| !vehicle.vehicle_ids.is_empty());
+
+    problem
+}
+
+/// Returns amount of vehicles of given type which serve at least one job.
+fn get_used_vehicles(solution: &Solution, type_id: &str) -> usize {
+    solution
+        .routes
+        .iter()
+        .filter(|route| route.tour.has_jobs())
+        .filter(|route| {
+            route.actor.vehicle.dimens.get_value::<String>("type_id").map_or(false, |id| id.as_str() == type_id)
+        })
+        .count()
+}
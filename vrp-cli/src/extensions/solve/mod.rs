@@ -1,3 +1,6 @@
 //! Solve command helpers
 
 pub mod config;
+pub mod min_fleet;
+pub mod service_level;
+pub mod trajectory;
@@ -0,0 +1,108 @@
+//! Service level evaluation and fleet scaling suggestion.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/service_level_test.rs"]
+mod service_level_test;
+
+use std::collections::HashSet;
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::Solution;
+use vrp_pragmatic::format::problem::Problem as ApiProblem;
+
+/// Specifies amount of vehicles to add to the fleet in order to reach service level target.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FleetScaling {
+    /// Vehicle type id.
+    pub type_id: String,
+    /// Amount of vehicles to add.
+    pub extra_vehicles: usize,
+    /// Service level reached with added vehicles.
+    pub service_level: f64,
+}
+
+/// Specifies service level of the solution and fleet scaling needed to reach the target.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceLevelReport {
+    /// A share of served jobs.
+    pub service_level: f64,
+    /// Fleet scaling suggestion. It is `None` when the target is already reached or when it cannot
+    /// be reached by adding vehicles of one type.
+    pub scaling: Option<FleetScaling>,
+}
+
+/// Evaluates service level, a share of served jobs, of the problem solved by given function. When
+/// it is below the target, suggests the minimal amount of vehicles of one type to add: for each
+/// vehicle type, the problem is solved repeatedly with increasing amount of extra vehicle ids until
+/// the target is reached. As each useful vehicle serves at least one job, no more vehicles are added
+/// than jobs left unassigned.
+///
+/// NOTE the result is only as good as the solver is: a smaller addition might be still enough.
+pub fn evaluate_service_level<F>(problem: &ApiProblem, target: f64, solve: F) -> Result<ServiceLevelReport, String>
+where
+    F: Fn(ApiProblem) -> Result<Solution, String>,
+{
+    if !(0. ..=1.).contains(&target) {
+        return Err(format!("service level target should be in range [0, 1], got: {}", target));
+    }
+
+    let solution = solve(problem.clone())?;
+    let service_level = get_service_level(problem, &solution);
+
+    if service_level >= target {
+        return Ok(ServiceLevelReport { service_level, scaling: None });
+    }
+
+    let mut max_extra = get_unassigned_jobs(&solution);
+    let mut scaling = None;
+
+    for vehicle in problem.fleet.vehicles.iter() {
+        for extra_vehicles in 1..=max_extra {
+            let problem = with_extra_vehicles(problem, vehicle.type_id.as_str(), extra_vehicles);
+            let service_level = get_service_level(&problem, &solve(problem.clone())?);
+
+            if service_level >= target {
+                scaling = Some(FleetScaling { type_id: vehicle.type_id.clone(), extra_vehicles, service_level });
+                // NOTE other types are interesting only if they need less vehicles
+                max_extra = extra_vehicles - 1;
+                break;
+            }
+        }
+    }
+
+    Ok(ServiceLevelReport { service_level, scaling })
+}
+
+/// Returns a share of jobs served in the solution.
+fn get_service_level(problem: &ApiProblem, solution: &Solution) -> f64 {
+    let total = problem.plan.jobs.len();
+
+    if total == 0 {
+        1.
+    } else {
+        (total - get_unassigned_jobs(solution)) as f64 / total as f64
+    }
+}
+
+/// Returns amount of unassigned plan jobs: job variants share the same id, breaks and reloads are
+/// not counted.
+fn get_unassigned_jobs(solution: &Solution) -> usize {
+    solution
+        .unassigned
+        .iter()
+        .map(|(job, _)| job)
+        .filter(|job| job.dimens().get_value::<String>("vehicle_id").is_none())
+        .filter_map(|job| job.dimens().get_id())
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Returns a copy of the problem where vehicle type has given amount of extra vehicle ids.
+fn with_extra_vehicles(problem: &ApiProblem, type_id: &str, extra: usize) -> ApiProblem {
+    let mut problem = problem.clone();
+
+    problem.fleet.vehicles.iter_mut().filter(|vehicle| vehicle.type_id == type_id).for_each(|vehicle| {
+        vehicle.vehicle_ids.extend((1..=extra).map(|idx| format!("{}_extra_{}", type_id, idx)));
+    });
+
+    problem
+}
@@ -0,0 +1,33 @@
+//! Objective value trajectory output.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/trajectory_test.rs"]
+mod trajectory_test;
+
+extern crate serde_json;
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufWriter, Write};
+use vrp_core::solver::Metrics;
+
+/// Represents population cost at particular generation.
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct TrajectoryPoint {
+    /// Generation sequence number.
+    pub generation: usize,
+    /// Cost of the best individual.
+    pub best: f64,
+    /// Mean cost of all individuals.
+    pub mean: f64,
+}
+
+/// Writes objective value trajectory collected by telemetry metrics as json array.
+pub fn write_trajectory<W: Write>(metrics: &Metrics, writer: BufWriter<W>) -> Result<(), String> {
+    let trajectory = metrics
+        .trajectory
+        .iter()
+        .map(|point| TrajectoryPoint { generation: point.generation, best: point.best, mean: point.mean })
+        .collect::<Vec<_>>();
+
+    serde_json::to_writer_pretty(writer, &trajectory).map_err(|err| format!("cannot write trajectory: '{}'", err))
+}
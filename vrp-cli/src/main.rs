@@ -13,6 +13,7 @@ mod cli {
     use super::commands::import::{get_import_app, run_import};
     use super::commands::solve::{get_solve_app, run_solve};
     use crate::commands::check::{get_check_app, run_check};
+    use crate::commands::compare::{get_compare_app, run_compare};
     use crate::commands::generate::{get_generate_app, run_generate};
     use clap::{crate_version, App};
     use std::process;
@@ -25,6 +26,7 @@ mod cli {
             .subcommand(get_solve_app())
             .subcommand(get_import_app())
             .subcommand(get_check_app())
+            .subcommand(get_compare_app())
             .subcommand(get_generate_app())
             .get_matches();
 
@@ -32,6 +34,7 @@ mod cli {
             ("solve", Some(solve_matches)) => run_solve(solve_matches),
             ("import", Some(import_matches)) => run_import(import_matches),
             ("check", Some(check_matches)) => run_check(check_matches),
+            ("compare", Some(compare_matches)) => run_compare(compare_matches),
             ("generate", Some(generate_matches)) => run_generate(generate_matches),
             ("", None) => {
                 eprintln!("No subcommand was used. Use -h to print help information.");
@@ -10,15 +10,33 @@ pub fn create_empty_job() -> Job {
         services: None,
         priority: None,
         skills: None,
+        hard_time_window: None,
+        companions: None,
+        transfer_from: None,
+        service_time_distribution: None,
+        corridor: None,
+        crew_size: None,
+        workload: None,
+        vehicle_affinity: None,
+        age: None,
     }
 }
 
 pub fn create_empty_job_task() -> JobTask {
-    JobTask { places: vec![], demand: None, tag: None }
+    JobTask { places: vec![], demand: None, tag: None, type_durations: None, variants: None }
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        relative_times: None,
+        opening_times: None,
+        grace: None,
+        time_penalties: None,
+        skill_times: None,
+    }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -26,21 +44,39 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: "car".to_string(),
-        costs: VehicleCosts { fixed: None, distance: 0.0, time: 0.0 },
+        costs: VehicleCosts {
+            fixed: None,
+            distance: 0.0,
+            time: 0.0,
+            distance_tiers: None,
+            max_daily_cost: None,
+            load_distance: None,
+        },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
                 latest: None,
                 location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                alternatives: None,
+                dock_slot: None,
             },
             end: None,
             depots: None,
             breaks: None,
             reloads: None,
+            anchors: None,
         }],
         capacity: vec![10],
         skills: None,
         limits: None,
+        range: None,
+        aggregate_capacity: None,
+        consumption: None,
+        crew_size: None,
+        loading_discipline: None,
+        reload_handling_time: None,
+        maneuvering_time: None,
+        capacity_decay: None,
     }
 }
 
@@ -0,0 +1,39 @@
+use super::*;
+use vrp_pragmatic::format::solution::ObjectiveTermExplanation;
+
+fn create_term(level: ObjectiveLevel, name: &str, values: (f64, f64), order: Ordering) -> ObjectiveTermExplanation {
+    ObjectiveTermExplanation { level, name: name.to_string(), values, order }
+}
+
+#[test]
+fn can_format_comparison() {
+    let comparison = SolutionComparison {
+        order: Ordering::Less,
+        level: Some(ObjectiveLevel::Primary),
+        terms: vec![
+            create_term(ObjectiveLevel::Primary, "minimize-tours", (1., 2.), Ordering::Less),
+            create_term(ObjectiveLevel::Secondary, "minimize-cost", (46., 28.), Ordering::Greater),
+        ],
+    };
+
+    let result = format_comparison(&comparison);
+
+    let lines = result.lines().map(|line| line.split_whitespace().collect::<Vec<_>>()).collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[1], vec!["minimize-tours", "primary", "1.000", "2.000", "first"]);
+    assert_eq!(lines[2], vec!["minimize-cost", "secondary", "46.000", "28.000", "second"]);
+    assert_eq!(result.lines().last(), Some("winner: first, decided by primary objectives"));
+}
+
+#[test]
+fn can_format_comparison_of_equal_solutions() {
+    let comparison = SolutionComparison {
+        order: Ordering::Equal,
+        level: None,
+        terms: vec![create_term(ObjectiveLevel::Primary, "minimize-tours", (1., 1.), Ordering::Equal)],
+    };
+
+    let result = format_comparison(&comparison);
+
+    assert_eq!(result.lines().last(), Some("winner: tie, solutions are equal by all objectives"));
+}
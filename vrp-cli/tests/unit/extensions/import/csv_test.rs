@@ -1,6 +1,11 @@
 use super::*;
 use crate::extensions::import::import_problem;
-use std::io::BufReader;
+use crate::extensions::solve::config::*;
+use std::io::{BufReader, BufWriter};
+use std::sync::Arc;
+use vrp_core::models::common::IdDimension;
+use vrp_core::models::Problem as CoreProblem;
+use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem};
 
 #[test]
 fn can_read_csv_problem() {
@@ -73,3 +78,49 @@ job1,52.5225,13.4095,1,3,,
 
     assert_eq!(result, "csv format expects two files with jobs and vehicles as an input");
 }
+
+#[test]
+fn can_solve_csv_problem_equivalently_to_pragmatic_json() {
+    let jobs_csv = r"
+ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END
+job1,52.52599,13.45413,-2,5,2020-07-04T08:00:00Z,2020-07-04T12:00:00Z
+job2,52.5225,13.4095,1,3,,
+job2,52.5165,13.3808,-1,3,,
+job3,52.5316,13.3884,-3,5,2020-07-04T08:00:00Z,2020-07-04T16:00:00Z
+";
+    let vehicles_csv = r"
+ID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE
+vehicle1,52.4664,13.4023,4,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,3,car
+";
+    let problem = read_csv_problem(BufReader::new(jobs_csv.as_bytes()), BufReader::new(vehicles_csv.as_bytes()))
+        .expect("cannot read csv");
+    let mut buffer = String::new();
+    serialize_problem(unsafe { BufWriter::new(buffer.as_mut_vec()) }, &problem).expect("cannot serialize problem");
+    let config = Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(100), variation: None }),
+        seed: Some(1),
+        ..Config::default()
+    };
+    let solve = |problem: CoreProblem| {
+        let (solution, cost, _) =
+            create_builder_from_config(Arc::new(problem), &config).unwrap().build().unwrap().solve().unwrap();
+        // NOTE order of activities is ignored as solutions with the same cost might be found
+        let mut routes = solution
+            .routes
+            .iter()
+            .map(|route| {
+                let mut ids = route.tour.jobs().filter_map(|job| job.dimens().get_id().cloned()).collect::<Vec<_>>();
+                ids.sort();
+                ids
+            })
+            .collect::<Vec<_>>();
+        routes.sort();
+        (cost, routes, solution.unassigned.len())
+    };
+
+    let csv_result = solve(problem.read_pragmatic().expect("cannot read csv problem"));
+    let json_result = solve(buffer.read_pragmatic().expect("cannot read json problem"));
+
+    assert_eq!(csv_result, json_result);
+    assert_eq!(csv_result.2, 0);
+}
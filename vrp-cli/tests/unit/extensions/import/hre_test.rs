@@ -297,20 +297,23 @@ fn can_write_hre_problem() {
                 Relation {
                     type_field: RelationType::Any,
                     jobs: vec!["job1".to_string()],
-                    vehicle_id: "vehicle_1".to_string(),
+                    vehicle_id: Some("vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
                 Relation {
                     type_field: RelationType::Sequence,
                     jobs: vec!["job1".to_string()],
-                    vehicle_id: "vehicle_1".to_string(),
+                    vehicle_id: Some("vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: vec!["job1".to_string()],
-                    vehicle_id: "vehicle_1".to_string(),
+                    vehicle_id: Some("vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
             ]),
         },
@@ -319,17 +322,27 @@ fn can_write_hre_problem() {
                 type_id: "vehicle".to_string(),
                 vehicle_ids: vec!["vehicle_1".to_string()],
                 profile: "car".to_string(),
-                costs: VehicleCosts { fixed: None, distance: 0.0, time: 0.0 },
+                costs: VehicleCosts {
+                    fixed: None,
+                    distance: 0.0,
+                    time: 0.0,
+                    distance_tiers: None,
+                    max_daily_cost: None,
+                    load_distance: None,
+                },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "2020-05-01T09:00:00.00Z".to_string(),
                         latest: None,
                         location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                        alternatives: None,
+                        dock_slot: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "2020-05-01T18:00:00.00Z".to_string(),
                         location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                        max_overtime: None,
                     }),
                     depots: Some(vec![VehicleCargoPlace {
                         location: Location::Coordinate { lat: 0.0, lng: 0.0 },
@@ -346,6 +359,7 @@ fn can_write_hre_problem() {
                         locations: None,
                     }]),
                     reloads: None,
+                    anchors: None,
                 }],
                 capacity: vec![10],
                 skills: Some(vec!["skill1".to_string()]),
@@ -353,7 +367,20 @@ fn can_write_hre_problem() {
                     max_distance: Some(10000.),
                     shift_time: Some(14400.),
                     allowed_areas: None,
+                    max_time_window_jobs: None,
+                    max_reloads: None,
+                    max_hourly_time_window_jobs: None,
+                    max_late_stops: None,
+                    max_first_leg_distance: None,
                 }),
+                range: None,
+                aggregate_capacity: None,
+                consumption: None,
+                crew_size: None,
+                loading_discipline: None,
+                reload_handling_time: None,
+                maneuvering_time: None,
+                capacity_decay: None,
             }],
             profiles: vec![Profile { name: "normal_car".to_string(), profile_type: "car".to_string(), speed: None }],
         },
@@ -32,6 +32,11 @@ fn can_read_config() {
     assert_eq!(termination.max_time, Some(300));
     assert_eq!(termination.max_generations, Some(3000));
 
+    match config.phase_switch.expect("no phase switch config") {
+        PhaseSwitchConfig::Stagnation { generations } => assert_eq!(generations, 500),
+        _ => unreachable!(),
+    }
+
     let mutation_config = config.mutation.expect("cannot get mutation");
     assert_eq!(mutation_config.name, "default-branching");
     assert_eq!(mutation_config.collection.len(), 3);
@@ -53,6 +58,7 @@ fn can_create_builder_from_config() {
     assert_eq!(builder.config.population.initial.methods.len(), 1);
     assert_eq!(builder.max_time, Some(300));
     assert_eq!(builder.max_generations, Some(3000));
+    assert!(builder.config.phase_switch.is_some());
 }
 
 #[test]
@@ -64,4 +70,90 @@ fn can_create_default_config() {
     assert!(config.mutation.is_none());
     assert!(config.termination.is_none());
     assert!(config.telemetry.is_none());
+    assert!(config.phase_switch.is_none());
+    assert!(config.seed.is_none());
+    assert!(config.penalty_limit.is_none());
+    assert!(config.final_sweep.is_none());
+    assert!(config.operator_streams.is_none());
+}
+
+#[test]
+fn can_write_and_read_config() {
+    let file = File::open("../examples/data/config/config.full.json").expect("cannot read config from file");
+    let mut config = read_config(BufReader::new(file)).unwrap();
+    config.seed = Some(42);
+
+    let serialized = serde_json::to_string(&config).unwrap();
+    let config = read_config(BufReader::new(serialized.as_bytes())).unwrap();
+
+    assert_eq!(config.seed, Some(42));
+    assert_eq!(config.population.and_then(|population| population.max_size), Some(4));
+    assert_eq!(config.termination.and_then(|termination| termination.max_generations), Some(3000));
+    assert_eq!(config.mutation.expect("no mutation config").collection.len(), 3);
+    assert!(matches!(config.phase_switch, Some(PhaseSwitchConfig::Stagnation { generations: 500 })));
+}
+
+#[test]
+fn can_reproduce_solution_with_seed_from_serialized_config() {
+    let config = Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(100), variation: None }),
+        seed: Some(1),
+        ..Config::default()
+    };
+    let serialized = serde_json::to_string(&config).unwrap();
+    let problem = create_example_problem();
+    let solve = |config: &Config| {
+        let (solution, cost, _) =
+            create_builder_from_config(problem.clone(), config).unwrap().build().unwrap().solve().unwrap();
+        let routes = solution
+            .routes
+            .iter()
+            .map(|route| route.tour.all_activities().map(|activity| activity.place.location).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        (cost, routes)
+    };
+
+    let expected = solve(&config);
+    let actual = solve(&read_config(BufReader::new(serialized.as_bytes())).unwrap());
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_configure_penalty_limit() {
+    let config = Config { penalty_limit: Some(100.), ..Config::default() };
+
+    let builder = create_builder_from_config(create_example_problem(), &config).unwrap();
+
+    assert_eq!(builder.config.penalty_limit, Some(100.));
+}
+
+#[test]
+fn can_configure_final_sweep() {
+    let config = Config { final_sweep: Some(true), ..Config::default() };
+
+    let builder = create_builder_from_config(create_example_problem(), &config).unwrap();
+
+    assert!(builder.config.final_sweep);
+}
+
+#[test]
+fn can_configure_operator_streams() {
+    let config = Config { seed: Some(1), operator_streams: Some(true), ..Config::default() };
+
+    let builder = create_builder_from_config(create_example_problem(), &config).unwrap();
+
+    assert!(builder.operator_streams);
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn cannot_build_solver_with_operator_streams_and_custom_mutation() {
+    let file = File::open("../examples/data/config/config.full.json").expect("cannot read config from file");
+    let config = read_config(BufReader::new(file)).unwrap();
+    let config = Config { seed: Some(1), operator_streams: Some(true), ..config };
+
+    let result = create_builder_from_config(create_example_problem(), &config).unwrap().build();
+
+    assert_eq!(result.err(), Some("operator streams cannot be used together with custom mutation".to_string()));
 }
@@ -0,0 +1,79 @@
+use super::*;
+use crate::helpers::generate::*;
+use std::sync::Arc;
+use vrp_core::solver::Builder;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::Location;
+
+fn create_test_problem(jobs: usize, vehicles: usize) -> ApiProblem {
+    ApiProblem {
+        plan: Plan {
+            jobs: (1..=jobs)
+                .map(|idx| Job {
+                    id: format!("job{}", idx),
+                    deliveries: Some(vec![JobTask {
+                        places: vec![JobPlace {
+                            location: Location::Coordinate { lat: 0., lng: 0.001 * idx as f64 },
+                            duration: 10.,
+                            ..create_empty_job_place()
+                        }],
+                        demand: Some(vec![1]),
+                        ..create_empty_job_task()
+                    }]),
+                    ..create_empty_job()
+                })
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: (1..=vehicles).map(|idx| format!("vehicle_{}", idx)).collect(),
+                capacity: vec![2],
+                ..create_test_vehicle_type()
+            }],
+            profiles: vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }],
+        },
+        objectives: None,
+        config: None,
+    }
+}
+
+fn solve_problem(problem: ApiProblem) -> Result<Solution, String> {
+    let problem = Arc::new(problem.read_pragmatic().map_err(|errors| format!("{:?}", errors))?);
+
+    Builder::new(problem)
+        .with_max_generations(Some(100))
+        .build()
+        .and_then(|solver| solver.solve())
+        .map(|(solution, _, _)| solution)
+}
+
+parameterized_test! {can_find_min_fleet_size, (jobs, vehicles, expected), {
+    can_find_min_fleet_size_impl(jobs, vehicles, expected);
+}}
+
+can_find_min_fleet_size! {
+    case01_exact_fleet: (4, 2, Some(2)),
+    case02_bigger_fleet: (5, 5, Some(3)),
+    case03_small_fleet: (5, 2, None),
+}
+
+fn can_find_min_fleet_size_impl(jobs: usize, vehicles: usize, expected: Option<usize>) {
+    let problem = create_test_problem(jobs, vehicles);
+
+    let result = find_min_fleet_size(&problem, "vehicle", solve_problem).expect("cannot find min fleet size");
+
+    assert_eq!(result, expected);
+    if let Some(min_size) = result {
+        let solve_with_size = |size| solve_problem(with_fleet_size(&problem, "vehicle", size)).unwrap();
+        assert!(solve_with_size(min_size).unassigned.is_empty());
+        assert!(!solve_with_size(min_size - 1).unassigned.is_empty());
+    }
+}
+
+#[test]
+fn can_detect_unknown_vehicle_type() {
+    let result = find_min_fleet_size(&create_test_problem(1, 1), "unknown", solve_problem);
+
+    assert_eq!(result, Err("cannot find vehicle type 'unknown'".to_string()));
+}
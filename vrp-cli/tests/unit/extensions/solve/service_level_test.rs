@@ -0,0 +1,86 @@
+use super::*;
+use crate::helpers::generate::*;
+use std::sync::Arc;
+use vrp_core::solver::Builder;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::Location;
+
+fn create_test_problem(jobs: usize, vehicles: usize) -> ApiProblem {
+    ApiProblem {
+        plan: Plan {
+            jobs: (1..=jobs)
+                .map(|idx| Job {
+                    id: format!("job{}", idx),
+                    deliveries: Some(vec![JobTask {
+                        places: vec![JobPlace {
+                            location: Location::Coordinate { lat: 0., lng: 0.001 * idx as f64 },
+                            duration: 10.,
+                            ..create_empty_job_place()
+                        }],
+                        demand: Some(vec![1]),
+                        ..create_empty_job_task()
+                    }]),
+                    ..create_empty_job()
+                })
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: (1..=vehicles).map(|idx| format!("vehicle_{}", idx)).collect(),
+                capacity: vec![3],
+                ..create_test_vehicle_type()
+            }],
+            profiles: vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }],
+        },
+        objectives: None,
+        config: None,
+    }
+}
+
+fn solve_problem(problem: ApiProblem) -> Result<Solution, String> {
+    let problem = Arc::new(problem.read_pragmatic().map_err(|errors| format!("{:?}", errors))?);
+
+    Builder::new(problem)
+        .with_max_generations(Some(100))
+        .build()
+        .and_then(|solver| solver.solve())
+        .map(|(solution, _, _)| solution)
+}
+
+parameterized_test! {can_evaluate_service_level, (jobs, vehicles, target, expected), {
+    can_evaluate_service_level_impl(jobs, vehicles, target, expected);
+}}
+
+can_evaluate_service_level! {
+    case01_target_reached: (10, 3, 0.9, (0.9, None)),
+    case02_one_extra_vehicle: (10, 3, 0.95, (0.9, Some((1, 1.)))),
+    case03_two_extra_vehicles: (10, 2, 0.95, (0.6, Some((2, 1.)))),
+    case04_all_served: (6, 2, 1., (1., None)),
+}
+
+fn can_evaluate_service_level_impl(jobs: usize, vehicles: usize, target: f64, expected: (f64, Option<(usize, f64)>)) {
+    let problem = create_test_problem(jobs, vehicles);
+
+    let report = evaluate_service_level(&problem, target, solve_problem).expect("cannot evaluate service level");
+
+    assert_eq!(report.service_level, expected.0);
+    assert_eq!(
+        report.scaling,
+        expected.1.map(|(extra_vehicles, service_level)| FleetScaling {
+            type_id: "vehicle".to_string(),
+            extra_vehicles,
+            service_level
+        })
+    );
+    if let Some(scaling) = report.scaling {
+        assert!(scaling.service_level >= target);
+    }
+}
+
+#[test]
+fn can_detect_invalid_target() {
+    let result = evaluate_service_level(&create_test_problem(1, 1), 1.5, solve_problem);
+
+    assert_eq!(result, Err("service level target should be in range [0, 1], got: 1.5".to_string()));
+}
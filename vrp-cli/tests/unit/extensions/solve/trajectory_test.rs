@@ -0,0 +1,21 @@
+use super::*;
+use vrp_core::models::examples::create_example_problem;
+use vrp_core::solver::{Builder, Telemetry, TelemetryMode};
+
+#[test]
+fn can_write_trajectory() {
+    let (_, cost, metrics) = Builder::new(create_example_problem())
+        .with_max_generations(Some(100))
+        .with_telemetry(Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 1000 }))
+        .build()
+        .and_then(|solver| solver.solve())
+        .unwrap();
+    let mut buffer = vec![];
+
+    write_trajectory(&metrics.expect("no metrics"), BufWriter::new(&mut buffer)).unwrap();
+
+    let trajectory: Vec<TrajectoryPoint> = serde_json::from_slice(buffer.as_slice()).unwrap();
+    assert!(!trajectory.is_empty());
+    assert!(trajectory.windows(2).all(|points| points[0].best >= points[1].best));
+    assert!((trajectory.last().unwrap().best - cost).abs() < 1E-6);
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/cost_cap_test.rs"]
+mod cost_cap_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::{ActivityCost, Actor, Job, TransportCost};
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns a maximum usage cost of the route served by given actor, if any.
+pub type CostCapFunc = Arc<dyn Fn(&Actor) -> Option<Cost> + Send + Sync>;
+
+/// A module which caps usage cost of the route, e.g. a daily charge of contracted vehicle: fixed
+/// and variable costs above the cap are not paid. It should be added after the transport and
+/// distance tiers modules as it relies on route usage cost.
+pub struct CostCapModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    cost_cap: CostCapFunc,
+}
+
+impl CostCapModule {
+    /// Creates a new instance of `CostCapModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        cost_cap: CostCapFunc,
+    ) -> Self {
+        Self {
+            state_keys: vec![COST_CAP_DISCOUNT_KEY],
+            constraints: vec![
+                ConstraintVariant::SoftRoute(Arc::new(CostCapSoftRouteConstraint { cost_cap: cost_cap.clone() })),
+                ConstraintVariant::SoftActivity(Arc::new(CostCapSoftActivityConstraint {
+                    activity,
+                    transport,
+                    cost_cap: cost_cap.clone(),
+                })),
+            ],
+            cost_cap,
+        }
+    }
+}
+
+impl ConstraintModule for CostCapModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let discount =
+            (self.cost_cap)(ctx.route.actor.as_ref()).map_or(0., |cap| (ctx.get_route_usage_cost() - cap).max(0.));
+
+        ctx.state_mut().put_route_state(COST_CAP_DISCOUNT_KEY, discount);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Discounts fixed cost of a new route when it alone exceeds the cap.
+struct CostCapSoftRouteConstraint {
+    cost_cap: CostCapFunc,
+}
+
+impl SoftRouteConstraint for CostCapSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, _job: &Job) -> Cost {
+        let actor = ctx.route.actor.as_ref();
+
+        match (self.cost_cap)(actor) {
+            Some(cap) if ctx.route.tour.job_count() == 0 => (cap - get_fixed_cost(actor)).min(0.),
+            _ => 0.,
+        }
+    }
+}
+
+/// Discounts the part of insertion cost which is above the cap.
+struct CostCapSoftActivityConstraint {
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    cost_cap: CostCapFunc,
+}
+
+impl SoftActivityConstraint for CostCapSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let actor = route_ctx.route.actor.as_ref();
+        let cap = if let Some(cap) = (self.cost_cap)(actor) {
+            cap
+        } else {
+            return 0.;
+        };
+
+        let usage =
+            if route_ctx.route.tour.has_jobs() { route_ctx.get_route_usage_cost() } else { get_fixed_cost(actor) };
+        let delta = estimate_activity_cost(self.activity.as_ref(), self.transport.as_ref(), route_ctx, activity_ctx);
+
+        (usage + delta).min(cap) - usage.min(cap) - delta
+    }
+}
+
+fn get_fixed_cost(actor: &Actor) -> Cost {
+    actor.driver.costs.fixed + actor.vehicle.costs.fixed
+}
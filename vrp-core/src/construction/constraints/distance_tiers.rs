@@ -0,0 +1,93 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/distance_tiers_test.rs"]
+mod distance_tiers_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::{Cost, Distance, Location};
+use crate::models::problem::{Actor, Job, TransportCost};
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns an extra cost of the route with given total distance driven by
+/// given actor. This cost is added on top of flat per distance cost of the actor.
+pub type DistanceCostFunc = Arc<dyn Fn(&Actor, Distance) -> Cost + Send + Sync>;
+
+/// A module which applies non linear, e.g. tiered, distance cost to the routes. It should be added
+/// after the transport module as it relies on total route distance.
+pub struct DistanceTiersModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    cost_func: DistanceCostFunc,
+}
+
+impl DistanceTiersModule {
+    /// Creates a new instance of `DistanceTiersModule`.
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>, cost_func: DistanceCostFunc) -> Self {
+        Self {
+            state_keys: vec![DISTANCE_TIERS_COST_KEY],
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(DistanceTiersSoftActivityConstraint {
+                transport,
+                cost_func: cost_func.clone(),
+            }))],
+            cost_func,
+        }
+    }
+}
+
+impl ConstraintModule for DistanceTiersModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let distance = get_total_distance(ctx);
+        let cost = (self.cost_func)(ctx.route.actor.as_ref(), distance);
+
+        ctx.state_mut().put_route_state(DISTANCE_TIERS_COST_KEY, cost);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct DistanceTiersSoftActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    cost_func: DistanceCostFunc,
+}
+
+impl SoftActivityConstraint for DistanceTiersSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let actor = route_ctx.route.actor.as_ref();
+        let profile = actor.vehicle.profile;
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+        let departure = prev.schedule.departure;
+
+        let get_distance = |from: Location, to: Location| self.transport.distance(profile, from, to, departure);
+
+        let delta = get_distance(prev.place.location, target.place.location)
+            + activity_ctx.next.map_or(0., |next| {
+                get_distance(target.place.location, next.place.location)
+                    - get_distance(prev.place.location, next.place.location)
+            });
+
+        let distance = get_total_distance(route_ctx);
+
+        (self.cost_func)(actor, distance + delta) - (self.cost_func)(actor, distance)
+    }
+}
+
+fn get_total_distance(route_ctx: &RouteContext) -> Distance {
+    route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.)
+}
@@ -38,6 +38,18 @@ impl FleetUsageConstraintModule {
         Self::new_with_cost(-1E12)
     }
 
+    /// Creates `FleetUsageConstraintModule` to keep used fleet size close to the target: a new
+    /// route is preferred while there are less routes than the target and penalized otherwise.
+    pub fn new_targeted(target: usize) -> Self {
+        Self {
+            state_keys: vec![],
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(TargetFleetCostSoftRouteConstraint {
+                target,
+                extra_cost: 1E12,
+            }))],
+        }
+    }
+
     /// Creates `FleetUsageConstraintModule` with custom extra cost.
     pub fn new_with_cost(extra_cost: Cost) -> Self {
         Self {
@@ -60,3 +72,24 @@ impl SoftRouteConstraint for FleetCostSoftRouteConstraint {
         }
     }
 }
+
+struct TargetFleetCostSoftRouteConstraint {
+    target: usize,
+    extra_cost: Cost,
+}
+
+impl SoftRouteConstraint for TargetFleetCostSoftRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, ctx: &RouteContext, _job: &Job) -> Cost {
+        if ctx.route.tour.job_count() == 0 {
+            let used = solution_ctx.routes.iter().filter(|route_ctx| route_ctx.route.tour.has_jobs()).count();
+
+            if used < self.target {
+                -self.extra_cost
+            } else {
+                self.extra_cost
+            }
+        } else {
+            0.
+        }
+    }
+}
@@ -63,6 +63,18 @@ pub const WAITING_KEY: i32 = 2;
 pub const TOTAL_DISTANCE_KEY: i32 = 3;
 /// A key which track stotal duration.
 pub const TOTAL_DURATION_KEY: i32 = 4;
+/// A key which tracks total lateness penalty.
+pub const LATENESS_PENALTY_KEY: i32 = 5;
+/// A key which tracks amount of jobs with time windows.
+pub const TIME_WINDOW_JOBS_KEY: i32 = 6;
+/// A key which tracks total penalty of violated soft precedences.
+pub const PRECEDENCE_PENALTY_KEY: i32 = 7;
+/// A key which tracks extra cost of distance tiers.
+pub const DISTANCE_TIERS_COST_KEY: i32 = 8;
+/// A key which tracks route cost above the vehicle cost cap.
+pub const COST_CAP_DISCOUNT_KEY: i32 = 9;
+/// A key which tracks synchronization times of transfer jobs.
+pub const TRANSFER_TIMES_KEY: i32 = 10;
 
 /// A key which tracks current vehicle capacity.
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
@@ -72,6 +84,25 @@ pub const MAX_FUTURE_CAPACITY_KEY: i32 = 12;
 pub const MAX_PAST_CAPACITY_KEY: i32 = 13;
 /// A key which tracks reload intervals.
 pub const RELOAD_INTERVALS_KEY: i32 = 14;
+/// A key which tracks total static delivery demand dispatched from depot.
+pub const DISPATCHED_DEMAND_KEY: i32 = 15;
+/// A key which tracks weighted aggregate of current vehicle load.
+pub const CURRENT_AGGREGATE_LOAD_KEY: i32 = 16;
+/// A key which tracks maximum weighted aggregate of vehicle load ahead in route.
+pub const MAX_FUTURE_AGGREGATE_LOAD_KEY: i32 = 17;
+/// A key which tracks maximum weighted aggregate of vehicle load backward in route.
+pub const MAX_PAST_AGGREGATE_LOAD_KEY: i32 = 18;
+/// A key which tracks arrival times of all routes at locations with limited receiving rate.
+pub const RECEIVING_ARRIVALS_KEY: i32 = 19;
+/// A key which tracks time intervals when routes are active.
+/// NOTE keys from 20 till 28 are used by objectives.
+pub const VEHICLE_POOL_INTERVALS_KEY: i32 = 29;
+/// A key which tracks total penalty of activities served within non-preferred time windows.
+pub const TIME_WINDOW_PENALTY_KEY: i32 = 30;
+// NOTE key 31 is used by objectives.
+/// A key which tracks extra cost of load carried along route legs.
+pub const LOAD_COST_KEY: i32 = 32;
+// NOTE key 33 is used by objectives.
 
 mod pipeline;
 pub use self::pipeline::*;
@@ -82,6 +113,21 @@ pub use self::area::*;
 mod transport;
 pub use self::transport::*;
 
+mod soft_time_window;
+pub use self::soft_time_window::*;
+
+mod time_window_penalty;
+pub use self::time_window_penalty::*;
+
+mod soft_precedence;
+pub use self::soft_precedence::*;
+
+mod distance_tiers;
+pub use self::distance_tiers::*;
+
+mod cost_cap;
+pub use self::cost_cap::*;
+
 mod capacity;
 pub use self::capacity::*;
 
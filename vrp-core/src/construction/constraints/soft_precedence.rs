@@ -0,0 +1,127 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/soft_precedence_test.rs"]
+mod soft_precedence_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::Job;
+use crate::models::solution::Tour;
+use hashbrown::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies a preferred order of two jobs: the first one should be served before the second one
+/// when both are served by the same route. Violation is penalized with given cost.
+pub type SoftPrecedence = (Job, Job, Cost);
+
+/// A module which penalizes violation of preferred order of jobs served by the same route.
+pub struct SoftPrecedenceModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    rules: Arc<PrecedenceRules>,
+}
+
+impl SoftPrecedenceModule {
+    /// Creates a new instance of `SoftPrecedenceModule`.
+    pub fn new(precedences: Vec<SoftPrecedence>) -> Self {
+        let rules = Arc::new(PrecedenceRules::new(precedences));
+
+        Self {
+            state_keys: vec![PRECEDENCE_PENALTY_KEY],
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(PrecedenceSoftActivityConstraint {
+                rules: rules.clone(),
+            }))],
+            rules,
+        }
+    }
+}
+
+impl ConstraintModule for SoftPrecedenceModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let penalty = self.rules.get_penalty(&ctx.route.tour);
+        ctx.state_mut().put_route_state(PRECEDENCE_PENALTY_KEY, penalty);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct PrecedenceRules {
+    successors: HashMap<Job, Vec<(Job, Cost)>>,
+    predecessors: HashMap<Job, Vec<(Job, Cost)>>,
+}
+
+impl PrecedenceRules {
+    fn new(precedences: Vec<SoftPrecedence>) -> Self {
+        precedences.into_iter().fold(
+            Self { successors: Default::default(), predecessors: Default::default() },
+            |mut rules, (before, after, penalty)| {
+                rules.successors.entry(before.clone()).or_insert_with(Vec::new).push((after.clone(), penalty));
+                rules.predecessors.entry(after).or_insert_with(Vec::new).push((before, penalty));
+                rules
+            },
+        )
+    }
+
+    /// Returns total penalty of violated precedences in the tour.
+    fn get_penalty(&self, tour: &Tour) -> Cost {
+        tour.jobs()
+            .filter_map(|job| self.successors.get(&job).map(|successors| (job, successors)))
+            .filter_map(|(job, successors)| tour.index(&job).map(|index| (index, successors)))
+            .flat_map(|(index, successors)| {
+                successors.iter().filter_map(move |(successor, penalty)| {
+                    tour.index(successor).filter(|&other| other < index).map(|_| *penalty)
+                })
+            })
+            .sum()
+    }
+
+    /// Returns penalty of violated precedences when job is inserted after activity with given index.
+    fn estimate_penalty(&self, tour: &Tour, job: &Job, index: usize) -> Cost {
+        let get_penalty = |rules: Option<&Vec<(Job, Cost)>>, is_violated: &dyn Fn(usize) -> bool| {
+            rules.map_or(0., |rules| {
+                rules
+                    .iter()
+                    .filter_map(|(other, penalty)| {
+                        tour.index(other).filter(|&other| is_violated(other)).map(|_| *penalty)
+                    })
+                    .sum::<Cost>()
+            })
+        };
+
+        get_penalty(self.successors.get(job), &|other| other <= index)
+            + get_penalty(self.predecessors.get(job), &|other| other > index)
+    }
+}
+
+struct PrecedenceSoftActivityConstraint {
+    rules: Arc<PrecedenceRules>,
+}
+
+impl SoftActivityConstraint for PrecedenceSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        activity_ctx.target.retrieve_job().map_or(0., |job| {
+            // NOTE activities of the same multi job can be already in the tour
+            if route_ctx.route.tour.contains(&job) {
+                0.
+            } else {
+                self.rules.estimate_penalty(&route_ctx.route.tour, &job, activity_ctx.index)
+            }
+        })
+    }
+}
@@ -0,0 +1,144 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/soft_time_window_test.rs"]
+mod soft_time_window_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::{Cost, Timestamp};
+use crate::models::problem::{ActivityCost, Actor, Job, TransportCost};
+use crate::models::solution::Activity;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns soft time window details of given activity: a timestamp after which
+/// activity is considered as late and penalty per unit of lateness. `None` means that activity's
+/// time window is hard.
+pub type LatenessFunc = Arc<dyn Fn(&Activity) -> Option<(Timestamp, Cost)> + Send + Sync>;
+
+/// A module which penalizes late service of activities with soft time windows. It expects that
+/// time window of such activities is relaxed, so `TransportConstraintModule` does not reject late
+/// arrival, and it should be added after the transport module as it relies on route schedules.
+pub struct SoftTimeWindowModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    lateness: Arc<LatenessPenalty>,
+}
+
+impl SoftTimeWindowModule {
+    /// Creates a new instance of `SoftTimeWindowModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        lateness_func: LatenessFunc,
+    ) -> Self {
+        let lateness = Arc::new(LatenessPenalty::new(activity, transport, lateness_func));
+
+        Self {
+            state_keys: vec![LATENESS_PENALTY_KEY],
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(LatenessSoftActivityConstraint::new(
+                lateness.clone(),
+            )))],
+            lateness,
+        }
+    }
+}
+
+impl ConstraintModule for SoftTimeWindowModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let penalty = self.lateness.get_route_penalty(ctx);
+
+        ctx.state_mut().put_route_state(LATENESS_PENALTY_KEY, penalty);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Calculates lateness penalty of activities using given lateness function.
+pub(crate) struct LatenessPenalty {
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    lateness_func: LatenessFunc,
+}
+
+impl LatenessPenalty {
+    /// Creates a new instance of `LatenessPenalty`.
+    pub(crate) fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        lateness_func: LatenessFunc,
+    ) -> Self {
+        Self { activity, transport, lateness_func }
+    }
+
+    /// Returns total lateness penalty of the route using activity schedules.
+    pub(crate) fn get_route_penalty(&self, route_ctx: &RouteContext) -> Cost {
+        route_ctx
+            .route
+            .tour
+            .all_activities()
+            .map(|activity| self.get_penalty(activity, activity.schedule.arrival))
+            .sum()
+    }
+
+    /// Returns lateness penalty when activity is reached at given arrival time.
+    fn get_penalty(&self, activity: &Activity, arrival: Timestamp) -> Cost {
+        (self.lateness_func)(activity).map_or(0., |(latest, penalty)| {
+            let start = arrival.max(activity.place.time.start);
+            (start - latest).max(0.) * penalty
+        })
+    }
+
+    /// Returns arrival time at `to` activity when departed from `from` at given time.
+    fn get_arrival(&self, actor: &Actor, from: &Activity, to: &Activity, departure: Timestamp) -> Timestamp {
+        departure + self.transport.duration(actor.vehicle.profile, from.place.location, to.place.location, departure)
+    }
+}
+
+/// Estimates lateness penalty change caused by activity insertion.
+pub(crate) struct LatenessSoftActivityConstraint {
+    lateness: Arc<LatenessPenalty>,
+}
+
+impl LatenessSoftActivityConstraint {
+    /// Creates a new instance of `LatenessSoftActivityConstraint`.
+    pub(crate) fn new(lateness: Arc<LatenessPenalty>) -> Self {
+        Self { lateness }
+    }
+}
+
+impl SoftActivityConstraint for LatenessSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let actor = route_ctx.route.actor.as_ref();
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let arr_time_at_target = self.lateness.get_arrival(actor, prev, target, prev.schedule.departure);
+        let target_penalty = self.lateness.get_penalty(target, arr_time_at_target);
+
+        // NOTE consider only direct impact on the next activity
+        let next_penalty_change = activity_ctx.next.map_or(0., |next| {
+            let dep_time_at_target = arr_time_at_target.max(target.place.time.start)
+                + self.lateness.activity.duration(actor, target, arr_time_at_target);
+            let arr_time_at_next = self.lateness.get_arrival(actor, target, next, dep_time_at_target);
+
+            self.lateness.get_penalty(next, arr_time_at_next) - self.lateness.get_penalty(next, next.schedule.arrival)
+        });
+
+        target_penalty + next_penalty_change
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/time_window_penalty_test.rs"]
+mod time_window_penalty_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::Job;
+use crate::models::solution::Activity;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns penalty of serving activity within its current time window, e.g.
+/// when the job has ranked time windows and a fallback one is used instead of the preferred one.
+pub type TimeWindowPenaltyFunc = Arc<dyn Fn(&Activity) -> Cost + Send + Sync>;
+
+/// A module which penalizes service of activities within non-preferred time windows.
+pub struct TimeWindowPenaltyModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    penalty_func: TimeWindowPenaltyFunc,
+}
+
+impl TimeWindowPenaltyModule {
+    /// Creates a new instance of `TimeWindowPenaltyModule`.
+    pub fn new(penalty_func: TimeWindowPenaltyFunc) -> Self {
+        Self {
+            state_keys: vec![TIME_WINDOW_PENALTY_KEY],
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(TimeWindowPenaltySoftActivityConstraint {
+                penalty_func: penalty_func.clone(),
+            }))],
+            penalty_func,
+        }
+    }
+}
+
+impl ConstraintModule for TimeWindowPenaltyModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let penalty = ctx.route.tour.all_activities().map(|activity| (self.penalty_func)(activity)).sum::<Cost>();
+
+        ctx.state_mut().put_route_state(TIME_WINDOW_PENALTY_KEY, penalty);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TimeWindowPenaltySoftActivityConstraint {
+    penalty_func: TimeWindowPenaltyFunc,
+}
+
+impl SoftActivityConstraint for TimeWindowPenaltySoftActivityConstraint {
+    fn estimate_activity(&self, _route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        (self.penalty_func)(activity_ctx.target)
+    }
+}
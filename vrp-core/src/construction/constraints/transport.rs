@@ -425,62 +425,70 @@ struct CostSoftActivityConstraint {
     transport: Arc<dyn TransportCost + Send + Sync>,
 }
 
-impl CostSoftActivityConstraint {
-    fn analyze_route_leg(
-        &self,
-        actor: &Actor,
-        start: &Activity,
-        end: &Activity,
-        time: Timestamp,
-    ) -> (Cost, Cost, Timestamp) {
-        let arrival =
-            time + self.transport.duration(actor.vehicle.profile, start.place.location, end.place.location, time);
-        let departure = arrival.max(end.place.time.start) + self.activity.duration(actor, end, arrival);
-
-        let transport_cost = self.transport.cost(actor, start.place.location, end.place.location, time);
-        let activity_cost = self.activity.cost(actor, end, arrival);
-
-        (transport_cost, activity_cost, departure)
-    }
-}
-
 impl SoftActivityConstraint for CostSoftActivityConstraint {
     fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
-        let actor = route_ctx.route.actor.as_ref();
-
-        let prev = activity_ctx.prev;
-        let target = activity_ctx.target;
-        let next = activity_ctx.next;
-
-        let (tp_cost_left, act_cost_left, dep_time_left) =
-            self.analyze_route_leg(actor, prev, target, prev.schedule.departure);
-
-        let (tp_cost_right, act_cost_right, dep_time_right) = if let Some(next) = next {
-            self.analyze_route_leg(actor, target, next, dep_time_left)
-        } else {
-            (0., 0., 0.)
-        };
+        estimate_activity_cost(self.activity.as_ref(), self.transport.as_ref(), route_ctx, activity_ctx)
+    }
+}
 
-        let new_costs = tp_cost_left + tp_cost_right + act_cost_left + act_cost_right;
+/// Estimates change of transport and activity costs when target activity is inserted.
+pub(crate) fn estimate_activity_cost(
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+) -> Cost {
+    let actor = route_ctx.route.actor.as_ref();
+
+    let prev = activity_ctx.prev;
+    let target = activity_ctx.target;
+    let next = activity_ctx.next;
+
+    let (tp_cost_left, act_cost_left, dep_time_left) =
+        analyze_route_leg(activity, transport, actor, prev, target, prev.schedule.departure);
+
+    let (tp_cost_right, act_cost_right, dep_time_right) = if let Some(next) = next {
+        analyze_route_leg(activity, transport, actor, target, next, dep_time_left)
+    } else {
+        (0., 0., 0.)
+    };
+
+    let new_costs = tp_cost_left + tp_cost_right + act_cost_left + act_cost_right;
+
+    // no jobs yet or open vrp.
+    if !route_ctx.route.tour.has_jobs() || next.is_none() {
+        return new_costs;
+    }
 
-        // no jobs yet or open vrp.
-        if !route_ctx.route.tour.has_jobs() || next.is_none() {
-            return new_costs;
-        }
+    let next = next.unwrap();
+    let waiting_time = *route_ctx.state.get_activity_state(WAITING_KEY, next).unwrap_or(&0_f64);
 
-        let next = next.unwrap();
-        let waiting_time = *route_ctx.state.get_activity_state(WAITING_KEY, next).unwrap_or(&0_f64);
+    let (tp_cost_old, act_cost_old, dep_time_old) =
+        analyze_route_leg(activity, transport, actor, prev, next, prev.schedule.departure);
 
-        let (tp_cost_old, act_cost_old, dep_time_old) =
-            self.analyze_route_leg(actor, prev, next, prev.schedule.departure);
+    let waiting_cost =
+        waiting_time.min(0_f64.max(dep_time_right - dep_time_old)) * actor.vehicle.costs.per_waiting_time;
 
-        let waiting_cost =
-            waiting_time.min(0_f64.max(dep_time_right - dep_time_old)) * actor.vehicle.costs.per_waiting_time;
+    let old_costs = tp_cost_old + act_cost_old + waiting_cost;
 
-        let old_costs = tp_cost_old + act_cost_old + waiting_cost;
+    new_costs - old_costs
+}
 
-        new_costs - old_costs
-    }
+fn analyze_route_leg(
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+    actor: &Actor,
+    start: &Activity,
+    end: &Activity,
+    time: Timestamp,
+) -> (Cost, Cost, Timestamp) {
+    let arrival = time + transport.duration(actor.vehicle.profile, start.place.location, end.place.location, time);
+    let departure = arrival.max(end.place.time.start) + activity.duration(actor, end, arrival);
+
+    let transport_cost = transport.cost(actor, start.place.location, end.place.location, time);
+    let activity_cost = activity.cost(actor, end, arrival);
+
+    (transport_cost, activity_cost, departure)
 }
 
 fn fail(code: i32) -> Option<ActivityConstraintViolation> {
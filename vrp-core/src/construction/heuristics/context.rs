@@ -2,7 +2,10 @@
 #[path = "../../../tests/unit/construction/heuristics/context_test.rs"]
 mod context_test;
 
-use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::constraints::{
+    COST_CAP_DISCOUNT_KEY, DISTANCE_TIERS_COST_KEY, LATENESS_PENALTY_KEY, LOAD_COST_KEY, PRECEDENCE_PENALTY_KEY,
+    TIME_WINDOW_PENALTY_KEY, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY,
+};
 use crate::construction::heuristics::factories::*;
 use crate::models::common::Cost;
 use crate::models::problem::*;
@@ -195,6 +198,14 @@ impl RouteContext {
 
     /// Gets route cost.
     pub fn get_route_cost(&self) -> Cost {
+        let discount = self.state.get_route_state::<f64>(COST_CAP_DISCOUNT_KEY).cloned().unwrap_or(0.);
+
+        self.get_route_usage_cost() - discount + self.get_route_penalty()
+    }
+
+    /// Gets route usage cost: fixed, distance and duration costs of vehicle and driver, without
+    /// any discounts and penalties.
+    pub fn get_route_usage_cost(&self) -> Cost {
         let get_cost = |costs: &Costs, distance: f64, duration: f64| {
             costs.fixed
                 + costs.per_distance * distance
@@ -209,8 +220,21 @@ impl RouteContext {
         let actor = &self.route.actor;
         let distance = self.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
         let duration = self.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+        let tiers = self.state.get_route_state::<f64>(DISTANCE_TIERS_COST_KEY).cloned().unwrap_or(0.);
+        let load = self.state.get_route_state::<f64>(LOAD_COST_KEY).cloned().unwrap_or(0.);
+
+        get_cost(&actor.vehicle.costs, distance, duration)
+            + get_cost(&actor.driver.costs, distance, duration)
+            + tiers
+            + load
+    }
 
-        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+    /// Gets route penalty: a sum of penalties of route's soft constraints, such as lateness.
+    pub fn get_route_penalty(&self) -> Cost {
+        [LATENESS_PENALTY_KEY, PRECEDENCE_PENALTY_KEY, TIME_WINDOW_PENALTY_KEY]
+            .iter()
+            .filter_map(|key| self.state.get_route_state::<f64>(*key).cloned())
+            .sum::<Cost>()
     }
 
     /// Unwraps given `RouteContext` as pair of mutable references.
@@ -364,6 +388,11 @@ impl RegistryContext {
         self.registry.next().map(move |actor| self.index[&actor].clone())
     }
 
+    /// Returns route for given actor as it is created before any insertion.
+    pub fn get_route(&self, actor: &Arc<Actor>) -> Option<RouteContext> {
+        self.index.get(actor).cloned()
+    }
+
     /// Sets this route as used.
     /// Returns whether the route was already marked as used in the registry.
     pub fn use_route(&mut self, route: &RouteContext) -> bool {
@@ -376,6 +405,33 @@ impl RegistryContext {
         self.registry.free_actor(&route.route.actor);
     }
 
+    /// Sets the actor as used without creating a route for it.
+    /// Returns whether the actor was available in the registry.
+    pub fn use_actor(&mut self, actor: &Arc<Actor>) -> bool {
+        self.registry.use_actor(actor)
+    }
+
+    /// Sets the actor as unused.
+    /// Returns whether the actor was not available in the registry.
+    pub fn free_actor(&mut self, actor: &Arc<Actor>) -> bool {
+        self.registry.free_actor(actor)
+    }
+
+    /// Modifies routes of available actors, e.g. to keep their state in sync with solution.
+    /// Routes are changed in place unless they are shared with another solution: then, only
+    /// a shared route is deep copied, so other solutions stay intact.
+    pub fn modify_available(&mut self, modifier: impl Fn(&mut RouteContext)) {
+        let actors = self.registry.available().collect::<Vec<_>>();
+        actors.iter().for_each(|actor| {
+            if let Some(route_ctx) = self.index.get_mut(actor) {
+                if Arc::strong_count(&route_ctx.route) > 1 || Arc::strong_count(&route_ctx.state) > 1 {
+                    *route_ctx = route_ctx.deep_copy();
+                }
+                modifier(route_ctx);
+            }
+        });
+    }
+
     /// Creates a deep copy of `RegistryContext`.
     pub fn deep_copy(&self) -> Self {
         Self { registry: self.registry.deep_copy(), index: self.index.clone() }
@@ -6,9 +6,24 @@ use std::sync::Arc;
 
 use crate::construction::constraints::{ActivityConstraintViolation, ConstraintPipeline};
 use crate::construction::heuristics::*;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, ValueDimension};
 use crate::models::problem::{Job, Multi, Single};
 use crate::models::solution::{Activity, Place};
+use crate::utils::compare_floats;
+
+/// A key of problem extras which specifies insertion neighborhood size: max amount of the nearest
+/// routes with jobs evaluated for job insertion. Routes without jobs are always evaluated. Only
+/// routes are pruned: all insertion positions are evaluated within a selected route.
+pub const INSERTION_NEIGHBORS_KEY: &str = "insertion_neighbors";
+
+/// A key of problem extras which specifies a factor of soft route costs used to evaluate job insertion.
+/// A factor below one relaxes soft route constraints, e.g. fleet usage or fixed costs, so job placement
+/// is driven mostly by activity costs.
+pub const SOFT_ROUTE_COST_FACTOR_KEY: &str = "soft_route_cost_factor";
+
+/// A dimension key of single job which specifies that time offsets of its places are relative to
+/// the shift start instead of the actual route departure which can be rescheduled.
+pub const SHIFT_START_OFFSETS_KEY: &str = "shift_start_offsets";
 
 /// Specifies allowed insertion position in route for the job.
 #[derive(Copy, Clone)]
@@ -27,7 +42,15 @@ pub fn evaluate_job_insertion(
     route_selector: &(dyn RouteSelector + Send + Sync),
     position: InsertionPosition,
 ) -> InsertionResult {
-    route_selector.select(ctx, job).fold(InsertionResult::make_failure(), |acc, route_ctx| {
+    let routes = route_selector.select(ctx, job);
+    let neighbors = ctx.problem.extras.get(INSERTION_NEIGHBORS_KEY).and_then(|n| n.downcast_ref::<usize>());
+
+    let routes: Box<dyn Iterator<Item = RouteContext>> = match neighbors {
+        Some(neighbors) => Box::new(get_nearest_routes(ctx, job, routes, *neighbors).into_iter()),
+        None => routes,
+    };
+
+    routes.fold(InsertionResult::make_failure(), |acc, route_ctx| {
         evaluate_job_insertion_in_route(job, ctx, &route_ctx, position, Some(acc))
     })
 }
@@ -51,7 +74,9 @@ pub fn evaluate_job_insertion_in_route(
         );
     }
 
-    let route_costs = constraint.evaluate_soft_route(&ctx.solution, &route_ctx, &job);
+    let route_cost_factor =
+        ctx.problem.extras.get(SOFT_ROUTE_COST_FACTOR_KEY).and_then(|f| f.downcast_ref::<f64>()).cloned().unwrap_or(1.);
+    let route_costs = constraint.evaluate_soft_route(&ctx.solution, &route_ctx, &job) * route_cost_factor;
     let best_known_cost = match &alternative {
         InsertionResult::Success(success) => Some(success.cost),
         _ => None,
@@ -63,6 +88,11 @@ pub fn evaluate_job_insertion_in_route(
         }
     }
 
+    // NOTE activity costs are compared against the best known cost reduced by route costs: insertion
+    // cost is their sum, so pruning keeps the same result as evaluation without best known cost.
+    // Otherwise, a route with negative route costs, e.g. a new one when fleet is maximized, is never preferred
+    let best_known_cost = best_known_cost.map(|cost| cost - route_costs);
+
     InsertionResult::choose_best_result(
         alternative,
         match job {
@@ -89,6 +119,75 @@ pub fn evaluate_job_constraint_in_route(
     }
 }
 
+/// Estimates cost of the job insertion into the route with given index at given position without
+/// modifying insertion context. Position is an index of tour activity after which the job is placed,
+/// so zero means right after the tour start. Returns `None` if insertion is not feasible. Only single
+/// jobs are supported as position of multi job is ambiguous.
+pub fn estimate_insertion(ctx: &InsertionContext, job: &Job, route_index: usize, position: usize) -> Option<Cost> {
+    let single = job.as_single()?;
+    let route_ctx = ctx.solution.routes.get(route_index)?;
+    let constraint = &ctx.problem.constraint;
+
+    if constraint.evaluate_hard_route(&ctx.solution, route_ctx, job).is_some() {
+        return None;
+    }
+
+    let route_costs = constraint.evaluate_soft_route(&ctx.solution, route_ctx, job);
+    let leg = route_ctx.route.tour.legs().nth(position)?;
+    let mut activity = Activity::new_with_job(single.clone());
+
+    let result = unwrap_from_result(analyze_insertion_in_route_leg(
+        constraint,
+        route_ctx,
+        leg,
+        single,
+        &mut activity,
+        SingleContext::new(None, position),
+    ));
+
+    result.cost.map(|cost| cost + route_costs)
+}
+
+/// Returns routes without jobs and not more than given amount of routes with jobs which are the
+/// nearest to the job. A distance to the route is the lowest distance to any of its jobs. Jobs
+/// which are not a part of the problem are ignored, so a route with such jobs only is the farthest
+/// one. If no distance is known, e.g. the job itself is not a part of the problem, routes are not pruned.
+fn get_nearest_routes(
+    ctx: &InsertionContext,
+    job: &Job,
+    routes: Box<dyn Iterator<Item = RouteContext> + '_>,
+    neighbors: usize,
+) -> Vec<RouteContext> {
+    let (mut used, empty): (Vec<_>, Vec<_>) = routes.partition(|route_ctx| route_ctx.route.tour.has_jobs());
+
+    if used.len() > neighbors {
+        let jobs = ctx.problem.jobs.as_ref();
+        let mut used_with_distances = used
+            .into_iter()
+            .map(|route_ctx| {
+                let profile = route_ctx.route.actor.vehicle.profile;
+                let distance = route_ctx
+                    .route
+                    .tour
+                    .jobs()
+                    .filter_map(|other| jobs.try_distance(profile, job, &other, 0.))
+                    .fold(None, |acc: Option<f64>, distance| Some(acc.map_or(distance, |acc| acc.min(distance))));
+
+                (distance, route_ctx)
+            })
+            .collect::<Vec<_>>();
+
+        used = if used_with_distances.iter().any(|(distance, _)| distance.is_some()) {
+            used_with_distances.sort_by(|(a, _), (b, _)| compare_floats(a.unwrap_or(f64::MAX), b.unwrap_or(f64::MAX)));
+            used_with_distances.into_iter().take(neighbors).map(|(_, route_ctx)| route_ctx).collect()
+        } else {
+            used_with_distances.into_iter().map(|(_, route_ctx)| route_ctx).collect()
+        };
+    }
+
+    used.into_iter().chain(empty).collect()
+}
+
 fn evaluate_single(
     job: &Job,
     single: &Arc<Single>,
@@ -218,31 +317,47 @@ fn analyze_insertion_in_route_leg<'a>(
         [prev, next] => (prev, Some(next)),
         _ => panic!("Unexpected route leg configuration."),
     };
-    let start_time = route_ctx.route.tour.start().unwrap().schedule.departure;
+    let start = route_ctx.route.tour.start().unwrap();
+    let start_time = if single.dimens.get_value::<bool>(SHIFT_START_OFFSETS_KEY).cloned().unwrap_or(false) {
+        start.schedule.arrival
+    } else {
+        start.schedule.departure
+    };
     // analyze service details
     single.places.iter().try_fold(out, |in1, detail| {
-        // analyze detail time windows
-        detail.times.iter().try_fold(in1, |in2, time| {
-            target.place = Place {
-                location: detail.location.unwrap_or(prev.place.location),
-                duration: detail.duration,
-                time: time.to_time_window(start_time),
-            };
-
-            let activity_ctx = ActivityContext { index, prev, target: &target, next };
-
-            if let Some(violation) = constraint.evaluate_hard_activity(route_ctx, &activity_ctx) {
-                return SingleContext::fail(violation, in2);
+        // NOTE job without location is served at location of previous activity or, when vehicle has
+        // to wait for next activity, at its location during waiting time
+        let locations = match (detail.location, next) {
+            (Some(location), _) => vec![location],
+            (None, Some(next))
+                if next.place.location != prev.place.location && next.schedule.arrival < next.place.time.start =>
+            {
+                vec![prev.place.location, next.place.location]
             }
+            _ => vec![prev.place.location],
+        };
 
-            let costs = constraint.evaluate_soft_activity(route_ctx, &activity_ctx);
+        // analyze detail locations and time windows
+        locations.iter().flat_map(|location| detail.times.iter().map(move |time| (*location, time))).try_fold(
+            in1,
+            |in2, (location, time)| {
+                target.place = Place { location, duration: detail.duration, time: time.to_time_window(start_time) };
 
-            if costs < in2.cost.unwrap_or(std::f64::MAX) {
-                SingleContext::success(activity_ctx.index, costs, target.place.clone())
-            } else {
-                SingleContext::skip(in2)
-            }
-        })
+                let activity_ctx = ActivityContext { index, prev, target: &target, next };
+
+                if let Some(violation) = constraint.evaluate_hard_activity(route_ctx, &activity_ctx) {
+                    return SingleContext::fail(violation, in2);
+                }
+
+                let costs = constraint.evaluate_soft_activity(route_ctx, &activity_ctx);
+
+                if costs < in2.cost.unwrap_or(std::f64::MAX) {
+                    SingleContext::success(activity_ctx.index, costs, target.place.clone())
+                } else {
+                    SingleContext::skip(in2)
+                }
+            },
+        )
     })
 }
 
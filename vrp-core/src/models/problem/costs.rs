@@ -17,12 +17,110 @@ use std::sync::Arc;
 pub struct ObjectiveCost {
     primary_objectives: Vec<TargetObjective>,
     secondary_objectives: Vec<TargetObjective>,
+    tie_break_objectives: Vec<TargetObjective>,
+    initial_phase_objectives: Vec<TargetObjective>,
 }
 
 impl ObjectiveCost {
     /// Creates an instance of `ObjectiveCost`.
     pub fn new(primary_objectives: Vec<TargetObjective>, secondary_objectives: Vec<TargetObjective>) -> Self {
-        Self { primary_objectives, secondary_objectives }
+        Self {
+            primary_objectives,
+            secondary_objectives,
+            tie_break_objectives: vec![],
+            initial_phase_objectives: vec![],
+        }
+    }
+
+    /// Sets tie-break objectives which are used only to order solutions equal by primary and
+    /// secondary objectives.
+    pub fn with_tie_break(mut self, tie_break_objectives: Vec<TargetObjective>) -> Self {
+        self.tie_break_objectives = tie_break_objectives;
+        self
+    }
+
+    /// Sets objectives which take precedence over all others during the initial phase of search.
+    /// Unlike primary objectives, they are compared one by one in the given order. They are used
+    /// only by objective returned from `phased` when the initial phase is active.
+    pub fn with_initial_phase(mut self, initial_phase_objectives: Vec<TargetObjective>) -> Self {
+        self.initial_phase_objectives = initial_phase_objectives;
+        self
+    }
+
+    /// Checks whether there are initial phase objectives.
+    pub fn has_initial_phase(&self) -> bool {
+        !self.initial_phase_objectives.is_empty()
+    }
+
+    /// Returns objective for the given phase of search. The phase is owned by the caller, normally,
+    /// by population of a specific solver run, so the problem objective itself is not changed.
+    pub fn phased(&self, is_initial_phase: bool) -> PhasedObjectiveCost<'_> {
+        PhasedObjectiveCost { objective: self, is_initial_phase }
+    }
+
+    /// Compares two solutions by each primary, secondary and tie-break objective term and explains
+    /// which level of objective hierarchy decided their order.
+    pub fn explain(&self, a: &InsertionContext, b: &InsertionContext) -> ObjectiveComparison {
+        let levels = [
+            (ObjectiveLevel::Primary, &self.primary_objectives),
+            (ObjectiveLevel::Secondary, &self.secondary_objectives),
+            (ObjectiveLevel::TieBreak, &self.tie_break_objectives),
+        ];
+
+        let terms = levels
+            .iter()
+            .flat_map(|(level, objectives)| {
+                objectives.iter().enumerate().map(move |(index, objective)| ObjectiveTermComparison {
+                    level: level.clone(),
+                    index,
+                    fitness: (objective.fitness(a), objective.fitness(b)),
+                    order: objective.total_order(a, b),
+                })
+            })
+            .collect();
+
+        let (level, order) = levels
+            .iter()
+            .map(|(level, objectives)| (Some(level.clone()), dominance_order(a, b, objectives)))
+            .find(|(_, order)| *order != Ordering::Equal)
+            .unwrap_or((None, Ordering::Equal));
+
+        ObjectiveComparison { order, level, terms }
+    }
+
+    fn get_initial_phase_objectives(&self, is_initial_phase: bool) -> &[TargetObjective] {
+        if is_initial_phase {
+            self.initial_phase_objectives.as_slice()
+        } else {
+            &[]
+        }
+    }
+
+    fn get_order(&self, a: &InsertionContext, b: &InsertionContext, is_initial_phase: bool) -> Ordering {
+        let initial_order = self
+            .get_initial_phase_objectives(is_initial_phase)
+            .iter()
+            .map(|objective| objective.total_order(a, b))
+            .find(|order| *order != Ordering::Equal)
+            .unwrap_or(Ordering::Equal);
+
+        match initial_order {
+            Ordering::Equal => match dominance_order(a, b, &self.primary_objectives) {
+                Ordering::Equal => match dominance_order(a, b, &self.secondary_objectives) {
+                    Ordering::Equal => dominance_order(a, b, &self.tie_break_objectives),
+                    order => order,
+                },
+                order => order,
+            },
+            order => order,
+        }
+    }
+
+    fn get_objectives(&self, is_initial_phase: bool) -> impl Iterator<Item = &TargetObjective> {
+        self.get_initial_phase_objectives(is_initial_phase)
+            .iter()
+            .chain(self.primary_objectives.iter())
+            .chain(self.secondary_objectives.iter())
     }
 }
 
@@ -30,10 +128,7 @@ impl Objective for ObjectiveCost {
     type Solution = InsertionContext;
 
     fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
-        match dominance_order(a, b, &self.primary_objectives) {
-            Ordering::Equal => dominance_order(a, b, &self.secondary_objectives),
-            order => order,
-        }
+        self.get_order(a, b, false)
     }
 
     fn distance(&self, _a: &Self::Solution, _b: &Self::Solution) -> f64 {
@@ -47,7 +142,36 @@ impl Objective for ObjectiveCost {
 
 impl MultiObjective for ObjectiveCost {
     fn objectives<'a>(&'a self) -> Box<dyn Iterator<Item = &TargetObjective> + 'a> {
-        Box::new(self.primary_objectives.iter().chain(self.secondary_objectives.iter()))
+        Box::new(self.get_objectives(false))
+    }
+}
+
+/// A view of `ObjectiveCost` for specific phase of search: during the initial phase, initial phase
+/// objectives take precedence over all others.
+pub struct PhasedObjectiveCost<'a> {
+    objective: &'a ObjectiveCost,
+    is_initial_phase: bool,
+}
+
+impl<'a> Objective for PhasedObjectiveCost<'a> {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        self.objective.get_order(a, b, self.is_initial_phase)
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.objective.distance(a, b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.objective.fitness(solution)
+    }
+}
+
+impl<'a> MultiObjective for PhasedObjectiveCost<'a> {
+    fn objectives<'b>(&'b self) -> Box<dyn Iterator<Item = &'b TargetObjective> + 'b> {
+        Box::new(self.objective.get_objectives(self.is_initial_phase))
     }
 }
 
@@ -57,9 +181,45 @@ impl Default for ObjectiveCost {
             vec![Box::new(TotalUnassignedJobs::default()), Box::new(TotalRoutes::default())],
             vec![Box::new(TotalTransportCost::default())],
         )
+        .with_initial_phase(vec![Box::new(TotalUnassignedJobs::default()), Box::new(TotalRoutes::default())])
     }
 }
 
+/// Specifies a level of objective hierarchy.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ObjectiveLevel {
+    /// Primary objectives.
+    Primary,
+    /// Secondary objectives.
+    Secondary,
+    /// Tie-break objectives.
+    TieBreak,
+}
+
+/// Specifies a comparison of two solutions by a single objective term.
+#[derive(Clone, Debug)]
+pub struct ObjectiveTermComparison {
+    /// A level of the term in objective hierarchy.
+    pub level: ObjectiveLevel,
+    /// An index of the term within its level.
+    pub index: usize,
+    /// Fitness values of the term for both solutions.
+    pub fitness: (f64, f64),
+    /// An order of solutions by the term: `Less` means that the first solution is better.
+    pub order: Ordering,
+}
+
+/// Specifies a comparison of two solutions by hierarchical objective.
+#[derive(Clone, Debug)]
+pub struct ObjectiveComparison {
+    /// An order of solutions: `Less` means that the first solution is better.
+    pub order: Ordering,
+    /// A level of objective hierarchy which decided the order. None if solutions are equal.
+    pub level: Option<ObjectiveLevel>,
+    /// Comparisons by each objective term.
+    pub terms: Vec<ObjectiveTermComparison>,
+}
+
 /// Provides the way to get cost information for specific activities done by specific actor.
 pub trait ActivityCost {
     /// Returns cost to perform activity.
@@ -88,6 +248,9 @@ impl Default for SimpleActivityCost {
 
 impl ActivityCost for SimpleActivityCost {}
 
+/// A transport distance and duration value which marks that there is no route between two locations.
+pub const UNREACHABLE_VALUE: f64 = -1.;
+
 /// Provides the way to get routing information for specific locations and actor.
 pub trait TransportCost {
     /// Returns transport cost between two locations.
@@ -95,6 +258,11 @@ pub trait TransportCost {
         let distance = self.distance(actor.vehicle.profile, from, to, departure);
         let duration = self.duration(actor.vehicle.profile, from, to, departure);
 
+        // NOTE unreachable leg makes route infeasible, so its sentinel values should not be charged
+        if distance < 0. || duration < 0. {
+            return 0.;
+        }
+
         distance * (actor.driver.costs.per_distance + actor.vehicle.costs.per_distance)
             + duration * (actor.driver.costs.per_driving_time + actor.vehicle.costs.per_driving_time)
     }
@@ -104,6 +272,12 @@ pub trait TransportCost {
 
     /// Returns transport distance between two locations.
     fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance;
+
+    /// Checks whether there is no route between two locations, e.g. they are on different islands.
+    /// Such locations have negative transport distance or duration, see `UNREACHABLE_VALUE`.
+    fn is_unreachable(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> bool {
+        self.distance(profile, from, to, departure) < 0. || self.duration(profile, from, to, departure) < 0.
+    }
 }
 
 /// Contains matrix routing data for specific profile and, optionally, time.
@@ -67,6 +67,7 @@ pub struct Place {
 }
 
 /// Represents a job which should be performed once but actual place/time might vary.
+#[repr(C)]
 pub struct Single {
     /// Specifies job details: where and when it can be performed.
     pub places: Vec<Place>,
@@ -154,6 +155,7 @@ impl Multi {
     /// Wraps given multi job into [`Arc`] adding reference to it from all sub-jobs.
     pub fn bind(multi: Self) -> Arc<Self> {
         // NOTE: layout must be identical
+        #[repr(C)]
         struct SingleConstruct {
             pub places: UnsafeCell<Vec<Place>>,
             pub dimens: UnsafeCell<Dimensions>,
@@ -212,6 +214,12 @@ impl Jobs {
         *self.index.get(&profile).unwrap().get(from).unwrap().1.get(to).unwrap()
     }
 
+    /// Returns cost distance between two jobs or `None` if any of them is not known, e.g. it is
+    /// not a part of the problem.
+    pub fn try_distance(&self, profile: Profile, from: &Job, to: &Job, _: Timestamp) -> Option<Cost> {
+        self.index.get(&profile).and_then(|index| index.get(from)).and_then(|(_, costs, _)| costs.get(to)).cloned()
+    }
+
     /// Returns job rank as relative cost from any vehicle's start position.
     pub fn rank(&self, profile: Profile, job: &Job) -> Cost {
         self.index.get(&profile).unwrap().get(job).unwrap().2
@@ -2,7 +2,7 @@
 #[path = "../../../tests/unit/models/solution/tour_test.rs"]
 mod tour_test;
 
-use crate::models::common::Schedule;
+use crate::models::common::{Demand, DemandDimension, Load, MultiDimLoad, Schedule};
 use crate::models::problem::{Actor, Job};
 use crate::models::solution::{Activity, Place};
 use crate::models::OP_START_MSG;
@@ -205,6 +205,28 @@ impl Tour {
         self.jobs.len()
     }
 
+    /// Returns total demand of the tour as sum of static and dynamic deliveries of all activities.
+    /// Demand is expected to be defined as `MultiDimLoad`, otherwise it is ignored.
+    pub fn total_demand(&self) -> MultiDimLoad {
+        self.activities.iter().filter_map(get_multi_demand).map(|demand| demand.delivery.0 + demand.delivery.1).sum()
+    }
+
+    /// Returns peak load of the tour: a max running load at any activity, starting at the tour
+    /// start with all static deliveries loaded. Reloads are not considered by this method.
+    /// Demand is expected to be defined as `MultiDimLoad`, otherwise it is ignored.
+    pub fn peak_load(&self) -> MultiDimLoad {
+        let start_load: MultiDimLoad =
+            self.activities.iter().filter_map(get_multi_demand).map(|demand| demand.delivery.0).sum();
+
+        self.activities
+            .iter()
+            .fold((start_load, start_load), |(current, peak), activity| {
+                let current = get_multi_demand(activity).map_or(current, |demand| current + demand.change());
+                (current, peak.max_load(current))
+            })
+            .1
+    }
+
     /// Creates a copy of existing tour deeply copying all activities and jobs.
     pub fn deep_copy(&self) -> Tour {
         Tour {
@@ -215,6 +237,10 @@ impl Tour {
     }
 }
 
+fn get_multi_demand(activity: &Activity) -> Option<&Demand<MultiDimLoad>> {
+    activity.job.as_ref().and_then(|job| job.dimens.get_demand())
+}
+
 /// Creates start activity.
 fn create_start_activity(actor: &Actor) -> Activity {
     let start = &actor.detail.start.as_ref().unwrap_or_else(|| unimplemented!("{}", OP_START_MSG));
@@ -1,7 +1,7 @@
 use crate::construction::heuristics::InsertionContext;
 use crate::construction::Quota;
 use crate::models::{Problem, Solution};
-use crate::solver::evolution::EvolutionConfig;
+use crate::solver::evolution::{create_default_mutation, EvolutionConfig, PhaseSwitch};
 use crate::solver::mutation::*;
 use crate::solver::selection::Selection;
 use crate::solver::termination::*;
@@ -56,8 +56,13 @@ pub struct Builder {
     /// A randomization seed
     pub seed: Option<u64>,
 
+    /// Specifies whether each mutation operator uses its own random stream derived from the seed.
+    pub operator_streams: bool,
+
     /// An evolution configuration..
     pub config: EvolutionConfig,
+
+    is_custom_mutation: bool,
 }
 
 impl Builder {
@@ -68,7 +73,9 @@ impl Builder {
             max_time: None,
             cost_variation: None,
             seed: None,
+            operator_streams: false,
             config: EvolutionConfig::new(problem),
+            is_custom_mutation: false,
         }
     }
 }
@@ -151,6 +158,7 @@ impl Builder {
     pub fn with_mutation(mut self, mutation: Arc<dyn Mutation + Send + Sync>) -> Self {
         self.config.telemetry.log("configured to use custom mutation");
         self.config.mutation = mutation;
+        self.is_custom_mutation = true;
         self
     }
 
@@ -161,6 +169,38 @@ impl Builder {
         self
     }
 
+    /// Sets a switch from the initial objective phase to the main one. Default is none: the
+    /// initial phase is not used.
+    pub fn with_phase_switch(mut self, phase_switch: Option<PhaseSwitch>) -> Self {
+        if let Some(phase_switch) = &phase_switch {
+            self.config.telemetry.log(format!("configured to use objective phase switch: {:?}", phase_switch).as_str());
+        }
+        self.config.phase_switch = phase_switch;
+        self
+    }
+
+    /// Sets a limit of total soft penalty, e.g. lateness, of a solution. A solution which exceeds it
+    /// is treated as infeasible: jobs are removed from it until the limit is met and reported as
+    /// unassigned with `PENALTY_LIMIT_CODE`. Default is none: penalty is not limited.
+    pub fn with_penalty_limit(mut self, limit: Option<f64>) -> Self {
+        if let Some(limit) = limit {
+            self.config.telemetry.log(format!("configured to use soft penalty limit: {}", limit).as_str());
+        }
+        self.config.penalty_limit = limit;
+        self
+    }
+
+    /// Sets whether a final insertion sweep should be applied after evolution: unassigned jobs of
+    /// the best known solution are inserted using cheapest insertion where it is feasible. The
+    /// result is accepted only when it is not worse than the best known solution. Default is false.
+    pub fn with_final_sweep(mut self, final_sweep: bool) -> Self {
+        if final_sweep {
+            self.config.telemetry.log("configured to use final insertion sweep");
+        }
+        self.config.final_sweep = final_sweep;
+        self
+    }
+
     /// Sets randomization seed.
     pub fn with_seed(mut self, seed: Option<u64>) -> Self {
         if seed.is_some() {
@@ -170,8 +210,43 @@ impl Builder {
         self
     }
 
+    /// Sets whether each ruin and recreate method of the default mutation should use its own
+    /// random stream derived from the seed. It is intended for debugging: a method's randomness
+    /// can be reproduced in isolation as changes in other methods do not affect it. Has effect
+    /// only when seed is set and cannot be combined with mutation set by `with_mutation`: in this
+    /// case, `build` returns an error. Default is false.
+    pub fn with_operator_streams(mut self, operator_streams: bool) -> Self {
+        self.operator_streams = operator_streams;
+        self
+    }
+
+    /// Sets whether time spent in each refinement phase (construction, selection, mutation and
+    /// evaluation) should be measured. Timings are stored in termination report. Default is false.
+    pub fn with_profile(mut self, profile: bool) -> Self {
+        if profile {
+            self.config.telemetry.log("configured to profile refinement phases");
+        }
+        self.config.profile = profile;
+        self
+    }
+
+    /// Sets how many times construction of initial solution is retried with perturbed job order and
+    /// relaxed soft route costs when it leaves jobs unassigned. The solution with the least amount of unassigned jobs is
+    /// added to the population. Default is zero: construction is not retried.
+    pub fn with_construction_retries(mut self, retries: usize) -> Self {
+        if retries > 0 {
+            self.config.telemetry.log(format!("configured to use construction retries: {}", retries).as_str());
+        }
+        self.config.construction_retries = retries;
+        self
+    }
+
     /// Builds [`Solver`](./struct.Solver.html) instance.
     pub fn build(self) -> Result<Solver, String> {
+        if self.operator_streams && self.is_custom_mutation {
+            return Err("operator streams cannot be used together with custom mutation".to_string());
+        }
+
         let problem = self.config.problem.clone();
 
         let (criterias, quota): (Vec<Box<dyn Termination>>, _) =
@@ -217,6 +292,12 @@ impl Builder {
         config.termination = Arc::new(CompositeTermination::new(criterias));
         config.quota = quota;
 
+        let operator_streams = self.operator_streams;
+        if let Some(seed) = self.seed.filter(|_| operator_streams) {
+            config.telemetry.log("configured to use random streams per mutation operator");
+            config.mutation = create_default_mutation(problem.clone(), Some(seed));
+        }
+
         config.random = Arc::new(if let Some(seed) = self.seed {
             config.telemetry.log(format!("configured to use seed: {}", seed).as_str());
             DefaultRandom::new_with_seed(seed)
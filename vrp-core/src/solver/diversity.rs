@@ -0,0 +1,38 @@
+//! A module which provides the logic to select diverse solutions, e.g. to present alternatives.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/diversity_test.rs"]
+mod diversity_test;
+
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job};
+use crate::models::Solution;
+use hashbrown::HashMap;
+use std::sync::Arc;
+
+/// Returns assignment distance between two solutions of the same problem: amount of jobs which
+/// are assigned to different actors or assigned in one solution and unassigned in another.
+pub fn assignment_distance(a: &Solution, b: &Solution) -> usize {
+    let (a, b) = (get_assignments(a), get_assignments(b));
+
+    a.iter().filter(|(job, actor)| b.get(job).map_or(true, |other| !Arc::ptr_eq(actor, other))).count()
+        + b.keys().filter(|job| !a.contains_key(job)).count()
+}
+
+/// Selects up to `k` solutions from the given ones, which are expected to be sorted from the best
+/// to the worst, greedily: a solution is selected only if its assignment distance to each already
+/// selected one is not less than `min_distance`.
+pub fn select_diverse(solutions: Vec<(Solution, Cost)>, k: usize, min_distance: usize) -> Vec<(Solution, Cost)> {
+    solutions.into_iter().fold(Vec::default(), |mut selected, (solution, cost)| {
+        if selected.len() < k && selected.iter().all(|(other, _)| assignment_distance(&solution, other) >= min_distance)
+        {
+            selected.push((solution, cost));
+        }
+
+        selected
+    })
+}
+
+fn get_assignments(solution: &Solution) -> HashMap<Job, Arc<Actor>> {
+    solution.routes.iter().flat_map(|route| route.tour.jobs().map(move |job| (job, route.actor.clone()))).collect()
+}
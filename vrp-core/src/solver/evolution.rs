@@ -2,16 +2,19 @@
 #[path = "../../tests/unit/solver/evolution_test.rs"]
 mod evolution_test;
 
-use crate::construction::heuristics::InsertionContext;
+use crate::construction::heuristics::{InsertionContext, SOFT_ROUTE_COST_FACTOR_KEY};
 use crate::construction::Quota;
+use crate::models::common::Cost;
 use crate::models::Problem;
 use crate::solver::mutation::*;
 use crate::solver::population::DominancePopulation;
 use crate::solver::selection::{NaiveSelection, Selection};
 use crate::solver::telemetry::Telemetry;
 use crate::solver::termination::*;
-use crate::solver::{Metrics, Population, RefinementContext, TelemetryMode};
+use crate::solver::{apply_final_sweep, apply_penalty_limit, Metrics, Population, RefinementContext, TelemetryMode};
 use crate::utils::{get_cpus, DefaultRandom, Random, Timer};
+use rand::prelude::SliceRandom;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 /// A configuration which controls evolution execution.
@@ -32,6 +35,26 @@ pub struct EvolutionConfig {
     pub random: Arc<dyn Random + Send + Sync>,
     /// A telemetry to be used.
     pub telemetry: Telemetry,
+    /// Specifies when the initial objective phase is finished. If not set, the initial phase is not used.
+    pub phase_switch: Option<PhaseSwitch>,
+    /// Specifies a limit of total soft penalty: solutions above it shed jobs. If not set, the limit is not used.
+    pub penalty_limit: Option<Cost>,
+    /// Specifies whether unassigned jobs of the best solution should be inserted at the end of evolution.
+    pub final_sweep: bool,
+    /// Specifies whether time spent in each refinement phase should be reported.
+    pub profile: bool,
+    /// Specifies how many times construction of initial solution is retried with perturbed job
+    /// order and relaxed soft constraints when it leaves jobs unassigned.
+    pub construction_retries: usize,
+}
+
+/// Specifies when the initial objective phase is switched to the main one.
+#[derive(Clone, Debug)]
+pub enum PhaseSwitch {
+    /// Switch happens at the given generation.
+    Generation(usize),
+    /// Switch happens when the best known solution is not improved during given amount of generations.
+    Stagnation(usize),
 }
 
 /// Contains population specific properties.
@@ -57,12 +80,7 @@ impl EvolutionConfig {
         Self {
             problem: problem.clone(),
             selection: Arc::new(NaiveSelection::new(get_cpus())),
-            mutation: Arc::new(NaiveBranching::new(
-                Arc::new(RuinAndRecreate::new_from_problem(problem)),
-                (0.0001, 0.1, 0.001),
-                1.5,
-                2..4,
-            )),
+            mutation: create_default_mutation(problem, None),
             termination: Arc::new(CompositeTermination::new(vec![
                 Box::new(MaxTime::new(300.)),
                 Box::new(MaxGeneration::new(3000)),
@@ -70,6 +88,11 @@ impl EvolutionConfig {
             quota: None,
             random: Arc::new(DefaultRandom::default()),
             telemetry: Telemetry::new(TelemetryMode::None),
+            phase_switch: None,
+            penalty_limit: None,
+            final_sweep: false,
+            profile: false,
+            construction_retries: 0,
             population: PopulationConfig {
                 max_size: 4,
                 initial: InitialConfig {
@@ -82,6 +105,9 @@ impl EvolutionConfig {
     }
 }
 
+/// A result of evolution: final population, telemetry metrics and termination report.
+pub type EvolutionResult = (Box<dyn Population>, Option<Metrics>, TerminationReport);
+
 /// An entity which simulates evolution process.
 pub struct EvolutionSimulator {
     config: EvolutionConfig,
@@ -105,37 +131,87 @@ impl EvolutionSimulator {
     }
 
     /// Runs evolution for given `problem` using evolution `config`.
-    /// Returns populations filled with solutions.
-    pub fn run(mut self) -> Result<(Box<dyn Population>, Option<Metrics>), String> {
+    /// Returns populations filled with solutions and a report about evolution termination.
+    pub fn run(mut self) -> Result<EvolutionResult, String> {
+        let evolution_time = Timer::start();
         self.config.telemetry.start();
 
-        let mut refinement_ctx = self.create_refinement_ctx()?;
+        let is_phased = self.config.phase_switch.is_some() && self.config.problem.objective.has_initial_phase();
+        let is_profiled = self.config.profile;
+        let mut timings = PhaseTimings::default();
+
+        let (refinement_ctx, elapsed) = measure(is_profiled, || self.create_refinement_ctx(is_phased));
+        let mut refinement_ctx = refinement_ctx?;
+        timings.construction += elapsed;
+        let mut last_improvement = refinement_ctx.statistics.generation;
+
+        let reason = loop {
+            if let Some(reason) = self.get_termination_reason(&mut refinement_ctx) {
+                break reason;
+            }
 
-        while !self.should_stop(&mut refinement_ctx) {
             let generation_time = Timer::start();
 
-            let parents = self.config.selection.select_parents(&refinement_ctx);
+            let (parents, elapsed) = measure(is_profiled, || self.config.selection.select_parents(&refinement_ctx));
+            timings.selection += elapsed;
 
-            let offspring = self.config.mutation.mutate_all(&refinement_ctx, parents);
+            let (offspring, elapsed) =
+                measure(is_profiled, || self.config.mutation.mutate_all(&refinement_ctx, parents));
+            timings.mutation += elapsed;
 
-            let is_improved =
-                if should_add_solution(&refinement_ctx) { refinement_ctx.population.add_all(offspring) } else { false };
+            let (is_improved, elapsed) = measure(is_profiled, || {
+                let offspring = offspring.into_iter().map(|ctx| self.limit_penalty(ctx)).collect();
+                if should_add_solution(&refinement_ctx) {
+                    refinement_ctx.population.add_all(offspring)
+                } else {
+                    false
+                }
+            });
+            timings.evaluation += elapsed;
 
             self.config.telemetry.on_generation(&mut refinement_ctx, generation_time, is_improved);
+
+            if is_improved {
+                last_improvement = refinement_ctx.statistics.generation;
+            }
+
+            if is_phased && self.should_switch_phase(&refinement_ctx, last_improvement) {
+                self.switch_phase(&mut refinement_ctx);
+            }
+        };
+
+        if is_phased {
+            self.switch_phase(&mut refinement_ctx);
+        }
+
+        if self.config.final_sweep {
+            self.sweep_unassigned(&mut refinement_ctx);
         }
 
         self.config.telemetry.on_result(&refinement_ctx);
 
-        Ok((refinement_ctx.population, self.config.telemetry.get_metrics()))
+        let duration = evolution_time.elapsed_secs_as_f64();
+        let phase_timings = if is_profiled {
+            let measured = timings.construction + timings.selection + timings.mutation + timings.evaluation;
+            Some(PhaseTimings { other: (duration - measured).max(0.), ..timings })
+        } else {
+            None
+        };
+
+        let report =
+            TerminationReport { reason, generations: refinement_ctx.statistics.generation, duration, phase_timings };
+
+        Ok((refinement_ctx.population, self.config.telemetry.get_metrics(), report))
     }
 
     /// Creates refinement context with population containing initial individuals.
-    fn create_refinement_ctx(&mut self) -> Result<RefinementContext, String> {
+    fn create_refinement_ctx(&mut self, is_phased: bool) -> Result<RefinementContext, String> {
         let mut refinement_ctx = RefinementContext::new(
             self.config.problem.clone(),
             Box::new(DominancePopulation::new(self.config.problem.clone(), self.config.population.max_size)),
             std::mem::replace(&mut self.config.quota, None),
         );
+        refinement_ctx.population.set_initial_phase(is_phased);
 
         self.config.telemetry.log(
             format!(
@@ -153,7 +229,7 @@ impl EvolutionSimulator {
             .for_each(|(ctx, idx)| {
                 if should_add_solution(&refinement_ctx) {
                     self.config.telemetry.on_initial(idx, self.config.population.initial.size, Timer::start());
-                    refinement_ctx.population.add(ctx);
+                    refinement_ctx.population.add(self.limit_penalty(ctx));
                 } else {
                     self.config.telemetry.log(format!("skipping provided initial solution {}", idx).as_str())
                 }
@@ -170,13 +246,10 @@ impl EvolutionSimulator {
                 return Err(());
             }
 
-            let method_idx = self.config.random.weighted(weights.as_slice());
-
-            let insertion_ctx =
-                self.config.population.initial.methods[method_idx].0.run(&refinement_ctx, empty_ctx.deep_copy());
+            let insertion_ctx = self.construct_solution(&refinement_ctx, &empty_ctx, weights.as_slice());
 
             if should_add_solution(&refinement_ctx) {
-                refinement_ctx.population.add(insertion_ctx);
+                refinement_ctx.population.add(self.limit_penalty(insertion_ctx));
                 self.config.telemetry.on_initial(idx, self.config.population.initial.size, item_time);
             } else {
                 self.config.telemetry.log(format!("skipping built initial solution {}", idx).as_str())
@@ -194,11 +267,150 @@ impl EvolutionSimulator {
         Ok(refinement_ctx)
     }
 
-    fn should_stop(&self, refinement_ctx: &mut RefinementContext) -> bool {
-        let is_terminated = self.config.termination.is_termination(refinement_ctx);
+    /// Builds initial solution using one of initial methods. When the solution has unassigned jobs,
+    /// construction is retried with shuffled job order up to configured amount of times and the
+    /// solution with the least amount of unassigned jobs is returned. On each retry, soft route
+    /// costs are relaxed further, so the last retry ignores them.
+    fn construct_solution(
+        &self,
+        refinement_ctx: &RefinementContext,
+        empty_ctx: &InsertionContext,
+        weights: &[usize],
+    ) -> InsertionContext {
+        let run_method = |insertion_ctx: InsertionContext| {
+            let method_idx = self.config.random.weighted(weights);
+            self.config.population.initial.methods[method_idx].0.run(refinement_ctx, insertion_ctx)
+        };
+
+        let mut best_ctx = run_method(empty_ctx.deep_copy());
+
+        for attempt in 1..=self.config.construction_retries {
+            if best_ctx.solution.unassigned.is_empty() {
+                break;
+            }
+
+            self.config.telemetry.log(
+                format!(
+                    "retrying construction ({} of {}): {} jobs are unassigned",
+                    attempt,
+                    self.config.construction_retries,
+                    best_ctx.solution.unassigned.len()
+                )
+                .as_str(),
+            );
+
+            let mut insertion_ctx = empty_ctx.deep_copy();
+            insertion_ctx.solution.required.shuffle(&mut insertion_ctx.random.get_rng());
+
+            let factor = 1. - attempt as f64 / self.config.construction_retries as f64;
+            insertion_ctx.problem = create_relaxed_problem(self.config.problem.as_ref(), factor);
+
+            let mut insertion_ctx = run_method(insertion_ctx);
+            insertion_ctx.problem = self.config.problem.clone();
+            if insertion_ctx.solution.unassigned.len() < best_ctx.solution.unassigned.len() {
+                best_ctx = insertion_ctx;
+            }
+        }
+
+        best_ctx
+    }
+
+    fn should_switch_phase(&self, refinement_ctx: &RefinementContext, last_improvement: usize) -> bool {
+        let generation = refinement_ctx.statistics.generation;
+
+        refinement_ctx.population.is_initial_phase()
+            && match self.config.phase_switch.as_ref() {
+                Some(PhaseSwitch::Generation(limit)) => generation >= *limit,
+                Some(PhaseSwitch::Stagnation(limit)) => generation - last_improvement >= *limit,
+                None => false,
+            }
+    }
+
+    fn switch_phase(&mut self, refinement_ctx: &mut RefinementContext) {
+        if refinement_ctx.population.is_initial_phase() {
+            refinement_ctx.population.set_initial_phase(false);
+
+            self.config.telemetry.log(
+                format!("switched to main objective phase at generation {}", refinement_ctx.statistics.generation)
+                    .as_str(),
+            );
+        }
+    }
+
+    /// Sheds jobs from solution when its total soft penalty exceeds the configured limit.
+    fn limit_penalty(&self, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        if let Some(limit) = self.config.penalty_limit {
+            apply_penalty_limit(&mut insertion_ctx, limit);
+        }
+
+        insertion_ctx
+    }
+
+    /// Tries to insert unassigned jobs of the best known solution. The result is added to population
+    /// only when it has less unassigned jobs and is not worse than the best known solution.
+    fn sweep_unassigned(&self, refinement_ctx: &mut RefinementContext) {
+        let best = match refinement_ctx.population.ranked().next() {
+            Some((insertion_ctx, _)) if !insertion_ctx.solution.unassigned.is_empty() => insertion_ctx,
+            _ => return,
+        };
+
+        let unassigned = best.solution.unassigned.len();
+        let (insertion_ctx, _) = apply_final_sweep(best.deep_copy());
+        let insertion_ctx = self.limit_penalty(insertion_ctx);
+
+        let is_improvement = insertion_ctx.solution.unassigned.len() < unassigned
+            && refinement_ctx.population.cmp(&insertion_ctx, best) != Ordering::Greater;
+
+        if is_improvement {
+            self.config.telemetry.log(
+                format!(
+                    "final sweep reduced unassigned jobs from {} to {}",
+                    unassigned,
+                    insertion_ctx.solution.unassigned.len()
+                )
+                .as_str(),
+            );
+            refinement_ctx.population.add(insertion_ctx);
+        }
+    }
+
+    /// Returns a reason to stop evolution or `None` if it should be continued.
+    fn get_termination_reason(&self, refinement_ctx: &mut RefinementContext) -> Option<TerminationReason> {
         let is_quota_reached = refinement_ctx.quota.as_ref().map_or(false, |q| q.is_reached());
 
-        is_terminated || is_quota_reached
+        let reason = self.config.termination.check_termination(refinement_ctx);
+
+        if reason.is_none() && is_quota_reached {
+            Some(TerminationReason::Cancelled)
+        } else {
+            reason
+        }
+    }
+}
+
+/// Creates a default mutation operator. When seed is specified, each ruin and recreate method
+/// uses its own random stream derived from it.
+pub(crate) fn create_default_mutation(problem: Arc<Problem>, seed: Option<u64>) -> Arc<dyn Mutation + Send + Sync> {
+    let ruin_recreate = if let Some(seed) = seed {
+        RuinAndRecreate::new(
+            Box::new(CompositeRecreate::new_from_problem(problem.clone()).with_random_streams(seed)),
+            Box::new(CompositeRuin::new_from_problem(problem).with_random_streams(seed)),
+        )
+    } else {
+        RuinAndRecreate::new_from_problem(problem)
+    };
+
+    Arc::new(NaiveBranching::new(Arc::new(ruin_recreate), (0.0001, 0.1, 0.001), 1.5, 2..4))
+}
+
+/// Runs given function and returns its result with elapsed time in seconds if measurement is enabled.
+fn measure<R, F: FnOnce() -> R>(is_enabled: bool, func: F) -> (R, f64) {
+    if is_enabled {
+        let timer = Timer::start();
+        let result = func();
+        (result, timer.elapsed_secs_as_f64())
+    } else {
+        (func(), 0.)
     }
 }
 
@@ -209,3 +421,20 @@ fn should_add_solution(refinement_ctx: &RefinementContext) -> bool {
     // NOTE when interrupted, population can return solution with worse primary objective fitness values as first
     is_population_empty || !is_quota_reached
 }
+
+/// Creates a copy of the problem where soft route costs are scaled by given factor.
+fn create_relaxed_problem(problem: &Problem, factor: f64) -> Arc<Problem> {
+    let mut extras = problem.extras.as_ref().clone();
+    extras.insert(SOFT_ROUTE_COST_FACTOR_KEY.to_string(), Arc::new(factor));
+
+    Arc::new(Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        constraint: problem.constraint.clone(),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        objective: problem.objective.clone(),
+        extras: Arc::new(extras),
+    })
+}
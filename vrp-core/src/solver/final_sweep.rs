@@ -0,0 +1,33 @@
+//! A module which provides the logic to insert unassigned jobs into the final solution.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/final_sweep_test.rs"]
+mod final_sweep_test;
+
+use crate::construction::heuristics::*;
+
+/// Tries to insert unassigned jobs into the solution using cheapest insertion: on each step, all
+/// unassigned jobs are evaluated in all routes, including routes of unused actors, and the job with
+/// the cheapest feasible insertion is inserted. Stops when no job can be inserted anymore.
+///
+/// Insertion evaluation respects all constraints, so the solution stays feasible. Jobs which still
+/// cannot be inserted are reported as unassigned with the latest constraint violation code.
+/// Returns amount of inserted jobs.
+pub fn apply_final_sweep(insertion_ctx: InsertionContext) -> (InsertionContext, usize) {
+    let unassigned = insertion_ctx.solution.unassigned.len();
+
+    if unassigned == 0 {
+        return (insertion_ctx, 0);
+    }
+
+    let insertion_ctx = InsertionHeuristic::default().process(
+        &AllRouteSelector::default(),
+        &AllJobSelector::default(),
+        &PairJobMapReducer::new(Box::new(BestResultSelector::default())),
+        insertion_ctx,
+        &None,
+    );
+    let inserted = unassigned - insertion_ctx.solution.unassigned.len();
+
+    (insertion_ctx, inserted)
+}
@@ -0,0 +1,176 @@
+//! A module which provides the logic to improve a solution by moving jobs between routes.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/inter_route_test.rs"]
+mod inter_route_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job};
+use crate::solver::mutation::get_routes_cost_savings;
+use crate::utils::compare_floats;
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Tries to reduce total cost of the solution by moving jobs between pairs of routes. Two kinds of
+/// moves are evaluated: relocate, which moves one job to the best position in another route, and
+/// exchange, which swaps two jobs of different routes. Each moved job is inserted using cheapest
+/// insertion which respects all constraints, so the solution stays feasible.
+///
+/// Moves are ranked by their estimated cost change: removal savings of moved jobs and their
+/// insertion costs are evaluated against current routes, so no route is copied for that. Then,
+/// starting from the most promising one, moves are applied to a copy of the solution until one
+/// of them actually reduces total cost. Routes left without jobs are removed from the solution.
+///
+/// Returns false and keeps the solution intact if no move reduces total cost. Locked jobs are
+/// not moved.
+pub fn inter_route_improve(insertion_ctx: &mut InsertionContext) -> bool {
+    let routes = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| {
+            let jobs = route_ctx.route.tour.jobs().filter(|job| !insertion_ctx.solution.locked.contains(job));
+            (route_ctx.route.actor.clone(), jobs.collect::<Vec<_>>())
+        })
+        .collect::<Vec<_>>();
+    let routes = &routes;
+
+    let pairs = (0..routes.len()).flat_map(|from| (0..routes.len()).map(move |to| (from, to)));
+
+    let relocates = pairs
+        .clone()
+        .filter(|(from, to)| from != to)
+        .flat_map(|(from, to)| routes[from].1.iter().map(move |job| vec![(job.clone(), routes[to].0.clone())]));
+
+    let exchanges = pairs.filter(|(from, to)| from < to).flat_map(|(from, to)| {
+        routes[from].1.iter().flat_map(move |first| {
+            routes[to].1.iter().map(move |second| {
+                vec![(first.clone(), routes[to].0.clone()), (second.clone(), routes[from].0.clone())]
+            })
+        })
+    });
+
+    let savings = get_removal_savings(insertion_ctx);
+    let mut candidates = relocates
+        .chain(exchanges)
+        .filter_map(|moves| estimate_move(insertion_ctx, &savings, &moves).map(|cost| (moves, cost)))
+        .filter(|(_, cost)| compare_floats(*cost, 0.) == Ordering::Less)
+        .collect::<Vec<_>>();
+    candidates.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+    let original_cost = insertion_ctx.solution.get_total_cost();
+
+    let best = candidates
+        .into_iter()
+        .filter_map(|(moves, _)| try_move(insertion_ctx, moves))
+        .find(|new_ctx| compare_floats(new_ctx.solution.get_total_cost(), original_cost) == Ordering::Less);
+
+    match best {
+        Some(best) => {
+            *insertion_ctx = best;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Removes given jobs from their routes and inserts each of them into the route of target actor.
+/// Routes left without jobs are removed and their actors are returned to the registry.
+/// Returns `None` if any job cannot be inserted there.
+pub(crate) fn try_move(insertion_ctx: &InsertionContext, moves: Vec<(Job, Arc<Actor>)>) -> Option<InsertionContext> {
+    let mut new_ctx = insertion_ctx.deep_copy();
+    let unassigned = new_ctx.solution.unassigned.drain().collect::<Vec<_>>();
+    let constraint = new_ctx.problem.constraint.clone();
+
+    new_ctx.solution.routes.iter_mut().for_each(|route_ctx| {
+        let is_changed =
+            moves.iter().fold(false, |is_changed, (job, _)| route_ctx.route_mut().tour.remove(job) || is_changed);
+        if is_changed {
+            constraint.accept_route_state(route_ctx);
+        }
+    });
+    new_ctx.solution.required.extend(moves.iter().map(|(job, _)| job.clone()));
+
+    let selector = TargetRouteSelector { targets: moves.iter().cloned().collect() };
+    let mut new_ctx = InsertionHeuristic::default().process(
+        &selector,
+        &AllJobSelector::default(),
+        &PairJobMapReducer::new(Box::new(BestResultSelector::default())),
+        new_ctx,
+        &None,
+    );
+
+    let is_moved = new_ctx.solution.unassigned.is_empty()
+        && moves.iter().all(|(job, actor)| {
+            new_ctx
+                .solution
+                .routes
+                .iter()
+                .any(|route_ctx| route_ctx.route.actor == *actor && route_ctx.route.tour.contains(job))
+        });
+
+    if is_moved {
+        let registry = &mut new_ctx.solution.registry;
+        new_ctx.solution.routes.retain(|route_ctx| {
+            if route_ctx.route.tour.has_jobs() {
+                true
+            } else {
+                registry.free_route(route_ctx);
+                false
+            }
+        });
+        new_ctx.solution.unassigned.extend(unassigned);
+        Some(new_ctx)
+    } else {
+        None
+    }
+}
+
+/// Returns cost savings from removing each job from its route. For a job which is the only one
+/// in its route, the whole route cost is saved.
+fn get_removal_savings(insertion_ctx: &InsertionContext) -> HashMap<Job, Cost> {
+    get_routes_cost_savings(insertion_ctx)
+        .into_iter()
+        .flat_map(|(route_ctx, savings)| {
+            let route_cost = if savings.len() == 1 { Some(route_ctx.get_route_cost()) } else { None };
+            savings.into_iter().map(move |(job, cost)| (job, route_cost.unwrap_or(cost)))
+        })
+        .collect()
+}
+
+/// Estimates total cost change of given moves using current routes: insertion cost of each job
+/// into the route of target actor minus its removal savings.
+/// Returns `None` if any job cannot be inserted into the current route of target actor.
+fn estimate_move(
+    insertion_ctx: &InsertionContext,
+    savings: &HashMap<Job, Cost>,
+    moves: &[(Job, Arc<Actor>)],
+) -> Option<Cost> {
+    moves.iter().try_fold(0., |total, (job, actor)| {
+        let route_ctx = insertion_ctx.solution.routes.iter().find(|route_ctx| route_ctx.route.actor == *actor)?;
+
+        match evaluate_job_insertion_in_route(job, insertion_ctx, route_ctx, InsertionPosition::Any, None) {
+            InsertionResult::Success(success) => Some(total + success.cost - savings.get(job).cloned().unwrap_or(0.)),
+            InsertionResult::Failure(_) => None,
+        }
+    })
+}
+
+/// Selects the route of target actor for each moved job.
+struct TargetRouteSelector {
+    targets: HashMap<Job, Arc<Actor>>,
+}
+
+impl RouteSelector for TargetRouteSelector {
+    fn select<'a>(&'a self, ctx: &'a InsertionContext, job: &'a Job) -> Box<dyn Iterator<Item = RouteContext> + 'a> {
+        Box::new(
+            self.targets
+                .get(job)
+                .and_then(|actor| ctx.solution.routes.iter().find(|route_ctx| route_ctx.route.actor == *actor))
+                .cloned()
+                .into_iter(),
+        )
+    }
+}
@@ -0,0 +1,154 @@
+//! A module which provides the logic to estimate a simple lower bound of solution cost.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/lower_bound_test.rs"]
+mod lower_bound_test;
+
+use crate::construction::constraints::COST_CAP_DISCOUNT_KEY;
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::{Cost, Location, Profile};
+use crate::models::problem::{Actor, Job, Place, Single};
+use crate::models::Problem;
+use hashbrown::HashMap;
+use std::sync::Arc;
+
+/// Returns a lower bound of the total cost for jobs assigned in the given solution.
+///
+/// The bound is a relaxation where each job is reached by its cheapest incoming leg from any other
+/// known location and served with the cheapest rates, plus the cheapest fixed cost for each used route.
+/// Waiting time is not included. A route estimate is limited by its usage cost after cost cap discount,
+/// so, the bound is never higher than the actual solution cost.
+///
+/// NOTE time dependent routing is not considered: transport costs are taken at zero departure time.
+pub fn lower_bound(insertion_ctx: &InsertionContext) -> Cost {
+    let problem = insertion_ctx.problem.as_ref();
+    let routes =
+        insertion_ctx.solution.routes.iter().filter(|route_ctx| route_ctx.route.tour.has_jobs()).collect::<Vec<_>>();
+
+    if routes.is_empty() {
+        return 0.;
+    }
+
+    let actors = get_actor_costs(problem.fleet.actors.as_slice());
+    let locations = get_location_counts(problem);
+
+    let fixed = problem
+        .fleet
+        .actors
+        .iter()
+        .map(|actor| actor.vehicle.costs.fixed + actor.driver.costs.fixed)
+        .fold(f64::MAX, |acc, fixed| acc.min(fixed));
+
+    routes
+        .iter()
+        .map(|route_ctx| {
+            let jobs_cost = route_ctx
+                .route
+                .tour
+                .jobs()
+                .flat_map(|job| match job {
+                    Job::Single(single) => vec![single],
+                    Job::Multi(multi) => multi.jobs.clone(),
+                })
+                .map(|single| get_single_cost(problem, single.as_ref(), &actors, &locations))
+                .sum::<Cost>();
+
+            // NOTE usage cost above the cap is not paid, so the route estimate cannot exceed it
+            let discount = route_ctx.state.get_route_state::<f64>(COST_CAP_DISCOUNT_KEY).cloned().unwrap_or(0.);
+
+            (fixed + jobs_cost).min(route_ctx.get_route_usage_cost() - discount)
+        })
+        .sum()
+}
+
+/// Minimum cost per distance, driving time and service time unit for each profile.
+type ActorCosts = Vec<(Profile, Cost, Cost, Cost)>;
+
+fn get_actor_costs(actors: &[Arc<Actor>]) -> ActorCosts {
+    // NOTE each cost component uses its own minimum rate, waiting time is not included as it can be avoided
+    actors
+        .iter()
+        .map(|actor| {
+            let vehicle = &actor.vehicle.costs;
+            let driver = &actor.driver.costs;
+
+            (
+                actor.vehicle.profile,
+                vehicle.per_distance + driver.per_distance,
+                vehicle.per_driving_time + driver.per_driving_time,
+                vehicle.per_service_time + driver.per_service_time,
+            )
+        })
+        .fold(HashMap::<Profile, (Cost, Cost, Cost)>::new(), |mut acc, (profile, distance, driving, service)| {
+            let entry = acc.entry(profile).or_insert((distance, driving, service));
+            *entry = (entry.0.min(distance), entry.1.min(driving), entry.2.min(service));
+            acc
+        })
+        .into_iter()
+        .map(|(profile, (distance, driving, service))| (profile, distance, driving, service))
+        .collect()
+}
+
+fn get_location_counts(problem: &Problem) -> HashMap<Location, usize> {
+    let job_locations = problem
+        .jobs
+        .all()
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single],
+            Job::Multi(multi) => multi.jobs.clone(),
+        })
+        .flat_map(|single| single.places.iter().filter_map(|place| place.location).collect::<Vec<_>>());
+
+    let actor_locations =
+        problem.fleet.actors.iter().filter_map(|actor| actor.detail.start.as_ref().map(|start| start.location));
+
+    job_locations.chain(actor_locations).fold(HashMap::new(), |mut acc, location| {
+        *acc.entry(location).or_insert(0) += 1;
+        acc
+    })
+}
+
+fn get_single_cost(
+    problem: &Problem,
+    single: &Single,
+    actors: &ActorCosts,
+    locations: &HashMap<Location, usize>,
+) -> Cost {
+    single
+        .places
+        .iter()
+        .map(|place| {
+            actors
+                .iter()
+                .map(|&(profile, per_distance, per_driving, per_service)| {
+                    per_service * place.duration
+                        + get_arrival_cost(problem, place, profile, per_distance, per_driving, locations)
+                })
+                .fold(f64::MAX, |acc, cost| acc.min(cost))
+        })
+        .fold(f64::MAX, |acc, cost| acc.min(cost))
+}
+
+fn get_arrival_cost(
+    problem: &Problem,
+    place: &Place,
+    profile: Profile,
+    per_distance: Cost,
+    per_driving: Cost,
+    locations: &HashMap<Location, usize>,
+) -> Cost {
+    place.location.map_or(0., |to| {
+        locations
+            .iter()
+            // NOTE the same location can be used as a source only when it is shared with other job or vehicle
+            .filter(|(&from, &count)| from != to || count > 1)
+            .map(|(&from, _)| {
+                (problem.transport.distance(profile, from, to, 0.), problem.transport.duration(profile, from, to, 0.))
+            })
+            // NOTE negative values are used to mark unreachable locations
+            .filter(|&(distance, duration)| distance >= 0. && duration >= 0.)
+            .map(|(distance, duration)| per_distance * distance + per_driving * duration)
+            .fold(None, |acc: Option<Cost>, cost| Some(acc.map_or(cost, |acc| acc.min(cost))))
+            .unwrap_or(0.)
+    })
+}
@@ -108,15 +108,51 @@ pub mod termination;
 mod builder;
 pub use self::builder::Builder;
 
+mod diversity;
+pub use self::diversity::{assignment_distance, select_diverse};
+
 mod evolution;
+pub use self::evolution::PhaseSwitch;
 use self::evolution::{EvolutionConfig, EvolutionSimulator};
 
+mod final_sweep;
+pub use self::final_sweep::apply_final_sweep;
+
+mod inter_route;
+pub use self::inter_route::inter_route_improve;
+
+mod lower_bound;
+pub use self::lower_bound::lower_bound;
+
+mod penalty_limit;
+pub use self::penalty_limit::{apply_penalty_limit, PENALTY_LIMIT_CODE};
+
 mod population;
 pub use self::population::DominancePopulation;
 
+mod reachable_jobs;
+pub use self::reachable_jobs::{analyze_usability, reachable_jobs, UsabilityReport, ISOLATED_CONSTRAINT_CODES_KEY};
+
+mod removal_savings;
+pub use self::removal_savings::removal_savings;
+
+mod route_split;
+pub use self::route_split::split_route;
+
+mod schedule;
+pub use self::schedule::{create_scheduled_solution, SCHEDULE_ONLY_KEY};
+
+mod service_times;
+pub use self::service_times::{
+    estimate_schedule_feasibility, DurationDistribution, ScheduleFeasibility, DURATION_DISTRIBUTION_KEY,
+};
+
 mod telemetry;
 pub use self::telemetry::{Metrics, Telemetry, TelemetryMode};
 
+mod worst_route;
+pub use self::worst_route::improve_worst_route;
+
 use std::cmp::Ordering;
 
 /// A key to store solution order information.
@@ -176,6 +212,15 @@ pub trait Population {
 
     /// Returns population size.
     fn size(&self) -> usize;
+
+    /// Sets whether the initial phase of search is active: individuals are compared using initial
+    /// phase objectives of the problem first. Default implementation ignores phases.
+    fn set_initial_phase(&mut self, _is_initial_phase: bool) {}
+
+    /// Checks whether the initial phase of search is active. Default implementation has no initial phase.
+    fn is_initial_phase(&self) -> bool {
+        false
+    }
 }
 
 impl RefinementContext {
@@ -205,7 +250,10 @@ pub struct Solver {
 
 impl Solver {
     /// Solves a Vehicle Routing Problem and returns a _(solution, its cost)_ pair in case of success
-    /// or error description, if solution cannot be found.
+    /// or error description, if solution cannot be found. A [`TerminationReport`] is stored in
+    /// solution's extras under `termination` key.
+    ///
+    /// [`TerminationReport`]: ./termination/struct.TerminationReport.html
     ///
     /// # Examples
     ///
@@ -231,13 +279,47 @@ impl Solver {
     /// # Ok::<(), String>(())
     /// ```
     pub fn solve(self) -> Result<(Solution, Cost, Option<Metrics>), String> {
-        let (population, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+        let (population, metrics, report) = EvolutionSimulator::new(self.config)?.run()?;
+
+        let mut extras = self.problem.extras.as_ref().clone();
+        extras.insert("termination".to_string(), Arc::new(report));
 
         // NOTE select the first best individual from population
         let (insertion_ctx, _) = population.ranked().next().ok_or_else(|| "cannot find any solution".to_string())?;
-        let solution = insertion_ctx.solution.to_solution(self.problem.extras.clone());
+        let solution = insertion_ctx.solution.to_solution(Arc::new(extras));
         let cost = self.problem.objective.fitness(insertion_ctx);
 
         Ok((solution, cost, metrics))
     }
+
+    /// Solves a Vehicle Routing Problem and returns up to `k` best solutions from final population
+    /// which are pairwise different at least by `min_distance` job assignments, see
+    /// [`assignment_distance`]. The first solution is the same as returned by [`solve`].
+    /// NOTE amount of returned solutions is limited by population size.
+    ///
+    /// [`assignment_distance`]: ./fn.assignment_distance.html
+    /// [`solve`]: #method.solve
+    pub fn solve_k_diverse(self, k: usize, min_distance: usize) -> Result<Vec<(Solution, Cost)>, String> {
+        let (population, _, report) = EvolutionSimulator::new(self.config)?.run()?;
+
+        let mut extras = self.problem.extras.as_ref().clone();
+        extras.insert("termination".to_string(), Arc::new(report));
+        let extras = Arc::new(extras);
+
+        let objective = self.problem.objective.as_ref();
+        let solutions = population
+            .ranked()
+            .map(|(insertion_ctx, _)| {
+                (insertion_ctx.solution.to_solution(extras.clone()), objective.fitness(insertion_ctx))
+            })
+            .collect();
+
+        let solutions = select_diverse(solutions, k, min_distance);
+
+        if solutions.is_empty() {
+            Err("cannot find any solution".to_string())
+        } else {
+            Ok(solutions)
+        }
+    }
 }
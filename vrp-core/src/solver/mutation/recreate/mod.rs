@@ -1,6 +1,10 @@
 //! The recreate module contains logic to build a feasible solution from partially ruined.
 
-use crate::construction::heuristics::InsertionContext;
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/recreate/recreate_test.rs"]
+mod recreate_test;
+
+use crate::construction::heuristics::*;
 use crate::solver::RefinementContext;
 
 /// A trait which specifies logic to produce a new feasible solution from partial one.
@@ -25,6 +29,7 @@ mod recreate_with_nearest_neighbor;
 pub use self::recreate_with_nearest_neighbor::*;
 use crate::models::common::SingleDimLoad;
 use crate::models::Problem;
+use crate::utils::{Random, StreamRandom};
 use std::sync::Arc;
 
 /// Provides the way to run one of multiple recreate methods with different probability.
@@ -54,6 +59,55 @@ impl CompositeRecreate {
             (Box::new(RecreateWithNearestNeighbor::default()), 1),
         ])
     }
+
+    /// Makes each recreate method to use its own random stream derived from the given master seed
+    /// and method's position, so changes in one method do not affect random values drawn by others.
+    /// NOTE values are reproducible only when mutation is not run in parallel.
+    pub fn with_random_streams(self, seed: u64) -> Self {
+        let recreates = self
+            .recreates
+            .into_iter()
+            .enumerate()
+            .map(|(idx, recreate)| {
+                let key = format!("recreate:{}", idx);
+                let recreate: Box<dyn Recreate + Send + Sync> =
+                    Box::new(StreamRecreate { recreate, random: Arc::new(StreamRandom::new(seed, key.as_str())) });
+
+                recreate
+            })
+            .collect();
+
+        Self { recreates, weights: self.weights }
+    }
+}
+
+/// A recreate method which uses its own random stream.
+struct StreamRecreate {
+    recreate: Box<dyn Recreate + Send + Sync>,
+    random: Arc<dyn Random + Send + Sync>,
+}
+
+impl Recreate for StreamRecreate {
+    fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let random = std::mem::replace(&mut insertion_ctx.random, self.random.clone());
+
+        let mut insertion_ctx = self.recreate.run(refinement_ctx, insertion_ctx);
+        insertion_ctx.random = random;
+
+        insertion_ctx
+    }
+}
+
+/// Inserts all required jobs into given solution using cheapest insertion heuristic. Jobs which
+/// cannot be inserted are moved to unassigned.
+pub fn reinsert_all(insertion_ctx: InsertionContext) -> InsertionContext {
+    InsertionHeuristic::default().process(
+        &AllRouteSelector::default(),
+        &AllJobSelector::default(),
+        &PairJobMapReducer::new(Box::new(BestResultSelector::default())),
+        insertion_ctx,
+        &None,
+    )
 }
 
 impl Recreate for CompositeRecreate {
@@ -1,11 +1,15 @@
 //! The ruin module contains various strategies to destroy small, medium or large parts of an
 //! existing solution.
 
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/ruin/ruin_test.rs"]
+mod ruin_test;
+
 use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
 use crate::models::problem::Job;
 use crate::models::Problem;
 use crate::solver::RefinementContext;
-use crate::utils::Random;
+use crate::utils::{Random, StreamRandom};
 use hashbrown::HashMap;
 use std::iter::{empty, once};
 use std::sync::Arc;
@@ -33,6 +37,11 @@ pub use self::random_job_removal::RandomJobRemoval;
 
 mod worst_jobs_removal;
 pub use self::worst_jobs_removal::WorstJobRemoval;
+pub(crate) use self::worst_jobs_removal::get_routes_cost_savings;
+
+mod worst_route_removal;
+pub use self::worst_route_removal::WorstRouteRemoval;
+pub(crate) use self::worst_route_removal::{get_route_neighbours, get_worst_route};
 
 /// A type which specifies a group of multiple ruin strategies with its probability.
 pub type RuinGroup = (Vec<(Arc<dyn Ruin + Send + Sync>, f64)>, usize);
@@ -120,6 +129,34 @@ impl CompositeRuin {
     }
 }
 
+impl CompositeRuin {
+    /// Makes each ruin method to use its own random stream derived from the given master seed
+    /// and method's position, so changes in one method do not affect random values drawn by others.
+    /// NOTE values are reproducible only when mutation is not run in parallel.
+    pub fn with_random_streams(self, seed: u64) -> Self {
+        let ruins = self
+            .ruins
+            .into_iter()
+            .enumerate()
+            .map(|(group_idx, ruins)| {
+                ruins
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ruin_idx, (ruin, probability))| {
+                        let key = format!("ruin:{}:{}", group_idx, ruin_idx);
+                        let ruin: Arc<dyn Ruin + Send + Sync> =
+                            Arc::new(StreamRuin { ruin, random: Arc::new(StreamRandom::new(seed, key.as_str())) });
+
+                        (ruin, probability)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self { ruins, weights: self.weights }
+    }
+}
+
 impl Ruin for CompositeRuin {
     fn run(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
         if insertion_ctx.solution.routes.is_empty() {
@@ -144,6 +181,39 @@ impl Ruin for CompositeRuin {
     }
 }
 
+/// A ruin method which uses its own random stream.
+struct StreamRuin {
+    ruin: Arc<dyn Ruin + Send + Sync>,
+    random: Arc<dyn Random + Send + Sync>,
+}
+
+impl Ruin for StreamRuin {
+    fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let random = std::mem::replace(&mut insertion_ctx.random, self.random.clone());
+
+        let mut insertion_ctx = self.ruin.run(refinement_ctx, insertion_ctx);
+        insertion_ctx.random = random;
+
+        insertion_ctx
+    }
+}
+
+/// Removes given jobs from solution and marks them as required, so they can be inserted back
+/// by recreate method. Locked jobs and jobs which are not assigned are kept as is.
+pub fn remove_jobs(insertion_ctx: &mut InsertionContext, jobs: &[Job]) {
+    let solution = &mut insertion_ctx.solution;
+
+    jobs.iter().for_each(|job| {
+        if !solution.locked.contains(job)
+            && solution.routes.iter_mut().any(|route_ctx| route_ctx.route_mut().tour.remove(job))
+        {
+            solution.required.push(job.clone());
+        }
+    });
+
+    insertion_ctx.restore();
+}
+
 fn get_removal_chunk_size(ctx: &InsertionContext, limit: &JobRemovalLimit) -> usize {
     let assigned = ctx.problem.jobs.size() - ctx.solution.unassigned.len() - ctx.solution.ignored.len();
 
@@ -93,7 +93,7 @@ impl Ruin for WorstJobRemoval {
     }
 }
 
-fn get_routes_cost_savings(insertion_ctx: &InsertionContext) -> Vec<(RouteContext, Vec<(Job, Cost)>)> {
+pub(crate) fn get_routes_cost_savings(insertion_ctx: &InsertionContext) -> Vec<(RouteContext, Vec<(Job, Cost)>)> {
     parallel_collect(&insertion_ctx.solution.routes, |rc| {
         let actor = rc.route.actor.as_ref();
         let mut savings: Vec<(Job, Cost)> = rc
@@ -0,0 +1,120 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/ruin/worst_route_removal_test.rs"]
+mod worst_route_removal_test;
+
+use super::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::models::common::{Cost, Timestamp};
+use crate::models::problem::Job;
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+use hashbrown::HashSet;
+
+/// A ruin strategy which removes all jobs from the worst route: the one with the highest cost per
+/// job. Optionally, the closest jobs served by other routes are removed too, so recreate method can
+/// re-sequence the worst route and swap jobs with its neighbours while other routes are kept as is.
+pub struct WorstRouteRemoval {
+    /// Specifies maximum amount of removed jobs from neighbour routes.
+    neighbours: usize,
+}
+
+impl WorstRouteRemoval {
+    /// Creates a new instance of `WorstRouteRemoval`.
+    pub fn new(neighbours: usize) -> Self {
+        Self { neighbours }
+    }
+}
+
+impl Default for WorstRouteRemoval {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl Ruin for WorstRouteRemoval {
+    fn run(&self, _refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let route_ctx = if let Some(route_ctx) = get_worst_route(&insertion_ctx) {
+            route_ctx
+        } else {
+            return insertion_ctx;
+        };
+
+        let locked = &insertion_ctx.solution.locked;
+        let route_jobs = route_ctx.route.tour.jobs().filter(|job| !locked.contains(job)).collect::<Vec<_>>();
+        let neighbours = get_route_neighbours(&insertion_ctx, route_ctx, self.neighbours);
+
+        let jobs = route_jobs.into_iter().chain(neighbours).collect::<Vec<_>>();
+
+        remove_jobs(&mut insertion_ctx, jobs.as_slice());
+
+        insertion_ctx
+    }
+}
+
+/// Returns the route with the highest cost per job which can be removed, if any.
+pub(crate) fn get_worst_route(insertion_ctx: &InsertionContext) -> Option<&RouteContext> {
+    let locked = &insertion_ctx.solution.locked;
+
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .filter_map(|route_ctx| get_cost_per_job(route_ctx, locked).map(|cost| (route_ctx, cost)))
+        .max_by(|(_, a), (_, b)| compare_floats(*a, *b))
+        .map(|(route_ctx, _)| route_ctx)
+}
+
+/// Returns up to `limit` closest jobs to the given route which are served by other routes and not locked.
+pub(crate) fn get_route_neighbours(
+    insertion_ctx: &InsertionContext,
+    route_ctx: &RouteContext,
+    limit: usize,
+) -> Vec<Job> {
+    if limit == 0 {
+        return vec![];
+    }
+
+    let locked = &insertion_ctx.solution.locked;
+    // NOTE jobs are taken in tour order to have a stable order of neighbours with the same cost
+    let route_jobs =
+        route_ctx.route.tour.all_activities().filter_map(|activity| activity.retrieve_job()).collect::<Vec<_>>();
+    let other_jobs = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .filter(|rc| *rc != route_ctx)
+        .flat_map(|rc| rc.route.tour.jobs())
+        .filter(|job| !locked.contains(job))
+        .collect::<HashSet<_>>();
+
+    // NOTE the closest neighbours of a job are usually served by the same route, so the walk is
+    // capped by route size plus limit: enough to find the closest jobs of other routes
+    let walk_limit = route_ctx.route.tour.job_count() + limit;
+
+    let profile = route_ctx.route.actor.vehicle.profile;
+    let mut neighbours = route_jobs
+        .iter()
+        .flat_map(|job| insertion_ctx.problem.jobs.neighbors(profile, job, Timestamp::default()).take(walk_limit))
+        .filter(|(job, _)| other_jobs.contains(job))
+        .map(|(job, cost)| (job.clone(), *cost))
+        .collect::<Vec<_>>();
+    neighbours.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+    neighbours.into_iter().fold(Vec::<Job>::new(), |mut acc, (job, _)| {
+        if acc.len() < limit && !acc.contains(&job) {
+            acc.push(job);
+        }
+        acc
+    })
+}
+
+/// Returns route cost per job which can be removed, or none if there are no such jobs.
+fn get_cost_per_job(route_ctx: &RouteContext, locked: &HashSet<Job>) -> Option<Cost> {
+    let jobs = route_ctx.route.tour.jobs().filter(|job| !locked.contains(job)).count();
+
+    if jobs > 0 {
+        Some(route_ctx.get_route_cost() / jobs as f64)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,121 @@
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::{Distance, Load};
+use crate::models::problem::{Job, TargetConstraint, TargetObjective, TransportCost};
+use crate::solver::objectives::*;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A type which provides functionality needed to minimize empty running.
+pub struct DeadheadDistance {}
+
+impl DeadheadDistance {
+    /// Creates _(constraint, objective)_  type pair which minimizes deadhead distance: a distance
+    /// driven without any load, e.g. from depot to the first pickup or from the last delivery back
+    /// to depot. Load is taken from the state maintained by capacity constraint module.
+    pub fn new_minimized<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+    ) -> (TargetConstraint, TargetObjective) {
+        let objective = DeadheadDistanceObjective::<T> { transport, phantom: PhantomData };
+
+        let constraint = DeadheadDistanceModule {
+            objective: objective.clone(),
+            constraints: vec![],
+            keys: vec![DEADHEAD_DISTANCE_KEY],
+        };
+
+        (Box::new(constraint), Box::new(objective))
+    }
+}
+
+struct DeadheadDistanceModule<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    objective: DeadheadDistanceObjective<T>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> ConstraintModule for DeadheadDistanceModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let distance = self.objective.get_deadhead_distance(ctx);
+        ctx.state_mut().put_route_state(DEADHEAD_DISTANCE_KEY, distance);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct DeadheadDistanceObjective<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> Clone for DeadheadDistanceObjective<T> {
+    fn clone(&self) -> Self {
+        Self { transport: self.transport.clone(), phantom: PhantomData }
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> DeadheadDistanceObjective<T> {
+    /// Replays load profile of the route and sums distances of legs which start with empty load.
+    fn get_deadhead_distance(&self, route_ctx: &RouteContext) -> Distance {
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let activities = route_ctx.route.tour.all_activities().collect::<Vec<_>>();
+
+        activities
+            .windows(2)
+            .filter(|leg| {
+                // NOTE compare with default as multi dimensional default load is not considered as empty
+                let load = route_ctx.state.get_activity_state::<T>(CURRENT_CAPACITY_KEY, leg[0]);
+                load.map_or(true, |load| *load == T::default())
+            })
+            .map(|leg| {
+                let (from, to) = (leg[0], leg[1]);
+                self.transport.distance(profile, from.place.location, to.place.location, from.schedule.departure)
+            })
+            .sum()
+    }
+
+    /// Returns deadhead distance from the route state or calculates it when the state is missing.
+    fn get_cached_deadhead_distance(&self, route_ctx: &RouteContext) -> Distance {
+        // NOTE state is missing when the objective is used without its module, e.g. as tie-break
+        route_ctx
+            .state
+            .get_route_state::<Distance>(DEADHEAD_DISTANCE_KEY)
+            .cloned()
+            .unwrap_or_else(|| self.get_deadhead_distance(route_ctx))
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> Objective for DeadheadDistanceObjective<T> {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| self.get_cached_deadhead_distance(route_ctx)).sum()
+    }
+}
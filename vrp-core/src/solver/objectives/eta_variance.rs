@@ -0,0 +1,169 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/eta_variance_test.rs"]
+mod eta_variance_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext, SolutionContext};
+use crate::models::problem::{Job, Single, TargetConstraint, TargetObjective, TransportCost};
+use crate::models::solution::Activity;
+use crate::solver::objectives::*;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies a function which returns true for jobs with service start times to be considered.
+pub type EtaJobFilter = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+
+/// Statistics of service start times: amount, mean and sum of squared deviations from the mean.
+type EtaStatistics = (usize, f64, f64);
+
+/// A type which provides functionality needed to keep customers served at similar times.
+pub struct EtaVariance {}
+
+impl EtaVariance {
+    /// Creates _(constraint, objective)_  type pair which minimizes variance of estimated service
+    /// start times (ETA) of all job activities in the solution, so customers are served at similar
+    /// times regardless of their tours. Jobs rejected by filter, e.g. vehicle breaks, are ignored.
+    pub fn new_minimized(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        job_filter: EtaJobFilter,
+    ) -> (TargetConstraint, TargetObjective) {
+        let constraint = EtaVarianceModule {
+            job_filter: job_filter.clone(),
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(EtaVarianceSoftActivityConstraint {
+                transport,
+                job_filter: job_filter.clone(),
+            }))],
+            keys: vec![ETA_VARIANCE_KEY],
+        };
+
+        (Box::new(constraint), Box::new(EtaVarianceObjective { job_filter }))
+    }
+}
+
+struct EtaVarianceModule {
+    job_filter: EtaJobFilter,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for EtaVarianceModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let statistics = get_solution_statistics(ctx, &self.job_filter);
+
+        // NOTE solution statistics are kept in each route state as soft activity constraint has no
+        // access to the solution context, available routes need them to estimate insertion too
+        ctx.routes.iter_mut().for_each(|route_ctx| route_ctx.state_mut().put_route_state(ETA_VARIANCE_KEY, statistics));
+        ctx.registry.modify_available(|route_ctx| route_ctx.state_mut().put_route_state(ETA_VARIANCE_KEY, statistics));
+        ctx.state.insert(ETA_VARIANCE_KEY, Arc::new(statistics));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct EtaVarianceSoftActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    job_filter: EtaJobFilter,
+}
+
+impl SoftActivityConstraint for EtaVarianceSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        if !is_considered(target, &self.job_filter) {
+            return 0.;
+        }
+
+        let statistics =
+            route_ctx.state.get_route_state::<EtaStatistics>(ETA_VARIANCE_KEY).cloned().unwrap_or_default();
+
+        let departure = prev.schedule.departure;
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let arrival =
+            departure + self.transport.duration(profile, prev.place.location, target.place.location, departure);
+        let eta = arrival.max(target.place.time.start);
+
+        // NOTE change of root of squared deviations sum is used as it is measured in time units and
+        // grows with amount of jobs as other costs do
+        add_eta(statistics, eta).2.sqrt() - statistics.2.sqrt()
+    }
+}
+
+struct EtaVarianceObjective {
+    job_filter: EtaJobFilter,
+}
+
+impl Objective for EtaVarianceObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        // NOTE state is missing when the objective is used without its module, e.g. as tie-break
+        let statistics = solution
+            .solution
+            .state
+            .get(&ETA_VARIANCE_KEY)
+            .and_then(|statistics| statistics.downcast_ref::<EtaStatistics>())
+            .cloned()
+            .unwrap_or_else(|| get_solution_statistics(&solution.solution, &self.job_filter));
+
+        get_variance(statistics)
+    }
+}
+
+fn get_solution_statistics(solution_ctx: &SolutionContext, job_filter: &EtaJobFilter) -> EtaStatistics {
+    solution_ctx
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.all_activities())
+        .filter(|activity| is_considered(activity, job_filter))
+        .map(get_eta)
+        .fold(EtaStatistics::default(), add_eta)
+}
+
+fn is_considered(activity: &Activity, job_filter: &EtaJobFilter) -> bool {
+    matches!(activity.job.as_ref(), Some(job) if job_filter(job))
+}
+
+/// Returns service start time of the activity.
+fn get_eta(activity: &Activity) -> f64 {
+    activity.schedule.arrival.max(activity.place.time.start)
+}
+
+/// Adds service start time to statistics using Welford's online algorithm.
+fn add_eta((count, mean, m2): EtaStatistics, eta: f64) -> EtaStatistics {
+    let count = count + 1;
+    let delta = eta - mean;
+    let new_mean = mean + delta / count as f64;
+
+    (count, new_mean, m2 + delta * (eta - new_mean))
+}
+
+/// Returns population variance of service start times.
+fn get_variance((count, _, m2): EtaStatistics) -> f64 {
+    if count > 0 {
+        m2 / count as f64
+    } else {
+        0.
+    }
+}
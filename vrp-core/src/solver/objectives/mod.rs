@@ -7,6 +7,44 @@ const BALANCE_MAX_LOAD_KEY: i32 = 20;
 const BALANCE_ACTIVITY_KEY: i32 = 21;
 const BALANCE_DISTANCE_KEY: i32 = 22;
 const BALANCE_DURATION_KEY: i32 = 23;
+const ROUTE_COMPACTNESS_KEY: i32 = 24;
+const BALANCE_STOPS_KEY: i32 = 25;
+const DEADHEAD_DISTANCE_KEY: i32 = 26;
+const TOTAL_LATENESS_KEY: i32 = 27;
+const ROUTE_OVERLAP_KEY: i32 = 28;
+const ROUTE_INTERSECTIONS_KEY: i32 = 31;
+const ETA_VARIANCE_KEY: i32 = 33;
+const BALANCE_WORKLOAD_KEY: i32 = 34;
+
+mod deadhead_distance;
+pub use self::deadhead_distance::DeadheadDistance;
+
+mod eta_variance;
+pub use self::eta_variance::{EtaJobFilter, EtaVariance};
+
+mod monetary_cost;
+pub use self::monetary_cost::{MonetaryBreakdown, MonetaryCost, MonetaryRates, RevenueJobFilter};
+
+mod route_compactness;
+pub use self::route_compactness::RouteCompactness;
+
+mod route_intersections;
+pub use self::route_intersections::RouteIntersections;
+
+mod route_overlap;
+pub use self::route_overlap::RouteOverlap;
+
+mod stop_balance;
+pub use self::stop_balance::StopBalance;
+
+mod total_lateness;
+pub use self::total_lateness::TotalLateness;
+
+mod total_long_routes;
+pub use self::total_long_routes::TotalLongRoutes;
+
+mod total_overtime_routes;
+pub use self::total_overtime_routes::{ShiftEndFn, TotalOvertimeRoutes};
 
 mod total_routes;
 pub use self::total_routes::TotalRoutes;
@@ -15,7 +53,13 @@ mod total_transport_cost;
 pub use self::total_transport_cost::TotalTransportCost;
 
 mod total_unassigned_jobs;
-pub use self::total_unassigned_jobs::TotalUnassignedJobs;
+pub use self::total_unassigned_jobs::{TotalUnassignedJobs, UnassignedJobWeightFn};
+
+mod vehicle_affinity;
+pub use self::vehicle_affinity::{AffinityMismatchFn, VehicleAffinity};
+
+mod weighted_sum;
+pub use self::weighted_sum::WeightedSum;
 
 mod work_balance;
 pub use self::work_balance::WorkBalance;
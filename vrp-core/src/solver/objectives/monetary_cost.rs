@@ -0,0 +1,322 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/monetary_cost_test.rs"]
+mod monetary_cost_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::{Cost, Distance, Duration, Timestamp};
+use crate::models::problem::{Job, TargetConstraint, TargetObjective, TransportCost};
+use crate::models::solution::{Activity, Route};
+use crate::models::Solution;
+use crate::solver::objectives::ShiftEndFn;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::ops::Add;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns true for unassigned jobs which are counted as lost revenue.
+pub type RevenueJobFilter = Arc<dyn Fn(&Job) -> bool + Send + Sync>;
+
+/// Specifies rates used to convert solution metrics into money.
+#[derive(Clone, Debug, Default)]
+pub struct MonetaryRates {
+    /// A cost per distance unit driven.
+    pub distance: Cost,
+    /// A cost per time unit of driver's work: from route start till route end.
+    pub time: Cost,
+    /// An extra cost per time unit worked after regular shift end.
+    pub overtime: Cost,
+    /// A fixed cost of each used vehicle.
+    pub fixed: Cost,
+    /// A lost revenue of each unassigned job.
+    pub unassigned: Cost,
+    /// A lost revenue of each job served after its time window end.
+    pub late: Cost,
+}
+
+/// A monetary cost of solution split into terms.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MonetaryBreakdown {
+    /// A cost of driven distance.
+    pub distance: Cost,
+    /// A cost of driver's time.
+    pub time: Cost,
+    /// An extra cost of overtime.
+    pub overtime: Cost,
+    /// A fixed cost of used vehicles.
+    pub fixed: Cost,
+    /// A lost revenue of unassigned jobs.
+    pub unassigned: Cost,
+    /// A lost revenue of jobs served late.
+    pub late: Cost,
+}
+
+impl MonetaryBreakdown {
+    /// Returns total monetary cost: a sum of all terms.
+    pub fn total(&self) -> Cost {
+        self.distance + self.time + self.overtime + self.fixed + self.unassigned + self.late
+    }
+}
+
+impl Add for MonetaryBreakdown {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            distance: self.distance + rhs.distance,
+            time: self.time + rhs.time,
+            overtime: self.overtime + rhs.overtime,
+            fixed: self.fixed + rhs.fixed,
+            unassigned: self.unassigned + rhs.unassigned,
+            late: self.late + rhs.late,
+        }
+    }
+}
+
+/// A type which provides functionality needed to minimize total monetary cost: distance, driver
+/// time, overtime and fixed vehicle costs together with lost revenue of unassigned or late jobs.
+pub struct MonetaryCost {}
+
+impl MonetaryCost {
+    /// Creates _(constraint, objective)_  type pair which minimizes total monetary cost calculated
+    /// using given rates. Overtime starts after regular shift end returned by the function, only
+    /// unassigned jobs accepted by the filter are counted as lost revenue. A job is served late
+    /// when service of any of its activities starts after the latest time returned by lateness function.
+    pub fn new_minimized(
+        rates: MonetaryRates,
+        shift_end_fn: ShiftEndFn,
+        job_filter: RevenueJobFilter,
+        lateness_fn: LatenessFunc,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+    ) -> (TargetConstraint, TargetObjective) {
+        let objective = MonetaryCostObjective { rates, shift_end_fn, job_filter, lateness_fn, transport };
+
+        let constraint = MonetaryCostModule {
+            constraints: vec![
+                ConstraintVariant::SoftActivity(Arc::new(objective.clone())),
+                ConstraintVariant::SoftRoute(Arc::new(objective.clone())),
+            ],
+            keys: vec![],
+        };
+
+        (Box::new(constraint), Box::new(objective))
+    }
+
+    /// Returns monetary cost breakdown of the solution calculated using given rates.
+    pub fn get_breakdown(
+        rates: &MonetaryRates,
+        shift_end_fn: &ShiftEndFn,
+        job_filter: &RevenueJobFilter,
+        lateness_fn: &LatenessFunc,
+        transport: &(dyn TransportCost + Send + Sync),
+        solution: &Solution,
+    ) -> MonetaryBreakdown {
+        let unassigned = solution.unassigned.iter().filter(|(job, _)| (job_filter)(job)).count();
+
+        solution.routes.iter().fold(get_unassigned_breakdown(rates, unassigned), |acc, route| {
+            let (distance, duration) = get_route_statistics(route, transport);
+            let late = get_late_jobs(route, job_filter, lateness_fn);
+            acc + get_route_breakdown(rates, shift_end_fn, route, distance, duration, late)
+        })
+    }
+}
+
+struct MonetaryCostModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for MonetaryCostModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+#[derive(Clone)]
+struct MonetaryCostObjective {
+    rates: MonetaryRates,
+    shift_end_fn: ShiftEndFn,
+    job_filter: RevenueJobFilter,
+    lateness_fn: LatenessFunc,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl MonetaryCostObjective {
+    fn get_leg(&self, route_ctx: &RouteContext, from: &Activity, to: &Activity) -> (Distance, Duration) {
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let (from_loc, to_loc, departure) = (from.place.location, to.place.location, from.schedule.departure);
+
+        (
+            self.transport.distance(profile, from_loc, to_loc, departure),
+            self.transport.duration(profile, from_loc, to_loc, departure),
+        )
+    }
+
+    fn get_breakdown(&self, ctx: &InsertionContext) -> MonetaryBreakdown {
+        let solution = &ctx.solution;
+        let unassigned = solution.unassigned.keys().filter(|job| (self.job_filter)(job)).count();
+
+        solution.routes.iter().fold(get_unassigned_breakdown(&self.rates, unassigned), |acc, route_ctx| {
+            // NOTE route statistics are maintained by transport constraint module
+            let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+            let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+            let late = get_late_jobs(&route_ctx.route, &self.job_filter, &self.lateness_fn);
+
+            acc + get_route_breakdown(&self.rates, &self.shift_end_fn, &route_ctx.route, distance, duration, late)
+        })
+    }
+}
+
+impl SoftActivityConstraint for MonetaryCostObjective {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let (prev_target_dist, prev_target_dur) = self.get_leg(route_ctx, prev, target);
+        let (target_next_dist, target_next_dur, prev_next_dist, prev_next_dur) =
+            activity_ctx.next.map_or((0., 0., 0., 0.), |next| {
+                let (target_next_dist, target_next_dur) = self.get_leg(route_ctx, target, next);
+                let (prev_next_dist, prev_next_dur) = self.get_leg(route_ctx, prev, next);
+
+                (target_next_dist, target_next_dur, prev_next_dist, prev_next_dur)
+            });
+
+        // NOTE waiting time, overtime and delays of next activities are ignored as they depend
+        // on schedule of the whole route
+        let distance = prev_target_dist + target_next_dist - prev_next_dist;
+        let duration = prev_target_dur + target.place.duration + target_next_dur - prev_next_dur;
+
+        let service_start = (prev.schedule.departure + prev_target_dur).max(target.place.time.start);
+        let late = if is_late_job_activity(target, service_start, &self.job_filter, &self.lateness_fn) {
+            self.rates.late
+        } else {
+            0.
+        };
+
+        self.rates.distance * distance + self.rates.time * duration + late
+    }
+}
+
+impl SoftRouteConstraint for MonetaryCostObjective {
+    fn estimate_job(&self, _: &SolutionContext, route_ctx: &RouteContext, _job: &Job) -> Cost {
+        if route_ctx.route.tour.job_count() == 0 {
+            self.rates.fixed
+        } else {
+            0.
+        }
+    }
+}
+
+impl Objective for MonetaryCostObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.get_breakdown(solution).total()
+    }
+}
+
+fn get_unassigned_breakdown(rates: &MonetaryRates, unassigned: usize) -> MonetaryBreakdown {
+    MonetaryBreakdown { unassigned: rates.unassigned * unassigned as f64, ..MonetaryBreakdown::default() }
+}
+
+/// Returns monetary cost of the route with given total distance and duration. Open routes and
+/// actors without regular shift end have no overtime.
+fn get_route_breakdown(
+    rates: &MonetaryRates,
+    shift_end_fn: &ShiftEndFn,
+    route: &Route,
+    distance: Distance,
+    duration: Duration,
+    late: usize,
+) -> MonetaryBreakdown {
+    if route.tour.job_count() == 0 {
+        return MonetaryBreakdown::default();
+    }
+
+    let actor = route.actor.as_ref();
+    let overtime = actor
+        .detail
+        .end
+        .as_ref()
+        .and_then(|_| (shift_end_fn)(actor))
+        .zip(route.tour.end())
+        .map_or(0., |(shift_end, end)| (end.schedule.arrival - shift_end).max(0.));
+
+    MonetaryBreakdown {
+        distance: rates.distance * distance,
+        time: rates.time * duration,
+        overtime: rates.overtime * overtime,
+        fixed: rates.fixed,
+        unassigned: 0.,
+        late: rates.late * late as f64,
+    }
+}
+
+/// Returns amount of jobs accepted by the filter which are served late in the route.
+fn get_late_jobs(route: &Route, job_filter: &RevenueJobFilter, lateness_fn: &LatenessFunc) -> usize {
+    route
+        .tour
+        .all_activities()
+        .filter(|activity| {
+            let service_start = activity.schedule.arrival.max(activity.place.time.start);
+            is_late_job_activity(activity, service_start, job_filter, lateness_fn)
+        })
+        .filter_map(|activity| activity.retrieve_job())
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn is_late_job_activity(
+    activity: &Activity,
+    service_start: Timestamp,
+    job_filter: &RevenueJobFilter,
+    lateness_fn: &LatenessFunc,
+) -> bool {
+    (lateness_fn)(activity).map_or(false, |(latest, _)| service_start > latest)
+        && activity.retrieve_job().map_or(false, |job| (job_filter)(&job))
+}
+
+/// Returns total distance and duration of the route calculated the same way as transport
+/// constraint module does.
+fn get_route_statistics(route: &Route, transport: &(dyn TransportCost + Send + Sync)) -> (Distance, Duration) {
+    let (start, end) = match (route.tour.start(), route.tour.end()) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return (0., 0.),
+    };
+
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let distance = activities
+        .windows(2)
+        .map(|leg| {
+            let (from, to) = (leg[0], leg[1]);
+            transport.distance(
+                route.actor.vehicle.profile,
+                from.place.location,
+                to.place.location,
+                from.schedule.departure,
+            )
+        })
+        .sum();
+
+    (distance, end.schedule.departure - start.schedule.departure)
+}
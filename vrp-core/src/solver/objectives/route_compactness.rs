@@ -0,0 +1,179 @@
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::{Distance, Location, Profile};
+use crate::models::problem::{Job, Single, TargetConstraint, TargetObjective, TransportCost};
+use crate::solver::objectives::*;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A type which provides functionality needed to keep routes geographically compact.
+pub struct RouteCompactness {}
+
+impl RouteCompactness {
+    /// Creates _(constraint, objective)_  type pair which minimizes spread of each route measured
+    /// as an average distance between all pairs of its stops.
+    pub fn new_minimized(transport: Arc<dyn TransportCost + Send + Sync>) -> (TargetConstraint, TargetObjective) {
+        let objective = RouteCompactnessObjective { transport };
+
+        let constraint = RouteCompactnessModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(objective.clone()))],
+            objective: objective.clone(),
+            keys: vec![ROUTE_COMPACTNESS_KEY],
+        };
+
+        (Box::new(constraint), Box::new(objective))
+    }
+}
+
+struct RouteCompactnessModule {
+    constraints: Vec<ConstraintVariant>,
+    objective: RouteCompactnessObjective,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for RouteCompactnessModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let spread = self.objective.get_route_spread(ctx);
+        ctx.state_mut().put_route_state(ROUTE_COMPACTNESS_KEY, spread);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+#[derive(Clone)]
+struct RouteCompactnessObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl RouteCompactnessObjective {
+    /// Returns sum of distances between all ordered pairs of route stops and amount of stops.
+    fn get_route_spread(&self, route_ctx: &RouteContext) -> (Distance, usize) {
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let locations = get_stop_locations(route_ctx);
+
+        let total = locations
+            .iter()
+            .enumerate()
+            .map(|(idx, &location)| self.get_distance_sum(profile, location, &locations[idx + 1..]))
+            .sum::<Distance>();
+
+        (total, locations.len())
+    }
+
+    /// Returns route spread from the route state or calculates it when the state is missing.
+    fn get_cached_route_spread(&self, route_ctx: &RouteContext) -> (Distance, usize) {
+        // NOTE state is missing when the objective is used without its module, e.g. as tie-break
+        route_ctx
+            .state
+            .get_route_state::<(Distance, usize)>(ROUTE_COMPACTNESS_KEY)
+            .cloned()
+            .unwrap_or_else(|| self.get_route_spread(route_ctx))
+    }
+
+    /// Returns sum of distances in both directions between given location and other locations.
+    fn get_distance_sum(&self, profile: Profile, location: Location, others: &[Location]) -> Distance {
+        // NOTE time dependent routing is not considered: distances are taken at zero departure time
+        others
+            .iter()
+            .map(|&other| {
+                self.transport.distance(profile, location, other, 0.)
+                    + self.transport.distance(profile, other, location, 0.)
+            })
+            .sum()
+    }
+
+    /// Returns the lowest sum of distances between given job's locations and other locations.
+    fn get_single_distance_sum(&self, profile: Profile, single: &Single, others: &[Location]) -> Option<Distance> {
+        single
+            .places
+            .iter()
+            .filter_map(|place| place.location)
+            .map(|location| self.get_distance_sum(profile, location, others))
+            .min_by(|a, b| compare_floats(*a, *b))
+    }
+}
+
+impl SoftRouteConstraint for RouteCompactnessObjective {
+    fn estimate_job(&self, _solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> f64 {
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let locations = get_stop_locations(route_ctx);
+        let (total, count) = self.get_cached_route_spread(route_ctx);
+
+        // NOTE distances between sub jobs of multi job are not considered
+        let (added_total, added_count) = match job {
+            Job::Single(single) => self
+                .get_single_distance_sum(profile, single, locations.as_slice())
+                .map_or((0., 0), |distance| (distance, 1)),
+            Job::Multi(multi) => multi
+                .jobs
+                .iter()
+                .filter_map(|single| self.get_single_distance_sum(profile, single, locations.as_slice()))
+                .fold((0., 0), |(total, count), distance| (total + distance, count + 1)),
+        };
+
+        if added_count == 0 {
+            0.
+        } else {
+            get_average(total + added_total, count + added_count) - get_average(total, count)
+        }
+    }
+}
+
+impl Objective for RouteCompactnessObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                let (total, count) = self.get_cached_route_spread(route_ctx);
+                get_average(total, count)
+            })
+            .sum()
+    }
+}
+
+fn get_stop_locations(route_ctx: &RouteContext) -> Vec<Location> {
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .filter(|activity| activity.job.is_some())
+        .map(|activity| activity.place.location)
+        .collect()
+}
+
+fn get_average(total: Distance, count: usize) -> Distance {
+    if count > 1 {
+        total / (count * (count - 1)) as f64
+    } else {
+        0.
+    }
+}
@@ -0,0 +1,184 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/route_intersections_test.rs"]
+mod route_intersections_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::Location;
+use crate::models::problem::{Job, TargetConstraint, TargetObjective};
+use crate::models::solution::Activity;
+use crate::solver::objectives::*;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A point on a plane.
+type Point = (f64, f64);
+
+/// A segment of route polyline.
+type Segment = (Point, Point);
+
+/// A type which provides functionality needed to keep route shapes clean.
+pub struct RouteIntersections {}
+
+impl RouteIntersections {
+    /// Creates _(constraint, objective)_  type pair which minimizes amount of self-intersections
+    /// of route polylines built from ordered activity locations. Coordinates of each location are
+    /// returned by coordinate function, locations without coordinates are ignored.
+    pub fn new_minimized(
+        coord_func: Arc<dyn Fn(Location) -> Option<(f64, f64)> + Send + Sync>,
+    ) -> (TargetConstraint, TargetObjective) {
+        let objective = RouteIntersectionsObjective { coord_func };
+
+        let constraint = RouteIntersectionsModule {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(objective.clone()))],
+            objective: objective.clone(),
+            keys: vec![ROUTE_INTERSECTIONS_KEY],
+        };
+
+        (Box::new(constraint), Box::new(objective))
+    }
+}
+
+struct RouteIntersectionsModule {
+    constraints: Vec<ConstraintVariant>,
+    objective: RouteIntersectionsObjective,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for RouteIntersectionsModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let intersections = self.objective.get_route_intersections(ctx);
+        ctx.state_mut().put_route_state(ROUTE_INTERSECTIONS_KEY, intersections);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+#[derive(Clone)]
+struct RouteIntersectionsObjective {
+    coord_func: Arc<dyn Fn(Location) -> Option<(f64, f64)> + Send + Sync>,
+}
+
+impl RouteIntersectionsObjective {
+    /// Returns route segments with index of their first activity.
+    fn get_route_segments(&self, route_ctx: &RouteContext) -> Vec<(usize, Segment)> {
+        let points = route_ctx.route.tour.all_activities().map(|activity| self.get_point(activity)).collect::<Vec<_>>();
+
+        points
+            .windows(2)
+            .enumerate()
+            .filter_map(|(idx, pair)| match (pair[0], pair[1]) {
+                (Some(from), Some(to)) => Some((idx, (from, to))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns amount of pairs of route segments which cross each other.
+    fn get_route_intersections(&self, route_ctx: &RouteContext) -> usize {
+        let segments = self.get_route_segments(route_ctx);
+
+        segments
+            .iter()
+            .enumerate()
+            .map(|(idx, (_, segment))| {
+                segments[idx + 1..].iter().filter(|(_, other)| is_crossing(segment, other)).count()
+            })
+            .sum()
+    }
+
+    /// Returns route intersections from the route state or calculates them when the state is missing.
+    fn get_cached_route_intersections(&self, route_ctx: &RouteContext) -> usize {
+        // NOTE state is missing when the objective is used without its module, e.g. as tie-break
+        route_ctx
+            .state
+            .get_route_state::<usize>(ROUTE_INTERSECTIONS_KEY)
+            .cloned()
+            .unwrap_or_else(|| self.get_route_intersections(route_ctx))
+    }
+
+    fn get_point(&self, activity: &Activity) -> Option<Point> {
+        (self.coord_func)(activity.place.location)
+    }
+}
+
+impl SoftActivityConstraint for RouteIntersectionsObjective {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let target = match self.get_point(activity_ctx.target) {
+            Some(target) => target,
+            None => return 0.,
+        };
+
+        let prev = self.get_point(activity_ctx.prev);
+        let next = activity_ctx.next.and_then(|next| self.get_point(next));
+
+        let (replaced, segments): (Vec<_>, Vec<_>) = self
+            .get_route_segments(route_ctx)
+            .into_iter()
+            .partition(|(idx, _)| activity_ctx.next.is_some() && *idx == activity_ctx.index);
+
+        let count_crossings =
+            |segment: &Segment| segments.iter().filter(|(_, other)| is_crossing(segment, other)).count();
+
+        let added = prev.map_or(0, |prev| count_crossings(&(prev, target)))
+            + next.map_or(0, |next| count_crossings(&(target, next)));
+        let removed = replaced.iter().map(|(_, segment)| count_crossings(segment)).sum::<usize>();
+
+        // NOTE penalty is scaled by route cost to be comparable with insertion costs
+        (added as f64 - removed as f64) * route_ctx.get_route_cost().max(1.)
+    }
+}
+
+impl Objective for RouteIntersectionsObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        let fitness_a = self.fitness(a) as usize;
+        let fitness_b = self.fitness(b) as usize;
+
+        fitness_a.cmp(&fitness_b)
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| self.get_cached_route_intersections(route_ctx)).sum::<usize>()
+            as f64
+    }
+}
+
+/// Checks whether two segments cross each other in a single point which is not an end of any of
+/// them. So, adjacent segments and segments which only touch each other are not crossing.
+fn is_crossing(a: &Segment, b: &Segment) -> bool {
+    let orientation = |p: Point, q: Point, r: Point| {
+        let value = (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0);
+        if value > 0. {
+            1
+        } else if value < 0. {
+            -1
+        } else {
+            0
+        }
+    };
+
+    orientation(a.0, a.1, b.0) * orientation(a.0, a.1, b.1) < 0
+        && orientation(b.0, b.1, a.0) * orientation(b.0, b.1, a.1) < 0
+}
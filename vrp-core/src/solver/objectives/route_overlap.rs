@@ -0,0 +1,165 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/route_overlap_test.rs"]
+mod route_overlap_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::Location;
+use crate::models::problem::{Job, Single, TargetConstraint, TargetObjective};
+use crate::solver::objectives::*;
+use crate::utils::compare_floats;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A type which provides functionality needed to keep routes geographically separated.
+pub struct RouteOverlap {}
+
+impl RouteOverlap {
+    /// Creates _(constraint, objective)_  type pair which minimizes overlap between routes measured
+    /// as amount of extra routes visiting the same area cell. Cell of each location is returned by
+    /// cell function, locations without cell are ignored.
+    pub fn new_minimized(
+        cell_func: Arc<dyn Fn(Location) -> Option<usize> + Send + Sync>,
+    ) -> (TargetConstraint, TargetObjective) {
+        let objective = RouteOverlapObjective { cell_func };
+
+        let constraint = RouteOverlapModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(objective.clone()))],
+            objective: objective.clone(),
+            keys: vec![ROUTE_OVERLAP_KEY],
+        };
+
+        (Box::new(constraint), Box::new(objective))
+    }
+}
+
+struct RouteOverlapModule {
+    constraints: Vec<ConstraintVariant>,
+    objective: RouteOverlapObjective,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for RouteOverlapModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let cells = self.objective.get_route_cells(ctx);
+        ctx.state_mut().put_route_state(ROUTE_OVERLAP_KEY, cells);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+#[derive(Clone)]
+struct RouteOverlapObjective {
+    cell_func: Arc<dyn Fn(Location) -> Option<usize> + Send + Sync>,
+}
+
+impl RouteOverlapObjective {
+    /// Returns cells visited by route's job activities.
+    fn get_route_cells(&self, route_ctx: &RouteContext) -> HashSet<usize> {
+        route_ctx
+            .route
+            .tour
+            .all_activities()
+            .filter(|activity| activity.job.is_some())
+            .filter_map(|activity| (self.cell_func)(activity.place.location))
+            .collect()
+    }
+
+    /// Returns route cells from the route state or calculates them when the state is missing.
+    fn get_cached_route_cells(&self, route_ctx: &RouteContext) -> HashSet<usize> {
+        // NOTE state is missing when the objective is used without its module, e.g. as tie-break
+        route_ctx
+            .state
+            .get_route_state::<HashSet<usize>>(ROUTE_OVERLAP_KEY)
+            .cloned()
+            .unwrap_or_else(|| self.get_route_cells(route_ctx))
+    }
+
+    /// Returns the lowest amount of new overlaps introduced by serving given job in the route.
+    fn get_single_overlap(
+        &self,
+        single: &Single,
+        route_cells: &HashSet<usize>,
+        other_cells: &[HashSet<usize>],
+    ) -> usize {
+        single
+            .places
+            .iter()
+            .filter_map(|place| place.location.and_then(|location| (self.cell_func)(location)))
+            .map(|cell| {
+                if route_cells.contains(&cell) {
+                    0
+                } else {
+                    other_cells.iter().filter(|cells| cells.contains(&cell)).count().min(1)
+                }
+            })
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+impl SoftRouteConstraint for RouteOverlapObjective {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> f64 {
+        let actor = &route_ctx.route.actor;
+        let route_cells = self.get_cached_route_cells(route_ctx);
+        let other_cells = solution_ctx
+            .routes
+            .iter()
+            .filter(|rc| !Arc::ptr_eq(&rc.route.actor, actor))
+            .map(|rc| self.get_cached_route_cells(rc))
+            .collect::<Vec<_>>();
+
+        // NOTE cells shared by sub jobs of multi job are counted separately
+        let overlap = match job {
+            Job::Single(single) => self.get_single_overlap(single, &route_cells, other_cells.as_slice()),
+            Job::Multi(multi) => multi
+                .jobs
+                .iter()
+                .map(|single| self.get_single_overlap(single, &route_cells, other_cells.as_slice()))
+                .sum(),
+        };
+
+        overlap as f64 * solution_ctx.get_max_cost()
+    }
+}
+
+impl Objective for RouteOverlapObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let counts = solution.solution.routes.iter().flat_map(|route_ctx| self.get_cached_route_cells(route_ctx)).fold(
+            HashMap::<usize, usize>::new(),
+            |mut acc, cell| {
+                *acc.entry(cell).or_insert(0) += 1;
+                acc
+            },
+        );
+
+        counts.values().map(|count| count - 1).sum::<usize>() as f64
+    }
+}
@@ -0,0 +1,126 @@
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use crate::models::problem::{Job, TargetConstraint, TargetObjective};
+use crate::solver::objectives::*;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A type which provides functionality needed to balance amount of stops across all routes.
+pub struct StopBalance {}
+
+impl StopBalance {
+    /// Creates _(constraint, objective)_  type pair which minimizes spread of stop counts across
+    /// all tours, measured as difference between the highest and the lowest amount of job
+    /// activities in a tour.
+    pub fn new_minimized() -> (TargetConstraint, TargetObjective) {
+        let constraint = StopBalanceModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(StopBalanceObjective {}))],
+            keys: vec![BALANCE_STOPS_KEY],
+        };
+
+        (Box::new(constraint), Box::new(StopBalanceObjective {}))
+    }
+}
+
+struct StopBalanceModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for StopBalanceModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let spread = get_solution_spread(ctx);
+        ctx.state.insert(BALANCE_STOPS_KEY, Arc::new(spread));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct StopBalanceObjective {}
+
+impl SoftRouteConstraint for StopBalanceObjective {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> f64 {
+        let added = match job {
+            Job::Single(_) => 1,
+            Job::Multi(multi) => multi.jobs.len(),
+        };
+
+        let actor = &route_ctx.route.actor;
+        let is_new_route = !solution_ctx.routes.iter().any(|rc| Arc::ptr_eq(&rc.route.actor, actor));
+        let new_route_count = route_ctx.route.tour.activity_count() + added;
+
+        let counts = solution_ctx.routes.iter().filter(|rc| rc.route.tour.has_jobs()).map(|rc| {
+            if Arc::ptr_eq(&rc.route.actor, actor) {
+                new_route_count
+            } else {
+                rc.route.tour.activity_count()
+            }
+        });
+
+        let new_spread =
+            if is_new_route { get_spread(counts.chain(std::iter::once(new_route_count))) } else { get_spread(counts) };
+
+        (new_spread - get_cached_spread(solution_ctx)) * solution_ctx.get_max_cost()
+    }
+}
+
+impl Objective for StopBalanceObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        get_cached_spread(&solution.solution)
+    }
+}
+
+/// Returns stop count spread from the solution state or calculates it when the state is missing.
+fn get_cached_spread(solution_ctx: &SolutionContext) -> f64 {
+    // NOTE state is missing when the objective is used without its module, e.g. as tie-break
+    solution_ctx
+        .state
+        .get(&BALANCE_STOPS_KEY)
+        .and_then(|spread| spread.downcast_ref::<f64>())
+        .cloned()
+        .unwrap_or_else(|| get_solution_spread(solution_ctx))
+}
+
+fn get_solution_spread(solution_ctx: &SolutionContext) -> f64 {
+    get_spread(
+        solution_ctx
+            .routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route.tour.has_jobs())
+            .map(|route_ctx| route_ctx.route.tour.activity_count()),
+    )
+}
+
+/// Returns difference between the highest and the lowest stop count.
+fn get_spread(counts: impl Iterator<Item = usize>) -> f64 {
+    counts
+        .fold(None, |acc: Option<(usize, usize)>, count| {
+            Some(acc.map_or((count, count), |(min, max)| (min.min(count), max.max(count))))
+        })
+        .map_or(0., |(min, max)| (max - min) as f64)
+}
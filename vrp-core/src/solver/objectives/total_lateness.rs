@@ -0,0 +1,101 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_lateness_test.rs"]
+mod total_lateness_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::{ActivityCost, Job, TargetConstraint, TargetObjective, TransportCost};
+use crate::solver::objectives::*;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A type which provides functionality needed to minimize total lateness of activities with soft
+/// time windows.
+pub struct TotalLateness {}
+
+impl TotalLateness {
+    /// Creates _(constraint, objective)_  type pair which minimizes total lateness of activities.
+    /// Lateness of each activity is weighted by the penalty returned from lateness function, e.g.
+    /// scaled by job priority, so important jobs are kept on time preferentially.
+    pub fn new_minimized(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        lateness_func: LatenessFunc,
+    ) -> (TargetConstraint, TargetObjective) {
+        let lateness = Arc::new(LatenessPenalty::new(activity, transport, lateness_func));
+        let objective = TotalLatenessObjective { lateness: lateness.clone() };
+
+        let constraint = TotalLatenessModule {
+            lateness: lateness.clone(),
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(LatenessSoftActivityConstraint::new(lateness)))],
+            keys: vec![TOTAL_LATENESS_KEY],
+        };
+
+        (Box::new(constraint), Box::new(objective))
+    }
+}
+
+struct TotalLatenessModule {
+    lateness: Arc<LatenessPenalty>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for TotalLatenessModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let lateness = self.lateness.get_route_penalty(ctx);
+        ctx.state_mut().put_route_state(TOTAL_LATENESS_KEY, lateness);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TotalLatenessObjective {
+    lateness: Arc<LatenessPenalty>,
+}
+
+impl TotalLatenessObjective {
+    /// Returns lateness from the route state or calculates it when the state is missing.
+    fn get_cached_lateness(&self, route_ctx: &RouteContext) -> Cost {
+        // NOTE state is missing when the objective is used without its module, e.g. as tie-break
+        route_ctx
+            .state
+            .get_route_state::<Cost>(TOTAL_LATENESS_KEY)
+            .cloned()
+            .unwrap_or_else(|| self.lateness.get_route_penalty(route_ctx))
+    }
+}
+
+impl Objective for TotalLatenessObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| self.get_cached_lateness(route_ctx)).sum()
+    }
+}
@@ -0,0 +1,99 @@
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use crate::models::problem::{Job, Single, TargetConstraint, TargetObjective};
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A type which provides functionality needed to minimize amount of routes which duration
+/// exceeds given threshold.
+pub struct TotalLongRoutes {}
+
+impl TotalLongRoutes {
+    /// Creates _(constraint, objective)_  type pair which minimizes amount of routes with
+    /// total duration higher than threshold.
+    pub fn new_duration_limited(threshold: f64) -> (TargetConstraint, TargetObjective) {
+        let objective = TotalLongRoutesObjective { threshold };
+
+        let constraint = TotalLongRoutesModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(objective.clone()))],
+            keys: vec![],
+        };
+
+        (Box::new(constraint), Box::new(objective))
+    }
+}
+
+struct TotalLongRoutesModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for TotalLongRoutesModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+#[derive(Clone)]
+struct TotalLongRoutesObjective {
+    threshold: f64,
+}
+
+impl TotalLongRoutesObjective {
+    fn get_duration(&self, route_ctx: &RouteContext) -> f64 {
+        route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.)
+    }
+
+    fn is_long_route(&self, route_ctx: &RouteContext) -> bool {
+        self.get_duration(route_ctx) > self.threshold
+    }
+}
+
+impl SoftRouteConstraint for TotalLongRoutesObjective {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> f64 {
+        // NOTE use min service time as a cheap lower bound of route duration increase
+        let get_min_duration =
+            |single: &Single| single.places.iter().map(|place| place.duration).fold(f64::MAX, |acc, d| acc.min(d));
+        let service_duration = match job {
+            Job::Single(single) => get_min_duration(single),
+            Job::Multi(multi) => multi.jobs.iter().map(|single| get_min_duration(single)).sum(),
+        };
+
+        if self.get_duration(route_ctx) + service_duration > self.threshold {
+            solution_ctx.get_max_cost()
+        } else {
+            0.
+        }
+    }
+}
+
+impl Objective for TotalLongRoutesObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        let fitness_a = self.fitness(a) as usize;
+        let fitness_b = self.fitness(b) as usize;
+
+        fitness_a.cmp(&fitness_b)
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().filter(|route_ctx| self.is_long_route(route_ctx)).count() as f64
+    }
+}
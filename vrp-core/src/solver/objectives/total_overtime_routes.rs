@@ -0,0 +1,120 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_overtime_routes_test.rs"]
+mod total_overtime_routes_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::Timestamp;
+use crate::models::problem::{Actor, Job, Single, TargetConstraint, TargetObjective};
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns regular shift end of the actor when the actor can work overtime.
+pub type ShiftEndFn = Arc<dyn Fn(&Actor) -> Option<Timestamp> + Send + Sync>;
+
+/// A type which provides functionality needed to minimize amount of routes which end after
+/// regular shift end of their actors, e.g. amount of drivers doing overtime.
+pub struct TotalOvertimeRoutes {}
+
+impl TotalOvertimeRoutes {
+    /// Creates _(constraint, objective)_  type pair which minimizes amount of routes with arrival
+    /// at the route end later than regular shift end returned by the function.
+    pub fn new_minimized(shift_end_fn: ShiftEndFn) -> (TargetConstraint, TargetObjective) {
+        let objective = TotalOvertimeRoutesObjective { shift_end_fn };
+
+        let constraint = TotalOvertimeRoutesModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(objective.clone()))],
+            keys: vec![],
+        };
+
+        (Box::new(constraint), Box::new(objective))
+    }
+}
+
+struct TotalOvertimeRoutesModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for TotalOvertimeRoutesModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+#[derive(Clone)]
+struct TotalOvertimeRoutesObjective {
+    shift_end_fn: ShiftEndFn,
+}
+
+impl TotalOvertimeRoutesObjective {
+    /// Returns regular shift end and arrival at the route end. Open routes have no overtime.
+    fn get_end_times(&self, route_ctx: &RouteContext) -> Option<(Timestamp, Timestamp)> {
+        let actor = route_ctx.route.actor.as_ref();
+        actor.detail.end.as_ref()?;
+
+        let shift_end = (self.shift_end_fn)(actor)?;
+        let arrival = route_ctx.route.tour.end()?.schedule.arrival;
+
+        Some((shift_end, arrival))
+    }
+
+    fn is_overtime_route(&self, route_ctx: &RouteContext) -> bool {
+        matches!(self.get_end_times(route_ctx), Some((shift_end, arrival)) if arrival > shift_end)
+    }
+}
+
+impl SoftRouteConstraint for TotalOvertimeRoutesObjective {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> f64 {
+        let (shift_end, arrival) = match self.get_end_times(route_ctx) {
+            Some((shift_end, arrival)) if arrival <= shift_end => (shift_end, arrival),
+            // NOTE route is already in overtime, so serving more jobs there does not increase fitness
+            _ => return 0.,
+        };
+
+        // NOTE use min service time as a cheap lower bound of route end delay
+        let get_min_duration =
+            |single: &Single| single.places.iter().map(|place| place.duration).fold(f64::MAX, |acc, d| acc.min(d));
+        let service_duration = match job {
+            Job::Single(single) => get_min_duration(single),
+            Job::Multi(multi) => multi.jobs.iter().map(|single| get_min_duration(single)).sum(),
+        };
+
+        if arrival + service_duration > shift_end {
+            solution_ctx.get_max_cost()
+        } else {
+            0.
+        }
+    }
+}
+
+impl Objective for TotalOvertimeRoutesObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        let fitness_a = self.fitness(a) as usize;
+        let fitness_b = self.fitness(b) as usize;
+
+        fitness_a.cmp(&fitness_b)
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().filter(|route_ctx| self.is_overtime_route(route_ctx)).count() as f64
+    }
+}
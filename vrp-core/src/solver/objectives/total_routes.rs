@@ -6,23 +6,34 @@ use crate::utils::compare_floats;
 /// An objective function which controls total amount of routes.
 pub struct TotalRoutes {
     is_minimization: bool,
+    target: Option<usize>,
 }
 
 impl Default for TotalRoutes {
     fn default() -> Self {
-        Self { is_minimization: true }
+        Self { is_minimization: true, target: None }
     }
 }
 
 impl TotalRoutes {
     /// Creates an instance of `TotalRoutes` with fleet minimization as a target.
     pub fn new_minimized() -> Self {
-        Self { is_minimization: true }
+        Self { is_minimization: true, target: None }
     }
 
     /// Creates an instance of `TotalRoutes` with fleet maximization as a target.
     pub fn new_maximized() -> Self {
-        Self { is_minimization: false }
+        Self { is_minimization: false, target: None }
+    }
+
+    /// Creates an instance of `TotalRoutes` which minimizes deviation from the target amount of
+    /// routes: both fewer and more routes are penalized.
+    pub fn new_targeted(target: usize) -> Self {
+        Self { is_minimization: true, target: Some(target) }
+    }
+
+    fn get_deviation(&self, solution: &InsertionContext) -> Option<f64> {
+        self.target.map(|target| (solution.solution.routes.len() as f64 - target as f64).abs())
     }
 }
 
@@ -30,6 +41,10 @@ impl Objective for TotalRoutes {
     type Solution = InsertionContext;
 
     fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        if let (Some(deviation_a), Some(deviation_b)) = (self.get_deviation(a), self.get_deviation(b)) {
+            return compare_floats(deviation_a, deviation_b);
+        }
+
         let fitness_a = a.solution.routes.len() as f64;
         let fitness_b = b.solution.routes.len() as f64;
 
@@ -40,10 +55,14 @@ impl Objective for TotalRoutes {
     }
 
     fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        if let (Some(deviation_a), Some(deviation_b)) = (self.get_deviation(a), self.get_deviation(b)) {
+            return deviation_a - deviation_b;
+        }
+
         a.solution.routes.len() as f64 - b.solution.routes.len() as f64
     }
 
     fn fitness(&self, solution: &Self::Solution) -> f64 {
-        solution.solution.routes.len() as f64
+        self.get_deviation(solution).unwrap_or(solution.solution.routes.len() as f64)
     }
 }
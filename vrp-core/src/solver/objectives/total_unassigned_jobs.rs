@@ -1,12 +1,34 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_unassigned_jobs_test.rs"]
+mod total_unassigned_jobs_test;
+
 use super::*;
 use crate::algorithms::nsga2::Objective;
+use crate::models::problem::Job;
+use crate::utils::compare_floats;
+use std::sync::Arc;
+
+/// A function which returns importance weight of unassigned job.
+pub type UnassignedJobWeightFn = Arc<dyn Fn(&Job) -> f64 + Send + Sync>;
 
-/// An objective function which minimizes amount of unassigned jobs as a target.
-pub struct TotalUnassignedJobs {}
+/// An objective function which minimizes amount of unassigned jobs as a target. When weight function
+/// is specified, solutions with the same amount of unassigned jobs are compared by total weight of
+/// their unassigned jobs, so more important jobs are kept assigned.
+#[derive(Default)]
+pub struct TotalUnassignedJobs {
+    weight_fn: Option<UnassignedJobWeightFn>,
+}
+
+impl TotalUnassignedJobs {
+    /// Creates an instance of `TotalUnassignedJobs` which breaks ties using total weight of unassigned jobs.
+    pub fn new_weighted(weight_fn: UnassignedJobWeightFn) -> Self {
+        Self { weight_fn: Some(weight_fn) }
+    }
 
-impl Default for TotalUnassignedJobs {
-    fn default() -> Self {
-        Self {}
+    fn get_weight(&self, solution: &InsertionContext) -> f64 {
+        self.weight_fn
+            .as_ref()
+            .map_or(0., |weight_fn| solution.solution.unassigned.keys().map(|job| (weight_fn)(job)).sum())
     }
 }
 
@@ -17,7 +39,7 @@ impl Objective for TotalUnassignedJobs {
         let fitness_a = a.solution.unassigned.len();
         let fitness_b = b.solution.unassigned.len();
 
-        fitness_a.cmp(&fitness_b)
+        fitness_a.cmp(&fitness_b).then_with(|| compare_floats(self.get_weight(a), self.get_weight(b)))
     }
 
     fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
@@ -0,0 +1,97 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/vehicle_affinity_test.rs"]
+mod vehicle_affinity_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use crate::models::problem::{Actor, Job, TargetConstraint, TargetObjective};
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies a function which returns true when job has affinity to another vehicle than the given actor's one.
+pub type AffinityMismatchFn = Arc<dyn Fn(&Actor, &Job) -> bool + Send + Sync>;
+
+/// A type which provides functionality needed to keep jobs on vehicles they have affinity to,
+/// e.g. ones which served them historically.
+pub struct VehicleAffinity {}
+
+impl VehicleAffinity {
+    /// Creates _(constraint, objective)_  type pair which minimizes amount of jobs served by
+    /// vehicles other than ones they have affinity to. Jobs without affinity are ignored.
+    pub fn new_minimized(mismatch_fn: AffinityMismatchFn) -> (TargetConstraint, TargetObjective) {
+        let constraint = VehicleAffinityModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(VehicleAffinitySoftRouteConstraint {
+                mismatch_fn: mismatch_fn.clone(),
+            }))],
+            keys: vec![],
+        };
+
+        (Box::new(constraint), Box::new(VehicleAffinityObjective { mismatch_fn }))
+    }
+}
+
+struct VehicleAffinityModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for VehicleAffinityModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct VehicleAffinitySoftRouteConstraint {
+    mismatch_fn: AffinityMismatchFn,
+}
+
+impl SoftRouteConstraint for VehicleAffinitySoftRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> f64 {
+        if (self.mismatch_fn)(&route_ctx.route.actor, job) {
+            solution_ctx.get_max_cost()
+        } else {
+            0.
+        }
+    }
+}
+
+struct VehicleAffinityObjective {
+    mismatch_fn: AffinityMismatchFn,
+}
+
+impl Objective for VehicleAffinityObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                let actor = &route_ctx.route.actor;
+                route_ctx.route.tour.jobs().filter(|job| (self.mismatch_fn)(actor, job)).count()
+            })
+            .sum::<usize>() as f64
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/weighted_sum_test.rs"]
+mod weighted_sum_test;
+
+use super::*;
+use crate::algorithms::nsga2::Objective;
+use crate::models::problem::TargetObjective;
+use crate::utils::compare_floats;
+
+/// An objective function which combines several objectives into one scalar value using weighted sum
+/// of their fitness values.
+pub struct WeightedSum {
+    objectives: Vec<(TargetObjective, f64)>,
+    scales: Vec<f64>,
+}
+
+impl WeightedSum {
+    /// Creates an instance of `WeightedSum` which uses raw fitness values of inner objectives.
+    pub fn new(objectives: Vec<(TargetObjective, f64)>) -> Self {
+        let scales = vec![1.; objectives.len()];
+        Self { objectives, scales }
+    }
+
+    /// Creates an instance of `WeightedSum` which normalizes fitness values of inner objectives
+    /// before weighting: each fitness value is divided by the scale of the objective, normally, an
+    /// estimate of its magnitude known before solving. This way, weights are not affected by
+    /// different scales of objectives, e.g. distance and amount of tours. Non-positive scales are
+    /// ignored.
+    pub fn new_normalized(objectives: Vec<(TargetObjective, f64)>, scales: Vec<f64>) -> Self {
+        assert_eq!(objectives.len(), scales.len());
+
+        let scales = scales.into_iter().map(|scale| if scale > 0. { scale } else { 1. }).collect();
+        Self { objectives, scales }
+    }
+
+    fn get_value(&self, solution: &InsertionContext) -> f64 {
+        self.objectives
+            .iter()
+            .zip(self.scales.iter())
+            .map(|((objective, weight), scale)| weight * objective.fitness(solution) / scale)
+            .sum()
+    }
+}
+
+impl Objective for WeightedSum {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.get_value(a), self.get_value(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.get_value(a) - self.get_value(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.get_value(solution)
+    }
+}
@@ -4,6 +4,7 @@ use crate::construction::constraints::*;
 use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
 use crate::models::common::{CapacityDimension, Load};
 use crate::models::problem::{Job, TargetConstraint, TargetObjective};
+use crate::models::solution::Activity;
 use crate::solver::objectives::*;
 use crate::utils::compare_floats;
 use std::cmp::Ordering;
@@ -100,6 +101,48 @@ impl WorkBalance {
         (Box::new(constraint), Box::new(objective))
     }
 
+    /// Creates _(constraint, objective)_  type pair which balances workload across all tours: each
+    /// activity contributes to tour workload with a value returned by `workload_func`.
+    pub fn new_workload_balanced(
+        threshold: Option<f64>,
+        tolerance: Option<f64>,
+        workload_func: Arc<dyn Fn(&Activity) -> f64 + Send + Sync>,
+    ) -> (TargetConstraint, TargetObjective) {
+        let get_workload = Arc::new(move |rc: &RouteContext| {
+            let tour = &rc.route.tour;
+
+            // NOTE skip start and end activities, the same as activity count does
+            tour.all_activities().skip(1).take(tour.activity_count()).map(|activity| workload_func(activity)).sum()
+        });
+
+        let value_func = Arc::new({
+            let get_workload = get_workload.clone();
+            move |rc: &RouteContext| get_workload(rc)
+        });
+        let values_func = Arc::new({
+            let get_workload = get_workload.clone();
+            move |ctx: &SolutionContext| ctx.routes.iter().map(|rc| get_workload(rc)).collect()
+        });
+
+        let objective = WorkBalanceObjectives {
+            threshold,
+            tolerance,
+            state_key: BALANCE_WORKLOAD_KEY,
+            value_func: value_func.clone(),
+            values_func: values_func.clone(),
+        };
+
+        let constraint = WorkBalanceModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(objective.clone()))],
+            value_func,
+            values_func,
+            state_key: BALANCE_WORKLOAD_KEY,
+            keys: vec![BALANCE_WORKLOAD_KEY],
+        };
+
+        (Box::new(constraint), Box::new(objective))
+    }
+
     /// Creates _(constraint, objective)_  type pair which balances travelled distances across all tours.
     pub fn new_distance_balanced(
         threshold: Option<f64>,
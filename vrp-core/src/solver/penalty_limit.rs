@@ -0,0 +1,68 @@
+//! A module which provides the logic to keep total soft penalty of a solution within a limit.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/penalty_limit_test.rs"]
+mod penalty_limit_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::Cost;
+use crate::utils::compare_floats;
+
+/// A code which is used to mark jobs unassigned due to soft penalty limit.
+pub const PENALTY_LIMIT_CODE: i32 = 100;
+
+/// Removes jobs from the given solution until its total soft penalty, e.g. lateness, does not
+/// exceed the limit. So, a solution with too high penalty is not accepted as is, but sheds jobs.
+///
+/// On each step, a job is removed from the route with the highest penalty: the one which reduces
+/// route penalty the most. Removed jobs are marked as unassigned with `PENALTY_LIMIT_CODE`.
+/// Locked jobs are never removed. Returns amount of removed jobs.
+pub fn apply_penalty_limit(insertion_ctx: &mut InsertionContext, limit: Cost) -> usize {
+    let constraint = insertion_ctx.problem.constraint.clone();
+    let mut removed = 0;
+
+    loop {
+        let solution = &mut insertion_ctx.solution;
+        let total = solution.routes.iter().map(|route_ctx| route_ctx.get_route_penalty()).sum::<Cost>();
+
+        if compare_floats(total, limit).is_le() {
+            break;
+        }
+
+        let mut routes = solution.routes.iter().enumerate().collect::<Vec<_>>();
+        routes.sort_by(|(_, a), (_, b)| compare_floats(b.get_route_penalty(), a.get_route_penalty()));
+
+        let candidate = routes.into_iter().take_while(|(_, route_ctx)| route_ctx.get_route_penalty() > 0.).find_map(
+            |(route_idx, route_ctx)| {
+                route_ctx
+                    .route
+                    .tour
+                    .jobs()
+                    .filter(|job| !solution.locked.contains(job))
+                    .map(|job| {
+                        let mut new_route_ctx = route_ctx.deep_copy();
+                        new_route_ctx.route_mut().tour.remove(&job);
+                        constraint.accept_route_state(&mut new_route_ctx);
+
+                        (job, new_route_ctx)
+                    })
+                    .min_by(|(_, a), (_, b)| compare_floats(a.get_route_penalty(), b.get_route_penalty()))
+                    .map(|(job, new_route_ctx)| (route_idx, job, new_route_ctx))
+            },
+        );
+
+        if let Some((route_idx, job, new_route_ctx)) = candidate {
+            solution.routes[route_idx] = new_route_ctx;
+            solution.unassigned.insert(job, PENALTY_LIMIT_CODE);
+            removed += 1;
+        } else {
+            break;
+        }
+    }
+
+    if removed > 0 {
+        insertion_ctx.restore();
+    }
+
+    removed
+}
@@ -22,6 +22,7 @@ pub struct DominancePopulation {
     problem: Arc<Problem>,
     max_population_size: usize,
     individuals: Vec<Individual>,
+    is_initial_phase: bool,
 }
 
 /// Contains ordering information about individual in population.
@@ -41,7 +42,7 @@ impl DominancePopulation {
     pub fn new(problem: Arc<Problem>, max_population_size: usize) -> Self {
         assert!(max_population_size > 0);
 
-        Self { problem, max_population_size, individuals: vec![] }
+        Self { problem, max_population_size, individuals: vec![], is_initial_phase: false }
     }
 }
 
@@ -73,7 +74,7 @@ impl Population for DominancePopulation {
     }
 
     fn cmp(&self, a: &Individual, b: &Individual) -> Ordering {
-        self.problem.objective.total_order(a, b)
+        self.problem.objective.phased(self.is_initial_phase).total_order(a, b)
     }
 
     fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = (&Individual, usize)> + 'a> {
@@ -83,14 +84,30 @@ impl Population for DominancePopulation {
     fn size(&self) -> usize {
         self.individuals.len()
     }
+
+    fn set_initial_phase(&mut self, is_initial_phase: bool) {
+        let is_changed = self.is_initial_phase != is_initial_phase;
+        self.is_initial_phase = is_initial_phase;
+
+        // NOTE individuals have to be resorted using objectives of the new phase
+        if is_changed && self.size() > 0 {
+            self.sort();
+            self.ensure_max_population_size();
+        }
+    }
+
+    fn is_initial_phase(&self) -> bool {
+        self.is_initial_phase
+    }
 }
 
 impl DominancePopulation {
     fn sort(&mut self) {
-        let objective = self.problem.objective.clone();
+        let problem = self.problem.clone();
+        let objective = problem.objective.phased(self.is_initial_phase);
 
         // get best order
-        let best_order = select_and_rank(self.individuals.as_slice(), self.individuals.len(), objective.as_ref())
+        let best_order = select_and_rank(self.individuals.as_slice(), self.individuals.len(), &objective)
             .into_iter()
             .zip(0..)
             .map(|(acc, idx)| DominanceOrder {
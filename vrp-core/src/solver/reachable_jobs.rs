@@ -0,0 +1,125 @@
+//! A module which provides the logic to find jobs which can be served by a specific vehicle.
+
+use crate::construction::heuristics::*;
+use crate::models::common::IdDimension;
+use crate::models::problem::Job;
+use crate::models::Problem;
+use crate::utils::DefaultRandom;
+use std::sync::Arc;
+
+/// A key of problem extras which specifies codes of hard constraints which are violated regardless
+/// of other jobs, e.g. capacity or skills. Only violations of such constraints are considered by
+/// `analyze_usability`. When it is not set, all constraints are considered.
+pub const ISOLATED_CONSTRAINT_CODES_KEY: &str = "isolated_constraint_codes";
+
+/// Keeps results of pre-solve analysis of vehicles and jobs.
+#[derive(Clone, Debug, Default)]
+pub struct UsabilityReport {
+    /// Ids of vehicles which cannot serve any of the analyzed jobs.
+    pub unusable_vehicles: Vec<String>,
+    /// Ids of jobs which cannot be served by any vehicle.
+    pub unservable_jobs: Vec<String>,
+}
+
+/// Returns ids of jobs which can be served by the vehicle with given id in isolation: each job
+/// is evaluated against an empty route of every vehicle's actor (e.g. shift), so all hard
+/// constraints of the problem are applied, but interactions between jobs are not considered.
+pub fn reachable_jobs(problem: Arc<Problem>, vehicle_id: &str, jobs: &[Job]) -> Vec<String> {
+    let insertion_ctx = InsertionContext::new(problem, Arc::new(DefaultRandom::default()));
+    let routes = get_vehicle_routes(&insertion_ctx, vehicle_id);
+
+    jobs.iter()
+        .filter(|job| is_reachable(&insertion_ctx, routes.as_slice(), job))
+        .filter_map(|job| job.dimens().get_id().cloned())
+        .collect()
+}
+
+/// Finds vehicles which cannot be used, e.g. their capacity is below every demand, and jobs which
+/// cannot be served by any vehicle. The same isolated evaluation as in `reachable_jobs` is used, but
+/// a vehicle and a job are considered as incompatible only when the job is rejected by constraints
+/// specified with `ISOLATED_CONSTRAINT_CODES_KEY`: violations of constraints which depend on other
+/// jobs (e.g. companions) are not guaranteed to hold in the solution.
+pub fn analyze_usability(problem: Arc<Problem>, jobs: &[Job]) -> UsabilityReport {
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(DefaultRandom::default()));
+    let isolated_codes =
+        problem.extras.get(ISOLATED_CONSTRAINT_CODES_KEY).and_then(|codes| codes.downcast_ref::<Vec<i32>>());
+    let is_isolated = |code: i32| isolated_codes.map_or(true, |codes| codes.contains(&code));
+
+    let vehicle_routes = problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.dimens.get_id().cloned())
+        .map(|vehicle_id| {
+            let routes = get_vehicle_routes(&insertion_ctx, vehicle_id.as_str());
+            (vehicle_id, routes)
+        })
+        .collect::<Vec<_>>();
+
+    let unusable_vehicles = if jobs.is_empty() {
+        vec![]
+    } else {
+        vehicle_routes
+            .iter()
+            .filter(|(_, routes)| {
+                jobs.iter().all(|job| is_incompatible(&insertion_ctx, routes.as_slice(), job, &is_isolated))
+            })
+            .map(|(vehicle_id, _)| vehicle_id.clone())
+            .collect()
+    };
+
+    let unservable_jobs = jobs
+        .iter()
+        .filter(|job| {
+            vehicle_routes
+                .iter()
+                .all(|(_, routes)| is_incompatible(&insertion_ctx, routes.as_slice(), job, &is_isolated))
+        })
+        .filter_map(|job| job.dimens().get_id().cloned())
+        .collect();
+
+    UsabilityReport { unusable_vehicles, unservable_jobs }
+}
+
+/// Returns empty routes of all actors of the vehicle with given id with accepted route state.
+fn get_vehicle_routes(insertion_ctx: &InsertionContext, vehicle_id: &str) -> Vec<RouteContext> {
+    let constraint = insertion_ctx.problem.constraint.as_ref();
+
+    insertion_ctx
+        .problem
+        .fleet
+        .actors
+        .iter()
+        .filter(|actor| actor.vehicle.dimens.get_id().map_or(false, |id| id == vehicle_id))
+        .filter_map(|actor| insertion_ctx.solution.registry.get_route(actor))
+        .map(|route_ctx| {
+            let mut route_ctx = route_ctx.deep_copy();
+            constraint.accept_route_state(&mut route_ctx);
+
+            route_ctx
+        })
+        .collect()
+}
+
+fn is_reachable(insertion_ctx: &InsertionContext, routes: &[RouteContext], job: &Job) -> bool {
+    routes.iter().any(|route_ctx| {
+        matches!(
+            evaluate_job_insertion_in_route(job, insertion_ctx, route_ctx, InsertionPosition::Any, None),
+            InsertionResult::Success(_)
+        )
+    })
+}
+
+fn is_incompatible(
+    insertion_ctx: &InsertionContext,
+    routes: &[RouteContext],
+    job: &Job,
+    is_isolated: &dyn Fn(i32) -> bool,
+) -> bool {
+    routes.iter().all(|route_ctx| {
+        match evaluate_job_insertion_in_route(job, insertion_ctx, route_ctx, InsertionPosition::Any, None) {
+            InsertionResult::Success(_) => false,
+            InsertionResult::Failure(failure) => is_isolated(failure.constraint),
+        }
+    })
+}
@@ -0,0 +1,42 @@
+//! A module which provides the logic to estimate cost reduction from removing a job from a solution.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/removal_savings_test.rs"]
+mod removal_savings_test;
+
+use crate::construction::heuristics::{RouteContext, RouteState};
+use crate::models::common::{Cost, IdDimension};
+use crate::models::problem::Job;
+use crate::models::solution::Route;
+use crate::models::{Problem, Solution};
+use std::sync::Arc;
+
+/// Returns cost reduction from removing the job with given id from its route: a difference between
+/// route cost before and after removal. Route state is recalculated in both cases, so changes in
+/// schedules (e.g. waiting time) and other route costs are taken into account. A route left without
+/// jobs is considered as not used, so its whole cost is saved.
+/// Returns `None` if the job is not assigned to any route.
+pub fn removal_savings(problem: &Problem, solution: &Solution, job_id: &str) -> Option<Cost> {
+    let route = solution.routes.iter().find(|route| route.tour.jobs().any(|job| has_id(&job, job_id)))?;
+    let job = route.tour.jobs().find(|job| has_id(job, job_id))?;
+
+    let mut remaining = route.deep_copy();
+    remaining.tour.remove(&job);
+
+    let cost_before = get_route_cost(problem, route.deep_copy());
+    let cost_after = if remaining.tour.has_jobs() { get_route_cost(problem, remaining) } else { 0. };
+
+    Some(cost_before - cost_after)
+}
+
+/// Returns route cost with route state accepted from scratch.
+fn get_route_cost(problem: &Problem, route: Route) -> Cost {
+    let mut route_ctx = RouteContext { route: Arc::new(route), state: Arc::new(RouteState::default()) };
+    problem.constraint.accept_route_state(&mut route_ctx);
+
+    route_ctx.get_route_cost()
+}
+
+fn has_id(job: &Job, job_id: &str) -> bool {
+    job.dimens().get_id().map_or(false, |id| id == job_id)
+}
@@ -0,0 +1,127 @@
+//! A module which provides the logic to split one route of a solution into two.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/route_split_test.rs"]
+mod route_split_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::{Actor, Job};
+use crate::utils::compare_floats;
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+/// Splits the route with given index into two routes: jobs before a break point stay in the
+/// route, the rest are moved to a new route of an available actor keeping their order. All break
+/// points and available actors are tried and the split with the lowest total cost is applied.
+/// Returns false and keeps the solution intact if no feasible split is found.
+pub fn split_route(insertion_ctx: &mut InsertionContext, route_index: usize) -> bool {
+    let (actor, jobs) = match insertion_ctx.solution.routes.get(route_index) {
+        Some(route_ctx) => (route_ctx.route.actor.clone(), get_ordered_jobs(route_ctx)),
+        None => return false,
+    };
+
+    let new_actors =
+        insertion_ctx.solution.registry.next().map(|route_ctx| route_ctx.route.actor.clone()).collect::<Vec<_>>();
+
+    let best = (1..jobs.len())
+        .flat_map(|split| new_actors.iter().map(move |new_actor| (split, new_actor.clone())))
+        .filter_map(|(split, new_actor)| {
+            let (head, tail) = jobs.split_at(split);
+            try_split(insertion_ctx, route_index, (actor.clone(), head), (new_actor, tail))
+        })
+        .min_by(|a, b| compare_floats(a.solution.get_total_cost(), b.solution.get_total_cost()));
+
+    match best {
+        Some(best) => {
+            *insertion_ctx = best;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns jobs of the route in order of their first activity.
+fn get_ordered_jobs(route_ctx: &RouteContext) -> Vec<Job> {
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job())
+        .fold((HashSet::new(), vec![]), |(mut seen, mut jobs), job| {
+            if seen.insert(job.clone()) {
+                jobs.push(job);
+            }
+            (seen, jobs)
+        })
+        .1
+}
+
+fn try_split(
+    insertion_ctx: &InsertionContext,
+    route_index: usize,
+    head: (Arc<Actor>, &[Job]),
+    tail: (Arc<Actor>, &[Job]),
+) -> Option<InsertionContext> {
+    let mut new_ctx = insertion_ctx.deep_copy();
+    let unassigned = new_ctx.solution.unassigned.drain().collect::<Vec<_>>();
+
+    let route_ctx = new_ctx.solution.routes.get_mut(route_index).unwrap();
+    head.1.iter().chain(tail.1.iter()).for_each(|job| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+    new_ctx.solution.required.extend(head.1.iter().chain(tail.1.iter()).cloned());
+
+    let selector = SplitRouteSelector { head: head.1.iter().cloned().collect(), actors: (head.0, tail.0) };
+    let mut new_ctx = InsertionHeuristic::new(InsertionPosition::Last).process(
+        &selector,
+        &OrderedJobSelector {},
+        &PairJobMapReducer::new(Box::new(BestResultSelector::default())),
+        new_ctx,
+        &None,
+    );
+
+    let is_split = new_ctx.solution.unassigned.is_empty()
+        && [&selector.actors.0, &selector.actors.1].iter().zip([head.1, tail.1].iter()).all(|(actor, jobs)| {
+            new_ctx.solution.routes.iter().any(|route_ctx| {
+                route_ctx.route.actor == **actor && jobs.iter().all(|job| route_ctx.route.tour.contains(job))
+            })
+        });
+
+    if is_split {
+        new_ctx.solution.unassigned.extend(unassigned);
+        Some(new_ctx)
+    } else {
+        None
+    }
+}
+
+/// Selects the original route for head jobs and the new route for tail jobs.
+struct SplitRouteSelector {
+    head: HashSet<Job>,
+    actors: (Arc<Actor>, Arc<Actor>),
+}
+
+impl RouteSelector for SplitRouteSelector {
+    fn select<'a>(&'a self, ctx: &'a InsertionContext, job: &'a Job) -> Box<dyn Iterator<Item = RouteContext> + 'a> {
+        let actor = if self.head.contains(job) { &self.actors.0 } else { &self.actors.1 };
+
+        Box::new(
+            ctx.solution
+                .routes
+                .iter()
+                .find(|route_ctx| route_ctx.route.actor == *actor)
+                .cloned()
+                .or_else(|| ctx.solution.registry.get_route(actor))
+                .into_iter(),
+        )
+    }
+}
+
+/// Selects required jobs one by one in their original order.
+struct OrderedJobSelector {}
+
+impl JobSelector for OrderedJobSelector {
+    fn select<'a>(&'a self, ctx: &'a mut InsertionContext) -> Box<dyn Iterator<Item = Job> + 'a> {
+        Box::new(ctx.solution.required.iter().take(1).cloned())
+    }
+}
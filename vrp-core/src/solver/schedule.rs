@@ -0,0 +1,46 @@
+//! A module which provides the logic to schedule a solution with fixed assignments.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/schedule_test.rs"]
+mod schedule_test;
+
+use crate::construction::heuristics::{RouteContext, RouteState};
+use crate::models::{Problem, Solution};
+use std::sync::Arc;
+
+/// A key of solution extras which marks a solution produced by `create_scheduled_solution`.
+pub const SCHEDULE_ONLY_KEY: &str = "schedule_only";
+
+/// Recomputes schedules of all activities (arrival, departure and waiting times) and route
+/// states of the given solution without any optimization: routes, their actors and order of
+/// activities are kept as is, so the result can violate some of the problem constraints.
+/// The returned solution is marked in its extras with `SCHEDULE_ONLY_KEY`.
+pub fn create_scheduled_solution(problem: Arc<Problem>, solution: Solution) -> Solution {
+    let mut registry = solution.registry.deep_copy();
+
+    // NOTE solution state is not accepted as constraint modules can move jobs from/to routes there
+    let routes = solution
+        .routes
+        .iter()
+        .filter(|route| {
+            if route.tour.has_jobs() {
+                true
+            } else {
+                registry.free_actor(&route.actor);
+                false
+            }
+        })
+        .map(|route| {
+            let mut route_ctx =
+                RouteContext { route: Arc::new(route.deep_copy()), state: Arc::new(RouteState::default()) };
+            problem.constraint.accept_route_state(&mut route_ctx);
+
+            route_ctx.route.deep_copy()
+        })
+        .collect();
+
+    let mut extras = problem.extras.as_ref().clone();
+    extras.insert(SCHEDULE_ONLY_KEY.to_string(), Arc::new(true));
+
+    Solution { registry, routes, unassigned: solution.unassigned, extras: Arc::new(extras) }
+}
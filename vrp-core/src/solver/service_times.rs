@@ -0,0 +1,142 @@
+//! A module which provides the logic to estimate how often a solution schedule holds when service
+//! times of jobs are random.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/service_times_test.rs"]
+mod service_times_test;
+
+use crate::models::common::{Duration, Location, Timestamp, ValueDimension};
+use crate::models::problem::Multi;
+use crate::models::solution::{Activity, Route};
+use crate::models::{Problem, Solution};
+use crate::utils::{compare_floats, Random};
+use std::cmp::Ordering;
+
+/// A dimension key of job's service time distribution.
+pub const DURATION_DISTRIBUTION_KEY: &str = "duration_distribution";
+
+/// Specifies a random distribution of job's service time.
+#[derive(Clone, Debug)]
+pub enum DurationDistribution {
+    /// A normal distribution with given mean and standard deviation.
+    Normal {
+        /// A mean value.
+        mean: Duration,
+        /// A standard deviation.
+        std_dev: Duration,
+    },
+    /// A uniform distribution on the interval [min, max).
+    Uniform {
+        /// A minimum value.
+        min: Duration,
+        /// A maximum value.
+        max: Duration,
+    },
+}
+
+impl DurationDistribution {
+    /// Samples a service time from the distribution. Negative values are truncated to zero.
+    pub fn sample(&self, random: &dyn Random) -> Duration {
+        let value = match self {
+            DurationDistribution::Normal { mean, std_dev } => {
+                // NOTE Box-Muller transform: the first uniform value is shifted to (0, 1] to avoid ln(0)
+                let u1 = 1. - random.uniform_real(0., 1.);
+                let u2 = random.uniform_real(0., 1.);
+
+                mean + std_dev * (-2. * u1.ln()).sqrt() * (2. * std::f64::consts::PI * u2).cos()
+            }
+            DurationDistribution::Uniform { min, max } => random.uniform_real(*min, *max),
+        };
+
+        value.max(0.)
+    }
+}
+
+/// Keeps statistics of solution schedule feasibility estimated over random samples of service times.
+#[derive(Clone, Debug)]
+pub struct ScheduleFeasibility {
+    /// Total amount of samples.
+    pub samples: usize,
+    /// Amount of samples where all tours are feasible.
+    pub feasible: usize,
+    /// Amount of samples where the tour is feasible per each route of the solution.
+    pub routes: Vec<usize>,
+}
+
+impl ScheduleFeasibility {
+    /// Returns a probability that the whole schedule holds.
+    pub fn probability(&self) -> f64 {
+        if self.samples > 0 {
+            self.feasible as f64 / self.samples as f64
+        } else {
+            1.
+        }
+    }
+}
+
+/// Estimates how often the schedule of the given solution holds when service times of jobs with
+/// `DURATION_DISTRIBUTION_KEY` dimension are drawn from their distributions. Each sample replays
+/// all tours in their order: a tour is infeasible when any of its activities, including the tour
+/// end, is reached after the end of its time window. Other jobs use service time of the problem.
+pub fn estimate_schedule_feasibility(
+    problem: &Problem,
+    solution: &Solution,
+    samples: usize,
+    random: &dyn Random,
+) -> ScheduleFeasibility {
+    (0..samples).fold(
+        ScheduleFeasibility { samples, feasible: 0, routes: vec![0; solution.routes.len()] },
+        |mut acc, _| {
+            let results =
+                solution.routes.iter().map(|route| is_route_feasible(problem, route, random)).collect::<Vec<_>>();
+
+            if results.iter().all(|is_feasible| *is_feasible) {
+                acc.feasible += 1;
+            }
+
+            acc.routes
+                .iter_mut()
+                .zip(results)
+                .filter(|(_, is_feasible)| *is_feasible)
+                .for_each(|(count, _)| *count += 1);
+
+            acc
+        },
+    )
+}
+
+fn is_route_feasible(problem: &Problem, route: &Route, random: &dyn Random) -> bool {
+    let actor = route.actor.as_ref();
+    let start = match route.tour.start() {
+        Some(start) => start,
+        None => return true,
+    };
+    let init: (Location, Timestamp) = (start.place.location, start.schedule.departure);
+
+    route
+        .tour
+        .all_activities()
+        .skip(1)
+        .try_fold(init, |(location, departure), activity| {
+            let arrival = departure
+                + problem.transport.duration(actor.vehicle.profile, location, activity.place.location, departure);
+
+            if compare_floats(arrival, activity.place.time.end) == Ordering::Greater {
+                return None;
+            }
+
+            let duration = get_distribution(activity)
+                .map_or_else(|| problem.activity.duration(actor, activity, arrival), |dist| dist.sample(random));
+
+            Some((activity.place.location, arrival.max(activity.place.time.start) + duration))
+        })
+        .is_some()
+}
+
+fn get_distribution(activity: &Activity) -> Option<DurationDistribution> {
+    activity.job.as_ref().and_then(|single| {
+        single.dimens.get_value::<DurationDistribution>(DURATION_DISTRIBUTION_KEY).cloned().or_else(|| {
+            Multi::roots(single)?.dimens.get_value::<DurationDistribution>(DURATION_DISTRIBUTION_KEY).cloned()
+        })
+    })
+}
@@ -6,7 +6,7 @@ mod telemetry_test;
 
 use crate::algorithms::nsga2::{MultiObjective, Objective};
 use crate::construction::heuristics::InsertionContext;
-use crate::solver::{RefinementContext, Statistics};
+use crate::solver::{lower_bound, RefinementContext, Statistics};
 use crate::utils::Timer;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -24,6 +24,12 @@ pub struct Metrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<Generation>,
+    /// Objective value trajectory: population cost for every generation.
+    pub trajectory: Vec<TrajectoryPoint>,
+    /// A lower bound of the best solution cost.
+    pub lower_bound: f64,
+    /// An optimality gap of the best solution cost in percents from its lower bound.
+    pub gap: f64,
 }
 
 /// Represents information about generation.
@@ -42,6 +48,16 @@ pub struct Generation {
     pub population: Vec<Individual>,
 }
 
+/// Represents population cost at particular generation.
+pub struct TrajectoryPoint {
+    /// Generation sequence number.
+    pub generation: usize,
+    /// Cost of the best individual.
+    pub best: f64,
+    /// Mean cost of all individuals.
+    pub mean: f64,
+}
+
 /// Keeps essential information about particular individual in population.
 pub struct Individual {
     /// Rank in population.
@@ -103,7 +119,15 @@ impl Telemetry {
     pub fn new(mode: TelemetryMode) -> Self {
         Self {
             time: Timer::start(),
-            metrics: Metrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![] },
+            metrics: Metrics {
+                duration: 0,
+                generations: 0,
+                speed: 0.0,
+                evolution: vec![],
+                trajectory: vec![],
+                lower_bound: 0.,
+                gap: 0.,
+            },
             mode,
             improvement_tracker: ImprovementTracker::new(1000),
             next_generation: None,
@@ -156,6 +180,8 @@ impl Telemetry {
             }
         };
 
+        let is_tracked = track_population.is_some();
+
         if let Some((best_individual, rank)) = refinement_ctx.population.ranked().next() {
             let should_log_best = generation % *log_best.unwrap_or(&usize::MAX) == 0;
             let should_log_population = generation % *log_population.unwrap_or(&usize::MAX) == 0;
@@ -172,6 +198,10 @@ impl Telemetry {
         } else {
             self.log("no progress yet");
         }
+
+        if is_tracked {
+            self.on_trajectory(refinement_ctx);
+        }
     }
 
     /// Reports population state.
@@ -220,6 +250,26 @@ impl Telemetry {
         }
     }
 
+    /// Tracks population cost at current generation, the point of the same generation is replaced.
+    fn on_trajectory(&mut self, refinement_ctx: &RefinementContext) {
+        let objective = &refinement_ctx.problem.objective;
+        let costs = refinement_ctx
+            .population
+            .ranked()
+            .map(|(insertion_ctx, _)| objective.fitness(insertion_ctx))
+            .collect::<Vec<_>>();
+
+        if let Some(best) = costs.first().cloned() {
+            let generation = refinement_ctx.statistics.generation;
+            let point = TrajectoryPoint { generation, best, mean: costs.iter().sum::<f64>() / costs.len() as f64 };
+
+            match self.metrics.trajectory.last_mut() {
+                Some(last) if last.generation == generation => *last = point,
+                _ => self.metrics.trajectory.push(point),
+            }
+        }
+    }
+
     /// Reports final statistic.
     pub fn on_result(&mut self, refinement_ctx: &RefinementContext) {
         let should_log_population = match &self.mode {
@@ -231,6 +281,11 @@ impl Telemetry {
 
         self.on_population(refinement_ctx, should_log_population, false);
 
+        // NOTE population can be changed after the last generation, e.g. by objective phase switch
+        if matches!(self.mode, TelemetryMode::OnlyMetrics { .. } | TelemetryMode::All { .. }) {
+            self.on_trajectory(refinement_ctx);
+        }
+
         let elapsed = self.time.elapsed_secs() as usize;
         let speed = refinement_ctx.statistics.generation as f64 / self.time.elapsed_secs_as_f64();
 
@@ -242,6 +297,17 @@ impl Telemetry {
             .as_str(),
         );
 
+        if let Some((best_ctx, _)) = refinement_ctx.population.ranked().next() {
+            let lower_bound = lower_bound(best_ctx);
+            let cost = refinement_ctx.problem.objective.fitness(best_ctx);
+            let gap = if cost > 0. { (cost - lower_bound) / cost * 100. } else { 0. };
+
+            self.log(format!("[{}s] lower bound: {:.2}, gap: {:.3}%", elapsed, lower_bound, gap).as_str());
+
+            self.metrics.lower_bound = lower_bound;
+            self.metrics.gap = gap;
+        }
+
         self.metrics.duration = elapsed;
         self.metrics.speed = speed;
     }
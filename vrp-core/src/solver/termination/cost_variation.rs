@@ -5,7 +5,7 @@ mod cost_variation_test;
 use crate::algorithms::nsga2::Objective;
 use crate::algorithms::statistics::get_cv;
 use crate::models::common::Cost;
-use crate::solver::termination::Termination;
+use crate::solver::termination::{Termination, TerminationReason};
 use crate::solver::RefinementContext;
 
 /// A termination criteria which is in terminated state based on cost variation during the refinement
@@ -50,4 +50,8 @@ impl Termination for CostVariation {
             false
         }
     }
+
+    fn reason(&self) -> TerminationReason {
+        TerminationReason::Stagnation
+    }
 }
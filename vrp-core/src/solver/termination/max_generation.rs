@@ -2,7 +2,7 @@
 #[path = "../../../tests/unit/solver/termination/max_generation_test.rs"]
 mod max_generation_test;
 
-use crate::solver::termination::Termination;
+use crate::solver::termination::{Termination, TerminationReason};
 use crate::solver::RefinementContext;
 
 /// A termination criteria which is in terminated state when maximum amount of generations is exceeded.
@@ -21,4 +21,8 @@ impl Termination for MaxGeneration {
     fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
         refinement_ctx.statistics.generation >= self.limit
     }
+
+    fn reason(&self) -> TerminationReason {
+        TerminationReason::MaxGenerations
+    }
 }
@@ -1,4 +1,4 @@
-use crate::solver::termination::Termination;
+use crate::solver::termination::{Termination, TerminationReason};
 use crate::solver::RefinementContext;
 use crate::utils::Timer;
 
@@ -19,4 +19,8 @@ impl Termination for MaxTime {
     fn is_termination(&self, _: &mut RefinementContext) -> bool {
         self.start.elapsed_secs_as_f64() > self.limit_in_secs
     }
+
+    fn reason(&self) -> TerminationReason {
+        TerminationReason::MaxTime
+    }
 }
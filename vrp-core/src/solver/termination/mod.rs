@@ -7,6 +7,63 @@ use crate::solver::RefinementContext;
 pub trait Termination {
     /// Returns true if termination condition is met.
     fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool;
+
+    /// Returns a reason reported when termination condition is met. Default is custom termination.
+    fn reason(&self) -> TerminationReason {
+        TerminationReason::CustomTermination
+    }
+
+    /// Returns a reason of termination if termination condition is met.
+    fn check_termination(&self, refinement_ctx: &mut RefinementContext) -> Option<TerminationReason> {
+        if self.is_termination(refinement_ctx) {
+            Some(self.reason())
+        } else {
+            None
+        }
+    }
+}
+
+/// Specifies a reason why refinement process is stopped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TerminationReason {
+    /// Maximum amount of generations is reached.
+    MaxGenerations,
+    /// Maximum time is elapsed.
+    MaxTime,
+    /// Best known solution is not improved enough: see `CostVariation`.
+    Stagnation,
+    /// A termination criteria not known to the solver is met.
+    CustomTermination,
+    /// Refinement process is interrupted by quota.
+    Cancelled,
+}
+
+/// Contains information about how refinement process is stopped.
+#[derive(Clone, Debug)]
+pub struct TerminationReport {
+    /// A termination reason.
+    pub reason: TerminationReason,
+    /// Total amount of generations.
+    pub generations: usize,
+    /// Total refinement duration in seconds.
+    pub duration: f64,
+    /// Time spent in each phase of refinement process. Collected only when profiling is enabled.
+    pub phase_timings: Option<PhaseTimings>,
+}
+
+/// Contains time in seconds spent in each phase of refinement process.
+#[derive(Clone, Debug, Default)]
+pub struct PhaseTimings {
+    /// Time spent to build initial solutions.
+    pub construction: f64,
+    /// Time spent to select parents.
+    pub selection: f64,
+    /// Time spent to mutate parents, e.g. by ruin and recreate.
+    pub mutation: f64,
+    /// Time spent to evaluate offspring and add it to population.
+    pub evaluation: f64,
+    /// Time spent in the rest of refinement loop, e.g. termination checks and telemetry.
+    pub other: f64,
 }
 
 mod cost_variation;
@@ -32,6 +89,10 @@ impl CompositeTermination {
 
 impl Termination for CompositeTermination {
     fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
-        self.terminations.iter().any(|t| t.is_termination(refinement_ctx))
+        self.check_termination(refinement_ctx).is_some()
+    }
+
+    fn check_termination(&self, refinement_ctx: &mut RefinementContext) -> Option<TerminationReason> {
+        self.terminations.iter().find_map(|t| t.check_termination(refinement_ctx))
     }
 }
@@ -0,0 +1,84 @@
+//! A module which provides the logic to re-optimize the worst route of a solution.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/worst_route_test.rs"]
+mod worst_route_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::Actor;
+use crate::solver::inter_route::try_move;
+use crate::solver::mutation::{get_route_neighbours, get_worst_route};
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Re-optimizes the worst route of the solution: the one with the highest cost per job which can
+/// be moved. Its jobs are re-sequenced using cheapest insertion and, additionally, each of them is
+/// tried to be exchanged with up to `neighbours` closest jobs served by other routes.
+///
+/// A candidate is accepted only if it reduces cost of the worst route and does not increase cost
+/// of any other route. The cheapest accepted candidate is applied. Returns false and keeps the
+/// solution intact if there is no such candidate. Locked jobs are not moved.
+pub fn improve_worst_route(insertion_ctx: &mut InsertionContext, neighbours: usize) -> bool {
+    let route_ctx = match get_worst_route(insertion_ctx) {
+        Some(route_ctx) => route_ctx,
+        None => return false,
+    };
+
+    let actor = route_ctx.route.actor.clone();
+    let jobs =
+        route_ctx.route.tour.jobs().filter(|job| !insertion_ctx.solution.locked.contains(job)).collect::<Vec<_>>();
+    let neighbours = get_route_neighbours(insertion_ctx, route_ctx, neighbours)
+        .into_iter()
+        .filter_map(|job| {
+            let route_ctx = insertion_ctx.solution.routes.iter().find(|rc| rc.route.tour.contains(&job))?;
+            Some((job, route_ctx.route.actor.clone()))
+        })
+        .collect::<Vec<_>>();
+
+    let resequence = vec![jobs.iter().map(|job| (job.clone(), actor.clone())).collect::<Vec<_>>()];
+    let actor = &actor;
+    let exchanges = jobs.iter().flat_map(|job| {
+        neighbours
+            .iter()
+            .map(move |(other, other_actor)| vec![(job.clone(), other_actor.clone()), (other.clone(), actor.clone())])
+    });
+
+    let original_costs = get_route_costs(insertion_ctx);
+    let is_improved = |new_ctx: &InsertionContext| {
+        get_route_costs(new_ctx).iter().all(|(route_actor, cost)| {
+            let original = original_costs.iter().find(|(a, _)| a == route_actor).map_or(0., |(_, cost)| *cost);
+
+            match compare_floats(*cost, original) {
+                Ordering::Less => true,
+                Ordering::Equal => route_actor != actor,
+                Ordering::Greater => false,
+            }
+        })
+    };
+
+    let best = resequence
+        .into_iter()
+        .chain(exchanges)
+        .filter_map(|moves| try_move(insertion_ctx, moves))
+        .filter(|new_ctx| is_improved(new_ctx))
+        .min_by(|a, b| compare_floats(a.solution.get_total_cost(), b.solution.get_total_cost()));
+
+    match best {
+        Some(best) => {
+            *insertion_ctx = best;
+            true
+        }
+        None => false,
+    }
+}
+
+fn get_route_costs(insertion_ctx: &InsertionContext) -> Vec<(Arc<Actor>, Cost)> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| (route_ctx.route.actor.clone(), route_ctx.get_route_cost()))
+        .collect()
+}
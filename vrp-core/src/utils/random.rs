@@ -3,6 +3,7 @@
 mod random_test;
 
 use rand::prelude::*;
+use std::sync::Mutex;
 
 /// Provides the way to use randomized values in generic way.
 pub trait Random {
@@ -75,3 +76,38 @@ impl Default for DefaultRandom {
         Self { seed: None }
     }
 }
+
+/// Derives a new seed from the given one and key, so independent random streams can be created
+/// deterministically from one master seed.
+pub fn derive_seed(seed: u64, key: &str) -> u64 {
+    // NOTE FNV-1a hash is used as it is stable across platforms and compiler versions
+    let hash = key
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64 ^ seed, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3));
+
+    // NOTE splitmix64 finalizer spreads small differences in keys over all bits
+    let hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    let hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    hash ^ (hash >> 31)
+}
+
+/// A random implementation which represents a deterministic stream of random values: unlike
+/// seeded `DefaultRandom`, each `get_rng` call advances the stream state.
+pub struct StreamRandom {
+    rng: Mutex<StdRng>,
+}
+
+impl StreamRandom {
+    /// Creates a new instance of `StreamRandom` which stream is derived from the given seed and key.
+    pub fn new(seed: u64, key: &str) -> Self {
+        Self { rng: Mutex::new(StdRng::seed_from_u64(derive_seed(seed, key))) }
+    }
+}
+
+impl Random for StreamRandom {
+    fn get_rng(&self) -> StdRng {
+        let seed = self.rng.lock().unwrap().gen::<u64>();
+        StdRng::seed_from_u64(seed)
+    }
+}
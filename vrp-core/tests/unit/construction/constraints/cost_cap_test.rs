@@ -0,0 +1,81 @@
+use crate::construction::constraints::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::Cost;
+use crate::models::problem::Fleet;
+use std::sync::Arc;
+
+fn create_module(cap: Option<Cost>) -> CostCapModule {
+    CostCapModule::new(Arc::new(TestActivityCost::default()), TestTransportCost::new_shared(), Arc::new(move |_| cap))
+}
+
+fn create_pipeline(cap: Option<Cost>) -> ConstraintPipeline {
+    let mut pipeline = create_constraint_pipeline_with_transport();
+    pipeline.add_module(Box::new(create_module(cap)));
+
+    pipeline
+}
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build()
+}
+
+parameterized_test! {can_cap_route_cost, (cap, expected), {
+    can_cap_route_cost_impl(cap, expected);
+}}
+
+can_cap_route_cost! {
+    case01: (None, 80.),
+    case02: (Some(100.), 80.),
+    case03: (Some(80.), 80.),
+    case04: (Some(50.), 50.),
+}
+
+fn can_cap_route_cost_impl(cap: Option<Cost>, expected: Cost) {
+    let mut route_ctx =
+        create_route_context_with_activities(&create_fleet(), "v1", vec![test_activity_with_location(10)]);
+
+    create_pipeline(cap).accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.get_route_usage_cost(), 80.);
+    assert_eq!(route_ctx.get_route_cost(), expected);
+}
+
+parameterized_test! {can_estimate_cost_cap_discount_of_insertion, (cap, expected), {
+    can_estimate_cost_cap_discount_of_insertion_impl(cap, expected);
+}}
+
+can_estimate_cost_cap_discount_of_insertion! {
+    case01: (None, 0.),
+    case02: (Some(120.), 0.),
+    case03: (Some(100.), -20.),
+    case04: (Some(80.), -40.),
+    case05: (Some(50.), -40.),
+}
+
+fn can_estimate_cost_cap_discount_of_insertion_impl(cap: Option<Cost>, expected: Cost) {
+    let mut route_ctx =
+        create_route_context_with_activities(&create_fleet(), "v1", vec![test_activity_with_location(10)]);
+    create_pipeline(cap).accept_route_state(&mut route_ctx);
+    let target = test_activity_with_location(15);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(2),
+    };
+
+    let result = create_module(cap)
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::SoftActivity(constraint) => {
+                Some(constraint.estimate_activity(&route_ctx, &activity_ctx))
+            }
+            _ => None,
+        })
+        .sum::<f64>();
+
+    assert_eq!(result, expected);
+}
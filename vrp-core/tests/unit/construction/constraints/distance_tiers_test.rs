@@ -0,0 +1,89 @@
+use crate::construction::constraints::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Fleet;
+use std::sync::Arc;
+
+const THRESHOLD: f64 = 25.;
+const EXTRA_PER_DISTANCE: f64 = 2.;
+
+fn create_module() -> DistanceTiersModule {
+    DistanceTiersModule::new(
+        TestTransportCost::new_shared(),
+        Arc::new(|_, distance| (distance - THRESHOLD).max(0.) * EXTRA_PER_DISTANCE),
+    )
+}
+
+fn create_pipeline() -> ConstraintPipeline {
+    let mut pipeline = create_constraint_pipeline_with_transport();
+    pipeline.add_module(Box::new(create_module()));
+
+    pipeline
+}
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build()
+}
+
+parameterized_test! {can_calculate_distance_tiers_cost_of_route, (locations, expected), {
+    can_calculate_distance_tiers_cost_of_route_impl(locations, expected);
+}}
+
+can_calculate_distance_tiers_cost_of_route! {
+    case01: (vec![5], 0.),
+    case02: (vec![10, 12], 0.),
+    case03: (vec![10, 15], 10.),
+    case04: (vec![30], 70.),
+}
+
+fn can_calculate_distance_tiers_cost_of_route_impl(locations: Vec<usize>, expected: f64) {
+    let mut route_ctx = create_route_context_with_activities(
+        &create_fleet(),
+        "v1",
+        locations.into_iter().map(test_activity_with_location).collect(),
+    );
+
+    create_pipeline().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<f64>(DISTANCE_TIERS_COST_KEY).cloned(), Some(expected));
+}
+
+parameterized_test! {can_estimate_distance_tiers_cost_of_insertion, (existing, target, expected), {
+    can_estimate_distance_tiers_cost_of_insertion_impl(existing, target, expected);
+}}
+
+can_estimate_distance_tiers_cost_of_insertion! {
+    case01: (5, 10, 0.),
+    case02: (10, 12, 0.),
+    case03: (10, 15, 10.),
+    case04: (15, 5, 0.),
+    case05: (15, 20, 20.),
+    case06: (5, 20, 30.),
+}
+
+fn can_estimate_distance_tiers_cost_of_insertion_impl(existing: usize, target: usize, expected: f64) {
+    let mut route_ctx =
+        create_route_context_with_activities(&create_fleet(), "v1", vec![test_activity_with_location(existing)]);
+    create_pipeline().accept_route_state(&mut route_ctx);
+    let target = test_activity_with_location(target);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let result = create_module()
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::SoftActivity(constraint) => {
+                Some(constraint.estimate_activity(&route_ctx, &activity_ctx))
+            }
+            _ => None,
+        })
+        .sum::<f64>();
+
+    assert_eq!(result, expected);
+}
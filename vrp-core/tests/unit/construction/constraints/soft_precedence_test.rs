@@ -0,0 +1,83 @@
+use crate::construction::constraints::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Fleet, Job};
+use crate::models::solution::Activity;
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build()
+}
+
+fn get_job(activity: &Activity) -> Job {
+    activity.retrieve_job().unwrap()
+}
+
+parameterized_test! {can_calculate_precedence_penalty_of_route, (precedences, expected), {
+    can_calculate_precedence_penalty_of_route_impl(precedences, expected);
+}}
+
+can_calculate_precedence_penalty_of_route! {
+    case01: (vec![(0, 1, 5.)], 0.),
+    case02: (vec![(1, 0, 5.)], 5.),
+    case03: (vec![(1, 0, 5.), (2, 1, 3.)], 8.),
+    case04: (vec![(0, 2, 5.), (2, 1, 3.)], 3.),
+}
+
+fn can_calculate_precedence_penalty_of_route_impl(precedences: Vec<(usize, usize, f64)>, expected: f64) {
+    let activities = vec![10, 20, 30].into_iter().map(test_activity_with_location).collect::<Vec<_>>();
+    let jobs = activities.iter().map(get_job).collect::<Vec<_>>();
+    let precedences = precedences
+        .into_iter()
+        .map(|(before, after, penalty)| (jobs[before].clone(), jobs[after].clone(), penalty))
+        .collect();
+    let mut route_ctx = create_route_context_with_activities(&create_fleet(), "v1", activities);
+
+    SoftPrecedenceModule::new(precedences).accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<f64>(PRECEDENCE_PENALTY_KEY).cloned(), Some(expected));
+}
+
+parameterized_test! {can_estimate_precedence_penalty_of_insertion, (is_target_first, index, expected), {
+    can_estimate_precedence_penalty_of_insertion_impl(is_target_first, index, expected);
+}}
+
+can_estimate_precedence_penalty_of_insertion! {
+    case01: (true, 0, 0.),
+    case02: (true, 1, 7.),
+    case03: (true, 2, 7.),
+    case04: (false, 0, 7.),
+    case05: (false, 1, 0.),
+    case06: (false, 2, 0.),
+}
+
+fn can_estimate_precedence_penalty_of_insertion_impl(is_target_first: bool, index: usize, expected: f64) {
+    let activities = vec![10, 20].into_iter().map(test_activity_with_location).collect::<Vec<_>>();
+    let target = test_activity_with_location(30);
+    let (before, after) = if is_target_first {
+        (get_job(&target), get_job(&activities[0]))
+    } else {
+        (get_job(&activities[0]), get_job(&target))
+    };
+    let module = SoftPrecedenceModule::new(vec![(before, after, 7.)]);
+    let mut route_ctx = create_route_context_with_activities(&create_fleet(), "v1", activities);
+    module.accept_route_state(&mut route_ctx);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::SoftActivity(constraint) => {
+                Some(constraint.estimate_activity(&route_ctx, &activity_ctx))
+            }
+            _ => None,
+        })
+        .sum::<f64>();
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,82 @@
+use crate::construction::constraints::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+use crate::models::problem::Fleet;
+use std::sync::Arc;
+
+const PENALTY: f64 = 2.;
+
+fn create_pipeline() -> ConstraintPipeline {
+    let mut pipeline = create_constraint_pipeline_with_transport();
+    pipeline.add_module(Box::new(SoftTimeWindowModule::new(
+        Arc::new(TestActivityCost::default()),
+        TestTransportCost::new_shared(),
+        Arc::new(|activity| activity.job.as_ref().map(|_| (activity.place.time.end, PENALTY))),
+    )));
+
+    pipeline
+}
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build()
+}
+
+parameterized_test! {can_calculate_lateness_penalty_of_route, (tw_ends, expected), {
+    can_calculate_lateness_penalty_of_route_impl(tw_ends, expected);
+}}
+
+can_calculate_lateness_penalty_of_route! {
+    case01: ((100., 100.), 0.),
+    case02: ((5., 100.), 10.),
+    case03: ((5., 15.), 20.),
+}
+
+fn can_calculate_lateness_penalty_of_route_impl(tw_ends: (f64, f64), expected: f64) {
+    let mut route_ctx = create_route_context_with_activities(
+        &create_fleet(),
+        "v1",
+        vec![
+            test_activity_with_location_and_tw(10, TimeWindow::new(0., tw_ends.0)),
+            test_activity_with_location_and_tw(20, TimeWindow::new(0., tw_ends.1)),
+        ],
+    );
+
+    create_pipeline().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<f64>(LATENESS_PENALTY_KEY).cloned(), Some(expected));
+}
+
+parameterized_test! {can_estimate_lateness_penalty_of_insertion, (target_tw_end, next_tw_end, expected), {
+    can_estimate_lateness_penalty_of_insertion_impl(target_tw_end, next_tw_end, expected);
+}}
+
+can_estimate_lateness_penalty_of_insertion! {
+    case01: (100., 100., 0.),
+    case02: (5., 100., 10.),
+    case03: (5., 20., 10.),
+    case04: (100., 15., 0.),
+}
+
+fn can_estimate_lateness_penalty_of_insertion_impl(target_tw_end: f64, next_tw_end: f64, expected: f64) {
+    let pipeline = create_pipeline();
+    let mut route_ctx = create_route_context_with_activities(
+        &create_fleet(),
+        "v1",
+        vec![test_activity_with_location_and_tw(20, TimeWindow::new(0., next_tw_end))],
+    );
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_location_and_tw(10, TimeWindow::new(0., target_tw_end));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let result = pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
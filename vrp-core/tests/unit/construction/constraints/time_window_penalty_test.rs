@@ -0,0 +1,84 @@
+use crate::construction::constraints::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+use crate::models::problem::Fleet;
+use std::sync::Arc;
+
+const FALLBACK_START: f64 = 10.;
+const PENALTY: f64 = 3.;
+
+fn create_pipeline() -> ConstraintPipeline {
+    let mut pipeline = create_constraint_pipeline_with_transport();
+    pipeline.add_module(Box::new(TimeWindowPenaltyModule::new(Arc::new(|activity| {
+        if activity.job.is_some() && activity.place.time.start >= FALLBACK_START {
+            PENALTY
+        } else {
+            0.
+        }
+    }))));
+
+    pipeline
+}
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build()
+}
+
+parameterized_test! {can_calculate_time_window_penalty_of_route, (tw_starts, expected), {
+    can_calculate_time_window_penalty_of_route_impl(tw_starts, expected);
+}}
+
+can_calculate_time_window_penalty_of_route! {
+    case01: ((0., 0.), 0.),
+    case02: ((FALLBACK_START, 0.), PENALTY),
+    case03: ((FALLBACK_START, FALLBACK_START), 2. * PENALTY),
+}
+
+fn can_calculate_time_window_penalty_of_route_impl(tw_starts: (f64, f64), expected: f64) {
+    let mut route_ctx = create_route_context_with_activities(
+        &create_fleet(),
+        "v1",
+        vec![
+            test_activity_with_location_and_tw(10, TimeWindow::new(tw_starts.0, 1000.)),
+            test_activity_with_location_and_tw(20, TimeWindow::new(tw_starts.1, 1000.)),
+        ],
+    );
+
+    create_pipeline().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<f64>(TIME_WINDOW_PENALTY_KEY).cloned(), Some(expected));
+    assert_eq!(route_ctx.get_route_penalty(), expected);
+}
+
+parameterized_test! {can_estimate_time_window_penalty_of_insertion, (target_tw_start, expected), {
+    can_estimate_time_window_penalty_of_insertion_impl(target_tw_start, expected);
+}}
+
+can_estimate_time_window_penalty_of_insertion! {
+    case01: (0., 0.),
+    case02: (FALLBACK_START, PENALTY),
+}
+
+fn can_estimate_time_window_penalty_of_insertion_impl(target_tw_start: f64, expected: f64) {
+    let pipeline = create_pipeline();
+    let mut route_ctx = create_route_context_with_activities(
+        &create_fleet(),
+        "v1",
+        vec![test_activity_with_location_and_tw(20, TimeWindow::new(FALLBACK_START, 1000.))],
+    );
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_location_and_tw(10, TimeWindow::new(target_tw_start, 1000.));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let result = pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -1,5 +1,9 @@
-use crate::construction::heuristics::RouteState;
+use crate::construction::heuristics::{RegistryContext, RouteState};
+use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::test_activity;
+use crate::models::common::IdDimension;
+use crate::models::solution::Registry;
+use std::sync::Arc;
 
 #[test]
 fn can_put_and_get_activity_state() {
@@ -77,3 +81,46 @@ fn can_remove_activity_states() {
     assert!(result1.is_none());
     assert!(result2.is_none());
 }
+
+#[test]
+fn can_modify_available_routes() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let mut registry = RegistryContext::new(Registry::new(&fleet));
+    let original = registry.deep_copy();
+    let used = registry.next().next().unwrap();
+    registry.use_route(&used);
+
+    registry.modify_available(|route_ctx| route_ctx.state_mut().put_route_state(1, "my_value".to_string()));
+
+    let get_value = |registry: &RegistryContext, vehicle_id: &str| {
+        let actor = fleet.actors.iter().find(|actor| actor.vehicle.dimens.get_id().unwrap() == vehicle_id).unwrap();
+        registry.get_route(actor).unwrap().state.get_route_state::<String>(1).cloned()
+    };
+    let used_id = used.route.actor.vehicle.dimens.get_id().unwrap().clone();
+    let available_id = if used_id == "v1" { "v2" } else { "v1" };
+    assert_eq!(get_value(&registry, used_id.as_str()), None);
+    assert_eq!(get_value(&registry, available_id), Some("my_value".to_string()));
+    assert_eq!(get_value(&original, available_id), None);
+}
+
+#[test]
+fn can_modify_unshared_available_routes_in_place() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut registry = RegistryContext::new(Registry::new(&fleet));
+    let original = registry.deep_copy();
+    registry.modify_available(|route_ctx| route_ctx.state_mut().put_route_state(1, 1));
+    drop(original);
+    let get_route_ptr = |registry: &RegistryContext| {
+        registry.get_route(fleet.actors.first().unwrap()).map(|route_ctx| Arc::as_ptr(&route_ctx.route)).unwrap()
+    };
+    let route_ptr = get_route_ptr(&registry);
+
+    registry.modify_available(|route_ctx| route_ctx.state_mut().put_route_state(1, 2));
+
+    assert_eq!(get_route_ptr(&registry), route_ptr);
+    let actor = fleet.actors.first().unwrap();
+    assert_eq!(registry.get_route(actor).unwrap().state.get_route_state::<i32>(1).cloned(), Some(2));
+}
@@ -1,3 +1,4 @@
+use crate::construction::constraints::FleetUsageConstraintModule;
 use crate::construction::heuristics::evaluators::evaluate_job_insertion;
 use crate::construction::heuristics::*;
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
@@ -176,6 +177,110 @@ mod single {
             unreachable!()
         }
     }
+
+    parameterized_test! {can_keep_insertion_result_with_route_costs, (route_costs, route_cost_factor, expected_used_vehicle), {
+        can_keep_insertion_result_with_route_costs_impl(route_costs, route_cost_factor, expected_used_vehicle);
+    }}
+
+    can_keep_insertion_result_with_route_costs! {
+        case01: (0., None, "v1"),
+        case02: (1000., None, "v1"),
+        case03: (-1000., None, "v2"),
+        case04: (-1000., Some(1.), "v2"),
+        case05: (-1000., Some(0.), "v1"),
+    }
+
+    fn can_keep_insertion_result_with_route_costs_impl(
+        route_costs: Cost,
+        route_cost_factor: Option<f64>,
+        expected_used_vehicle: &str,
+    ) {
+        let mut registry = Registry::new(
+            &FleetBuilder::default()
+                .add_driver(test_driver_with_costs(empty_costs()))
+                .add_vehicles(vec![
+                    VehicleBuilder::default().id("v1").build(),
+                    VehicleBuilder::default()
+                        .id("v2")
+                        .details(vec![VehicleDetail {
+                            start: Some(VehiclePlace {
+                                location: 20,
+                                time: TimeInterval { earliest: Some(0.), latest: None },
+                            }),
+                            end: None,
+                        }])
+                        .build(),
+                ])
+                .build(),
+        );
+        let actor = registry.next().find(|actor| get_vehicle_id(&actor.vehicle) == "v1").unwrap();
+        registry.use_actor(&actor);
+        let mut route_ctx = RouteContext::new(actor);
+        route_ctx.route_mut().tour.insert_at(create_activity_at(5), 1);
+        let mut constraint = create_constraint_pipeline_with_transport();
+        constraint.add_module(Box::new(FleetUsageConstraintModule::new_with_cost(route_costs)));
+        constraint.accept_route_state(&mut route_ctx);
+        let mut ctx = create_insertion_context(registry, constraint, vec![route_ctx]);
+        if let Some(route_cost_factor) = route_cost_factor {
+            let mut extras = ctx.problem.extras.as_ref().clone();
+            extras.insert(SOFT_ROUTE_COST_FACTOR_KEY.to_string(), Arc::new(route_cost_factor));
+            Arc::get_mut(&mut ctx.problem).unwrap().extras = Arc::new(extras);
+        }
+        let job = Job::Single(test_single_with_location(Some(3)));
+        let expected = AllRouteSelector::default()
+            .select(&ctx, &job)
+            .map(|route_ctx| evaluate_job_insertion_in_route(&job, &ctx, &route_ctx, InsertionPosition::Any, None))
+            .fold(InsertionResult::make_failure(), InsertionResult::choose_best_result);
+
+        let result = evaluate_job_insertion(&job, &ctx, &AllRouteSelector::default(), InsertionPosition::Any);
+
+        match (result, expected) {
+            (InsertionResult::Success(success), InsertionResult::Success(expected)) => {
+                assert_eq!(get_vehicle_id(success.context.route.actor.vehicle.deref()), expected_used_vehicle);
+                assert!(success.context.route.actor == expected.context.route.actor);
+                assert_eq!(compare_floats(success.cost, expected.cost), Ordering::Equal);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    parameterized_test! {can_estimate_insertion_without_modifying_context, (location, position, is_feasible), {
+        can_estimate_insertion_without_modifying_context_impl(location, position, is_feasible);
+    }}
+
+    can_estimate_insertion_without_modifying_context! {
+        case01: (7, 0, true),
+        case02: (7, 1, true),
+        case03: (7, 2, true),
+        case04: (7, 3, false),
+        case05: (1111, 1, false),
+    }
+
+    fn can_estimate_insertion_without_modifying_context_impl(location: Location, position: usize, is_feasible: bool) {
+        let registry = create_test_registry();
+        let mut route_ctx = RouteContext::new(registry.next().next().unwrap());
+        route_ctx.route_mut().tour.insert_at(create_activity_at(5), 1).insert_at(create_activity_at(10), 2);
+        let constraint = create_constraint_pipeline_with_transport();
+        constraint.accept_route_state(&mut route_ctx);
+        let mut ctx = create_insertion_context(registry, constraint, vec![route_ctx]);
+        let job = Job::Single(test_single_with_location(Some(location)));
+        let original_cost = ctx.solution.get_total_cost();
+
+        let estimate = estimate_insertion(&ctx, &job, 0, position);
+
+        assert_eq!(estimate.is_some(), is_feasible);
+        assert_eq!(ctx.solution.routes[0].route.tour.activity_count(), 2);
+        assert_eq!(compare_floats(ctx.solution.get_total_cost(), original_cost), Ordering::Equal);
+        if let Some(estimate) = estimate {
+            let mut activity = Activity::new_with_job(job.to_single().clone());
+            activity.place =
+                Place { location, duration: DEFAULT_JOB_DURATION, time: DEFAULT_JOB_TIME_SPAN.to_time_window(0.) };
+            ctx.solution.routes[0].route_mut().tour.insert_at(activity, position + 1);
+            ctx.problem.constraint.clone().accept_insertion(&mut ctx.solution, 0, &job);
+
+            assert_eq!(compare_floats(ctx.solution.get_total_cost() - original_cost, estimate), Ordering::Equal);
+        }
+    }
 }
 
 mod multi {
@@ -297,3 +402,91 @@ mod multi {
         }
     }
 }
+
+mod neighbors {
+    use super::*;
+    use crate::construction::heuristics::evaluators::{get_nearest_routes, INSERTION_NEIGHBORS_KEY};
+    use crate::helpers::models::solution::test_activity_with_job;
+    use crate::helpers::solver::generate_matrix_routes;
+    use crate::helpers::utils::random::FakeRandom;
+    use crate::models::{Extras, Problem};
+
+    parameterized_test! {can_select_nearest_routes, (neighbors, expected), {
+        can_select_nearest_routes_impl(neighbors, expected);
+    }}
+
+    can_select_nearest_routes! {
+        case01_one: (1, vec!["0", "1"]),
+        case02_two: (2, vec!["0", "1", "2"]),
+        case03_all: (3, vec!["0", "1", "2", "3"]),
+        case04_more_than_routes: (10, vec!["0", "1", "2", "3"]),
+    }
+
+    fn can_select_nearest_routes_impl(neighbors: usize, expected: Vec<&str>) {
+        let (problem, mut solution) = generate_matrix_routes(1, 4, |data| (data.clone(), data));
+        let registry = &mut solution.registry;
+        solution.routes.iter().for_each(|route| {
+            registry.use_actor(&route.actor);
+        });
+        let mut extras = Extras::default();
+        extras.insert(INSERTION_NEIGHBORS_KEY.to_string(), Arc::new(neighbors));
+        let problem = Arc::new(Problem { extras: Arc::new(extras), ..problem });
+        let mut ctx =
+            InsertionContext::new_from_solution(problem, (solution, None), Arc::new(FakeRandom::new(vec![], vec![])));
+        let job = ctx.solution.routes[0].route.tour.jobs().next().unwrap();
+        ctx.solution.routes[0].route_mut().tour.remove(&job);
+
+        let routes = get_nearest_routes(&ctx, &job, AllRouteSelector::default().select(&ctx, &job), neighbors);
+
+        let mut ids =
+            routes.iter().map(|route_ctx| get_vehicle_id(&route_ctx.route.actor.vehicle).clone()).collect::<Vec<_>>();
+        ids.sort();
+        assert_eq!(ids, expected);
+    }
+
+    #[test]
+    fn can_select_nearest_routes_ignoring_unknown_jobs() {
+        let (problem, mut solution) = generate_matrix_routes(1, 4, |data| (data.clone(), data));
+        let registry = &mut solution.registry;
+        solution.routes.iter().for_each(|route| {
+            registry.use_actor(&route.actor);
+        });
+        let mut ctx = InsertionContext::new_from_solution(
+            Arc::new(problem),
+            (solution, None),
+            Arc::new(FakeRandom::new(vec![], vec![])),
+        );
+        let job = ctx.solution.routes[0].route.tour.jobs().next().unwrap();
+        ctx.solution.routes[0].route_mut().tour.remove(&job);
+        let known = ctx.solution.routes[1].route.tour.jobs().next().unwrap();
+        let tour = &mut ctx.solution.routes[1].route_mut().tour;
+        tour.remove(&known);
+        tour.insert_last(test_activity_with_job(test_single_with_id_and_location("unknown", Some(1))));
+
+        let routes = get_nearest_routes(&ctx, &job, AllRouteSelector::default().select(&ctx, &job), 2);
+
+        let mut ids =
+            routes.iter().map(|route_ctx| get_vehicle_id(&route_ctx.route.actor.vehicle).clone()).collect::<Vec<_>>();
+        ids.sort();
+        assert_eq!(ids, vec!["0", "2", "3"]);
+    }
+
+    #[test]
+    fn can_keep_all_routes_for_unknown_job() {
+        let (problem, mut solution) = generate_matrix_routes(1, 4, |data| (data.clone(), data));
+        let registry = &mut solution.registry;
+        solution.routes.iter().for_each(|route| {
+            registry.use_actor(&route.actor);
+        });
+        let ctx = InsertionContext::new_from_solution(
+            Arc::new(problem),
+            (solution, None),
+            Arc::new(FakeRandom::new(vec![], vec![])),
+        );
+        let job = Job::Single(test_single_with_id_and_location("unknown", Some(1)));
+
+        let routes = get_nearest_routes(&ctx, &job, AllRouteSelector::default().select(&ctx, &job), 1);
+
+        assert_eq!(routes.len(), 4);
+    }
+}
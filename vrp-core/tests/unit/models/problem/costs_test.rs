@@ -1,4 +1,8 @@
 use super::*;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::{test_driver, test_vehicle_with_id, FleetBuilder};
+use crate::helpers::models::solution::{create_route_context_with_activities, test_actor};
+use crate::solver::objectives::TotalRoutes;
 
 fn create_matrix_data(
     profile: Profile,
@@ -80,3 +84,87 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance(0, 0, 1, 0.), 1.);
     assert_eq!(costs.distance(1, 0, 1, 0.), 5.);
 }
+
+#[test]
+fn can_handle_unreachable_locations() {
+    let costs = create_matrix_transport_cost(vec![MatrixData::new(
+        0,
+        None,
+        vec![0., UNREACHABLE_VALUE, 2., 0.],
+        vec![0., UNREACHABLE_VALUE, 2., 0.],
+    )])
+    .unwrap();
+    let actor = test_actor();
+
+    assert!(costs.is_unreachable(0, 0, 1, 0.));
+    assert!(!costs.is_unreachable(0, 1, 0, 0.));
+    assert_eq!(costs.cost(actor.as_ref(), 0, 1, 0.), 0.);
+    assert!(costs.cost(actor.as_ref(), 1, 0, 0.) > 0.);
+}
+
+fn create_insertion_context_with_routes(routes: usize) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes =
+        (0..routes).map(|_| create_route_context_with_activities(&fleet, "v1", vec![])).collect();
+
+    insertion_ctx
+}
+
+parameterized_test! {can_use_tie_break_objectives, (tie_break, expected), {
+    can_use_tie_break_objectives_impl(tie_break, expected);
+}}
+
+can_use_tie_break_objectives! {
+    case01: (None, Ordering::Equal),
+    case02: (Some(true), Ordering::Less),
+    case03: (Some(false), Ordering::Greater),
+}
+
+fn can_use_tie_break_objectives_impl(tie_break: Option<bool>, expected: Ordering) {
+    let tie_break_objectives: Vec<TargetObjective> = match tie_break {
+        Some(true) => vec![Box::new(TotalRoutes::new_minimized())],
+        Some(false) => vec![Box::new(TotalRoutes::new_maximized())],
+        None => vec![],
+    };
+    let objective =
+        ObjectiveCost::new(vec![Box::new(TotalUnassignedJobs::default())], vec![]).with_tie_break(tie_break_objectives);
+
+    let result =
+        objective.total_order(&create_insertion_context_with_routes(1), &create_insertion_context_with_routes(2));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_explain_objective_comparison() {
+    let objective = ObjectiveCost::new(
+        vec![Box::new(TotalUnassignedJobs::default()), Box::new(TotalRoutes::new_minimized())],
+        vec![Box::new(TotalRoutes::new_maximized())],
+    );
+
+    let result = objective.explain(&create_insertion_context_with_routes(1), &create_insertion_context_with_routes(2));
+
+    assert_eq!(result.order, Ordering::Less);
+    assert_eq!(result.level, Some(ObjectiveLevel::Primary));
+    assert_eq!(
+        result.terms.iter().map(|term| (term.level.clone(), term.index, term.order)).collect::<Vec<_>>(),
+        vec![
+            (ObjectiveLevel::Primary, 0, Ordering::Equal),
+            (ObjectiveLevel::Primary, 1, Ordering::Less),
+            (ObjectiveLevel::Secondary, 0, Ordering::Greater),
+        ]
+    );
+    assert_eq!(result.terms[1].fitness, (1., 2.));
+}
+
+#[test]
+fn can_explain_comparison_of_equal_solutions() {
+    let objective = ObjectiveCost::new(vec![Box::new(TotalRoutes::new_minimized())], vec![]);
+
+    let result = objective.explain(&create_insertion_context_with_routes(1), &create_insertion_context_with_routes(1));
+
+    assert_eq!(result.order, Ordering::Equal);
+    assert_eq!(result.level, None);
+    assert_eq!(result.terms.len(), 1);
+}
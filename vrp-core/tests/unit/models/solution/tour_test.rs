@@ -1,6 +1,7 @@
 use super::*;
 use crate::helpers::models::problem::test_single;
 use crate::helpers::models::solution::*;
+use crate::models::common::{Demand, DemandDimension, MultiDimLoad};
 use crate::models::problem::Job;
 use std::sync::Arc;
 
@@ -190,3 +191,46 @@ fn can_get_start_and_end() {
     assert_eq!(get_memory_address(tour.start().unwrap()), get_memory_address(&tour.activities[0]));
     assert_eq!(get_memory_address(tour.end().unwrap()), get_memory_address(&tour.activities[3]));
 }
+
+fn create_activity_with_demand(pickup: (i32, i32), delivery: (i32, i32)) -> Activity {
+    let create_load = |value: i32| MultiDimLoad::new(vec![value, value * 2]);
+    let mut single = test_single();
+    single.dimens.set_demand(Demand {
+        pickup: (create_load(pickup.0), create_load(pickup.1)),
+        delivery: (create_load(delivery.0), create_load(delivery.1)),
+    });
+
+    test_activity_with_job(Arc::new(single))
+}
+
+parameterized_test! {can_get_total_demand_and_peak_load, (demands, expected_total, expected_peak), {
+    can_get_total_demand_and_peak_load_impl(demands, expected_total, expected_peak);
+}}
+
+can_get_total_demand_and_peak_load! {
+    case01: (vec![], 0, 0),
+    case02: (vec![((0, 0), (3, 0))], 3, 3),
+    case03: (vec![((0, 0), (3, 0)), ((0, 0), (2, 0))], 5, 5),
+    case04: (vec![((2, 0), (0, 0)), ((3, 0), (0, 0))], 0, 5),
+    case05: (vec![((0, 0), (3, 0)), ((0, 4), (0, 0)), ((0, 0), (0, 4)), ((1, 0), (0, 0))], 7, 4),
+    case06: (vec![((0, 0), (1, 0)), ((0, 4), (0, 0)), ((0, 0), (0, 4)), ((5, 0), (0, 0))], 5, 5),
+}
+
+fn can_get_total_demand_and_peak_load_impl(
+    demands: Vec<((i32, i32), (i32, i32))>,
+    expected_total: i32,
+    expected_peak: i32,
+) {
+    let mut tour = Tour::default();
+    tour.set_start(test_activity_without_job());
+    tour.set_end(test_activity_without_job());
+    demands.into_iter().for_each(|(pickup, delivery)| {
+        tour.insert_last(create_activity_with_demand(pickup, delivery));
+    });
+
+    let total_demand = tour.total_demand();
+    let peak_load = tour.peak_load();
+
+    assert_eq!(total_demand, MultiDimLoad::new(vec![expected_total, expected_total * 2]));
+    assert_eq!(peak_load, MultiDimLoad::new(vec![expected_peak, expected_peak * 2]));
+}
@@ -0,0 +1,79 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Fleet, Single};
+use crate::models::solution::Registry;
+
+fn create_solution(fleet: &Fleet, jobs: &[Arc<Single>], assignments: Vec<(&str, Vec<usize>)>) -> Solution {
+    Solution {
+        registry: Registry::new(fleet),
+        routes: assignments
+            .into_iter()
+            .map(|(vehicle_id, indices)| {
+                let activities = indices.into_iter().map(|idx| test_activity_with_job(jobs[idx].clone())).collect();
+                create_route_with_activities(fleet, vehicle_id, activities)
+            })
+            .collect(),
+        unassigned: vec![],
+        extras: Arc::new(Default::default()),
+    }
+}
+
+fn create_test_data() -> (Fleet, Vec<Arc<Single>>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let jobs = (0..4).map(|idx| test_single_with_id(format!("job{}", idx).as_str())).collect();
+
+    (fleet, jobs)
+}
+
+parameterized_test! {can_calculate_assignment_distance, (assignments, expected), {
+    can_calculate_assignment_distance_impl(assignments, expected);
+}}
+
+can_calculate_assignment_distance! {
+    case01_same: (vec![("v1", vec![0, 1]), ("v2", vec![2, 3])], 0),
+    case02_other_order: (vec![("v1", vec![1, 0]), ("v2", vec![3, 2])], 0),
+    case03_one_moved: (vec![("v1", vec![0]), ("v2", vec![1, 2, 3])], 1),
+    case04_one_unassigned: (vec![("v1", vec![0, 1]), ("v2", vec![2])], 1),
+    case05_swapped: (vec![("v2", vec![0, 1]), ("v1", vec![2, 3])], 4),
+}
+
+fn can_calculate_assignment_distance_impl(assignments: Vec<(&str, Vec<usize>)>, expected: usize) {
+    let (fleet, jobs) = create_test_data();
+    let original = create_solution(&fleet, jobs.as_slice(), vec![("v1", vec![0, 1]), ("v2", vec![2, 3])]);
+    let other = create_solution(&fleet, jobs.as_slice(), assignments);
+
+    assert_eq!(assignment_distance(&original, &other), expected);
+    assert_eq!(assignment_distance(&other, &original), expected);
+}
+
+parameterized_test! {can_select_diverse_solutions, (k, min_distance, expected_costs), {
+    can_select_diverse_solutions_impl(k, min_distance, expected_costs);
+}}
+
+can_select_diverse_solutions! {
+    case01_top_k: (3, 0, vec![1., 2., 3.]),
+    case02_diverse: (3, 1, vec![1., 3., 4.]),
+    case03_more_diverse: (3, 2, vec![1., 4.]),
+    case04_limited_by_k: (1, 1, vec![1.]),
+}
+
+fn can_select_diverse_solutions_impl(k: usize, min_distance: usize, expected_costs: Vec<Cost>) {
+    let (fleet, jobs) = create_test_data();
+    let solutions = vec![
+        (vec![("v1", vec![0, 1]), ("v2", vec![2, 3])], 1.),
+        (vec![("v1", vec![1, 0]), ("v2", vec![2, 3])], 2.),
+        (vec![("v1", vec![0, 1, 2]), ("v2", vec![3])], 3.),
+        (vec![("v2", vec![0, 1]), ("v1", vec![2, 3])], 4.),
+    ]
+    .into_iter()
+    .map(|(assignments, cost)| (create_solution(&fleet, jobs.as_slice(), assignments), cost))
+    .collect();
+
+    let selected = select_diverse(solutions, k, min_distance);
+
+    assert_eq!(selected.iter().map(|(_, cost)| *cost).collect::<Vec<_>>(), expected_costs);
+}
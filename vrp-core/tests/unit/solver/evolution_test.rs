@@ -1,5 +1,11 @@
 use super::*;
+use crate::algorithms::nsga2::Objective;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::models::common::Cost;
 use crate::models::examples::create_example_problem;
+use crate::models::problem::ObjectiveCost;
+use crate::solver::objectives::{TotalRoutes, TotalTransportCost, TotalUnassignedJobs};
+use std::sync::Mutex;
 
 parameterized_test! {can_enable_telemetry_metrics, mode, {
         can_enable_telemetry_metrics_impl(mode);
@@ -14,7 +20,7 @@ fn can_enable_telemetry_metrics_impl(mode: TelemetryMode) {
     let config = EvolutionConfig { telemetry: Telemetry::new(mode), ..EvolutionConfig::new(create_example_problem()) };
     let evolution = EvolutionSimulator::new(config).unwrap();
 
-    let (_, metrics) = evolution.run().unwrap();
+    let (_, metrics, _) = evolution.run().unwrap();
 
     let metrics = metrics.expect("metrics are empty");
     assert_eq!(metrics.generations, 3000);
@@ -36,7 +42,7 @@ fn can_disable_telemetry_metrics_impl(mode: TelemetryMode) {
     let config = EvolutionConfig { telemetry: Telemetry::new(mode), ..EvolutionConfig::new(create_example_problem()) };
     let evolution = EvolutionSimulator::new(config).unwrap();
 
-    let (_, metrics) = evolution.run().unwrap();
+    let (_, metrics, _) = evolution.run().unwrap();
 
     assert!(metrics.is_none())
 }
@@ -58,9 +64,247 @@ fn can_use_quota() {
     };
     let evolution = EvolutionSimulator::new(config).unwrap();
 
-    let (_, metrics) = evolution.run().unwrap();
+    let (_, metrics, report) = evolution.run().unwrap();
 
     let metrics = metrics.expect("metrics are empty");
     assert_eq!(metrics.generations, 0);
     assert_eq!(metrics.evolution.len(), 1);
+    assert_eq!(report.reason, TerminationReason::Cancelled);
+    assert_eq!(report.generations, 0);
+}
+
+parameterized_test! {can_report_termination_reason, (termination, expected_reason, expected_generations), {
+        can_report_termination_reason_impl(termination, expected_reason, expected_generations);
+}}
+
+can_report_termination_reason! {
+        case01: (Arc::new(MaxGeneration::new(10)), TerminationReason::MaxGenerations, Some(10)),
+        case02: (Arc::new(MaxTime::new(0.)), TerminationReason::MaxTime, None),
+        case03: (Arc::new(CostVariation::new(5, 1.)), TerminationReason::Stagnation, None),
+        case04: (Arc::new(CompositeTermination::new(vec![Box::new(MaxTime::new(300.)), Box::new(MaxGeneration::new(10))])),
+                 TerminationReason::MaxGenerations, Some(10)),
+        case05: (Arc::new(CustomTermination {}), TerminationReason::CustomTermination, Some(0)),
+}
+
+struct CustomTermination {}
+
+impl Termination for CustomTermination {
+    fn is_termination(&self, _: &mut RefinementContext) -> bool {
+        true
+    }
+}
+
+fn can_report_termination_reason_impl(
+    termination: Arc<dyn Termination>,
+    expected_reason: TerminationReason,
+    expected_generations: Option<usize>,
+) {
+    let config = EvolutionConfig { termination, ..EvolutionConfig::new(create_example_problem()) };
+    let evolution = EvolutionSimulator::new(config).unwrap();
+
+    let (_, _, report) = evolution.run().unwrap();
+
+    assert_eq!(report.reason, expected_reason);
+    if let Some(expected_generations) = expected_generations {
+        assert_eq!(report.generations, expected_generations);
+    }
+    assert!(report.duration >= 0.);
+}
+
+parameterized_test! {can_report_phase_timings, profile, {
+        can_report_phase_timings_impl(profile);
+}}
+
+can_report_phase_timings! {
+        case01: true,
+        case02: false,
+}
+
+fn can_report_phase_timings_impl(profile: bool) {
+    let config = EvolutionConfig {
+        termination: Arc::new(MaxGeneration::new(100)),
+        profile,
+        ..EvolutionConfig::new(create_example_problem())
+    };
+    let evolution = EvolutionSimulator::new(config).unwrap();
+
+    let (_, _, report) = evolution.run().unwrap();
+
+    if let Some(timings) = report.phase_timings {
+        assert!(profile);
+        let phases = [timings.construction, timings.selection, timings.mutation, timings.evaluation, timings.other];
+        assert!(phases.iter().all(|time| *time >= 0.));
+        assert!(timings.mutation > 0.);
+        assert!((phases.iter().sum::<f64>() - report.duration).abs() < 1E-6);
+    } else {
+        assert!(!profile);
+    }
+}
+
+parameterized_test! {can_switch_objective_phase, phase_switch, {
+        can_switch_objective_phase_impl(phase_switch);
+}}
+
+can_switch_objective_phase! {
+        case01: PhaseSwitch::Generation(100),
+        case02: PhaseSwitch::Stagnation(20),
+}
+
+fn can_switch_objective_phase_impl(phase_switch: PhaseSwitch) {
+    struct PhaseTracker {
+        phase_one: Arc<Mutex<Option<(usize, Cost)>>>,
+    }
+
+    impl Termination for PhaseTracker {
+        fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
+            let objective = refinement_ctx.problem.objective.as_ref();
+            let is_initial_phase = refinement_ctx.population.is_initial_phase();
+            if let Some((best, _)) = refinement_ctx.population.ranked().next().filter(|_| is_initial_phase) {
+                *self.phase_one.lock().unwrap() = Some((best.solution.routes.len(), objective.fitness(best)));
+            }
+
+            false
+        }
+    }
+
+    let (problem, _) = generate_matrix_routes(5, 2, |data| (data.clone(), data));
+    let problem = Arc::new(Problem {
+        objective: Arc::new(
+            ObjectiveCost::new(
+                vec![Box::new(TotalUnassignedJobs::default())],
+                vec![Box::new(TotalTransportCost::default())],
+            )
+            .with_initial_phase(vec![Box::new(TotalUnassignedJobs::default()), Box::new(TotalRoutes::default())]),
+        ),
+        ..problem
+    });
+    let phase_one = Arc::new(Mutex::new(None));
+    let config = EvolutionConfig {
+        termination: Arc::new(CompositeTermination::new(vec![
+            Box::new(MaxGeneration::new(300)),
+            Box::new(PhaseTracker { phase_one: phase_one.clone() }),
+        ])),
+        phase_switch: Some(phase_switch),
+        ..EvolutionConfig::new(problem.clone())
+    };
+
+    let (population, _, _) = EvolutionSimulator::new(config).unwrap().run().unwrap();
+
+    let (routes, cost) = phase_one.lock().unwrap().expect("initial phase is not tracked");
+    let (best, _) = population.ranked().next().unwrap();
+    assert!(!population.is_initial_phase());
+    assert_eq!(best.solution.routes.len(), routes);
+    assert!(problem.objective.fitness(best) <= cost);
+}
+
+parameterized_test! {can_retry_construction, (failures, retries, expected_calls, expected_assigned), {
+        can_retry_construction_impl(failures, retries, expected_calls, expected_assigned);
+}}
+
+can_retry_construction! {
+        case01: (0, 0, 1, true),
+        case02: (1, 0, 1, false),
+        case03: (1, 2, 2, true),
+        case04: (3, 2, 3, false),
+}
+
+fn can_retry_construction_impl(failures: usize, retries: usize, expected_calls: usize, expected_assigned: bool) {
+    struct FailingRecreate {
+        failures: usize,
+        calls: Arc<Mutex<usize>>,
+        inner: RecreateWithCheapest,
+    }
+
+    impl Recreate for FailingRecreate {
+        fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+            let mut calls = self.calls.lock().unwrap();
+            *calls += 1;
+
+            if *calls > self.failures {
+                self.inner.run(refinement_ctx, insertion_ctx)
+            } else {
+                let required = std::mem::replace(&mut insertion_ctx.solution.required, vec![]);
+                insertion_ctx.solution.unassigned.extend(required.into_iter().map(|job| (job, 0)));
+                insertion_ctx
+            }
+        }
+    }
+
+    struct InitialTracker {
+        unassigned: Arc<Mutex<Option<usize>>>,
+    }
+
+    impl Termination for InitialTracker {
+        fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
+            if let Some((best, _)) = refinement_ctx.population.ranked().next() {
+                *self.unassigned.lock().unwrap() = Some(best.solution.unassigned.len());
+                true
+            } else {
+                false
+            }
+        }
+    }
+
+    let calls = Arc::new(Mutex::new(0));
+    let unassigned = Arc::new(Mutex::new(None));
+    let mut config = EvolutionConfig {
+        termination: Arc::new(InitialTracker { unassigned: unassigned.clone() }),
+        construction_retries: retries,
+        ..EvolutionConfig::new(create_example_problem())
+    };
+    config.population.initial.methods =
+        vec![(Box::new(FailingRecreate { failures, calls: calls.clone(), inner: RecreateWithCheapest::default() }), 1)];
+
+    EvolutionSimulator::new(config).unwrap().run().unwrap();
+
+    let unassigned = unassigned.lock().unwrap().expect("initial solution is not tracked");
+    assert_eq!(*calls.lock().unwrap(), expected_calls);
+    assert_eq!(unassigned == 0, expected_assigned);
+}
+
+#[test]
+fn can_relax_soft_route_costs_on_construction_retry() {
+    struct TrackingRecreate {
+        factors: Arc<Mutex<Vec<Option<f64>>>>,
+    }
+
+    impl Recreate for TrackingRecreate {
+        fn run(&self, _: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+            let factor = insertion_ctx.problem.extras.get(SOFT_ROUTE_COST_FACTOR_KEY).and_then(|f| f.downcast_ref());
+            self.factors.lock().unwrap().push(factor.cloned());
+
+            let required = std::mem::replace(&mut insertion_ctx.solution.required, vec![]);
+            insertion_ctx.solution.unassigned.extend(required.into_iter().map(|job| (job, 0)));
+            insertion_ctx
+        }
+    }
+
+    struct InitialTracker {
+        is_relaxed: Arc<Mutex<Option<bool>>>,
+    }
+
+    impl Termination for InitialTracker {
+        fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
+            if let Some((best, _)) = refinement_ctx.population.ranked().next() {
+                *self.is_relaxed.lock().unwrap() = Some(best.problem.extras.contains_key(SOFT_ROUTE_COST_FACTOR_KEY));
+                true
+            } else {
+                false
+            }
+        }
+    }
+
+    let factors = Arc::new(Mutex::new(vec![]));
+    let is_relaxed = Arc::new(Mutex::new(None));
+    let mut config = EvolutionConfig {
+        termination: Arc::new(InitialTracker { is_relaxed: is_relaxed.clone() }),
+        construction_retries: 2,
+        ..EvolutionConfig::new(create_example_problem())
+    };
+    config.population.initial.methods = vec![(Box::new(TrackingRecreate { factors: factors.clone() }), 1)];
+
+    EvolutionSimulator::new(config).unwrap().run().unwrap();
+
+    assert_eq!(*factors.lock().unwrap(), vec![None, Some(0.5), Some(0.)]);
+    assert_eq!(*is_relaxed.lock().unwrap(), Some(false));
 }
@@ -0,0 +1,58 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, TimeSpan, TimeWindow};
+use crate::models::problem::{Job, Place, Single};
+use crate::models::solution::Registry;
+use std::sync::Arc;
+
+fn create_job_with_tw_end(location: Location, tw_end: f64) -> Job {
+    Job::Single(Arc::new(Single {
+        places: vec![Place {
+            location: Some(location),
+            duration: 0.,
+            times: vec![TimeSpan::Window(TimeWindow::new(0., tw_end))],
+        }],
+        dimens: Default::default(),
+    }))
+}
+
+fn create_test_insertion_ctx(unassigned: Vec<(Location, f64)>) -> InsertionContext {
+    let constraint = create_constraint_pipeline_with_transport();
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![test_activity_with_location(10)]);
+    constraint.accept_route_state(&mut route_ctx);
+
+    let mut insertion_ctx = create_insertion_context(Registry::new(&fleet), constraint, vec![route_ctx.clone()]);
+    insertion_ctx.solution.registry.use_route(&route_ctx);
+    unassigned.into_iter().for_each(|(location, tw_end)| {
+        insertion_ctx.solution.unassigned.insert(create_job_with_tw_end(location, tw_end), 0);
+    });
+
+    insertion_ctx
+}
+
+parameterized_test! {can_insert_unassigned_jobs, (unassigned, expected_inserted), {
+    can_insert_unassigned_jobs_impl(unassigned, expected_inserted);
+}}
+
+can_insert_unassigned_jobs! {
+    case01_no_unassigned: (vec![], 0),
+    case02_one_feasible: (vec![(20, 1000.)], 1),
+    case03_one_infeasible: (vec![(50, 5.)], 0),
+    case04_mixed: (vec![(20, 1000.), (50, 5.), (30, 1000.)], 2),
+}
+
+fn can_insert_unassigned_jobs_impl(unassigned: Vec<(Location, f64)>, expected_inserted: usize) {
+    let total_unassigned = unassigned.len();
+    let insertion_ctx = create_test_insertion_ctx(unassigned);
+
+    let (insertion_ctx, inserted) = apply_final_sweep(insertion_ctx);
+
+    assert_eq!(inserted, expected_inserted);
+    assert_eq!(insertion_ctx.solution.unassigned.len(), total_unassigned - expected_inserted);
+    assert_eq!(insertion_ctx.solution.routes.len(), 1);
+    assert_eq!(insertion_ctx.solution.routes[0].route.tour.job_count(), 1 + expected_inserted);
+}
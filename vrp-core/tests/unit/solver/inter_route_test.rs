@@ -0,0 +1,73 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::helpers::utils::random::FakeRandom;
+
+fn create_insertion_ctx(rows: usize, cols: usize, misplaced: Option<(usize, usize)>) -> InsertionContext {
+    let (problem, mut solution) = generate_matrix_routes(rows, cols, |data| (data.clone(), data));
+
+    if let Some((from, to)) = misplaced {
+        let activity = solution.routes[from].tour.all_activities().nth(2).unwrap().deep_copy();
+        solution.routes[from].tour.remove(&activity.retrieve_job().unwrap());
+        solution.routes[to].tour.insert_last(activity);
+    }
+
+    let registry = &mut solution.registry;
+    solution.routes.iter().for_each(|route| {
+        registry.use_actor(&route.actor);
+    });
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(FakeRandom::new(vec![], vec![])))
+}
+
+#[test]
+fn can_move_job_to_cheaper_route() {
+    let mut insertion_ctx = create_insertion_ctx(4, 2, Some((0, 1)));
+    let original_cost = insertion_ctx.solution.get_total_cost();
+
+    let result = inter_route_improve(&mut insertion_ctx);
+
+    assert!(result);
+    assert!(insertion_ctx.solution.get_total_cost() < original_cost);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(),
+        8
+    );
+}
+
+#[test]
+fn can_return_false_for_single_route() {
+    let mut insertion_ctx = create_insertion_ctx(3, 1, None);
+    let original_cost = insertion_ctx.solution.get_total_cost();
+
+    let result = inter_route_improve(&mut insertion_ctx);
+
+    assert!(!result);
+    assert_eq!(insertion_ctx.solution.get_total_cost(), original_cost);
+}
+
+#[test]
+fn can_reach_local_optimum() {
+    let mut insertion_ctx = create_insertion_ctx(4, 2, Some((0, 1)));
+    let mut costs = vec![insertion_ctx.solution.get_total_cost()];
+
+    while inter_route_improve(&mut insertion_ctx) {
+        costs.push(insertion_ctx.solution.get_total_cost());
+    }
+
+    assert!(costs.len() > 1);
+    assert!(costs.windows(2).all(|costs| costs[1] < costs[0]));
+    assert!(!inter_route_improve(&mut insertion_ctx));
+}
+
+#[test]
+fn can_remove_empty_route() {
+    let mut insertion_ctx = create_insertion_ctx(2, 2, Some((1, 0)));
+    assert_eq!(insertion_ctx.solution.routes.len(), 2);
+
+    while inter_route_improve(&mut insertion_ctx) {}
+
+    assert_eq!(insertion_ctx.solution.routes.len(), 1);
+    assert_eq!(insertion_ctx.solution.routes[0].route.tour.job_count(), 4);
+    assert_eq!(insertion_ctx.solution.registry.next().count(), 1);
+}
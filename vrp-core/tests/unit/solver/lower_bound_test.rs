@@ -0,0 +1,126 @@
+use super::*;
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::CostCapModule;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{create_route_with_activities, test_activity_with_job};
+use crate::helpers::solver::generate_matrix_routes;
+use crate::models::examples::create_example_problem;
+use crate::models::problem::{create_matrix_transport_cost, Costs, Jobs, MatrixData, ObjectiveCost};
+use crate::models::solution::Registry;
+use crate::models::Solution;
+use crate::solver::{Builder, Telemetry, TelemetryMode};
+use crate::utils::DefaultRandom;
+
+parameterized_test! {can_estimate_lower_bound_not_higher_than_cost, (rows, cols), {
+    can_estimate_lower_bound_not_higher_than_cost_impl(rows, cols);
+}}
+
+can_estimate_lower_bound_not_higher_than_cost! {
+    case01: (4, 1),
+    case02: (4, 2),
+    case03: (3, 3),
+}
+
+fn can_estimate_lower_bound_not_higher_than_cost_impl(rows: usize, cols: usize) {
+    let (problem, solution) = generate_matrix_routes(rows, cols, |data| (data.clone(), data));
+    let insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(DefaultRandom::default()));
+
+    let result = lower_bound(&insertion_ctx);
+
+    assert!(result > 0.);
+    assert!(result <= insertion_ctx.problem.objective.fitness(&insertion_ctx));
+}
+
+#[test]
+fn can_estimate_lower_bound_not_higher_than_cost_with_different_rates() {
+    let costs =
+        Costs { fixed: 0., per_distance: 0., per_driving_time: 1., per_waiting_time: 100., per_service_time: 2. };
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicle(VehicleBuilder::default().id("v1").costs(costs).build())
+            .build(),
+    );
+    let singles =
+        vec![test_single_with_id_and_location("job1", Some(1)), test_single_with_id_and_location("job2", Some(2))];
+    let activities = singles
+        .iter()
+        .map(|single| {
+            let mut activity = test_activity_with_job(single.clone());
+            activity.place.location = single.places.first().unwrap().location.unwrap();
+            activity.place.duration = 10.;
+            activity
+        })
+        .collect();
+    let route = create_route_with_activities(&fleet, "v1", activities);
+    #[rustfmt::skip]
+    let matrix = vec![
+        0., 5., 8.,
+        5., 0., 3.,
+        8., 3., 0.,
+    ];
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, matrix.clone(), matrix)]).unwrap();
+    let jobs = Jobs::new(&fleet, singles.into_iter().map(Job::Single).collect(), &transport);
+    let problem = Problem {
+        fleet: fleet.clone(),
+        jobs: Arc::new(jobs),
+        locks: vec![],
+        constraint: Arc::new(create_constraint_pipeline_with_transport()),
+        activity: Arc::new(TestActivityCost::default()),
+        transport,
+        objective: Arc::new(ObjectiveCost::default()),
+        extras: Arc::new(Default::default()),
+    };
+    let solution = Solution {
+        registry: Registry::new(&fleet),
+        routes: vec![route],
+        unassigned: Default::default(),
+        extras: Arc::new(Default::default()),
+    };
+    let insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(DefaultRandom::default()));
+
+    let result = lower_bound(&insertion_ctx);
+
+    assert!(result > 0.);
+    assert!(result <= insertion_ctx.problem.objective.fitness(&insertion_ctx));
+}
+
+#[test]
+fn can_estimate_lower_bound_not_higher_than_cost_with_cost_cap() {
+    let (problem, solution) = generate_matrix_routes(4, 1, |data| (data.clone(), data));
+    let mut constraint = create_constraint_pipeline_with_transport();
+    constraint.add_module(Box::new(CostCapModule::new(
+        problem.activity.clone(),
+        problem.transport.clone(),
+        Arc::new(|_| Some(1.)),
+    )));
+    let problem = Problem { constraint: Arc::new(constraint), ..problem };
+    let insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(DefaultRandom::default()));
+
+    let result = lower_bound(&insertion_ctx);
+
+    let cost = insertion_ctx.problem.objective.fitness(&insertion_ctx);
+    assert_eq!(cost, 1.);
+    assert!(result > 0.);
+    assert!(result <= cost);
+}
+
+#[test]
+fn can_report_gap_in_metrics() {
+    let solver = Builder::new(create_example_problem())
+        .with_max_generations(Some(100))
+        .with_telemetry(Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 100 }))
+        .build()
+        .unwrap();
+
+    let (_, cost, metrics) = solver.solve().unwrap();
+
+    let metrics = metrics.expect("metrics are empty");
+    assert!(metrics.lower_bound > 0.);
+    assert!(metrics.lower_bound <= cost);
+    assert!(metrics.gap >= 0. && metrics.gap < 100.);
+}
@@ -0,0 +1,30 @@
+use super::reinsert_all;
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::solver::mutation::remove_jobs;
+use crate::utils::DefaultRandom;
+use std::sync::Arc;
+
+#[test]
+fn can_reinsert_removed_jobs() {
+    let (problem, solution) = generate_matrix_routes(4, 2, |data| (data.clone(), data));
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(DefaultRandom::default()));
+    let jobs = insertion_ctx
+        .problem
+        .jobs
+        .all()
+        .filter(|job| ["c1", "c2", "c5"].contains(&get_customer_id(job).as_str()))
+        .collect::<Vec<_>>();
+    remove_jobs(&mut insertion_ctx, jobs.as_slice());
+
+    let insertion_ctx = reinsert_all(insertion_ctx);
+
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(
+        get_customer_ids_from_routes_sorted(&insertion_ctx).into_iter().flatten().count(),
+        insertion_ctx.problem.jobs.size()
+    );
+}
@@ -0,0 +1,88 @@
+use super::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::models::problem::Job;
+use crate::models::Problem;
+use crate::solver::DominancePopulation;
+use crate::utils::DefaultRandom;
+use std::sync::{Arc, Mutex};
+
+fn create_insertion_ctx(rows: usize, cols: usize) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes(rows, cols, |data| (data.clone(), data));
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(DefaultRandom::default()))
+}
+
+fn get_jobs_by_ids(problem: &Problem, ids: &[&str]) -> Vec<Job> {
+    problem.jobs.all().filter(|job| ids.contains(&get_customer_id(job).as_str())).collect()
+}
+
+#[test]
+fn can_remove_given_jobs() {
+    let mut insertion_ctx = create_insertion_ctx(4, 2);
+    let jobs = get_jobs_by_ids(insertion_ctx.problem.as_ref(), &["c1", "c4", "c5", "c6", "c7"]);
+
+    remove_jobs(&mut insertion_ctx, jobs.as_slice());
+
+    assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), vec!["c1", "c4", "c5", "c6", "c7"]);
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), vec![vec!["c0", "c2", "c3"]]);
+}
+
+struct DrawingRuin {
+    draws: usize,
+    values: Arc<Mutex<Vec<i32>>>,
+}
+
+impl Ruin for DrawingRuin {
+    fn run(&self, _: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let values = (0..self.draws).map(|_| insertion_ctx.random.uniform_int(0, 1000)).collect::<Vec<_>>();
+        self.values.lock().unwrap().extend(values);
+
+        insertion_ctx
+    }
+}
+
+parameterized_test! {can_use_independent_random_streams, other_draws, {
+    can_use_independent_random_streams_impl(other_draws);
+}}
+
+can_use_independent_random_streams! {
+    case01: 1,
+    case02: 3,
+}
+
+fn can_use_independent_random_streams_impl(other_draws: usize) {
+    let run_ruins = |other_draws: usize| {
+        let other_values = Arc::new(Mutex::new(vec![]));
+        let values = Arc::new(Mutex::new(vec![]));
+        let ruin = CompositeRuin::new(vec![(
+            vec![
+                (Arc::new(DrawingRuin { draws: other_draws, values: other_values.clone() }), 1.),
+                (Arc::new(DrawingRuin { draws: 2, values: values.clone() }), 1.),
+            ],
+            1,
+        )])
+        .with_random_streams(42);
+        let insertion_ctx = create_insertion_ctx(4, 2);
+        let refinement_ctx = RefinementContext::new(
+            insertion_ctx.problem.clone(),
+            Box::new(DominancePopulation::new(insertion_ctx.problem.clone(), 4)),
+            None,
+        );
+
+        (0..5).fold(insertion_ctx, |insertion_ctx, _| ruin.run(&refinement_ctx, insertion_ctx));
+
+        let values = values.lock().unwrap().clone();
+        let other_values = other_values.lock().unwrap().clone();
+        (values, other_values)
+    };
+
+    let (expected_values, _) = run_ruins(2);
+    let (values, other_values) = run_ruins(other_draws);
+
+    assert_eq!(values.len(), 10);
+    assert_eq!(other_values.len(), 5 * other_draws);
+    assert_eq!(values, expected_values);
+    assert_ne!(values[..other_draws.min(2)], other_values[..other_draws.min(2)]);
+}
@@ -0,0 +1,56 @@
+use super::{Ruin, WorstRouteRemoval};
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::{Lock, LockDetail, LockOrder, LockPosition, Problem};
+use std::sync::Arc;
+
+parameterized_test! {can_remove_jobs_from_worst_route, (neighbours, locked_ids, expected_removed, expected_routes), {
+    can_remove_jobs_from_worst_route_impl(neighbours, locked_ids, expected_removed, expected_routes);
+}}
+
+can_remove_jobs_from_worst_route! {
+    case_01: (0, vec![], vec!["c4", "c5", "c6", "c7"], vec![vec!["c0", "c1", "c2", "c3"]]),
+    case_02: (0, vec!["c5"], vec!["c4", "c6", "c7"], vec![vec!["c0", "c1", "c2", "c3"], vec!["c5"]]),
+    case_03: (1, vec![], vec!["c0", "c4", "c5", "c6", "c7"], vec![vec!["c1", "c2", "c3"]]),
+    case_04: (0, vec!["c4", "c5", "c6", "c7"], vec!["c0", "c1", "c2", "c3"], vec![vec!["c4", "c5", "c6", "c7"]]),
+    case_05: (0, vec!["c0", "c1", "c2"], vec!["c3"], vec![vec!["c0", "c1", "c2"], vec!["c4", "c5", "c6", "c7"]]),
+}
+
+fn can_remove_jobs_from_worst_route_impl(
+    neighbours: usize,
+    locked_ids: Vec<&str>,
+    expected_removed: Vec<&str>,
+    expected_routes: Vec<Vec<&str>>,
+) {
+    // NOTE make distances slightly asymmetric to have a strict order of neighbours
+    let (problem, solution) = generate_matrix_routes(4, 2, |data| {
+        let data = data.into_iter().enumerate().map(|(idx, value)| value + idx as f64 * 0.001).collect::<Vec<_>>();
+        (data.clone(), data)
+    });
+    let problem = Problem {
+        jobs: problem.jobs.clone(),
+        locks: vec![Arc::new(Lock {
+            condition: Arc::new(|_| false),
+            details: vec![LockDetail {
+                order: LockOrder::Any,
+                position: LockPosition::Any,
+                jobs: problem.jobs.all().filter(|job| locked_ids.contains(&get_customer_id(job).as_str())).collect(),
+            }],
+            is_lazy: false,
+        })],
+        ..problem
+    };
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        Arc::new(FakeRandom::new(vec![], vec![])),
+    );
+
+    let insertion_ctx = WorstRouteRemoval::new(neighbours)
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected_removed);
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), expected_routes);
+}
@@ -0,0 +1,73 @@
+use super::*;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::Location;
+
+fn create_test_route_ctx(vehicle: &str, locations: Vec<Location>) -> RouteContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+
+    create_route_context_with_activities(
+        &fleet,
+        vehicle,
+        locations.into_iter().map(test_activity_with_location).collect(),
+    )
+}
+
+fn create_test_insertion_ctx(use_module: bool) -> (InsertionContext, TargetObjective) {
+    let (module, objective) = EtaVariance::new_minimized(TestTransportCost::new_shared(), Arc::new(|_| true));
+    let mut constraint = ConstraintPipeline::default();
+    if use_module {
+        constraint.add_module(module);
+    }
+    let routes = vec![create_test_route_ctx("v1", vec![10, 20]), create_test_route_ctx("v2", vec![30])];
+    let mut insertion_ctx = create_insertion_context(create_test_registry(), constraint, routes);
+    insertion_ctx.problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+    (insertion_ctx, objective)
+}
+
+parameterized_test! {can_calculate_eta_variance, use_module, {
+    can_calculate_eta_variance_impl(use_module);
+}}
+
+can_calculate_eta_variance! {
+    case01: true,
+    case02: false,
+}
+
+fn can_calculate_eta_variance_impl(use_module: bool) {
+    let (insertion_ctx, objective) = create_test_insertion_ctx(use_module);
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(compare_floats(fitness, 200. / 3.), Ordering::Equal);
+}
+
+parameterized_test! {can_estimate_activity_eta_variance, (location, expected), {
+    can_estimate_activity_eta_variance_impl(location, expected);
+}}
+
+can_estimate_activity_eta_variance! {
+    case01: (20, 0.),
+    case02: (40, (500_f64).sqrt() - (200_f64).sqrt()),
+}
+
+fn can_estimate_activity_eta_variance_impl(location: Location, expected: f64) {
+    let (insertion_ctx, _) = create_test_insertion_ctx(true);
+    let route_ctx = insertion_ctx.solution.routes.get(1).unwrap();
+    let target = test_activity_with_location(location);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let result = insertion_ctx.problem.constraint.evaluate_soft_activity(route_ctx, &activity_ctx);
+
+    assert!((result - expected).abs() < 1E-6);
+}
@@ -0,0 +1,156 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::IdDimension;
+use crate::models::problem::Actor;
+
+fn create_rates() -> MonetaryRates {
+    MonetaryRates { distance: 1., time: 2., overtime: 3., fixed: 100., unassigned: 1000., late: 500. }
+}
+
+fn create_lateness_fn(latest: Option<Timestamp>) -> LatenessFunc {
+    Arc::new(move |activity: &Activity| activity.job.as_ref().and(latest).map(|latest| (latest, 1.)))
+}
+
+fn create_job_filter() -> RevenueJobFilter {
+    Arc::new(|job: &Job| job.dimens().get_id().map_or(true, |id| id != "ignored"))
+}
+
+fn create_test_insertion_context() -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let constraint = create_constraint_pipeline_with_transport();
+    let routes = vec![("v1", 10), ("v2", 20)]
+        .into_iter()
+        .map(|(vehicle_id, location)| {
+            let mut route_ctx =
+                create_route_context_with_activities(&fleet, vehicle_id, vec![test_activity_with_location(location)]);
+            constraint.accept_route_state(&mut route_ctx);
+            route_ctx
+        })
+        .collect();
+
+    let mut insertion_ctx = create_insertion_context(create_test_registry(), constraint, routes);
+    insertion_ctx.solution.unassigned.insert(Job::Single(test_single_with_id("job3")), 0);
+    insertion_ctx.solution.unassigned.insert(Job::Single(test_single_with_id("ignored")), 0);
+
+    insertion_ctx
+}
+
+#[test]
+fn can_calculate_monetary_breakdown() {
+    let shift_end_fn: ShiftEndFn = Arc::new(|_: &Actor| Some(30.));
+    let insertion_ctx = create_test_insertion_context();
+    let solution = insertion_ctx.solution.to_solution(Arc::new(Default::default()));
+
+    let breakdown = MonetaryCost::get_breakdown(
+        &create_rates(),
+        &shift_end_fn,
+        &create_job_filter(),
+        &create_lateness_fn(None),
+        TestTransportCost::new_shared().as_ref(),
+        &solution,
+    );
+
+    assert_eq!(
+        breakdown,
+        MonetaryBreakdown { distance: 60., time: 120., overtime: 30., fixed: 200., unassigned: 1000., late: 0. }
+    );
+    assert_eq!(breakdown.total(), 1410.);
+}
+
+parameterized_test! {can_calculate_late_revenue_loss, (latest, expected), {
+    can_calculate_late_revenue_loss_impl(latest, expected);
+}}
+
+can_calculate_late_revenue_loss! {
+    case01_all_on_time: (Some(20.), 0.),
+    case02_one_late: (Some(15.), 500.),
+    case03_all_late: (Some(5.), 1000.),
+    case04_no_time_windows: (None, 0.),
+}
+
+fn can_calculate_late_revenue_loss_impl(latest: Option<Timestamp>, expected: Cost) {
+    let shift_end_fn: ShiftEndFn = Arc::new(|_: &Actor| None);
+    let insertion_ctx = create_test_insertion_context();
+    let solution = insertion_ctx.solution.to_solution(Arc::new(Default::default()));
+
+    let breakdown = MonetaryCost::get_breakdown(
+        &create_rates(),
+        &shift_end_fn,
+        &create_job_filter(),
+        &create_lateness_fn(latest),
+        TestTransportCost::new_shared().as_ref(),
+        &solution,
+    );
+
+    assert_eq!(breakdown.late, expected);
+}
+
+#[test]
+fn can_use_monetary_total_as_fitness() {
+    let shift_end_fn: ShiftEndFn = Arc::new(|_: &Actor| Some(30.));
+    let (_, objective) = MonetaryCost::new_minimized(
+        create_rates(),
+        shift_end_fn.clone(),
+        create_job_filter(),
+        create_lateness_fn(Some(15.)),
+        TestTransportCost::new_shared(),
+    );
+    let insertion_ctx = create_test_insertion_context();
+    let solution = insertion_ctx.solution.to_solution(Arc::new(Default::default()));
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    let breakdown = MonetaryCost::get_breakdown(
+        &create_rates(),
+        &shift_end_fn,
+        &create_job_filter(),
+        &create_lateness_fn(Some(15.)),
+        TestTransportCost::new_shared().as_ref(),
+        &solution,
+    );
+    assert_eq!(fitness, breakdown.total());
+}
+
+parameterized_test! {can_estimate_activity_insertion, (next, latest, expected), {
+    can_estimate_activity_insertion_impl(next, latest, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_between_activities: (Some(12), None, 18.),
+    case02_at_the_end: (None, None, 15.),
+    case03_on_time: (None, Some(15.), 15.),
+    case04_late: (None, Some(14.), 115.),
+}
+
+fn can_estimate_activity_insertion_impl(next: Option<usize>, latest: Option<Timestamp>, expected: Cost) {
+    let (module, _) = MonetaryCost::new_minimized(
+        MonetaryRates { distance: 1., time: 2., late: 100., ..MonetaryRates::default() },
+        Arc::new(|_: &Actor| None),
+        create_job_filter(),
+        create_lateness_fn(latest),
+        TestTransportCost::new_shared(),
+    );
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let prev = test_activity_with_location(10);
+    let target = test_activity_with_location(15);
+    let next = next.map(test_activity_with_location);
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: next.as_ref() };
+
+    let soft_activity = module
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::SoftActivity(soft_activity) => Some(soft_activity.clone()),
+            _ => None,
+        })
+        .unwrap();
+    let cost = soft_activity.estimate_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(cost, expected);
+}
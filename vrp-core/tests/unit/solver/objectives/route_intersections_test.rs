@@ -0,0 +1,85 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+/// Places locations in corners of square: 0 - (0, 0), 1 - (10, 0), 2 - (10, 10), 3 - (0, 10).
+fn get_corner(location: Location) -> Option<(f64, f64)> {
+    match location {
+        0 => Some((0., 0.)),
+        1 => Some((10., 0.)),
+        2 => Some((10., 10.)),
+        3 => Some((0., 10.)),
+        _ => None,
+    }
+}
+
+fn create_test_route_ctx(locations: Vec<Location>, constraint: &ConstraintPipeline) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        locations.into_iter().map(test_activity_with_location).collect(),
+    );
+    constraint.accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_calculate_route_intersections, (locations, use_module, expected), {
+    can_calculate_route_intersections_impl(locations, use_module, expected);
+}}
+
+can_calculate_route_intersections! {
+    case01: (vec![1, 2, 3], true, 0.),
+    case02: (vec![1, 3, 2], true, 1.),
+    case03: (vec![1, 3, 2], false, 1.),
+    case04: (vec![2, 1, 3], true, 1.),
+    case05: (vec![1, 4, 3, 2], true, 0.),
+    case06: (vec![1, 1, 2], true, 0.),
+}
+
+fn can_calculate_route_intersections_impl(locations: Vec<Location>, use_module: bool, expected: f64) {
+    let (module, objective) = RouteIntersections::new_minimized(Arc::new(get_corner));
+    let mut constraint = create_constraint_pipeline_with_transport();
+    if use_module {
+        constraint.add_module(module);
+    }
+    let route_ctx = create_test_route_ctx(locations, &constraint);
+    let insertion_ctx = create_insertion_context(create_test_registry(), constraint, vec![route_ctx]);
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+parameterized_test! {can_estimate_activity_intersections, (index, location, has_penalty), {
+    can_estimate_activity_intersections_impl(index, location, has_penalty);
+}}
+
+can_estimate_activity_intersections! {
+    case01: (1, 3, true),
+    case02: (2, 3, false),
+    case03: (0, 3, true),
+    case04: (1, 4, false),
+}
+
+fn can_estimate_activity_intersections_impl(index: usize, location: Location, has_penalty: bool) {
+    let (module, _) = RouteIntersections::new_minimized(Arc::new(get_corner));
+    let mut constraint = ConstraintPipeline::default();
+    constraint.add_module(module);
+    let route_ctx = create_test_route_ctx(vec![1, 2], &constraint);
+    let target = test_activity_with_location(location);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = constraint.evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result > 0., has_penalty);
+    assert!(result >= 0.);
+}
@@ -0,0 +1,77 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_test_insertion_context(routes: Vec<(&str, Vec<Location>)>, use_module: bool) -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(routes.iter().map(|(vehicle_id, _)| test_vehicle_with_id(vehicle_id)).collect())
+        .build();
+    let (module, _) = RouteOverlap::new_minimized(Arc::new(|location| Some(location / 10)));
+    let mut constraint = create_constraint_pipeline_with_transport();
+    if use_module {
+        constraint.add_module(module);
+    }
+    let routes = routes
+        .into_iter()
+        .map(|(vehicle_id, locations)| {
+            let mut route_ctx = create_route_context_with_activities(
+                &fleet,
+                vehicle_id,
+                locations.into_iter().map(test_activity_with_location).collect(),
+            );
+            constraint.accept_route_state(&mut route_ctx);
+            route_ctx
+        })
+        .collect();
+
+    create_insertion_context(create_test_registry(), constraint, routes)
+}
+
+parameterized_test! {can_calculate_route_overlap, (routes, use_module, expected), {
+    can_calculate_route_overlap_impl(routes, use_module, expected);
+}}
+
+can_calculate_route_overlap! {
+    case01: (vec![("v1", vec![10, 20]), ("v2", vec![30, 40])], true, 0.),
+    case02: (vec![("v1", vec![10, 20]), ("v2", vec![25, 40])], true, 1.),
+    case03: (vec![("v1", vec![10, 20]), ("v2", vec![15, 25]), ("v3", vec![18])], true, 3.),
+    case04: (vec![("v1", vec![10, 20]), ("v2", vec![15, 25]), ("v3", vec![18])], false, 3.),
+    case05: (vec![("v1", vec![10, 15, 18])], true, 0.),
+}
+
+fn can_calculate_route_overlap_impl(routes: Vec<(&str, Vec<Location>)>, use_module: bool, expected: f64) {
+    let insertion_ctx = create_test_insertion_context(routes, use_module);
+    let (_, objective) = RouteOverlap::new_minimized(Arc::new(|location| Some(location / 10)));
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+parameterized_test! {can_estimate_job_overlap, (route_index, location, expected), {
+    can_estimate_job_overlap_impl(route_index, location, expected);
+}}
+
+can_estimate_job_overlap! {
+    case01: (0, 45, 1.),
+    case02: (1, 45, 0.),
+    case03: (0, 55, 0.),
+    case04: (0, 15, 0.),
+    case05: (1, 28, 0.),
+    case06: (1, 15, 1.),
+}
+
+fn can_estimate_job_overlap_impl(route_index: usize, location: Location, expected: f64) {
+    let insertion_ctx = create_test_insertion_context(vec![("v1", vec![10, 20]), ("v2", vec![25, 40])], true);
+    let objective = RouteOverlapObjective { cell_func: Arc::new(|location| Some(location / 10)) };
+    let solution_ctx = &insertion_ctx.solution;
+    let job = Job::Single(test_single_with_location(Some(location)));
+
+    let estimate = objective.estimate_job(solution_ctx, &solution_ctx.routes[route_index], &job);
+
+    assert!(solution_ctx.get_max_cost() > 0.);
+    assert_eq!(estimate, expected * solution_ctx.get_max_cost());
+}
@@ -0,0 +1,46 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+
+parameterized_test! {can_calculate_weighted_lateness, (weights, use_module, expected), {
+    can_calculate_weighted_lateness_impl(weights, use_module, expected);
+}}
+
+can_calculate_weighted_lateness! {
+    case01: ((1., 1.), true, 10.),
+    case02: ((2., 0.5), true, 12.5),
+    case03: ((2., 0.5), false, 12.5),
+}
+
+fn can_calculate_weighted_lateness_impl(weights: (f64, f64), use_module: bool, expected: f64) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let (module, objective) = TotalLateness::new_minimized(
+        Arc::new(TestActivityCost::default()),
+        TestTransportCost::new_shared(),
+        Arc::new(move |activity| {
+            let weight = if activity.place.location == 10 { weights.0 } else { weights.1 };
+            activity.job.as_ref().map(|_| (activity.place.time.end, weight))
+        }),
+    );
+    let mut constraint = create_constraint_pipeline_with_transport();
+    if use_module {
+        constraint.add_module(module);
+    }
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_location_and_tw(10, TimeWindow::new(0., 5.)),
+            test_activity_with_location_and_tw(20, TimeWindow::new(0., 15.)),
+        ],
+    );
+    constraint.accept_route_state(&mut route_ctx);
+    let insertion_ctx = create_insertion_context(create_test_registry(), constraint, vec![route_ctx]);
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
@@ -0,0 +1,80 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::Place;
+
+parameterized_test! {can_count_overtime_routes, (shift_ends, expected), {
+    can_count_overtime_routes_impl(shift_ends, expected);
+}}
+
+can_count_overtime_routes! {
+    case01_no_overtime: ((Some(100.), Some(100.)), 0.),
+    case02_one_overtime: ((Some(10.), Some(100.)), 1.),
+    case03_all_overtime: ((Some(10.), Some(10.)), 2.),
+    case04_no_soft_shift_end: ((None, None), 0.),
+}
+
+fn can_count_overtime_routes_impl(shift_ends: (Option<f64>, Option<f64>), expected: f64) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let (_, objective) = TotalOvertimeRoutes::new_minimized(Arc::new(move |actor: &Actor| {
+        if get_vehicle_id(&actor.vehicle) == "v1" {
+            shift_ends.0
+        } else {
+            shift_ends.1
+        }
+    }));
+    let constraint = create_constraint_pipeline_with_transport();
+    let routes = vec![("v1", 10), ("v2", 20)]
+        .into_iter()
+        .map(|(vehicle_id, location)| {
+            let mut route_ctx =
+                create_route_context_with_activities(&fleet, vehicle_id, vec![test_activity_with_location(location)]);
+            constraint.accept_route_state(&mut route_ctx);
+            route_ctx
+        })
+        .collect();
+    let insertion_ctx = create_insertion_context(create_test_registry(), constraint, routes);
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+parameterized_test! {can_estimate_job_insertion, (shift_end, expected_penalty), {
+    can_estimate_job_insertion_impl(shift_end, expected_penalty);
+}}
+
+can_estimate_job_insertion! {
+    case01_stays_in_regular_time: (100., false),
+    case02_starts_overtime: (25., true),
+    case03_already_in_overtime: (10., false),
+}
+
+fn can_estimate_job_insertion_impl(shift_end: f64, expected_penalty: bool) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let (module, _) = TotalOvertimeRoutes::new_minimized(Arc::new(move |_: &Actor| Some(shift_end)));
+    let constraint = create_constraint_pipeline_with_transport();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![test_activity_with_location(10)]);
+    constraint.accept_route_state(&mut route_ctx);
+    let insertion_ctx = create_insertion_context(create_test_registry(), constraint, vec![route_ctx.clone()]);
+    let job = Job::Single(Arc::new(Single {
+        places: vec![Place { duration: 10., ..test_place_with_location(Some(5)) }],
+        ..test_single()
+    }));
+
+    let soft_route = module
+        .get_constraints()
+        .find_map(|constraint| match constraint {
+            ConstraintVariant::SoftRoute(soft_route) => Some(soft_route.clone()),
+            _ => None,
+        })
+        .unwrap();
+    let penalty = soft_route.estimate_job(&insertion_ctx.solution, &route_ctx, &job);
+
+    assert_eq!(penalty > 0., expected_penalty);
+}
@@ -0,0 +1,40 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::create_test_registry;
+use crate::models::common::IdDimension;
+
+fn create_insertion_ctx_with_unassigned(job_ids: Vec<&str>) -> InsertionContext {
+    let mut insertion_ctx = create_insertion_context(create_test_registry(), ConstraintPipeline::default(), vec![]);
+    job_ids.into_iter().for_each(|job_id| {
+        insertion_ctx.solution.unassigned.insert(Job::Single(test_single_with_id(job_id)), 0);
+    });
+
+    insertion_ctx
+}
+
+fn get_weight_fn() -> UnassignedJobWeightFn {
+    Arc::new(|job: &Job| if job.dimens().get_id().map_or(false, |id| id.starts_with("important")) { 10. } else { 1. })
+}
+
+parameterized_test! {can_compare_unassigned_jobs, (is_weighted, left, right, expected), {
+    can_compare_unassigned_jobs_impl(is_weighted, left, right, expected);
+}}
+
+can_compare_unassigned_jobs! {
+    case01_less_unassigned: (true, vec!["important"], vec!["job1", "job2"], Ordering::Less),
+    case02_same_amount_weighted: (true, vec!["job1"], vec!["important"], Ordering::Less),
+    case03_same_amount_same_weight: (true, vec!["job1"], vec!["job2"], Ordering::Equal),
+    case04_same_amount_not_weighted: (false, vec!["job1"], vec!["important"], Ordering::Equal),
+}
+
+fn can_compare_unassigned_jobs_impl(is_weighted: bool, left: Vec<&str>, right: Vec<&str>, expected: Ordering) {
+    let objective =
+        if is_weighted { TotalUnassignedJobs::new_weighted(get_weight_fn()) } else { TotalUnassignedJobs::default() };
+    let (left, right) = (create_insertion_ctx_with_unassigned(left), create_insertion_ctx_with_unassigned(right));
+
+    assert_eq!(objective.total_order(&left, &right), expected);
+    assert_eq!(objective.total_order(&right, &left), expected.reverse());
+    assert_eq!(objective.fitness(&left), left.solution.unassigned.len() as f64);
+}
@@ -0,0 +1,91 @@
+use super::*;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::IdDimension;
+use std::collections::HashMap;
+
+fn create_mismatch_fn(affinities: Vec<(&str, &str)>) -> AffinityMismatchFn {
+    let affinities = affinities
+        .into_iter()
+        .map(|(job_id, vehicle_id)| (job_id.to_string(), vehicle_id.to_string()))
+        .collect::<HashMap<_, _>>();
+
+    Arc::new(move |actor, job| {
+        job.dimens()
+            .get_id()
+            .and_then(|job_id| affinities.get(job_id))
+            .map_or(false, |vehicle_id| actor.vehicle.dimens.get_id() != Some(vehicle_id))
+    })
+}
+
+fn create_test_insertion_ctx(affinities: Vec<(&str, &str)>) -> (InsertionContext, TargetObjective) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let create_route_ctx = |vehicle: &str, jobs: Vec<&str>| {
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            vehicle,
+            jobs.into_iter()
+                .map(|job_id| test_activity_with_job(test_single_with_id_and_location(job_id, Some(10))))
+                .collect(),
+        );
+        route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, 10.);
+
+        route_ctx
+    };
+    let routes = vec![create_route_ctx("v1", vec!["job1", "job2"]), create_route_ctx("v2", vec!["job3"])];
+
+    let (module, objective) = VehicleAffinity::new_minimized(create_mismatch_fn(affinities));
+    let mut constraint = ConstraintPipeline::default();
+    constraint.add_module(module);
+
+    (create_insertion_context(create_test_registry(), constraint, routes), objective)
+}
+
+parameterized_test! {can_count_affinity_mismatches, (affinities, expected), {
+    can_count_affinity_mismatches_impl(affinities, expected);
+}}
+
+can_count_affinity_mismatches! {
+    case01_no_affinities: (vec![], 0.),
+    case02_all_matched: (vec![("job1", "v1"), ("job2", "v1"), ("job3", "v2")], 0.),
+    case03_some_mismatched: (vec![("job1", "v1"), ("job2", "v2"), ("job3", "v1")], 2.),
+    case04_unknown_vehicle: (vec![("job1", "v3")], 1.),
+}
+
+fn can_count_affinity_mismatches_impl(affinities: Vec<(&str, &str)>, expected: f64) {
+    let (insertion_ctx, objective) = create_test_insertion_ctx(affinities);
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+parameterized_test! {can_estimate_job_affinity_mismatch, (vehicle, affinity, is_penalized), {
+    can_estimate_job_affinity_mismatch_impl(vehicle, affinity, is_penalized);
+}}
+
+can_estimate_job_affinity_mismatch! {
+    case01_same_vehicle: ("v1", Some("v1"), false),
+    case02_other_vehicle: ("v2", Some("v1"), true),
+    case03_no_affinity: ("v2", None, false),
+}
+
+fn can_estimate_job_affinity_mismatch_impl(vehicle: &str, affinity: Option<&str>, is_penalized: bool) {
+    let (insertion_ctx, _) =
+        create_test_insertion_ctx(affinity.map(|affinity| vec![("job4", affinity)]).unwrap_or_default());
+    let route_ctx = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .find(|route_ctx| route_ctx.route.actor.vehicle.dimens.get_id().map(|id| id.as_str()) == Some(vehicle))
+        .unwrap();
+    let job = Job::Single(test_single_with_id("job4"));
+
+    let result = insertion_ctx.problem.constraint.evaluate_soft_route(&insertion_ctx.solution, route_ctx, &job);
+
+    assert_eq!(result, if is_penalized { insertion_ctx.solution.get_max_cost() } else { 0. });
+}
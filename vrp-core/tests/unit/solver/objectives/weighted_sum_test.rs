@@ -0,0 +1,77 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use std::sync::Arc;
+
+struct FakeObjective {
+    key: i32,
+}
+
+impl Objective for FakeObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.state.get(&self.key).and_then(|value| value.downcast_ref::<f64>()).cloned().unwrap()
+    }
+}
+
+fn create_solution(values: (f64, f64)) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.state.insert(1, Arc::new(values.0));
+    insertion_ctx.solution.state.insert(2, Arc::new(values.1));
+
+    insertion_ctx
+}
+
+fn create_objectives(weights: (f64, f64)) -> Vec<(TargetObjective, f64)> {
+    vec![(Box::new(FakeObjective { key: 1 }), weights.0), (Box::new(FakeObjective { key: 2 }), weights.1)]
+}
+
+parameterized_test! {can_compare_solutions_with_weighted_sum, (is_normalized, scale, expected), {
+    can_compare_solutions_with_weighted_sum_impl(is_normalized, scale, expected);
+}}
+
+can_compare_solutions_with_weighted_sum! {
+    case01_raw: (false, 1., Ordering::Less),
+    case02_raw_scaled: (false, 0.001, Ordering::Greater),
+    case03_normalized: (true, 1., Ordering::Greater),
+    case04_normalized_scaled: (true, 0.001, Ordering::Greater),
+}
+
+fn can_compare_solutions_with_weighted_sum_impl(is_normalized: bool, scale: f64, expected: Ordering) {
+    // NOTE first objective is like distance, second one is like amount of tours
+    let a = create_solution((1000. * scale, 2.));
+    let b = create_solution((1200. * scale, 1.));
+    let objective = if is_normalized {
+        WeightedSum::new_normalized(create_objectives((1., 1.)), vec![1000. * scale, 4.])
+    } else {
+        WeightedSum::new(create_objectives((1., 1.)))
+    };
+
+    let result = objective.total_order(&a, &b);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_normalize_by_given_scales() {
+    let objective = WeightedSum::new_normalized(create_objectives((1., 2.)), vec![1000., 4.]);
+
+    assert_eq!(objective.fitness(&create_solution((1000., 4.))), 3.);
+    assert_eq!(objective.fitness(&create_solution((500., 8.))), 4.5);
+}
+
+#[test]
+fn can_ignore_non_positive_scales_when_normalizing() {
+    let objective = WeightedSum::new_normalized(create_objectives((1., 1.)), vec![0., 4.]);
+
+    assert_eq!(objective.fitness(&create_solution((0., 4.))), 1.);
+    assert_eq!(objective.fitness(&create_solution((2., 2.))), 2.5);
+}
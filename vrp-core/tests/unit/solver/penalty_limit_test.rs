@@ -0,0 +1,74 @@
+use super::*;
+use crate::construction::constraints::SoftTimeWindowModule;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+use crate::models::solution::Registry;
+use std::sync::Arc;
+
+const PENALTY: f64 = 2.;
+
+fn create_test_insertion_ctx(tw_ends: Vec<f64>) -> InsertionContext {
+    let mut constraint = create_constraint_pipeline_with_transport();
+    constraint.add_module(Box::new(SoftTimeWindowModule::new(
+        Arc::new(TestActivityCost::default()),
+        TestTransportCost::new_shared(),
+        Arc::new(|activity| activity.job.as_ref().map(|_| (activity.place.time.end, PENALTY))),
+    )));
+
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = tw_ends
+        .into_iter()
+        .enumerate()
+        .map(|(idx, end)| test_activity_with_location_and_tw((idx + 1) * 10, TimeWindow::new(0., end)))
+        .collect();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    constraint.accept_route_state(&mut route_ctx);
+
+    create_insertion_context(Registry::new(&fleet), constraint, vec![route_ctx])
+}
+
+fn get_total_penalty(insertion_ctx: &InsertionContext) -> Cost {
+    insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.get_route_penalty()).sum()
+}
+
+parameterized_test! {can_shed_jobs_when_penalty_exceeds_limit, (tw_ends, limit, expected_removed), {
+    can_shed_jobs_when_penalty_exceeds_limit_impl(tw_ends, limit, expected_removed);
+}}
+
+can_shed_jobs_when_penalty_exceeds_limit! {
+    case01: (vec![100., 100., 100.], 0., 0),
+    case02: (vec![5., 5., 100.], 1000., 0),
+    case03: (vec![100., 100., 5.], 0., 1),
+    case04: (vec![5., 5., 5.], 0., 3),
+}
+
+fn can_shed_jobs_when_penalty_exceeds_limit_impl(tw_ends: Vec<f64>, limit: Cost, expected_removed: usize) {
+    let total_jobs = tw_ends.len();
+    let mut insertion_ctx = create_test_insertion_ctx(tw_ends);
+
+    let removed = apply_penalty_limit(&mut insertion_ctx, limit);
+
+    assert_eq!(removed, expected_removed);
+    assert!(get_total_penalty(&insertion_ctx) <= limit);
+    assert_eq!(insertion_ctx.solution.unassigned.len(), expected_removed);
+    assert!(insertion_ctx.solution.unassigned.values().all(|&code| code == PENALTY_LIMIT_CODE));
+    let assigned =
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>();
+    assert_eq!(assigned, total_jobs - expected_removed);
+}
+
+#[test]
+fn can_remove_job_which_reduces_penalty_most() {
+    let mut insertion_ctx = create_test_insertion_ctx(vec![100., 5., 100.]);
+    let late_job = insertion_ctx.solution.routes[0].route.tour.get(2).and_then(|a| a.retrieve_job()).unwrap();
+    let penalty = get_total_penalty(&insertion_ctx);
+    assert!(penalty > 0.);
+
+    let removed = apply_penalty_limit(&mut insertion_ctx, penalty - 1.);
+
+    assert_eq!(removed, 1);
+    assert_eq!(insertion_ctx.solution.unassigned.get(&late_job).cloned(), Some(PENALTY_LIMIT_CODE));
+}
@@ -4,8 +4,9 @@ use crate::helpers::construction::constraints::create_constraint_pipeline_with_t
 use crate::helpers::models::domain::*;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::create_route_context_with_activities;
-use crate::models::problem::Job;
+use crate::models::problem::{Job, ObjectiveCost};
 use crate::models::Problem;
+use crate::solver::objectives::{TotalTransportCost, TotalUnassignedJobs};
 use crate::solver::{DominancePopulation, Individual, Population};
 use std::sync::Arc;
 
@@ -141,3 +142,29 @@ fn can_check_improvement() {
     assert_eq!(false, population.add(create_individual(&problem, 20., 0)));
     assert_eq!(true, population.add(create_individual(&problem, 5., 0)));
 }
+
+#[test]
+fn can_use_initial_phase_objectives_only_when_population_is_in_initial_phase() {
+    let problem = Arc::new(Problem {
+        objective: Arc::new(
+            ObjectiveCost::new(vec![Box::new(TotalTransportCost::default())], vec![])
+                .with_initial_phase(vec![Box::new(TotalUnassignedJobs::default())]),
+        ),
+        ..Arc::try_unwrap(create_problem()).ok().unwrap()
+    });
+    let individuals = || vec![create_individual(&problem, 100., 0), create_individual(&problem, 25., 2)];
+    let mut initial_population = DominancePopulation::new(problem.clone(), 4);
+    let mut main_population = DominancePopulation::new(problem.clone(), 4);
+    initial_population.set_initial_phase(true);
+
+    initial_population.add_all(individuals());
+    main_population.add_all(individuals());
+
+    assert!(initial_population.is_initial_phase());
+    assert!(!main_population.is_initial_phase());
+    assert_eq!(get_best_fitness(&initial_population), 100.);
+    assert_eq!(get_best_fitness(&main_population), 25.);
+
+    initial_population.set_initial_phase(false);
+    assert_eq!(get_best_fitness(&initial_population), 25.);
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::create_problem_with_constraint_jobs_and_fleet;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::models::common::{Location, TimeWindow};
+use crate::models::solution::{Activity, Registry};
+
+fn create_activity(id: &str, location: Location, tw: TimeWindow) -> Activity {
+    let mut activity = test_activity_with_job(test_single_with_id_and_location(id, Some(location)));
+    activity.place.location = location;
+    activity.place.time = tw;
+
+    activity
+}
+
+fn create_problem_and_solution(activities: Vec<Activity>) -> (Arc<Problem>, Solution) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route = create_route_with_activities(&fleet, "v1", activities);
+    let problem =
+        create_problem_with_constraint_jobs_and_fleet(create_constraint_pipeline_with_transport(), vec![], fleet);
+    let solution = Solution {
+        registry: Registry::new(&problem.fleet),
+        routes: vec![route],
+        unassigned: vec![],
+        extras: Arc::new(Default::default()),
+    };
+
+    (problem, solution)
+}
+
+parameterized_test! {can_calculate_removal_savings, (job_id, expected), {
+    can_calculate_removal_savings_impl(job_id, expected);
+}}
+
+can_calculate_removal_savings! {
+    case01_before_waiting: ("job1", 0.),
+    case02_waiting: ("job2", 80.),
+    case03_after_waiting: ("job3", 0.),
+}
+
+fn can_calculate_removal_savings_impl(job_id: &str, expected: Cost) {
+    // NOTE vehicle and driver costs are 1 per distance and time unit: route 0-5-10-3-0 has 20 distance
+    // and 40 duration units as vehicle waits at 10 till 30, so its cost is 2 * (20 + 40) = 120.
+    // Without job2, route 0-5-3-0 costs 2 * (10 + 10) = 40, without job1 or job3 it still costs 120
+    let (problem, solution) = create_problem_and_solution(vec![
+        create_activity("job1", 5, TimeWindow::new(0., 100.)),
+        create_activity("job2", 10, TimeWindow::new(30., 100.)),
+        create_activity("job3", 3, TimeWindow::new(0., 100.)),
+    ]);
+
+    let savings = removal_savings(&problem, &solution, job_id).expect("job should be assigned");
+
+    assert_eq!(savings, expected);
+}
+
+#[test]
+fn can_save_whole_route_cost_when_last_job_is_removed() {
+    let (problem, solution) = create_problem_and_solution(vec![create_activity("job1", 5, TimeWindow::new(0., 100.))]);
+
+    assert_eq!(removal_savings(&problem, &solution, "job1"), Some(40.));
+}
+
+#[test]
+fn can_return_none_for_unknown_job() {
+    let (problem, solution) = generate_matrix_routes(3, 2, |data| (data.clone(), data));
+
+    assert_eq!(removal_savings(&problem, &solution, "unknown"), None);
+}
@@ -0,0 +1,52 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::helpers::utils::random::FakeRandom;
+
+fn create_insertion_ctx(rows: usize, cols: usize, routes: usize) -> InsertionContext {
+    let (problem, mut solution) = generate_matrix_routes(rows, cols, |data| (data.clone(), data));
+    solution.routes.truncate(routes);
+    let registry = &mut solution.registry;
+    solution.routes.iter().for_each(|route| {
+        registry.use_actor(&route.actor);
+    });
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(FakeRandom::new(vec![], vec![])))
+}
+
+parameterized_test! {can_split_route, (rows, cols, routes, expected), {
+    can_split_route_impl(rows, cols, routes, expected);
+}}
+
+can_split_route! {
+    case01_free_actor: (4, 2, 1, Some(2)),
+    case02_no_free_actor: (4, 1, 1, None),
+    case03_all_actors_used: (4, 2, 2, None),
+    case04_single_job: (1, 2, 1, None),
+}
+
+fn can_split_route_impl(rows: usize, cols: usize, routes: usize, expected: Option<usize>) {
+    let mut insertion_ctx = create_insertion_ctx(rows, cols, routes);
+    let original_ids = get_customer_ids_from_routes(&insertion_ctx);
+
+    let result = split_route(&mut insertion_ctx, 0);
+
+    assert_eq!(result, expected.is_some());
+    let ids = get_customer_ids_from_routes(&insertion_ctx);
+    match expected {
+        Some(expected) => {
+            assert_eq!(ids.len(), expected);
+            assert!(ids.iter().all(|ids| !ids.is_empty()));
+            assert_eq!(ids.concat(), original_ids.concat());
+        }
+        None => assert_eq!(ids, original_ids),
+    }
+}
+
+#[test]
+fn can_return_false_for_unknown_route() {
+    let mut insertion_ctx = create_insertion_ctx(4, 2, 1);
+
+    assert!(!split_route(&mut insertion_ctx, 1));
+    assert_eq!(insertion_ctx.solution.routes.len(), 1);
+}
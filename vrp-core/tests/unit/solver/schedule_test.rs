@@ -0,0 +1,75 @@
+use super::*;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::SolutionContext;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::create_problem_with_constraint_jobs_and_fleet;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{IdDimension, Location, TimeWindow};
+use crate::models::problem::Job;
+use crate::models::solution::Registry;
+use std::slice::Iter;
+
+/// Simulates a module which moves jobs out of routes when solution state is accepted.
+struct RemoveRoutesModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for RemoveRoutesModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.clear();
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+fn get_locations(solution: &Solution) -> Vec<Vec<Location>> {
+    solution
+        .routes
+        .iter()
+        .map(|route| route.tour.all_activities().map(|activity| activity.place.location).collect())
+        .collect()
+}
+
+#[test]
+fn can_keep_route_which_violates_time_window() {
+    let mut constraint = create_constraint_pipeline_with_transport();
+    constraint.add_module(Box::new(RemoveRoutesModule { constraints: vec![], keys: vec![] }));
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route = create_route_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_location_and_tw(5, TimeWindow::new(0., 100.)),
+            test_activity_with_location_and_tw(3, TimeWindow::new(0., 6.)),
+        ],
+    );
+    let problem = create_problem_with_constraint_jobs_and_fleet(constraint, vec![], fleet);
+    let solution = Solution {
+        registry: Registry::new(&problem.fleet),
+        routes: vec![route],
+        unassigned: vec![],
+        extras: Arc::new(Default::default()),
+    };
+
+    let result = create_scheduled_solution(problem, solution);
+
+    assert_eq!(get_locations(&result), vec![vec![0, 5, 3, 0]]);
+    let route = result.routes.first().unwrap();
+    assert_eq!(route.actor.vehicle.dimens.get_id().unwrap(), "v1");
+    let activity = route.tour.get(2).unwrap();
+    assert_eq!(activity.schedule.arrival, 7.);
+    assert!(activity.schedule.arrival > activity.place.time.end);
+    assert_eq!(result.extras.get(SCHEDULE_ONLY_KEY).and_then(|value| value.downcast_ref::<bool>()), Some(&true));
+}
@@ -0,0 +1,56 @@
+use super::*;
+use crate::helpers::models::domain::{create_empty_problem, create_empty_solution};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+use crate::utils::StreamRandom;
+use std::sync::Arc;
+
+fn create_activity(location: Location, tw: TimeWindow, distribution: Option<DurationDistribution>) -> Activity {
+    let mut single = SingleBuilder::default().location(Some(location)).build();
+    if let Some(distribution) = distribution {
+        single.dimens.set_value(DURATION_DISTRIBUTION_KEY, distribution);
+    }
+
+    Activity { job: Some(Arc::new(single)), ..test_activity_with_location_and_tw(location, tw) }
+}
+
+parameterized_test! {can_estimate_schedule_feasibility, (distribution, latest, expected), {
+    can_estimate_schedule_feasibility_impl(distribution, latest, expected);
+}}
+
+can_estimate_schedule_feasibility! {
+    case01_normal_loose: (DurationDistribution::Normal { mean: 5., std_dev: 2. }, 100., (1., 1.)),
+    case02_normal_tight: (DurationDistribution::Normal { mean: 5., std_dev: 2. }, 25., (0.4, 0.6)),
+    case03_normal_too_tight: (DurationDistribution::Normal { mean: 5., std_dev: 2. }, 15., (0., 0.)),
+    case04_uniform_loose: (DurationDistribution::Uniform { min: 0., max: 10. }, 30., (1., 1.)),
+    case05_uniform_tight: (DurationDistribution::Uniform { min: 0., max: 10. }, 22., (0.15, 0.25)),
+}
+
+fn can_estimate_schedule_feasibility_impl(distribution: DurationDistribution, latest: f64, expected: (f64, f64)) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route = create_route_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            create_activity(10, TimeWindow::new(0., 1000.), Some(distribution)),
+            create_activity(20, TimeWindow::new(0., latest), None),
+        ],
+    );
+    let solution = Solution { routes: vec![route], ..create_empty_solution() };
+
+    let result =
+        estimate_schedule_feasibility(create_empty_problem().as_ref(), &solution, 1000, &StreamRandom::new(0, "test"));
+
+    assert_eq!(result.samples, 1000);
+    assert_eq!(result.routes, vec![result.feasible]);
+    assert!(result.probability() >= expected.0 && result.probability() <= expected.1);
+}
+
+#[test]
+fn can_sample_non_negative_durations() {
+    let random = StreamRandom::new(0, "test");
+    let distribution = DurationDistribution::Normal { mean: 0., std_dev: 10. };
+
+    assert!((0..100).map(|_| distribution.sample(&random)).all(|duration| duration >= 0.));
+}
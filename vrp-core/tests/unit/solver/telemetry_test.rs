@@ -1,6 +1,7 @@
 use super::*;
 use crate::helpers::solver::create_default_refinement_ctx;
 use crate::models::examples::create_example_problem;
+use crate::solver::Builder;
 use crate::utils::compare_floats;
 use std::cmp::Ordering;
 
@@ -35,3 +36,20 @@ fn can_update_statistic() {
     telemetry.on_generation(&mut refinement_ctx, Timer::start(), true);
     compare_statistic(&refinement_ctx, (1000, 2. / 1001., 0.001));
 }
+
+#[test]
+fn can_track_trajectory_in_metrics() {
+    let solver = Builder::new(create_example_problem())
+        .with_max_generations(Some(100))
+        .with_telemetry(Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 1000 }))
+        .build()
+        .unwrap();
+
+    let (_, cost, metrics) = solver.solve().unwrap();
+
+    let trajectory = metrics.expect("metrics are empty").trajectory;
+    assert!(!trajectory.is_empty());
+    assert!(trajectory.windows(2).all(|points| points[0].generation < points[1].generation));
+    assert!(trajectory.windows(2).all(|points| points[0].best >= points[1].best));
+    assert_eq!(compare_floats(trajectory.last().unwrap().best, cost), Ordering::Equal);
+}
@@ -0,0 +1,75 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::Solution;
+
+fn create_insertion_ctx(modify: fn(&mut Solution)) -> InsertionContext {
+    let (problem, mut solution) = generate_matrix_routes(4, 2, |data| (data.clone(), data));
+
+    modify(&mut solution);
+
+    let registry = &mut solution.registry;
+    solution.routes.iter().for_each(|route| {
+        registry.use_actor(&route.actor);
+    });
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(FakeRandom::new(vec![], vec![])))
+}
+
+fn get_costs(insertion_ctx: &InsertionContext) -> Vec<Cost> {
+    insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.get_route_cost()).collect()
+}
+
+fn get_job_counts(insertion_ctx: &InsertionContext) -> Vec<usize> {
+    insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).collect()
+}
+
+#[test]
+fn can_resequence_worst_route() {
+    let mut insertion_ctx = create_insertion_ctx(|solution| {
+        let activity = solution.routes[1].tour.all_activities().nth(2).unwrap().deep_copy();
+        solution.routes[1].tour.remove(&activity.retrieve_job().unwrap());
+        solution.routes[1].tour.insert_at(activity, 3);
+    });
+    let original_costs = get_costs(&insertion_ctx);
+
+    let result = improve_worst_route(&mut insertion_ctx, 0);
+
+    assert!(result);
+    let costs = get_costs(&insertion_ctx);
+    assert_eq!(costs[0], original_costs[0]);
+    assert!(costs[1] < original_costs[1]);
+    assert_eq!(get_job_counts(&insertion_ctx), vec![4, 4]);
+}
+
+#[test]
+fn can_exchange_jobs_with_neighbour_route() {
+    let mut insertion_ctx = create_insertion_ctx(|solution| {
+        let first = solution.routes[0].tour.all_activities().nth(4).unwrap().deep_copy();
+        let second = solution.routes[1].tour.all_activities().nth(2).unwrap().deep_copy();
+        solution.routes[0].tour.remove(&first.retrieve_job().unwrap());
+        solution.routes[1].tour.remove(&second.retrieve_job().unwrap());
+        solution.routes[0].tour.insert_last(second);
+        solution.routes[1].tour.insert_at(first, 2);
+    });
+    let original_costs = get_costs(&insertion_ctx);
+
+    let result = improve_worst_route(&mut insertion_ctx, 2);
+
+    assert!(result);
+    let costs = get_costs(&insertion_ctx);
+    assert!(costs[0] < original_costs[0]);
+    assert!(costs[1] < original_costs[1]);
+    assert_eq!(get_job_counts(&insertion_ctx), vec![4, 4]);
+}
+
+#[test]
+fn can_keep_solution_intact_without_improvement() {
+    let mut insertion_ctx = create_insertion_ctx(|_| {});
+    let original_costs = get_costs(&insertion_ctx);
+
+    let result = improve_worst_route(&mut insertion_ctx, 2);
+
+    assert!(!result);
+    assert_eq!(get_costs(&insertion_ctx), original_costs);
+}
@@ -20,3 +20,21 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+#[test]
+fn can_derive_different_seeds_for_different_keys() {
+    assert_eq!(derive_seed(42, "ruin:0:0"), derive_seed(42, "ruin:0:0"));
+    assert_ne!(derive_seed(42, "ruin:0:0"), derive_seed(42, "ruin:0:1"));
+    assert_ne!(derive_seed(42, "ruin:0:0"), derive_seed(43, "ruin:0:0"));
+}
+
+#[test]
+fn can_reproduce_stream_values() {
+    let get_values = |random: &StreamRandom| (0..5).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>();
+
+    let values = get_values(&StreamRandom::new(42, "stream"));
+
+    assert_eq!(values, get_values(&StreamRandom::new(42, "stream")));
+    assert_ne!(values, get_values(&StreamRandom::new(42, "other")));
+    assert!(values.iter().any(|&value| value != values[0]));
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/anchors_test.rs"]
+mod anchors_test;
+
+use super::*;
+
+/// Checks that every non empty tour visits exactly one anchor, if anchors are defined.
+pub fn check_anchors(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle_shift = context.get_vehicle_shift(tour)?;
+        let has_anchors = get_shift_anchors(&context.problem, &vehicle_shift).map_or(false, |a| !a.is_empty());
+
+        let anchor_count = tour.stops.iter().try_fold(0, |acc, stop| {
+            stop.activities
+                .iter()
+                .filter(|activity| activity.activity_type == "anchor")
+                .try_fold(acc, |acc, activity| context.get_activity_type(tour, stop, activity).map(|_| acc + 1))
+        })?;
+
+        let job_count = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .filter(|activity| context.job_map.contains_key(activity.job_id.as_str()))
+            .count();
+
+        let has_violation = context.solution.violations.as_ref().map_or(false, |violations| {
+            violations.iter().any(|violation| match violation {
+                Violation::Anchor { vehicle_id, shift_index, .. } => {
+                    *vehicle_id == tour.vehicle_id && *shift_index == tour.shift_index
+                }
+                _ => false,
+            })
+        });
+
+        match (anchor_count, job_count) {
+            (count, _) if count > 1 => Err(format!(
+                "Tour has '{}' anchor visits, expected one for vehicle '{}', shift index '{}'",
+                count, tour.vehicle_id, tour.shift_index
+            )),
+            (1, 0) => Err(format!(
+                "Tour without jobs has anchor visit for vehicle '{}', shift index '{}'",
+                tour.vehicle_id, tour.shift_index
+            )),
+            (0, count) if count > 0 && has_anchors && !has_violation => Err(format!(
+                "Tour has no anchor visit for vehicle '{}', shift index '{}'",
+                tour.vehicle_id, tour.shift_index
+            )),
+            _ => Ok(()),
+        }
+    })
+}
@@ -3,20 +3,31 @@
 mod assignment_test;
 
 use super::*;
+use crate::checker::limits::get_service_start;
 use crate::format::solution::activity_matcher::try_match_job;
 use crate::format::{get_coord_index, get_job_index};
 use std::collections::HashSet;
+use vrp_core::utils::CollectGroupBy;
 
 /// Checks assignment of jobs and vehicles.
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), String> {
     check_vehicles(ctx)?;
+    check_dock_slots(ctx)?;
     check_jobs_presence(ctx)?;
     check_jobs_match(ctx)?;
+    check_jobs_companions(ctx)?;
+    check_jobs_transfers(ctx)?;
+    check_jobs_corridors(ctx)?;
+    check_jobs_crew_sizes(ctx)?;
+    check_loading_disciplines(ctx)?;
+    check_skill_time_windows(ctx)?;
+    check_receiving_rates(ctx)?;
 
     Ok(())
 }
 
-/// Checks that vehicles in each tour are used once per shift and they are known in problem.
+/// Checks that vehicles in each tour are used once per shift, they are known in problem and
+/// they work on the day of the tour.
 fn check_vehicles(ctx: &CheckerContext) -> Result<(), String> {
     let all_vehicles: HashSet<_> = ctx.problem.fleet.vehicles.iter().flat_map(|v| v.vehicle_ids.iter()).collect();
     let mut used_vehicles = HashSet::<(String, usize)>::new();
@@ -26,6 +37,10 @@ fn check_vehicles(ctx: &CheckerContext) -> Result<(), String> {
             return Err(format!("Used vehicle with unknown id: {}", tour.vehicle_id));
         }
 
+        if let Some(day) = tour.day.filter(|&day| !is_vehicle_working_day(&ctx.problem, &tour.vehicle_id, day)) {
+            return Err(format!("Vehicle '{}' is used on day {} when it does not work", tour.vehicle_id, day));
+        }
+
         if !(used_vehicles.insert((tour.vehicle_id.to_string(), tour.shift_index))) {
             Err(format!("Vehicle with '{}' id used more than once for shift {}", tour.vehicle_id, tour.shift_index))
         } else {
@@ -36,6 +51,26 @@ fn check_vehicles(ctx: &CheckerContext) -> Result<(), String> {
     Ok(())
 }
 
+/// Checks that vehicles depart from their start locations within dock slots.
+fn check_dock_slots(ctx: &CheckerContext) -> Result<(), String> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let shift = ctx.get_vehicle_shift(tour)?;
+        let slot = match shift.start.dock_slot.as_ref() {
+            Some(slot) if slot.len() == 2 => (parse_time(&slot[0]), parse_time(&slot[1])),
+            _ => return Ok(()),
+        };
+
+        let departure =
+            parse_time(&tour.stops.first().ok_or_else(|| "Cannot get first stop".to_string())?.time.departure);
+
+        if slot.0 <= departure && departure <= slot.1 {
+            Ok(())
+        } else {
+            Err(format!("Vehicle '{}' departs at {} which is outside of its dock slot", tour.vehicle_id, departure))
+        }
+    })
+}
+
 /// Checks job task rules.
 fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
     struct JobAssignment {
@@ -184,3 +219,255 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Checks that jobs with companions are served within the same tour as at least one of them.
+fn check_jobs_companions(ctx: &CheckerContext) -> Result<(), String> {
+    let all_companions = ctx
+        .problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.companions.as_ref().filter(|companions| !companions.is_empty()).map(|c| (&job.id, c)))
+        .collect::<HashMap<_, _>>();
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let job_ids = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .filter(|activity| {
+                matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+            })
+            .map(|activity| &activity.job_id)
+            .collect::<HashSet<_>>();
+
+        job_ids.iter().try_for_each(|id| match all_companions.get(id) {
+            Some(companions) if !companions.iter().any(|companion| companion != *id && job_ids.contains(companion)) => {
+                Err(format!("Job '{}' is served without companions in tour '{}'", id, tour.vehicle_id))
+            }
+            _ => Ok(()),
+        })
+    })
+}
+
+/// Checks that jobs of the same service corridor are served in ascending order of their positions.
+fn check_jobs_corridors(ctx: &CheckerContext) -> Result<(), String> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .filter_map(|activity| {
+                ctx.get_job_by_id(&activity.job_id)
+                    .and_then(|job| job.corridor.as_ref())
+                    .map(|corridor| (&activity.job_id, corridor))
+            })
+            .try_fold(HashMap::<&str, f64>::new(), |mut positions, (job_id, corridor)| {
+                match positions.insert(corridor.id.as_str(), corridor.position) {
+                    Some(last) if last > corridor.position => Err(format!(
+                        "Job '{}' is served out of order of corridor '{}' in tour '{}'",
+                        job_id, corridor.id, tour.vehicle_id
+                    )),
+                    _ => Ok(positions),
+                }
+            })
+            .map(|_| ())
+    })
+}
+
+/// Checks that jobs are served by vehicles with crew big enough to serve them.
+fn check_jobs_crew_sizes(ctx: &CheckerContext) -> Result<(), String> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let crew_size = ctx.get_vehicle(&tour.vehicle_id)?.crew_size.unwrap_or(1);
+
+        tour.stops.iter().flat_map(|stop| stop.activities.iter()).try_for_each(|activity| {
+            match ctx.get_job_by_id(&activity.job_id).and_then(|job| job.crew_size) {
+                Some(required) if required > crew_size => Err(format!(
+                    "Job '{}' requires crew of size {}, but vehicle '{}' has crew of size {}",
+                    activity.job_id, required, tour.vehicle_id, crew_size
+                )),
+                _ => Ok(()),
+            }
+        })
+    })
+}
+
+/// Checks that job activities are served within skill time windows only by vehicles with required
+/// skills. NOTE only places with time windows specified by `times` are considered.
+fn check_skill_time_windows(ctx: &CheckerContext) -> Result<(), String> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let skills = ctx.get_vehicle(&tour.vehicle_id)?.skills.clone().unwrap_or_default();
+
+        tour.stops.iter().flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity))).try_for_each(
+            |(stop, activity)| {
+                let activity_type = ctx.get_activity_type(tour, stop, activity)?;
+                let location = ctx.get_activity_location(stop, activity);
+                let place = ctx.visit_job(
+                    activity,
+                    &activity_type,
+                    |_, task| task.places.iter().find(|place| place.location == location).cloned(),
+                    || None,
+                )?;
+
+                let (times, skill_times) = match place {
+                    Some(JobPlace {
+                        times,
+                        skill_times: Some(skill_times),
+                        relative_times: None,
+                        opening_times: None,
+                        ..
+                    }) => (times, skill_times),
+                    _ => return Ok(()),
+                };
+
+                let service_start = get_service_start(ctx, tour, stop, activity)?;
+                let contains = |tw: &Vec<String>| {
+                    let tw = parse_time_window(tw);
+                    tw.start <= service_start && service_start <= tw.end
+                };
+
+                let is_standard = times.as_ref().map_or(true, |times| times.is_empty() || times.iter().any(contains));
+                let is_allowed = skill_times
+                    .iter()
+                    .filter(|skill_times| skill_times.times.iter().any(contains))
+                    .all(|skill_times| skill_times.skills.iter().all(|skill| skills.contains(skill)));
+
+                if is_standard || is_allowed {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "Job '{}' is served within skill time window by vehicle '{}' without required skills",
+                        activity.job_id, tour.vehicle_id
+                    ))
+                }
+            },
+        )
+    })
+}
+
+/// Checks that pickup and delivery jobs are unloaded in order defined by vehicle loading discipline.
+fn check_loading_disciplines(ctx: &CheckerContext) -> Result<(), String> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let discipline = match ctx.get_vehicle(&tour.vehicle_id)?.loading_discipline.as_ref() {
+            Some(discipline) => discipline,
+            None => return Ok(()),
+        };
+
+        let intervals = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .filter(|activity| {
+                ctx.get_job_by_id(&activity.job_id)
+                    .map_or(false, |job| job.pickups.is_some() && job.deliveries.is_some())
+            })
+            .enumerate()
+            .fold(HashMap::<String, (usize, usize)>::new(), |mut acc, (idx, activity)| {
+                acc.entry(activity.job_id.clone()).or_insert((idx, idx)).1 = idx;
+                acc
+            });
+
+        intervals.iter().try_for_each(|(job_id, &(start, end))| {
+            intervals.iter().filter(|(_, &(other_start, _))| start < other_start).try_for_each(
+                |(other_id, &(other_start, other_end))| {
+                    let is_violated = match discipline {
+                        VehicleLoadingDiscipline::Lifo => other_start < end && end < other_end,
+                        VehicleLoadingDiscipline::Fifo => other_end < end,
+                    };
+
+                    if is_violated {
+                        Err(format!(
+                            "jobs '{}' and '{}' violate {:?} loading discipline of vehicle '{}'",
+                            job_id, other_id, discipline, tour.vehicle_id
+                        ))
+                    } else {
+                        Ok(())
+                    }
+                },
+            )
+        })
+    })
+}
+
+/// Checks that transfer jobs are served by other tour than their first legs and their activities are
+/// completed after first leg's activities.
+fn check_jobs_transfers(ctx: &CheckerContext) -> Result<(), String> {
+    let activities = ctx
+        .solution
+        .tours
+        .iter()
+        .enumerate()
+        .flat_map(|(tour_idx, tour)| {
+            tour.stops.iter().flat_map(move |stop| {
+                stop.activities
+                    .iter()
+                    .filter(|activity| {
+                        matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+                    })
+                    .map(move |activity| (activity.job_id.clone(), (tour_idx, ctx.get_activity_time(stop, activity))))
+            })
+        })
+        .collect_group_by();
+
+    ctx.problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.transfer_from.as_ref().map(|source| (&job.id, source)))
+        .try_for_each(|(id, source)| match (activities.get(id), activities.get(source)) {
+            (None, _) => Ok(()),
+            (Some(_), None) => Err(format!("Transfer job '{}' is served without job '{}'", id, source)),
+            (Some(transfer), Some(source_activities)) => {
+                let (tour_idx, first) = transfer.first().unwrap();
+                let (source_tour_idx, last) = source_activities.last().unwrap();
+
+                if tour_idx == source_tour_idx {
+                    Err(format!("Transfer job '{}' is served in the same tour as job '{}'", id, source))
+                } else if first.end < last.end {
+                    Err(format!("Transfer job '{}' is served before job '{}' is completed", id, source))
+                } else {
+                    Ok(())
+                }
+            }
+        })
+}
+
+/// Checks that arrivals of different tours at locations with receiving rate are not too close.
+fn check_receiving_rates(ctx: &CheckerContext) -> Result<(), String> {
+    let rates = ctx.problem.config.as_ref().and_then(|config| config.receiving_rates.as_ref());
+
+    rates.iter().flat_map(|rates| rates.iter()).try_for_each(|rate| {
+        let arrivals = ctx
+            .solution
+            .tours
+            .iter()
+            .enumerate()
+            .flat_map(|(tour_idx, tour)| {
+                tour.stops.iter().flat_map(move |stop| {
+                    stop.activities
+                        .iter()
+                        .filter(move |activity| ctx.get_activity_location(stop, activity) == rate.location)
+                        .filter(|activity| {
+                            matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+                        })
+                        .map(move |activity| {
+                            (tour_idx, activity.job_id.as_str(), ctx.get_activity_time(stop, activity))
+                        })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        arrivals.iter().try_for_each(|(tour_idx, job_id, time)| {
+            let other = arrivals.iter().find(|(other_idx, _, other_time)| {
+                other_idx != tour_idx && (time.start - other_time.start).abs() < rate.interval
+            });
+
+            match other {
+                Some((_, other_id, _)) => Err(format!(
+                    "Jobs '{}' and '{}' are served by different tours within receiving rate interval {}",
+                    job_id, other_id, rate.interval
+                )),
+                None => Ok(()),
+            }
+        })
+    })
+}
@@ -15,10 +15,9 @@ pub fn check_breaks(context: &CheckerContext) -> Result<(), String> {
             .filter(|activity| activity.activity_type == "break")
             .count();
         let matched_break_count = tour.stops.iter().try_fold(0, |acc, stop| {
-            stop.activities
-                .windows(stop.activities.len().min(2))
-                .flat_map(|leg| as_leg_info_with_break(context, tour, stop, leg))
-                .try_fold(acc, |acc, (from_loc, to, vehicle_break)| {
+            (0..stop.activities.len())
+                .flat_map(|idx| as_leg_info_with_break(context, tour, stop, idx))
+                .try_fold(acc, |acc, (from_loc, to, next_loc, vehicle_break)| {
                     // check time
                     let visit_time = get_time_window(stop, to);
                     let break_time_window = get_break_time_window(tour, &vehicle_break)?;
@@ -42,9 +41,9 @@ pub fn check_breaks(context: &CheckerContext) -> Result<(), String> {
                             }
                         }
                         None => {
-                            if *from_loc != actual_location {
+                            if *from_loc != actual_location && next_loc != Some(&actual_location) {
                                 return Err(format!(
-                                    "Break location '{:?}' is invalid: expected previous activity location '{:?}'",
+                                    "Break location '{:?}' is invalid: expected previous or next activity location '{:?}'",
                                     actual_location, from_loc
                                 ));
                             }
@@ -95,22 +94,20 @@ fn as_leg_info_with_break<'a>(
     context: &CheckerContext,
     tour: &Tour,
     stop: &'a Stop,
-    leg: &'a [Activity],
-) -> Option<(&'a Location, &'a Activity, VehicleBreak)> {
-    let leg = match leg {
-        [from, to] => Some((from.location.as_ref().unwrap_or(&stop.location), to)),
-        [to] => Some((&stop.location, to)),
-        _ => None,
-    };
+    idx: usize,
+) -> Option<(&'a Location, &'a Activity, Option<&'a Location>, VehicleBreak)> {
+    let get_location = |activity: &'a Activity| activity.location.as_ref().unwrap_or(&stop.location);
 
-    if let Some((from_loc, to)) = leg {
-        if let Ok(activity_type) = context.get_activity_type(tour, stop, to) {
-            if let ActivityType::Break(vehicle_break) = activity_type {
-                return Some((from_loc, to, vehicle_break));
-            }
-        }
+    let to = stop.activities.get(idx)?;
+    let from_loc =
+        if idx > 0 { stop.activities.get(idx - 1).map_or(&stop.location, get_location) } else { &stop.location };
+    let next_loc = stop.activities.get(idx + 1).map(get_location);
+
+    if let Ok(ActivityType::Break(vehicle_break)) = context.get_activity_type(tour, stop, to) {
+        Some((from_loc, to, next_loc, vehicle_break))
+    } else {
+        None
     }
-    None
 }
 
 fn get_break_time_window(tour: &Tour, vehicle_break: &VehicleBreak) -> Result<TimeWindow, String> {
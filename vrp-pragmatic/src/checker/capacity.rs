@@ -3,6 +3,7 @@
 mod capacity_test;
 
 use super::*;
+use crate::constraints::CapacityDecay;
 use std::iter::once;
 use vrp_core::models::common::{Load, MultiDimLoad};
 
@@ -120,6 +121,98 @@ pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
     })
 }
 
+/// Checks that total static delivery demand of all tours does not exceed depot inventory.
+pub fn check_depot_inventory(context: &CheckerContext) -> Result<(), String> {
+    let inventory = match context.problem.config.as_ref().and_then(|config| config.depot_inventory.as_ref()) {
+        Some(inventory) => MultiDimLoad::new(inventory.clone()),
+        None => return Ok(()),
+    };
+
+    let dispatched =
+        context.solution.tours.iter().try_fold::<_, _, Result<_, String>>(MultiDimLoad::default(), |acc, tour| {
+            tour.stops.iter().flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity))).try_fold(
+                acc,
+                |acc, (stop, activity)| {
+                    let activity_type = context.get_activity_type(tour, stop, activity)?;
+                    Ok(match get_demand(context, activity, &activity_type)? {
+                        (DemandType::StaticDelivery, demand) | (DemandType::StaticPickupDelivery, demand) => {
+                            acc + demand
+                        }
+                        _ => acc,
+                    })
+                },
+            )
+        })?;
+
+    if inventory.can_fit(&dispatched) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Depot inventory violation, expected: not more than {:?}, got: {:?}",
+            inventory.as_vec(),
+            dispatched.as_vec()
+        ))
+    }
+}
+
+/// Checks that weighted sum of vehicle load does not exceed vehicle aggregate capacity.
+pub fn check_aggregate_capacity(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let aggregate = match context.get_vehicle(&tour.vehicle_id)?.aggregate_capacity.as_ref() {
+            Some(aggregate) => aggregate,
+            None => return Ok(()),
+        };
+
+        tour.stops.iter().try_for_each(|stop| {
+            let value =
+                stop.load.iter().zip(aggregate.weights.iter()).map(|(load, weight)| *load as f64 * weight).sum::<f64>();
+
+            if value > aggregate.limit {
+                Err(format!(
+                    "Aggregate capacity violation in tour '{}', expected: not more than {}, got: {}",
+                    tour.vehicle_id, aggregate.limit, value
+                ))
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
+
+/// Checks that load carried on each leg fits vehicle capacity decayed by the leg's arrival time.
+pub fn check_capacity_decay(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let duration = match vehicle.capacity_decay.as_ref() {
+            Some(VehicleCapacityDecay::Linear { duration }) => *duration,
+            None => return Ok(()),
+        };
+        let capacity = MultiDimLoad::new(vehicle.capacity.clone());
+        let departure =
+            parse_time(&tour.stops.first().ok_or_else(|| "Cannot get first stop".to_string())?.time.departure);
+
+        tour.stops.windows(2).try_for_each(|leg| match leg {
+            [from, to] => {
+                let arrival = parse_time(&to.time.arrival);
+                let decayed = capacity * CapacityDecay::Linear { duration }.get_share(arrival - departure);
+
+                if decayed.can_fit(&MultiDimLoad::new(from.load.clone())) {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "Capacity decay violation in tour '{}', expected: not more than {:?} at '{}', got: {:?}",
+                        tour.vehicle_id,
+                        decayed.as_vec(),
+                        to.time.arrival,
+                        from.load
+                    ))
+                }
+            }
+            _ => Ok(()),
+        })
+    })
+}
+
 enum DemandType {
     None,
     StaticPickup,
@@ -3,16 +3,53 @@
 mod limits_test;
 
 use super::*;
+use std::collections::HashSet;
+use vrp_core::utils::compare_floats;
 
 /// Check that shift limits are not violated:
 /// * max shift time
 /// * max distance
+/// * vehicle range
+/// * max amount of jobs with time windows
+/// * max amount of reloads
+/// * max amount of jobs with time windows within any hour
+/// * max amount of late activities
+/// * max distance to the first job
+/// * max amount of tours active at the same time
+/// * min reload handling time
 ///
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), String> {
     context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
 
+        if let Some(range) = vehicle.range {
+            if tour.statistic.distance as f64 > range {
+                return Err(format!(
+                    "vehicle range violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                    range, tour.statistic.distance, tour.vehicle_id, tour.shift_index
+                ));
+            }
+        }
+
+        if let Some(handling_time) = vehicle.reload_handling_time {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+                .filter(|(_, activity)| activity.activity_type == "reload")
+                .try_for_each(|(stop, activity)| {
+                    let time = context.get_activity_time(stop, activity);
+                    if time.end - time.start < handling_time {
+                        Err(format!(
+                            "reload handling time violation, expected: not less than {}, got: {}, vehicle id '{}', shift index: {}",
+                            handling_time, time.end - time.start, tour.vehicle_id, tour.shift_index
+                        ))
+                    } else {
+                        Ok(())
+                    }
+                })?;
+        }
+
         if let Some(ref limits) = vehicle.limits {
             if let Some(max_distance) = limits.max_distance {
                 if tour.statistic.distance as f64 > max_distance {
@@ -31,8 +68,214 @@ pub fn check_limits(context: &CheckerContext) -> Result<(), String> {
                     ));
                 }
             }
+
+            if let Some(max_tw_jobs) = limits.max_time_window_jobs {
+                let tw_jobs = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities.iter())
+                    .filter_map(|activity| context.get_job_by_id(&activity.job_id))
+                    .filter(|job| has_time_windows(job))
+                    .map(|job| job.id.as_str())
+                    .collect::<HashSet<_>>()
+                    .len();
+
+                if tw_jobs > max_tw_jobs {
+                    return Err(format!(
+                        "max time window jobs limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_tw_jobs, tw_jobs, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_reloads) = limits.max_reloads {
+                let reloads = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities.iter())
+                    .filter(|activity| activity.activity_type == "reload")
+                    .count();
+
+                if reloads > max_reloads {
+                    return Err(format!(
+                        "max reloads limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_reloads, reloads, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_hourly_tw_jobs) = limits.max_hourly_time_window_jobs {
+                let mut service_starts = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+                    .filter(|(_, activity)| {
+                        matches!(context.get_job_by_id(&activity.job_id), Some(job) if has_time_windows(job))
+                    })
+                    .map(|(stop, activity)| get_service_start(context, tour, stop, activity))
+                    .collect::<Result<Vec<_>, _>>()?;
+                service_starts.sort_by(|a, b| compare_floats(*a, *b));
+
+                let hourly_tw_jobs = service_starts
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, start)| service_starts[..=idx].iter().filter(|other| **other > *start - 3600.).count())
+                    .max()
+                    .unwrap_or(0);
+
+                if hourly_tw_jobs > max_hourly_tw_jobs {
+                    return Err(format!(
+                        "max hourly time window jobs limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_hourly_tw_jobs, hourly_tw_jobs, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_late_stops) = limits.max_late_stops {
+                let late_stops = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+                    .map(|(stop, activity)| is_late_activity(context, tour, stop, activity))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .filter(|is_late| *is_late)
+                    .count();
+
+                if late_stops > max_late_stops {
+                    return Err(format!(
+                        "max late stops limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_late_stops, late_stops, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_first_leg_distance) = limits.max_first_leg_distance {
+                let first_leg_distance = tour
+                    .stops
+                    .iter()
+                    .find(|stop| {
+                        stop.activities.iter().any(|activity| {
+                            matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+                        })
+                    })
+                    .map_or(0, |stop| stop.distance);
+
+                if first_leg_distance as f64 > max_first_leg_distance {
+                    return Err(format!(
+                        "max first leg distance limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_first_leg_distance, first_leg_distance, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
         }
 
         Ok(())
-    })
+    })?;
+
+    check_vehicle_pool(context)
+}
+
+/// Checks that amount of tours active at the same time does not exceed vehicle pool size.
+fn check_vehicle_pool(context: &CheckerContext) -> Result<(), String> {
+    let size = match context.problem.config.as_ref().and_then(|config| config.vehicle_pool) {
+        Some(size) => size,
+        None => return Ok(()),
+    };
+
+    let mut events = context
+        .solution
+        .tours
+        .iter()
+        .filter_map(|tour| {
+            let start = parse_time(&tour.stops.first()?.time.departure);
+            let end = parse_time(&tour.stops.last()?.time.arrival);
+
+            Some(vec![(start, 1), (end, -1)])
+        })
+        .flatten()
+        .collect::<Vec<(f64, i32)>>();
+
+    // NOTE tour which ends at the same time as other starts is not considered as overlapping
+    events.sort_by(|(a_time, a_delta), (b_time, b_delta)| compare_floats(*a_time, *b_time).then(a_delta.cmp(b_delta)));
+
+    let max_active = events
+        .iter()
+        .scan(0, |active, (_, delta)| {
+            *active += delta;
+            Some(*active)
+        })
+        .max()
+        .unwrap_or(0) as usize;
+
+    if max_active > size {
+        Err(format!("vehicle pool violation, expected: not more than {} active tours, got: {}", size, max_active))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns time when job activity's service starts: it is departure time minus service duration
+/// and vehicle maneuvering time.
+pub(crate) fn get_service_start(
+    context: &CheckerContext,
+    tour: &Tour,
+    stop: &Stop,
+    activity: &Activity,
+) -> Result<f64, String> {
+    let time = context.get_activity_time(stop, activity);
+    let location = context.get_activity_location(stop, activity);
+    let activity_type = context.get_activity_type(tour, stop, activity)?;
+
+    let duration = context.visit_job(
+        activity,
+        &activity_type,
+        |_, task| task.places.iter().find(|place| place.location == location).map_or(0., |place| place.duration),
+        || 0.,
+    )?;
+    let maneuvering = context.get_vehicle(&tour.vehicle_id)?.maneuvering_time.unwrap_or(0.);
+
+    Ok((time.end - duration - maneuvering).max(time.start))
+}
+
+/// Checks whether job activity's service starts after the end of its time window extended by grace
+/// period. NOTE only absolute time windows are considered.
+fn is_late_activity(context: &CheckerContext, tour: &Tour, stop: &Stop, activity: &Activity) -> Result<bool, String> {
+    let service_start = get_service_start(context, tour, stop, activity)?;
+    let location = context.get_activity_location(stop, activity);
+    let activity_type = context.get_activity_type(tour, stop, activity)?;
+
+    context.visit_job(
+        activity,
+        &activity_type,
+        |_, task| {
+            task.places
+                .iter()
+                .find(|place| place.location == location)
+                .and_then(|place| place.times.as_ref().map(|times| (times, place.grace.unwrap_or(0.))))
+                .map_or(false, |(times, grace)| {
+                    let started = times
+                        .iter()
+                        .map(|tw| parse_time_window(tw))
+                        .filter(|tw| tw.start <= service_start)
+                        .collect::<Vec<_>>();
+
+                    !started.is_empty() && started.iter().all(|tw| service_start > tw.end + grace)
+                })
+        },
+        || false,
+    )
+}
+
+fn has_time_windows(job: &Job) -> bool {
+    [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+        .iter()
+        .filter_map(|tasks| tasks.as_ref())
+        .flat_map(|tasks| tasks.iter())
+        .flat_map(|task| task.places.iter())
+        .any(|place| {
+            place.times.as_ref().map_or(false, |times| !times.is_empty())
+                || place.relative_times.is_some()
+                || place.opening_times.is_some()
+        })
 }
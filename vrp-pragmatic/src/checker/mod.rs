@@ -3,15 +3,16 @@
 
 use crate::format::problem::*;
 use crate::format::solution::*;
-use crate::format::Location;
+use crate::format::{get_shift_anchors, Location};
 use crate::parse_time;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use vrp_core::models::common::TimeWindow;
 use vrp_core::models::Problem as CoreProblem;
 
 /// Stores problem and solution together and provides some helper methods.
 pub struct CheckerContext {
-    /// An original problem definition.
+    /// An original problem definition with expanded planning horizon.
     pub problem: Problem,
     /// Routing matrices.
     pub matrices: Option<Vec<Matrix>>,
@@ -29,6 +30,7 @@ enum ActivityType {
     Depot(VehicleCargoPlace),
     Break(VehicleBreak),
     Reload(VehicleCargoPlace),
+    Anchor,
 }
 
 impl CheckerContext {
@@ -39,6 +41,7 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Self {
+        let problem = expand_horizon(problem);
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
 
         Self { problem, matrices, solution, job_map, core_problem }
@@ -47,8 +50,12 @@ impl CheckerContext {
     /// Performs solution check.
     pub fn check(&self) -> Result<(), String> {
         check_vehicle_load(&self)?;
+        check_depot_inventory(self)?;
+        check_aggregate_capacity(self)?;
+        check_capacity_decay(self)?;
         check_relations(&self)?;
         check_breaks(&self)?;
+        check_anchors(self)?;
         check_assignment(&self)?;
         check_routing(&self)?;
         check_limits(&self)?;
@@ -155,6 +162,10 @@ impl CheckerContext {
                 .and_then(|depot| depot.iter().find(|d| d.location == location))
                 .map(|d| ActivityType::Depot(d.clone()))
                 .ok_or_else(|| format!("Cannot find depot for tour '{}'", tour.vehicle_id)),
+            "anchor" => get_shift_anchors(&self.problem, &shift)
+                .and_then(|anchors| anchors.iter().find(|a| a.location == location))
+                .map(|_| ActivityType::Anchor)
+                .ok_or_else(|| format!("Cannot find anchor for tour '{}'", tour.vehicle_id)),
             _ => Err(format!("Unknown activity type: '{}'", activity.activity_type)),
         }
     }
@@ -191,7 +202,8 @@ impl CheckerContext {
                         tasks.iter().find(|task| task.tag == activity.job_tag)
                     })
                 }
-                .map(|task| job_visitor(job, task))
+                .and_then(|task| get_task_variant(task, activity))
+                .map(|task| job_visitor(job, &task))
             }
             .ok_or_else(|| "Cannot match activity to job place".to_string()),
             _ => Ok(other_visitor()),
@@ -219,6 +231,28 @@ fn match_job_task<'a>(
     tasks.and_then(|tasks| tasks_fn(tasks))
 }
 
+/// Returns the task itself or its variant used by activity, if task has variants.
+fn get_task_variant<'a>(task: &'a JobTask, activity: &Activity) -> Option<Cow<'a, JobTask>> {
+    match task.variants.as_ref() {
+        Some(variants) if !variants.is_empty() => {
+            variants.iter().find(|variant| Some(&variant.tag) == activity.job_tag.as_ref()).map(|variant| {
+                Cow::Owned(JobTask {
+                    places: task
+                        .places
+                        .iter()
+                        .map(|place| JobPlace { duration: variant.duration.unwrap_or(place.duration), ..place.clone() })
+                        .collect(),
+                    demand: variant.demand.clone(),
+                    tag: Some(variant.tag.clone()),
+                    variants: None,
+                    ..task.clone()
+                })
+            })
+        }
+        _ => Some(Cow::Borrowed(task)),
+    }
+}
+
 fn parse_time_window(tw: &[String]) -> TimeWindow {
     TimeWindow::new(parse_time(tw.first().unwrap()), parse_time(tw.last().unwrap()))
 }
@@ -236,11 +270,16 @@ fn get_location(stop: &Stop, activity: &Activity) -> Location {
     activity.location.as_ref().unwrap_or_else(|| &stop.location).clone()
 }
 
+mod anchors;
+use crate::checker::anchors::check_anchors;
+
 mod assignment;
 use crate::checker::assignment::check_assignment;
 
 mod capacity;
-use crate::checker::capacity::check_vehicle_load;
+use crate::checker::capacity::{
+    check_aggregate_capacity, check_capacity_decay, check_depot_inventory, check_vehicle_load,
+};
 
 mod limits;
 use crate::checker::limits::check_limits;
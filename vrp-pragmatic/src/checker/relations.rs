@@ -12,7 +12,14 @@ pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
     (0_usize..)
         .zip(context.problem.plan.relations.as_ref().map_or(vec![].iter(), |relations| relations.iter()))
         .try_for_each(|(idx, relation)| {
-            let tour = get_tour_by_vehicle_id(&relation.vehicle_id, relation.shift_index, &context.solution);
+            // NOTE soft precedence can be violated
+            let vehicle_id = match (&relation.type_field, relation.vehicle_id.as_ref()) {
+                (RelationType::SoftPrecedence, _) => return Ok(()),
+                (_, Some(vehicle_id)) => vehicle_id,
+                (_, None) => return Err(format!("Relation {} has no vehicle id", idx)),
+            };
+
+            let tour = get_tour_by_vehicle_id(vehicle_id, relation.shift_index, &context.solution);
             // NOTE tour can be absent for tour relation
             let tour = if let Ok(tour) = tour {
                 tour
@@ -67,6 +74,7 @@ pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
                         Ok(())
                     }
                 }
+                RelationType::SoftPrecedence => unreachable!(),
                 RelationType::Any => {
                     let has_wrong_assignment = context
                         .solution
@@ -37,8 +37,17 @@ pub fn check_routing(context: &CheckerContext) -> Result<(), String> {
                 let to_idx = get_location_index(&to.location, &coord_index)?;
                 let matrix_idx = from_idx * matrix_size + to_idx;
 
-                let distance = get_matrix_value(matrix_idx, &matrix.distances)?;
-                let duration = get_matrix_value(matrix_idx, &matrix.travel_times)?;
+                if is_unreachable(matrix, matrix_idx) {
+                    return Err(format!(
+                        "unreachable leg is used to get {} stop in the tour: {}",
+                        leg_idx + 1,
+                        tour.vehicle_id
+                    ));
+                }
+
+                let distance = get_matrix_value(matrix_idx, &matrix.distances, matrix.default_distance)?;
+                let duration = get_matrix_value(matrix_idx, &matrix.travel_times, matrix.default_travel_time)?
+                    * matrix.time_unit.map_or(1., |unit| unit.as_seconds()) as i64;
 
                 let time = time + duration;
                 let total_distance = total_distance + distance;
@@ -120,10 +129,23 @@ fn get_matrix_size(matrices: &[Matrix]) -> usize {
     (matrices.first().unwrap().travel_times.len() as f64).sqrt().round() as usize
 }
 
-fn get_matrix_value(idx: usize, matrix_values: &[i64]) -> Result<i64, String> {
+fn is_unreachable(matrix: &Matrix, idx: usize) -> bool {
+    let has_value = |values: &Vec<i64>, value: i64| values.get(idx).map_or(false, |cell| *cell == value);
+
+    matrix.error_codes.as_ref().and_then(|codes| codes.get(idx)).map_or(false, |code| *code > 0)
+        || matrix
+            .unreachable_value
+            .map_or(false, |value| has_value(&matrix.travel_times, value) || has_value(&matrix.distances, value))
+}
+
+fn get_matrix_value(idx: usize, matrix_values: &[i64], default: Option<i64>) -> Result<i64, String> {
     matrix_values
         .get(idx)
         .cloned()
+        .map(|value| match default {
+            Some(default) if value < 0 => default,
+            _ => value,
+        })
         .ok_or_else(|| format!("attempt to get value out of bounds: {} vs {}", idx, matrix_values.len()))
 }
 
@@ -0,0 +1,140 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Demand, DemandDimension, Load, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Activity;
+
+/// A function which returns weighted sum of load dimensions.
+pub type WeightedLoadFunc<T> = Arc<dyn Fn(&T, &[f64]) -> f64 + Send + Sync>;
+
+/// A module which limits weighted sum of all load dimensions by a shared vehicle cap, e.g. total
+/// volume of separate compartments. Weights and the cap are taken from `aggregate_capacity` vehicle
+/// dimension, vehicles without it are not restricted.
+///
+/// The module relies on load states calculated by capacity module, so it has to be added after it.
+pub struct AggregateCapacityModule<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    weighted_load: WeightedLoadFunc<T>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> AggregateCapacityModule<T> {
+    /// Creates a new instance of `AggregateCapacityModule`.
+    pub fn new(code: i32, weighted_load: WeightedLoadFunc<T>) -> Self {
+        Self {
+            weighted_load: weighted_load.clone(),
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(AggregateCapacityHardActivityConstraint {
+                code,
+                weighted_load,
+                phantom: PhantomData,
+            }))],
+            keys: vec![CURRENT_AGGREGATE_LOAD_KEY, MAX_FUTURE_AGGREGATE_LOAD_KEY, MAX_PAST_AGGREGATE_LOAD_KEY],
+        }
+    }
+
+    fn recalculate_states(&self, ctx: &mut RouteContext) {
+        let weights = if let Some((weights, _)) = get_aggregate_capacity(ctx) {
+            weights.clone()
+        } else {
+            return;
+        };
+
+        let (route, state) = ctx.as_mut();
+        let intervals = state
+            .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+            .cloned()
+            .unwrap_or_else(|| vec![(0, route.tour.total() - 1)]);
+
+        intervals.into_iter().for_each(|(start_idx, end_idx)| {
+            let activities = route.tour.activities_slice(start_idx, end_idx);
+
+            activities.iter().fold(0., |max: f64, activity| {
+                let current = state
+                    .get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity)
+                    .map_or(0., |load| (self.weighted_load)(load, weights.as_slice()));
+                let max = max.max(current);
+
+                state.put_activity_state(CURRENT_AGGREGATE_LOAD_KEY, activity, current);
+                state.put_activity_state(MAX_PAST_AGGREGATE_LOAD_KEY, activity, max);
+
+                max
+            });
+
+            activities.iter().rev().fold(0., |max: f64, activity| {
+                let max = max.max(*state.get_activity_state::<f64>(CURRENT_AGGREGATE_LOAD_KEY, activity).unwrap());
+                state.put_activity_state(MAX_FUTURE_AGGREGATE_LOAD_KEY, activity, max);
+
+                max
+            });
+        });
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> ConstraintModule for AggregateCapacityModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.recalculate_states(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.recalculate_states(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct AggregateCapacityHardActivityConstraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    code: i32,
+    weighted_load: WeightedLoadFunc<T>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> HardActivityConstraint
+    for AggregateCapacityHardActivityConstraint<T>
+{
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let (weights, limit) = get_aggregate_capacity(route_ctx)?;
+        let demand: &Demand<T> = activity_ctx.target.job.as_ref().and_then(|job| job.dimens.get_demand())?;
+
+        let weighted = |load: &T| (self.weighted_load)(load, weights.as_slice());
+        let get_state = |key: i32, activity: &Activity| {
+            route_ctx.state.get_activity_state::<f64>(key, activity).cloned().unwrap_or(0.)
+        };
+        let fits = |value: f64| value <= *limit;
+
+        let pivot = activity_ctx.prev;
+        let change = weighted(&demand.change());
+
+        // NOTE the same logic as in capacity module, but applied to weighted aggregate of load
+        let is_violated = !fits(get_state(MAX_PAST_AGGREGATE_LOAD_KEY, pivot) + weighted(&demand.delivery.0))
+            || !fits(get_state(MAX_FUTURE_AGGREGATE_LOAD_KEY, pivot) + change)
+            || !fits(get_state(CURRENT_AGGREGATE_LOAD_KEY, pivot) + change);
+
+        if is_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn get_aggregate_capacity(route_ctx: &RouteContext) -> Option<&(Vec<f64>, f64)> {
+    route_ctx.route.actor.vehicle.dimens.get_value::<(Vec<f64>, f64)>("aggregate_capacity")
+}
@@ -0,0 +1,60 @@
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::problem::{Actor, Fleet, Job};
+
+/// A module which allows vehicle to start its shift from one of alternative locations. Each
+/// alternative is a separate actor of the same vehicle, so the module keeps only one of them in use:
+/// once a route is created for one actor, the others are removed from the list of available actors.
+pub struct AlternativeStartsModule {
+    groups: Vec<Vec<Arc<Actor>>>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl AlternativeStartsModule {
+    /// Creates a new instance of `AlternativeStartsModule`.
+    pub fn new(fleet: &Fleet) -> Self {
+        let groups = fleet
+            .vehicles
+            .iter()
+            .filter(|vehicle| vehicle.details.len() > 1)
+            .map(|vehicle| {
+                fleet.actors.iter().filter(|actor| Arc::ptr_eq(&actor.vehicle, vehicle)).cloned().collect::<Vec<_>>()
+            })
+            .collect();
+
+        Self { groups, constraints: vec![], keys: vec![] }
+    }
+}
+
+impl ConstraintModule for AlternativeStartsModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.groups.iter().for_each(|actors| {
+            let used = actors.iter().find(|actor| ctx.routes.iter().any(|route_ctx| route_ctx.route.actor == **actor));
+
+            actors.iter().filter(|actor| Some(*actor) != used).for_each(|actor| {
+                if used.is_some() {
+                    ctx.registry.use_actor(actor);
+                } else {
+                    ctx.registry.free_actor(actor);
+                }
+            });
+        });
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
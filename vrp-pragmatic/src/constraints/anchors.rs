@@ -0,0 +1,161 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/anchors_test.rs"]
+mod anchors_test;
+
+use crate::constraints::*;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::{Job, Single};
+use vrp_core::models::solution::Route;
+
+/// A module which requires every non empty route to visit one of vehicle anchors, e.g. a weigh
+/// station. An anchor is a conditional job of vehicle shift which becomes required once the route
+/// serves any other job and is ignored otherwise, so empty routes have no anchor visit.
+pub struct AnchorModule {
+    conditional: ConditionalJobModule,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl AnchorModule {
+    /// Creates a new instance of `AnchorModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            conditional: ConditionalJobModule::new(create_job_transition()),
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(AnchorHardRouteConstraint { code })),
+                ConstraintVariant::SoftRoute(Arc::new(AnchorSoftRouteConstraint {})),
+            ],
+        }
+    }
+}
+
+impl ConstraintModule for AnchorModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        remove_idle_anchors(ctx);
+        self.conditional.accept_solution_state(ctx);
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.conditional.state_keys()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Locks anchor jobs to specific vehicles.
+struct AnchorHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for AnchorHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        match job.as_single() {
+            Some(single) if is_anchor_job(single) && !is_anchor_vehicle(&ctx.route, single) => {
+                Some(RouteConstraintViolation { code: self.code })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Makes anchor insertion the most preferable one.
+struct AnchorSoftRouteConstraint {}
+
+impl SoftRouteConstraint for AnchorSoftRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, _: &RouteContext, job: &Job) -> f64 {
+        match job.as_single() {
+            Some(single) if is_anchor_job(single) => -solution_ctx.get_max_cost(),
+            _ => 0.,
+        }
+    }
+}
+
+/// Promotes anchor jobs of active routes from ignored and removes others from required.
+fn create_job_transition() -> Box<dyn JobContextTransition + Send + Sync> {
+    Box::new(ConcreteJobContextTransition {
+        remove_required: |ctx, job| !is_required_job(ctx, job, true),
+        promote_required: |ctx, job| is_required_job(ctx, job, false),
+        remove_locked: |_, _| false,
+        promote_locked: |_, _| false,
+    })
+}
+
+/// Anchor is required only if route of its vehicle serves other jobs.
+fn is_required_job(ctx: &SolutionContext, job: &Job, default: bool) -> bool {
+    match job.as_single() {
+        Some(single) if is_anchor_job(single) => {
+            ctx.routes.iter().any(|rc| is_anchor_vehicle(&rc.route, single) && is_active_route(&rc.route))
+        }
+        _ => default,
+    }
+}
+
+/// Removes anchors from routes without other jobs, e.g. after ruin, and from unassigned jobs.
+fn remove_idle_anchors(ctx: &mut SolutionContext) {
+    let locked = &ctx.locked;
+    let anchors = ctx
+        .routes
+        .iter_mut()
+        .filter(|rc| !is_active_route(&rc.route))
+        .flat_map(|rc| {
+            let anchors = rc
+                .route
+                .tour
+                .jobs()
+                .filter(|job| job.as_single().map_or(false, is_anchor_job))
+                .filter(|job| !locked.contains(job))
+                .collect::<Vec<_>>();
+
+            anchors.iter().for_each(|job| {
+                rc.route_mut().tour.remove(job);
+            });
+
+            anchors.into_iter()
+        })
+        .collect::<Vec<_>>();
+
+    ctx.ignored.extend(anchors);
+
+    let routes = &ctx.routes;
+    let unassigned = ctx
+        .unassigned
+        .keys()
+        .filter(|job| {
+            job.as_single().map_or(false, |single| {
+                is_anchor_job(single)
+                    && !routes.iter().any(|rc| is_anchor_vehicle(&rc.route, single) && is_active_route(&rc.route))
+            })
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    unassigned.iter().for_each(|job| {
+        ctx.unassigned.remove(job);
+    });
+    ctx.ignored.extend(unassigned);
+}
+
+fn is_anchor_job(job: &Arc<Single>) -> bool {
+    job.dimens.get_value::<String>("type").map_or(false, |t| t == "anchor")
+}
+
+fn is_anchor_vehicle(route: &Route, anchor: &Arc<Single>) -> bool {
+    get_vehicle_id_from_job(anchor)
+        .map_or(false, |vehicle_id| is_correct_vehicle(route, vehicle_id, get_shift_index(&anchor.dimens)))
+}
+
+/// Route is active when it serves at least one job which is not a vehicle specific one.
+fn is_active_route(route: &Route) -> bool {
+    route.tour.jobs().any(|job| job.dimens().get_value::<String>("vehicle_id").is_none())
+}
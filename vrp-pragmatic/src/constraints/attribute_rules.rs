@@ -0,0 +1,373 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/attribute_rules_test.rs"]
+mod attribute_rules_test;
+
+use std::collections::HashSet;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::{Dimensions, ValueDimension};
+use vrp_core::models::problem::Job;
+
+/// A module which allows to assign job only to vehicle when all attribute rules hold for them.
+/// Each rule is a boolean expression over job and vehicle dimensions, e.g.
+/// `!(job.tag == 'fragile' && vehicle.type_id == 'construction')`.
+pub struct AttributeRulesModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl AttributeRulesModule {
+    /// Creates a new instance of `AttributeRulesModule`.
+    pub fn new(rules: Vec<AttributeRule>, code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(AttributeRulesHardRouteConstraint {
+                rules,
+                code,
+            }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for AttributeRulesModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct AttributeRulesHardRouteConstraint {
+    rules: Vec<AttributeRule>,
+    code: i32,
+}
+
+impl HardRouteConstraint for AttributeRulesHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let vehicle = &ctx.route.actor.vehicle.dimens;
+
+        // NOTE task attributes, such as tag, are taken from sub jobs, job attributes from the job itself
+        let is_valid = match job {
+            Job::Single(single) if single.dimens.get_value::<String>("vehicle_id").is_some() => true,
+            Job::Single(single) => self.rules.iter().all(|rule| rule.evaluate(&[&single.dimens], vehicle)),
+            Job::Multi(multi) => multi
+                .jobs
+                .iter()
+                .all(|single| self.rules.iter().all(|rule| rule.evaluate(&[&single.dimens, &multi.dimens], vehicle))),
+        };
+
+        if is_valid {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+/// A boolean expression over job and vehicle attributes which should hold for each assignment.
+///
+/// Attributes are referenced as `job.<name>` and `vehicle.<name>` where name is a dimension key,
+/// e.g. `job.tag`, `job.priority`, `vehicle.type_id` or `vehicle.skills`. Supported literals are
+/// strings in single or double quotes, numbers, `true` and `false`. Attributes are compared using
+/// `==`, `!=`, `<`, `<=`, `>`, `>=`, set attributes are checked with `has`, e.g.
+/// `vehicle.skills has 'fridge'`. Comparisons are combined using `!`, `&&`, `||` and parentheses.
+/// A missing attribute is not equal to any value.
+pub struct AttributeRule {
+    expr: Expr,
+}
+
+impl AttributeRule {
+    /// Parses rule from expression.
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let tokens = tokenize(expression)?;
+        let mut parser = Parser { tokens, position: 0 };
+
+        let expr = parser.parse_or()?;
+
+        match parser.peek() {
+            Some(token) => Err(format!("unexpected token '{}'", token)),
+            None => Ok(Self { expr }),
+        }
+    }
+
+    /// Checks whether the rule holds for job and vehicle attributes. Job attributes are searched
+    /// in given dimensions in their order.
+    pub fn evaluate(&self, job: &[&Dimensions], vehicle: &Dimensions) -> bool {
+        self.expr.evaluate(job, vehicle)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Num(f64),
+    Op(&'static str),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Ident(value) | Token::Str(value) => write!(f, "{}", value),
+            Token::Num(value) => write!(f, "{}", value),
+            Token::Op(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+enum Operand {
+    Job(String),
+    Vehicle(String),
+    Literal(Literal),
+}
+
+enum Literal {
+    Str(String),
+    Num(f64),
+    Bool(bool),
+}
+
+enum Expr {
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare(&'static str, Operand, Operand),
+    Value(Operand),
+}
+
+enum Value<'a> {
+    Missing,
+    Str(&'a str),
+    Num(f64),
+    Bool(bool),
+    Set(&'a HashSet<String>),
+}
+
+impl Expr {
+    fn evaluate(&self, job: &[&Dimensions], vehicle: &Dimensions) -> bool {
+        match self {
+            Expr::Not(expr) => !expr.evaluate(job, vehicle),
+            Expr::And(left, right) => left.evaluate(job, vehicle) && right.evaluate(job, vehicle),
+            Expr::Or(left, right) => left.evaluate(job, vehicle) || right.evaluate(job, vehicle),
+            Expr::Compare(op, left, right) => {
+                compare(op, get_value(left, job, vehicle), get_value(right, job, vehicle))
+            }
+            Expr::Value(operand) => match get_value(operand, job, vehicle) {
+                Value::Missing => false,
+                Value::Bool(value) => value,
+                _ => true,
+            },
+        }
+    }
+}
+
+fn get_value<'a>(operand: &'a Operand, job: &[&'a Dimensions], vehicle: &'a Dimensions) -> Value<'a> {
+    match operand {
+        Operand::Job(name) => job.iter().map(|dimens| get_dimension(dimens, name)).find(|value| !value.is_missing()),
+        Operand::Vehicle(name) => Some(get_dimension(vehicle, name)),
+        Operand::Literal(Literal::Str(value)) => Some(Value::Str(value.as_str())),
+        Operand::Literal(Literal::Num(value)) => Some(Value::Num(*value)),
+        Operand::Literal(Literal::Bool(value)) => Some(Value::Bool(*value)),
+    }
+    .unwrap_or(Value::Missing)
+}
+
+fn get_dimension<'a>(dimens: &'a Dimensions, name: &str) -> Value<'a> {
+    let value = match dimens.get(name) {
+        Some(value) => value,
+        None => return Value::Missing,
+    };
+
+    if let Some(value) = value.downcast_ref::<String>() {
+        Value::Str(value.as_str())
+    } else if let Some(value) = value.downcast_ref::<f64>() {
+        Value::Num(*value)
+    } else if let Some(value) = value.downcast_ref::<i32>() {
+        Value::Num(*value as f64)
+    } else if let Some(value) = value.downcast_ref::<usize>() {
+        Value::Num(*value as f64)
+    } else if let Some(value) = value.downcast_ref::<bool>() {
+        Value::Bool(*value)
+    } else if let Some(value) = value.downcast_ref::<HashSet<String>>() {
+        Value::Set(value)
+    } else {
+        Value::Missing
+    }
+}
+
+impl<'a> Value<'a> {
+    fn is_missing(&self) -> bool {
+        matches!(self, Value::Missing)
+    }
+}
+
+fn compare(op: &str, left: Value, right: Value) -> bool {
+    match (op, left, right) {
+        ("has", Value::Set(set), Value::Str(value)) => set.contains(value),
+        ("==", left, right) => is_equal(&left, &right),
+        ("!=", left, right) => !is_equal(&left, &right),
+        (op, Value::Num(left), Value::Num(right)) => match op {
+            "<" => left < right,
+            "<=" => left <= right,
+            ">" => left > right,
+            ">=" => left >= right,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn is_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Str(left), Value::Str(right)) => left == right,
+        (Value::Num(left), Value::Num(right)) => left == right,
+        (Value::Bool(left), Value::Bool(right)) => left == right,
+        _ => false,
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    const OPERATORS: &[&str] = &["==", "!=", "<=", ">=", "&&", "||", "<", ">", "!", "(", ")"];
+
+    let chars = expression.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let ch = chars[idx];
+        let rest = chars[idx..].iter().collect::<String>();
+
+        if ch.is_whitespace() {
+            idx += 1;
+        } else if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(*op)) {
+            tokens.push(Token::Op(op));
+            idx += op.len();
+        } else if ch == '\'' || ch == '"' {
+            let end = chars[idx + 1..]
+                .iter()
+                .position(|other| *other == ch)
+                .ok_or_else(|| "unterminated string literal".to_string())?;
+            tokens.push(Token::Str(chars[idx + 1..idx + 1 + end].iter().collect()));
+            idx += end + 2;
+        } else if ch.is_ascii_digit() || (ch == '-' && chars.get(idx + 1).map_or(false, |next| next.is_ascii_digit())) {
+            let len = 1 + chars[idx + 1..].iter().take_while(|other| other.is_ascii_digit() || **other == '.').count();
+            let value = chars[idx..idx + len].iter().collect::<String>();
+            tokens.push(Token::Num(value.parse::<f64>().map_err(|_| format!("invalid number '{}'", value))?));
+            idx += len;
+        } else if ch.is_alphabetic() || ch == '_' {
+            let len = chars[idx..]
+                .iter()
+                .take_while(|other| other.is_alphanumeric() || **other == '_' || **other == '.')
+                .count();
+            tokens.push(Token::Ident(chars[idx..idx + len].iter().collect()));
+            idx += len;
+        } else {
+            return Err(format!("unexpected character '{}'", ch));
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<Token, String> {
+        let token =
+            self.tokens.get(self.position).cloned().ok_or_else(|| "unexpected end of expression".to_string())?;
+        self.position += 1;
+
+        Ok(token)
+    }
+
+    fn is_next(&mut self, expected: &Token) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, String> {
+        let mut expr = self.parse_and()?;
+
+        while self.is_next(&Token::Op("||")) {
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, String> {
+        let mut expr = self.parse_not()?;
+
+        while self.is_next(&Token::Op("&&")) {
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_not()?));
+        }
+
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, String> {
+        if self.is_next(&Token::Op("!")) {
+            Ok(Expr::Not(Box::new(self.parse_not()?)))
+        } else if self.is_next(&Token::Op("(")) {
+            let expr = self.parse_or()?;
+
+            match self.next()? {
+                Token::Op(")") => Ok(expr),
+                token => Err(format!("expected ')', got '{}'", token)),
+            }
+        } else {
+            self.parse_compare()
+        }
+    }
+
+    fn parse_compare(&mut self) -> Result<Expr, String> {
+        let left = self.parse_operand()?;
+
+        let op = match self.peek() {
+            Some(Token::Op(op)) if matches!(*op, "==" | "!=" | "<" | "<=" | ">" | ">=") => *op,
+            Some(Token::Ident(ident)) if ident == "has" => "has",
+            _ => return Ok(Expr::Value(left)),
+        };
+        self.position += 1;
+
+        Ok(Expr::Compare(op, left, self.parse_operand()?))
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, String> {
+        match self.next()? {
+            Token::Str(value) => Ok(Operand::Literal(Literal::Str(value))),
+            Token::Num(value) => Ok(Operand::Literal(Literal::Num(value))),
+            Token::Ident(ident) => match ident.as_str() {
+                "true" => Ok(Operand::Literal(Literal::Bool(true))),
+                "false" => Ok(Operand::Literal(Literal::Bool(false))),
+                _ => match ident.split_once('.') {
+                    Some(("job", name)) if !name.is_empty() => Ok(Operand::Job(name.to_string())),
+                    Some(("vehicle", name)) if !name.is_empty() => Ok(Operand::Vehicle(name.to_string())),
+                    _ => Err(format!("unknown identifier '{}'", ident)),
+                },
+            },
+            token => Err(format!("unexpected token '{}'", token)),
+        }
+    }
+}
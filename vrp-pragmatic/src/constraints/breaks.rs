@@ -8,8 +8,8 @@ use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use vrp_core::models::common::{Schedule, TimeWindow, ValueDimension};
-use vrp_core::models::problem::{Job, Single};
+use vrp_core::models::common::{Cost, Schedule, TimeWindow, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, Single, TransportCost};
 use vrp_core::models::solution::Activity;
 
 /// Implements break functionality with variable location and time.
@@ -20,13 +20,18 @@ pub struct BreakModule {
 }
 
 impl BreakModule {
-    pub fn new(code: i32) -> Self {
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+    ) -> Self {
         Self {
             conditional: ConditionalJobModule::new(create_job_transition()),
             constraints: vec![
                 ConstraintVariant::HardRoute(Arc::new(BreakHardRouteConstraint { code })),
                 ConstraintVariant::HardActivity(Arc::new(BreakHardActivityConstraint { code })),
                 ConstraintVariant::SoftRoute(Arc::new(BreakSoftRouteConstraint {})),
+                ConstraintVariant::SoftActivity(Arc::new(BreakSoftActivityConstraint { activity, transport })),
             ],
         }
     }
@@ -125,6 +130,37 @@ impl SoftRouteConstraint for BreakSoftRouteConstraint {
     }
 }
 
+/// Prefers break placement which does not delay the next activity, e.g. during its waiting time.
+struct BreakSoftActivityConstraint {
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SoftActivityConstraint for BreakSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        match (as_break_job(activity_ctx.target), activity_ctx.next) {
+            (Some(_), Some(next)) => {
+                let actor = route_ctx.route.actor.as_ref();
+                let profile = actor.vehicle.profile;
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+                let departure = prev.schedule.departure;
+                let arrival =
+                    departure + self.transport.duration(profile, prev.place.location, target.place.location, departure);
+                let departure = arrival.max(target.place.time.start) + self.activity.duration(actor, target, arrival);
+                let arrival =
+                    departure + self.transport.duration(profile, target.place.location, next.place.location, departure);
+                let departure = arrival.max(next.place.time.start) + self.activity.duration(actor, next, arrival);
+
+                let delay = (departure - next.schedule.departure).max(0.);
+
+                delay * (actor.vehicle.costs.per_driving_time + actor.driver.costs.per_driving_time)
+            }
+            _ => 0.,
+        }
+    }
+}
+
 /// Promotes break jobs from required and ignored.
 fn create_job_transition() -> Box<dyn JobContextTransition + Send + Sync> {
     Box::new(ConcreteJobContextTransition {
@@ -155,17 +191,21 @@ fn is_required_job(ctx: &SolutionContext, job: &Job, default: bool) -> bool {
 
 /// Removes breaks which conditions are violated after ruin:
 /// * break without location served separately when original job is removed, but break is kept.
+///   Such break is served at location of previous or next activity.
 /// * break is defined by interval, but its time is violated. This might happen due to departure time rescheduling.
 fn remove_invalid_breaks(ctx: &mut SolutionContext) {
     let breaks_to_remove = ctx
         .routes
         .iter()
         .flat_map(|rc| {
-            rc.route
-                .tour
-                .all_activities()
-                .fold((0, HashSet::new()), |(prev, mut breaks), activity| {
+            let activities = rc.route.tour.all_activities().collect::<Vec<_>>();
+
+            activities
+                .iter()
+                .enumerate()
+                .fold((0, HashSet::new()), |(prev, mut breaks), (idx, activity)| {
                     let current = activity.place.location;
+                    let next = activities.get(idx + 1).map(|next| next.place.location);
 
                     if let Some(break_single) = as_break_job(activity) {
                         let break_job = Job::Single(break_single.clone());
@@ -176,8 +216,9 @@ fn remove_invalid_breaks(ctx: &mut SolutionContext) {
                             let location_count = break_single.places.iter().filter(|p| p.location.is_some()).count();
                             assert!(location_count == 0 || location_count == break_single.places.len());
 
-                            let is_orphan =
-                                prev != current && break_single.places.first().and_then(|p| p.location).is_none();
+                            let is_orphan = prev != current
+                                && next != Some(current)
+                                && break_single.places.first().and_then(|p| p.location).is_none();
                             let is_not_on_time = !is_on_proper_time(rc, break_single, &activity.schedule);
 
                             if is_orphan || is_not_on_time {
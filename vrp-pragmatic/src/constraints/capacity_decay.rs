@@ -0,0 +1,182 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/capacity_decay_test.rs"]
+mod capacity_decay_test;
+
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Sub};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{CapacityDimension, Demand, DemandDimension, Duration, Load, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost};
+use vrp_core::models::solution::Activity;
+
+/// Specifies how usable vehicle capacity decays with time passed since tour departure, e.g. when
+/// perishable goods melt.
+#[derive(Clone, Debug)]
+pub enum CapacityDecay {
+    /// Capacity decreases linearly from full at departure and is fully lost after given duration.
+    Linear {
+        /// A duration after which no capacity is left.
+        duration: Duration,
+    },
+}
+
+impl CapacityDecay {
+    /// Returns a share of capacity which is still usable after given time since departure.
+    pub fn get_share(&self, elapsed: Duration) -> f64 {
+        match self {
+            CapacityDecay::Linear { duration } => (1. - elapsed / duration).clamp(0., 1.),
+        }
+    }
+}
+
+/// A module which enforces time dependent vehicle capacity: load carried on each route leg should
+/// fit capacity left at the leg's arrival time. Decay model is taken from `capacity_decay` vehicle
+/// dimension and vehicles without it are not affected.
+///
+/// The module relies on load states calculated by capacity module, so it has to be added after it.
+pub struct CapacityDecayModule<T: Load + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + 'static> {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + 'static> CapacityDecayModule<T> {
+    /// Creates a new instance of `CapacityDecayModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+    ) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(CapacityDecayHardActivityConstraint::<T> {
+                code,
+                activity,
+                transport,
+                phantom: PhantomData,
+            }))],
+            keys: vec![],
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + 'static> ConstraintModule
+    for CapacityDecayModule<T>
+{
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct CapacityDecayHardActivityConstraint<T: Load + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + 'static>
+{
+    code: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + 'static>
+    CapacityDecayHardActivityConstraint<T>
+{
+    /// Checks whether load carried on any leg exceeds decayed capacity as if target activity is
+    /// inserted. Schedule is recalculated after insertion point as inserted activity delays all
+    /// next ones.
+    fn has_violation(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext, decay: &CapacityDecay) -> bool {
+        let actor = route_ctx.route.actor.as_ref();
+        let tour = &route_ctx.route.tour;
+        let capacity: Option<&T> = actor.vehicle.dimens.get_capacity();
+        let (capacity, departure) = match (capacity, tour.start()) {
+            (Some(capacity), Some(start)) => (*capacity, start.schedule.departure),
+            _ => return false,
+        };
+        let fits = |load: &T, arrival: Timestamp| (capacity * decay.get_share(arrival - departure)).can_fit(load);
+
+        let (prev, target, index) = (activity_ctx.prev, activity_ctx.target, activity_ctx.index);
+        let demand: Option<&Demand<T>> = target.job.as_ref().and_then(|job| job.dimens.get_demand());
+        let (delivery, pickup, change) =
+            demand.map_or((T::default(), T::default(), T::default()), |d| (d.delivery.0, d.pickup.0, d.change()));
+
+        let total = tour.total();
+        let (start_idx, end_idx) = route_ctx
+            .state
+            .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+            .and_then(|intervals| intervals.iter().find(|(start, end)| *start <= index && index <= *end).cloned())
+            .unwrap_or((0, total.max(1) - 1));
+
+        // NOTE static delivery is carried from the interval start, static pickup till the interval end,
+        // dynamic demand affects only the next leg as the counterpart activity is not known here
+        let has_past_violation = delivery.is_not_empty()
+            && (start_idx..index)
+                .filter_map(|idx| tour.get(idx).zip(tour.get(idx + 1)))
+                .any(|(from, to)| !fits(&(get_load::<T>(route_ctx, from) + delivery), to.schedule.arrival));
+
+        if has_past_violation {
+            return true;
+        }
+
+        let profile = actor.vehicle.profile;
+        let carried = get_load::<T>(route_ctx, prev) + delivery;
+        let init = (prev.place.location, prev.schedule.departure);
+
+        std::iter::once(target)
+            .chain(tour.all_activities().skip(index + 1))
+            .enumerate()
+            .scan(init, |(location, departure), (offset, activity)| {
+                let arrival =
+                    *departure + self.transport.duration(profile, *location, activity.place.location, *departure);
+                let service_start = arrival.max(activity.place.time.start);
+
+                *location = activity.place.location;
+                *departure = service_start + self.activity.duration(actor, activity, arrival);
+
+                let load = match offset {
+                    0 => carried,
+                    1 => carried + change,
+                    _ => {
+                        let from_idx = index + offset - 1;
+                        let extra = if from_idx <= end_idx { pickup } else { T::default() };
+                        tour.get(from_idx).map_or(extra, |from| get_load::<T>(route_ctx, from) + extra)
+                    }
+                };
+
+                Some(fits(&load, arrival))
+            })
+            .any(|fits| !fits)
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + 'static> HardActivityConstraint
+    for CapacityDecayHardActivityConstraint<T>
+{
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let decay = route_ctx.route.actor.vehicle.dimens.get_value::<CapacityDecay>("capacity_decay")?;
+
+        if self.has_violation(route_ctx, activity_ctx, decay) {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn get_load<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(route_ctx: &RouteContext, activity: &Activity) -> T {
+    route_ctx.state.get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity).cloned().unwrap_or_default()
+}
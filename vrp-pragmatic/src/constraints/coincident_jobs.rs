@@ -0,0 +1,77 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/coincident_jobs_test.rs"]
+mod coincident_jobs_test;
+
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::Location;
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Activity;
+
+/// A module which keeps jobs with the same location within one stop of the tour: a job can be
+/// inserted only next to other jobs at its location if the tour already visits it and no job can
+/// be inserted in between of jobs which share location.
+pub struct CoincidentJobsModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl CoincidentJobsModule {
+    /// Creates a new instance of `CoincidentJobsModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(CoincidentJobsHardActivityConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for CoincidentJobsModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct CoincidentJobsHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for CoincidentJobsHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let location = activity_ctx.target.place.location;
+        let prev = activity_ctx.prev;
+        let next = activity_ctx.next;
+
+        let is_next_to_stop = is_job_at(prev, location) || next.map_or(false, |next| is_job_at(next, location));
+        let is_inside_stop = next.map_or(false, |next| next.job.is_some() && is_job_at(prev, next.place.location));
+        let is_visited = || route_ctx.route.tour.all_activities().any(|activity| is_job_at(activity, location));
+
+        let is_split = !is_next_to_stop && (is_inside_stop || is_visited());
+
+        if is_split {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn is_job_at(activity: &Activity, location: Location) -> bool {
+    activity.job.is_some() && activity.place.location == location
+}
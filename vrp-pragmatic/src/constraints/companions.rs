@@ -0,0 +1,91 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/companions_test.rs"]
+mod companions_test;
+
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Route;
+
+/// A module which allows to assign a job with companions only to the route which serves at least
+/// one of its companion jobs, e.g. when delivery requires extra crew members. Jobs which lost
+/// their companions, e.g. after ruin, are removed from the route and become required again.
+pub struct CompanionModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl CompanionModule {
+    /// Creates a new instance of `CompanionModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(CompanionHardRouteConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for CompanionModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let locked = &ctx.locked;
+        let removed = ctx
+            .routes
+            .iter_mut()
+            .flat_map(|route_ctx| {
+                let jobs = route_ctx
+                    .route
+                    .tour
+                    .jobs()
+                    .filter(|job| !locked.contains(job) && !has_companion(&route_ctx.route, job))
+                    .collect::<Vec<_>>();
+
+                jobs.iter().for_each(|job| {
+                    route_ctx.route_mut().tour.remove(job);
+                });
+
+                jobs.into_iter()
+            })
+            .collect::<Vec<_>>();
+
+        ctx.required.extend(removed);
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct CompanionHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for CompanionHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        if has_companion(&ctx.route, job) {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+/// Checks whether route serves any companion of the job. Returns true if job has no companions.
+fn has_companion(route: &Route, job: &Job) -> bool {
+    job.dimens().get_value::<Vec<String>>("companions").map_or(true, |companions| {
+        route
+            .tour
+            .jobs()
+            .any(|other| other != *job && other.dimens().get_id().map_or(false, |id| companions.contains(id)))
+    })
+}
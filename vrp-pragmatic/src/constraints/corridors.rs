@@ -0,0 +1,84 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/corridors_test.rs"]
+mod corridors_test;
+
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Activity;
+
+/// A job corridor: corridor id and job position along it.
+type Corridor = (String, f64);
+
+/// A module which keeps jobs of the same service corridor, e.g. manholes along a road, in
+/// ascending order of their positions along the corridor on any route which serves them.
+/// Corridor membership is taken from `corridor` job dimension.
+pub struct CorridorModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl CorridorModule {
+    /// Creates a new instance of `CorridorModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(CorridorHardActivityConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for CorridorModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct CorridorHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for CorridorHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let (id, position) = get_corridor(activity_ctx.target)?;
+
+        let (before, after): (Vec<_>, Vec<_>) = route_ctx
+            .route
+            .tour
+            .all_activities()
+            .enumerate()
+            .filter_map(|(idx, activity)| get_corridor(activity).map(|corridor| (idx, corridor)))
+            .filter(|(_, (other_id, _))| *other_id == id)
+            .partition(|(idx, _)| *idx <= activity_ctx.index);
+
+        let is_ordered = before.iter().all(|(_, (_, other))| *other <= position)
+            && after.iter().all(|(_, (_, other))| *other >= position);
+
+        if is_ordered {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+fn get_corridor(activity: &Activity) -> Option<Corridor> {
+    activity.retrieve_job().and_then(|job| job.dimens().get_value::<Corridor>("corridor").cloned())
+}
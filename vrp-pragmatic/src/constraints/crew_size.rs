@@ -0,0 +1,58 @@
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::{Dimensions, ValueDimension};
+use vrp_core::models::problem::Job;
+
+/// A module which allows to assign job only to vehicle with crew big enough to serve it, e.g. a job
+/// with heavy items requires two persons. Crew sizes are taken from `crew_size` job and vehicle
+/// dimensions, default crew size is one.
+pub struct CrewSizeModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl CrewSizeModule {
+    /// Creates a new instance of `CrewSizeModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(CrewSizeHardRouteConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for CrewSizeModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct CrewSizeHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for CrewSizeHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        if get_crew_size(job.dimens()) <= get_crew_size(&ctx.route.actor.vehicle.dimens) {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+fn get_crew_size(dimens: &Dimensions) -> usize {
+    dimens.get_value::<usize>("crew_size").cloned().unwrap_or(1)
+}
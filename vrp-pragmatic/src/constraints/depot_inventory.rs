@@ -0,0 +1,106 @@
+use crate::constraints::get_priority;
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::{Demand, DemandDimension, Load};
+use vrp_core::models::problem::{Job, Single};
+
+/// A module which limits total static delivery demand of all routes by depot inventory. Inventory
+/// needed by required jobs with higher priority is reserved, so lower priority jobs are the first
+/// ones to be left unassigned when inventory is not enough.
+pub struct DepotInventoryModule<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> DepotInventoryModule<T> {
+    pub fn new(inventory: T, code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(DepotInventoryHardRouteConstraint {
+                inventory,
+                code,
+            }))],
+            keys: vec![DISPATCHED_DEMAND_KEY],
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> ConstraintModule for DepotInventoryModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let dispatched = ctx.route.tour.jobs().fold(T::default(), |acc, job| acc + get_dispatched_demand::<T>(&job));
+        ctx.state_mut().put_route_state(DISPATCHED_DEMAND_KEY, dispatched);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct DepotInventoryHardRouteConstraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    inventory: T,
+    code: i32,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> HardRouteConstraint
+    for DepotInventoryHardRouteConstraint<T>
+{
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        _: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let demand = get_dispatched_demand::<T>(job);
+        if !demand.is_not_empty() {
+            return None;
+        }
+
+        let dispatched = solution_ctx.routes.iter().fold(T::default(), |acc, route_ctx| {
+            acc + route_ctx.state.get_route_state::<T>(DISPATCHED_DEMAND_KEY).cloned().unwrap_or_default()
+        });
+
+        let priority = get_priority(job).unwrap_or(1);
+        let reserved = if priority > 1 {
+            solution_ctx
+                .required
+                .iter()
+                .filter(|other| get_priority(other).unwrap_or(1) < priority)
+                .fold(T::default(), |acc, other| acc + get_dispatched_demand::<T>(other))
+        } else {
+            T::default()
+        };
+
+        if self.inventory.can_fit(&(dispatched + reserved + demand)) {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+/// Returns static delivery demand of the job: only this part of demand is loaded at depot.
+fn get_dispatched_demand<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(job: &Job) -> T {
+    let get_demand = |single: &Single| single.dimens.get_demand().map_or(T::default(), |d: &Demand<T>| d.delivery.0);
+
+    match job {
+        Job::Single(single) => get_demand(single),
+        Job::Multi(multi) => multi.jobs.iter().fold(T::default(), |acc, single| acc + get_demand(single)),
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/first_leg_distance_test.rs"]
+mod first_leg_distance_test;
+
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Distance, ValueDimension};
+use vrp_core::models::problem::{Job, TransportCost};
+use vrp_core::models::solution::Activity;
+
+/// A module which limits distance driven from the vehicle start to the first job of the route,
+/// e.g. to avoid long unproductive driving at route start. The limit is taken from
+/// `max_first_leg_distance` vehicle dimension and vehicles without it are not restricted.
+pub struct FirstLegDistanceModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl FirstLegDistanceModule {
+    /// Creates a new instance of `FirstLegDistanceModule`.
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>, code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(FirstLegDistanceHardActivityConstraint {
+                code,
+                transport,
+            }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for FirstLegDistanceModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct FirstLegDistanceHardActivityConstraint {
+    code: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for FirstLegDistanceHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let max_distance = *route_ctx.route.actor.vehicle.dimens.get_value::<f64>("max_first_leg_distance")?;
+        let tour = &route_ctx.route.tour;
+
+        // NOTE first leg is not changed when target activity is inserted after the first job
+        if tour.all_activities().take(activity_ctx.index + 1).any(is_job_activity) {
+            return None;
+        }
+
+        let activities = tour
+            .all_activities()
+            .take(activity_ctx.index + 1)
+            .chain(std::iter::once(activity_ctx.target))
+            .chain(tour.all_activities().skip(activity_ctx.index + 1))
+            .collect::<Vec<_>>();
+
+        let first_leg_distance = self.get_first_leg_distance(route_ctx, activities.as_slice())?;
+
+        if first_leg_distance > max_distance {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+impl FirstLegDistanceHardActivityConstraint {
+    /// Returns distance driven from the route start to the first job activity, if there is any.
+    fn get_first_leg_distance(&self, route_ctx: &RouteContext, activities: &[&Activity]) -> Option<Distance> {
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let first_idx = activities.iter().position(|activity| is_job_activity(activity))?;
+
+        Some(activities[..=first_idx].windows(2).fold(0., |acc, leg| match leg {
+            [from, to] => {
+                acc + self.transport.distance(profile, from.place.location, to.place.location, from.schedule.departure)
+            }
+            _ => acc,
+        }))
+    }
+}
+
+/// Checks whether activity serves a job, not a break, a reload or a depot visit.
+fn is_job_activity(activity: &Activity) -> bool {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_value::<String>("type"))
+        .map_or(false, |job_type| matches!(job_type.as_str(), "pickup" | "delivery" | "service" | "replacement"))
+}
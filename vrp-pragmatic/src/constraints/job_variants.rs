@@ -0,0 +1,107 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/job_variants_test.rs"]
+mod job_variants_test;
+
+use std::collections::HashSet;
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::Job;
+
+/// A module which allows to serve a job using one of its variants, e.g. with different demand or
+/// service duration. Each variant is a separate job with the same id, so the module keeps only one
+/// of them assigned: once a variant is inserted, the others are moved to the list of ignored jobs.
+/// As a result, the insertion heuristic evaluates all variants and picks the best feasible one.
+/// When no variant is assigned, only the first one is kept in the list of unassigned jobs, so the
+/// job is counted once.
+pub struct JobVariantsModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl JobVariantsModule {
+    /// Creates a new instance of `JobVariantsModule`.
+    pub fn new() -> Self {
+        Self { constraints: vec![], keys: vec![] }
+    }
+}
+
+impl Default for JobVariantsModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConstraintModule for JobVariantsModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, job: &Job) {
+        if let Some(id) = get_variant_id(job) {
+            let id = id.clone();
+            let (siblings, required) =
+                solution_ctx.required.drain(..).partition::<Vec<_>, _>(|other| get_variant_id(other) == Some(&id));
+
+            solution_ctx.required = required;
+            solution_ctx.ignored.extend(siblings);
+        }
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let assigned = ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+            .filter_map(|job| get_variant_id(&job).cloned())
+            .collect::<HashSet<_>>();
+
+        let is_assigned = |job: &Job| get_variant_id(job).map_or(false, |id| assigned.contains(id));
+
+        let (siblings, required) = ctx.required.drain(..).partition::<Vec<_>, _>(|job| is_assigned(job));
+        ctx.required = required;
+        ctx.ignored.extend(siblings);
+
+        let siblings = ctx.unassigned.keys().filter(|job| is_assigned(job)).cloned().collect::<Vec<_>>();
+        siblings.iter().for_each(|job| {
+            ctx.unassigned.remove(job);
+        });
+        ctx.ignored.extend(siblings);
+
+        let (released, ignored) = ctx
+            .ignored
+            .drain(..)
+            .partition::<Vec<_>, _>(|job| get_variant_id(job).map_or(false, |id| !assigned.contains(id)));
+        ctx.ignored = ignored;
+        ctx.required.extend(released);
+
+        let mut unassigned =
+            ctx.unassigned.keys().filter(|job| get_variant_id(job).is_some()).cloned().collect::<Vec<_>>();
+        unassigned.sort_by_key(get_variant_index);
+
+        let mut ids = HashSet::new();
+        let siblings =
+            unassigned.into_iter().filter(|job| !ids.insert(get_variant_id(job).cloned())).collect::<Vec<_>>();
+        siblings.iter().for_each(|job| {
+            ctx.unassigned.remove(job);
+        });
+        ctx.ignored.extend(siblings);
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+fn get_variant_index(job: &Job) -> Option<usize> {
+    job.as_single().and_then(|single| single.dimens.get_value::<usize>("variant")).cloned()
+}
+
+fn get_variant_id(job: &Job) -> Option<&String> {
+    job.as_single()
+        .filter(|single| single.dimens.get_value::<usize>("variant").is_some())
+        .and_then(|single| single.dimens.get_id())
+}
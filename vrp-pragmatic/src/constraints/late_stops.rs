@@ -0,0 +1,119 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/late_stops_test.rs"]
+mod late_stops_test;
+
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost};
+use vrp_core::models::solution::Activity;
+
+/// A module which limits amount of late activities on a route when time windows are soft: each
+/// activity can still be served late, but the route cannot have more late activities than allowed.
+/// Lateness is determined by the lateness function, the limit is taken from `max_late_stops`
+/// vehicle dimension and vehicles without it are not restricted.
+pub struct LateStopsModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl LateStopsModule {
+    /// Creates a new instance of `LateStopsModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        lateness_func: LatenessFunc,
+        code: i32,
+    ) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(LateStopsHardActivityConstraint {
+                code,
+                activity,
+                transport,
+                lateness_func,
+            }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for LateStopsModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct LateStopsHardActivityConstraint {
+    code: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    lateness_func: LatenessFunc,
+}
+
+impl LateStopsHardActivityConstraint {
+    fn is_late(&self, activity: &Activity, service_start: Timestamp) -> bool {
+        (self.lateness_func)(activity).map_or(false, |(latest, _)| service_start > latest)
+    }
+
+    /// Returns amount of late activities as if target activity is inserted. Schedule is recalculated
+    /// after insertion point as inserted activity delays all next ones.
+    fn get_late_activities(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> usize {
+        let actor = route_ctx.route.actor.as_ref();
+        let profile = actor.vehicle.profile;
+        let tour = &route_ctx.route.tour;
+        let index = activity_ctx.index;
+
+        let before = tour
+            .all_activities()
+            .take(index + 1)
+            .filter(|activity| self.is_late(activity, activity.schedule.arrival.max(activity.place.time.start)))
+            .count();
+
+        let init = (activity_ctx.prev.place.location, activity_ctx.prev.schedule.departure);
+        let after = std::iter::once(activity_ctx.target)
+            .chain(tour.all_activities().skip(index + 1))
+            .scan(init, |(location, departure), activity| {
+                let arrival =
+                    *departure + self.transport.duration(profile, *location, activity.place.location, *departure);
+
+                let service_start = arrival.max(activity.place.time.start);
+
+                *location = activity.place.location;
+                *departure = service_start + self.activity.duration(actor, activity, arrival);
+
+                Some(self.is_late(activity, service_start))
+            })
+            .filter(|is_late| *is_late)
+            .count();
+
+        before + after
+    }
+}
+
+impl HardActivityConstraint for LateStopsHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let max_late_stops = *route_ctx.route.actor.vehicle.dimens.get_value::<usize>("max_late_stops")?;
+
+        if self.get_late_activities(route_ctx, activity_ctx) > max_late_stops {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
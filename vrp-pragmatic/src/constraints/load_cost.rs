@@ -0,0 +1,152 @@
+use crate::constraints::WeightedLoadFunc;
+use std::marker::PhantomData;
+use std::ops::{Add, Range, Sub};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, Demand, DemandDimension, Load, ValueDimension};
+use vrp_core::models::problem::{Job, TransportCost};
+use vrp_core::models::solution::Activity;
+
+/// A module which applies load dependent distance cost: each route leg costs extra its distance
+/// multiplied by weighted sum of load carried on it, e.g. a loaded tanker burns more fuel than an
+/// empty one. Weights are taken from `load_distance` vehicle dimension, vehicles without it
+/// are not affected.
+///
+/// The module relies on load states calculated by capacity module, so it has to be added after it.
+pub struct LoadCostModule<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    calculator: LoadCostCalculator<T>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> LoadCostModule<T> {
+    /// Creates a new instance of `LoadCostModule`.
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>, weighted_load: WeightedLoadFunc<T>) -> Self {
+        let calculator = LoadCostCalculator { transport, weighted_load, phantom: PhantomData };
+
+        Self {
+            calculator: calculator.clone(),
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(calculator))],
+            keys: vec![LOAD_COST_KEY],
+        }
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> ConstraintModule for LoadCostModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let cost = get_load_distance(ctx).map_or(0., |weights| {
+            let total = ctx.route.tour.total();
+            self.calculator.get_legs_cost(ctx, 0..total.max(1) - 1, |load| self.calculator.weighted(load, weights))
+        });
+
+        ctx.state_mut().put_route_state(LOAD_COST_KEY, cost);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct LoadCostCalculator<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    weighted_load: WeightedLoadFunc<T>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> Clone for LoadCostCalculator<T> {
+    fn clone(&self) -> Self {
+        Self { transport: self.transport.clone(), weighted_load: self.weighted_load.clone(), phantom: PhantomData }
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> LoadCostCalculator<T> {
+    fn weighted(&self, load: &T, weights: &[f64]) -> Cost {
+        (self.weighted_load)(load, weights)
+    }
+
+    /// Returns sum of costs of legs which start at activities with given indices. Leg cost is its
+    /// distance multiplied by per distance cost of load carried after leg's start activity.
+    fn get_legs_cost<F>(&self, route_ctx: &RouteContext, range: Range<usize>, load_cost: F) -> Cost
+    where
+        F: Fn(&T) -> Cost,
+    {
+        let tour = &route_ctx.route.tour;
+
+        range
+            .filter_map(|idx| tour.get(idx).zip(tour.get(idx + 1)))
+            .map(|(from, to)| self.get_distance(route_ctx, from, to) * load_cost(&get_load(route_ctx, from)))
+            .sum()
+    }
+
+    fn get_distance(&self, route_ctx: &RouteContext, from: &Activity, to: &Activity) -> f64 {
+        let profile = route_ctx.route.actor.vehicle.profile;
+        self.transport.distance(profile, from.place.location, to.place.location, from.schedule.departure)
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> SoftActivityConstraint for LoadCostCalculator<T> {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let weights = if let Some(weights) = get_load_distance(route_ctx) {
+            weights.as_slice()
+        } else {
+            return 0.;
+        };
+
+        let (prev, target, index) = (activity_ctx.prev, activity_ctx.target, activity_ctx.index);
+        let demand: Option<&Demand<T>> = target.job.as_ref().and_then(|job| job.dimens.get_demand());
+        let (delivery, pickup, change) =
+            demand.map_or((T::default(), T::default(), T::default()), |d| (d.delivery.0, d.pickup.0, d.change()));
+
+        let total = route_ctx.route.tour.total();
+        let (start_idx, end_idx) = route_ctx
+            .state
+            .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+            .and_then(|intervals| intervals.iter().find(|(start, end)| *start <= index && index <= *end).cloned())
+            .unwrap_or((0, total.max(1) - 1));
+
+        let weighted = |load: &T| self.weighted(load, weights);
+        let extra = |extra: T| move |load: &T| weighted(&(*load + extra)) - weighted(load);
+
+        // NOTE static delivery is carried from the interval start, static pickup till the interval end,
+        // dynamic demand affects only the next leg as the counterpart activity is not known here
+        let past =
+            if delivery.is_not_empty() { self.get_legs_cost(route_ctx, start_idx..index, extra(delivery)) } else { 0. };
+        let future = if pickup.is_not_empty() {
+            self.get_legs_cost(route_ctx, index + 1..(end_idx + 1).min(total.max(1) - 1), extra(pickup))
+        } else {
+            0.
+        };
+
+        let prev_load = get_load(route_ctx, prev);
+        let carried = prev_load + delivery;
+        let target_leg = self.get_distance(route_ctx, prev, target) * weighted(&carried);
+        let next_leg = activity_ctx.next.map_or(0., |next| {
+            self.get_distance(route_ctx, target, next) * weighted(&(carried + change))
+                - self.get_distance(route_ctx, prev, next) * weighted(&prev_load)
+        });
+
+        past + target_leg + next_leg + future
+    }
+}
+
+fn get_load<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(route_ctx: &RouteContext, activity: &Activity) -> T {
+    route_ctx.state.get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity).cloned().unwrap_or_default()
+}
+
+fn get_load_distance(route_ctx: &RouteContext) -> Option<&Vec<f64>> {
+    route_ctx.route.actor.vehicle.dimens.get_value::<Vec<f64>>("load_distance")
+}
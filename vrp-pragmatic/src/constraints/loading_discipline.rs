@@ -0,0 +1,131 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/loading_discipline_test.rs"]
+mod loading_discipline_test;
+
+use std::collections::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Activity;
+
+/// Specifies the order in which cargo loaded on vehicle can be unloaded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadingDiscipline {
+    /// Last in, first out: the last loaded job has to be delivered first.
+    Lifo,
+    /// First in, first out: the first loaded job has to be delivered first.
+    Fifo,
+}
+
+/// A module which enforces loading discipline of pickup and delivery jobs on vehicles with
+/// `loading_discipline` dimension. A job is loaded at its first pickup and unloaded at its last
+/// delivery: with LIFO, load intervals of two jobs cannot cross, with FIFO, they cannot be nested.
+pub struct LoadingDisciplineModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl LoadingDisciplineModule {
+    /// Creates a new instance of `LoadingDisciplineModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(LoadingDisciplineHardActivityConstraint {
+                code,
+            }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for LoadingDisciplineModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct LoadingDisciplineHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for LoadingDisciplineHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let discipline = route_ctx.route.actor.vehicle.dimens.get_value::<LoadingDiscipline>("loading_discipline")?;
+        let target_job = get_pickup_delivery_job(activity_ctx.target)?;
+
+        let tour = &route_ctx.route.tour;
+        let intervals = tour
+            .all_activities()
+            .take(activity_ctx.index + 1)
+            .chain(std::iter::once(activity_ctx.target))
+            .chain(tour.all_activities().skip(activity_ctx.index + 1))
+            .filter_map(get_pickup_delivery_job)
+            .enumerate()
+            .fold(HashMap::<Job, (usize, usize, usize)>::new(), |mut acc, (idx, job)| {
+                let entry = acc.entry(job).or_insert((idx, idx, 0));
+                entry.1 = idx;
+                entry.2 += 1;
+                acc
+            });
+
+        // NOTE a job is checked only when all its activities are in the tour, so the last
+        //      inserted activity of pickup and delivery job decides about its feasibility
+        let is_complete = |job: &Job, count: usize| job.as_multi().map_or(false, |multi| multi.jobs.len() == count);
+
+        let &(start, end, count) = intervals.get(&target_job)?;
+        if !is_complete(&target_job, count) {
+            return None;
+        }
+
+        let is_violated = intervals
+            .iter()
+            .filter(|(job, (_, _, count))| **job != target_job && is_complete(job, *count))
+            .any(|(_, &(other_start, other_end, _))| {
+                let is_nested = (start < other_start && other_end < end) || (other_start < start && end < other_end);
+                let is_crossing = (start < other_start && other_start < end && end < other_end)
+                    || (other_start < start && start < other_end && other_end < end);
+
+                match discipline {
+                    LoadingDiscipline::Lifo => is_crossing,
+                    LoadingDiscipline::Fifo => is_nested,
+                }
+            });
+
+        if is_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns a job which has both pickups and deliveries.
+fn get_pickup_delivery_job(activity: &Activity) -> Option<Job> {
+    activity.retrieve_job().filter(|job| {
+        job.as_multi().map_or(false, |multi| {
+            let has_type = |activity_type: &str| {
+                multi.jobs.iter().any(|single| {
+                    single.dimens.get_value::<String>("type").map_or(false, |value| value == activity_type)
+                })
+            };
+
+            has_type("pickup") && has_type("delivery")
+        })
+    })
+}
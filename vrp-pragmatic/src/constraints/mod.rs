@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 use vrp_core::models::common::{Dimensions, IdDimension, ValueDimension};
-use vrp_core::models::problem::Single;
+use vrp_core::models::problem::{Job, Single};
 use vrp_core::models::solution::{Activity, Route};
 
 fn as_single_job<F>(activity: &Activity, condition: F) -> Option<&Arc<Single>>
@@ -20,17 +20,73 @@ fn get_vehicle_id_from_job(job: &Arc<Single>) -> Option<&String> {
     job.dimens.get_value::<String>("vehicle_id")
 }
 
+fn get_priority(job: &Job) -> Option<i32> {
+    match job {
+        Job::Single(job) => job.dimens.get_value::<i32>("priority"),
+        Job::Multi(job) => job.dimens.get_value::<i32>("priority"),
+    }
+    .cloned()
+}
+
 fn is_correct_vehicle(route: &Route, target_id: &str, target_shift: usize) -> bool {
     route.actor.vehicle.dimens.get_id().unwrap() == target_id
         && get_shift_index(&route.actor.vehicle.dimens) == target_shift
 }
 
+mod aggregate_capacity;
+pub use self::aggregate_capacity::{AggregateCapacityModule, WeightedLoadFunc};
+
+mod attribute_rules;
+pub use self::attribute_rules::{AttributeRule, AttributeRulesModule};
+
+mod alternative_starts;
+pub use self::alternative_starts::AlternativeStartsModule;
+
+mod anchors;
+pub use self::anchors::AnchorModule;
+
 mod breaks;
 pub use self::breaks::BreakModule;
 
+mod coincident_jobs;
+pub use self::coincident_jobs::CoincidentJobsModule;
+
+mod capacity_decay;
+pub use self::capacity_decay::{CapacityDecay, CapacityDecayModule};
+
+mod companions;
+pub use self::companions::CompanionModule;
+
+mod corridors;
+pub use self::corridors::CorridorModule;
+
+mod crew_size;
+pub use self::crew_size::CrewSizeModule;
+
+mod depot_inventory;
+pub use self::depot_inventory::DepotInventoryModule;
+
+mod first_leg_distance;
+pub use self::first_leg_distance::FirstLegDistanceModule;
+
+mod job_variants;
+pub use self::job_variants::JobVariantsModule;
+
+mod late_stops;
+pub use self::late_stops::LateStopsModule;
+
+mod loading_discipline;
+pub use self::loading_discipline::{LoadingDiscipline, LoadingDisciplineModule};
+
+mod load_cost;
+pub use self::load_cost::LoadCostModule;
+
 mod priorities;
 pub use self::priorities::PriorityModule;
 
+mod receiving_rates;
+pub use self::receiving_rates::ReceivingRateModule;
+
 mod reloads;
 pub use self::reloads::ReloadMultiTrip;
 
@@ -39,3 +95,18 @@ pub use self::reachable::ReachableModule;
 
 mod skills;
 pub use self::skills::SkillsModule;
+
+mod skill_time_windows;
+pub use self::skill_time_windows::SkillTimeWindowsModule;
+
+mod time_window_density;
+pub use self::time_window_density::TimeWindowDensityModule;
+
+mod time_window_jobs;
+pub use self::time_window_jobs::TimeWindowJobsModule;
+
+mod transfers;
+pub use self::transfers::{TransferModule, TransferScheduleModule};
+
+mod vehicle_pool;
+pub use self::vehicle_pool::VehiclePoolModule;
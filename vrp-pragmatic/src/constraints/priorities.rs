@@ -1,9 +1,9 @@
+use crate::constraints::get_priority;
 use std::cmp::Ordering;
 use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use vrp_core::models::common::ValueDimension;
 use vrp_core::models::problem::Job;
 use vrp_core::utils::compare_floats;
 
@@ -90,11 +90,3 @@ impl HardActivityConstraint for PriorityHardActivityConstraint {
         }
     }
 }
-
-fn get_priority(job: &Job) -> Option<i32> {
-    match job {
-        Job::Single(job) => job.dimens.get_value::<i32>("priority"),
-        Job::Multi(job) => job.dimens.get_value::<i32>("priority"),
-    }
-    .cloned()
-}
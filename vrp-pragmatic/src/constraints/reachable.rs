@@ -54,17 +54,17 @@ impl HardActivityConstraint for ReachableHardActivityConstraint {
 
         let profile = _route_ctx.route.actor.vehicle.profile;
 
-        let prev_to_target =
-            self.transport.distance(profile, prev.place.location, target.place.location, prev.schedule.departure);
-
-        if prev_to_target < 0. {
+        if self.transport.is_unreachable(profile, prev.place.location, target.place.location, prev.schedule.departure) {
             return Some(ActivityConstraintViolation { code: self.code, stopped: false });
         }
 
         if let Some(next) = next {
-            let target_to_next =
-                self.transport.distance(profile, target.place.location, next.place.location, target.schedule.departure);
-            if target_to_next < 0. {
+            if self.transport.is_unreachable(
+                profile,
+                target.place.location,
+                next.place.location,
+                target.schedule.departure,
+            ) {
                 return Some(ActivityConstraintViolation { code: self.code, stopped: false });
             }
         }
@@ -0,0 +1,223 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/receiving_rates_test.rs"]
+mod receiving_rates_test;
+
+use std::collections::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Duration, Location, Timestamp};
+use vrp_core::models::problem::{ActivityCost, Actor, Job, TransportCost};
+use vrp_core::models::solution::{Activity, Route};
+
+/// Keeps arrival times of all routes at locations with limited receiving rate.
+type Arrivals = HashMap<Location, Vec<(Arc<Actor>, Timestamp)>>;
+
+/// A module which limits receiving rate of locations shared by multiple tours, e.g. a warehouse
+/// which can accept only one delivery at a time: arrivals of different tours at such location
+/// should be separated at least by location's interval. Jobs which arrive too close to other
+/// tours, e.g. after ruin, are removed from the route and become required again.
+pub struct ReceivingRateModule {
+    rates: Arc<HashMap<Location, Duration>>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ReceivingRateModule {
+    /// Creates a new instance of `ReceivingRateModule`.
+    pub fn new(
+        rates: HashMap<Location, Duration>,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+    ) -> Self {
+        let rates = Arc::new(rates);
+
+        Self {
+            rates: rates.clone(),
+            activity: activity.clone(),
+            transport: transport.clone(),
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(ReceivingRateHardActivityConstraint {
+                code,
+                rates,
+                activity,
+                transport,
+            }))],
+            keys: vec![RECEIVING_ARRIVALS_KEY],
+        }
+    }
+
+    /// Recalculates arrivals of all routes and puts them into all routes, including routes of
+    /// available actors, so they can be used by route's constraints.
+    fn update_arrivals(&self, solution_ctx: &mut SolutionContext) {
+        let arrivals = Arc::new(solution_ctx.routes.iter().fold(Arrivals::default(), |mut arrivals, route_ctx| {
+            add_arrivals(&mut arrivals, &route_ctx.route.actor, self.get_arrivals(&route_ctx.route));
+            arrivals
+        }));
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| put_arrivals(route_ctx, arrivals.clone()));
+        solution_ctx.registry.modify_available(|route_ctx| put_arrivals(route_ctx, arrivals.clone()));
+    }
+
+    /// Returns arrival times of route's job activities at rate limited locations.
+    fn get_arrivals(&self, route: &Route) -> Vec<(Job, Location, Timestamp)> {
+        let actor = route.actor.as_ref();
+        let start = route.tour.start().unwrap();
+        let init = (start.place.location, start.schedule.departure);
+
+        route
+            .tour
+            .all_activities()
+            .skip(1)
+            .scan(init, |(location, departure), activity| {
+                let arrival = *departure
+                    + self.transport.duration(actor.vehicle.profile, *location, activity.place.location, *departure);
+
+                *location = activity.place.location;
+                *departure = arrival.max(activity.place.time.start) + self.activity.duration(actor, activity, arrival);
+
+                Some((activity, arrival))
+            })
+            .filter(|(activity, _)| self.rates.contains_key(&activity.place.location))
+            .filter_map(|(activity, arrival)| {
+                activity.retrieve_job().map(|job| (job, activity.place.location, arrival))
+            })
+            .collect()
+    }
+}
+
+impl ConstraintModule for ReceivingRateModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {
+        self.update_arrivals(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let locked = &ctx.locked;
+        let rates = self.rates.as_ref();
+
+        // NOTE routes are checked one by one against arrivals of already checked routes, so the
+        // first route keeps its jobs and conflicting jobs are removed from the next ones
+        let (_, removed) = ctx.routes.iter_mut().fold(
+            (Arrivals::default(), Vec::<Job>::new()),
+            |(mut arrivals, mut removed), route_ctx| {
+                let actor = route_ctx.route.actor.clone();
+
+                loop {
+                    let conflict = self.get_arrivals(&route_ctx.route).into_iter().find(|(job, location, arrival)| {
+                        !locked.contains(job) && is_conflict(rates, &arrivals, &actor, *location, *arrival)
+                    });
+
+                    match conflict {
+                        Some((job, _, _)) => {
+                            route_ctx.route_mut().tour.remove(&job);
+                            removed.push(job);
+                        }
+                        None => break,
+                    }
+                }
+
+                add_arrivals(&mut arrivals, &actor, self.get_arrivals(&route_ctx.route));
+
+                (arrivals, removed)
+            },
+        );
+
+        ctx.required.extend(removed);
+
+        self.update_arrivals(ctx);
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct ReceivingRateHardActivityConstraint {
+    code: i32,
+    rates: Arc<HashMap<Location, Duration>>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for ReceivingRateHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let arrivals = route_ctx.state.get_route_state::<Arc<Arrivals>>(RECEIVING_ARRIVALS_KEY)?;
+
+        let actor = &route_ctx.route.actor;
+        let tour = &route_ctx.route.tour;
+        let is_limited =
+            |activity: &Activity| activity.job.is_some() && self.rates.contains_key(&activity.place.location);
+
+        // NOTE job activities after insertion point are moved in time, so they have to be checked too
+        let last_index =
+            (activity_ctx.index + 1..tour.total()).rev().find(|index| is_limited(tour.get(*index).unwrap()));
+        let mut activities =
+            std::iter::once(activity_ctx.target).chain(last_index.into_iter().flat_map(|last_index| {
+                (activity_ctx.index + 1..=last_index).map(move |index| tour.get(index).unwrap())
+            }));
+
+        let prev = activity_ctx.prev;
+        let is_violated = activities
+            .try_fold((prev.place.location, prev.schedule.departure), |(location, departure), activity| {
+                let arrival = departure
+                    + self.transport.duration(actor.vehicle.profile, location, activity.place.location, departure);
+
+                if is_limited(activity)
+                    && is_conflict(self.rates.as_ref(), arrivals, actor, activity.place.location, arrival)
+                {
+                    return Err(());
+                }
+
+                let departure =
+                    arrival.max(activity.place.time.start) + self.activity.duration(actor.as_ref(), activity, arrival);
+
+                Ok((activity.place.location, departure))
+            })
+            .is_err();
+
+        if is_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks whether arrival at location is too close to arrival of other actor.
+fn is_conflict(
+    rates: &HashMap<Location, Duration>,
+    arrivals: &Arrivals,
+    actor: &Arc<Actor>,
+    location: Location,
+    arrival: Timestamp,
+) -> bool {
+    match (rates.get(&location), arrivals.get(&location)) {
+        (Some(interval), Some(others)) => others
+            .iter()
+            .any(|(other, other_arrival)| !Arc::ptr_eq(other, actor) && (arrival - other_arrival).abs() < *interval),
+        _ => false,
+    }
+}
+
+fn add_arrivals(arrivals: &mut Arrivals, actor: &Arc<Actor>, route_arrivals: Vec<(Job, Location, Timestamp)>) {
+    route_arrivals.into_iter().for_each(|(_, location, arrival)| {
+        arrivals.entry(location).or_default().push((actor.clone(), arrival));
+    });
+}
+
+fn put_arrivals(route_ctx: &mut RouteContext, arrivals: Arc<Arrivals>) {
+    route_ctx.state_mut().put_route_state(RECEIVING_ARRIVALS_KEY, arrivals);
+}
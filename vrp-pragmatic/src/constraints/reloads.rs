@@ -33,7 +33,14 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> MultiTrip<T> for Rel
             let vehicle_id = get_vehicle_id_from_job(&job).unwrap();
             let shift_index = get_shift_index(&job.dimens);
 
-            is_correct_vehicle(route, vehicle_id, shift_index)
+            let is_below_limit = route
+                .actor
+                .vehicle
+                .dimens
+                .get_value::<usize>("max_reloads")
+                .map_or(true, |max| route.tour.jobs().filter(|job| self.is_reload_job(job)).count() < *max);
+
+            is_correct_vehicle(route, vehicle_id, shift_index) && is_below_limit
         } else {
             false
         }
@@ -0,0 +1,87 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/skill_time_windows_test.rs"]
+mod skill_time_windows_test;
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{TimeWindow, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::utils::compare_floats;
+
+/// A module which restricts usage of job time windows available only for vehicles with specific
+/// skills. Such time windows are taken from `skill_tws` job dimension: when activity is scheduled
+/// within one of them, vehicle should have all skills from `skills` dimension.
+pub struct SkillTimeWindowsModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl SkillTimeWindowsModule {
+    /// Creates a new instance of `SkillTimeWindowsModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(SkillTimeWindowsHardActivityConstraint {
+                code,
+            }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for SkillTimeWindowsModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct SkillTimeWindowsHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for SkillTimeWindowsHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let skill_tws = activity_ctx
+            .target
+            .job
+            .as_ref()
+            .and_then(|single| single.dimens.get_value::<Vec<(TimeWindow, HashSet<String>)>>("skill_tws"))?;
+
+        let time = &activity_ctx.target.place.time;
+        let (_, required) = skill_tws.iter().find(|(tw, _)| {
+            compare_floats(tw.start, time.start) == Ordering::Equal
+                && compare_floats(tw.end, time.end) == Ordering::Equal
+        })?;
+
+        let has_skills = route_ctx
+            .route
+            .actor
+            .vehicle
+            .dimens
+            .get_value::<HashSet<String>>("skills")
+            .map_or(false, |skills| required.iter().all(|skill| skills.contains(skill)));
+
+        if has_skills {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
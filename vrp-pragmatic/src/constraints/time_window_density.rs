@@ -0,0 +1,134 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/time_window_density_test.rs"]
+mod time_window_density_test;
+
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Duration, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost};
+use vrp_core::models::solution::Activity;
+
+/// A module which limits amount of activities of jobs with time windows served by a vehicle within
+/// any time span of given duration, e.g. an hour, to smooth dispatcher workload. Activities are
+/// counted by service start time, the limit is taken from `max_hourly_tw_jobs` vehicle dimension and
+/// vehicles without it are not restricted.
+pub struct TimeWindowDensityModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl TimeWindowDensityModule {
+    /// Creates a new instance of `TimeWindowDensityModule`.
+    pub fn new(
+        span: Duration,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+    ) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(TimeWindowDensityHardActivityConstraint {
+                code,
+                span,
+                activity,
+                transport,
+            }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for TimeWindowDensityModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TimeWindowDensityHardActivityConstraint {
+    code: i32,
+    span: Duration,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl TimeWindowDensityHardActivityConstraint {
+    /// Returns service start times of activities with time windows as if target activity is inserted.
+    /// Schedule is recalculated after insertion point as inserted activity delays all next ones.
+    fn get_service_starts(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Vec<Timestamp> {
+        let actor = route_ctx.route.actor.as_ref();
+        let profile = actor.vehicle.profile;
+        let tour = &route_ctx.route.tour;
+        let index = activity_ctx.index;
+
+        let before = tour
+            .all_activities()
+            .take(index + 1)
+            .map(|activity| (activity, activity.schedule.arrival.max(activity.place.time.start)));
+
+        let init = (activity_ctx.prev.place.location, activity_ctx.prev.schedule.departure);
+        let after = std::iter::once(activity_ctx.target).chain(tour.all_activities().skip(index + 1)).scan(
+            init,
+            |(location, departure), activity| {
+                let arrival =
+                    *departure + self.transport.duration(profile, *location, activity.place.location, *departure);
+
+                let service_start = arrival.max(activity.place.time.start);
+
+                *location = activity.place.location;
+                *departure = service_start + self.activity.duration(actor, activity, arrival);
+
+                Some((activity, service_start))
+            },
+        );
+
+        before.chain(after).filter(|(activity, _)| has_time_windows(activity)).map(|(_, start)| start).collect()
+    }
+}
+
+impl HardActivityConstraint for TimeWindowDensityHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let max_jobs = *route_ctx.route.actor.vehicle.dimens.get_value::<usize>("max_hourly_tw_jobs")?;
+
+        if !has_time_windows(activity_ctx.target) {
+            return None;
+        }
+
+        let service_starts = self.get_service_starts(route_ctx, activity_ctx);
+
+        if get_max_density(service_starts.as_slice(), self.span) > max_jobs {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns max amount of sorted timestamps within any time span of given duration.
+fn get_max_density(timestamps: &[Timestamp], span: Duration) -> usize {
+    let (max, _) = timestamps.iter().enumerate().fold((0, 0), |(max, start), (end, timestamp)| {
+        let start = (start..=end).find(|idx| timestamps[*idx] > *timestamp - span).unwrap_or(end);
+
+        (max.max(end - start + 1), start)
+    });
+
+    max
+}
+
+fn has_time_windows(activity: &Activity) -> bool {
+    matches!(activity.job.as_ref(), Some(job) if job.dimens.get_value::<bool>("has_tws").is_some())
+}
@@ -0,0 +1,72 @@
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::{Dimensions, ValueDimension};
+use vrp_core::models::problem::Job;
+
+/// A module which limits amount of jobs with time windows per route.
+pub struct TimeWindowJobsModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl TimeWindowJobsModule {
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(TimeWindowJobsHardRouteConstraint { code }))],
+            keys: vec![TIME_WINDOW_JOBS_KEY],
+        }
+    }
+}
+
+impl ConstraintModule for TimeWindowJobsModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let tw_jobs = ctx.route.tour.jobs().filter(has_time_windows).count();
+        ctx.state_mut().put_route_state(TIME_WINDOW_JOBS_KEY, tw_jobs);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TimeWindowJobsHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for TimeWindowJobsHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        if let Some(max_tw_jobs) = ctx.route.actor.vehicle.dimens.get_value::<usize>("max_tw_jobs") {
+            if has_time_windows(job) {
+                let tw_jobs = ctx.state.get_route_state::<usize>(TIME_WINDOW_JOBS_KEY).cloned().unwrap_or(0);
+                if tw_jobs + 1 > *max_tw_jobs {
+                    return Some(RouteConstraintViolation { code: self.code });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+fn has_time_windows(job: &Job) -> bool {
+    let has_tws = |dimens: &Dimensions| dimens.get_value::<bool>("has_tws").is_some();
+
+    match job {
+        Job::Single(single) => has_tws(&single.dimens),
+        Job::Multi(multi) => multi.jobs.iter().any(|single| has_tws(&single.dimens)),
+    }
+}
@@ -0,0 +1,436 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/transfers_test.rs"]
+mod transfers_test;
+
+use std::collections::{HashMap, HashSet};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{IdDimension, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost};
+use vrp_core::models::solution::{Activity, Route};
+
+/// A module which allows to serve a job with cargo brought to the hub by another job, the first
+/// leg, which is served by another vehicle. The job can be assigned only when its first leg is
+/// served by another tour and its activities start after the first leg's last activity is done.
+/// Jobs which lost their first leg, e.g. after ruin, are removed from the route and become
+/// required again. Waiting for the first leg is applied to route schedules by `TransferScheduleModule`.
+pub struct TransferModule {
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl TransferModule {
+    /// Creates a new instance of `TransferModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+    ) -> Self {
+        Self {
+            activity: activity.clone(),
+            transport: transport.clone(),
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(TransferHardRouteConstraint { code })),
+                ConstraintVariant::HardActivity(Arc::new(TransferHardActivityConstraint { code, activity, transport })),
+            ],
+            keys: vec![TRANSFER_TIMES_KEY],
+        }
+    }
+
+    /// Recalculates synchronization times and puts them into all routes, including routes of
+    /// available actors, so they can be used by route's constraints.
+    fn update_times(&self, solution_ctx: &mut SolutionContext) {
+        let (ready, sync) = self.get_times(solution_ctx);
+        let (ready, sync) = (Arc::new(ready), Arc::new(sync));
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            put_times(route_ctx, ready.clone(), sync.clone());
+            self.accept_route_state(route_ctx);
+        });
+
+        solution_ctx.registry.modify_available(|route_ctx| put_times(route_ctx, ready.clone(), sync.clone()));
+    }
+
+    /// Returns departure times of first legs' last activities and start times of second legs' first
+    /// activities, both by first leg job id.
+    fn get_times(&self, solution_ctx: &SolutionContext) -> (HashMap<String, Timestamp>, HashMap<String, Timestamp>) {
+        let sources = get_sources(solution_ctx);
+
+        // NOTE waiting for a first leg can delay first legs of other transfers served later in the
+        // same route, so times are propagated until they are stable
+        let mut times = self.get_route_times(solution_ctx, &sources, &HashMap::new());
+        for _ in 0..solution_ctx.routes.len() {
+            let next = self.get_route_times(solution_ctx, &sources, &times.0);
+            let is_stable = next.0 == times.0;
+            times = next;
+
+            if is_stable {
+                break;
+            }
+        }
+
+        times
+    }
+
+    /// Returns synchronization times of routes calculated with given ready times of first legs.
+    fn get_route_times(
+        &self,
+        solution_ctx: &SolutionContext,
+        sources: &HashSet<String>,
+        ready_times: &HashMap<String, Timestamp>,
+    ) -> (HashMap<String, Timestamp>, HashMap<String, Timestamp>) {
+        solution_ctx.routes.iter().fold(Default::default(), |(mut ready, mut sync), route_ctx| {
+            let route = route_ctx.route.as_ref();
+            let schedules = get_schedules(route, ready_times, self.activity.as_ref(), self.transport.as_ref());
+
+            route.tour.all_activities().zip(schedules).for_each(|(activity, (_, start, departure))| {
+                if let Some(job) = activity.retrieve_job() {
+                    // NOTE activities are iterated in tour order, so the last one defines the time
+                    if let Some(id) = get_job_id(&job).filter(|id| sources.contains(*id)) {
+                        ready.insert(id.clone(), departure);
+                    }
+
+                    if let Some(source) = get_transfer_from(&job) {
+                        let time = sync.entry(source.clone()).or_insert(start);
+                        *time = time.min(start);
+                    }
+                }
+            });
+
+            (ready, sync)
+        })
+    }
+}
+
+/// Returns ids of first leg jobs.
+fn get_sources(solution_ctx: &SolutionContext) -> HashSet<String> {
+    solution_ctx
+        .required
+        .iter()
+        .chain(solution_ctx.unassigned.keys())
+        .filter_map(|job| get_transfer_from(job).cloned())
+        .chain(
+            solution_ctx
+                .routes
+                .iter()
+                .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+                .filter_map(|job| get_transfer_from(&job).cloned()),
+        )
+        .collect()
+}
+
+impl ConstraintModule for TransferModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        self.update_times(solution_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let (ready, sync) = match ctx.state.get_route_state::<TransferTimes>(TRANSFER_TIMES_KEY) {
+            Some(times) => (times.ready.clone(), times.sync.clone()),
+            None => return,
+        };
+
+        let limits = ctx
+            .route
+            .tour
+            .all_activities()
+            .enumerate()
+            .filter_map(|(index, activity)| {
+                activity
+                    .retrieve_job()
+                    .as_ref()
+                    .and_then(get_job_id)
+                    .and_then(|id| sync.get(id))
+                    .map(|time| (index, *time))
+            })
+            .collect();
+
+        ctx.state_mut().put_route_state(TRANSFER_TIMES_KEY, TransferTimes { ready, sync, limits });
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let served = ctx
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(index, route_ctx)| {
+                route_ctx.route.tour.jobs().filter_map(move |job| get_job_id(&job).map(|id| (id.clone(), index)))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let locked = &ctx.locked;
+        let removed = ctx
+            .routes
+            .iter_mut()
+            .enumerate()
+            .flat_map(|(index, route_ctx)| {
+                let jobs = route_ctx
+                    .route
+                    .tour
+                    .jobs()
+                    .filter(|job| {
+                        !locked.contains(job)
+                            && get_transfer_from(job)
+                                .map_or(false, |source| served.get(source).map_or(true, |other| *other == index))
+                    })
+                    .collect::<Vec<_>>();
+
+                jobs.iter().for_each(|job| {
+                    route_ctx.route_mut().tour.remove(job);
+                });
+
+                jobs.into_iter()
+            })
+            .collect::<Vec<_>>();
+
+        ctx.required.extend(removed);
+
+        self.update_times(ctx);
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// A module which makes vehicle wait for the first leg before serving the second one: it updates
+/// route schedules using ready times kept by `TransferModule` in route state, time windows of
+/// activities are not changed. It should go after the module which updates route schedules.
+pub struct TransferScheduleModule {
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl TransferScheduleModule {
+    /// Creates a new instance of `TransferScheduleModule`.
+    pub fn new(activity: Arc<dyn ActivityCost + Send + Sync>, transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self { activity, transport, constraints: vec![], keys: vec![] }
+    }
+
+    fn update_schedules(&self, ctx: &mut RouteContext) {
+        let ready = match ctx.state.get_route_state::<TransferTimes>(TRANSFER_TIMES_KEY) {
+            Some(times) => times.ready.clone(),
+            None => return,
+        };
+
+        let has_transfers = ctx.route.tour.all_activities().any(|activity| get_ready_time(activity, &ready).is_some());
+        if !has_transfers {
+            return;
+        }
+
+        let schedules = get_schedules(&ctx.route, &ready, self.activity.as_ref(), self.transport.as_ref());
+        ctx.route_mut().tour.all_activities_mut().zip(schedules).for_each(|(activity, (arrival, _, departure))| {
+            activity.schedule.arrival = arrival;
+            activity.schedule.departure = departure;
+        });
+
+        let total_duration =
+            ctx.route.tour.end().unwrap().schedule.departure - ctx.route.tour.start().unwrap().schedule.departure;
+        ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, total_duration);
+    }
+}
+
+impl ConstraintModule for TransferScheduleModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {
+        // NOTE ready times of first legs in other routes can be changed by insertion
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.update_schedules(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.update_schedules(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Keeps synchronization times of transfer jobs.
+struct TransferTimes {
+    /// Departure times of first legs' last activities by first leg job id.
+    ready: Arc<HashMap<String, Timestamp>>,
+    /// Start times of second legs' first activities by first leg job id.
+    sync: Arc<HashMap<String, Timestamp>>,
+    /// Route's first leg activities with the latest allowed departure time: activity index and time.
+    limits: Vec<(usize, Timestamp)>,
+}
+
+struct TransferHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for TransferHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let is_served_by_other = |source: &String| {
+            solution_ctx
+                .routes
+                .iter()
+                .filter(|route_ctx| !Arc::ptr_eq(&route_ctx.route.actor, &ctx.route.actor))
+                .any(|route_ctx| route_ctx.route.tour.jobs().any(|other| get_job_id(&other) == Some(source)))
+        };
+
+        if get_transfer_from(job).map_or(true, is_served_by_other) {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+struct TransferHardActivityConstraint {
+    code: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for TransferHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let times = route_ctx.state.get_route_state::<TransferTimes>(TRANSFER_TIMES_KEY)?;
+
+        let actor = route_ctx.route.actor.as_ref();
+        let profile = actor.vehicle.profile;
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let ready_time = match target.retrieve_job().as_ref().and_then(get_transfer_from) {
+            Some(source) => match times.ready.get(source) {
+                Some(ready_time) => Some(*ready_time),
+                None => return Some(ActivityConstraintViolation { code: self.code, stopped: true }),
+            },
+            None => None,
+        };
+
+        let departure = prev.schedule.departure;
+        let arrival =
+            departure + self.transport.duration(profile, prev.place.location, target.place.location, departure);
+        let start = arrival.max(target.place.time.start);
+        let start = ready_time.map_or(start, |ready_time| start.max(ready_time));
+
+        if start > target.place.time.end {
+            return Some(ActivityConstraintViolation { code: self.code, stopped: false });
+        }
+
+        let departure = start + self.activity.duration(actor, target, arrival);
+
+        // NOTE transport constraint does not know about waiting for the first leg
+        if let (Some(_), Some(next)) = (ready_time, activity_ctx.next) {
+            let arrival =
+                departure + self.transport.duration(profile, target.place.location, next.place.location, departure);
+            let latest_arrival = route_ctx
+                .state
+                .get_activity_state::<Timestamp>(LATEST_ARRIVAL_KEY, next)
+                .cloned()
+                .unwrap_or(next.place.time.end);
+
+            if arrival > latest_arrival {
+                return Some(ActivityConstraintViolation { code: self.code, stopped: false });
+            }
+        }
+
+        // NOTE first leg activities after insertion point should not be delayed after second leg start
+        let last_index = times.limits.iter().map(|(index, _)| *index).filter(|index| *index > activity_ctx.index).max();
+
+        let is_delayed = last_index.map_or(false, |last_index| {
+            let tour = &route_ctx.route.tour;
+            (activity_ctx.index + 1..=last_index)
+                .try_fold((target.place.location, departure), |(location, departure), index| {
+                    let activity = tour.get(index).unwrap();
+                    let arrival =
+                        departure + self.transport.duration(profile, location, activity.place.location, departure);
+                    let start = arrival.max(activity.place.time.start);
+                    let start = get_ready_time(activity, &times.ready).map_or(start, |ready| start.max(ready));
+                    let departure = start + self.activity.duration(actor, activity, arrival);
+
+                    let is_late = times.limits.iter().any(|(idx, time)| *idx == index && departure > *time);
+
+                    if is_late {
+                        Err(())
+                    } else {
+                        Ok((activity.place.location, departure))
+                    }
+                })
+                .is_err()
+        });
+
+        if is_delayed {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn put_times(
+    route_ctx: &mut RouteContext,
+    ready: Arc<HashMap<String, Timestamp>>,
+    sync: Arc<HashMap<String, Timestamp>>,
+) {
+    route_ctx.state_mut().put_route_state(TRANSFER_TIMES_KEY, TransferTimes { ready, sync, limits: vec![] });
+}
+
+/// Returns arrival, service start and departure times of route activities. Service of second leg
+/// activities starts not earlier than the ready time of their first leg.
+fn get_schedules(
+    route: &Route,
+    ready: &HashMap<String, Timestamp>,
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+) -> Vec<(Timestamp, Timestamp, Timestamp)> {
+    let actor = route.actor.as_ref();
+    let start = route.tour.start().unwrap();
+    let init = (start.place.location, start.schedule.departure);
+
+    std::iter::once((init.1, init.1, init.1))
+        .chain(route.tour.all_activities().skip(1).scan(init, |(location, departure), current| {
+            let arrival =
+                *departure + transport.duration(actor.vehicle.profile, *location, current.place.location, *departure);
+            let start = arrival.max(current.place.time.start);
+            let start = get_ready_time(current, ready).map_or(start, |ready| start.max(ready));
+
+            *location = current.place.location;
+            *departure = start + activity.duration(actor, current, arrival);
+
+            Some((arrival, start, *departure))
+        }))
+        .collect()
+}
+
+/// Returns ready time of the first leg if the activity belongs to a second leg.
+fn get_ready_time(activity: &Activity, ready: &HashMap<String, Timestamp>) -> Option<Timestamp> {
+    activity.retrieve_job().as_ref().and_then(get_transfer_from).and_then(|source| ready.get(source)).cloned()
+}
+
+fn get_job_id(job: &Job) -> Option<&String> {
+    job.dimens().get_id()
+}
+
+fn get_transfer_from(job: &Job) -> Option<&String> {
+    job.dimens().get_value::<String>("transfer_from")
+}
@@ -0,0 +1,224 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/vehicle_pool_test.rs"]
+mod vehicle_pool_test;
+
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::Timestamp;
+use vrp_core::models::problem::{ActivityCost, Actor, Job, TransportCost};
+use vrp_core::models::solution::Route;
+use vrp_core::utils::compare_floats;
+
+/// Keeps time intervals when routes are active.
+type Intervals = Vec<(Arc<Actor>, Timestamp, Timestamp)>;
+
+/// A module which limits amount of routes active at the same time by size of physical vehicle
+/// pool shared by all vehicle shifts. A route is active from departure at its start till arrival
+/// at its end. Routes which overlap too many other routes, e.g. after ruin, lose their jobs.
+pub struct VehiclePoolModule {
+    size: usize,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl VehiclePoolModule {
+    /// Creates a new instance of `VehiclePoolModule`.
+    pub fn new(
+        size: usize,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+    ) -> Self {
+        Self {
+            size,
+            activity: activity.clone(),
+            transport: transport.clone(),
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(VehiclePoolHardActivityConstraint {
+                code,
+                size,
+                activity,
+                transport,
+            }))],
+            keys: vec![VEHICLE_POOL_INTERVALS_KEY],
+        }
+    }
+
+    /// Recalculates intervals of all routes and puts them into all routes, including routes of
+    /// available actors, so they can be used by route's constraints.
+    fn update_intervals(&self, solution_ctx: &mut SolutionContext) {
+        let intervals = Arc::new(
+            solution_ctx
+                .routes
+                .iter()
+                .filter_map(|route_ctx| self.get_interval(&route_ctx.route))
+                .collect::<Intervals>(),
+        );
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| put_intervals(route_ctx, intervals.clone()));
+        solution_ctx.registry.modify_available(|route_ctx| put_intervals(route_ctx, intervals.clone()));
+    }
+
+    /// Returns time interval when the route is active. Schedule is recalculated as it can be
+    /// outdated when jobs are removed from the route.
+    fn get_interval(&self, route: &Route) -> Option<(Arc<Actor>, Timestamp, Timestamp)> {
+        if !route.tour.has_jobs() {
+            return None;
+        }
+
+        let actor = route.actor.as_ref();
+        let start = route.tour.start()?;
+        let init = (start.place.location, start.schedule.departure);
+
+        let (_, end) = route.tour.all_activities().skip(1).fold(init, |(location, departure), activity| {
+            let arrival = departure
+                + self.transport.duration(actor.vehicle.profile, location, activity.place.location, departure);
+
+            (
+                activity.place.location,
+                arrival.max(activity.place.time.start) + self.activity.duration(actor, activity, arrival),
+            )
+        });
+
+        Some((route.actor.clone(), start.schedule.departure, end))
+    }
+}
+
+impl ConstraintModule for VehiclePoolModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {
+        self.update_intervals(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let locked = &ctx.locked;
+
+        // NOTE routes are checked one by one against intervals of already checked routes, so the
+        // first routes keep their jobs and unlocked jobs are removed from the conflicting ones
+        let (_, removed) = ctx.routes.iter_mut().fold(
+            (Intervals::default(), Vec::<Job>::new()),
+            |(mut intervals, mut removed), route_ctx| {
+                let is_conflict = self
+                    .get_interval(&route_ctx.route)
+                    .into_iter()
+                    .any(|(_, start, end)| get_max_active(&intervals, &route_ctx.route.actor, start, end) >= self.size);
+
+                if is_conflict {
+                    let jobs = route_ctx.route.tour.jobs().filter(|job| !locked.contains(job)).collect::<Vec<_>>();
+                    jobs.iter().for_each(|job| {
+                        route_ctx.route_mut().tour.remove(job);
+                    });
+                    removed.extend(jobs);
+                }
+
+                intervals.extend(self.get_interval(&route_ctx.route));
+
+                (intervals, removed)
+            },
+        );
+
+        ctx.required.extend(removed);
+
+        self.update_intervals(ctx);
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct VehiclePoolHardActivityConstraint {
+    code: i32,
+    size: usize,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for VehiclePoolHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let intervals = route_ctx.state.get_route_state::<Arc<Intervals>>(VEHICLE_POOL_INTERVALS_KEY)?;
+
+        let actor = route_ctx.route.actor.as_ref();
+        let profile = actor.vehicle.profile;
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let start = route_ctx.route.tour.start()?;
+
+        // NOTE departure is moved to the latest possible time when the first job is inserted, so
+        // the interval starts later than departure of an empty route
+        let start_time = if activity_ctx.index == 0 {
+            let latest = actor.detail.start.as_ref().and_then(|start| start.time.latest).unwrap_or(f64::MAX);
+            let to_target =
+                self.transport.duration(profile, prev.place.location, target.place.location, prev.schedule.departure);
+
+            prev.schedule.departure.max(target.place.time.start - to_target).min(latest)
+        } else {
+            start.schedule.departure
+        };
+
+        let departure = if activity_ctx.index == 0 { start_time } else { prev.schedule.departure };
+        let arrival =
+            departure + self.transport.duration(profile, prev.place.location, target.place.location, departure);
+        let departure = arrival.max(target.place.time.start) + self.activity.duration(actor, target, arrival);
+
+        // NOTE waiting time can only absorb delay, so the route end is moved not more than the next activity
+        let end_time = match activity_ctx.next {
+            Some(next) => {
+                let arrival =
+                    departure + self.transport.duration(profile, target.place.location, next.place.location, departure);
+
+                if next.job.is_none() {
+                    arrival
+                } else {
+                    let end = route_ctx.route.tour.end().map_or(arrival, |end| end.schedule.departure);
+                    end + (arrival - next.schedule.arrival).max(0.)
+                }
+            }
+            None => departure,
+        };
+
+        if get_max_active(intervals, &route_ctx.route.actor, start_time, end_time) >= self.size {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns max amount of other actors' routes active at the same time within given interval.
+/// Intervals which only touch each other are not considered as overlapping.
+fn get_max_active(intervals: &Intervals, actor: &Arc<Actor>, start: Timestamp, end: Timestamp) -> usize {
+    let mut events = intervals
+        .iter()
+        .filter(|(other, other_start, other_end)| {
+            !Arc::ptr_eq(other, actor) && *other_start < end && *other_end > start
+        })
+        .flat_map(|(_, other_start, other_end)| vec![(other_start.max(start), 1), (other_end.min(end), -1)])
+        .collect::<Vec<(Timestamp, i32)>>();
+
+    events.sort_by(|(a_time, a_delta), (b_time, b_delta)| compare_floats(*a_time, *b_time).then(a_delta.cmp(b_delta)));
+
+    events
+        .iter()
+        .scan(0, |active, (_, delta)| {
+            *active += delta;
+            Some(*active)
+        })
+        .max()
+        .unwrap_or(0) as usize
+}
+
+fn put_intervals(route_ctx: &mut RouteContext, intervals: Arc<Intervals>) {
+    route_ctx.state_mut().put_route_state(VEHICLE_POOL_INTERVALS_KEY, intervals);
+}
@@ -1,8 +1,11 @@
-use vrp_core::models::common::{Cost, Timestamp};
+use std::collections::HashMap;
+use vrp_core::models::common::{Cost, Duration, Timestamp, ValueDimension};
 use vrp_core::models::problem::{ActivityCost, Actor};
 use vrp_core::models::solution::Activity;
 
-/// Uses costs only for vehicle ignoring costs of driver.
+/// Uses costs only for vehicle ignoring costs of driver. Service duration of a job activity
+/// depends on vehicle type when the job specifies it. Vehicle maneuvering time is added to
+/// duration of each activity which requires stopping, i.e. all activities except breaks.
 pub struct OnlyVehicleActivityCost {}
 
 impl ActivityCost for OnlyVehicleActivityCost {
@@ -12,6 +15,23 @@ impl ActivityCost for OnlyVehicleActivityCost {
 
         waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
     }
+
+    fn duration(&self, actor: &Actor, activity: &Activity, _arrival: Timestamp) -> Cost {
+        let job = activity.job.as_ref();
+
+        let service = job
+            .and_then(|job| job.dimens.get_value::<HashMap<String, Duration>>("type_durations"))
+            .zip(actor.vehicle.dimens.get_value::<String>("type_id"))
+            .and_then(|(type_durations, type_id)| type_durations.get(type_id).cloned())
+            .unwrap_or(activity.place.duration);
+
+        let maneuvering = job
+            .filter(|job| job.dimens.get_value::<String>("type").map_or(true, |job_type| job_type != "break"))
+            .and_then(|_| actor.vehicle.dimens.get_value::<Duration>("maneuvering_time").cloned())
+            .unwrap_or(0.);
+
+        service + maneuvering
+    }
 }
 
 impl Default for OnlyVehicleActivityCost {
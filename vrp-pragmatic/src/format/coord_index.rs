@@ -34,6 +34,10 @@ impl CoordIndex {
             vehicle.shifts.iter().for_each(|shift| {
                 index.add(&shift.start.location);
 
+                if let Some(alternatives) = &shift.start.alternatives {
+                    alternatives.iter().for_each(|location| index.add(location));
+                }
+
                 if let Some(end) = &shift.end {
                     index.add(&end.location);
                 }
@@ -53,9 +57,17 @@ impl CoordIndex {
                 if let Some(reloads) = &shift.reloads {
                     reloads.iter().for_each(|reload| index.add(&reload.location));
                 }
+
+                if let Some(anchors) = &shift.anchors {
+                    anchors.iter().for_each(|anchor| index.add(&anchor.location));
+                }
             });
         });
 
+        if let Some(anchors) = problem.config.as_ref().and_then(|config| config.anchors.as_ref()) {
+            anchors.iter().for_each(|anchor| index.add(&anchor.location));
+        }
+
         index
     }
 
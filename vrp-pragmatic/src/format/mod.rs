@@ -3,10 +3,13 @@
 //!
 
 extern crate serde_json;
+use self::problem::{Problem as ApiProblem, VehicleCargoPlace, VehicleShift};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::BufWriter;
+use vrp_core::models::common::{Cost, Distance, ValueDimension};
 use vrp_core::models::problem::Job as CoreJob;
+use vrp_core::models::problem::Vehicle;
 use vrp_core::models::Problem as CoreProblem;
 
 mod coord_index;
@@ -108,6 +111,24 @@ const LOCKING_CONSTRAINT_CODE: i32 = 7;
 const REACHABLE_CONSTRAINT_CODE: i32 = 8;
 const PRIORITY_CONSTRAINT_CODE: i32 = 9;
 const AREA_CONSTRAINT_CODE: i32 = 10;
+const TIME_WINDOW_JOBS_CONSTRAINT_CODE: i32 = 11;
+const DEPOT_INVENTORY_CONSTRAINT_CODE: i32 = 12;
+const AGGREGATE_CAPACITY_CONSTRAINT_CODE: i32 = 13;
+const ANCHOR_CONSTRAINT_CODE: i32 = 14;
+const COINCIDENT_JOBS_CONSTRAINT_CODE: i32 = 15;
+const COMPANION_CONSTRAINT_CODE: i32 = 16;
+const TRANSFER_CONSTRAINT_CODE: i32 = 17;
+const RECEIVING_RATE_CONSTRAINT_CODE: i32 = 18;
+const VEHICLE_POOL_CONSTRAINT_CODE: i32 = 19;
+const TIME_WINDOW_DENSITY_CONSTRAINT_CODE: i32 = 20;
+const CORRIDOR_CONSTRAINT_CODE: i32 = 21;
+const CREW_SIZE_CONSTRAINT_CODE: i32 = 22;
+const LATE_STOPS_CONSTRAINT_CODE: i32 = 23;
+const LOADING_DISCIPLINE_CONSTRAINT_CODE: i32 = 24;
+const SKILL_TIME_WINDOWS_CONSTRAINT_CODE: i32 = 25;
+const FIRST_LEG_DISTANCE_CONSTRAINT_CODE: i32 = 26;
+const CAPACITY_DECAY_CONSTRAINT_CODE: i32 = 27;
+const ATTRIBUTE_RULES_CONSTRAINT_CODE: i32 = 28;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
@@ -121,6 +142,39 @@ pub(crate) fn get_job_index(problem: &CoreProblem) -> &JobIndex {
         .unwrap_or_else(|| panic!("cannot get job index!"))
 }
 
+/// Gets a key of job variant in job index: the first variant is stored under the job id itself.
+pub(crate) fn get_variant_job_key(job_id: &str, variant_idx: usize) -> String {
+    if variant_idx == 0 {
+        job_id.to_string()
+    } else {
+        format!("{}_variant_{}", job_id, variant_idx)
+    }
+}
+
+/// Gets extra cost of the tour with given total distance defined by vehicle distance tiers.
+pub(crate) fn get_distance_tiers_cost(vehicle: &Vehicle, distance: Distance) -> Cost {
+    vehicle
+        .dimens
+        .get_value::<Vec<(Distance, Cost)>>("distance_tiers")
+        .map_or(0., |tiers| tiers.iter().map(|(threshold, delta)| (distance - *threshold).max(0.) * *delta).sum())
+}
+
+/// Gets extra cost of the leg with given distance driven with given load defined by vehicle load
+/// distance costs.
+pub(crate) fn get_load_distance_cost(vehicle: &Vehicle, load: &[i32], distance: Distance) -> Cost {
+    vehicle.dimens.get_value::<Vec<f64>>("load_distance").map_or(0., |costs| {
+        costs.iter().zip(load.iter()).map(|(cost, value)| cost * *value as f64).sum::<Cost>() * distance
+    })
+}
+
+/// Gets anchors of vehicle shift: its own or, if not defined, the ones from problem configuration.
+pub(crate) fn get_shift_anchors<'a>(
+    problem: &'a ApiProblem,
+    shift: &'a VehicleShift,
+) -> Option<&'a Vec<VehicleCargoPlace>> {
+    shift.anchors.as_ref().or_else(|| problem.config.as_ref().and_then(|config| config.anchors.as_ref()))
+}
+
 /// Gets coord index from core problem definition.
 pub(crate) fn get_coord_index(problem: &CoreProblem) -> &CoordIndex {
     problem
@@ -0,0 +1,36 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/escalation_test.rs"]
+mod escalation_test;
+
+use crate::format::problem::*;
+
+/// Escalates priorities of aging jobs: each `cycles` planning cycles waited by the job make its
+/// priority one level more important, but not more than `maxPriority`. Jobs without priority have
+/// the most important one already. Problem without escalation is returned as it is.
+pub(crate) fn escalate_priorities(problem: Problem) -> Problem {
+    let escalation = match problem.config.as_ref().and_then(|config| config.priority_escalation.as_ref()) {
+        Some(escalation) => escalation,
+        None => return problem,
+    };
+
+    let jobs = problem
+        .plan
+        .jobs
+        .iter()
+        .map(|job| Job { priority: get_escalated_priority(job, escalation), ..job.clone() })
+        .collect();
+
+    Problem { plan: Plan { jobs, relations: problem.plan.relations.clone() }, ..problem }
+}
+
+fn get_escalated_priority(job: &Job, escalation: &PriorityEscalation) -> Option<i32> {
+    let max_priority = escalation.max_priority.unwrap_or(1);
+
+    match (job.priority, job.age) {
+        (Some(priority), Some(age)) if priority > max_priority => {
+            let levels = (age / escalation.cycles.max(1)) as i32;
+            Some((priority - levels).max(max_priority))
+        }
+        (priority, _) => priority,
+    }
+}
@@ -2,16 +2,20 @@
 #[path = "../../../tests/unit/format/problem/fleet_reader_test.rs"]
 mod fleet_reader_test;
 
+use crate::constraints::{CapacityDecay, LoadingDiscipline};
 use crate::extensions::create_typed_actor_groups;
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{add_skills, ApiProblem, ProblemProperties};
-use crate::format::problem::Matrix;
+use crate::format::problem::{
+    get_shift_day, is_vehicle_working_day, DistanceTier, Matrix, VehicleCapacityDecay, VehicleLoadingDiscipline,
+};
 use crate::parse_time;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use vrp_core::construction::constraints::{Area, TravelLimitFunc};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
+use vrp_core::utils::compare_floats;
 
 pub(crate) fn create_transport_costs(
     api_problem: &ApiProblem,
@@ -43,25 +47,18 @@ pub(crate) fn create_transport_costs(
             (profile, matrix.timestamp.clone(), matrix)
         })
         .map(|(profile, timestamp, matrix)| {
-            let (durations, distances) = if let Some(error_codes) = &matrix.error_codes {
-                let mut durations: Vec<Duration> = Default::default();
-                let mut distances: Vec<Distance> = Default::default();
-                for (i, error) in error_codes.iter().enumerate() {
-                    if *error > 0 {
-                        durations.push(-1.);
-                        distances.push(-1.);
-                    } else {
-                        durations.push(*matrix.travel_times.get(i).unwrap() as f64);
-                        distances.push(*matrix.distances.get(i).unwrap() as f64);
-                    }
-                }
-                (durations, distances)
-            } else {
-                (
-                    matrix.travel_times.iter().map(|d| *d as f64).collect(),
-                    matrix.distances.iter().map(|d| *d as f64).collect(),
-                )
-            };
+            let time_factor = matrix.time_unit.map_or(1., |unit| unit.as_seconds());
+            let get_value =
+                |idx: usize, value: f64| if is_unreachable_cell(matrix, idx) { UNREACHABLE_VALUE } else { value };
+
+            let durations = (0..)
+                .zip(matrix.travel_times.iter())
+                .map(|(idx, &value)| get_value(idx, get_matrix_value(value, matrix.default_travel_time) * time_factor))
+                .collect();
+            let distances = (0..)
+                .zip(matrix.distances.iter())
+                .map(|(idx, &value)| get_value(idx, get_matrix_value(value, matrix.default_distance)))
+                .collect();
 
             MatrixData::new(profile, timestamp.map(|t| parse_time(&t)), durations, distances)
         })
@@ -101,39 +98,134 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
-                let location = coord_index.get_by_loc(&shift.start.location).unwrap();
                 let earliest = parse_time(&shift.start.earliest);
                 let latest = shift.start.latest.as_ref().map(|time| parse_time(&time));
-                (location, earliest, latest)
+
+                // NOTE dock slot restricts departure time further
+                match shift.start.dock_slot.as_ref() {
+                    Some(slot) if slot.len() == 2 => {
+                        let (slot_start, slot_end) = (parse_time(&slot[0]), parse_time(&slot[1]));
+                        (earliest.max(slot_start), Some(latest.map_or(slot_end, |latest| latest.min(slot_end))))
+                    }
+                    _ => (earliest, latest),
+                }
             };
 
+            // NOTE overtime extends shift end, regular shift end is kept in vehicle dimens
+            let shift_end = shift.end.as_ref().and_then(|end| end.max_overtime.map(|_| parse_time(&end.latest)));
             let end = shift.end.as_ref().map(|end| {
                 let location = coord_index.get_by_loc(&end.location).unwrap();
-                let time = parse_time(&end.latest);
+                let time = parse_time(&end.latest) + end.max_overtime.unwrap_or(0.);
                 (location, time)
             });
 
-            let details = vec![VehicleDetail {
-                start: Some(VehiclePlace {
-                    location: start.0,
-                    time: TimeInterval { earliest: Some(start.1), latest: start.2 },
-                }),
-                end: end.map(|(location, time)| VehiclePlace {
-                    location,
-                    time: TimeInterval { earliest: None, latest: Some(time) },
-                }),
-            }];
+            // NOTE each alternative start location is a separate vehicle detail
+            let details = std::iter::once(&shift.start.location)
+                .chain(shift.start.alternatives.iter().flatten())
+                .map(|location| VehicleDetail {
+                    start: Some(VehiclePlace {
+                        location: coord_index.get_by_loc(location).unwrap(),
+                        time: TimeInterval { earliest: Some(start.0), latest: start.1 },
+                    }),
+                    end: end.map(|(location, time)| VehiclePlace {
+                        location,
+                        time: TimeInterval { earliest: None, latest: Some(time) },
+                    }),
+                })
+                .collect::<Vec<_>>();
+
+            let day = get_shift_day(api_problem, vehicle, shift_index);
 
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
+                // NOTE vehicle does not exist on days when it does not work
+                if day.map_or(false, |day| !is_vehicle_working_day(api_problem, vehicle_id, day)) {
+                    return;
+                }
+
                 let mut dimens: Dimensions = Default::default();
                 dimens.set_value("type_id", vehicle.type_id.clone());
                 dimens.set_value("shift_index", shift_index);
                 dimens.set_id(vehicle_id);
 
+                if let Some(shift_end) = shift_end {
+                    dimens.set_value("shift_end", shift_end);
+                }
+
+                if let Some(day) = day {
+                    dimens.set_value("day", day);
+                }
+
                 if let Some(areas) = areas.take() {
                     dimens.set_value("areas", areas);
                 }
 
+                if let Some(max_tw_jobs) = vehicle.limits.as_ref().and_then(|l| l.max_time_window_jobs) {
+                    dimens.set_value("max_tw_jobs", max_tw_jobs);
+                }
+
+                if let Some(max_reloads) = vehicle.limits.as_ref().and_then(|l| l.max_reloads) {
+                    dimens.set_value("max_reloads", max_reloads);
+                }
+
+                if let Some(max_hourly_tw_jobs) = vehicle.limits.as_ref().and_then(|l| l.max_hourly_time_window_jobs) {
+                    dimens.set_value("max_hourly_tw_jobs", max_hourly_tw_jobs);
+                }
+
+                if let Some(max_late_stops) = vehicle.limits.as_ref().and_then(|l| l.max_late_stops) {
+                    dimens.set_value("max_late_stops", max_late_stops);
+                }
+
+                if let Some(max_first_leg_distance) = vehicle.limits.as_ref().and_then(|l| l.max_first_leg_distance) {
+                    dimens.set_value("max_first_leg_distance", max_first_leg_distance);
+                }
+
+                if let Some(aggregate) = vehicle.aggregate_capacity.as_ref() {
+                    dimens.set_value("aggregate_capacity", (aggregate.weights.clone(), aggregate.limit));
+                }
+
+                if let Some(tiers) = vehicle.costs.distance_tiers.as_ref() {
+                    dimens.set_value("distance_tiers", get_distance_tiers(vehicle.costs.distance, tiers));
+                }
+
+                if let Some(max_daily_cost) = vehicle.costs.max_daily_cost {
+                    dimens.set_value("max_daily_cost", max_daily_cost);
+                }
+
+                if let Some(load_distance) = vehicle.costs.load_distance.as_ref() {
+                    dimens.set_value("load_distance", load_distance.clone());
+                }
+
+                if let Some(consumption) = vehicle.consumption.as_ref() {
+                    dimens.set_value("consumption", consumption.clone());
+                }
+
+                if let Some(crew_size) = vehicle.crew_size {
+                    dimens.set_value("crew_size", crew_size);
+                }
+
+                if let Some(maneuvering_time) = vehicle.maneuvering_time {
+                    dimens.set_value("maneuvering_time", maneuvering_time);
+                }
+
+                if let Some(capacity_decay) = vehicle.capacity_decay.as_ref() {
+                    dimens.set_value(
+                        "capacity_decay",
+                        match capacity_decay {
+                            VehicleCapacityDecay::Linear { duration } => CapacityDecay::Linear { duration: *duration },
+                        },
+                    );
+                }
+
+                if let Some(loading_discipline) = vehicle.loading_discipline.as_ref() {
+                    dimens.set_value(
+                        "loading_discipline",
+                        match loading_discipline {
+                            VehicleLoadingDiscipline::Lifo => LoadingDiscipline::Lifo,
+                            VehicleLoadingDiscipline::Fifo => LoadingDiscipline::Fifo,
+                        },
+                    );
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
                 } else {
@@ -162,14 +254,23 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
 }
 
 pub fn read_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
-    let limits = api_problem.fleet.vehicles.iter().filter(|vehicle| vehicle.limits.is_some()).fold(
-        HashMap::new(),
-        |mut acc, vehicle| {
-            let limits = vehicle.limits.as_ref().unwrap().clone();
-            acc.insert(vehicle.type_id.clone(), (limits.max_distance, limits.shift_time));
-            acc
-        },
-    );
+    let limits =
+        api_problem.fleet.vehicles.iter().filter(|vehicle| vehicle.limits.is_some() || vehicle.range.is_some()).fold(
+            HashMap::new(),
+            |mut acc, vehicle| {
+                let max_distance = vehicle.limits.as_ref().and_then(|limits| limits.max_distance);
+                let shift_time = vehicle.limits.as_ref().and_then(|limits| limits.shift_time);
+
+                // NOTE range is a physical limit, so the lowest of both distance limits is used
+                let max_distance = match (max_distance, vehicle.range) {
+                    (Some(max_distance), Some(range)) => Some(max_distance.min(range)),
+                    (max_distance, range) => max_distance.or(range),
+                };
+
+                acc.insert(vehicle.type_id.clone(), (max_distance, shift_time));
+                acc
+            },
+        );
 
     if limits.is_empty() {
         None
@@ -192,3 +293,34 @@ fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
         acc
     })
 }
+
+/// Checks whether matrix cell is marked as unreachable by error code or by sentinel value.
+fn is_unreachable_cell(matrix: &Matrix, idx: usize) -> bool {
+    let has_value = |values: &Vec<i64>, value: i64| values.get(idx).map_or(false, |cell| *cell == value);
+
+    matrix.error_codes.as_ref().and_then(|codes| codes.get(idx)).map_or(false, |code| *code > 0)
+        || matrix
+            .unreachable_value
+            .map_or(false, |value| has_value(&matrix.travel_times, value) || has_value(&matrix.distances, value))
+}
+
+fn get_matrix_value(value: i64, default: Option<i64>) -> f64 {
+    match (value, default) {
+        (value, Some(default)) if value < 0 => default as f64,
+        (value, _) => value as f64,
+    }
+}
+
+/// Returns distance tiers sorted by threshold within their cost per distance unit relative to previous tier.
+fn get_distance_tiers(per_distance: f64, tiers: &[DistanceTier]) -> Vec<(Distance, Cost)> {
+    let mut tiers = tiers.iter().map(|tier| (tier.threshold, tier.distance)).collect::<Vec<_>>();
+    tiers.sort_by(|(a, _), (b, _)| compare_floats(*a, *b));
+
+    tiers
+        .into_iter()
+        .fold((per_distance, vec![]), |(prev, mut acc), (threshold, distance)| {
+            acc.push((threshold, distance - prev));
+            (distance, acc)
+        })
+        .1
+}
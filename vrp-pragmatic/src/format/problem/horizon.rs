@@ -0,0 +1,119 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/horizon_test.rs"]
+mod horizon_test;
+
+use crate::format::problem::*;
+use crate::{format_time, parse_time};
+
+/// A duration of one day of planning horizon.
+const DAY_DURATION: f64 = 86400.;
+
+/// Expands multi day planning horizon: shifts of each vehicle type are repeated for every day when
+/// the type is available with all their times moved by day offset. Shifts of the same day keep
+/// their order and days follow each other. Problem without horizon is returned as it is.
+pub(crate) fn expand_horizon(problem: Problem) -> Problem {
+    let horizon = match problem.config.as_ref().and_then(|config| config.horizon.as_ref()) {
+        Some(horizon) => horizon,
+        None => return problem,
+    };
+    let config_anchors = problem.config.as_ref().and_then(|config| config.anchors.as_ref());
+
+    let vehicles = problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| VehicleType {
+            shifts: get_available_days(horizon, &vehicle.type_id)
+                .into_iter()
+                .flat_map(|day| {
+                    let offset = day as f64 * DAY_DURATION;
+                    vehicle.shifts.iter().map(move |shift| move_shift(shift, config_anchors, offset))
+                })
+                .collect(),
+            ..vehicle.clone()
+        })
+        .collect();
+
+    Problem { fleet: Fleet { vehicles, profiles: problem.fleet.profiles.clone() }, ..problem }
+}
+
+/// Returns a day of planning horizon for vehicle shift with given index if the problem has horizon.
+/// Vehicle type is expected to be expanded by `expand_horizon`.
+pub(crate) fn get_shift_day(problem: &Problem, vehicle: &VehicleType, shift_index: usize) -> Option<usize> {
+    let horizon = problem.config.as_ref().and_then(|config| config.horizon.as_ref())?;
+    let days = get_available_days(horizon, &vehicle.type_id);
+    let day_shifts = vehicle.shifts.len() / days.len().max(1);
+
+    days.get(shift_index / day_shifts.max(1)).cloned()
+}
+
+/// Checks whether vehicle with given id works on given day of planning horizon: vehicles without
+/// specific days work on all days when their type is available.
+pub(crate) fn is_vehicle_working_day(problem: &Problem, vehicle_id: &str, day: usize) -> bool {
+    problem
+        .config
+        .as_ref()
+        .and_then(|config| config.horizon.as_ref())
+        .and_then(|horizon| horizon.vehicles.as_ref())
+        .and_then(|vehicles| vehicles.iter().find(|vehicle| vehicle.vehicle_id == vehicle_id))
+        .map_or(true, |vehicle| vehicle.days.contains(&day))
+}
+
+/// Returns days when vehicle type is available: days without specific fleet have all vehicle types.
+fn get_available_days(horizon: &PlanningHorizon, type_id: &str) -> Vec<usize> {
+    (0..horizon.days)
+        .filter(|day| {
+            let mut day_fleets = horizon.fleets.iter().flatten().filter(|fleet| fleet.day == *day).peekable();
+
+            day_fleets.peek().is_none() || day_fleets.any(|fleet| fleet.type_ids.iter().any(|id| id == type_id))
+        })
+        .collect()
+}
+
+fn move_shift(shift: &VehicleShift, config_anchors: Option<&Vec<VehicleCargoPlace>>, offset: f64) -> VehicleShift {
+    let move_time = |time: &String| format_time(parse_time(time) + offset);
+    let move_places = |places: &Vec<VehicleCargoPlace>| {
+        places
+            .iter()
+            .map(|place| VehicleCargoPlace {
+                times: place
+                    .times
+                    .as_ref()
+                    .map(|times| times.iter().map(|tw| tw.iter().map(move_time).collect()).collect()),
+                ..place.clone()
+            })
+            .collect()
+    };
+
+    VehicleShift {
+        start: ShiftStart {
+            earliest: move_time(&shift.start.earliest),
+            latest: shift.start.latest.as_ref().map(move_time),
+            dock_slot: shift.start.dock_slot.as_ref().map(|slot| slot.iter().map(move_time).collect()),
+            ..shift.start.clone()
+        },
+        end: shift.end.as_ref().map(|end| ShiftEnd {
+            earliest: end.earliest.as_ref().map(move_time),
+            latest: move_time(&end.latest),
+            ..end.clone()
+        }),
+        depots: shift.depots.as_ref().map(move_places),
+        breaks: shift.breaks.as_ref().map(|breaks| {
+            breaks
+                .iter()
+                .map(|vehicle_break| VehicleBreak {
+                    time: match &vehicle_break.time {
+                        VehicleBreakTime::TimeWindow(tw) => {
+                            VehicleBreakTime::TimeWindow(tw.iter().map(move_time).collect())
+                        }
+                        VehicleBreakTime::TimeOffset(offset) => VehicleBreakTime::TimeOffset(offset.clone()),
+                    },
+                    ..vehicle_break.clone()
+                })
+                .collect()
+        }),
+        reloads: shift.reloads.as_ref().map(move_places),
+        // NOTE anchors from configuration have absolute times, so they are moved within the shift
+        anchors: shift.anchors.as_ref().or(config_anchors).map(move_places),
+    }
+}
@@ -1,18 +1,30 @@
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{add_skills, parse_time_window, ApiProblem, ProblemProperties};
-use crate::format::problem::{JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleCargoPlace, VehicleType};
-use crate::format::{JobIndex, Location};
+use crate::format::problem::{
+    Job as ApiJob, JobCorridor, JobPlace, JobTask, OpeningTime, RelationType, ServiceTimeDistribution, VehicleBreak,
+    VehicleBreakTime, VehicleCargoPlace, VehicleType, VehicleTypeDuration,
+};
+use crate::format::{get_shift_anchors, get_variant_job_key, JobIndex, Location};
+use crate::parse_time;
 use crate::utils::VariableJobPermutation;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use vrp_core::construction::constraints::SoftPrecedence;
+use vrp_core::construction::heuristics::SHIFT_START_OFFSETS_KEY;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
 use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
-use vrp_core::utils::{CollectGroupBy, Random};
+use vrp_core::solver::{DurationDistribution, DURATION_DISTRIBUTION_KEY};
+use vrp_core::utils::{compare_floats, CollectGroupBy, Random};
 
 // TODO configure sample size
 const MULTI_JOB_SAMPLE_SIZE: usize = 3;
 
+const DEFAULT_LATENESS_PENALTY: f64 = 1.;
+
+const DEFAULT_PRECEDENCE_PENALTY: f64 = 1000.;
+
 pub(crate) fn read_jobs_with_extra_locks(
     api_problem: &ApiProblem,
     props: &ProblemProperties,
@@ -36,12 +48,20 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
         return vec![];
     }
 
-    let relations = api_problem.plan.relations.as_ref().unwrap().iter().fold(HashMap::new(), |mut acc, r| {
-        let shift_index = r.shift_index.unwrap_or(0);
-        acc.entry((r.vehicle_id.clone(), shift_index)).or_insert_with(Vec::new).push(r.clone());
+    let relations = api_problem
+        .plan
+        .relations
+        .as_ref()
+        .unwrap()
+        .iter()
+        .filter_map(|r| r.vehicle_id.as_ref().map(|vehicle_id| (vehicle_id, r)))
+        .filter(|(_, r)| !matches!(r.type_field, RelationType::SoftPrecedence))
+        .fold(HashMap::new(), |mut acc, (vehicle_id, r)| {
+            let shift_index = r.shift_index.unwrap_or(0);
+            acc.entry((vehicle_id.clone(), shift_index)).or_insert_with(Vec::new).push(r.clone());
 
-        acc
-    });
+            acc
+        });
 
     relations.into_iter().fold(vec![], |mut acc, ((vehicle_id, shift_index), rels)| {
         let condition = create_condition(vehicle_id.clone(), shift_index);
@@ -50,6 +70,7 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
                 RelationType::Any => LockOrder::Any,
                 RelationType::Sequence => LockOrder::Sequence,
                 RelationType::Strict => LockOrder::Strict,
+                RelationType::SoftPrecedence => unreachable!(),
             };
 
             let position = match (rel.jobs.first().map(|s| s.as_str()), rel.jobs.last().map(|s| s.as_str())) {
@@ -94,6 +115,23 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
     })
 }
 
+/// Reads soft precedences of jobs from relations: each job should be served before the next one.
+pub fn read_soft_precedences(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<SoftPrecedence> {
+    api_problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .filter(|relation| matches!(relation.type_field, RelationType::SoftPrecedence))
+        .flat_map(|relation| {
+            let penalty = relation.penalty.unwrap_or(DEFAULT_PRECEDENCE_PENALTY);
+            let jobs = relation.jobs.iter().filter_map(|job_id| job_index.get(job_id).cloned()).collect::<Vec<_>>();
+
+            jobs.windows(2).map(|pair| (pair[0].clone(), pair[1].clone(), penalty)).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 fn read_required_jobs(
     api_problem: &ApiProblem,
     props: &ProblemProperties,
@@ -103,8 +141,9 @@ fn read_required_jobs(
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
+    let time_factor = get_relative_time_factor(api_problem);
 
-    let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
+    let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool, lateness: Option<f64>| {
         let absent = (empty(), empty());
         let capacity = task.demand.clone().map_or_else(empty, MultiDimLoad::new);
         let demand = if is_static_demand { (capacity, empty()) } else { (empty(), capacity) };
@@ -117,49 +156,88 @@ fn read_required_jobs(
             _ => panic!("Invalid activity type."),
         };
 
-        let places =
-            task.places.iter().map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times))).collect();
+        let places = task
+            .places
+            .iter()
+            .map(|p| (Some(p.location.clone()), p.duration, parse_place_times(p, time_factor)))
+            .collect();
+
+        let mut single =
+            get_single_with_extras(places, demand, &task.tag, activity_type, has_multi_dimens, &coord_index);
+        let graces = task.places.iter().map(|p| p.grace.unwrap_or(0.) * time_factor).collect::<Vec<_>>();
+        add_soft_time_windows(&mut single, lateness, graces.as_slice());
+        add_time_window_penalties(&mut single, task.places.as_slice());
+        add_skill_time_windows(&mut single, task.places.as_slice());
+        add_type_durations(&mut single.dimens, &task.type_durations);
+
+        if task.places.iter().any(has_place_times) {
+            single.dimens.set_value("has_tws", true);
+        }
+
+        if task.places.iter().any(|place| place.relative_times.is_some()) {
+            single.dimens.set_value(SHIFT_START_OFFSETS_KEY, true);
+        }
 
-        get_single_with_extras(places, demand, &task.tag, activity_type, has_multi_dimens, &coord_index)
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
         let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
         let is_static_demand = pickups == 0 || deliveries == 0;
-
-        let singles =
-            job.pickups
-                .iter()
-                .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand)))
-                .chain(job.deliveries.iter().flat_map(|tasks| {
-                    tasks.iter().map(|task| get_single_from_task(task, "delivery", is_static_demand))
-                }))
-                .chain(
-                    job.replacements
+        let lateness = get_lateness_penalty(api_problem, job.hard_time_window);
+
+        if let Some((task, activity_type)) = get_task_with_variants(job) {
+            let is_static_demand = activity_type != "service";
+            task.variants.iter().flatten().enumerate().for_each(|(variant_idx, variant)| {
+                let task = JobTask {
+                    places: task
+                        .places
                         .iter()
-                        .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "replacement", true))),
-                )
-                .chain(
-                    job.services
-                        .iter()
-                        .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "service", false))),
-                )
-                .collect::<Vec<_>>();
+                        .map(|place| JobPlace { duration: variant.duration.unwrap_or(place.duration), ..place.clone() })
+                        .collect(),
+                    demand: variant.demand.clone(),
+                    tag: Some(variant.tag.clone()),
+                    variants: None,
+                    ..task.clone()
+                };
+
+                let mut single = get_single_from_task(&task, activity_type, is_static_demand, lateness);
+                single.dimens.set_value("variant", variant_idx);
+
+                let problem_job = get_single_job(job, single);
+                job_index.insert(get_variant_job_key(&job.id, variant_idx), problem_job.clone());
+                jobs.push(problem_job);
+            });
+
+            return;
+        }
+
+        let singles = job
+            .pickups
+            .iter()
+            .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand, lateness)))
+            .chain(job.deliveries.iter().flat_map(|tasks| {
+                tasks.iter().map(|task| get_single_from_task(task, "delivery", is_static_demand, lateness))
+            }))
+            .chain(
+                job.replacements.iter().flat_map(|tasks| {
+                    tasks.iter().map(|task| get_single_from_task(task, "replacement", true, lateness))
+                }),
+            )
+            .chain(
+                job.services
+                    .iter()
+                    .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "service", false, lateness))),
+            )
+            .collect::<Vec<_>>();
 
         assert!(!singles.is_empty());
 
         let problem_job = if singles.len() > 1 {
-            get_multi_job(
-                &job.id,
-                job.priority,
-                &job.skills,
-                singles,
-                job.pickups.as_ref().map_or(0, |p| p.len()),
-                random,
-            )
+            get_multi_job(job, singles, job.pickups.as_ref().map_or(0, |p| p.len()), random)
         } else {
-            get_single_job(&job.id, singles.into_iter().next().unwrap(), job.priority, &job.skills)
+            get_single_job(job, singles.into_iter().next().unwrap())
         };
 
         job_index.insert(job.id.clone(), problem_job.clone());
@@ -189,6 +267,10 @@ fn read_conditional_jobs(
             if let Some(reloads) = &shift.reloads {
                 read_reloads(coord_index, job_index, &mut jobs, vehicle, shift_index, reloads);
             }
+
+            if let Some(anchors) = get_shift_anchors(api_problem, shift) {
+                read_anchors(coord_index, job_index, &mut jobs, vehicle, shift_index, anchors);
+            }
         }
     });
 
@@ -259,25 +341,10 @@ fn read_depots(
     shift_index: usize,
     depots: &[VehicleCargoPlace],
 ) -> Vec<Arc<Lock>> {
-    get_cargo_jobs("depot", coord_index, vehicle, shift_index, depots)
+    get_alternative_cargo_jobs("depot", coord_index, vehicle, shift_index, depots)
         .into_iter()
-        .map(|(vehicle_id, _, single)| (vehicle_id, single))
-        .collect_group_by_key(|(vehicle_id, _)| vehicle_id.clone())
-        .into_iter()
-        .map(|(vehicle_id, singles)| {
-            let (places, dimens): (_, Vec<Dimensions>) = singles
-                .into_iter()
-                .map(|(_, single)| {
-                    assert_eq!(single.places.len(), 1);
-                    (single.places.first().cloned().unwrap(), single.dimens)
-                })
-                .unzip();
-
-            let job_id = format!("{}_depot_{}_1", vehicle_id, shift_index);
-            let mut dimens = dimens.first().cloned().unwrap();
-            dimens.set_id(&job_id);
-
-            add_conditional_job(job_index, jobs, job_id.clone(), Single { places, dimens });
+        .map(|(vehicle_id, job_id, single)| {
+            add_conditional_job(job_index, jobs, job_id.clone(), single);
 
             Arc::new(Lock::new(
                 Arc::new(move |actor| {
@@ -298,6 +365,21 @@ fn read_depots(
         .collect()
 }
 
+fn read_anchors(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    anchors: &[VehicleCargoPlace],
+) {
+    get_alternative_cargo_jobs("anchor", coord_index, vehicle, shift_index, anchors).into_iter().for_each(
+        |(_, job_id, single)| {
+            add_conditional_job(job_index, jobs, job_id, single);
+        },
+    );
+}
+
 fn read_reloads(
     coord_index: &CoordIndex,
     job_index: &mut JobIndex,
@@ -306,9 +388,20 @@ fn read_reloads(
     shift_index: usize,
     reloads: &[VehicleCargoPlace],
 ) {
-    get_cargo_jobs("reload", coord_index, vehicle, shift_index, reloads).into_iter().for_each(|(_, job_id, single)| {
-        add_conditional_job(job_index, jobs, job_id, single);
-    });
+    // NOTE reload handling time is a minimum duration of each reload
+    let reloads = reloads
+        .iter()
+        .map(|reload| VehicleCargoPlace {
+            duration: reload.duration.max(vehicle.reload_handling_time.unwrap_or(0.)),
+            ..reload.clone()
+        })
+        .collect::<Vec<_>>();
+
+    get_cargo_jobs("reload", coord_index, vehicle, shift_index, &reloads).into_iter().for_each(
+        |(_, job_id, single)| {
+            add_conditional_job(job_index, jobs, job_id, single);
+        },
+    );
 }
 
 fn get_cargo_jobs(
@@ -345,6 +438,37 @@ fn get_cargo_jobs(
         .collect()
 }
 
+/// Creates one job per vehicle which has cargo places as alternative places, so only one of them is visited.
+fn get_alternative_cargo_jobs(
+    job_type: &str,
+    coord_index: &CoordIndex,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    cargo_places: &[VehicleCargoPlace],
+) -> Vec<(String, String, Single)> {
+    get_cargo_jobs(job_type, coord_index, vehicle, shift_index, cargo_places)
+        .into_iter()
+        .map(|(vehicle_id, _, single)| (vehicle_id, single))
+        .collect_group_by_key(|(vehicle_id, _)| vehicle_id.clone())
+        .into_iter()
+        .map(|(vehicle_id, singles)| {
+            let (places, dimens): (_, Vec<Dimensions>) = singles
+                .into_iter()
+                .map(|(_, single)| {
+                    assert_eq!(single.places.len(), 1);
+                    (single.places.first().cloned().unwrap(), single.dimens)
+                })
+                .unzip();
+
+            let job_id = format!("{}_{}_{}_1", vehicle_id, job_type, shift_index);
+            let mut dimens = dimens.first().cloned().unwrap();
+            dimens.set_id(&job_id);
+
+            (vehicle_id, job_id, Single { places, dimens })
+        })
+        .collect()
+}
+
 fn get_conditional_job(
     coord_index: &CoordIndex,
     vehicle_id: String,
@@ -409,28 +533,39 @@ fn get_single_with_extras(
     single
 }
 
-fn get_single_job(id: &str, single: Single, priority: Option<i32>, skills: &Option<Vec<String>>) -> Job {
-    let mut single = single;
-    single.dimens.set_id(id);
+fn get_task_with_variants(job: &ApiJob) -> Option<(&JobTask, &'static str)> {
+    let tasks = job
+        .pickups
+        .iter()
+        .flat_map(|tasks| tasks.iter().map(|task| (task, "pickup")))
+        .chain(job.deliveries.iter().flat_map(|tasks| tasks.iter().map(|task| (task, "delivery"))))
+        .chain(job.replacements.iter().flat_map(|tasks| tasks.iter().map(|task| (task, "replacement"))))
+        .chain(job.services.iter().flat_map(|tasks| tasks.iter().map(|task| (task, "service"))))
+        .collect::<Vec<_>>();
+
+    match tasks.as_slice() {
+        [(task, activity_type)] if task.variants.as_ref().map_or(false, |variants| !variants.is_empty()) => {
+            Some((*task, *activity_type))
+        }
+        _ => None,
+    }
+}
 
-    add_priority(&mut single.dimens, priority);
-    add_skills(&mut single.dimens, skills);
+fn get_single_job(job: &ApiJob, single: Single) -> Job {
+    let mut single = single;
+    add_job_dimens(&mut single.dimens, job);
 
     Job::Single(Arc::new(single))
 }
 
 fn get_multi_job(
-    id: &str,
-    priority: Option<i32>,
-    skills: &Option<Vec<String>>,
+    job: &ApiJob,
     singles: Vec<Single>,
     deliveries_start_index: usize,
     random: &Arc<dyn Random + Send + Sync>,
 ) -> Job {
     let mut dimens: Dimensions = Default::default();
-    dimens.set_id(id);
-    add_priority(&mut dimens, priority);
-    add_skills(&mut dimens, skills);
+    add_job_dimens(&mut dimens, job);
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -466,18 +601,228 @@ fn add_tag(dimens: &mut Dimensions, tag: &Option<String>) {
     }
 }
 
+fn add_type_durations(dimens: &mut Dimensions, type_durations: &Option<Vec<VehicleTypeDuration>>) {
+    if let Some(type_durations) = type_durations {
+        let type_durations = type_durations
+            .iter()
+            .flat_map(|type_duration| {
+                type_duration.type_ids.iter().map(move |type_id| (type_id.clone(), type_duration.duration))
+            })
+            .collect::<HashMap<_, _>>();
+
+        dimens.set_value("type_durations", type_durations);
+    }
+}
+
+fn add_job_dimens(dimens: &mut Dimensions, job: &ApiJob) {
+    dimens.set_id(job.id.as_str());
+
+    add_priority(dimens, job.priority);
+    add_skills(dimens, &job.skills);
+    add_companions(dimens, &job.companions);
+    add_transfer(dimens, &job.transfer_from);
+    add_service_time_distribution(dimens, &job.service_time_distribution);
+    add_corridor(dimens, &job.corridor);
+    add_crew_size(dimens, job.crew_size);
+    add_workload(dimens, job.workload);
+    add_vehicle_affinity(dimens, &job.vehicle_affinity);
+}
+
 fn add_priority(dimens: &mut Dimensions, priority: Option<i32>) {
     if let Some(priority) = priority {
         dimens.set_value("priority", priority);
     }
 }
 
+fn add_companions(dimens: &mut Dimensions, companions: &Option<Vec<String>>) {
+    if let Some(companions) = companions.as_ref().filter(|companions| !companions.is_empty()) {
+        dimens.set_value("companions", companions.clone());
+    }
+}
+
+fn add_transfer(dimens: &mut Dimensions, transfer_from: &Option<String>) {
+    if let Some(transfer_from) = transfer_from {
+        dimens.set_value("transfer_from", transfer_from.clone());
+    }
+}
+
+fn add_corridor(dimens: &mut Dimensions, corridor: &Option<JobCorridor>) {
+    if let Some(corridor) = corridor {
+        dimens.set_value("corridor", (corridor.id.clone(), corridor.position));
+    }
+}
+
+fn add_crew_size(dimens: &mut Dimensions, crew_size: Option<usize>) {
+    if let Some(crew_size) = crew_size {
+        dimens.set_value("crew_size", crew_size);
+    }
+}
+
+fn add_workload(dimens: &mut Dimensions, workload: Option<f64>) {
+    if let Some(workload) = workload {
+        dimens.set_value("workload", workload);
+    }
+}
+
+fn add_vehicle_affinity(dimens: &mut Dimensions, vehicle_affinity: &Option<String>) {
+    if let Some(vehicle_affinity) = vehicle_affinity {
+        dimens.set_value("vehicle_affinity", vehicle_affinity.clone());
+    }
+}
+
+fn add_service_time_distribution(dimens: &mut Dimensions, distribution: &Option<ServiceTimeDistribution>) {
+    if let Some(distribution) = distribution {
+        let distribution = match *distribution {
+            ServiceTimeDistribution::Normal { mean, std_dev } => DurationDistribution::Normal { mean, std_dev },
+            ServiceTimeDistribution::Uniform { min, max } => DurationDistribution::Uniform { min, max },
+        };
+
+        dimens.set_value(DURATION_DISTRIBUTION_KEY, distribution);
+    }
+}
+
+/// Returns lateness penalty if job has soft time windows.
+fn get_lateness_penalty(api_problem: &ApiProblem, hard_time_window: Option<bool>) -> Option<f64> {
+    let policy = api_problem.config.as_ref().and_then(|config| config.time_windows.as_ref());
+    let is_soft = hard_time_window.map(|is_hard| !is_hard).unwrap_or_else(|| policy.map_or(false, |p| p.soft));
+
+    if is_soft {
+        Some(policy.and_then(|p| p.lateness_penalty).unwrap_or(DEFAULT_LATENESS_PENALTY))
+    } else {
+        None
+    }
+}
+
+/// Relaxes time windows of the job and keeps original ones extended by place grace period to
+/// calculate lateness penalty.
+fn add_soft_time_windows(single: &mut Single, lateness: Option<f64>, graces: &[f64]) {
+    if let Some(penalty) = lateness {
+        let soft_tws = single
+            .places
+            .iter_mut()
+            .zip(graces.iter())
+            .flat_map(|(place, grace)| place.times.iter_mut().map(move |time| (time, *grace)))
+            .filter_map(|(time, grace)| match time {
+                TimeSpan::Window(tw) if tw.end < std::f64::MAX => {
+                    let original = TimeWindow::new(tw.start, tw.end + grace);
+                    tw.end = std::f64::MAX;
+                    Some(original)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if !soft_tws.is_empty() {
+            single.dimens.set_value("soft_tws", soft_tws);
+            single.dimens.set_value("lateness_penalty", penalty);
+        }
+    }
+}
+
+/// Keeps penalties of time windows specified in `times` of job places, so serving the job within
+/// a non-preferred time window can be penalized.
+fn add_time_window_penalties(single: &mut Single, places: &[JobPlace]) {
+    let tw_penalties = single
+        .places
+        .iter()
+        .zip(places.iter())
+        .filter_map(|(place, api_place)| api_place.time_penalties.as_ref().map(|penalties| (place, penalties)))
+        .flat_map(|(place, penalties)| place.times.iter().zip(penalties.iter()))
+        .filter_map(|(time, penalty)| match time {
+            TimeSpan::Window(tw) if *penalty > 0. => Some((tw.clone(), *penalty)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if !tw_penalties.is_empty() {
+        single.dimens.set_value("tw_penalties", tw_penalties);
+    }
+}
+
 fn empty() -> MultiDimLoad {
     MultiDimLoad::default()
 }
 
+/// Returns amount of seconds in a time unit of relative time windows.
+fn get_relative_time_factor(api_problem: &ApiProblem) -> f64 {
+    api_problem
+        .config
+        .as_ref()
+        .and_then(|config| config.time_windows.as_ref())
+        .and_then(|time_windows| time_windows.unit)
+        .map_or(1., |unit| unit.as_seconds())
+}
+
+fn parse_place_times(place: &JobPlace, time_factor: f64) -> Vec<TimeSpan> {
+    let offsets = place.relative_times.iter().flat_map(|offsets| offsets.iter()).map(|offset| {
+        TimeSpan::Offset(TimeOffset::new(*offset.first().unwrap() * time_factor, *offset.last().unwrap() * time_factor))
+    });
+    let openings = place
+        .opening_times
+        .iter()
+        .flat_map(|openings| openings.iter())
+        .map(|opening| TimeSpan::Window(parse_opening_time(opening, time_factor)));
+
+    let skill_windows = get_skill_time_windows(place).into_iter().map(|(tw, _)| TimeSpan::Window(tw));
+
+    if !has_times(&place.times) && (place.relative_times.is_some() || place.opening_times.is_some()) {
+        openings.chain(offsets).chain(skill_windows).collect()
+    } else {
+        parse_times(&place.times).into_iter().chain(openings).chain(offsets).chain(skill_windows).collect()
+    }
+}
+
+/// Returns time windows of job place which require vehicle skills. Time windows which are also
+/// specified in `times` are available for any vehicle, so they are excluded.
+fn get_skill_time_windows(place: &JobPlace) -> Vec<(TimeWindow, HashSet<String>)> {
+    let times = place.times.iter().flat_map(|times| times.iter()).map(|tw| parse_time_window(tw)).collect::<Vec<_>>();
+
+    place
+        .skill_times
+        .iter()
+        .flat_map(|skill_times| skill_times.iter())
+        .flat_map(|skill_times| {
+            let skills = skill_times.skills.iter().cloned().collect::<HashSet<_>>();
+            skill_times.times.iter().map(move |tw| (parse_time_window(tw), skills.clone()))
+        })
+        .filter(|(tw, _)| {
+            !times.iter().any(|other| {
+                compare_floats(other.start, tw.start) == Ordering::Equal
+                    && compare_floats(other.end, tw.end) == Ordering::Equal
+            })
+        })
+        .collect()
+}
+
+fn add_skill_time_windows(single: &mut Single, places: &[JobPlace]) {
+    let skill_tws = places.iter().flat_map(get_skill_time_windows).collect::<Vec<_>>();
+
+    if !skill_tws.is_empty() {
+        single.dimens.set_value("skill_tws", skill_tws);
+    }
+}
+
+/// Converts opening time to a time window which starts at open time and lasts given duration.
+fn parse_opening_time(opening: &OpeningTime, time_factor: f64) -> TimeWindow {
+    let start = parse_time(&opening.open);
+    TimeWindow::new(start, start + opening.duration * time_factor)
+}
+
+fn has_place_times(place: &JobPlace) -> bool {
+    has_times(&place.times)
+        || place.relative_times.is_some()
+        || place.opening_times.is_some()
+        || place.skill_times.is_some()
+}
+
+/// Checks whether time windows are specified: an empty list explicitly marks that there are none.
+fn has_times(times: &Option<Vec<Vec<String>>>) -> bool {
+    times.as_ref().map_or(false, |tws| !tws.is_empty())
+}
+
+/// Parses time windows: when they are omitted or empty, the place can be visited at any time.
 fn parse_times(times: &Option<Vec<Vec<String>>>) -> Vec<TimeSpan> {
-    times.as_ref().map_or(vec![TimeSpan::Window(TimeWindow::max())], |tws| {
+    times.as_ref().filter(|tws| !tws.is_empty()).map_or(vec![TimeSpan::Window(TimeWindow::max())], |tws| {
         tws.iter().map(|tw| TimeSpan::Window(parse_time_window(tw))).collect()
     })
 }
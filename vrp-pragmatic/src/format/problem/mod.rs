@@ -4,5 +4,15 @@
 mod model;
 pub use self::model::*;
 
+mod escalation;
+pub(crate) use self::escalation::escalate_priorities;
+
+mod horizon;
+pub(crate) use self::horizon::{expand_horizon, get_shift_day, is_vehicle_working_day};
+
 mod reader;
-pub use self::reader::PragmaticProblem;
+pub use self::reader::{
+    create_approx_matrices, get_auto_weights, with_attribute_rules, with_auto_weights, with_history_affinity,
+    PragmaticProblem,
+};
+pub(crate) use self::reader::{get_lateness_fn, get_revenue_job_filter, get_shift_end_fn, MONETARY_RATES_KEY};
@@ -20,9 +20,12 @@ pub enum RelationType {
     Sequence,
     /// Relation type which locks jobs in strict order, no insertions in between are allowed.
     Strict,
+    /// Relation type which prefers serving jobs in specific order when they are assigned to the
+    /// same tour: violation of the order is penalized, but allowed.
+    SoftPrecedence,
 }
 
-/// Relation is the way to lock specific jobs to specific vehicles.
+/// Relation is the way to lock specific jobs to specific vehicles or to specify their preferred order.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Relation {
@@ -31,15 +34,20 @@ pub struct Relation {
     pub type_field: RelationType,
     /// List of job ids.
     pub jobs: Vec<String>,
-    /// Vehicle id.
-    pub vehicle_id: String,
+    /// Vehicle id. Required by all relation types except soft precedence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_id: Option<String>,
     /// Vehicle shift index.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shift_index: Option<usize>,
+    /// A penalty applied per each violated order of two jobs in soft precedence relation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penalty: Option<f64>,
 }
 
 /// Specifies a place for sub job.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct JobPlace {
     /// A job place location.
     pub location: Location,
@@ -48,10 +56,48 @@ pub struct JobPlace {
     /// A list of job place time windows with time specified in RFC3339 format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub times: Option<Vec<Vec<String>>>,
+    /// A list of job place time windows with time specified in seconds relative to the start
+    /// time of the vehicle which serves the job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relative_times: Option<Vec<Vec<f64>>>,
+    /// A list of job place time windows specified by open time and duration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opening_times: Option<Vec<OpeningTime>>,
+    /// A grace period in seconds (or in time windows unit) after the end of each place time window:
+    /// late arrival within it is not penalized when time windows are soft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grace: Option<f64>,
+    /// A list of penalties per each time window specified in `times`: serving the job within a
+    /// time window with non-zero penalty costs it, so windows can be ranked by preference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_penalties: Option<Vec<f64>>,
+    /// A list of time windows which are available only when the job is served by vehicle with
+    /// required skills, e.g. premium service window offered by certified technician.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_times: Option<Vec<SkillTimeWindows>>,
+}
+
+/// Specifies time windows which can be used only by vehicle with all given skills.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct SkillTimeWindows {
+    /// A list of skills which vehicle should have.
+    pub skills: Vec<String>,
+    /// A list of time windows with time specified in RFC3339 format.
+    pub times: Vec<Vec<String>>,
+}
+
+/// Specifies a time window as an open time and a duration after it, e.g. business hours.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct OpeningTime {
+    /// An open time in RFC3339 format.
+    pub open: String,
+    /// A duration in seconds (or in time windows unit) after open time.
+    pub duration: f64,
 }
 
 /// Specifies a job task.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct JobTask {
     /// A list of possible places where given task can be performed.
     pub places: Vec<JobPlace>,
@@ -61,6 +107,36 @@ pub struct JobTask {
     /// An tag which will be propagated back within corresponding activity in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// A list of service durations specific for vehicle types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_durations: Option<Vec<VehicleTypeDuration>>,
+    /// A list of alternative ways to serve the task: exactly one of them is used when job is assigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variants: Option<Vec<JobTaskVariant>>,
+}
+
+/// Specifies an alternative demand and service duration of job task.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobTaskVariant {
+    /// A variant demand used instead of task demand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub demand: Option<Vec<i32>>,
+    /// A variant service duration used instead of job place durations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+    /// A variant tag which is propagated back within corresponding activity in solution.
+    pub tag: String,
+}
+
+/// Specifies a service duration of job task when it is served by vehicle of given types.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleTypeDuration {
+    /// A list of vehicle type ids.
+    pub type_ids: Vec<String>,
+    /// A service duration used instead of job place duration.
+    pub duration: f64,
 }
 
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
@@ -68,6 +144,7 @@ pub struct JobTask {
 /// * all of them should be completed or none of them.
 /// * all pickups must be completed before any of deliveries.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Job {
     /// A job id.
     pub id: String,
@@ -95,6 +172,79 @@ pub struct Job {
     /// A set of skills required to serve a job.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<Vec<String>>,
+
+    /// Overrides time window policy specified in config: true makes job time windows hard,
+    /// false - soft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hard_time_window: Option<bool>,
+
+    /// A list of companion job ids: job can be assigned only to the tour which serves at least
+    /// one of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub companions: Option<Vec<String>>,
+
+    /// An id of the job which brings cargo to the hub where this job picks it up, possibly by
+    /// another vehicle: job can be assigned only if that job is served by another tour and its
+    /// pickups at the hub are scheduled after the other job's drop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_from: Option<String>,
+
+    /// A random distribution of job service time used to estimate how often solution schedule
+    /// holds. It does not affect optimization: place durations are used instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_time_distribution: Option<ServiceTimeDistribution>,
+
+    /// A service corridor which job belongs to, e.g. a road with linear assets: jobs of the same
+    /// corridor are served in ascending order of their positions along it on any tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corridor: Option<JobCorridor>,
+
+    /// A size of crew required to serve a job, e.g. two persons to carry heavy items: job can be
+    /// served only by vehicle with the same or bigger crew. Default is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crew_size: Option<usize>,
+    /// A workload weight of the job used by `balance-activities` objective instead of activity count,
+    /// e.g. heavy job can count as two ordinary ones. Default is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workload: Option<f64>,
+    /// An id of the vehicle which job has affinity to, e.g. one which served it historically:
+    /// `history-affinity` objective prefers to keep the job on that vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_affinity: Option<String>,
+    /// Amount of planning cycles the job has already waited without being served. Job priority is
+    /// escalated with age according to `config.priorityEscalation`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<usize>,
+}
+
+/// Specifies job membership in a service corridor.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobCorridor {
+    /// A corridor id.
+    pub id: String,
+    /// A job position along the corridor, e.g. distance from its start.
+    pub position: f64,
+}
+
+/// Specifies a random distribution of job service time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ServiceTimeDistribution {
+    /// A normal distribution.
+    #[serde(rename_all = "camelCase")]
+    Normal {
+        /// A mean value in seconds.
+        mean: f64,
+        /// A standard deviation in seconds.
+        std_dev: f64,
+    },
+    /// A uniform distribution.
+    Uniform {
+        /// A minimum value in seconds.
+        min: f64,
+        /// A maximum value in seconds.
+        max: f64,
+    },
 }
 
 /// A plan specifies work which has to be done.
@@ -113,6 +263,7 @@ pub struct Plan {
 
 /// Specifies vehicle costs.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VehicleCosts {
     /// Fixed is cost of vehicle usage per tour.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -123,10 +274,35 @@ pub struct VehicleCosts {
 
     /// Cost per time unit.
     pub time: f64,
+
+    /// Distance tiers: cost per distance unit which replaces `distance` cost when total tour
+    /// distance exceeds tier threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_tiers: Option<Vec<DistanceTier>>,
+
+    /// Maximum daily cost: fixed and variable costs of a tour are not charged above it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_daily_cost: Option<f64>,
+
+    /// Load distance costs: an extra cost per distance unit and per unit of each capacity dimension
+    /// carried by vehicle on the leg, e.g. fuel needed to move heavy load.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_distance: Option<Vec<f64>>,
+}
+
+/// Specifies distance cost tier.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DistanceTier {
+    /// A total tour distance after which tier is applied.
+    pub threshold: f64,
+
+    /// Cost per distance unit driven beyond threshold.
+    pub distance: f64,
 }
 
 /// Specifies vehicle shift start.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ShiftStart {
     /// Earliest possible departure date time.
     pub earliest: String,
@@ -139,6 +315,17 @@ pub struct ShiftStart {
 
     /// Shift start location.
     pub location: Location,
+
+    /// Alternative shift start locations, e.g. other depots. If specified, the vehicle starts
+    /// its shift from the one of them or `location`, whichever is better for its tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternatives: Option<Vec<Location>>,
+
+    /// A dock slot: time window in which the vehicle has to depart from its start location, e.g.
+    /// an assigned slot at a shared loading dock. Departure has to fit both the slot and
+    /// `earliest`/`latest`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dock_slot: Option<Vec<String>>,
 }
 
 /// Specifies vehicle shift end.
@@ -153,6 +340,11 @@ pub struct ShiftEnd {
 
     /// Shift end location.
     pub location: Location,
+
+    /// Max duration of overtime: vehicle can arrive at shift end later than latest time, but not
+    /// more than by this value. No overtime is allowed when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_overtime: Option<f64>,
 }
 
 /// Specifies vehicle shift.
@@ -178,6 +370,11 @@ pub struct VehicleShift {
     /// unloaded during single tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reloads: Option<Vec<VehicleCargoPlace>>,
+
+    /// Vehicle anchors: places, one of which has to be visited exactly once by every non empty
+    /// tour, e.g. a weigh station. If omitted, anchors from configuration are used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchors: Option<Vec<VehicleCargoPlace>>,
 }
 
 /// Specifies a place where vehicle can load or unload cargo.
@@ -198,6 +395,16 @@ pub struct VehicleCargoPlace {
     pub tag: Option<String>,
 }
 
+/// Vehicle aggregate capacity: a shared cap on weighted sum of all capacity dimensions.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateCapacity {
+    /// Weights of capacity dimensions, the same size as vehicle capacity.
+    pub weights: Vec<f64>,
+    /// Max value of weighted sum of vehicle load.
+    pub limit: f64,
+}
+
 /// Vehicle limits.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -216,6 +423,31 @@ pub struct VehicleLimits {
     /// No area restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_areas: Option<Vec<AreaLimit>>,
+
+    /// Max amount of jobs with time windows per shift/tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_time_window_jobs: Option<usize>,
+
+    /// Max amount of reloads per shift/tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_reloads: Option<usize>,
+
+    /// Max amount of jobs with time windows served within any rolling hour of shift/tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_hourly_time_window_jobs: Option<usize>,
+
+    /// Max amount of activities served after their soft time window end per shift/tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_late_stops: Option<usize>,
+
+    /// Max distance driven from shift start to the first job per shift/tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_first_leg_distance: Option<f64>,
 }
 
 /// Specifies area limit.
@@ -275,6 +507,10 @@ pub struct VehicleType {
     /// Vehicle capacity.
     pub capacity: Vec<i32>,
 
+    /// Vehicle aggregate capacity which limits weighted sum of all capacity dimensions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_capacity: Option<AggregateCapacity>,
+
     /// Vehicle skills.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<Vec<String>>,
@@ -282,6 +518,80 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle range: max distance which vehicle can travel per shift/tour as there is no way
+    /// to refuel or recharge it on the route. No range restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<f64>,
+
+    /// Vehicle consumption model used to report fuel and emissions of its tours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumption: Option<VehicleConsumption>,
+
+    /// Vehicle crew size: amount of persons staffed on vehicle. Default is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crew_size: Option<usize>,
+
+    /// Vehicle loading discipline which restricts order of deliveries of pickup and delivery jobs.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loading_discipline: Option<VehicleLoadingDiscipline>,
+
+    /// A minimum time spent at depot on each reload: reload duration is extended to it when shorter.
+    /// No minimum when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reload_handling_time: Option<f64>,
+
+    /// A maneuvering time added to duration of each stop, e.g. to approximate turns of long trucks.
+    /// No maneuvering time when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maneuvering_time: Option<f64>,
+
+    /// A model of capacity decay over time since tour departure, e.g. for perishable goods.
+    /// Capacity does not change when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity_decay: Option<VehicleCapacityDecay>,
+}
+
+/// Specifies the order in which cargo of pickup and delivery jobs can be unloaded from vehicle.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VehicleLoadingDiscipline {
+    /// Last in, first out: the last loaded job has to be delivered first, e.g. single rear door.
+    Lifo,
+    /// First in, first out: the first loaded job has to be delivered first.
+    Fifo,
+}
+
+/// Specifies how usable vehicle capacity decays with time passed since tour departure.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum VehicleCapacityDecay {
+    /// Capacity decreases linearly from full at departure and is fully lost after given duration.
+    Linear {
+        /// A duration in seconds after which no capacity is left.
+        duration: f64,
+    },
+}
+
+/// Vehicle consumption model: fuel consumed per distance unit is a sum of base consumption,
+/// load and speed dependent parts.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleConsumption {
+    /// Fuel consumed per distance unit by empty vehicle.
+    pub distance: f64,
+
+    /// Extra fuel consumed per distance unit per unit of each capacity dimension carried.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load: Option<Vec<f64>>,
+
+    /// Extra fuel consumed per distance unit per unit of average leg speed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f64>,
+
+    /// Emissions, e.g. carbon, produced per fuel unit.
+    pub emission_factor: f64,
 }
 
 /// Specifies routing profile.
@@ -313,9 +623,193 @@ pub struct Fleet {
 
 // region Configuration
 
-/// Specifies extra configuration (reserved for future).
+/// Specifies extra configuration.
+#[derive(Clone, Default, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Specifies time window policy applied to all jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_windows: Option<TimeWindowsConfig>,
+
+    /// Specifies depot inventory: max total delivery demand which can be dispatched from
+    /// depot by all vehicles, per capacity dimension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depot_inventory: Option<Vec<i32>>,
+
+    /// Specifies anchors applied to all vehicle shifts which have no anchors defined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchors: Option<Vec<VehicleCargoPlace>>,
+
+    /// Specifies how jobs with the same location are served. If omitted, the solver is free to
+    /// visit the same location several times, consecutive activities there are reported as one stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coincident_jobs: Option<CoincidentJobsPolicy>,
+
+    /// Specifies receiving rates of locations shared by multiple tours, e.g. warehouses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiving_rates: Option<Vec<ReceivingRate>>,
+
+    /// Specifies size of physical vehicle pool shared by all vehicle shifts: max amount of tours
+    /// which can be active at the same time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_pool: Option<usize>,
+
+    /// Specifies coordinate system of locations which is used to approximate routing matrix.
+    /// Default is geographic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coordinate_system: Option<CoordinateSystem>,
+
+    /// Specifies multi day planning horizon. If omitted, vehicle shifts are used as they are.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizon: Option<PlanningHorizon>,
+
+    /// Specifies how priority of jobs which have waited several planning cycles is escalated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_escalation: Option<PriorityEscalation>,
+
+    /// Specifies whether each activity in solution is annotated with cumulative distance and
+    /// duration from route start. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cumulative_stats: Option<bool>,
+
+    /// Specifies rules over job and vehicle attributes which should hold for each assignment, e.g.
+    /// `!(job.tag == 'fragile' && vehicle.type_id == 'construction')`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<String>>,
+
+    /// Specifies whether weights of default objectives are adapted to problem size. It is ignored
+    /// when objectives are specified in the problem. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_weights: Option<bool>,
+}
+
+/// Specifies how job priority is escalated with job age: each `cycles` planning cycles waited
+/// make the job one priority level more important.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriorityEscalation {
+    /// Amount of planning cycles after which job priority is escalated by one level.
+    pub cycles: usize,
+
+    /// The most important priority which can be reached by escalation. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_priority: Option<i32>,
+}
+
+/// Specifies multi day planning horizon: shifts of each vehicle type are repeated every day when
+/// the type is available, so each job is assigned to a day and a tour within that day.
 #[derive(Clone, Deserialize, Debug, Serialize)]
-pub struct Config {}
+#[serde(rename_all = "camelCase")]
+pub struct PlanningHorizon {
+    /// Amount of days in the horizon. Vehicle shifts specify the first day.
+    pub days: usize,
+
+    /// Specifies vehicle types available on specific days. All vehicle types are available
+    /// on days which are not listed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fleets: Option<Vec<DayFleet>>,
+
+    /// Specifies days when specific vehicles work, e.g. part-time drivers. Vehicles which are not
+    /// listed work on all days when their vehicle type is available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicles: Option<Vec<VehicleDays>>,
+}
+
+/// Specifies vehicle types available on a day of planning horizon.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DayFleet {
+    /// A day index, starting from zero.
+    pub day: usize,
+
+    /// Ids of vehicle types available on the day.
+    pub type_ids: Vec<String>,
+}
+
+/// Specifies days of planning horizon when a vehicle works.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleDays {
+    /// A vehicle id.
+    pub vehicle_id: String,
+
+    /// Day indices, starting from zero, when the vehicle works.
+    pub days: Vec<usize>,
+}
+
+/// Specifies coordinate system of locations.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CoordinateSystem {
+    /// Coordinates are latitude and longitude in degrees: distances are calculated using
+    /// haversine formula.
+    Geographic,
+    /// Coordinates are projected, e.g. UTM, where `lat` is northing and `lng` is easting in
+    /// meters: distances are calculated as euclidean.
+    Projected,
+}
+
+/// Specifies receiving rate of a location: how often different tours can arrive there.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceivingRate {
+    /// A location with limited receiving rate.
+    pub location: Location,
+
+    /// A minimum time between arrivals of different tours at the location.
+    pub interval: f64,
+}
+
+/// Specifies time window policy.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeWindowsConfig {
+    /// When set to true, job time windows are soft: late service is allowed, but penalized.
+    #[serde(default)]
+    pub soft: bool,
+
+    /// A penalty applied per time unit of lateness.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness_penalty: Option<f64>,
+
+    /// A unit of relative job time windows and opening time durations. Default is seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<TimeUnit>,
+}
+
+/// Specifies a time unit.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeUnit {
+    /// Values are specified in seconds.
+    Seconds,
+    /// Values are specified in minutes.
+    Minutes,
+    /// Values are specified in hours.
+    Hours,
+}
+
+impl TimeUnit {
+    /// Returns amount of seconds in one unit.
+    pub fn as_seconds(&self) -> f64 {
+        match self {
+            TimeUnit::Seconds => 1.,
+            TimeUnit::Minutes => 60.,
+            TimeUnit::Hours => 3600.,
+        }
+    }
+}
+
+/// Specifies how jobs with the same location are served.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CoincidentJobsPolicy {
+    /// Jobs at the same location are served within one stop of the tour: travel is shared and
+    /// service durations are summed up.
+    Combined,
+    /// Each job is reported as a separate stop, even if previous one has the same location.
+    Separate,
+}
 
 // endregion
 
@@ -323,6 +817,7 @@ pub struct Config {}
 
 /// Specifies a group of objective functions.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Objectives {
     /// A list of primary objective functions. An accepted solution should not
     /// be worse of any of these.
@@ -331,6 +826,10 @@ pub struct Objectives {
     /// by the secondary objective if it improves the primary one.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub secondary: Option<Vec<Objective>>,
+    /// A list of tie-break objective functions. They are used only to order solutions
+    /// which are equal by primary and secondary objectives.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tie_break: Option<Vec<Objective>>,
 }
 
 /// Specifies objective function types.
@@ -349,6 +848,62 @@ pub enum Objective {
     #[serde(rename(deserialize = "maximize-tours", serialize = "maximize-tours"))]
     MaximizeTours,
 
+    /// An objective to keep total tour amount close to the target.
+    #[serde(rename(deserialize = "target-tours", serialize = "target-tours"))]
+    TargetTours {
+        /// A desired amount of tours.
+        target: usize,
+    },
+
+    /// An objective to minimize amount of tours which duration exceeds given threshold.
+    #[serde(rename(deserialize = "minimize-long-tours", serialize = "minimize-long-tours"))]
+    MinimizeLongTours {
+        /// A tour duration threshold.
+        threshold: f64,
+    },
+
+    /// An objective to minimize amount of tours which end after regular shift end, e.g. amount of
+    /// drivers doing overtime.
+    #[serde(rename(deserialize = "minimize-overtime-tours", serialize = "minimize-overtime-tours"))]
+    MinimizeOvertimeTours,
+
+    /// An objective to minimize spread of each tour measured as average distance between its stops.
+    #[serde(rename(deserialize = "compact-tours", serialize = "compact-tours"))]
+    CompactTours,
+
+    /// An objective to minimize distance driven without any load.
+    #[serde(rename(deserialize = "minimize-deadhead", serialize = "minimize-deadhead"))]
+    MinimizeDeadhead,
+
+    /// An objective to minimize lateness of jobs with soft time windows weighted by job priority.
+    #[serde(rename(deserialize = "minimize-weighted-lateness", serialize = "minimize-weighted-lateness"))]
+    MinimizeWeightedLateness,
+
+    /// An objective to minimize geographic overlap between tours measured on square grid.
+    #[serde(rename(deserialize = "minimize-overlap", serialize = "minimize-overlap"))]
+    MinimizeOverlap {
+        /// A size of grid cell side in meters.
+        resolution: f64,
+    },
+
+    /// An objective to minimize amount of self-intersections of tours drawn as polylines through
+    /// their stops.
+    #[serde(rename(deserialize = "minimize-intersections", serialize = "minimize-intersections"))]
+    MinimizeIntersections,
+
+    /// An objective to minimize variance of estimated service start times of all jobs, so customers
+    /// are served at similar times regardless of their tours.
+    #[serde(rename(deserialize = "minimize-eta-variance", serialize = "minimize-eta-variance"))]
+    MinimizeEtaVariance,
+
+    /// An objective to minimize total monetary cost: a sum of distance, driver time, overtime and
+    /// fixed vehicle costs together with lost revenue of unassigned jobs.
+    #[serde(rename(deserialize = "minimize-monetary-cost", serialize = "minimize-monetary-cost"))]
+    MinimizeMonetaryCost {
+        /// Rates used to convert solution metrics into money.
+        rates: MonetaryRates,
+    },
+
     /// An objective to minimize amount of unassigned jobs.
     #[serde(rename(deserialize = "minimize-unassigned", serialize = "minimize-unassigned"))]
     MinimizeUnassignedJobs,
@@ -387,6 +942,64 @@ pub enum Objective {
         #[serde(skip_serializing_if = "Option::is_none")]
         options: Option<BalanceOptions>,
     },
+
+    /// An objective to minimize difference between the highest and the lowest amount of stops
+    /// across all tours.
+    #[serde(rename(deserialize = "balance-stops", serialize = "balance-stops"))]
+    BalanceStops,
+
+    /// An objective to minimize amount of jobs served by other vehicles than ones specified in
+    /// their `vehicleAffinity` property, e.g. to keep historical job assignments.
+    #[serde(rename(deserialize = "history-affinity", serialize = "history-affinity"))]
+    HistoryAffinity,
+
+    /// An objective which combines several objectives into one using weighted sum of their values.
+    #[serde(rename(deserialize = "weighted-sum", serialize = "weighted-sum"))]
+    WeightedSum {
+        /// Objectives with their weights.
+        objectives: Vec<WeightedObjective>,
+        /// Specifies whether objective values are normalized by their values of initial solution
+        /// before weighting. Default is false.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        normalize: Option<bool>,
+    },
+}
+
+/// Specifies an objective with its weight inside weighted sum objective.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct WeightedObjective {
+    /// An objective function.
+    pub objective: Objective,
+    /// A weight of objective function.
+    pub weight: f64,
+}
+
+/// Specifies rates of monetary cost objective. Rates which are not specified are zero.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct MonetaryRates {
+    /// A cost per distance unit driven.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+
+    /// A cost per second of driver's work: from tour start till tour end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<f64>,
+
+    /// An extra cost per second of work after regular shift end, see `maxOvertime` of shift end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overtime: Option<f64>,
+
+    /// A fixed cost of each used vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed: Option<f64>,
+
+    /// A lost revenue of each unassigned job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unassigned: Option<f64>,
+
+    /// A lost revenue of each job served after its time window end, see soft time windows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub late: Option<f64>,
 }
 
 /// Specifies balance objective options. At the moment, it uses coefficient of variation as
@@ -446,6 +1059,23 @@ pub struct Matrix {
     /// Error codes to mark unreachable locations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_codes: Option<Vec<i64>>,
+
+    /// A sentinel value which marks unreachable cells in travel times or distances, e.g. when
+    /// routing engine encodes missing route between islands as `-1` or a huge value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unreachable_value: Option<i64>,
+
+    /// A default travel time used for missing cells marked by negative value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_travel_time: Option<i64>,
+
+    /// A default distance used for missing cells marked by negative value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_distance: Option<i64>,
+
+    /// A unit of travel times. Default is seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_unit: Option<TimeUnit>,
 }
 
 // endregion
@@ -461,6 +1091,35 @@ pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Vec
     })
 }
 
+/// Deserializes problem from separate json files with fleet and jobs. Fleet file has the same
+/// format as `fleet` property of the problem. Jobs file is the problem without `fleet` property:
+/// it specifies `plan` and, optionally, `objectives` and `config`.
+pub fn deserialize_split_problem<F: Read, J: Read>(
+    fleet: BufReader<F>,
+    jobs: BufReader<J>,
+) -> Result<Problem, Vec<FormatError>> {
+    /// A problem definition without fleet.
+    #[derive(Deserialize)]
+    struct JobsInput {
+        plan: Plan,
+        objectives: Option<Objectives>,
+        config: Option<Config>,
+    }
+
+    let create_error = |input: &str, err: serde_json::Error| {
+        vec![FormatError::new(
+            "E0000".to_string(),
+            "cannot deserialize problem".to_string(),
+            format!("check input {} json: '{}'", input, err),
+        )]
+    };
+
+    let fleet: Fleet = serde_json::from_reader(fleet).map_err(|err| create_error("fleet", err))?;
+    let jobs: JobsInput = serde_json::from_reader(jobs).map_err(|err| create_error("jobs", err))?;
+
+    Ok(Problem { plan: jobs.plan, fleet, objectives: jobs.objectives, config: jobs.config })
+}
+
 /// Deserializes routing matrix in json format from `BufReader`.
 pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, Vec<FormatError>> {
     serde_json::from_reader(reader).map_err(|err| {
@@ -472,6 +1131,63 @@ pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, Vec<F
     })
 }
 
+/// Deserializes routing matrices from separate json files with distances and travel times.
+/// Each file has the same format as routing matrix, but specifies only one of `distances` or
+/// `travelTimes` properties. Files are paired by their `profile` and `timestamp` properties.
+pub fn deserialize_split_matrices<R: Read>(
+    distances: Vec<BufReader<R>>,
+    durations: Vec<BufReader<R>>,
+) -> Result<Vec<Matrix>, Vec<FormatError>> {
+    let create_error =
+        |action: String| vec![FormatError::new("E0001".to_string(), "cannot deserialize matrix".to_string(), action)];
+    let read_all = |readers: Vec<BufReader<R>>| {
+        readers
+            .into_iter()
+            .map(|reader| {
+                serde_json::from_reader::<_, serde_json::Map<String, serde_json::Value>>(reader)
+                    .map_err(|err| create_error(format!("check input json: '{}'", err)))
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let get_key = |matrix: &serde_json::Map<String, serde_json::Value>| {
+        (matrix.get("profile").cloned(), matrix.get("timestamp").cloned())
+    };
+
+    let distances = read_all(distances)?;
+    let mut durations = read_all(durations)?;
+
+    if distances.len() != durations.len() {
+        return Err(create_error(format!(
+            "specify the same amount of distance and duration files, got: {} vs {}",
+            distances.len(),
+            durations.len()
+        )));
+    }
+
+    distances
+        .into_iter()
+        .map(|distance| {
+            let key = get_key(&distance);
+            let idx = durations
+                .iter()
+                .position(|duration| get_key(duration) == key)
+                .ok_or_else(|| create_error(format!("cannot find durations for profile and timestamp: '{:?}'", key)))?;
+
+            let mut matrix = durations.swap_remove(idx);
+            distance.into_iter().for_each(|(name, value)| {
+                if name == "distances" {
+                    matrix.insert(name, value);
+                } else {
+                    matrix.entry(name).or_insert(value);
+                }
+            });
+
+            serde_json::from_value(serde_json::Value::Object(matrix))
+                .map_err(|err| create_error(format!("check input json: '{}'", err)))
+        })
+        .collect()
+}
+
 /// Deserializes json list of locations from `BufReader`.
 pub fn deserialize_locations<R: Read>(reader: BufReader<R>) -> Result<Vec<Location>, Vec<FormatError>> {
     serde_json::from_reader(reader).map_err(|err| {
@@ -487,3 +1203,8 @@ pub fn deserialize_locations<R: Read>(reader: BufReader<R>) -> Result<Vec<Locati
 pub fn serialize_problem<W: Write>(writer: BufWriter<W>, problem: &Problem) -> Result<(), Error> {
     serde_json::to_writer_pretty(writer, problem).map_err(Error::from)
 }
+
+/// Serializes routing `matrix` in json to `writer`.
+pub fn serialize_matrix<W: Write>(writer: BufWriter<W>, matrix: &Matrix) -> Result<(), Error> {
+    serde_json::to_writer_pretty(writer, matrix).map_err(Error::from)
+}
@@ -1,68 +1,510 @@
-use crate::format::problem::reader::{ApiProblem, ProblemProperties};
-use crate::format::problem::BalanceOptions;
+use crate::format::coord_index::CoordIndex;
+use crate::format::problem::reader::{get_lateness, ApiProblem, ProblemProperties};
 use crate::format::problem::Objective::*;
+use crate::format::problem::{BalanceOptions, Config, MonetaryRates as ApiMonetaryRates, Objective, Objectives};
+use crate::format::Location as ApiLocation;
+use std::collections::HashMap;
 use std::sync::Arc;
-use vrp_core::construction::constraints::{ConstraintPipeline, FleetUsageConstraintModule};
-use vrp_core::models::common::{MultiDimLoad, SingleDimLoad};
-use vrp_core::models::problem::{ObjectiveCost, TargetConstraint, TargetObjective};
+use vrp_core::construction::constraints::{ConstraintPipeline, FleetUsageConstraintModule, LatenessFunc};
+use vrp_core::models::common::{
+    Cost, Dimensions, IdDimension, Location, MultiDimLoad, SingleDimLoad, Timestamp, ValueDimension,
+};
+use vrp_core::models::problem::{
+    ActivityCost, Fleet, Job, Jobs, Multi, ObjectiveCost, TargetConstraint, TargetObjective, TransportCost,
+};
+use vrp_core::models::solution::Activity;
 use vrp_core::solver::objectives::*;
+use vrp_core::utils::compare_floats;
 
-pub fn create_objective(
+pub(crate) fn create_objective(
     api_problem: &ApiProblem,
     constraint: &mut ConstraintPipeline,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    coord_index: Arc<CoordIndex>,
     props: &ProblemProperties,
+    scales: &ObjectiveScales,
 ) -> Arc<ObjectiveCost> {
     Arc::new(if let Some(objectives) = &api_problem.objectives {
-        let mut map_objectives = |objectives: &Vec<_>| {
-            let mut core_objectives: Vec<TargetObjective> = vec![];
-            objectives.iter().for_each(|objective| match objective {
-                MinimizeCost => core_objectives.push(Box::new(TotalTransportCost::default())),
-                MinimizeTours => {
-                    constraint.add_module(Box::new(FleetUsageConstraintModule::new_minimized()));
-                    core_objectives.push(Box::new(TotalRoutes::new_minimized()))
-                }
-                MaximizeTours => {
-                    constraint.add_module(Box::new(FleetUsageConstraintModule::new_maximized()));
-                    core_objectives.push(Box::new(TotalRoutes::new_maximized()))
-                }
-                MinimizeUnassignedJobs => core_objectives.push(Box::new(TotalUnassignedJobs::default())),
-                BalanceMaxLoad { options } => {
-                    let (module, objective) = get_load_balance(props, options);
-                    constraint.add_module(module);
-                    core_objectives.push(objective);
-                }
-                BalanceActivities { options } => {
-                    let (threshold, tolerance) = unwrap_options(options);
-                    let (module, objective) = WorkBalance::new_activity_balanced(threshold, tolerance);
-                    constraint.add_module(module);
-                    core_objectives.push(objective);
-                }
-                BalanceDistance { options } => {
-                    let (threshold, tolerance) = unwrap_options(options);
-                    let (module, objective) = WorkBalance::new_distance_balanced(threshold, tolerance);
-                    constraint.add_module(module);
-                    core_objectives.push(objective);
-                }
-                BalanceDuration { options } => {
-                    let (threshold, tolerance) = unwrap_options(options);
-                    let (module, objective) = WorkBalance::new_duration_balanced(threshold, tolerance);
-                    constraint.add_module(module);
-                    core_objectives.push(objective);
-                }
-            });
-            core_objectives
+        let map_objectives = |objectives: &[Objective], constraint: &mut ConstraintPipeline| {
+            map_objectives(objectives, constraint, &activity, &transport, &coord_index, props, scales)
         };
 
-        let primary_objectives = map_objectives(&objectives.primary);
-        let secondary_objectives = map_objectives(&objectives.secondary.clone().unwrap_or_else(Vec::new));
+        let primary_objectives = map_objectives(&objectives.primary, constraint);
+        let secondary_objectives = map_objectives(&objectives.secondary.clone().unwrap_or_else(Vec::new), constraint);
+        // NOTE tie-break objectives should not guide insertion heuristic, so their modules are ignored
+        let tie_break_objectives =
+            map_objectives(&objectives.tie_break.clone().unwrap_or_default(), &mut ConstraintPipeline::default());
+
+        let objective =
+            ObjectiveCost::new(primary_objectives, secondary_objectives).with_tie_break(tie_break_objectives);
 
-        ObjectiveCost::new(primary_objectives, secondary_objectives)
+        // NOTE initial phase minimizes amount of tours, so it makes no sense when they are maximized or targeted
+        let has_max_tours = objectives
+            .primary
+            .iter()
+            .chain(objectives.secondary.iter().flat_map(|objectives| objectives.iter()))
+            .any(|objective| matches!(objective, MaximizeTours | TargetTours { .. }));
+
+        if has_max_tours {
+            objective
+        } else {
+            objective
+                .with_initial_phase(vec![Box::new(get_unassigned_jobs(props)), Box::new(TotalRoutes::new_minimized())])
+        }
     } else {
         constraint.add_module(Box::new(FleetUsageConstraintModule::new_minimized()));
-        ObjectiveCost::default()
+
+        let is_auto_weights = api_problem.config.as_ref().and_then(|config| config.auto_weights).unwrap_or(false);
+
+        if is_auto_weights {
+            // NOTE keeps default objectives, but tours are traded off against cost with size dependent weights
+            let (tours_weight, cost_weight) = get_auto_weights(api_problem);
+            let tours_and_cost = vrp_core::solver::objectives::WeightedSum::new_normalized(
+                vec![
+                    (Box::new(TotalRoutes::default()), tours_weight),
+                    (Box::new(TotalTransportCost::default()), cost_weight),
+                ],
+                vec![scales.get(&MinimizeTours), scales.get(&MinimizeCost)],
+            );
+
+            ObjectiveCost::new(vec![Box::new(get_unassigned_jobs(props))], vec![Box::new(tours_and_cost)])
+                .with_initial_phase(vec![Box::new(get_unassigned_jobs(props)), Box::new(TotalRoutes::default())])
+        } else if props.has_priorities {
+            ObjectiveCost::new(
+                vec![Box::new(get_unassigned_jobs(props)), Box::new(TotalRoutes::default())],
+                vec![Box::new(TotalTransportCost::default())],
+            )
+            .with_initial_phase(vec![Box::new(get_unassigned_jobs(props)), Box::new(TotalRoutes::default())])
+        } else {
+            ObjectiveCost::default()
+        }
     })
 }
 
+/// Returns weights of tour amount and cost objectives used by auto weighting mode. Problem size is
+/// the largest of job and vehicle amounts: the smaller the problem, the more important each saved
+/// tour is, so tour weight goes linearly from 10 for problems up to 50 jobs down to 1 for problems
+/// with 500 jobs or more. Cost weight is always 1.
+pub fn get_auto_weights(api_problem: &ApiProblem) -> (f64, f64) {
+    const SMALL_PROBLEM_SIZE: f64 = 50.;
+    const LARGE_PROBLEM_SIZE: f64 = 500.;
+    const MAX_TOURS_WEIGHT: f64 = 10.;
+
+    let jobs = api_problem.plan.jobs.len();
+    let vehicles = api_problem.fleet.vehicles.iter().map(|vehicle| vehicle.vehicle_ids.len()).sum::<usize>();
+
+    let size = (jobs.max(vehicles) as f64).clamp(SMALL_PROBLEM_SIZE, LARGE_PROBLEM_SIZE);
+    let ratio = (LARGE_PROBLEM_SIZE - size) / (LARGE_PROBLEM_SIZE - SMALL_PROBLEM_SIZE);
+
+    (1. + (MAX_TOURS_WEIGHT - 1.) * ratio, 1.)
+}
+
+/// Enables auto weighting of default objectives, see `config.autoWeights`: amount of unassigned
+/// jobs is minimized first, then normalized weighted sum of tour amount and cost with weights from
+/// `get_auto_weights`. Objectives specified in the problem are kept as is.
+pub fn with_auto_weights(api_problem: ApiProblem) -> ApiProblem {
+    if api_problem.objectives.is_some() {
+        return api_problem;
+    }
+
+    let mut api_problem = api_problem;
+    api_problem.config.get_or_insert_with(Config::default).auto_weights = Some(true);
+
+    api_problem
+}
+
+/// Sets vehicle affinity of jobs from given map of job ids to vehicle ids, e.g. built from historical
+/// assignments, and adds `history-affinity` objective as tie-break one: among solutions with equal
+/// values of other objectives, the one which keeps more jobs on their vehicles is preferred. When
+/// no objectives are specified in the problem, default ones are used.
+pub fn with_history_affinity(api_problem: ApiProblem, affinities: &HashMap<String, String>) -> ApiProblem {
+    let mut api_problem = api_problem;
+
+    api_problem.plan.jobs.iter_mut().for_each(|job| {
+        if let Some(vehicle_id) = affinities.get(&job.id) {
+            job.vehicle_affinity = Some(vehicle_id.clone());
+        }
+    });
+
+    let mut objectives = api_problem.objectives.take().unwrap_or_else(|| Objectives {
+        primary: vec![MinimizeUnassignedJobs, MinimizeTours],
+        secondary: Some(vec![MinimizeCost]),
+        tie_break: None,
+    });
+
+    let has_history_affinity = objectives
+        .primary
+        .iter()
+        .chain(objectives.secondary.iter().flatten())
+        .chain(objectives.tie_break.iter().flatten())
+        .any(|objective| matches!(objective, HistoryAffinity));
+
+    if !has_history_affinity {
+        objectives.tie_break = Some(
+            objectives.tie_break.unwrap_or_default().into_iter().chain(std::iter::once(HistoryAffinity)).collect(),
+        );
+    }
+
+    ApiProblem { objectives: Some(objectives), ..api_problem }
+}
+
+fn map_objectives(
+    objectives: &[Objective],
+    constraint: &mut ConstraintPipeline,
+    activity: &Arc<dyn ActivityCost + Send + Sync>,
+    transport: &Arc<dyn TransportCost + Send + Sync>,
+    coord_index: &CoordIndex,
+    props: &ProblemProperties,
+    scales: &ObjectiveScales,
+) -> Vec<TargetObjective> {
+    let mut core_objectives: Vec<TargetObjective> = vec![];
+    objectives.iter().for_each(|objective| match objective {
+        MinimizeCost => core_objectives.push(Box::new(TotalTransportCost::default())),
+        MinimizeTours => {
+            constraint.add_module(Box::new(FleetUsageConstraintModule::new_minimized()));
+            core_objectives.push(Box::new(TotalRoutes::new_minimized()))
+        }
+        MaximizeTours => {
+            constraint.add_module(Box::new(FleetUsageConstraintModule::new_maximized()));
+            core_objectives.push(Box::new(TotalRoutes::new_maximized()))
+        }
+        TargetTours { target } => {
+            constraint.add_module(Box::new(FleetUsageConstraintModule::new_targeted(*target)));
+            core_objectives.push(Box::new(TotalRoutes::new_targeted(*target)))
+        }
+        MinimizeLongTours { threshold } => {
+            let (module, objective) = TotalLongRoutes::new_duration_limited(*threshold);
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        MinimizeOvertimeTours => {
+            let (module, objective) = TotalOvertimeRoutes::new_minimized(get_shift_end_fn());
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        CompactTours => {
+            let (module, objective) = RouteCompactness::new_minimized(transport.clone());
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        MinimizeDeadhead => {
+            let (module, objective) = if props.has_multi_dimen_capacity {
+                DeadheadDistance::new_minimized::<MultiDimLoad>(transport.clone())
+            } else {
+                DeadheadDistance::new_minimized::<SingleDimLoad>(transport.clone())
+            };
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        MinimizeWeightedLateness => {
+            let (module, objective) =
+                TotalLateness::new_minimized(activity.clone(), transport.clone(), Arc::new(get_weighted_lateness));
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        MinimizeOverlap { resolution } => {
+            let (module, objective) = RouteOverlap::new_minimized(get_grid_cells(coord_index, *resolution));
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        MinimizeIntersections => {
+            let (module, objective) = RouteIntersections::new_minimized(get_coordinates(coord_index));
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        MinimizeEtaVariance => {
+            // NOTE jobs bound to specific vehicle, such as breaks or reloads, are not customer facing
+            let (module, objective) = EtaVariance::new_minimized(
+                transport.clone(),
+                Arc::new(|single| single.dimens.get_value::<String>("vehicle_id").is_none()),
+            );
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        MinimizeMonetaryCost { rates } => {
+            let (module, objective) = MonetaryCost::new_minimized(
+                create_monetary_rates(rates),
+                get_shift_end_fn(),
+                get_revenue_job_filter(),
+                get_lateness_fn(),
+                transport.clone(),
+            );
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        MinimizeUnassignedJobs => core_objectives.push(Box::new(get_unassigned_jobs(props))),
+        BalanceMaxLoad { options } => {
+            let (module, objective) = get_load_balance(props, options);
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        BalanceActivities { options } => {
+            let (threshold, tolerance) = unwrap_options(options);
+            let (module, objective) = if props.has_workloads {
+                WorkBalance::new_workload_balanced(threshold, tolerance, Arc::new(get_activity_workload))
+            } else {
+                WorkBalance::new_activity_balanced(threshold, tolerance)
+            };
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        BalanceDistance { options } => {
+            let (threshold, tolerance) = unwrap_options(options);
+            let (module, objective) = WorkBalance::new_distance_balanced(threshold, tolerance);
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        BalanceDuration { options } => {
+            let (threshold, tolerance) = unwrap_options(options);
+            let (module, objective) = WorkBalance::new_duration_balanced(threshold, tolerance);
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        BalanceStops => {
+            let (module, objective) = StopBalance::new_minimized();
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        HistoryAffinity => {
+            let (module, objective) = VehicleAffinity::new_minimized(Arc::new(|actor, job| {
+                job.dimens()
+                    .get_value::<String>("vehicle_affinity")
+                    .map_or(false, |vehicle_id| actor.vehicle.dimens.get_id() != Some(vehicle_id))
+            }));
+            constraint.add_module(module);
+            core_objectives.push(objective);
+        }
+        Objective::WeightedSum { objectives, normalize } => {
+            let inners = objectives
+                .iter()
+                .flat_map(|weighted| {
+                    // NOTE fleet usage modules make a new tour too expensive to trade it off against
+                    // other objectives, so they are ignored for tour objectives
+                    let mut fleet_constraint = ConstraintPipeline::default();
+                    let constraint = match weighted.objective {
+                        MinimizeTours | TargetTours { .. } => &mut fleet_constraint,
+                        _ => &mut *constraint,
+                    };
+
+                    map_objectives(
+                        std::slice::from_ref(&weighted.objective),
+                        constraint,
+                        activity,
+                        transport,
+                        coord_index,
+                        props,
+                        scales,
+                    )
+                    .into_iter()
+                    .map(move |objective| (objective, weighted.weight))
+                })
+                .collect();
+
+            core_objectives.push(if normalize.unwrap_or(false) {
+                let scales = objectives.iter().map(|weighted| scales.get(&weighted.objective)).collect();
+                Box::new(vrp_core::solver::objectives::WeightedSum::new_normalized(inners, scales))
+            } else {
+                Box::new(vrp_core::solver::objectives::WeightedSum::new(inners))
+            });
+        }
+    });
+    core_objectives
+}
+
+/// Keeps estimates of objective value magnitudes known before solving. They are used to normalize
+/// objectives combined in weighted sum, so the same problem is always evaluated with the same scales.
+pub(crate) struct ObjectiveScales {
+    tours: f64,
+    jobs: f64,
+    cost: f64,
+}
+
+impl ObjectiveScales {
+    /// Estimates scales from the problem: amount of tours is limited by amount of vehicles and jobs,
+    /// cost is estimated as total cost of serving each job by a separate round trip from the
+    /// cheapest vehicle start.
+    pub fn new(fleet: &Fleet, jobs: &Jobs, transport: &(dyn TransportCost + Send + Sync)) -> Self {
+        // NOTE actors with the same profile, start location and rates have the same round trip costs
+        let actors = fleet
+            .actors
+            .iter()
+            .filter_map(|actor| actor.detail.start.as_ref().map(|start| (actor, start.location)))
+            .fold(HashMap::new(), |mut acc, (actor, start)| {
+                let costs = (&actor.vehicle.costs, &actor.driver.costs);
+                let key = (
+                    actor.vehicle.profile,
+                    start,
+                    (costs.0.per_distance + costs.1.per_distance).to_bits(),
+                    (costs.0.per_driving_time + costs.1.per_driving_time).to_bits(),
+                );
+                acc.entry(key).or_insert_with(|| (actor.clone(), start));
+                acc
+            })
+            .into_values()
+            .collect::<Vec<_>>();
+
+        let cost = jobs
+            .all()
+            .flat_map(|job| match job {
+                Job::Single(single) => vec![single],
+                Job::Multi(multi) => multi.jobs.clone(),
+            })
+            .filter_map(|single| {
+                single
+                    .places
+                    .iter()
+                    .filter_map(|place| place.location)
+                    .flat_map(|location| {
+                        actors.iter().map(move |(actor, start)| {
+                            transport.cost(actor, *start, location, 0.) + transport.cost(actor, location, *start, 0.)
+                        })
+                    })
+                    .min_by(|a, b| compare_floats(*a, *b))
+            })
+            .sum::<Cost>();
+
+        Self { tours: fleet.actors.len().min(jobs.size()) as f64, jobs: jobs.size() as f64, cost }
+    }
+
+    /// Returns scale of the objective, objectives without estimate have unit scale.
+    pub fn get(&self, objective: &Objective) -> f64 {
+        match objective {
+            MinimizeTours | TargetTours { .. } => self.tours,
+            MinimizeUnassignedJobs => self.jobs,
+            MinimizeCost | MinimizeMonetaryCost { .. } => self.cost,
+            _ => 1.,
+        }
+    }
+}
+
+/// Returns a function which returns regular shift end of the actor when its shift allows overtime.
+pub(crate) fn get_shift_end_fn() -> ShiftEndFn {
+    Arc::new(|actor| actor.vehicle.dimens.get_value::<Timestamp>("shift_end").cloned())
+}
+
+/// Returns a filter of jobs which are counted as lost revenue when unassigned: jobs bound to specific
+/// vehicle, such as breaks or reloads, are ignored and job variants are counted once by the first one.
+pub(crate) fn get_revenue_job_filter() -> RevenueJobFilter {
+    Arc::new(|job| {
+        let dimens = job.dimens();
+        dimens.get_value::<String>("vehicle_id").is_none()
+            && dimens.get_value::<usize>("variant").map_or(true, |variant| *variant == 0)
+    })
+}
+
+/// Returns a function which returns latest service start of the job activity when soft time windows are used.
+pub(crate) fn get_lateness_fn() -> LatenessFunc {
+    Arc::new(get_lateness)
+}
+
+/// Returns rates of monetary cost objective if it is specified anywhere in the problem objectives.
+pub(crate) fn get_monetary_rates(api_problem: &ApiProblem) -> Option<MonetaryRates> {
+    let objectives = api_problem.objectives.as_ref()?;
+
+    Some(&objectives.primary)
+        .into_iter()
+        .chain(objectives.secondary.iter())
+        .chain(objectives.tie_break.iter())
+        .flat_map(|objectives| objectives.iter())
+        .flat_map(|objective| match objective {
+            Objective::WeightedSum { objectives, .. } => {
+                objectives.iter().map(|weighted| &weighted.objective).collect()
+            }
+            _ => vec![objective],
+        })
+        .find_map(|objective| match objective {
+            MinimizeMonetaryCost { rates } => Some(create_monetary_rates(rates)),
+            _ => None,
+        })
+}
+
+fn create_monetary_rates(rates: &ApiMonetaryRates) -> MonetaryRates {
+    MonetaryRates {
+        distance: rates.distance.unwrap_or(0.),
+        time: rates.time.unwrap_or(0.),
+        overtime: rates.overtime.unwrap_or(0.),
+        fixed: rates.fixed.unwrap_or(0.),
+        unassigned: rates.unassigned.unwrap_or(0.),
+        late: rates.late.unwrap_or(0.),
+    }
+}
+
+/// Returns unassigned jobs objective: when jobs have priorities, solutions with the same amount of
+/// unassigned jobs are compared by their priorities, so more important jobs are kept assigned.
+fn get_unassigned_jobs(props: &ProblemProperties) -> TotalUnassignedJobs {
+    if props.has_priorities {
+        TotalUnassignedJobs::new_weighted(Arc::new(|job| {
+            let priority = get_priority(job.dimens()).unwrap_or(1).max(1);
+            1. / priority as f64
+        }))
+    } else {
+        TotalUnassignedJobs::default()
+    }
+}
+
+/// Returns lateness penalty scaled by job priority: the higher priority, the higher penalty.
+fn get_weighted_lateness(activity: &Activity) -> Option<(Timestamp, Cost)> {
+    get_lateness(activity).map(|(latest, penalty)| {
+        let priority = activity.job.as_ref().and_then(|single| {
+            Multi::roots(single).map_or_else(|| get_priority(&single.dimens), |multi| get_priority(&multi.dimens))
+        });
+
+        (latest, penalty / priority.unwrap_or(1).max(1) as f64)
+    })
+}
+
+/// Returns a function which maps location index to index of square grid cell with given side in
+/// meters. Cells are approximated with equirectangular projection, so they are smaller to the poles.
+fn get_grid_cells(coord_index: &CoordIndex, resolution: f64) -> Arc<dyn Fn(Location) -> Option<usize> + Send + Sync> {
+    const METERS_PER_DEGREE: f64 = 111_320.;
+
+    let cells = coord_index
+        .unique()
+        .iter()
+        .map(|location| match location {
+            ApiLocation::Coordinate { lat, lng } => {
+                let y = (lat * METERS_PER_DEGREE / resolution).floor() as i64;
+                let x = (lng * METERS_PER_DEGREE * lat.to_radians().cos() / resolution).floor() as i64;
+                Some((x, y))
+            }
+            ApiLocation::Reference { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    let cell_index = cells.iter().flatten().fold(HashMap::new(), |mut acc, cell| {
+        let next_idx = acc.len();
+        acc.entry(*cell).or_insert(next_idx);
+        acc
+    });
+    let cells = cells.into_iter().map(|cell| cell.and_then(|cell| cell_index.get(&cell).cloned())).collect::<Vec<_>>();
+
+    Arc::new(move |location| cells.get(location).cloned().flatten())
+}
+
+/// Returns a function which maps location index to its planar coordinates: longitude and latitude.
+fn get_coordinates(coord_index: &CoordIndex) -> Arc<dyn Fn(Location) -> Option<(f64, f64)> + Send + Sync> {
+    let coordinates = coord_index
+        .unique()
+        .iter()
+        .map(|location| match location {
+            ApiLocation::Coordinate { lat, lng } => Some((*lng, *lat)),
+            ApiLocation::Reference { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    Arc::new(move |location| coordinates.get(location).cloned().flatten())
+}
+
+fn get_priority(dimens: &Dimensions) -> Option<i32> {
+    dimens.get_value::<i32>("priority").cloned()
+}
+
+/// Returns workload of job activity: it is taken from job's `workload` dimension, default is one.
+fn get_activity_workload(activity: &Activity) -> f64 {
+    activity.retrieve_job().and_then(|job| job.dimens().get_value::<f64>("workload").cloned()).unwrap_or(1.)
+}
+
 fn unwrap_options(options: &Option<BalanceOptions>) -> (Option<f64>, Option<f64>) {
     (options.as_ref().and_then(|o| o.threshold), options.as_ref().and_then(|o| o.tolerance))
 }
@@ -12,29 +12,41 @@ mod fleet_reader;
 mod objective_reader;
 
 use self::fleet_reader::{create_transport_costs, read_fleet, read_limits};
-use self::job_reader::{read_jobs_with_extra_locks, read_locks};
-use self::objective_reader::create_objective;
+use self::job_reader::{read_jobs_with_extra_locks, read_locks, read_soft_precedences};
+use self::objective_reader::{create_objective, get_monetary_rates, ObjectiveScales};
+pub use self::objective_reader::{get_auto_weights, with_auto_weights, with_history_affinity};
+pub(crate) use self::objective_reader::{get_lateness_fn, get_revenue_job_filter, get_shift_end_fn};
 use crate::constraints::*;
 use crate::extensions::{get_route_modifier, OnlyVehicleActivityCost};
 use crate::format::coord_index::CoordIndex;
-use crate::format::problem::{deserialize_matrix, deserialize_problem, Matrix};
+use crate::format::problem::{
+    deserialize_matrix, deserialize_problem, escalate_priorities, expand_horizon, CoincidentJobsPolicy, Config,
+    CoordinateSystem, Matrix,
+};
 use crate::format::*;
 use crate::utils::get_approx_transportation;
 use crate::validation::ValidationContext;
 use crate::{get_unique_locations, parse_time};
 use std::cmp::Ordering::Equal;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::iter::FromIterator;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
-use vrp_core::models::common::{Dimensions, MultiDimLoad, SingleDimLoad, TimeWindow, ValueDimension};
+use vrp_core::models::common::{
+    Cost, Dimensions, Duration, Location, MultiDimLoad, SingleDimLoad, TimeWindow, Timestamp, ValueDimension,
+};
 use vrp_core::models::problem::{ActivityCost, Fleet, TransportCost};
+use vrp_core::models::solution::Activity;
 use vrp_core::models::{Extras, Lock, Problem};
+use vrp_core::solver::ISOLATED_CONSTRAINT_CODES_KEY;
 use vrp_core::utils::{compare_floats, DefaultRandom, Random};
 
 pub type ApiProblem = crate::format::problem::Problem;
 
+/// A key of problem extras which keeps rates of monetary cost objective.
+pub(crate) const MONETARY_RATES_KEY: &str = "monetary_rates";
+
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
     /// Reads problem defined in pragmatic format.
@@ -112,11 +124,36 @@ pub struct ProblemProperties {
     has_unreachable_locations: bool,
     has_depots: bool,
     has_reloads: bool,
+    has_anchors: bool,
     has_priorities: bool,
     has_area_limits: bool,
+    has_tw_job_limits: bool,
+    has_tw_density_limits: bool,
+    has_late_stop_limits: bool,
+    has_first_leg_limits: bool,
+    has_soft_time_windows: bool,
+    has_time_window_penalties: bool,
+    has_distance_tiers: bool,
+    has_cost_caps: bool,
+    has_load_costs: bool,
+    has_capacity_decays: bool,
+    has_alternative_starts: bool,
+    has_aggregate_capacity: bool,
+    has_job_variants: bool,
+    has_combined_stops: bool,
+    has_separate_stops: bool,
+    has_companions: bool,
+    has_transfers: bool,
+    has_corridors: bool,
+    has_crew_sizes: bool,
+    has_loading_disciplines: bool,
+    has_skill_time_windows: bool,
+    has_workloads: bool,
 }
 
-fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
+/// Creates routing matrices, one per fleet profile, approximated from job and vehicle coordinates.
+/// These are the same matrices which are used when problem is read without routing matrix.
+pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
     const DEFAULT_SPEED: f64 = 10.;
     // get each speed value once
     let speeds = problem
@@ -128,8 +165,11 @@ fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
         .collect::<HashSet<u64>>();
     let speeds = speeds.into_iter().map(f64::from_bits).collect::<Vec<_>>();
 
+    let coordinate_system =
+        problem.config.as_ref().and_then(|config| config.coordinate_system).unwrap_or(CoordinateSystem::Geographic);
+
     let locations = get_unique_locations(&problem);
-    let approx_data = get_approx_transportation(&locations, speeds.as_slice());
+    let approx_data = get_approx_transportation(&locations, speeds.as_slice(), coordinate_system);
 
     problem
         .fleet
@@ -146,6 +186,10 @@ fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
                 travel_times: approx_data[idx].0.clone(),
                 distances: approx_data[idx].1.clone(),
                 error_codes: None,
+                default_travel_time: None,
+                default_distance: None,
+                time_unit: None,
+                unreachable_value: None,
             }
         })
         .collect()
@@ -169,6 +213,8 @@ fn map_to_problem(
 ) -> Result<Problem, Vec<FormatError>> {
     ValidationContext::new(&api_problem, Some(&matrices)).validate()?;
 
+    let api_problem = escalate_priorities(expand_horizon(api_problem));
+
     let problem_props = get_problem_properties(&api_problem, &matrices);
 
     let coord_index = Arc::new(coord_index);
@@ -196,7 +242,9 @@ fn map_to_problem(
         &random,
     );
     let locks = locks.into_iter().chain(read_locks(&api_problem, &job_index).into_iter()).collect::<Vec<_>>();
+    let precedences = read_soft_precedences(&api_problem, &job_index);
     let limits = read_limits(&api_problem).unwrap_or_else(|| Arc::new(|_| (None, None)));
+    let rates = read_receiving_rates(&api_problem, &coord_index);
     let mut constraint = create_constraint_pipeline(
         coord_index.clone(),
         &fleet,
@@ -204,12 +252,25 @@ fn map_to_problem(
         transport.clone(),
         &problem_props,
         &locks,
+        precedences,
         limits,
+        rates,
     );
+    add_depot_inventory_module(&mut constraint, &api_problem, &problem_props);
+    add_vehicle_pool_module(&mut constraint, &api_problem, activity.clone(), transport.clone());
+    add_attribute_rules_module(&mut constraint, &api_problem);
 
-    let objective = create_objective(&api_problem, &mut constraint, &problem_props);
+    let objective = create_objective(
+        &api_problem,
+        &mut constraint,
+        activity.clone(),
+        transport.clone(),
+        coord_index.clone(),
+        &problem_props,
+        &ObjectiveScales::new(&fleet, &jobs, transport.as_ref()),
+    );
     let constraint = Arc::new(constraint);
-    let extras = Arc::new(create_extras(constraint.clone(), &problem_props, job_index, coord_index));
+    let extras = Arc::new(create_extras(&api_problem, constraint.clone(), &problem_props, job_index, coord_index));
 
     Ok(Problem {
         fleet: Arc::new(fleet),
@@ -223,6 +284,7 @@ fn map_to_problem(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_constraint_pipeline(
     coord_index: Arc<CoordIndex>,
     fleet: &Fleet,
@@ -230,9 +292,36 @@ fn create_constraint_pipeline(
     transport: Arc<dyn TransportCost + Send + Sync>,
     props: &ProblemProperties,
     locks: &[Arc<Lock>],
+    precedences: Vec<SoftPrecedence>,
     limits: TravelLimitFunc,
+    rates: HashMap<Location, Duration>,
 ) -> ConstraintPipeline {
     let mut constraint = ConstraintPipeline::default();
+
+    // NOTE companion module removes jobs from routes, so it goes before modules which keep route states
+    if props.has_companions {
+        constraint.add_module(Box::new(CompanionModule::new(COMPANION_CONSTRAINT_CODE)));
+    }
+
+    // NOTE transfer module removes jobs from routes, so it goes before transport module
+    if props.has_transfers {
+        constraint.add_module(Box::new(TransferModule::new(
+            activity.clone(),
+            transport.clone(),
+            TRANSFER_CONSTRAINT_CODE,
+        )));
+    }
+
+    // NOTE receiving rate module removes jobs from routes, so it goes before transport module too
+    if !rates.is_empty() {
+        constraint.add_module(Box::new(ReceivingRateModule::new(
+            rates,
+            activity.clone(),
+            transport.clone(),
+            RECEIVING_RATE_CONSTRAINT_CODE,
+        )));
+    }
+
     constraint.add_module(Box::new(TransportConstraintModule::new(
         activity.clone(),
         transport.clone(),
@@ -242,24 +331,111 @@ fn create_constraint_pipeline(
         DURATION_LIMIT_CONSTRAINT_CODE,
     )));
 
+    // NOTE transfer schedule module changes route schedules, so it goes right after transport module
+    if props.has_transfers {
+        constraint.add_module(Box::new(TransferScheduleModule::new(activity.clone(), transport.clone())));
+    }
+
+    if props.has_soft_time_windows {
+        constraint.add_module(Box::new(SoftTimeWindowModule::new(
+            activity.clone(),
+            transport.clone(),
+            Arc::new(get_lateness),
+        )));
+
+        if props.has_late_stop_limits {
+            constraint.add_module(Box::new(LateStopsModule::new(
+                activity.clone(),
+                transport.clone(),
+                Arc::new(get_lateness),
+                LATE_STOPS_CONSTRAINT_CODE,
+            )));
+        }
+    }
+
+    if props.has_first_leg_limits {
+        constraint
+            .add_module(Box::new(FirstLegDistanceModule::new(transport.clone(), FIRST_LEG_DISTANCE_CONSTRAINT_CODE)));
+    }
+
+    if props.has_time_window_penalties {
+        constraint.add_module(Box::new(TimeWindowPenaltyModule::new(Arc::new(get_time_window_penalty))));
+    }
+
+    if props.has_distance_tiers {
+        constraint.add_module(Box::new(DistanceTiersModule::new(
+            transport.clone(),
+            Arc::new(|actor, distance| get_distance_tiers_cost(&actor.vehicle, distance)),
+        )));
+    }
+
     add_capacity_module(&mut constraint, &props);
 
+    if props.has_aggregate_capacity {
+        add_aggregate_capacity_module(&mut constraint, props);
+    }
+
+    if props.has_load_costs {
+        add_load_cost_module(&mut constraint, transport.clone(), props);
+    }
+
+    if props.has_capacity_decays {
+        add_capacity_decay_module(&mut constraint, activity.clone(), transport.clone(), props);
+    }
+
+    // NOTE cost cap module relies on route usage cost, so it goes after all modules which change it
+    if props.has_cost_caps {
+        constraint.add_module(Box::new(CostCapModule::new(
+            activity.clone(),
+            transport.clone(),
+            Arc::new(|actor| actor.vehicle.dimens.get_value::<f64>("max_daily_cost").cloned()),
+        )));
+    }
+
     if props.has_breaks {
-        constraint.add_module(Box::new(BreakModule::new(BREAK_CONSTRAINT_CODE)));
+        constraint.add_module(Box::new(BreakModule::new(activity.clone(), transport.clone(), BREAK_CONSTRAINT_CODE)));
+    }
+
+    if props.has_anchors {
+        constraint.add_module(Box::new(AnchorModule::new(ANCHOR_CONSTRAINT_CODE)));
     }
 
     if props.has_skills {
         constraint.add_module(Box::new(SkillsModule::new(SKILLS_CONSTRAINT_CODE)));
     }
 
+    if props.has_crew_sizes {
+        constraint.add_module(Box::new(CrewSizeModule::new(CREW_SIZE_CONSTRAINT_CODE)));
+    }
+
+    if props.has_loading_disciplines {
+        constraint.add_module(Box::new(LoadingDisciplineModule::new(LOADING_DISCIPLINE_CONSTRAINT_CODE)));
+    }
+
+    if props.has_skill_time_windows {
+        constraint.add_module(Box::new(SkillTimeWindowsModule::new(SKILL_TIME_WINDOWS_CONSTRAINT_CODE)));
+    }
+
+    if props.has_combined_stops {
+        constraint.add_module(Box::new(CoincidentJobsModule::new(COINCIDENT_JOBS_CONSTRAINT_CODE)));
+    }
+
     if props.has_priorities {
         constraint.add_module(Box::new(PriorityModule::new(PRIORITY_CONSTRAINT_CODE)));
     }
 
+    if props.has_corridors {
+        constraint.add_module(Box::new(CorridorModule::new(CORRIDOR_CONSTRAINT_CODE)));
+    }
+
     if !locks.is_empty() {
         constraint.add_module(Box::new(StrictLockingModule::new(fleet, locks, LOCKING_CONSTRAINT_CODE)));
     }
 
+    if !precedences.is_empty() {
+        constraint.add_module(Box::new(SoftPrecedenceModule::new(precedences)));
+    }
+
     if props.has_unreachable_locations {
         constraint.add_module(Box::new(ReachableModule::new(transport.clone(), REACHABLE_CONSTRAINT_CODE)));
     }
@@ -268,6 +444,27 @@ fn create_constraint_pipeline(
         add_area_module(&mut constraint, coord_index);
     }
 
+    if props.has_tw_job_limits {
+        constraint.add_module(Box::new(TimeWindowJobsModule::new(TIME_WINDOW_JOBS_CONSTRAINT_CODE)));
+    }
+
+    if props.has_tw_density_limits {
+        constraint.add_module(Box::new(TimeWindowDensityModule::new(
+            3600.,
+            activity.clone(),
+            transport.clone(),
+            TIME_WINDOW_DENSITY_CONSTRAINT_CODE,
+        )));
+    }
+
+    if props.has_alternative_starts {
+        constraint.add_module(Box::new(AlternativeStartsModule::new(fleet)));
+    }
+
+    if props.has_job_variants {
+        constraint.add_module(Box::new(JobVariantsModule::new()));
+    }
+
     constraint
 }
 
@@ -292,6 +489,148 @@ fn add_capacity_module(constraint: &mut ConstraintPipeline, props: &ProblemPrope
     });
 }
 
+fn add_aggregate_capacity_module(constraint: &mut ConstraintPipeline, props: &ProblemProperties) {
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Box::new(AggregateCapacityModule::<MultiDimLoad>::new(
+            AGGREGATE_CAPACITY_CONSTRAINT_CODE,
+            Arc::new(|load, weights| {
+                load.load.iter().zip(weights.iter()).map(|(value, weight)| *value as f64 * weight).sum()
+            }),
+        ))
+    } else {
+        Box::new(AggregateCapacityModule::<SingleDimLoad>::new(
+            AGGREGATE_CAPACITY_CONSTRAINT_CODE,
+            Arc::new(|load, weights| load.value as f64 * weights.first().cloned().unwrap_or(1.)),
+        ))
+    });
+}
+
+fn add_load_cost_module(
+    constraint: &mut ConstraintPipeline,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    props: &ProblemProperties,
+) {
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Box::new(LoadCostModule::<MultiDimLoad>::new(
+            transport,
+            Arc::new(|load, weights| {
+                load.load.iter().zip(weights.iter()).map(|(value, weight)| *value as f64 * weight).sum()
+            }),
+        ))
+    } else {
+        Box::new(LoadCostModule::<SingleDimLoad>::new(
+            transport,
+            Arc::new(|load, weights| load.value as f64 * weights.first().cloned().unwrap_or(0.)),
+        ))
+    });
+}
+
+fn add_capacity_decay_module(
+    constraint: &mut ConstraintPipeline,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    props: &ProblemProperties,
+) {
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Box::new(CapacityDecayModule::<MultiDimLoad>::new(activity, transport, CAPACITY_DECAY_CONSTRAINT_CODE))
+    } else {
+        Box::new(CapacityDecayModule::<SingleDimLoad>::new(activity, transport, CAPACITY_DECAY_CONSTRAINT_CODE))
+    });
+}
+
+fn add_depot_inventory_module(
+    constraint: &mut ConstraintPipeline,
+    api_problem: &ApiProblem,
+    props: &ProblemProperties,
+) {
+    if let Some(inventory) = api_problem.config.as_ref().and_then(|config| config.depot_inventory.as_ref()) {
+        constraint.add_module(if props.has_multi_dimen_capacity {
+            Box::new(DepotInventoryModule::new(MultiDimLoad::new(inventory.clone()), DEPOT_INVENTORY_CONSTRAINT_CODE))
+        } else {
+            Box::new(DepotInventoryModule::new(SingleDimLoad::new(inventory[0]), DEPOT_INVENTORY_CONSTRAINT_CODE))
+        });
+    }
+}
+
+fn add_vehicle_pool_module(
+    constraint: &mut ConstraintPipeline,
+    api_problem: &ApiProblem,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+) {
+    if let Some(size) = api_problem.config.as_ref().and_then(|config| config.vehicle_pool) {
+        constraint.add_module(Box::new(VehiclePoolModule::new(
+            size,
+            activity,
+            transport,
+            VEHICLE_POOL_CONSTRAINT_CODE,
+        )));
+    }
+}
+
+/// Adds rules over job and vehicle attributes to the problem config, see `config.rules`. Rules
+/// already defined in the problem are kept.
+pub fn with_attribute_rules(api_problem: ApiProblem, rules: &[String]) -> ApiProblem {
+    if rules.is_empty() {
+        return api_problem;
+    }
+
+    let mut api_problem = api_problem;
+    let config = api_problem.config.get_or_insert_with(Config::default);
+    config.rules.get_or_insert_with(Vec::new).extend(rules.iter().cloned());
+
+    api_problem
+}
+
+fn add_attribute_rules_module(constraint: &mut ConstraintPipeline, api_problem: &ApiProblem) {
+    let rules = api_problem
+        .config
+        .as_ref()
+        .and_then(|config| config.rules.as_ref())
+        .iter()
+        .flat_map(|rules| rules.iter())
+        .map(|rule| AttributeRule::parse(rule).expect("invalid rule"))
+        .collect::<Vec<_>>();
+
+    if !rules.is_empty() {
+        constraint.add_module(Box::new(AttributeRulesModule::new(rules, ATTRIBUTE_RULES_CONSTRAINT_CODE)));
+    }
+}
+
+fn read_receiving_rates(api_problem: &ApiProblem, coord_index: &CoordIndex) -> HashMap<Location, Duration> {
+    api_problem
+        .config
+        .as_ref()
+        .and_then(|config| config.receiving_rates.as_ref())
+        .iter()
+        .flat_map(|rates| rates.iter())
+        .filter_map(|rate| coord_index.get_by_loc(&rate.location).map(|location| (location, rate.interval)))
+        .collect()
+}
+
+fn get_lateness(activity: &Activity) -> Option<(Timestamp, Cost)> {
+    activity.job.as_ref().and_then(|single| {
+        let soft_tws = single.dimens.get_value::<Vec<TimeWindow>>("soft_tws")?;
+        let penalty = single.dimens.get_value::<f64>("lateness_penalty")?;
+
+        soft_tws
+            .iter()
+            .find(|tw| compare_floats(tw.start, activity.place.time.start) == Equal)
+            .map(|tw| (tw.end, *penalty))
+    })
+}
+
+fn get_time_window_penalty(activity: &Activity) -> Cost {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_value::<Vec<(TimeWindow, Cost)>>("tw_penalties"))
+        .and_then(|tw_penalties| {
+            tw_penalties.iter().find(|(tw, _)| compare_floats(tw.start, activity.place.time.start) == Equal)
+        })
+        .map_or(0., |(_, penalty)| *penalty)
+}
+
 fn add_area_module(constraint: &mut ConstraintPipeline, coord_index: Arc<CoordIndex>) {
     constraint.add_module(Box::new(AreaModule::new(
         Arc::new(|actor| actor.vehicle.dimens.get_value::<Vec<Area>>("areas")),
@@ -305,6 +644,7 @@ fn add_area_module(constraint: &mut ConstraintPipeline, coord_index: Arc<CoordIn
 }
 
 fn create_extras(
+    api_problem: &ApiProblem,
     constraint: Arc<ConstraintPipeline>,
     props: &ProblemProperties,
     job_index: JobIndex,
@@ -318,10 +658,35 @@ fn create_extras(
     extras.insert("coord_index".to_owned(), coord_index);
     extras.insert("job_index".to_owned(), Arc::new(job_index.clone()));
 
+    if props.has_separate_stops {
+        extras.insert("coincident_jobs".to_owned(), Arc::new(CoincidentJobsPolicy::Separate));
+    }
+
+    if api_problem.config.as_ref().and_then(|config| config.cumulative_stats).unwrap_or(false) {
+        extras.insert("cumulative_stats".to_owned(), Arc::new(true));
+    }
+
     if props.has_depots {
         extras.insert("route_modifier".to_owned(), Arc::new(get_route_modifier(constraint, job_index)));
     }
 
+    if let Some(rates) = get_monetary_rates(api_problem) {
+        extras.insert(MONETARY_RATES_KEY.to_owned(), Arc::new(rates));
+    }
+
+    // NOTE these constraints do not depend on other jobs, so a job rejected alone is never served
+    extras.insert(
+        ISOLATED_CONSTRAINT_CODES_KEY.to_owned(),
+        Arc::new(vec![
+            TIME_CONSTRAINT_CODE,
+            DISTANCE_LIMIT_CONSTRAINT_CODE,
+            DURATION_LIMIT_CONSTRAINT_CODE,
+            CAPACITY_CONSTRAINT_CODE,
+            SKILLS_CONSTRAINT_CODE,
+            REACHABLE_CONSTRAINT_CODE,
+        ]),
+    );
+
     extras
 }
 
@@ -331,15 +696,17 @@ fn parse_time_window(tw: &[String]) -> TimeWindow {
 }
 
 fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
-    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
+    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some() || m.unreachable_value.is_some());
     let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
         || api_problem.plan.jobs.iter().any(|job| {
-            job.pickups
-                .iter()
-                .chain(job.deliveries.iter())
-                .flat_map(|tasks| tasks.iter())
-                .any(|task| task.demand.as_ref().map_or(false, |d| d.len() > 1))
-        });
+            job.pickups.iter().chain(job.deliveries.iter()).flat_map(|tasks| tasks.iter()).any(|task| {
+                task.demand
+                    .iter()
+                    .chain(task.variants.iter().flatten().flat_map(|v| v.demand.iter()))
+                    .any(|d| d.len() > 1)
+            })
+        })
+        || api_problem.config.as_ref().and_then(|c| c.depot_inventory.as_ref()).map_or(false, |i| i.len() > 1);
     let has_breaks = api_problem
         .fleet
         .vehicles
@@ -358,6 +725,9 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .vehicles
         .iter()
         .any(|t| t.shifts.iter().any(|s| s.reloads.as_ref().map_or(false, |reloads| !reloads.is_empty())));
+    let has_anchors = api_problem.fleet.vehicles.iter().any(|t| {
+        t.shifts.iter().any(|s| get_shift_anchors(api_problem, s).map_or(false, |anchors| !anchors.is_empty()))
+    });
 
     let has_priorities = api_problem.plan.jobs.iter().filter_map(|job| job.priority).any(|priority| priority > 1);
     let has_area_limits = api_problem
@@ -365,6 +735,103 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .vehicles
         .iter()
         .any(|v| v.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).map_or(false, |a| !a.is_empty()));
+    let has_tw_job_limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|v| v.limits.as_ref().map_or(false, |l| l.max_time_window_jobs.is_some()));
+    let has_tw_density_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.iter().any(|l| l.max_hourly_time_window_jobs.is_some()));
+    let has_late_stop_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.iter().any(|l| l.max_late_stops.is_some()));
+    let has_first_leg_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.iter().any(|l| l.max_first_leg_distance.is_some()));
+    let has_soft_time_windows = {
+        let is_soft = api_problem
+            .config
+            .as_ref()
+            .and_then(|config| config.time_windows.as_ref())
+            .map_or(false, |time_windows| time_windows.soft);
+        api_problem.plan.jobs.iter().any(|job| job.hard_time_window.map_or(is_soft, |is_hard| !is_hard))
+    };
+
+    let has_time_window_penalties = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| {
+            job.pickups
+                .iter()
+                .chain(job.deliveries.iter())
+                .chain(job.replacements.iter())
+                .chain(job.services.iter())
+                .flat_map(|tasks| tasks.iter())
+        })
+        .flat_map(|task| task.places.iter())
+        .any(|place| place.time_penalties.iter().flatten().any(|penalty| *penalty > 0.));
+
+    let has_distance_tiers =
+        api_problem.fleet.vehicles.iter().any(|v| v.costs.distance_tiers.as_ref().map_or(false, |t| !t.is_empty()));
+
+    let has_cost_caps = api_problem.fleet.vehicles.iter().any(|v| v.costs.max_daily_cost.is_some());
+
+    let has_load_costs = api_problem.fleet.vehicles.iter().any(|v| v.costs.load_distance.is_some());
+    let has_capacity_decays = api_problem.fleet.vehicles.iter().any(|v| v.capacity_decay.is_some());
+
+    let has_alternative_starts = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|v| v.shifts.iter())
+        .any(|s| s.start.alternatives.as_ref().map_or(false, |alternatives| !alternatives.is_empty()));
+
+    let has_aggregate_capacity = api_problem.fleet.vehicles.iter().any(|v| v.aggregate_capacity.is_some());
+
+    let has_job_variants = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| {
+            job.pickups
+                .iter()
+                .chain(job.deliveries.iter())
+                .chain(job.replacements.iter())
+                .chain(job.services.iter())
+                .flat_map(|tasks| tasks.iter())
+        })
+        .any(|task| task.variants.as_ref().map_or(false, |variants| !variants.is_empty()));
+
+    let coincident_jobs = api_problem.config.as_ref().and_then(|config| config.coincident_jobs);
+    let has_combined_stops = coincident_jobs == Some(CoincidentJobsPolicy::Combined);
+    let has_separate_stops = coincident_jobs == Some(CoincidentJobsPolicy::Separate);
+
+    let has_companions =
+        api_problem.plan.jobs.iter().any(|job| job.companions.as_ref().map_or(false, |ids| !ids.is_empty()));
+
+    let has_transfers = api_problem.plan.jobs.iter().any(|job| job.transfer_from.is_some());
+
+    let has_corridors = api_problem.plan.jobs.iter().any(|job| job.corridor.is_some());
+
+    let has_crew_sizes = api_problem.plan.jobs.iter().any(|job| job.crew_size.is_some());
+
+    let has_loading_disciplines = api_problem.fleet.vehicles.iter().any(|v| v.loading_discipline.is_some());
+
+    let has_skill_time_windows = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| {
+            job.pickups
+                .iter()
+                .chain(job.deliveries.iter())
+                .chain(job.replacements.iter())
+                .chain(job.services.iter())
+                .flat_map(|tasks| tasks.iter())
+        })
+        .flat_map(|task| task.places.iter())
+        .any(|place| place.skill_times.as_ref().map_or(false, |skill_times| !skill_times.is_empty()));
+
+    let has_workloads = api_problem.plan.jobs.iter().any(|job| job.workload.is_some());
 
     ProblemProperties {
         has_multi_dimen_capacity,
@@ -373,8 +840,31 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_unreachable_locations,
         has_depots,
         has_reloads,
+        has_anchors,
         has_priorities,
         has_area_limits,
+        has_tw_job_limits,
+        has_tw_density_limits,
+        has_late_stop_limits,
+        has_first_leg_limits,
+        has_soft_time_windows,
+        has_time_window_penalties,
+        has_distance_tiers,
+        has_cost_caps,
+        has_load_costs,
+        has_capacity_decays,
+        has_alternative_starts,
+        has_aggregate_capacity,
+        has_job_variants,
+        has_combined_stops,
+        has_separate_stops,
+        has_companions,
+        has_transfers,
+        has_corridors,
+        has_crew_sizes,
+        has_loading_disciplines,
+        has_skill_time_windows,
+        has_workloads,
     }
 }
 
@@ -1,7 +1,6 @@
-use crate::format::{CoordIndex, JobIndex};
+use crate::format::{get_variant_job_key, CoordIndex, JobIndex};
 use crate::parse_time;
 use std::collections::HashSet;
-use std::iter::once;
 use std::sync::Arc;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Job, Single};
@@ -29,6 +28,11 @@ pub(crate) fn try_match_job(
             .first()
             .map(|stop| parse_time(&stop.time.departure))
             .ok_or_else(|| "empty route".to_owned())?,
+        shift_start_time: tour
+            .stops
+            .first()
+            .map(|stop| parse_time(&stop.time.arrival))
+            .ok_or_else(|| "empty route".to_owned())?,
         location: coord_index
             .get_by_loc(activity.location.as_ref().unwrap_or(&stop.location))
             .ok_or_else(|| format!("cannot get location for activity for job '{}'", activity.job_id))?,
@@ -47,8 +51,14 @@ pub(crate) fn try_match_job(
         "pickup" | "delivery" | "replacement" | "service" => {
             let job =
                 job_index.get(&activity.job_id).ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
-            let singles: Box<dyn Iterator<Item = &Arc<_>>> = match job {
-                Job::Single(single) => Box::new(once(single)),
+            let singles = match job {
+                // NOTE job variants are stored in job index separately
+                Job::Single(_) => (0..)
+                    .map(|idx| job_index.get(&get_variant_job_key(&activity.job_id, idx)))
+                    .take_while(|job| job.is_some())
+                    .flatten()
+                    .filter_map(|job| job.as_single().map(|single| (job.clone(), single.clone())))
+                    .collect::<Vec<_>>(),
                 Job::Multi(multi) => {
                     let tags = multi.jobs.iter().filter_map(|job| get_tag(job).cloned()).collect::<HashSet<_>>();
                     if tags.len() < multi.jobs.len() {
@@ -58,17 +68,18 @@ pub(crate) fn try_match_job(
                         ));
                     }
 
-                    Box::new(multi.jobs.iter())
+                    multi.jobs.iter().map(|single| (job.clone(), single.clone())).collect()
                 }
             };
-            let (single, place) = singles
-                .filter_map(|single| match_place(single, true, &ctx).map(|place| (single, place)))
+            let (job, single, place) = singles
+                .into_iter()
+                .filter_map(|(job, single)| match_place(&single, true, &ctx).map(|place| (job, single, place)))
                 .next()
                 .ok_or_else(|| format!("cannot match job '{}'", activity.job_id))?;
 
-            Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
+            Ok(Some(JobInfo(job, single, place, ctx.time)))
         }
-        "break" | "depot" | "reload" => Ok(Some(
+        "break" | "depot" | "reload" | "anchor" => Ok(Some(
             (1..)
                 .map(|idx| format!("{}_{}_{}_{}", tour.vehicle_id, activity.activity_type, tour.shift_index, idx))
                 .map(|job_id| job_index.get(&job_id))
@@ -86,6 +97,7 @@ pub(crate) fn try_match_job(
 
 struct ActivityContext<'a> {
     route_start_time: Timestamp,
+    shift_start_time: Timestamp,
     location: Location,
     time: TimeWindow,
     act_type: &'a String,
@@ -95,6 +107,8 @@ struct ActivityContext<'a> {
 
 fn match_place<'a>(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &'a ActivityContext) -> Option<Place> {
     let job_id = get_job_id(single);
+    // NOTE job time offsets are relative to the shift start, break offsets - to the actual departure
+    let start_time = if is_job_activity { activity_ctx.shift_start_time } else { activity_ctx.route_start_time };
     let is_same_ids = *activity_ctx.job_id == job_id;
     let is_same_tags = match (get_tag(single), activity_ctx.tag) {
         (Some(job_tag), Some(activity_tag)) => job_tag == activity_tag,
@@ -109,24 +123,17 @@ fn match_place<'a>(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &'
             .iter()
             .find(|place| {
                 let is_same_location = place.location.map_or(true, |l| l == activity_ctx.location);
-                let is_proper_time =
-                    place.times.iter().any(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time));
+                let is_proper_time = place.times.iter().any(|time| time.intersects(start_time, &activity_ctx.time));
 
                 is_same_location && is_proper_time
             })
             .map(|place| {
-                let time = place
-                    .times
-                    .iter()
-                    .find(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time))
-                    .unwrap();
+                let time = place.times.iter().find(|time| time.intersects(start_time, &activity_ctx.time)).unwrap();
 
                 let time = match time {
                     TimeSpan::Window(tw) => tw.clone(),
                     // NOTE we don't know when original start should be
-                    TimeSpan::Offset(offset) => {
-                        TimeWindow::new(activity_ctx.time.start, activity_ctx.route_start_time + offset.end)
-                    }
+                    TimeSpan::Offset(offset) => TimeWindow::new(activity_ctx.time.start, start_time + offset.end),
                 };
 
                 Place { location: activity_ctx.location, duration: place.duration, time }
@@ -0,0 +1,92 @@
+use crate::format::problem::{Objective, Problem as ApiProblem};
+use std::cmp::Ordering;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::problem::ObjectiveLevel;
+use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::DefaultRandom;
+
+/// Specifies a comparison of two solutions by a single objective term.
+#[derive(Clone, Debug)]
+pub struct ObjectiveTermExplanation {
+    /// A level of the term in objective hierarchy.
+    pub level: ObjectiveLevel,
+    /// An objective type, e.g. `minimize-tours`.
+    pub name: String,
+    /// Objective values for both solutions.
+    pub values: (f64, f64),
+    /// An order of solutions by the term: `Less` means that the first solution is better.
+    pub order: Ordering,
+}
+
+/// Specifies a comparison of two solutions by problem objectives.
+#[derive(Clone, Debug)]
+pub struct SolutionComparison {
+    /// An order of solutions: `Less` means that the first solution is better.
+    pub order: Ordering,
+    /// A level of objective hierarchy which decided the order. None if solutions are equal.
+    pub level: Option<ObjectiveLevel>,
+    /// Comparisons by each objective term in the order of objective hierarchy.
+    pub terms: Vec<ObjectiveTermExplanation>,
+}
+
+/// Compares two solutions of the problem by each objective term, e.g. to explain why the solver
+/// prefers one solution over another. Terms are compared the same way as by the solver: primary
+/// objectives first, then secondary and tie-break ones.
+pub fn compare_solutions(
+    problem: Arc<Problem>,
+    api_problem: &ApiProblem,
+    first: Solution,
+    second: Solution,
+) -> SolutionComparison {
+    let create_ctx = |solution: Solution| {
+        InsertionContext::new_from_solution(problem.clone(), (solution, None), Arc::new(DefaultRandom::default()))
+    };
+    let (first, second) = (create_ctx(first), create_ctx(second));
+
+    let comparison = problem.objective.explain(&first, &second);
+
+    SolutionComparison {
+        order: comparison.order,
+        level: comparison.level,
+        terms: comparison
+            .terms
+            .into_iter()
+            .map(|term| ObjectiveTermExplanation {
+                name: get_objective_names(api_problem, &term.level)
+                    .get(term.index)
+                    .cloned()
+                    .unwrap_or_else(|| format!("objective-{}", term.index)),
+                level: term.level,
+                values: term.fitness,
+                order: term.order,
+            })
+            .collect(),
+    }
+}
+
+/// Returns objective types of given level in the same order as they are used by the solver.
+fn get_objective_names(api_problem: &ApiProblem, level: &ObjectiveLevel) -> Vec<String> {
+    let get_names = |objectives: Option<&Vec<Objective>>| {
+        objectives
+            .iter()
+            .flat_map(|objectives| objectives.iter())
+            .map(|objective| {
+                serde_json::to_value(objective)
+                    .ok()
+                    .and_then(|value| value.get("type").and_then(|name| name.as_str()).map(|name| name.to_string()))
+                    .unwrap_or_default()
+            })
+            .collect()
+    };
+
+    match (api_problem.objectives.as_ref(), level) {
+        (Some(objectives), ObjectiveLevel::Primary) => get_names(Some(&objectives.primary)),
+        (Some(objectives), ObjectiveLevel::Secondary) => get_names(objectives.secondary.as_ref()),
+        (Some(objectives), ObjectiveLevel::TieBreak) => get_names(objectives.tie_break.as_ref()),
+        // NOTE default objectives, see `ObjectiveCost::default`
+        (None, ObjectiveLevel::Primary) => vec!["minimize-unassigned".to_string(), "minimize-tours".to_string()],
+        (None, ObjectiveLevel::Secondary) => vec!["minimize-cost".to_string()],
+        (None, ObjectiveLevel::TieBreak) => vec![],
+    }
+}
@@ -0,0 +1,52 @@
+//! Provides the way to estimate fuel consumption and emissions of vehicle tours.
+
+use crate::format::problem::VehicleConsumption;
+use crate::format::solution::Consumption;
+use std::sync::Arc;
+use vrp_core::models::common::{Distance, Duration, ValueDimension};
+use vrp_core::models::problem::Vehicle;
+
+/// A key of problem extras which can be used to override default consumption function.
+pub const CONSUMPTION_FUNC_KEY: &str = "consumption_func";
+
+/// Specifies a leg driven by vehicle.
+pub struct ConsumptionLeg<'a> {
+    /// Leg distance.
+    pub distance: Distance,
+    /// Leg driving duration.
+    pub duration: Duration,
+    /// Load carried on the leg.
+    pub load: &'a [i32],
+}
+
+/// A function which returns fuel consumed and emissions produced by the vehicle on the leg or
+/// `None` if vehicle has no consumption model.
+pub type ConsumptionFunc = Arc<dyn Fn(&Vehicle, &ConsumptionLeg) -> Option<Consumption> + Send + Sync>;
+
+/// Creates a consumption function which uses vehicle consumption model: fuel consumed per distance
+/// unit is a sum of base consumption, weighted carried load and average leg speed multiplied by
+/// their factors, emissions are proportional to consumed fuel.
+pub fn create_default_consumption_func() -> ConsumptionFunc {
+    Arc::new(|vehicle, leg| {
+        let model = vehicle.dimens.get_value::<VehicleConsumption>("consumption")?;
+
+        let load = model.load.as_ref().map_or(0., |factors| {
+            factors.iter().zip(leg.load.iter()).map(|(factor, value)| factor * *value as f64).sum::<f64>()
+        });
+        let speed = if leg.duration > 0. { leg.distance / leg.duration } else { 0. };
+        let speed = model.speed.map_or(0., |factor| factor * speed);
+
+        let fuel = leg.distance * (model.distance + load + speed);
+
+        Some(Consumption { fuel, emissions: fuel * model.emission_factor })
+    })
+}
+
+/// Gets consumption function from problem extras or the default one.
+pub(crate) fn get_consumption_func(extras: &vrp_core::models::Extras) -> ConsumptionFunc {
+    extras
+        .get(CONSUMPTION_FUNC_KEY)
+        .and_then(|func| func.downcast_ref::<ConsumptionFunc>())
+        .cloned()
+        .unwrap_or_else(create_default_consumption_func)
+}
@@ -0,0 +1,165 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/delta_test.rs"]
+mod delta_test;
+
+use crate::format::solution::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufReader, BufWriter, Error, Read, Write};
+
+/// Identifies a tour within a solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourKey {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// A day of planning horizon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day: Option<usize>,
+}
+
+/// Specifies how job assignment is changed comparing to baseline solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JobChange {
+    /// Job id.
+    pub job_id: String,
+    /// A tour which served the job in baseline solution. Omitted when job was not assigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<TourKey>,
+    /// A tour which serves the job in new solution. Omitted when job is not assigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<TourKey>,
+}
+
+/// A solution expressed as changes against a baseline solution: only tours which are new or
+/// differ from baseline ones are kept, so unchanged tours are not sent again.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionDelta {
+    /// Total statistic of new solution.
+    pub statistic: Statistic,
+
+    /// Keys of all tours of new solution in their order.
+    pub tours: Vec<TourKey>,
+
+    /// Tours which are new or differ from baseline: they replace baseline tours with the same key.
+    pub changed_tours: Vec<Tour>,
+
+    /// Jobs which are moved to another tour, newly assigned or unassigned.
+    pub job_changes: Vec<JobChange>,
+
+    /// List of unassigned jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unassigned: Option<Vec<UnassignedJob>>,
+
+    /// List of constraint violations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub violations: Option<Vec<Violation>>,
+
+    /// An extra information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Extras>,
+}
+
+/// Creates a delta of new solution against baseline one.
+pub fn create_solution_delta(baseline: &Solution, solution: &Solution) -> SolutionDelta {
+    let baseline_tours = baseline.tours.iter().map(|tour| (get_tour_key(tour), tour)).collect::<HashMap<_, _>>();
+
+    let changed_tours = solution
+        .tours
+        .iter()
+        .filter(|tour| baseline_tours.get(&get_tour_key(tour)).map_or(true, |baseline| *baseline != *tour))
+        .cloned()
+        .collect();
+
+    let baseline_jobs = get_job_tours(baseline);
+    let solution_jobs = get_job_tours(solution);
+
+    let mut job_changes = baseline_jobs
+        .keys()
+        .chain(solution_jobs.keys().filter(|job_id| !baseline_jobs.contains_key(*job_id)))
+        .filter_map(|job_id| {
+            let from = baseline_jobs.get(job_id).cloned();
+            let to = solution_jobs.get(job_id).cloned();
+
+            if from != to {
+                Some(JobChange { job_id: job_id.clone(), from, to })
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    job_changes.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    SolutionDelta {
+        statistic: solution.statistic.clone(),
+        tours: solution.tours.iter().map(get_tour_key).collect(),
+        changed_tours,
+        job_changes,
+        unassigned: solution.unassigned.clone(),
+        violations: solution.violations.clone(),
+        extras: solution.extras.clone(),
+    }
+}
+
+/// Applies delta to baseline solution and returns the new solution.
+pub fn apply_solution_delta(baseline: &Solution, delta: &SolutionDelta) -> Result<Solution, String> {
+    let tours = delta
+        .tours
+        .iter()
+        .map(|key| {
+            delta
+                .changed_tours
+                .iter()
+                .chain(baseline.tours.iter())
+                .find(|tour| get_tour_key(tour) == *key)
+                .cloned()
+                .ok_or_else(|| {
+                    format!("cannot find tour of vehicle '{}' with shift index {}", key.vehicle_id, key.shift_index)
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Solution {
+        statistic: delta.statistic.clone(),
+        tours,
+        unassigned: delta.unassigned.clone(),
+        violations: delta.violations.clone(),
+        extras: delta.extras.clone(),
+    })
+}
+
+/// Serializes solution delta into json format.
+pub fn serialize_solution_delta<W: Write>(writer: BufWriter<W>, delta: &SolutionDelta) -> Result<(), Error> {
+    serde_json::to_writer_pretty(writer, delta).map_err(Error::from)
+}
+
+/// Deserializes solution delta from json format.
+pub fn deserialize_solution_delta<R: Read>(reader: BufReader<R>) -> Result<SolutionDelta, Error> {
+    serde_json::from_reader(reader).map_err(Error::from)
+}
+
+fn get_tour_key(tour: &Tour) -> TourKey {
+    TourKey { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index, day: tour.day }
+}
+
+/// Returns a map of job ids to keys of tours which serve them.
+fn get_job_tours(solution: &Solution) -> HashMap<String, TourKey> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let key = get_tour_key(tour);
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter())
+                .filter(|activity| {
+                    matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+                })
+                .map(move |activity| (activity.job_id.clone(), key.clone()))
+        })
+        .collect()
+}
@@ -1,4 +1,4 @@
-use crate::format::solution::{Statistic, Timing};
+use crate::format::solution::{Consumption, Statistic, Timing};
 use std::ops::Add;
 
 impl Default for Statistic {
@@ -8,6 +8,7 @@ impl Default for Statistic {
             distance: 0,
             duration: 0,
             times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+            tightness: None,
         }
     }
 }
@@ -26,6 +27,24 @@ impl Add for Statistic {
                 waiting: self.times.waiting + rhs.times.waiting,
                 break_time: self.times.break_time + rhs.times.break_time,
             },
+            tightness: match (self.tightness, rhs.tightness) {
+                (Some(left), Some(right)) => Some(left.min(right)),
+                (left, right) => left.or(right),
+            },
         }
     }
 }
+
+impl Default for Consumption {
+    fn default() -> Self {
+        Consumption { fuel: 0., emissions: 0. }
+    }
+}
+
+impl Add for Consumption {
+    type Output = Consumption;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Consumption { fuel: self.fuel + rhs.fuel, emissions: self.emissions + rhs.emissions }
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/frozen_prefix_test.rs"]
+mod frozen_prefix_test;
+
+use crate::format::problem::{Relation, RelationType};
+use crate::format::solution::Solution;
+use std::collections::HashMap;
+
+/// Creates strict relations which lock first activities of each tour from given solution to
+/// its vehicle shift. Added to the problem plan, they keep the tour prefix unchanged, so the
+/// solver can modify only the rest of the tour.
+///
+/// A prefix size is taken from `prefix_sizes` by vehicle id or `default_size` is used.
+/// NOTE jobs with multiple pickups or deliveries are not supported by relations.
+pub fn create_frozen_prefix_relations(
+    solution: &Solution,
+    prefix_sizes: &HashMap<String, usize>,
+    default_size: usize,
+) -> Vec<Relation> {
+    solution
+        .tours
+        .iter()
+        .filter_map(|tour| {
+            let prefix_size = prefix_sizes.get(&tour.vehicle_id).cloned().unwrap_or(default_size);
+
+            let jobs = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter())
+                .filter(|activity| activity.job_id != "departure" && activity.job_id != "arrival")
+                .take(prefix_size)
+                .map(|activity| activity.job_id.clone())
+                .collect::<Vec<_>>();
+
+            if jobs.is_empty() {
+                None
+            } else {
+                Some(Relation {
+                    type_field: RelationType::Strict,
+                    jobs: std::iter::once("departure".to_string()).chain(jobs).collect(),
+                    vehicle_id: Some(tour.vehicle_id.clone()),
+                    shift_index: Some(tour.shift_index),
+                    penalty: None,
+                })
+            }
+        })
+        .collect()
+}
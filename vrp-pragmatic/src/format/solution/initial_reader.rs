@@ -29,15 +29,27 @@ pub fn read_init_solution<R: Read>(solution: BufReader<R>, problem: Arc<Problem>
     let mut registry = Registry::new(&problem.fleet);
     let mut added_jobs = HashSet::default();
 
-    let actor_index = registry.all().map(|actor| (get_actor_key(actor.as_ref()), actor)).collect::<HashMap<_, _>>();
+    let actor_index = registry.all().fold(HashMap::<_, Vec<_>>::new(), |mut acc, actor| {
+        acc.entry(get_actor_key(actor.as_ref())).or_default().push(actor);
+        acc
+    });
     let coord_index = get_coord_index(problem.as_ref());
     let job_index = get_job_index(problem.as_ref());
 
     let routes =
         solution.tours.iter().try_fold::<_, _, Result<_, String>>(Vec::<_>::default(), |mut routes, tour| {
             let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index);
-            let actor =
-                actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {:?}", actor_key))?.clone();
+            // NOTE start location distinguishes actors of vehicle with alternative shift starts
+            let start = tour.stops.first().and_then(|stop| coord_index.get_by_loc(&stop.location));
+            let actor = actor_index
+                .get(&actor_key)
+                .and_then(|actors| {
+                    actors.iter().find(|actor| {
+                        start.map_or(true, |start| actor.detail.start.as_ref().map(|s| s.location) == Some(start))
+                    })
+                })
+                .ok_or_else(|| format!("cannot find vehicle for {:?}", actor_key))?
+                .clone();
             registry.use_actor(&actor);
 
             let mut core_route = create_core_route(actor, tour)?;
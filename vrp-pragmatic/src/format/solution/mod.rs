@@ -5,9 +5,27 @@ pub use self::model::*;
 
 pub(crate) mod activity_matcher;
 
+mod comparison;
+pub use self::comparison::*;
+
+mod consumption;
+pub use self::consumption::*;
+
+mod delta;
+pub use self::delta::*;
+
+mod frozen_prefix;
+pub use self::frozen_prefix::create_frozen_prefix_relations;
+
 mod geo_serializer;
 pub use self::geo_serializer::serialize_solution_as_geojson;
 
+mod route_hints;
+pub use self::route_hints::create_route_hint_relations;
+
+mod route_card_serializer;
+pub use self::route_card_serializer::serialize_solution_as_route_card;
+
 mod initial_reader;
 pub use self::initial_reader::read_init_solution;
 
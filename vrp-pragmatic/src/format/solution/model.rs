@@ -27,6 +27,11 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Time window tightness: the minimum slack, i.e. how much later an activity can start without
+    /// violating its time window. A value close to zero means that any delay causes lateness.
+    /// Omitted when there are no activities with time windows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tightness: Option<i64>,
 }
 
 /// Represents a schedule.
@@ -65,6 +70,13 @@ pub struct Activity {
     /// Job tag.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub job_tag: Option<String>,
+    /// Distance traveled since departure from start. Reported when cumulative stats are requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<i64>,
+    /// Duration since departure from start till the end of activity. Reported when cumulative
+    /// stats are requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i64>,
 }
 
 /// A stop is a place where vehicle is supposed to be parked.
@@ -92,10 +104,25 @@ pub struct Tour {
     pub type_id: String,
     /// Shift index.
     pub shift_index: usize,
+    /// A day of multi day planning horizon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day: Option<usize>,
     /// List of stops.
     pub stops: Vec<Stop>,
     /// Tour statistic.
     pub statistic: Statistic,
+    /// Tour fuel consumption and emissions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumption: Option<Consumption>,
+}
+
+/// Represents fuel consumption and emissions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct Consumption {
+    /// Consumed fuel.
+    pub fuel: f64,
+    /// Produced emissions.
+    pub emissions: f64,
 }
 
 /// Unassigned job reason.
@@ -132,10 +159,35 @@ pub enum Violation {
         /// A reason of violation.
         reason: String,
     },
+
+    /// An anchor visit violation: non empty tour has no anchor visit.
+    #[serde(rename(deserialize = "anchor", serialize = "anchor"))]
+    Anchor {
+        /// An id of a vehicle anchor belong to.
+        vehicle_id: String,
+        /// Index of the shift.
+        shift_index: usize,
+        /// A reason of violation.
+        reason: String,
+    },
+
+    /// A time window violation: job activity is started after its time window end.
+    #[serde(rename(deserialize = "time-window", serialize = "time-window"))]
+    TimeWindow {
+        /// An id of a vehicle which serves the job.
+        vehicle_id: String,
+        /// Index of the shift.
+        shift_index: usize,
+        /// An id of the job.
+        job_id: String,
+        /// A reason of violation.
+        reason: String,
+    },
 }
 
 /// Encapsulates different measurements regarding algorithm evaluation.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Metrics {
     /// Total algorithm duration.
     pub duration: usize,
@@ -145,6 +197,10 @@ pub struct Metrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<Generation>,
+    /// A lower bound of the best solution cost.
+    pub lower_bound: f64,
+    /// An optimality gap of the best solution cost in percents from its lower bound.
+    pub gap: f64,
 }
 
 /// Represents information about generation.
@@ -181,12 +237,68 @@ pub struct Individual {
     pub fitness: Vec<f64>,
 }
 
+/// Contains information about why the solver is stopped.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct Termination {
+    /// A termination reason: `max_generations`, `max_time`, `stagnation`, `custom_termination` or `cancelled`.
+    pub reason: String,
+    /// Total amount of generations.
+    pub generations: usize,
+    /// Total algorithm duration in seconds.
+    pub duration: f64,
+}
+
+/// Contains monetary cost breakdown of the solution calculated using rates of monetary cost objective.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct MonetaryCost {
+    /// A cost of driven distance.
+    pub distance: f64,
+    /// A cost of driver's time.
+    pub time: f64,
+    /// An extra cost of overtime.
+    pub overtime: f64,
+    /// A fixed cost of used vehicles.
+    pub fixed: f64,
+    /// A lost revenue of unassigned jobs.
+    pub unassigned: f64,
+    /// A lost revenue of jobs served late.
+    pub late: f64,
+    /// A total monetary cost: a sum of all terms above.
+    pub total: f64,
+}
+
+/// Specifies a geographic extent of the solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BoundingBox {
+    /// Minimum latitude.
+    pub min_lat: f64,
+    /// Minimum longitude.
+    pub min_lng: f64,
+    /// Maximum latitude.
+    pub max_lat: f64,
+    /// Maximum longitude.
+    pub max_lng: f64,
+}
+
 /// Contains extra information.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Extras {
     /// A telemetry metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
+    /// A solver configuration used to produce the solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<serde_json::Value>,
+    /// A solver termination report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub termination: Option<Termination>,
+    /// A monetary cost breakdown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monetary: Option<MonetaryCost>,
+    /// A bounding box of all visited locations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<BoundingBox>,
 }
 
 /// A VRP solution.
@@ -0,0 +1,63 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/route_card_serializer_test.rs"]
+mod route_card_serializer_test;
+
+use super::Solution;
+use crate::format::solution::{Stop, Tour};
+use crate::format::Location;
+use std::io::{BufWriter, Error, Write};
+
+/// Serializes solution into route card format: a plain text with one block per tour which contains
+/// vehicle id, shift times, ordered list of stops with their locations and estimated times, total
+/// distance and duration.
+pub fn serialize_solution_as_route_card<W: Write>(mut writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    solution.tours.iter().enumerate().try_for_each(|(tour_idx, tour)| {
+        if tour_idx > 0 {
+            writeln!(writer)?;
+        }
+
+        write_tour(&mut writer, tour)
+    })?;
+
+    writer.flush()
+}
+
+fn write_tour<W: Write>(writer: &mut BufWriter<W>, tour: &Tour) -> Result<(), Error> {
+    let shift_start = tour.stops.first().map_or("-", |stop| stop.time.departure.as_str());
+    let shift_end = tour.stops.last().map_or("-", |stop| stop.time.arrival.as_str());
+
+    writeln!(writer, "Vehicle: {} (shift {})", tour.vehicle_id, tour.shift_index)?;
+    writeln!(writer, "Shift: {} - {}", shift_start, shift_end)?;
+    writeln!(writer, "Stops:")?;
+
+    tour.stops.iter().enumerate().try_for_each(|(stop_idx, stop)| write_stop(writer, stop_idx + 1, stop))?;
+
+    writeln!(writer, "Total distance: {} m", tour.statistic.distance)?;
+    writeln!(writer, "Total duration: {} s", tour.statistic.duration)
+}
+
+fn write_stop<W: Write>(writer: &mut BufWriter<W>, number: usize, stop: &Stop) -> Result<(), Error> {
+    let activities = stop
+        .activities
+        .iter()
+        .map(|activity| format!("{} ({})", activity.job_id, activity.activity_type))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    writeln!(
+        writer,
+        "  {}. {} | eta: {} | departure: {} | {}",
+        number,
+        get_address(&stop.location),
+        stop.time.arrival,
+        stop.time.departure,
+        activities
+    )
+}
+
+fn get_address(location: &Location) -> String {
+    match location {
+        Location::Coordinate { lat, lng } => format!("{}, {}", lat, lng),
+        Location::Reference { index } => format!("location #{}", index),
+    }
+}
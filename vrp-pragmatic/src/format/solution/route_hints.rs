@@ -0,0 +1,45 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/route_hints_test.rs"]
+mod route_hints_test;
+
+use crate::format::problem::{Relation, RelationType};
+use crate::format::solution::Solution;
+use std::collections::HashSet;
+
+/// Creates soft precedence relations which keep order of jobs in each tour of given solution,
+/// e.g. produced by an external route sequencing tool. Added to the problem plan together with
+/// the solution used as initial one, they seed the search with hinted sequences: the solver can
+/// still reorder jobs or move them to other tours, but each violated order is penalized.
+///
+/// NOTE jobs are not tied to the vehicle, so job assignment is not affected by hints.
+pub fn create_route_hint_relations(solution: &Solution, penalty: Option<f64>) -> Vec<Relation> {
+    solution
+        .tours
+        .iter()
+        .filter_map(|tour| {
+            let mut visited = HashSet::new();
+            let jobs = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter())
+                .filter(|activity| {
+                    matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "replacement" | "service")
+                })
+                .filter(|activity| visited.insert(activity.job_id.clone()))
+                .map(|activity| activity.job_id.clone())
+                .collect::<Vec<_>>();
+
+            if jobs.len() < 2 {
+                None
+            } else {
+                Some(Relation {
+                    type_field: RelationType::SoftPrecedence,
+                    jobs,
+                    vehicle_id: None,
+                    shift_index: None,
+                    penalty,
+                })
+            }
+        })
+        .collect()
+}
@@ -3,17 +3,23 @@
 mod writer_test;
 
 use crate::format::coord_index::CoordIndex;
+use crate::format::problem::{
+    get_lateness_fn, get_revenue_job_filter, get_shift_end_fn, CoincidentJobsPolicy, MONETARY_RATES_KEY,
+};
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use crate::format::*;
 use crate::format_time;
+use std::collections::HashSet;
 use std::io::{BufWriter, Write};
 use vrp_core::construction::constraints::route_intervals;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::Multi;
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::models::{Problem, Solution};
-use vrp_core::solver::Metrics;
+use vrp_core::solver::objectives::{MonetaryCost, MonetaryRates};
+use vrp_core::solver::termination::{TerminationReason, TerminationReport};
+use vrp_core::solver::{Metrics, PENALTY_LIMIT_CODE, SCHEDULE_ONLY_KEY};
 
 type ApiActivity = crate::format::solution::model::Activity;
 type ApiSolution = crate::format::solution::model::Solution;
@@ -21,7 +27,10 @@ type ApiSchedule = crate::format::solution::model::Schedule;
 type ApiMetrics = crate::format::solution::model::Metrics;
 type ApiGeneration = crate::format::solution::model::Generation;
 type ApiIndividual = crate::format::solution::model::Individual;
+type ApiTermination = crate::format::solution::model::Termination;
+type ApiMonetaryCost = crate::format::solution::model::MonetaryCost;
 type DomainSchedule = vrp_core::models::common::Schedule;
+type ApiLocation = crate::format::Location;
 type DomainLocation = vrp_core::models::common::Location;
 type DomainExtras = vrp_core::models::Extras;
 
@@ -32,6 +41,9 @@ pub trait PragmaticSolution<W: Write> {
 
     /// Serializes solution in pragmatic geo json format.
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Serializes solution in plain text route card format.
+    fn write_route_card(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
 }
 
 impl<W: Write> PragmaticSolution<W> for Solution {
@@ -46,6 +58,12 @@ impl<W: Write> PragmaticSolution<W> for Solution {
         serialize_solution_as_geojson(writer, &solution).map_err(|err| err.to_string())?;
         Ok(())
     }
+
+    fn write_route_card(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let solution = create_solution(problem, self, None);
+        serialize_solution_as_route_card(writer, &solution).map_err(|err| err.to_string())?;
+        Ok(())
+    }
 }
 
 impl<W: Write> PragmaticSolution<W> for (Solution, Metrics) {
@@ -58,21 +76,31 @@ impl<W: Write> PragmaticSolution<W> for (Solution, Metrics) {
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
         self.0.write_geo_json(problem, writer)
     }
+
+    fn write_route_card(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        self.0.write_route_card(problem, writer)
+    }
 }
 
 struct Leg {
     pub last_detail: Option<(DomainLocation, Timestamp)>,
     pub load: Option<MultiDimLoad>,
     pub statistic: Statistic,
+    pub consumption: Option<Consumption>,
 }
 
 impl Leg {
-    fn new(last_detail: Option<(DomainLocation, Timestamp)>, load: Option<MultiDimLoad>, statistic: Statistic) -> Self {
-        Self { last_detail, load, statistic }
+    fn new(
+        last_detail: Option<(DomainLocation, Timestamp)>,
+        load: Option<MultiDimLoad>,
+        statistic: Statistic,
+        consumption: Option<Consumption>,
+    ) -> Self {
+        Self { last_detail, load, statistic, consumption }
     }
 
     fn empty() -> Self {
-        Self { last_detail: None, load: None, statistic: Statistic::default() }
+        Self { last_detail: None, load: None, statistic: Statistic::default(), consumption: None }
     }
 }
 
@@ -87,13 +115,17 @@ pub fn create_solution(problem: &Problem, solution: &Solution, metrics: Option<&
     let unassigned = create_unassigned(solution);
     let violations = create_violations(solution);
 
-    let extras = create_extras(solution, metrics);
+    let bbox = get_bounding_box(tours.as_slice());
+    let extras = create_extras(problem, solution, metrics, bbox);
 
     ApiSolution { statistic, tours, unassigned, violations, extras }
 }
 
 fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> Tour {
     let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
+    let is_separate_stops = has_separate_stops(problem.extras.as_ref());
+    let is_cumulative_stats = has_cumulative_stats(problem.extras.as_ref());
+    let consumption_func = get_consumption_func(problem.extras.as_ref());
 
     let actor = route.actor.as_ref();
     let vehicle = actor.vehicle.as_ref();
@@ -102,8 +134,10 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
         vehicle_id: vehicle.dimens.get_id().unwrap().clone(),
         type_id: vehicle.dimens.get_value::<String>("type_id").unwrap().to_string(),
         shift_index: *vehicle.dimens.get_value::<usize>("shift_index").unwrap(),
+        day: vehicle.dimens.get_value::<usize>("day").cloned(),
         stops: vec![],
         statistic: Statistic::default(),
+        consumption: None,
     };
 
     let intervals = route_intervals(route, Box::new(|a| get_activity_type(a).map_or(false, |t| t == "reload")));
@@ -140,6 +174,8 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     location: None,
                     time: None,
                     job_tag: None,
+                    distance: if is_cumulative_stats { Some(0) } else { None },
+                    duration: if is_cumulative_stats { Some(0) } else { None },
                 }],
             });
             (start_idx + 1, start)
@@ -148,7 +184,12 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
         };
 
         let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
-            Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
+            Leg::new(
+                Some((start.place.location, start.schedule.departure)),
+                Some(start_delivery),
+                leg.statistic,
+                leg.consumption,
+            ),
             |leg, act| {
                 let activity_type = get_activity_type(act).cloned();
                 let (prev_location, prev_departure) = leg.last_detail.unwrap();
@@ -176,20 +217,32 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 let driving =
                     problem.transport.duration(vehicle.profile, prev_location, act.place.location, prev_departure);
                 let arrival = prev_departure + driving;
-                let start = act.schedule.arrival.max(act.place.time.start);
-                let waiting = start - act.schedule.arrival;
                 let serving = problem.activity.duration(route.actor.as_ref(), act, act.schedule.arrival);
+                // NOTE schedule can have extra waiting not defined by time window, e.g. for transfer's first leg
+                let start = act.schedule.arrival.max(act.place.time.start).max(act.schedule.departure - serving);
+                let waiting = start - act.schedule.arrival;
                 let departure = start + serving;
 
                 // total cost and distance
+                let leg_distance =
+                    problem.transport.distance(vehicle.profile, prev_location, act.place.location, prev_departure);
+                let carried = leg.load.unwrap().as_vec();
                 let cost = leg.statistic.cost
                     + problem.activity.cost(actor, act, act.schedule.arrival)
-                    + problem.transport.cost(actor, prev_location, act.place.location, prev_departure);
-                let distance = leg.statistic.distance
-                    + problem.transport.distance(vehicle.profile, prev_location, act.place.location, prev_departure)
-                        as i64;
-
-                if prev_location != act.place.location {
+                    + problem.transport.cost(actor, prev_location, act.place.location, prev_departure)
+                    + get_load_distance_cost(vehicle, carried.as_slice(), leg_distance);
+                let distance = leg.statistic.distance + leg_distance as i64;
+                let duration = leg.statistic.duration + departure as i64 - prev_departure as i64;
+
+                // fuel consumption and emissions
+                let consumption = consumption_func(
+                    vehicle,
+                    &ConsumptionLeg { distance: leg_distance, duration: driving, load: carried.as_slice() },
+                )
+                .map(|delta| leg.consumption.clone().unwrap_or_default() + delta)
+                .or_else(|| leg.consumption.clone());
+
+                if is_separate_stops || prev_location != act.place.location {
                     tour.stops.push(Stop {
                         location: coord_index.get_by_idx(act.place.location).unwrap(),
                         time: format_as_schedule(&(arrival, departure)),
@@ -212,6 +265,8 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     location: Some(coord_index.get_by_idx(act.place.location).unwrap()),
                     time: Some(Interval { start: format_time(arrival), end: format_time(departure) }),
                     job_tag,
+                    distance: if is_cumulative_stats { Some(distance) } else { None },
+                    duration: if is_cumulative_stats { Some(duration) } else { None },
                 });
 
                 Leg {
@@ -219,15 +274,17 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     statistic: Statistic {
                         cost,
                         distance,
-                        duration: leg.statistic.duration + departure as i64 - prev_departure as i64,
+                        duration,
                         times: Timing {
                             driving: leg.statistic.times.driving + driving as i64,
                             serving: leg.statistic.times.serving + (if is_break { 0 } else { serving as i64 }),
                             waiting: leg.statistic.times.waiting + waiting as i64,
                             break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
                         },
+                        tightness: None,
                     },
                     load: Some(load),
+                    consumption,
                 }
             },
         );
@@ -247,15 +304,55 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
             activity.time = None;
         });
 
-    leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.cost += vehicle.costs.fixed + get_distance_tiers_cost(vehicle, leg.statistic.distance as f64);
+
+    if let Some(max_daily_cost) = vehicle.dimens.get_value::<f64>("max_daily_cost") {
+        leg.statistic.cost = leg.statistic.cost.min(*max_daily_cost);
+    }
 
     tour.vehicle_id = vehicle.dimens.get_id().unwrap().clone();
     tour.type_id = vehicle.dimens.get_value::<String>("type_id").unwrap().clone();
     tour.statistic = leg.statistic;
+    tour.statistic.tightness = get_tightness(route);
+    tour.consumption = leg.consumption;
 
     tour
 }
 
+/// Returns the minimum slack across job activities with time windows: how much later an activity
+/// can start without violating its time window. It is negative when an activity is served late.
+fn get_tightness(route: &Route) -> Option<i64> {
+    route
+        .tour
+        .all_activities()
+        .filter(|activity| activity.job.is_some() && activity.place.time.end < f64::MAX)
+        .filter(|activity| {
+            get_activity_type(activity)
+                .map_or(false, |t| matches!(t.as_str(), "pickup" | "delivery" | "replacement" | "service"))
+        })
+        .map(|activity| (activity.place.time.end - activity.schedule.arrival.max(activity.place.time.start)) as i64)
+        .min()
+}
+
+/// Returns a bounding box of all stop locations, including departure and arrival ones. It is not
+/// defined when there are no tours or some location is not specified by coordinates.
+fn get_bounding_box(tours: &[Tour]) -> Option<BoundingBox> {
+    tours.iter().flat_map(|tour| tour.stops.iter()).try_fold(None, |bbox: Option<BoundingBox>, stop| {
+        match stop.location {
+            ApiLocation::Coordinate { lat, lng } => Some(Some(match bbox {
+                Some(bbox) => BoundingBox {
+                    min_lat: bbox.min_lat.min(lat),
+                    min_lng: bbox.min_lng.min(lng),
+                    max_lat: bbox.max_lat.max(lat),
+                    max_lng: bbox.max_lng.max(lng),
+                },
+                None => BoundingBox { min_lat: lat, min_lng: lng, max_lat: lat, max_lng: lng },
+            })),
+            ApiLocation::Reference { .. } => None,
+        }
+    })?
+}
+
 fn format_schedule(schedule: &DomainSchedule) -> ApiSchedule {
     ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
 }
@@ -282,15 +379,47 @@ fn map_code_reason(code: i32) -> (i32, &'static str) {
         LOCKING_CONSTRAINT_CODE => (104, "cannot be served due to relation lock"),
         PRIORITY_CONSTRAINT_CODE => (105, "cannot be served due to priority"),
         AREA_CONSTRAINT_CODE => (106, "cannot be assigned due to area constraint"),
+        TIME_WINDOW_JOBS_CONSTRAINT_CODE => {
+            (107, "cannot be assigned due to max time window jobs constraint of vehicle")
+        }
+        DEPOT_INVENTORY_CONSTRAINT_CODE => (108, "cannot be assigned due to depot inventory constraint"),
+        PENALTY_LIMIT_CODE => (109, "cannot be assigned due to soft penalty limit"),
+        AGGREGATE_CAPACITY_CONSTRAINT_CODE => (110, "cannot be assigned due to aggregate capacity of vehicle"),
+        ANCHOR_CONSTRAINT_CODE => (111, "anchor is not assignable"),
+        COINCIDENT_JOBS_CONSTRAINT_CODE => {
+            (112, "cannot be served within one stop with other jobs at the same location")
+        }
+        COMPANION_CONSTRAINT_CODE => (113, "cannot be assigned to a tour without companion jobs"),
+        TRANSFER_CONSTRAINT_CODE => (114, "cannot be assigned in sync with transfer job served by other tour"),
+        RECEIVING_RATE_CONSTRAINT_CODE => (115, "cannot be assigned due to receiving rate of location"),
+        VEHICLE_POOL_CONSTRAINT_CODE => (116, "cannot be assigned as all vehicles of the pool are in use"),
+        TIME_WINDOW_DENSITY_CONSTRAINT_CODE => {
+            (117, "cannot be assigned due to max hourly time window jobs constraint of vehicle")
+        }
+        CORRIDOR_CONSTRAINT_CODE => (118, "cannot be served in order of its service corridor"),
+        CREW_SIZE_CONSTRAINT_CODE => (119, "cannot be served by vehicle with required crew size"),
+        LATE_STOPS_CONSTRAINT_CODE => (120, "cannot be assigned due to max late stops constraint of vehicle"),
+        LOADING_DISCIPLINE_CONSTRAINT_CODE => (121, "cannot be served due to loading discipline of vehicle"),
+        SKILL_TIME_WINDOWS_CONSTRAINT_CODE => {
+            (122, "cannot be served within time window which requires vehicle skills")
+        }
+        FIRST_LEG_DISTANCE_CONSTRAINT_CODE => {
+            (123, "cannot be assigned due to max first leg distance constraint of vehicle")
+        }
+        CAPACITY_DECAY_CONSTRAINT_CODE => (124, "cannot be assigned due to capacity decay of vehicle"),
+        ATTRIBUTE_RULES_CONSTRAINT_CODE => (125, "cannot be assigned due to attribute rule"),
         _ => (0, "unknown"),
     }
 }
 
 fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
+    // NOTE job variants share the same id, so they are reported once
+    let mut job_ids = HashSet::new();
     let unassigned = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_value::<String>("vehicle_id").is_none())
+        .filter(|(job, _)| job_ids.insert(job.dimens().get_id().cloned()))
         .map(|(job, code)| {
             let (code, reason) = map_code_reason(*code);
             UnassignedJob {
@@ -308,16 +437,30 @@ fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
 }
 
 fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
-    // NOTE at the moment only break violation is mapped
+    // NOTE at the moment only break, anchor and time window violations are mapped
+    let time_window_violations =
+        if is_schedule_only(solution.extras.as_ref()) { solution.routes.as_slice() } else { &[] };
+
     let violations = solution
         .unassigned
         .iter()
-        .filter(|(job, _)| job.dimens().get_value::<String>("type").map_or(false, |t| t == "break"))
-        .map(|(job, code)| Violation::Break {
-            vehicle_id: job.dimens().get_value::<String>("vehicle_id").expect("vehicle id").clone(),
-            shift_index: *job.dimens().get_value::<usize>("shift_index").expect("shift index"),
-            reason: map_code_reason(*code).1.to_string(),
+        .filter_map(|(job, code)| {
+            let dimens = job.dimens();
+            let vehicle_id = || dimens.get_value::<String>("vehicle_id").expect("vehicle id").clone();
+            let shift_index = || *dimens.get_value::<usize>("shift_index").expect("shift index");
+            let reason = || map_code_reason(*code).1.to_string();
+
+            match dimens.get_value::<String>("type").map(|t| t.as_str()) {
+                Some("break") => {
+                    Some(Violation::Break { vehicle_id: vehicle_id(), shift_index: shift_index(), reason: reason() })
+                }
+                Some("anchor") => {
+                    Some(Violation::Anchor { vehicle_id: vehicle_id(), shift_index: shift_index(), reason: reason() })
+                }
+                _ => None,
+            }
         })
+        .chain(time_window_violations.iter().flat_map(create_time_window_violations))
         .collect::<Vec<_>>();
 
     if violations.is_empty() {
@@ -327,6 +470,34 @@ fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
     }
 }
 
+fn create_time_window_violations(route: &Route) -> impl Iterator<Item = Violation> + '_ {
+    // NOTE can happen only when solution is not produced by the solver, e.g. schedule of fixed tours,
+    // so it is checked only for solutions marked as schedule only
+    let vehicle = route.actor.vehicle.as_ref();
+    let vehicle_id = move || vehicle.dimens.get_id().unwrap().clone();
+    let shift_index = move || *vehicle.dimens.get_value::<usize>("shift_index").unwrap();
+
+    route.tour.all_activities().filter(|activity| activity.schedule.arrival > activity.place.time.end).filter_map(
+        move |activity| {
+            let reason = format!(
+                "arrival at '{}' is later than time window end at '{}'",
+                format_time(activity.schedule.arrival),
+                format_time(activity.place.time.end)
+            );
+
+            // NOTE break ids are internal, so late break is reported as break violation
+            match get_activity_type(activity).map(|t| t.as_str()) {
+                Some("break") => {
+                    Some(Violation::Break { vehicle_id: vehicle_id(), shift_index: shift_index(), reason })
+                }
+                _ => activity.retrieve_job().and_then(|job| job.dimens().get_id().cloned()).map(|job_id| {
+                    Violation::TimeWindow { vehicle_id: vehicle_id(), shift_index: shift_index(), job_id, reason }
+                }),
+            }
+        },
+    )
+}
+
 fn get_activity_type(activity: &Activity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_value::<String>("type"))
 }
@@ -349,6 +520,21 @@ fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<Mult
     }
 }
 
+fn has_separate_stops(extras: &DomainExtras) -> bool {
+    extras
+        .get("coincident_jobs")
+        .and_then(|s| s.downcast_ref::<CoincidentJobsPolicy>())
+        .map_or(false, |policy| *policy == CoincidentJobsPolicy::Separate)
+}
+
+fn has_cumulative_stats(extras: &DomainExtras) -> bool {
+    extras.get("cumulative_stats").and_then(|s| s.downcast_ref::<bool>()).map_or(false, |value| *value)
+}
+
+fn is_schedule_only(extras: &DomainExtras) -> bool {
+    extras.get(SCHEDULE_ONLY_KEY).and_then(|s| s.downcast_ref::<bool>()).map_or(false, |value| *value)
+}
+
 fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
     let capacity_type = extras
         .get("capacity_type")
@@ -361,37 +547,82 @@ fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
     }
 }
 
-fn create_extras(_solution: &Solution, metrics: Option<&Metrics>) -> Option<Extras> {
-    if let Some(metrics) = metrics {
-        Some(Extras {
-            metrics: Some(ApiMetrics {
-                duration: metrics.duration,
-                generations: metrics.generations,
-                speed: metrics.speed,
-                evolution: metrics
-                    .evolution
+fn create_extras(
+    problem: &Problem,
+    solution: &Solution,
+    metrics: Option<&Metrics>,
+    bbox: Option<BoundingBox>,
+) -> Option<Extras> {
+    let metrics = metrics.map(|metrics| ApiMetrics {
+        duration: metrics.duration,
+        generations: metrics.generations,
+        speed: metrics.speed,
+        evolution: metrics
+            .evolution
+            .iter()
+            .map(|g| ApiGeneration {
+                number: g.number,
+                timestamp: g.timestamp,
+                i_all_ratio: g.i_all_ratio,
+                i_1000_ratio: g.i_1000_ratio,
+                is_improvement: g.is_improvement,
+                population: g
+                    .population
                     .iter()
-                    .map(|g| ApiGeneration {
-                        number: g.number,
-                        timestamp: g.timestamp,
-                        i_all_ratio: g.i_all_ratio,
-                        i_1000_ratio: g.i_1000_ratio,
-                        is_improvement: g.is_improvement,
-                        population: g
-                            .population
-                            .iter()
-                            .map(|i| ApiIndividual {
-                                tours: i.tours,
-                                unassigned: i.unassigned,
-                                cost: i.cost,
-                                improvement: i.improvement,
-                                fitness: i.fitness.clone(),
-                            })
-                            .collect(),
+                    .map(|i| ApiIndividual {
+                        tours: i.tours,
+                        unassigned: i.unassigned,
+                        cost: i.cost,
+                        improvement: i.improvement,
+                        fitness: i.fitness.clone(),
                     })
                     .collect(),
-            }),
-        })
+            })
+            .collect(),
+        lower_bound: metrics.lower_bound,
+        gap: metrics.gap,
+    });
+
+    let termination =
+        solution.extras.get("termination").and_then(|report| report.downcast_ref::<TerminationReport>()).map(
+            |report| ApiTermination {
+                reason: match report.reason {
+                    TerminationReason::MaxGenerations => "max_generations",
+                    TerminationReason::MaxTime => "max_time",
+                    TerminationReason::Stagnation => "stagnation",
+                    TerminationReason::CustomTermination => "custom_termination",
+                    TerminationReason::Cancelled => "cancelled",
+                }
+                .to_string(),
+                generations: report.generations,
+                duration: report.duration,
+            },
+        );
+
+    let monetary =
+        problem.extras.get(MONETARY_RATES_KEY).and_then(|rates| rates.downcast_ref::<MonetaryRates>()).map(|rates| {
+            let breakdown = MonetaryCost::get_breakdown(
+                rates,
+                &get_shift_end_fn(),
+                &get_revenue_job_filter(),
+                &get_lateness_fn(),
+                problem.transport.as_ref(),
+                solution,
+            );
+
+            ApiMonetaryCost {
+                distance: breakdown.distance,
+                time: breakdown.time,
+                overtime: breakdown.overtime,
+                fixed: breakdown.fixed,
+                unassigned: breakdown.unassigned,
+                late: breakdown.late,
+                total: breakdown.total(),
+            }
+        });
+
+    if metrics.is_some() || termination.is_some() || monetary.is_some() || bbox.is_some() {
+        Some(Extras { metrics, config: None, termination, monetary, bbox })
     } else {
         None
     }
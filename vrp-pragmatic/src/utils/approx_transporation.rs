@@ -2,14 +2,25 @@
 #[path = "../../tests/unit/utils/approx_transportation_test.rs"]
 mod approx_transportation_test;
 
+use crate::format::problem::CoordinateSystem;
 use crate::format::Location;
 use vrp_core::utils::parallel_collect;
 
-/// Gets approximated durations and distances rounded to nearest integer.
-pub fn get_approx_transportation(locations: &[Location], speeds: &[f64]) -> Vec<(Vec<i64>, Vec<i64>)> {
+/// Gets approximated durations and distances rounded to nearest integer. Distances are calculated
+/// according to given coordinate system.
+pub fn get_approx_transportation(
+    locations: &[Location],
+    speeds: &[f64],
+    coordinate_system: CoordinateSystem,
+) -> Vec<(Vec<i64>, Vec<i64>)> {
     assert!(!speeds.is_empty());
     assert!(speeds.iter().all(|&speed| speed > 0.));
 
+    let get_distance = match coordinate_system {
+        CoordinateSystem::Geographic => get_haversine_distance,
+        CoordinateSystem::Projected => get_euclidean_distance,
+    };
+
     let distances =
         locations.iter().flat_map(|l1| locations.iter().map(move |l2| get_distance(l1, l2))).collect::<Vec<_>>();
 
@@ -23,7 +34,7 @@ pub fn get_approx_transportation(locations: &[Location], speeds: &[f64]) -> Vec<
 }
 
 /// Gets distance between two points using haversine formula.
-fn get_distance(p1: &Location, p2: &Location) -> f64 {
+fn get_haversine_distance(p1: &Location, p2: &Location) -> f64 {
     let (p1_lat, p1_lng) = as_lat_lon(p1.clone());
     let (p2_lat, p2_lng) = as_lat_lon(p2.clone());
 
@@ -42,6 +53,14 @@ fn get_distance(p1: &Location, p2: &Location) -> f64 {
     radius * c
 }
 
+/// Gets euclidean distance between two points of projected coordinate system.
+fn get_euclidean_distance(p1: &Location, p2: &Location) -> f64 {
+    let (p1_y, p1_x) = as_lat_lon(p1.clone());
+    let (p2_y, p2_x) = as_lat_lon(p2.clone());
+
+    ((p1_x - p2_x).powi(2) + (p1_y - p2_y).powi(2)).sqrt()
+}
+
 /// Converts degrees to radians.
 #[inline(always)]
 fn degree_rad(degrees: f64) -> f64 {
@@ -3,7 +3,10 @@
 mod jobs_test;
 
 use super::*;
-use vrp_core::models::common::MultiDimLoad;
+use crate::constraints::AttributeRule;
+use crate::parse_time_safe;
+use std::collections::HashSet;
+use vrp_core::models::common::{MultiDimLoad, TimeWindow};
 
 /// Checks that plan has no jobs with duplicate ids.
 fn check_e1100_no_jobs_with_duplicate_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -74,13 +77,26 @@ fn check_e1102_multiple_pickups_deliveries_demand(ctx: &ValidationContext) -> Re
 
 /// Checks that job's time windows are correct.
 fn check_e1103_time_window_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let time_factor = ctx
+        .problem
+        .config
+        .as_ref()
+        .and_then(|config| config.time_windows.as_ref())
+        .and_then(|time_windows| time_windows.unit)
+        .map_or(1., |unit| unit.as_seconds());
     let has_invalid_tws = |tasks: &Option<Vec<JobTask>>| {
         tasks.as_ref().map_or(false, |tasks| {
-            tasks
-                .iter()
-                .flat_map(|task| task.places.iter())
-                .filter_map(|place| place.times.as_ref())
-                .any(|tws| !check_raw_time_windows(tws, false))
+            tasks.iter().flat_map(|task| task.places.iter()).any(|place| {
+                // NOTE empty list of time windows explicitly marks that there are none
+                let tws = get_place_time_windows(place, time_factor);
+                let has_invalid_times = !tws.is_empty() && !check_time_windows(&tws, false);
+                let has_invalid_offsets = place
+                    .relative_times
+                    .as_ref()
+                    .map_or(false, |offsets| offsets.iter().any(|offset| offset.len() != 2 || offset[0] > offset[1]));
+
+                has_invalid_times || has_invalid_offsets
+            })
         })
     };
 
@@ -101,6 +117,18 @@ fn check_e1103_time_window_correctness(ctx: &ValidationContext) -> Result<(), Fo
     }
 }
 
+/// Returns absolute time windows of job place including ones specified by opening times.
+fn get_place_time_windows(place: &JobPlace, time_factor: f64) -> Vec<Option<TimeWindow>> {
+    let openings = place.opening_times.iter().flat_map(|openings| openings.iter()).map(|opening| {
+        parse_time_safe(&opening.open)
+            .ok()
+            .filter(|_| opening.duration >= 0.)
+            .map(|start| TimeWindow::new(start, start + opening.duration * time_factor))
+    });
+
+    place.times.as_ref().map(|tws| get_time_windows(tws)).unwrap_or_default().into_iter().chain(openings).collect()
+}
+
 /// Checks that reserved job ids are no used.
 fn check_e1104_no_reserved_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
     let ids = ctx.jobs().filter(|job| is_reserved_job_id(&job.id)).map(|job| job.id.clone()).collect::<Vec<_>>();
@@ -178,6 +206,374 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job's vehicle type durations are not negative and refer to existing vehicle types.
+fn check_e1108_type_durations_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx.vehicles().map(|vehicle| vehicle.type_id.as_str()).collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().filter_map(|task| task.type_durations.as_ref()).flat_map(|tds| tds.iter()).any(
+                |type_duration| {
+                    type_duration.duration.is_sign_negative()
+                        || type_duration.type_ids.is_empty()
+                        || type_duration.type_ids.iter().any(|type_id| !type_ids.contains(type_id.as_str()))
+                },
+            )
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "invalid job type durations".to_string(),
+            format!(
+                "ensure that type durations have non-negative duration and known vehicle type ids, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job's task variants are defined properly.
+fn check_e1109_task_variants_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let relation_ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .flat_map(|relation| relation.jobs.iter())
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            let tasks = ctx.tasks(job);
+            let variants = tasks.iter().filter_map(|task| task.variants.as_ref()).collect::<Vec<_>>();
+
+            if variants.is_empty() {
+                return false;
+            }
+
+            tasks.len() > 1
+                || relation_ids.contains(&job.id)
+                || variants.iter().any(|variants| {
+                    variants.is_empty()
+                        || get_duplicates(variants.iter().map(|variant| &variant.tag)).is_some()
+                        || variants.iter().any(|variant| {
+                            variant.duration.map_or(false, |duration| duration.is_sign_negative())
+                                || variant.demand.as_ref().map_or(false, |demand| demand.iter().any(|d| *d < 0))
+                        })
+                })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "invalid job task variants".to_string(),
+            format!(
+                "ensure that variants are used only within a single task of job not used in relations, have unique \
+                 tags, non-negative demand and duration, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job's companions refer to other existing jobs which have no companions.
+fn check_e1110_companions_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let companions = ctx
+        .jobs()
+        .filter_map(|job| job.companions.as_ref().map(|companions| (job.id.as_str(), companions)))
+        .collect::<HashMap<_, _>>();
+
+    let mut ids = companions
+        .iter()
+        .filter(|(id, job_companions)| {
+            job_companions.iter().any(|companion| {
+                companion == *id
+                    || !ctx.job_index.contains_key(companion)
+                    || companions.contains_key(companion.as_str())
+            })
+        })
+        .map(|(id, _)| id.to_string())
+        .collect::<Vec<_>>();
+    ids.sort();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "invalid job companions".to_string(),
+            format!(
+                "ensure that companions refer to other existing jobs which have no companions, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that time window grace period is non-negative and it is used only with soft time windows.
+fn check_e1111_time_window_grace_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_soft_policy =
+        ctx.problem.config.as_ref().and_then(|config| config.time_windows.as_ref()).map_or(false, |tws| tws.soft);
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            let is_soft = job.hard_time_window.map_or(is_soft_policy, |is_hard| !is_hard);
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter().filter_map(|place| place.grace))
+                .any(|grace| grace.is_sign_negative() || !is_soft)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid time window grace".to_string(),
+            format!("use non-negative grace only with soft time windows, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that transfer job refers to other existing job which is not a transfer job itself and
+/// that transfer job picks up cargo where the other job delivers it.
+fn check_e1112_transfers_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| match job.transfer_from.as_ref().map(|source| (source, ctx.job_index.get(source))) {
+            Some((source, Some(other))) => {
+                let drops = other.deliveries.iter().flatten().flat_map(|task| task.places.iter()).collect::<Vec<_>>();
+                let pickups = job.pickups.iter().flatten().flat_map(|task| task.places.iter()).collect::<Vec<_>>();
+
+                *source == job.id
+                    || other.transfer_from.is_some()
+                    || pickups.is_empty()
+                    || !pickups.iter().all(|pickup| drops.iter().any(|drop| drop.location == pickup.location))
+            }
+            Some((_, None)) => true,
+            None => false,
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid transfer job".to_string(),
+            format!(
+                "ensure that transfer job refers to other existing job which is not a transfer job and \
+                 which delivers cargo to locations of its pickups, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that receiving rates have positive intervals and are defined once per location.
+fn check_e1113_receiving_rates_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let rates = ctx.problem.config.as_ref().and_then(|config| config.receiving_rates.as_ref());
+    let indices = rates
+        .iter()
+        .flat_map(|rates| {
+            rates.iter().enumerate().filter(move |(idx, rate)| {
+                !(rate.interval.is_finite() && rate.interval > 0.)
+                    || rates.iter().take(*idx).any(|other| other.location == rate.location)
+            })
+        })
+        .map(|(idx, _)| idx.to_string())
+        .collect::<Vec<_>>();
+
+    if indices.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "invalid receiving rates".to_string(),
+            format!(
+                "ensure that receiving rate interval is positive and location is not duplicated, rate indices: '{}'",
+                indices.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that service time distribution parameters are non negative and define non empty range.
+fn check_e1114_service_time_distributions_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_valid = |value: f64| value.is_finite() && !value.is_sign_negative();
+    let ids = ctx
+        .jobs()
+        .filter(|job| match job.service_time_distribution {
+            Some(ServiceTimeDistribution::Normal { mean, std_dev }) => !is_valid(mean) || !is_valid(std_dev),
+            Some(ServiceTimeDistribution::Uniform { min, max }) => !is_valid(min) || !is_valid(max) || min > max,
+            None => false,
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1114".to_string(),
+            "invalid service time distribution".to_string(),
+            format!(
+                "ensure that distribution parameters are non-negative and min is not greater than max, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that time window penalties are non-negative and specified per each time window.
+fn check_e1115_time_window_penalties_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).any(|place| match &place.time_penalties {
+                Some(penalties) => {
+                    !matches!(&place.times, Some(times) if times.len() == penalties.len())
+                        || penalties.iter().any(|penalty| !penalty.is_finite() || penalty.is_sign_negative())
+                }
+                None => false,
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1115".to_string(),
+            "invalid time window penalties".to_string(),
+            format!(
+                "ensure that time window penalties are non-negative and specified per each time window, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job crew sizes are positive.
+fn check_e1116_crew_sizes_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx.jobs().filter(|job| job.crew_size == Some(0)).map(|job| job.id.clone()).collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1116".to_string(),
+            "invalid job crew size".to_string(),
+            format!("ensure that crew size is positive, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that skill time windows have skills and correct time windows.
+fn check_e1117_skill_time_windows_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .flat_map(|place| place.skill_times.iter().flat_map(|skill_times| skill_times.iter()))
+                .any(|skill_times| {
+                    skill_times.skills.is_empty() || !check_time_windows(&get_time_windows(&skill_times.times), false)
+                })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1117".to_string(),
+            "invalid skill time windows".to_string(),
+            format!(
+                "ensure that skill time windows have at least one skill and valid time windows, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job workloads are non-negative.
+fn check_e1118_workloads_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.workload.map_or(false, |workload| !workload.is_finite() || workload.is_sign_negative()))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1118".to_string(),
+            "invalid job workload".to_string(),
+            format!("ensure that workload is non-negative, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that priority escalation is correct.
+fn check_e1119_priority_escalation_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let escalation = ctx.problem.config.as_ref().and_then(|config| config.priority_escalation.as_ref());
+
+    match escalation {
+        Some(escalation) if escalation.cycles == 0 || escalation.max_priority.map_or(false, |max| max < 1) => {
+            Err(FormatError::new(
+                "E1119".to_string(),
+                "invalid priority escalation".to_string(),
+                "ensure that cycles is positive and max priority is not less than 1".to_string(),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks that attribute rules can be parsed.
+fn check_e1120_attribute_rules_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let errors = ctx
+        .problem
+        .config
+        .as_ref()
+        .and_then(|config| config.rules.as_ref())
+        .iter()
+        .flat_map(|rules| rules.iter())
+        .filter_map(|rule| AttributeRule::parse(rule).err().map(|err| format!("'{}': {}", rule, err)))
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1120".to_string(),
+            "invalid attribute rules".to_string(),
+            format!("fix syntax of rules: {}", errors.join(", ")),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -189,5 +585,18 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_type_durations_are_correct(ctx),
+        check_e1109_task_variants_are_correct(ctx),
+        check_e1110_companions_are_correct(ctx),
+        check_e1111_time_window_grace_is_correct(ctx),
+        check_e1112_transfers_are_correct(ctx),
+        check_e1113_receiving_rates_are_correct(ctx),
+        check_e1114_service_time_distributions_are_correct(ctx),
+        check_e1115_time_window_penalties_are_correct(ctx),
+        check_e1116_crew_sizes_are_correct(ctx),
+        check_e1117_skill_time_windows_are_correct(ctx),
+        check_e1118_workloads_are_correct(ctx),
+        check_e1119_priority_escalation_is_correct(ctx),
+        check_e1120_attribute_rules_are_correct(ctx),
     ])
 }
@@ -28,11 +28,24 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 MinimizeCost => acc.entry("minimize-cost"),
                 MinimizeTours => acc.entry("minimize-tours"),
                 MaximizeTours => acc.entry("maximize-tours"),
+                TargetTours { .. } => acc.entry("target-tours"),
+                MinimizeLongTours { .. } => acc.entry("minimize-long-tours"),
+                MinimizeOvertimeTours => acc.entry("minimize-overtime-tours"),
+                CompactTours => acc.entry("compact-tours"),
+                MinimizeDeadhead => acc.entry("minimize-deadhead"),
+                MinimizeWeightedLateness => acc.entry("minimize-weighted-lateness"),
+                MinimizeOverlap { .. } => acc.entry("minimize-overlap"),
+                MinimizeIntersections => acc.entry("minimize-intersections"),
+                MinimizeEtaVariance => acc.entry("minimize-eta-variance"),
+                MinimizeMonetaryCost { .. } => acc.entry("minimize-monetary-cost"),
                 MinimizeUnassignedJobs => acc.entry("minimize-unassigned"),
                 BalanceMaxLoad { .. } => acc.entry("balance-max-load"),
                 BalanceActivities { .. } => acc.entry("balance-activities"),
                 BalanceDistance { .. } => acc.entry("balance-distance"),
                 BalanceDuration { .. } => acc.entry("balance-duration"),
+                BalanceStops => acc.entry("balance-stops"),
+                HistoryAffinity => acc.entry("history-affinity"),
+                WeightedSum { .. } => acc.entry("weighted-sum"),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -77,12 +90,157 @@ fn check_e1602_no_cost_value_objective(objectives: &[&Objective]) -> Result<(),
     }
 }
 
+/// Checks that weighted lateness objective is used only with soft time windows.
+fn check_e1603_weighted_lateness_with_hard_time_windows(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_weighted_lateness = objectives.iter().any(|objective| matches!(objective, MinimizeWeightedLateness));
+    let is_soft_policy = ctx
+        .problem
+        .config
+        .as_ref()
+        .and_then(|config| config.time_windows.as_ref())
+        .map_or(false, |time_windows| time_windows.soft);
+    let has_soft_time_windows =
+        ctx.problem.plan.jobs.iter().any(|job| job.hard_time_window.map_or(is_soft_policy, |is_hard| !is_hard));
+
+    if has_weighted_lateness && !has_soft_time_windows {
+        Err(FormatError::new(
+            "E1603".to_string(),
+            "weighted lateness objective without soft time windows".to_string(),
+            "make time windows soft via 'config.timeWindows' or job 'hardTimeWindow' property".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that overlap objective has positive grid resolution and is used only with coordinates.
+fn check_e1604_overlap_objective_is_correct(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let (_, has_indices) = ctx.coord_index.get_used_types();
+    let is_invalid = objectives.iter().any(|objective| match objective {
+        MinimizeOverlap { resolution } => has_indices || *resolution <= 0.,
+        _ => false,
+    });
+
+    if is_invalid {
+        Err(FormatError::new(
+            "E1604".to_string(),
+            "invalid overlap objective".to_string(),
+            "use geocoordinates for locations and specify positive 'resolution'".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that weighted sum objective has inner objectives with positive weights, and they are not
+/// maximized or weighted sums themselves.
+fn check_e1605_weighted_sum_objective_is_correct(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let is_invalid = objectives.iter().any(|objective| match objective {
+        WeightedSum { objectives, .. } => {
+            objectives.is_empty()
+                || objectives.iter().any(|weighted| {
+                    weighted.weight <= 0. || matches!(weighted.objective, MaximizeTours | WeightedSum { .. })
+                })
+        }
+        _ => false,
+    });
+
+    if is_invalid {
+        Err(FormatError::new(
+            "E1605".to_string(),
+            "invalid weighted sum objective".to_string(),
+            "specify at least one objective with positive weight, do not use 'maximize-tours' or 'weighted-sum' inside"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that overtime objective is used only with shift ends which allow overtime.
+fn check_e1606_overtime_objective_without_overtime(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_overtime_objective = objectives.iter().any(|objective| matches!(objective, MinimizeOvertimeTours));
+    let has_overtime = ctx
+        .vehicles()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .any(|shift| shift.end.as_ref().and_then(|end| end.max_overtime).is_some());
+
+    if has_overtime_objective && !has_overtime {
+        Err(FormatError::new(
+            "E1606".to_string(),
+            "overtime objective without overtime".to_string(),
+            "specify 'maxOvertime' property of vehicle shift end".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that intersections objective is used only with coordinates.
+fn check_e1607_intersections_objective_without_coordinates(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let (_, has_indices) = ctx.coord_index.get_used_types();
+    let has_intersections_objective = objectives.iter().any(|objective| matches!(objective, MinimizeIntersections));
+
+    if has_intersections_objective && has_indices {
+        Err(FormatError::new(
+            "E1607".to_string(),
+            "intersections objective without coordinates".to_string(),
+            "use geocoordinates for locations or remove 'minimize-intersections' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that monetary cost objective has no negative rates.
+fn check_e1608_monetary_cost_objective_is_correct(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let is_invalid = objectives.iter().any(|objective| match objective {
+        MinimizeMonetaryCost { rates } => {
+            [rates.distance, rates.time, rates.overtime, rates.fixed, rates.unassigned, rates.late]
+                .iter()
+                .any(|rate| rate.map_or(false, |rate| rate < 0.))
+        }
+        _ => false,
+    });
+
+    if is_invalid {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "invalid monetary cost objective".to_string(),
+            "specify non negative rates of 'minimize-monetary-cost' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| {
         Some(&objectives.primary)
             .iter()
             .chain(objectives.secondary.as_ref().iter())
+            .chain(objectives.tie_break.as_ref().iter())
             .flat_map(|objectives| objectives.iter())
+            .flat_map(|objective| {
+                let inners = match objective {
+                    WeightedSum { objectives, .. } => objectives.iter().map(|weighted| &weighted.objective).collect(),
+                    _ => vec![],
+                };
+
+                Some(objective).into_iter().chain(inners)
+            })
             .collect()
     })
 }
@@ -93,6 +251,12 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), Vec<FormatErro
             check_e1600_empty_objective(&objectives),
             check_e1601_duplicate_objectives(&objectives),
             check_e1602_no_cost_value_objective(&objectives),
+            check_e1603_weighted_lateness_with_hard_time_windows(ctx, &objectives),
+            check_e1604_overlap_objective_is_correct(ctx, &objectives),
+            check_e1605_weighted_sum_objective_is_correct(&objectives),
+            check_e1606_overtime_objective_without_overtime(ctx, &objectives),
+            check_e1607_intersections_objective_without_coordinates(ctx, &objectives),
+            check_e1608_monetary_cost_objective_is_correct(&objectives),
         ])
     } else {
         Ok(())
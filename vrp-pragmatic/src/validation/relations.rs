@@ -37,7 +37,7 @@ fn check_e1201_vehicle_existence(
 ) -> Result<(), FormatError> {
     let vehicle_ids = relations
         .iter()
-        .map(|relation| relation.vehicle_id.clone())
+        .filter_map(|relation| relation.vehicle_id.clone())
         .filter(|vehicle_id| !vehicle_map.contains_key(vehicle_id))
         .collect::<Vec<_>>();
 
@@ -73,7 +73,7 @@ fn check_e1203_no_multiple_places_times(ctx: &ValidationContext, relations: &[Re
     let mut job_ids = relations
         .iter()
         .filter(|relation| match relation.type_field {
-            RelationType::Any => false,
+            RelationType::Any | RelationType::SoftPrecedence => false,
             _ => true,
         })
         .flat_map(|relation| {
@@ -115,15 +115,15 @@ fn check_e1204_job_assigned_to_multiple_vehicles(relations: &[Relation]) -> Resu
     let mut job_vehicle_map = HashMap::<String, String>::new();
     let job_ids: Vec<String> = relations
         .iter()
-        .flat_map(|relation| {
+        .filter_map(|relation| relation.vehicle_id.as_ref().map(|vehicle_id| (vehicle_id, relation)))
+        .flat_map(|(vehicle_id, relation)| {
             relation
                 .jobs
                 .clone()
                 .into_iter()
                 .filter(|job_id| !is_reserved_job_id(job_id))
                 .filter(|job_id| {
-                    *job_vehicle_map.entry(job_id.clone()).or_insert_with(|| relation.vehicle_id.clone())
-                        != relation.vehicle_id
+                    *job_vehicle_map.entry(job_id.clone()).or_insert_with(|| vehicle_id.clone()) != *vehicle_id
                 })
                 .collect::<Vec<String>>()
                 .into_iter()
@@ -147,9 +147,11 @@ fn check_e1205_relation_has_correct_shift_index(
 ) -> Result<(), FormatError> {
     let vehicle_ids: Vec<String> = relations
         .iter()
-        .filter_map(|relation| vehicle_map.get(&relation.vehicle_id).map(|vehicle| (vehicle, relation)))
+        .filter_map(|relation| {
+            relation.vehicle_id.as_ref().and_then(|id| vehicle_map.get(id)).map(|vehicle| (vehicle, relation))
+        })
         .filter(|(vehicle, relation)| vehicle.shifts.get(relation.shift_index.unwrap_or(0)).is_none())
-        .map(|(_, relation)| relation.vehicle_id.clone())
+        .filter_map(|(_, relation)| relation.vehicle_id.clone())
         .collect::<Vec<_>>();
 
     if vehicle_ids.is_empty() {
@@ -174,8 +176,10 @@ fn check_e1206_relation_has_no_missing_shift_properties(
     let vehicle_ids: Vec<String> = relations
         .iter()
         .filter_map(|relation| {
-            vehicle_map
-                .get(&relation.vehicle_id)
+            relation
+                .vehicle_id
+                .as_ref()
+                .and_then(|id| vehicle_map.get(id))
                 .and_then(|vehicle| vehicle.shifts.get(relation.shift_index.unwrap_or(0)))
                 .map(|vehicle_shift| (vehicle_shift, relation))
         })
@@ -188,7 +192,7 @@ fn check_e1206_relation_has_no_missing_shift_properties(
                 _ => false,
             })
         })
-        .map(|(_, relation)| relation.vehicle_id.clone())
+        .filter_map(|(_, relation)| relation.vehicle_id.clone())
         .collect::<Vec<_>>();
 
     if vehicle_ids.is_empty() {
@@ -206,6 +210,48 @@ fn check_e1206_relation_has_no_missing_shift_properties(
     }
 }
 
+/// Checks that vehicle id is specified for relations which lock jobs to the vehicle.
+fn check_e1207_relation_has_vehicle_id(relations: &[Relation]) -> Result<(), FormatError> {
+    let has_missing_vehicle_id = relations
+        .iter()
+        .any(|relation| !matches!(relation.type_field, RelationType::SoftPrecedence) && relation.vehicle_id.is_none());
+
+    if has_missing_vehicle_id {
+        Err(FormatError::new(
+            "E1207".to_string(),
+            "relation has no vehicle id".to_string(),
+            "specify vehicle id for any, sequence and strict relation types".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that soft precedence relation is not bound to the vehicle and has only job ids from the plan.
+fn check_e1208_soft_precedence_is_correct(relations: &[Relation]) -> Result<(), FormatError> {
+    let has_invalid_relations = relations
+        .iter()
+        .filter(|relation| matches!(relation.type_field, RelationType::SoftPrecedence))
+        .any(|relation| {
+            relation.vehicle_id.is_some()
+                || relation.shift_index.is_some()
+                || relation.penalty.map_or(false, |penalty| penalty < 0.)
+                || relation.jobs.iter().any(|job_id| is_reserved_job_id(job_id))
+        });
+
+    if has_invalid_relations {
+        Err(FormatError::new(
+            "E1208".to_string(),
+            "invalid soft precedence relation".to_string(),
+            "remove vehicle id, shift index and special job ids from soft precedence relation, \
+             make sure that penalty is not negative"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 /// Validates relations in the plan.
 pub fn validate_relations(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let vehicle_map = ctx
@@ -223,6 +269,8 @@ pub fn validate_relations(ctx: &ValidationContext) -> Result<(), Vec<FormatError
             check_e1204_job_assigned_to_multiple_vehicles(relations),
             check_e1205_relation_has_correct_shift_index(relations, &vehicle_map),
             check_e1206_relation_has_no_missing_shift_properties(relations, &vehicle_map),
+            check_e1207_relation_has_vehicle_id(relations),
+            check_e1208_soft_precedence_is_correct(relations),
         ])
     } else {
         Ok(())
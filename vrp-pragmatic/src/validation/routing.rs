@@ -120,6 +120,25 @@ fn check_e1505_index_size_mismatch(ctx: &ValidationContext) -> Result<(), Format
     }
 }
 
+/// Checks that time units are declared explicitly everywhere when at least one is declared.
+fn check_e1506_ambiguous_time_units(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let matrices = ctx.matrices.map_or(&[] as &[Matrix], |matrices| matrices.as_slice());
+    let tw_unit = ctx.problem.config.as_ref().and_then(|config| config.time_windows.as_ref()).and_then(|tw| tw.unit);
+
+    let has_declared = tw_unit.is_some() || matrices.iter().any(|matrix| matrix.time_unit.is_some());
+    let has_undeclared = tw_unit.is_none() || matrices.iter().any(|matrix| matrix.time_unit.is_none());
+
+    if has_declared && has_undeclared {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "ambiguous time units".to_string(),
+            "specify time unit for all routing matrices and job time windows or remove all of them".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let location_types = ctx.coord_index.get_used_types();
@@ -131,5 +150,6 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_limit_areas_cannot_be_used_with_indices(ctx, location_types),
         check_e1505_index_size_mismatch(ctx),
+        check_e1506_ambiguous_time_units(ctx),
     ])
 }
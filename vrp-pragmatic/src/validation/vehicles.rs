@@ -182,6 +182,400 @@ fn check_e1306_vehicle_depot_is_correct(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+fn check_e1307_depot_inventory_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let inventory = ctx.problem.config.as_ref().and_then(|config| config.depot_inventory.as_ref());
+
+    let is_correct = inventory.map_or(true, |inventory| {
+        !inventory.is_empty()
+            && inventory.iter().all(|value| *value >= 0)
+            && ctx.vehicles().all(|vehicle| vehicle.capacity.len() == inventory.len())
+    });
+
+    if is_correct {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1307".to_string(),
+            "invalid depot inventory".to_string(),
+            "ensure that depot inventory has non negative values and the same dimension as vehicle capacity"
+                .to_string(),
+        ))
+    }
+}
+
+/// Checks that vehicle distance tiers are correct.
+fn check_e1308_vehicle_distance_tiers_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.costs.distance_tiers.as_ref().map_or(false, |tiers| {
+                tiers.is_empty()
+                    || tiers.iter().any(|tier| tier.threshold < 0. || tier.distance < 0.)
+                    || tiers.iter().map(|tier| tier.threshold.to_bits()).collect::<HashSet<_>>().len() != tiers.len()
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1308".to_string(),
+            "invalid distance tiers in vehicle costs".to_string(),
+            format!(
+                "ensure that distance tiers list is not empty, has unique non negative thresholds and \
+                 non negative costs, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle range is positive.
+fn check_e1309_vehicle_range_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.range.map_or(false, |range| range <= 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle range".to_string(),
+            format!("ensure that vehicle range is positive, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle aggregate capacity is correct.
+fn check_e1310_vehicle_aggregate_capacity_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.aggregate_capacity.as_ref().map_or(false, |aggregate| {
+                aggregate.weights.len() != vehicle.capacity.len()
+                    || aggregate.weights.iter().any(|weight| *weight < 0.)
+                    || aggregate.limit < 0.
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid vehicle aggregate capacity".to_string(),
+            format!(
+                "ensure that aggregate capacity has non negative weights and limit, and the same dimension \
+                 as vehicle capacity, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle max daily cost is correct.
+fn check_e1311_vehicle_max_daily_cost_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.costs.max_daily_cost.map_or(false, |cost| cost < 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid vehicle max daily cost".to_string(),
+            format!("ensure that max daily cost is not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle anchors are correct.
+fn check_e1312_vehicle_anchors_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|shift, shift_time| {
+            shift
+                .anchors
+                .as_ref()
+                .map(|anchors| !anchors.is_empty() && check_cargo_place_time_windows(anchors, shift_time))
+                .unwrap_or(true)
+        }),
+    );
+    let has_empty_config_anchors = ctx
+        .problem
+        .config
+        .as_ref()
+        .and_then(|config| config.anchors.as_ref())
+        .map_or(false, |anchors| anchors.is_empty());
+
+    if type_ids.is_empty() && !has_empty_config_anchors {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle anchors".to_string(),
+            format!(
+                "ensure that anchors are not empty and have correct time windows, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle pool size is positive.
+fn check_e1313_vehicle_pool_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let pool = ctx.problem.config.as_ref().and_then(|config| config.vehicle_pool);
+
+    if pool.map_or(true, |size| size > 0) {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle pool".to_string(),
+            "ensure that vehicle pool size is positive".to_string(),
+        ))
+    }
+}
+
+/// Checks that vehicle shift max overtime is not negative.
+fn check_e1314_vehicle_max_overtime_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(
+            |shift, _| !matches!(shift.end.as_ref().and_then(|end| end.max_overtime), Some(overtime) if overtime < 0.),
+        ),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid vehicle max overtime".to_string(),
+            format!("ensure that max overtime is not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle load distance costs are correct.
+fn check_e1315_vehicle_load_distance_costs_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            matches!(vehicle.costs.load_distance.as_ref(),
+                Some(costs) if costs.len() != vehicle.capacity.len() || costs.iter().any(|cost| *cost < 0.))
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1315".to_string(),
+            "invalid load distance costs in vehicle costs".to_string(),
+            format!(
+                "ensure that load distance costs are non negative and have the same dimension as vehicle \
+                 capacity, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle consumption model is correct.
+fn check_e1316_vehicle_consumption_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.consumption.iter().any(|consumption| {
+                consumption.distance < 0.
+                    || consumption.emission_factor < 0.
+                    || matches!(consumption.speed, Some(speed) if speed < 0.)
+                    || matches!(consumption.load.as_ref(), Some(load) if load.len() != vehicle.capacity.len())
+                    || consumption.load.iter().flatten().any(|factor| *factor < 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1316".to_string(),
+            "invalid vehicle consumption model".to_string(),
+            format!(
+                "ensure that consumption model has non negative factors and load factors have the same \
+                 dimension as vehicle capacity, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that planning horizon is correct.
+fn check_e1317_planning_horizon_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let horizon = match ctx.problem.config.as_ref().and_then(|config| config.horizon.as_ref()) {
+        Some(horizon) => horizon,
+        None => return Ok(()),
+    };
+
+    let type_ids = ctx.vehicles().map(|vehicle| vehicle.type_id.as_str()).collect::<HashSet<_>>();
+    let vehicle_ids = ctx.vehicles().flat_map(|vehicle| vehicle.vehicle_ids.iter()).collect::<HashSet<_>>();
+    let fleets = horizon.fleets.iter().flatten().collect::<Vec<_>>();
+    let vehicles = horizon.vehicles.iter().flatten().collect::<Vec<_>>();
+
+    let is_correct = horizon.days > 0
+        && fleets.iter().all(|fleet| fleet.day < horizon.days)
+        && fleets.iter().flat_map(|fleet| fleet.type_ids.iter()).all(|type_id| type_ids.contains(type_id.as_str()))
+        && fleets.iter().map(|fleet| fleet.day).collect::<HashSet<_>>().len() == fleets.len()
+        && vehicles.iter().all(|vehicle| vehicle.days.iter().all(|day| *day < horizon.days))
+        && vehicles.iter().all(|vehicle| vehicle_ids.contains(&vehicle.vehicle_id))
+        && vehicles.iter().map(|vehicle| &vehicle.vehicle_id).collect::<HashSet<_>>().len() == vehicles.len();
+
+    if is_correct {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1317".to_string(),
+            "invalid planning horizon".to_string(),
+            "ensure that planning horizon has at least one day, day fleets have unique days within \
+             the horizon and refer to existing vehicle types, vehicle days are within the horizon and \
+             refer to unique existing vehicle ids"
+                .to_string(),
+        ))
+    }
+}
+
+/// Checks that vehicle crew sizes are positive.
+fn check_e1318_vehicle_crew_size_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.crew_size == Some(0))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1318".to_string(),
+            "invalid vehicle crew size".to_string(),
+            format!("ensure that crew size is positive, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle dock slots are correct.
+fn check_e1319_vehicle_dock_slot_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|shift, shift_time| {
+            shift.start.dock_slot.as_ref().map_or(true, |slot| {
+                let departure = shift_time.and_then(|shift_time| {
+                    shift
+                        .start
+                        .latest
+                        .as_ref()
+                        .map_or(Some(shift_time.clone()), |latest| get_time_window(&shift.start.earliest, latest))
+                });
+
+                match (get_time_window_from_vec(slot), departure) {
+                    (Some(slot), Some(departure)) => slot.start <= slot.end && slot.intersects(&departure),
+                    _ => false,
+                }
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1319".to_string(),
+            "invalid dock slot in vehicle shift".to_string(),
+            format!(
+                "ensure that dock slot is a valid time window which intersects shift start time, vehicle type \
+                 ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle max first leg distance is correct.
+fn check_e1320_vehicle_max_first_leg_distance_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().and_then(|limits| limits.max_first_leg_distance).map_or(false, |max| max < 0.)
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1320".to_string(),
+            "invalid vehicle max first leg distance".to_string(),
+            format!("ensure that max first leg distance is not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle maneuvering time is correct.
+fn check_e1321_vehicle_maneuvering_time_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.maneuvering_time.map_or(false, |time| time < 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1321".to_string(),
+            "invalid vehicle maneuvering time".to_string(),
+            format!("ensure that maneuvering time is not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle capacity decay is correct.
+fn check_e1322_vehicle_capacity_decay_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| match vehicle.capacity_decay {
+            Some(VehicleCapacityDecay::Linear { duration }) => duration <= 0.,
+            None => false,
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1322".to_string(),
+            "invalid vehicle capacity decay".to_string(),
+            format!("ensure that capacity decay duration is positive, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
 fn get_invalid_type_ids(
     ctx: &ValidationContext,
     check_shift: Box<dyn Fn(&VehicleShift, Option<TimeWindow>) -> bool>,
@@ -240,5 +634,21 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1304_vehicle_reload_time_is_correct(ctx),
         check_e1305_vehicle_limit_area_is_correct(ctx),
         check_e1306_vehicle_depot_is_correct(ctx),
+        check_e1307_depot_inventory_is_correct(ctx),
+        check_e1308_vehicle_distance_tiers_are_correct(ctx),
+        check_e1309_vehicle_range_is_correct(ctx),
+        check_e1310_vehicle_aggregate_capacity_is_correct(ctx),
+        check_e1311_vehicle_max_daily_cost_is_correct(ctx),
+        check_e1312_vehicle_anchors_are_correct(ctx),
+        check_e1313_vehicle_pool_is_correct(ctx),
+        check_e1314_vehicle_max_overtime_is_correct(ctx),
+        check_e1315_vehicle_load_distance_costs_are_correct(ctx),
+        check_e1316_vehicle_consumption_is_correct(ctx),
+        check_e1317_planning_horizon_is_correct(ctx),
+        check_e1318_vehicle_crew_size_is_correct(ctx),
+        check_e1319_vehicle_dock_slot_is_correct(ctx),
+        check_e1320_vehicle_max_first_leg_distance_is_correct(ctx),
+        check_e1321_vehicle_maneuvering_time_is_correct(ctx),
+        check_e1322_vehicle_capacity_decay_is_correct(ctx),
     ])
 }
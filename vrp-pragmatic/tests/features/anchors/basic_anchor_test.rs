@@ -0,0 +1,106 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_anchor(location: f64, times: Option<Vec<Vec<String>>>) -> VehicleCargoPlace {
+    VehicleCargoPlace { location: vec![location, 0.].to_loc(), duration: 1., times, tag: None }
+}
+
+fn create_vehicle_type(type_id: &str, anchors: Option<Vec<VehicleCargoPlace>>) -> VehicleType {
+    VehicleType {
+        vehicle_ids: (1..=3).map(|idx| format!("{}_{}", type_id, idx)).collect(),
+        shifts: vec![VehicleShift { anchors, ..create_default_vehicle_shift() }],
+        ..create_vehicle_with_capacity(type_id, vec![2])
+    }
+}
+
+fn create_jobs() -> Vec<Job> {
+    (1..=4).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect()
+}
+
+fn get_anchor_locations(tour: &Tour) -> Vec<Location> {
+    tour.stops
+        .iter()
+        .filter(|stop| stop.activities.iter().any(|activity| activity.activity_type == "anchor"))
+        .map(|stop| stop.location.clone())
+        .collect()
+}
+
+#[test]
+fn can_visit_anchor_once_in_every_non_empty_tour() {
+    let problem = Problem {
+        plan: Plan { jobs: create_jobs(), relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type("my_vehicle", Some(vec![create_anchor(10., None)]))],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(solution.violations.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    solution.tours.iter().for_each(|tour| {
+        assert_eq!(get_anchor_locations(tour), vec![vec![10., 0.].to_loc()]);
+    });
+}
+
+#[test]
+fn can_use_global_anchors_when_vehicle_has_no_own() {
+    let problem = Problem {
+        plan: Plan { jobs: create_jobs(), relations: None },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_type("own", Some(vec![create_anchor(10., None)])),
+                create_vehicle_type("global", None),
+            ],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config { anchors: Some(vec![create_anchor(5., None)]), ..Default::default() }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(!solution.tours.is_empty());
+    solution.tours.iter().for_each(|tour| {
+        let expected = if tour.type_id == "own" { 10. } else { 5. };
+        assert_eq!(get_anchor_locations(tour), vec![vec![expected, 0.].to_loc()]);
+    });
+}
+
+#[test]
+fn can_report_violation_when_anchor_cannot_be_visited() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type(
+                "my_vehicle",
+                Some(vec![create_anchor(10., Some(vec![vec![format_time(0.), format_time(5.)]]))]),
+            )],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert!(get_anchor_locations(&solution.tours[0]).is_empty());
+    match solution.violations.as_deref() {
+        Some([Violation::Anchor { vehicle_id, shift_index, .. }]) => {
+            assert_eq!(vehicle_id, &solution.tours[0].vehicle_id);
+            assert_eq!(*shift_index, 0);
+        }
+        _ => unreachable!("unexpected violations: {:?}", solution.violations),
+    }
+}
@@ -39,6 +39,7 @@ fn can_assign_break_between_jobs() {
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -91,7 +92,10 @@ fn can_assign_break_between_jobs() {
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -0,0 +1,57 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_waiting(breaks: Option<Vec<VehicleBreak>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![5., 0.], vec![(0, 10)], 1.),
+                create_delivery_job_with_times("job2", vec![10., 0.], vec![(100, 200)], 1.),
+                create_delivery_job("job3", vec![15., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { breaks, ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_route_end_time(solution: &Solution) -> String {
+    solution.tours.first().and_then(|tour| tour.stops.last()).map(|stop| stop.time.arrival.clone()).unwrap()
+}
+
+parameterized_test! {can_assign_break_during_waiting_time, (break_times, duration), {
+    can_assign_break_during_waiting_time_impl(break_times, duration);
+}}
+
+can_assign_break_during_waiting_time! {
+    case01: ((0., 300.), 10.),
+    case02: ((30., 60.), 20.),
+    case03: ((90., 95.), 10.),
+}
+
+fn can_assign_break_during_waiting_time_impl(break_times: (f64, f64), duration: f64) {
+    let problem = create_problem_with_waiting(None);
+    let matrix = create_matrix_from_problem(&problem);
+    let expected = get_route_end_time(&solve_with_metaheuristic(problem, Some(vec![matrix])));
+
+    let problem = create_problem_with_waiting(Some(vec![VehicleBreak {
+        time: VehicleBreakTime::TimeWindow(vec![format_time(break_times.0), format_time(break_times.1)]),
+        duration,
+        locations: None,
+    }]));
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.times.break_time, duration as i64);
+    assert_eq!(get_route_end_time(&solution), expected);
+}
@@ -18,6 +18,7 @@ fn can_assign_break_using_second_location() {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![30., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     breaks: Some(vec![VehicleBreak {
                         time: VehicleBreakTime::TimeWindow(vec![format_time(10.), format_time(30.)]),
@@ -44,6 +45,7 @@ fn can_assign_break_using_second_location() {
                 distance: 30,
                 duration: 34,
                 times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -96,7 +98,10 @@ fn can_assign_break_using_second_location() {
                     distance: 30,
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -38,6 +38,7 @@ fn can_assign_interval_break_between_jobs() {
                 distance: 30,
                 duration: 34,
                 times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -70,6 +71,8 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:06Z".to_string(),
                                 }),
                                 job_tag: None,
+                                distance: None,
+                                duration: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -80,6 +83,8 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:08Z".to_string(),
                                 }),
                                 job_tag: None,
+                                distance: None,
+                                duration: None,
                             }
                         ],
                     },
@@ -105,7 +110,10 @@ fn can_assign_interval_break_between_jobs() {
                     distance: 30,
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -127,11 +135,18 @@ fn can_assign_interval_break_with_reload() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![30., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: Some(vec![VehicleBreak {
@@ -145,6 +160,7 @@ fn can_assign_interval_break_with_reload() {
                         duration: 3.0,
                         tag: None,
                     }]),
+                    anchors: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -165,6 +181,7 @@ fn can_assign_interval_break_with_reload() {
                 distance: 60,
                 duration: 69,
                 times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -197,6 +214,8 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:11Z".to_string(),
                                 }),
                                 job_tag: None,
+                                distance: None,
+                                duration: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -207,6 +226,8 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:13Z".to_string(),
                                 }),
                                 job_tag: None,
+                                distance: None,
+                                duration: None,
                             }
                         ],
                     },
@@ -256,7 +277,10 @@ fn can_assign_interval_break_with_reload() {
                     distance: 60,
                     duration: 69,
                     times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -17,6 +17,8 @@ fn can_use_two_breaks() {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
                         location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
                     },
                     breaks: Some(vec![
                         VehicleBreak {
@@ -50,6 +52,7 @@ fn can_use_two_breaks() {
                 distance: 198,
                 duration: 204,
                 times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -98,6 +101,8 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:43Z".to_string(),
                                 }),
                                 job_tag: None,
+                                distance: None,
+                                duration: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -108,6 +113,8 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:45Z".to_string(),
                                 }),
                                 job_tag: None,
+                                distance: None,
+                                duration: None,
                             }
                         ],
                     },
@@ -125,7 +132,10 @@ fn can_use_two_breaks() {
                     distance: 198,
                     duration: 204,
                     times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -29,8 +29,9 @@ fn get_solution(
             relations: Some(vec![Relation {
                 type_field: relation_type,
                 jobs,
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                penalty: None,
             }]),
         },
         fleet: Fleet {
@@ -75,6 +76,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -127,7 +129,10 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -155,6 +160,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -207,7 +213,10 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -18,11 +18,14 @@ fn can_skip_break_when_vehicle_not_used() {
                             earliest: format_time(0.),
                             latest: None,
                             location: vec![100., 0.].to_loc(),
+                            alternatives: None,
+                            dock_slot: None,
                         },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(1000.).to_string(),
                             location: vec![100., 0.].to_loc(),
+                            max_overtime: None,
                         }),
                         depots: None,
                         breaks: Some(vec![VehicleBreak {
@@ -31,6 +34,7 @@ fn can_skip_break_when_vehicle_not_used() {
                             locations: Some(vec![vec![6., 0.].to_loc()]),
                         }]),
                         reloads: None,
+                        anchors: None,
                     }],
                     ..create_default_vehicle_type()
                 },
@@ -52,6 +56,7 @@ fn can_skip_break_when_vehicle_not_used() {
                 distance: 20,
                 duration: 22,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_without_break_1".to_string(),
@@ -96,7 +101,10 @@ fn can_skip_break_when_vehicle_not_used() {
                     distance: 20,
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -138,6 +146,7 @@ fn can_skip_break_when_jobs_completed() {
                 distance: 2,
                 duration: 12,
                 times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -174,7 +183,10 @@ fn can_skip_break_when_jobs_completed() {
                     distance: 2,
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             violations: Some(vec![Violation::Break {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -228,6 +240,7 @@ fn can_skip_second_break_when_jobs_completed() {
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -280,7 +293,10 @@ fn can_skip_second_break_when_jobs_completed() {
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
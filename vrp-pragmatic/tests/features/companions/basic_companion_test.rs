@@ -0,0 +1,77 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_job_with_companions(job: Job, companions: Vec<&str>) -> Job {
+    Job { companions: Some(companions.into_iter().map(|id| id.to_string()).collect()), ..job }
+}
+
+fn create_problem(jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_vehicle_with_capacity("my_vehicle", vec![2])
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_tour_job_ids(tour: &Tour) -> Vec<String> {
+    let mut job_ids = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect::<Vec<_>>();
+    job_ids.sort();
+
+    job_ids
+}
+
+#[test]
+fn can_assign_job_to_tour_with_companion() {
+    let problem = create_problem(vec![
+        create_delivery_job("job1", vec![1., 0.]),
+        create_job_with_companions(create_delivery_job("job2", vec![10., 0.]), vec!["job1"]),
+        create_delivery_job("job3", vec![10., 0.]),
+        create_delivery_job("job4", vec![1., 0.]),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let mut tours = solution.tours.iter().map(get_tour_job_ids).collect::<Vec<_>>();
+    tours.sort();
+    assert_eq!(tours, vec![vec!["job1".to_string(), "job2".to_string()], vec!["job3".to_string(), "job4".to_string()]]);
+}
+
+#[test]
+fn can_skip_job_when_companion_cannot_be_assigned() {
+    let problem = create_problem(vec![
+        create_delivery_job_with_demand("job1", vec![1., 0.], vec![3]),
+        create_job_with_companions(create_delivery_job("job2", vec![2., 0.]), vec!["job1"]),
+        create_delivery_job("job3", vec![3., 0.]),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_tour_job_ids(&solution.tours[0]), vec!["job3".to_string()]);
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 2);
+    let companion_reason = unassigned
+        .iter()
+        .find(|job| job.job_id == "job2")
+        .and_then(|job| job.reasons.first())
+        .expect("should have reason for job2");
+    assert_eq!(companion_reason.code, 113);
+    assert_eq!(companion_reason.description, "cannot be assigned to a tour without companion jobs");
+}
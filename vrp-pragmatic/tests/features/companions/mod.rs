@@ -0,0 +1 @@
+mod basic_companion_test;
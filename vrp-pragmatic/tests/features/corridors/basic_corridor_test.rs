@@ -0,0 +1,48 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_corridor_job(id: &str, location: f64, position: f64) -> Job {
+    Job {
+        corridor: Some(JobCorridor { id: "road".to_string(), position }),
+        ..create_delivery_job(id, vec![location, 0.])
+    }
+}
+
+#[test]
+fn can_serve_corridor_jobs_in_order_of_their_positions() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_corridor_job("job1", 1., 5.),
+                create_corridor_job("job2", 2., 4.),
+                create_corridor_job("job3", 3., 3.),
+                create_corridor_job("job4", 4., 2.),
+                create_corridor_job("job5", 5., 1.),
+                create_delivery_job("job6", vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_ids = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .map(|activity| activity.job_id.as_str())
+        .filter(|job_id| *job_id != "job6" && job_id.starts_with("job"))
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids, vec!["job5", "job4", "job3", "job2", "job1"]);
+}
@@ -0,0 +1 @@
+mod basic_corridor_test;
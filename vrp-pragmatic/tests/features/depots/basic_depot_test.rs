@@ -40,6 +40,7 @@ fn can_assign_single_depot() {
                 distance: 14,
                 duration: 18,
                 times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -92,7 +93,10 @@ fn can_assign_single_depot() {
                     distance: 14,
                     duration: 18,
                     times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
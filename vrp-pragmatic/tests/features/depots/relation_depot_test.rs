@@ -13,8 +13,9 @@ fn can_use_depot_in_relation() {
             relations: Some(vec![Relation {
                 type_field: RelationType::Strict,
                 jobs: to_strings(vec!["departure", "depot", "job1", "job2", "job3"]),
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                penalty: None,
             }]),
         },
         fleet: Fleet {
@@ -0,0 +1,47 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_load_by_aggregate_capacity, (is_pickup, aggregate, expected_unassigned), {
+    can_limit_load_by_aggregate_capacity_impl(is_pickup, aggregate, expected_unassigned);
+}}
+
+can_limit_load_by_aggregate_capacity! {
+    case01_delivery_no_aggregate: (false, None, 0),
+    case02_delivery_enough: (false, Some((vec![1., 1.], 12.)), 0),
+    case03_delivery_exceeded: (false, Some((vec![1., 1.], 11.)), 1),
+    case04_delivery_weighted: (false, Some((vec![1., 2.], 17.)), 1),
+    case05_pickup_no_aggregate: (true, None, 0),
+    case06_pickup_enough: (true, Some((vec![1., 1.], 12.)), 0),
+    case07_pickup_exceeded: (true, Some((vec![1., 1.], 11.)), 1),
+}
+
+fn can_limit_load_by_aggregate_capacity_impl(
+    is_pickup: bool,
+    aggregate: Option<(Vec<f64>, f64)>,
+    expected_unassigned: usize,
+) {
+    let create_job = if is_pickup { create_pickup_job_with_demand } else { create_delivery_job_with_demand };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job("job1", vec![1., 0.], vec![6, 0]), create_job("job2", vec![2., 0.], vec![0, 6])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                aggregate_capacity: aggregate.map(|(weights, limit)| AggregateCapacity { weights, limit }),
+                ..create_vehicle_with_capacity("my_vehicle", vec![10, 10])
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.unwrap_or_default();
+    assert_eq!(unassigned.len(), expected_unassigned);
+    assert!(unassigned.iter().all(|job| job.reasons.first().map_or(false, |reason| reason.code == 110)));
+    let assigned = solution.tours.iter().flat_map(|tour| tour.stops.iter()).flat_map(|stop| stop.activities.iter());
+    assert_eq!(assigned.filter(|activity| activity.job_id.starts_with("job")).count(), 2 - expected_unassigned);
+}
@@ -0,0 +1,99 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_vehicle_with_alternative_starts(vehicle_ids: Vec<&str>, capacity: i32) -> VehicleType {
+    let shift = create_default_open_vehicle_shift();
+
+    VehicleType {
+        vehicle_ids: vehicle_ids.iter().map(|id| id.to_string()).collect(),
+        shifts: vec![VehicleShift {
+            start: ShiftStart {
+                alternatives: Some(vec![vec![10., 0.].to_loc(), vec![20., 0.].to_loc()]),
+                ..shift.start.clone()
+            },
+            ..shift
+        }],
+        ..create_vehicle_with_capacity("my_vehicle", vec![capacity])
+    }
+}
+
+fn get_start_locations(solution: &Solution) -> Vec<Location> {
+    solution.tours.iter().map(|tour| tour.stops.first().unwrap().location.clone()).collect()
+}
+
+parameterized_test! {can_start_from_cheapest_alternative, (job_location, expected_start), {
+    can_start_from_cheapest_alternative_impl(job_location, expected_start);
+}}
+
+can_start_from_cheapest_alternative! {
+    case01: (1., 0.),
+    case02: (11., 10.),
+    case03: (19., 20.),
+    case04: (25., 20.),
+}
+
+fn can_start_from_cheapest_alternative_impl(job_location: f64, expected_start: f64) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![job_location, 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_alternative_starts(vec!["my_vehicle_1"], 10)],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_start_locations(&solution), vec![vec![expected_start, 0.].to_loc()]);
+    assert_eq!(solution.statistic.distance, (job_location - expected_start).abs().round() as i64);
+}
+
+#[test]
+fn can_use_vehicle_only_once_with_alternative_starts() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![21., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_alternative_starts(vec!["my_vehicle_1"], 10)],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+}
+
+#[test]
+fn can_use_different_alternative_starts_for_vehicles_of_same_type() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![21., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_alternative_starts(vec!["my_vehicle_1", "my_vehicle_2"], 1)],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let mut starts = get_start_locations(&solution);
+    starts.sort_by(|a, b| format!("{:?}", a).cmp(&format!("{:?}", b)));
+    let mut expected = vec![vec![0., 0.].to_loc(), vec![20., 0.].to_loc()];
+    expected.sort_by(|a, b| format!("{:?}", a).cmp(&format!("{:?}", b)));
+    assert_eq!(starts, expected);
+}
@@ -17,11 +17,18 @@ fn can_use_multiple_times_from_vehicle_and_job() {
             vehicles: vec![VehicleType {
                 shifts: vec![
                     VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: vec![0., 0.].to_loc(),
+                            alternatives: None,
+                            dock_slot: None,
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(99.).to_string(),
                             location: vec![0., 0.].to_loc(),
+                            max_overtime: None,
                         }),
                         ..create_default_vehicle_shift()
                     },
@@ -30,11 +37,14 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                             earliest: format_time(100.),
                             latest: None,
                             location: vec![0., 0.].to_loc(),
+                            alternatives: None,
+                            dock_slot: None,
                         },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(200.).to_string(),
                             location: vec![0., 0.].to_loc(),
+                            max_overtime: None,
                         }),
                         ..create_default_vehicle_shift()
                     },
@@ -58,6 +68,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 distance: 40,
                 duration: 42,
                 times: Timing { driving: 40, serving: 2, waiting: 0, break_time: 0 },
+                tightness: Some(90),
             },
             tours: vec![
                 Tour {
@@ -95,7 +106,10 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         distance: 20,
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0 },
+                        tightness: Some(90),
                     },
+                    consumption: None,
+                    day: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_1".to_string(),
@@ -132,7 +146,10 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         distance: 20,
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0 },
+                        tightness: Some(90),
                     },
+                    consumption: None,
+                    day: None,
                 }
             ],
             ..create_empty_solution()
@@ -27,6 +27,7 @@ fn can_use_vehicle_with_open_end() {
                 distance: 1,
                 duration: 2,
                 times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -55,7 +56,10 @@ fn can_use_vehicle_with_open_end() {
                     distance: 1,
                     duration: 2,
                     times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
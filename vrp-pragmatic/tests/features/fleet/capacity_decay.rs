@@ -0,0 +1,39 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_load_by_capacity_decay, (duration, expected_unassigned), {
+    can_limit_load_by_capacity_decay_impl(duration, expected_unassigned);
+}}
+
+can_limit_load_by_capacity_decay! {
+    case01_no_decay: (None, vec![]),
+    case02_slow_decay: (Some(100.), vec![]),
+    case03_fast_decay: (Some(10.), vec!["job2"]),
+}
+
+fn can_limit_load_by_capacity_decay_impl(duration: Option<f64>, expected_unassigned: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", vec![1., 0.], vec![5]),
+                create_delivery_job_with_demand("job2", vec![8., 0.], vec![5]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity_decay: duration.map(|duration| VehicleCapacityDecay::Linear { duration }),
+                ..create_vehicle_with_capacity("my_vehicle", vec![10])
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.unwrap_or_default();
+    assert_eq!(unassigned.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), expected_unassigned);
+    assert!(unassigned.iter().all(|job| job.reasons.first().map_or(false, |reason| reason.code == 124)));
+}
@@ -0,0 +1,41 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_distance_tiers, (tiers, expected_cost), {
+    can_use_distance_tiers_impl(tiers, expected_cost);
+}}
+
+can_use_distance_tiers! {
+    case01: (None, 27.),
+    case02: (Some(vec![(10., 3.)]), 27.),
+    case03: (Some(vec![(8., 3.)]), 27.),
+    case04: (Some(vec![(5., 3.)]), 33.),
+    case05: (Some(vec![(5., 3.), (7., 10.)]), 40.),
+    case06: (Some(vec![(7., 10.), (5., 3.)]), 40.),
+    case07: (Some(vec![(5., 0.5)]), 25.5),
+}
+
+fn can_use_distance_tiers_impl(tiers: Option<Vec<(f64, f64)>>, expected_cost: f64) {
+    let distance_tiers = tiers.map(|tiers| {
+        tiers.into_iter().map(|(threshold, distance)| DistanceTier { threshold, distance }).collect::<Vec<_>>()
+    });
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![4., 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { distance_tiers, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.distance, 8);
+    assert_eq!(solution.tours[0].statistic.cost, expected_cost);
+    assert_eq!(solution.statistic.cost, expected_cost);
+}
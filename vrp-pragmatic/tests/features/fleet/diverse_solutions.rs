@@ -0,0 +1,51 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::collections::HashMap;
+
+fn get_assignments(solution: &Solution) -> HashMap<String, String> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            get_ids_from_tour(tour)
+                .into_iter()
+                .flatten()
+                .filter(|id| id.starts_with("job"))
+                .map(move |id| (id, tour.vehicle_id.clone()))
+        })
+        .collect()
+}
+
+#[test]
+fn can_return_k_diverse_solutions() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![3],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solutions = solve_k_diverse(problem, Some(vec![matrix]), 200, 3, 1);
+
+    assert!(solutions.len() > 1);
+    solutions.iter().enumerate().for_each(|(idx, solution)| {
+        assert!(solution.unassigned.is_none());
+        solutions.iter().skip(idx + 1).for_each(|other| assert_ne!(get_assignments(solution), get_assignments(other)));
+    });
+}
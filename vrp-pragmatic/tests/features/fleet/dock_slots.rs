@@ -0,0 +1,46 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use crate::parse_time;
+
+parameterized_test! {can_depart_within_dock_slot, (dock_slot, job_time, is_assigned), {
+    can_depart_within_dock_slot_impl(dock_slot, job_time, is_assigned);
+}}
+
+can_depart_within_dock_slot! {
+    case01_early_slot_early_job: ((0, 5), (0, 15), true),
+    case02_early_slot_late_job: ((0, 5), (30, 100), true),
+    case03_late_slot_early_job: ((10, 20), (0, 15), false),
+    case04_late_slot_late_job: ((10, 20), (30, 100), true),
+}
+
+fn can_depart_within_dock_slot_impl(dock_slot: (i32, i32), job_time: (i32, i32), is_assigned: bool) {
+    let mut shift = create_default_vehicle_shift();
+    shift.start.dock_slot = Some(vec![format_time(dock_slot.0 as f64), format_time(dock_slot.1 as f64)]);
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![10., 0.], vec![job_time], 1.)],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if is_assigned {
+        assert!(solution.unassigned.is_none());
+        assert_eq!(solution.tours.len(), 1);
+        let departure = parse_time(&solution.tours[0].stops[0].time.departure);
+        assert!(departure >= dock_slot.0 as f64 && departure <= dock_slot.1 as f64);
+    } else {
+        assert!(solution.tours.is_empty());
+        let unassigned = solution.unassigned.unwrap_or_default();
+        assert_eq!(unassigned.len(), 1);
+        assert_eq!(unassigned[0].reasons[0].code, 2);
+    }
+}
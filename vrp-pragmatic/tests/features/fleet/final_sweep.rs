@@ -0,0 +1,42 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(jobs: usize, capacity: i32) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: (1..=jobs).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![capacity])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_insert_unassigned_jobs_with_final_sweep, (capacity, final_sweep, expected_unassigned), {
+    can_insert_unassigned_jobs_with_final_sweep_impl(capacity, final_sweep, expected_unassigned);
+}}
+
+can_insert_unassigned_jobs_with_final_sweep! {
+    case01_no_sweep: (10, false, 2),
+    case02_sweep_all: (10, true, 0),
+    case03_sweep_some: (3, true, 1),
+}
+
+fn can_insert_unassigned_jobs_with_final_sweep_impl(capacity: i32, final_sweep: bool, expected_unassigned: usize) {
+    // NOTE initial solution misses some jobs and no generations are run, so they are left unassigned
+    let init_problem = create_problem(2, capacity);
+    let init_matrix = create_matrix_from_problem(&init_problem);
+    let init_solution = solve_with_metaheuristic(init_problem, Some(vec![init_matrix]));
+    let problem = create_problem(4, capacity);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_init_solution_and_builder(problem, Some(vec![matrix]), &init_solution, 0, |builder| {
+        builder.with_final_sweep(final_sweep)
+    });
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), expected_unassigned);
+}
@@ -0,0 +1,39 @@
+use crate::format::problem::*;
+use crate::format::solution::Consumption;
+use crate::helpers::*;
+
+parameterized_test! {can_report_fuel_consumption, (demand, consumption, expected), {
+    can_report_fuel_consumption_impl(demand, consumption, expected);
+}}
+
+can_report_fuel_consumption! {
+    case01_no_model: (5, None, None),
+    case02_base_only: (5, Some((1., None, None)), Some((8., 16.))),
+    case03_light_load: (1, Some((1., Some(vec![0.5]), None)), Some((10., 20.))),
+    case04_heavy_load: (5, Some((1., Some(vec![0.5]), None)), Some((18., 36.))),
+    case05_speed: (5, Some((1., None, Some(0.5))), Some((12., 24.))),
+}
+
+fn can_report_fuel_consumption_impl(
+    demand: i32,
+    consumption: Option<(f64, Option<Vec<f64>>, Option<f64>)>,
+    expected: Option<(f64, f64)>,
+) {
+    let consumption =
+        consumption.map(|(distance, load, speed)| VehicleConsumption { distance, load, speed, emission_factor: 2. });
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![4., 0.], vec![demand])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { consumption, ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.distance, 8);
+    assert_eq!(solution.tours[0].consumption, expected.map(|(fuel, emissions)| Consumption { fuel, emissions }));
+}
@@ -0,0 +1,64 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_load_distance_costs, (job, load_distance, expected_cost), {
+    can_use_load_distance_costs_impl(job, load_distance, expected_cost);
+}}
+
+can_use_load_distance_costs! {
+    case01_no_costs: (create_delivery_job_with_demand("job1", vec![4., 0.], vec![5]), None, 27.),
+    case02_zero_costs: (create_delivery_job_with_demand("job1", vec![4., 0.], vec![5]), Some(vec![0.]), 27.),
+    case03_light_delivery: (create_delivery_job_with_demand("job1", vec![4., 0.], vec![1]), Some(vec![0.5]), 29.),
+    case04_heavy_delivery: (create_delivery_job_with_demand("job1", vec![4., 0.], vec![5]), Some(vec![0.5]), 37.),
+    case05_heavy_pickup: (create_pickup_job_with_demand("job1", vec![4., 0.], vec![5]), Some(vec![0.5]), 37.),
+}
+
+fn can_use_load_distance_costs_impl(job: Job, load_distance: Option<Vec<f64>>, expected_cost: f64) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { load_distance, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.distance, 8);
+    assert_eq!(solution.tours[0].statistic.cost, expected_cost);
+    assert_eq!(solution.statistic.cost, expected_cost);
+}
+
+#[test]
+fn can_deliver_heavy_job_first() {
+    // NOTE both orders have the same distance, but the one which unloads heavy job first is cheaper
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("heavy", vec![2., 0.], vec![9]),
+                create_delivery_job_with_demand("light", vec![0., 2.], vec![1]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { load_distance: Some(vec![1.]), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_ids_from_tour(&solution.tours[0]).concat(), vec!["departure", "heavy", "light", "arrival"]);
+}
@@ -0,0 +1,75 @@
+use crate::format::problem::*;
+use crate::format::solution::Tour;
+use crate::helpers::*;
+
+fn count_job_stops(tour: &Tour) -> usize {
+    tour.stops.iter().filter(|stop| stop.activities.iter().any(|activity| activity.activity_type == "delivery")).count()
+}
+
+#[test]
+fn can_add_maneuvering_time_to_stop_duration() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { maneuvering_time: Some(5.), ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.first().expect("no tour");
+    assert_eq!(count_job_stops(tour), 2);
+    assert_eq!(tour.statistic.times.serving, 2 * (1 + 5));
+    assert_eq!(tour.statistic.duration, 4 + 2 * (1 + 5));
+}
+
+parameterized_test! {can_prefer_fewer_stops_on_maneuvering_vehicle, (maneuvering_time, expected_stops), {
+    can_prefer_fewer_stops_on_maneuvering_vehicle_impl(maneuvering_time, expected_stops);
+}}
+
+can_prefer_fewer_stops_on_maneuvering_vehicle! {
+    case01_heavy_penalty: (100., (1, 3)),
+    case02_no_penalty: (0., (3, 1)),
+}
+
+fn can_prefer_fewer_stops_on_maneuvering_vehicle_impl(maneuvering_time: f64, expected_stops: (usize, usize)) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    maneuvering_time: Some(maneuvering_time),
+                    costs: VehicleCosts { time: 0.5, ..create_default_vehicle_costs() },
+                    ..create_vehicle_with_capacity("truck", vec![3])
+                },
+                create_vehicle_with_capacity("van", vec![3]),
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let get_stops = |type_id: &str| {
+        solution.tours.iter().filter(|tour| tour.type_id == type_id).map(count_job_stops).sum::<usize>()
+    };
+    assert_eq!((get_stops("truck"), get_stops("van")), expected_stops);
+}
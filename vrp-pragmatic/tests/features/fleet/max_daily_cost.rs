@@ -0,0 +1,59 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_vehicle_type(id: &str, location: f64, max_daily_cost: Option<f64>) -> VehicleType {
+    VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations((location, 0.), (location, 0.))],
+        costs: VehicleCosts { max_daily_cost, ..create_default_vehicle_costs() },
+        ..create_vehicle_with_capacity(id, vec![4])
+    }
+}
+
+parameterized_test! {can_use_vehicle_with_max_daily_cost, (max_daily_cost, expected_jobs), {
+    can_use_vehicle_with_max_daily_cost_impl(max_daily_cost, expected_jobs);
+}}
+
+can_use_vehicle_with_max_daily_cost! {
+    case01: (None, 2),
+    case02: (Some(100.), 2),
+    case03: (Some(30.), 4),
+}
+
+fn can_use_vehicle_with_max_daily_cost_impl(max_daily_cost: Option<f64>, expected_jobs: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![2., 0.]),
+                create_delivery_job("job2", vec![4., 0.]),
+                create_delivery_job("job3", vec![16., 0.]),
+                create_delivery_job("job4", vec![18., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type("capped", 0., max_daily_cost), create_vehicle_type("normal", 20., None)],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs],
+            secondary: Some(vec![MinimizeCost]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.iter().find(|tour| tour.type_id == "capped").expect("capped vehicle is not used");
+    assert_eq!(
+        tour.stops.iter().flat_map(|stop| stop.activities.iter()).filter(|a| a.job_id.starts_with("job")).count(),
+        expected_jobs
+    );
+    if let Some(max_daily_cost) = max_daily_cost {
+        assert!(tour.statistic.cost <= max_daily_cost);
+    }
+    assert_eq!(solution.statistic.cost, solution.tours.iter().map(|tour| tour.statistic.cost).sum::<f64>());
+}
@@ -1,4 +1,18 @@
+mod aggregate_capacity;
+mod alternative_starts;
 mod basic_multi_shift;
 mod basic_open_end;
+mod capacity_decay;
+mod distance_tiers;
+mod diverse_solutions;
+mod dock_slots;
+mod final_sweep;
+mod fuel_consumption;
+mod load_distance_costs;
+mod maneuvering_time;
+mod max_daily_cost;
+mod multi_day_horizon;
 mod multi_dimens;
+mod type_durations;
 mod unreachable_jobs;
+mod unusable_vehicles;
@@ -0,0 +1,88 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+parameterized_test! {can_assign_jobs_to_days_within_horizon, (days, fleets, expected_days, expected_unassigned), {
+    can_assign_jobs_to_days_within_horizon_impl(days, fleets, expected_days, expected_unassigned);
+}}
+
+can_assign_jobs_to_days_within_horizon! {
+    case01_all_days: (3, None, vec![0, 1, 2], 0),
+    case02_no_fleet_on_day: (3, Some(vec![DayFleet { day: 1, type_ids: vec![] }]), vec![0, 2], 2),
+    case03_short_horizon: (2, None, vec![0, 1], 2),
+}
+
+fn can_assign_jobs_to_days_within_horizon_impl(
+    days: usize,
+    fleets: Option<Vec<DayFleet>>,
+    expected_days: Vec<usize>,
+    expected_unassigned: usize,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=6).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config { horizon: Some(PlanningHorizon { days, fleets, vehicles: None }), ..Default::default() }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let mut days = solution.tours.iter().map(|tour| tour.day.expect("tour has no day")).collect::<Vec<_>>();
+    days.sort_unstable();
+    assert_eq!(days, expected_days);
+    assert!(solution.tours.iter().all(|tour| get_tour_jobs(tour) == 2));
+    assert_eq!(solution.unassigned.unwrap_or_default().len(), expected_unassigned);
+}
+
+fn get_tour_jobs(tour: &Tour) -> usize {
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .count()
+}
+
+#[test]
+fn can_respect_vehicle_working_days() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=4).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_vehicle_with_capacity("my_vehicle", vec![1])
+            }],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config {
+            horizon: Some(PlanningHorizon {
+                days: 3,
+                fleets: None,
+                vehicles: Some(vec![VehicleDays { vehicle_id: "my_vehicle_2".to_string(), days: vec![1] }]),
+            }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let mut tours = solution
+        .tours
+        .iter()
+        .map(|tour| (tour.vehicle_id.as_str(), tour.day.expect("tour has no day")))
+        .collect::<Vec<_>>();
+    tours.sort_unstable();
+    assert_eq!(tours, vec![("my_vehicle_1", 0), ("my_vehicle_1", 1), ("my_vehicle_1", 2), ("my_vehicle_2", 1)]);
+    assert!(solution.unassigned.is_none());
+}
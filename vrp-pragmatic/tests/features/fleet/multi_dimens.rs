@@ -34,6 +34,7 @@ fn can_use_two_dimensions() {
                 distance: 2,
                 duration: 4,
                 times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -70,7 +71,10 @@ fn can_use_two_dimensions() {
                     distance: 2,
                     duration: 4,
                     times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -103,6 +107,7 @@ fn can_unassign_due_to_dimension_mismatch() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
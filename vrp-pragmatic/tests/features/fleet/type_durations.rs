@@ -0,0 +1,50 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_use_vehicle_type_durations, (specialist_capacity, expected_type_id, expected_duration), {
+    can_use_vehicle_type_durations_impl(specialist_capacity, expected_type_id, expected_duration);
+}}
+
+can_use_vehicle_type_durations! {
+    case01: (0, "generalist", 30.),
+    case02: (10, "specialist", 10.),
+}
+
+fn can_use_vehicle_type_durations_impl(specialist_capacity: i32, expected_type_id: &str, expected_duration: f64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace { duration: 30., ..create_job_place(vec![2., 0.]) }],
+                    demand: Some(vec![1]),
+                    tag: None,
+                    type_durations: Some(vec![VehicleTypeDuration {
+                        type_ids: vec!["specialist".to_string()],
+                        duration: 10.,
+                    }]),
+                    variants: None,
+                }]),
+                ..create_job("job1")
+            }],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("generalist"),
+                create_vehicle_with_capacity("specialist", vec![specialist_capacity]),
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].type_id, expected_type_id);
+    assert_eq!(solution.tours[0].stops[1].time.arrival, format_time(2.));
+    assert_eq!(solution.tours[0].stops[1].time.departure, format_time(2. + expected_duration));
+    assert_eq!(solution.tours[0].statistic.times.serving, expected_duration as i64);
+}
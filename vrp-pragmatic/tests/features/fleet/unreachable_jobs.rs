@@ -15,6 +15,10 @@ fn can_use_vehicle_with_open_end() {
         travel_times: vec![0, 1, 1, 0],
         distances: vec![0, 1, 1, 0],
         error_codes: Some(vec![0, 1, 1, 1]),
+        default_travel_time: None,
+        default_distance: None,
+        time_unit: None,
+        unreachable_value: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -27,6 +31,7 @@ fn can_use_vehicle_with_open_end() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -37,3 +42,46 @@ fn can_use_vehicle_with_open_end() {
         }
     );
 }
+
+#[test]
+fn can_skip_job_reachable_only_via_impassable_leg() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: Option::None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: None,
+        travel_times: vec![0, 99999, 1, 1, 0, 2, 1, 99999, 0],
+        distances: vec![0, 99999, 1, 1, 0, 2, 1, 99999, 0],
+        error_codes: None,
+        default_travel_time: None,
+        default_distance: None,
+        time_unit: None,
+        unreachable_value: Some(99999),
+    };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.tours[0]
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .map(|a| a.job_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["departure", "job1", "arrival"]
+    );
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job2".to_string(),
+            reasons: vec![UnassignedJobReason { code: 100, description: "location unreachable".to_string() }]
+        }])
+    );
+}
@@ -0,0 +1,53 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::solver::analyze_usability;
+
+#[test]
+fn can_detect_unusable_vehicles_and_unservable_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job_with_skills("job2", vec![2., 0.], vec!["unknown_skill".to_string()]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle"), create_vehicle_with_capacity("empty_vehicle", vec![0])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read core problem"));
+    let jobs = core_problem.jobs.all().collect::<Vec<_>>();
+
+    let report = analyze_usability(core_problem, jobs.as_slice());
+
+    assert_eq!(report.unusable_vehicles, vec!["empty_vehicle_1".to_string()]);
+    assert_eq!(report.unservable_jobs, vec!["job2".to_string()]);
+}
+
+#[test]
+fn can_ignore_constraints_depending_on_other_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                Job { companions: Some(vec!["job1".to_string()]), ..create_delivery_job("job2", vec![2., 0.]) },
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read core problem"));
+    let jobs = core_problem.jobs.all().collect::<Vec<_>>();
+
+    let report = analyze_usability(core_problem, jobs.as_slice());
+
+    assert!(report.unusable_vehicles.is_empty());
+    assert!(report.unservable_jobs.is_empty());
+}
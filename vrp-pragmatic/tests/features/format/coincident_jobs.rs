@@ -0,0 +1,84 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_problem(jobs: Vec<Job>, coincident_jobs: CoincidentJobsPolicy) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config {
+            coincident_jobs: Some(coincident_jobs),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    }
+}
+
+fn get_stop_locations(tour: &Tour) -> Vec<Location> {
+    tour.stops.iter().map(|stop| stop.location.clone()).collect()
+}
+
+#[test]
+fn can_combine_coincident_jobs_into_one_stop() {
+    let jobs = vec![
+        create_delivery_job("job1", vec![1., 0.]),
+        create_delivery_job("job2", vec![1., 0.]),
+        create_delivery_job("job3", vec![3., 0.]),
+    ];
+    let solve = |policy| {
+        let problem = create_problem(jobs.clone(), policy);
+        let matrix = create_matrix_from_problem(&problem);
+        solve_with_metaheuristic(problem, Some(vec![matrix]))
+    };
+
+    let combined = solve(CoincidentJobsPolicy::Combined);
+    let separate = solve(CoincidentJobsPolicy::Separate);
+
+    assert!(combined.unassigned.is_none());
+    assert!(separate.unassigned.is_none());
+    assert_eq!(
+        get_stop_locations(&combined.tours[0]),
+        vec![vec![0., 0.].to_loc(), vec![1., 0.].to_loc(), vec![3., 0.].to_loc()]
+    );
+    assert_eq!(combined.tours[0].stops[1].activities.len(), 2);
+    assert_eq!(
+        get_stop_locations(&separate.tours[0]),
+        vec![vec![0., 0.].to_loc(), vec![1., 0.].to_loc(), vec![1., 0.].to_loc(), vec![3., 0.].to_loc()]
+    );
+    assert_eq!(separate.tours[0].stops[1].distance, separate.tours[0].stops[2].distance);
+    assert_eq!(combined.statistic.distance, 3);
+    assert_eq!(separate.statistic.distance, 3);
+}
+
+#[test]
+fn can_keep_coincident_jobs_within_one_stop_when_split_is_cheaper() {
+    let problem = create_problem(
+        vec![
+            create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 2)], 1.),
+            create_delivery_job_with_times("job2", vec![1., 0.], vec![(0, 1000)], 1.),
+            create_delivery_job_with_times("job3", vec![2., 0.], vec![(0, 3)], 1.),
+        ],
+        CoincidentJobsPolicy::Combined,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+    assert_eq!(solution.tours.len(), 1);
+    let locations = get_stop_locations(&solution.tours[0]);
+    let unique_locations = locations.iter().fold(Vec::<Location>::new(), |mut acc, location| {
+        if !acc.contains(location) {
+            acc.push(location.clone());
+        }
+        acc
+    });
+    assert_eq!(locations.len(), unique_locations.len());
+}
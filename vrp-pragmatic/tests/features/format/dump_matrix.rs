@@ -0,0 +1,31 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::io::{BufReader, BufWriter};
+
+#[test]
+fn can_solve_problem_with_dumped_approx_matrix_as_without_matrix() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![0.001, 0.]),
+                create_delivery_job("job2", vec![0.002, 0.003]),
+                create_delivery_job("job3", vec![0.005, 0.001]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let mut buffer = Vec::new();
+    let matrices = create_approx_matrices(&problem);
+    assert_eq!(matrices.len(), 1);
+    serialize_matrix(BufWriter::new(&mut buffer), &matrices[0]).unwrap();
+    let dumped_matrix = deserialize_matrix(BufReader::new(buffer.as_slice())).ok().unwrap();
+
+    let approx_solution = solve_with_metaheuristic(problem.clone(), None);
+    let dumped_solution = solve_with_metaheuristic(problem, Some(vec![dumped_matrix]));
+
+    assert!(dumped_solution.unassigned.is_none());
+    assert_eq!(dumped_solution.statistic, approx_solution.statistic);
+    assert_eq!(dumped_solution.tours, approx_solution.tours);
+}
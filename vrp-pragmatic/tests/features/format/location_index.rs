@@ -17,6 +17,8 @@ fn create_test_problem() -> Problem {
                         earliest: format_time(0.),
                         latest: None,
                         location: Location::Reference { index: 2 },
+                        alternatives: None,
+                        dock_slot: None,
                     },
                     ..create_default_open_vehicle_shift()
                 }],
@@ -35,6 +37,10 @@ fn create_test_matrix() -> Matrix {
         travel_times: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         distances: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         error_codes: None,
+        default_travel_time: None,
+        default_distance: None,
+        time_unit: None,
+        unreachable_value: None,
     }
 }
 
@@ -52,7 +58,8 @@ fn can_use_location_index() {
                 cost: 18.,
                 distance: 3,
                 duration: 5,
-                times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 }
+                times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 },
+                tightness: None
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -97,8 +104,11 @@ fn can_use_location_index() {
                     cost: 18.,
                     distance: 3,
                     duration: 5,
-                    times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 }
-                }
+                    times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 },
+                    tightness: None
+                },
+                consumption: None,
+                day: None,
             }],
             unassigned: None,
             violations: None,
@@ -1 +1,7 @@
+mod coincident_jobs;
+mod dump_matrix;
 mod location_index;
+mod partial_matrix;
+mod solution_comparison;
+mod split_matrix;
+mod split_problem;
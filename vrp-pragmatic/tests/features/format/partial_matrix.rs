@@ -0,0 +1,64 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", 1)],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: Location::Reference { index: 2 },
+                        alternatives: None,
+                        dock_slot: None,
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_matrix() -> Matrix {
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![0, 1, 1, 1, 0, 1, 1, -1, 0],
+        distances: vec![0, 1, 1, 1, 0, 1, 1, -1, 0],
+        error_codes: None,
+        default_travel_time: Some(100),
+        default_distance: Some(100),
+        time_unit: None,
+        unreachable_value: None,
+    }
+}
+
+#[test]
+fn can_avoid_missing_leg_with_large_default() {
+    let problem = create_test_problem();
+    let matrix = create_test_matrix();
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, 2);
+    assert_eq!(
+        solution.tours[0]
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .map(|a| a.job_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["departure", "job1", "job2"]
+    );
+}
@@ -0,0 +1,74 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::cmp::Ordering;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::models::problem::ObjectiveLevel;
+
+fn create_test_problem(relations: Option<Vec<Relation>>) -> Problem {
+    let create_vehicle = |id: &str, location: f64| VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations((location, 0.), (location, 0.))],
+        ..create_default_vehicle(id)
+    };
+
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![9., 0.])],
+            relations,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle("vehicle_a", 0.), create_vehicle("vehicle_b", 10.)],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_any_relation(job_id: &str, vehicle_id: &str) -> Relation {
+    Relation {
+        type_field: RelationType::Any,
+        jobs: vec![job_id.to_string()],
+        vehicle_id: Some(vehicle_id.to_string()),
+        shift_index: None,
+        penalty: None,
+    }
+}
+
+#[test]
+fn can_explain_solution_comparison_by_objective_terms() {
+    let problem = create_test_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+    // NOTE first solution serves both jobs in one tour, second one uses a vehicle per job
+    let first = solve_with_metaheuristic(problem.clone(), Some(vec![matrix.clone()]));
+    let second = solve_with_metaheuristic(
+        create_test_problem(Some(vec![
+            create_any_relation("job1", "vehicle_a_1"),
+            create_any_relation("job2", "vehicle_b_1"),
+        ])),
+        Some(vec![matrix.clone()]),
+    );
+    assert_eq!(first.tours.len(), 1);
+    assert_eq!(second.tours.len(), 2);
+    assert!(first.statistic.distance > second.statistic.distance);
+
+    let core_problem = Arc::new((problem.clone(), vec![matrix]).read_pragmatic().unwrap());
+    let read_solution = |solution: &Solution| {
+        let solution = serde_json::to_string(solution).unwrap();
+        read_init_solution(BufReader::new(solution.as_bytes()), core_problem.clone()).unwrap()
+    };
+
+    let comparison = compare_solutions(core_problem.clone(), &problem, read_solution(&first), read_solution(&second));
+
+    assert_eq!(comparison.order, Ordering::Less);
+    assert_eq!(comparison.level, Some(ObjectiveLevel::Primary));
+    assert_eq!(
+        comparison.terms.iter().map(|term| (term.name.as_str(), term.order)).collect::<Vec<_>>(),
+        vec![
+            ("minimize-unassigned", Ordering::Equal),
+            ("minimize-tours", Ordering::Less),
+            ("minimize-cost", Ordering::Greater),
+        ]
+    );
+    assert_eq!(comparison.terms[1].values, (1., 2.));
+}
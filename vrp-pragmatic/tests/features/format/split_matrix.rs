@@ -0,0 +1,36 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::io::BufReader;
+
+#[test]
+fn can_solve_problem_with_split_matrix_as_with_combined() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![5., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let matrix = Matrix { travel_times: matrix.distances.iter().map(|distance| distance * 2).collect(), ..matrix };
+    let distances = serde_json::json!({ "profile": matrix.profile, "distances": matrix.distances }).to_string();
+    let durations = serde_json::json!({ "profile": matrix.profile, "travelTimes": matrix.travel_times }).to_string();
+    let split_matrices = deserialize_split_matrices(
+        vec![BufReader::new(distances.as_bytes())],
+        vec![BufReader::new(durations.as_bytes())],
+    )
+    .ok()
+    .unwrap();
+
+    let combined_solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+    let split_solution = solve_with_metaheuristic(problem, Some(split_matrices));
+
+    assert!(split_solution.unassigned.is_none());
+    assert_eq!(split_solution.statistic, combined_solution.statistic);
+    assert_eq!(split_solution.tours, combined_solution.tours);
+}
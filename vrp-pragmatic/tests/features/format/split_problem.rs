@@ -0,0 +1,39 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::io::{BufReader, BufWriter};
+
+#[test]
+fn can_solve_split_problem_as_merged_one() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_pickup_job("job3", vec![5., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        objectives: Some(Objectives {
+            primary: vec![Objective::MinimizeUnassignedJobs, Objective::MinimizeTours],
+            secondary: Some(vec![Objective::MinimizeCost]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let mut buffer = Vec::new();
+    serialize_problem(BufWriter::new(&mut buffer), &problem).unwrap();
+    let mut jobs = serde_json::from_slice::<serde_json::Map<String, serde_json::Value>>(buffer.as_slice()).unwrap();
+    let fleet = jobs.remove("fleet").unwrap().to_string();
+    let jobs = serde_json::Value::Object(jobs).to_string();
+    let split_problem =
+        deserialize_split_problem(BufReader::new(fleet.as_bytes()), BufReader::new(jobs.as_bytes())).ok().unwrap();
+
+    let merged_solution = solve_with_metaheuristic(problem, Some(vec![matrix.clone()]));
+    let split_solution = solve_with_metaheuristic(split_problem, Some(vec![matrix]));
+
+    assert!(split_solution.unassigned.is_none());
+    assert_eq!(split_solution.statistic, merged_solution.statistic);
+    assert_eq!(split_solution.tours, merged_solution.tours);
+}
@@ -21,6 +21,11 @@ fn can_limit_by_area() {
                             Location::new_coordinate(-5., 5.),
                         ],
                     }]),
+                    max_time_window_jobs: None,
+                    max_reloads: None,
+                    max_hourly_time_window_jobs: None,
+                    max_late_stops: None,
+                    max_first_leg_distance: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -40,6 +45,7 @@ fn can_limit_by_area() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -0,0 +1,47 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_by_depot_inventory, (inventory, expected_unassigned), {
+    can_limit_by_depot_inventory_impl(inventory, expected_unassigned);
+}}
+
+can_limit_by_depot_inventory! {
+    case01: (5, vec![]),
+    case02: (4, vec!["job2"]),
+    case03: (3, vec!["job1", "job2"]),
+    case04: (0, vec!["job1", "job2", "job3", "job4", "job5"]),
+}
+
+fn can_limit_by_depot_inventory_impl(inventory: i32, expected_unassigned: Vec<&str>) {
+    let create_job_with_priority = |id: &str, location: Vec<f64>, priority: i32| Job {
+        priority: Some(priority),
+        ..create_delivery_job(id, location)
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_priority("job1", vec![1., 0.], 2),
+                create_job_with_priority("job2", vec![2., 0.], 3),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+                create_delivery_job("job5", vec![5., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![10], ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config { depot_inventory: Some(vec![inventory]), ..Default::default() }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.unwrap_or_default();
+    let mut unassigned_ids = unassigned.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    unassigned_ids.sort();
+    assert_eq!(unassigned_ids, expected_unassigned);
+    assert!(unassigned.iter().all(|job| job.reasons.first().map_or(false, |reason| reason.code == 108)));
+}
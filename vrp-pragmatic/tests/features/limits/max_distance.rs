@@ -8,7 +8,16 @@ fn can_limit_by_max_distance() {
         plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: Some(99.), shift_time: None, allowed_areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(99.),
+                    shift_time: None,
+                    allowed_areas: None,
+                    max_time_window_jobs: None,
+                    max_reloads: None,
+                    max_hourly_time_window_jobs: None,
+                    max_late_stops: None,
+                    max_first_leg_distance: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
@@ -21,6 +30,10 @@ fn can_limit_by_max_distance() {
         travel_times: vec![1, 1, 1, 1],
         distances: vec![1, 100, 100, 1],
         error_codes: Option::None,
+        default_travel_time: None,
+        default_distance: None,
+        time_unit: None,
+        unreachable_value: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -33,6 +46,7 @@ fn can_limit_by_max_distance() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -0,0 +1,79 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_vehicle_limits(max_first_leg_distance: Option<f64>) -> Option<VehicleLimits> {
+    Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        allowed_areas: None,
+        max_time_window_jobs: None,
+        max_reloads: None,
+        max_hourly_time_window_jobs: None,
+        max_late_stops: None,
+        max_first_leg_distance,
+    })
+}
+
+parameterized_test! {can_limit_by_max_first_leg_distance, (max_first_leg_distance, expected_vehicle), {
+    can_limit_by_max_first_leg_distance_impl(max_first_leg_distance, expected_vehicle);
+}}
+
+can_limit_by_max_first_leg_distance! {
+    case01_no_limit: (None, "cheap_1"),
+    case02_above_first_leg: (Some(10.), "cheap_1"),
+    case03_below_first_leg: (Some(5.), "close_1"),
+}
+
+fn can_limit_by_max_first_leg_distance_impl(max_first_leg_distance: Option<f64>, expected_vehicle: &str) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![8., 0.]), create_delivery_job("job2", vec![9., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    limits: create_vehicle_limits(max_first_leg_distance),
+                    ..create_default_vehicle("cheap")
+                },
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    costs: VehicleCosts { fixed: Some(100.), ..create_default_vehicle_costs() },
+                    ..create_default_vehicle("close")
+                },
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours.first().unwrap().vehicle_id, expected_vehicle);
+}
+
+#[test]
+fn can_skip_job_with_far_first_leg() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![2., 0.]), create_delivery_job("job2", vec![8., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { limits: create_vehicle_limits(Some(1.)), ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.unwrap_or_default();
+    assert!(solution.tours.is_empty());
+    assert_eq!(unassigned.len(), 2);
+    assert!(unassigned.iter().all(|job| matches!(job.reasons.first(), Some(reason) if reason.code == 123)));
+}
@@ -0,0 +1,61 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_by_max_hourly_time_window_jobs, (job3_times, expected_unassigned), {
+    can_limit_by_max_hourly_time_window_jobs_impl(job3_times, expected_unassigned);
+}}
+
+can_limit_by_max_hourly_time_window_jobs! {
+    case01: ((0, 1000), 1),
+    case02: ((4000, 5000), 0),
+}
+
+fn can_limit_by_max_hourly_time_window_jobs_impl(job3_times: (i32, i32), expected_unassigned: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 1000)], 1.),
+                create_delivery_job_with_times("job2", vec![2., 0.], vec![(0, 1000)], 1.),
+                create_delivery_job_with_times("job3", vec![3., 0.], vec![job3_times], 1.),
+                create_delivery_job("job4", vec![4., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(10000.).to_string(),
+                        location: vec![0., 0.].to_loc(),
+                        max_overtime: None,
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: None,
+                    max_time_window_jobs: None,
+                    max_reloads: None,
+                    max_hourly_time_window_jobs: Some(2),
+                    max_late_stops: None,
+                    max_first_leg_distance: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let unassigned = solution.unassigned.unwrap_or_default();
+    assert_eq!(unassigned.len(), expected_unassigned);
+    assert!(unassigned.iter().all(|job| job.job_id != "job4"));
+    assert!(unassigned.iter().all(|job| matches!(job.reasons.first(), Some(reason) if reason.code == 117)));
+}
@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+parameterized_test! {can_limit_by_max_late_stops, (max_late_stops, expected_unassigned), {
+    can_limit_by_max_late_stops_impl(max_late_stops, expected_unassigned);
+}}
+
+can_limit_by_max_late_stops! {
+    case01: (0, 2),
+    case02: (1, 1),
+    case03: (2, 0),
+}
+
+fn can_limit_by_max_late_stops_impl(max_late_stops: usize, expected_unassigned: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 1)], 1.),
+                create_delivery_job_with_times("job2", vec![2., 0.], vec![(0, 1)], 1.),
+                create_delivery_job_with_times("job3", vec![3., 0.], vec![(0, 1)], 1.),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: None,
+                    max_time_window_jobs: None,
+                    max_reloads: None,
+                    max_hourly_time_window_jobs: None,
+                    max_late_stops: Some(max_late_stops),
+                    max_first_leg_distance: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config {
+            time_windows: Some(TimeWindowsConfig { soft: true, lateness_penalty: Some(1.), unit: None }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.clone().unwrap_or_default();
+    assert_eq!(unassigned.len(), expected_unassigned);
+    assert!(unassigned.iter().all(|job| matches!(job.reasons.first(), Some(reason) if reason.code == 120)));
+    let late_stops = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter(|stop| stop.activities.iter().any(|activity| activity.job_id.starts_with("job")))
+        .filter(|stop| parse_time(&stop.time.arrival) > 1.)
+        .count();
+    assert!(late_stops <= max_late_stops);
+}
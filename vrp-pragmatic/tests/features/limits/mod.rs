@@ -1,3 +1,11 @@
 mod area_allowance;
+mod depot_inventory;
 mod max_distance;
+mod max_first_leg_distance;
+mod max_hourly_time_window_jobs;
+mod max_late_stops;
+mod max_time_window_jobs;
+mod receiving_rate;
 mod shift_time;
+mod vehicle_pool;
+mod vehicle_range;
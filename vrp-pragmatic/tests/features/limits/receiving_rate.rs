@@ -0,0 +1,44 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_by_receiving_rate, (capacity, interval, expected_unassigned), {
+    can_limit_by_receiving_rate_impl(capacity, interval, expected_unassigned);
+}}
+
+can_limit_by_receiving_rate! {
+    case01_different_tours_same_arrival: (1, 5., vec![115]),
+    case02_same_tour: (2, 5., vec![]),
+    case03_no_receiving_rate: (1, 0., vec![]),
+}
+
+fn can_limit_by_receiving_rate_impl(capacity: i32, interval: f64, expected_unassigned: Vec<i32>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![10., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_vehicle_with_capacity("my_vehicle", vec![capacity])
+            }],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config {
+            receiving_rates: if interval > 0. {
+                Some(vec![ReceivingRate { location: vec![10., 0.].to_loc(), interval }])
+            } else {
+                None
+            },
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.unwrap_or_default();
+    let codes = unassigned.iter().filter_map(|job| job.reasons.first()).map(|reason| reason.code).collect::<Vec<_>>();
+    assert_eq!(codes, expected_unassigned);
+}
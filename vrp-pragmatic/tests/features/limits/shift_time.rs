@@ -4,7 +4,16 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_shift_time_limit(shift_time: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, shift_time: Some(shift_time), allowed_areas: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: Some(shift_time),
+            allowed_areas: None,
+            max_time_window_jobs: None,
+            max_reloads: None,
+            max_hourly_time_window_jobs: None,
+            max_late_stops: None,
+            max_first_leg_distance: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -25,6 +34,10 @@ fn can_limit_one_job_by_shift_time() {
         travel_times: vec![1, 100, 100, 1],
         distances: vec![1, 1, 1, 1],
         error_codes: Option::None,
+        default_travel_time: None,
+        default_distance: None,
+        time_unit: None,
+        unreachable_value: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -37,6 +50,7 @@ fn can_limit_one_job_by_shift_time() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -82,6 +96,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 distance: 6,
                 duration: 36,
                 times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -134,7 +149,10 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     distance: 6,
                     duration: 36,
                     times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             unassigned: Some(vec![
                 UnassignedJob {
@@ -0,0 +1,45 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_type(type_id: &str, earliest: f64) -> VehicleType {
+    let shift = create_default_vehicle_shift();
+    VehicleType {
+        vehicle_ids: vec![format!("{}_1", type_id), format!("{}_2", type_id)],
+        shifts: vec![VehicleShift { start: ShiftStart { earliest: format_time(earliest), ..shift.start }, ..shift }],
+        ..create_vehicle_with_capacity(type_id, vec![1])
+    }
+}
+
+parameterized_test! {can_limit_by_vehicle_pool, (vehicle_pool, expected_unassigned), {
+    can_limit_by_vehicle_pool_impl(vehicle_pool, expected_unassigned);
+}}
+
+can_limit_by_vehicle_pool! {
+    case01_pool_of_two: (Some(2), 0),
+    case02_pool_of_one: (Some(1), 2),
+    case03_no_pool: (None, 0),
+}
+
+fn can_limit_by_vehicle_pool_impl(vehicle_pool: Option<usize>, expected_unassigned: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=4).map(|idx| create_delivery_job(&format!("job{}", idx), vec![10., 0.])).collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type("morning", 0.), create_vehicle_type("evening", 30.)],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config { vehicle_pool, ..Default::default() }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.unwrap_or_default();
+    assert_eq!(unassigned.len(), expected_unassigned);
+    assert!(unassigned.iter().all(|job| job.reasons.first().into_iter().any(|reason| reason.code == 116)));
+    assert_eq!(solution.tours.len(), 4 - expected_unassigned);
+}
@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_assign_job_out_of_range_to_another_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![3., 0.]), create_delivery_job("job2", vec![20., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType { range: Some(10.), ..create_vehicle_with_capacity("short_range", vec![1]) },
+                create_vehicle_with_capacity("long_range", vec![2]),
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let job2_tours = solution
+        .tours
+        .iter()
+        .filter(|tour| get_ids_from_tour(tour).iter().flatten().any(|id| id == "job2"))
+        .map(|tour| tour.type_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(job2_tours, vec!["long_range"]);
+}
+
+#[test]
+fn can_limit_by_vehicle_range() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![20., 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { range: Some(39.), ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 101,
+                description: "cannot be assigned due to max distance constraint of vehicle".to_string()
+            }]
+        }])
+    );
+}
@@ -1,15 +1,21 @@
 //! This module contains feature tests: minimalistic tests which check features in isolation and combination.
 
+mod anchors;
 mod breaks;
+mod companions;
+mod corridors;
 mod depots;
 mod fleet;
 mod format;
 mod limits;
 mod multjob;
+mod objectives;
 mod pickdev;
 mod priorities;
 mod relations;
 mod reload;
 mod skills;
 mod timing;
+mod transfers;
+mod variants;
 mod work_balance;
@@ -34,6 +34,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 distance: 16,
                 duration: 20,
                 times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -97,7 +98,10 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                     distance: 16,
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -133,6 +137,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                 distance: 12,
                 duration: 15,
                 times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -188,7 +193,10 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                     distance: 12,
                     duration: 15,
                     times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
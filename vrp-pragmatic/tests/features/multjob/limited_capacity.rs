@@ -38,6 +38,7 @@ fn can_handle_limited_capacity() {
                 distance: 36,
                 duration: 42,
                 times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -120,7 +121,10 @@ fn can_handle_limited_capacity() {
                     distance: 36,
                     duration: 42,
                     times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
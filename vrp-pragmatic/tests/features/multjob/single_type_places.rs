@@ -31,6 +31,7 @@ fn can_use_only_deliveries_as_static_demand() {
                 distance: 8,
                 duration: 11,
                 times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -69,7 +70,10 @@ fn can_use_only_deliveries_as_static_demand() {
                     distance: 8,
                     duration: 11,
                     times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -90,6 +94,7 @@ fn can_use_only_pickups_as_static_demand() {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![10., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
@@ -111,6 +116,7 @@ fn can_use_only_pickups_as_static_demand() {
                 distance: 10,
                 duration: 13,
                 times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -157,7 +163,10 @@ fn can_use_only_pickups_as_static_demand() {
                     distance: 10,
                     duration: 13,
                     times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -0,0 +1,107 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_test_problem(jobs: usize, vehicles: usize) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: (0..jobs).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: (0..vehicles).map(|idx| format!("my_vehicle_{}", idx)).collect(),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_get_auto_weights_based_on_problem_size, (jobs, vehicles, expected), {
+    can_get_auto_weights_based_on_problem_size_impl(jobs, vehicles, expected);
+}}
+
+can_get_auto_weights_based_on_problem_size! {
+    case01_small: (10, 2, (10., 1.)),
+    case02_medium: (275, 10, (5.5, 1.)),
+    case03_large_by_jobs: (1000, 10, (1., 1.)),
+    case04_large_by_vehicles: (10, 500, (1., 1.)),
+}
+
+fn can_get_auto_weights_based_on_problem_size_impl(jobs: usize, vehicles: usize, expected: (f64, f64)) {
+    let problem = create_test_problem(jobs, vehicles);
+
+    assert_eq!(get_auto_weights(&problem), expected);
+}
+
+#[test]
+fn can_keep_specified_objectives_with_auto_weights() {
+    let problem = Problem {
+        objectives: Some(Objectives { primary: vec![MinimizeCost], secondary: None, tie_break: None }),
+        ..create_test_problem(10, 2)
+    };
+
+    let problem = with_auto_weights(problem);
+
+    let objectives = problem.objectives.expect("no objectives");
+    assert!(matches!(objectives.primary.as_slice(), [MinimizeCost]));
+    assert!(objectives.secondary.is_none());
+    assert!(problem.config.is_none());
+}
+
+#[test]
+fn can_solve_small_problem_with_auto_weights() {
+    let problem = with_auto_weights(Problem {
+        plan: Plan {
+            jobs: vec![4., 5., 6., 14., 15., 16.]
+                .into_iter()
+                .map(|x| create_delivery_job(&format!("job{}", x), vec![x, 0.]))
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![3],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    });
+    let matrix = create_matrix_from_problem(&problem);
+
+    assert!(problem.objectives.is_none());
+    assert_eq!(problem.config.as_ref().and_then(|config| config.auto_weights), Some(true));
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+}
+
+#[test]
+fn can_follow_priorities_with_auto_weights() {
+    let problem = with_auto_weights(Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_priority("job1", vec![1., 0.], 2),
+                create_delivery_job_with_priority("job2", vec![2., 0.], 1),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![1])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.as_ref().map_or(0, |unassigned| unassigned.len()), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job2"], vec!["arrival"]]);
+}
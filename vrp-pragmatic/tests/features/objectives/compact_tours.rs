@@ -0,0 +1,64 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn get_tour_jobs(solution: &Solution) -> Vec<Vec<String>> {
+    let mut tours = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let mut job_ids = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter())
+                .filter(|activity| activity.activity_type == "delivery")
+                .map(|activity| activity.job_id.clone())
+                .collect::<Vec<_>>();
+            job_ids.sort();
+            job_ids
+        })
+        .collect::<Vec<_>>();
+    tours.sort();
+
+    tours
+}
+
+parameterized_test! {can_compact_tours, (is_compact, expected), {
+    can_compact_tours_impl(is_compact, expected);
+}}
+
+can_compact_tours! {
+    case01: (false, vec![vec!["east1", "east2", "west1", "west2"]]),
+    case02: (true, vec![vec!["east1", "east2"], vec!["west1", "west2"]]),
+}
+
+fn can_compact_tours_impl(is_compact: bool, expected: Vec<Vec<&str>>) {
+    let primary = if is_compact { vec![MinimizeUnassignedJobs, CompactTours] } else { vec![MinimizeUnassignedJobs] };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("east1", vec![10., 0.]),
+                create_delivery_job("east2", vec![11., 0.]),
+                create_delivery_job("west1", vec![-10., 0.]),
+                create_delivery_job("west2", vec![-11., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives { primary, secondary: Some(vec![MinimizeCost]), tie_break: None }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_tour_jobs(&solution), expected);
+}
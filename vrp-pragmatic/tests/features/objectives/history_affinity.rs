@@ -0,0 +1,82 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::collections::HashMap;
+
+fn create_test_problem(objectives: Option<Objectives>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![-5., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives,
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_affinities(affinities: Vec<(&str, &str)>) -> HashMap<String, String> {
+    affinities.into_iter().map(|(job_id, vehicle_id)| (job_id.to_string(), vehicle_id.to_string())).collect()
+}
+
+#[test]
+fn can_use_default_objectives_with_history_affinity() {
+    let problem = with_history_affinity(create_test_problem(None), &create_test_affinities(vec![("job1", "v1")]));
+
+    assert_eq!(problem.plan.jobs[0].vehicle_affinity, Some("v1".to_string()));
+    assert_eq!(problem.plan.jobs[1].vehicle_affinity, None);
+    let objectives = problem.objectives.expect("no objectives");
+    assert!(matches!(objectives.primary.as_slice(), [MinimizeUnassignedJobs, MinimizeTours]));
+    assert!(matches!(objectives.secondary.as_deref(), Some([MinimizeCost])));
+    assert!(matches!(objectives.tie_break.as_deref(), Some([HistoryAffinity])));
+}
+
+#[test]
+fn can_keep_specified_objectives_with_history_affinity() {
+    let problem = create_test_problem(Some(Objectives {
+        primary: vec![MinimizeCost],
+        secondary: None,
+        tie_break: Some(vec![BalanceStops]),
+    }));
+
+    let problem = with_history_affinity(problem, &create_test_affinities(vec![]));
+
+    let objectives = problem.objectives.expect("no objectives");
+    assert!(matches!(objectives.primary.as_slice(), [MinimizeCost]));
+    assert!(objectives.secondary.is_none());
+    assert!(matches!(objectives.tie_break.as_deref(), Some([BalanceStops, HistoryAffinity])));
+}
+
+parameterized_test! {can_return_jobs_to_historical_vehicles, affinities, {
+    can_return_jobs_to_historical_vehicles_impl(affinities);
+}}
+
+can_return_jobs_to_historical_vehicles! {
+    case01: vec![("job1", "my_vehicle_1"), ("job2", "my_vehicle_2")],
+    case02: vec![("job1", "my_vehicle_2"), ("job2", "my_vehicle_1")],
+}
+
+fn can_return_jobs_to_historical_vehicles_impl(affinities: Vec<(&str, &str)>) {
+    let problem = with_history_affinity(create_test_problem(None), &create_test_affinities(affinities.clone()));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    affinities.into_iter().for_each(|(job_id, vehicle_id)| {
+        let tour = solution
+            .tours
+            .iter()
+            .find(|tour| tour.stops.iter().flat_map(|stop| stop.activities.iter()).any(|a| a.job_id == job_id))
+            .expect("cannot find tour");
+        assert_eq!(tour.vehicle_id, vehicle_id);
+    });
+}
@@ -0,0 +1,43 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_minimize_deadhead_distance, (is_pickup, demand, expected), {
+    can_minimize_deadhead_distance_impl(is_pickup, demand, expected);
+}}
+
+can_minimize_deadhead_distance! {
+    case01: (true, vec![1], vec![vec!["departure"], vec!["near"], vec!["far"], vec!["arrival"]]),
+    case02: (false, vec![1], vec![vec!["departure"], vec!["far"], vec!["near"], vec!["arrival"]]),
+    case03: (true, vec![1, 1], vec![vec!["departure"], vec!["near"], vec!["far"], vec!["arrival"]]),
+    case04: (false, vec![1, 1], vec![vec!["departure"], vec!["far"], vec!["near"], vec!["arrival"]]),
+}
+
+fn can_minimize_deadhead_distance_impl(is_pickup: bool, demand: Vec<i32>, expected: Vec<Vec<&str>>) {
+    // NOTE both tour orders have the same total distance, but different distance driven without load
+    let create_job = if is_pickup { create_pickup_job_with_demand } else { create_delivery_job_with_demand };
+    let capacity = demand.iter().map(|_| 10).collect();
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job("near", vec![1., 0.], demand.clone()), create_job("far", vec![10., 0.], demand)],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", capacity)],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs, MinimizeDeadhead],
+            secondary: Some(vec![MinimizeCost]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.distance, 20);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), expected);
+}
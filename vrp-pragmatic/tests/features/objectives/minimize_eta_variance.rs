@@ -0,0 +1,53 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn get_eta_variance(solution: &Solution) -> f64 {
+    let etas = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter(|stop| stop.activities.iter().any(|activity| activity.job_id.starts_with("job")))
+        .map(|stop| parse_time(&stop.time.arrival))
+        .collect::<Vec<_>>();
+    let mean = etas.iter().sum::<f64>() / etas.len() as f64;
+
+    etas.iter().map(|eta| (eta - mean).powi(2)).sum::<f64>() / etas.len() as f64
+}
+
+fn solve_with_objectives(primary: Vec<Objective>) -> Solution {
+    let problem = Problem {
+        plan: Plan {
+            // NOTE two jobs in opposite directions: only two tours give equal etas
+            jobs: vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![-10., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: (1..=2).map(|idx| format!("my_vehicle_{}", idx)).collect(),
+                costs: VehicleCosts { fixed: None, distance: 0., ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives { primary, secondary: Some(vec![MinimizeCost]), tie_break: None }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    solve_with_metaheuristic(problem, Some(vec![matrix]))
+}
+
+#[test]
+fn can_minimize_eta_variance() {
+    let cost_solution = solve_with_objectives(vec![MinimizeUnassignedJobs, MinimizeTours]);
+    let eta_solution = solve_with_objectives(vec![MinimizeUnassignedJobs, MinimizeEtaVariance]);
+
+    assert!(cost_solution.unassigned.is_none());
+    assert!(eta_solution.unassigned.is_none());
+    assert_eq!(cost_solution.tours.len(), 1);
+    assert!(get_eta_variance(&eta_solution) < get_eta_variance(&cost_solution));
+    assert_eq!(get_eta_variance(&eta_solution), 0.);
+}
@@ -0,0 +1,52 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::CoordIndex;
+use crate::helpers::*;
+
+parameterized_test! {can_minimize_route_intersections, (primary, is_crossing), {
+    can_minimize_route_intersections_impl(primary, is_crossing);
+}}
+
+can_minimize_route_intersections! {
+    case01_minimize_intersections: (vec![MinimizeUnassignedJobs, MinimizeIntersections], false),
+    case02_minimize_cost_only: (vec![MinimizeUnassignedJobs], true),
+}
+
+fn can_minimize_route_intersections_impl(primary: Vec<Objective>, is_crossing: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![10., 0.]),
+                create_delivery_job("job2", vec![10., 10.]),
+                create_delivery_job("job3", vec![0., 10.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        objectives: Some(Objectives { primary, secondary: Some(vec![MinimizeCost]), tie_break: None }),
+        ..create_empty_problem()
+    };
+    // NOTE make diagonals of the square cheaper, so cost optimal tour crosses itself
+    let mut matrix = create_matrix_from_problem(&problem);
+    let coord_index = CoordIndex::new(&problem);
+    let size = coord_index.unique().len();
+    let get_index = |lat: f64, lng: f64| coord_index.get_by_loc(&vec![lat, lng].to_loc()).unwrap();
+    let diagonals = vec![(get_index(0., 0.), get_index(10., 10.)), (get_index(10., 0.), get_index(0., 10.))];
+    diagonals.iter().flat_map(|&(from, to)| vec![(from, to), (to, from)]).for_each(|(from, to)| {
+        matrix.distances[from * size + to] = 1;
+        matrix.travel_times[from * size + to] = 1;
+    });
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let order = get_ids_from_tour(&solution.tours[0])
+        .into_iter()
+        .flatten()
+        .filter(|id| id.starts_with("job"))
+        .collect::<Vec<_>>();
+    // NOTE tour crosses itself when the job opposite to depot is not served in the middle
+    assert_eq!(order.len(), 3);
+    assert_eq!(order[1] != "job2", is_crossing);
+}
@@ -0,0 +1,43 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_minimize_long_tours, (threshold, expected_tours), {
+    can_minimize_long_tours_impl(threshold, expected_tours);
+}}
+
+can_minimize_long_tours! {
+    case01: (35., 2),
+    case02: (60., 1),
+}
+
+fn can_minimize_long_tours_impl(threshold: f64, expected_tours: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=5)
+                .map(|idx| create_delivery_job_with_duration(&format!("job{}", idx), vec![1., 0.], 10.))
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs, MinimizeLongTours { threshold }],
+            secondary: Some(vec![MinimizeCost]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+    assert!(solution.tours.iter().all(|tour| tour.statistic.duration as f64 <= threshold));
+}
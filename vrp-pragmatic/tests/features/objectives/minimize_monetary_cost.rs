@@ -0,0 +1,142 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_report_monetary_cost_breakdown() {
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_duration("job1", vec![5., 0.], 10.),
+                create_delivery_job_with_duration("job2", vec![10., 0.], 10.),
+                create_delivery_job("job3", vec![1000., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: shift.end.clone().map(|end| ShiftEnd {
+                        latest: format_time(35.),
+                        max_overtime: Some(100.),
+                        ..end
+                    }),
+                    ..shift
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeMonetaryCost {
+                rates: MonetaryRates {
+                    distance: Some(2.),
+                    time: Some(1.),
+                    overtime: Some(10.),
+                    fixed: Some(100.),
+                    unassigned: Some(1000.),
+                    late: None,
+                },
+            }],
+            secondary: Some(vec![MinimizeCost]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.as_ref().map(|unassigned| unassigned.len()), Some(1));
+    let monetary = solution.extras.as_ref().and_then(|extras| extras.monetary.clone()).expect("no monetary cost");
+    assert_eq!(
+        monetary,
+        MonetaryCost {
+            distance: 40.,
+            time: 40.,
+            overtime: 50.,
+            fixed: 100.,
+            unassigned: 1000.,
+            late: 0.,
+            total: 1230.
+        }
+    );
+    assert_eq!(
+        monetary.distance + monetary.time + monetary.overtime + monetary.fixed + monetary.unassigned + monetary.late,
+        monetary.total
+    );
+    assert_eq!(monetary.distance, 2. * solution.statistic.distance as f64);
+    assert_eq!(monetary.time, solution.statistic.duration as f64);
+}
+
+parameterized_test! {can_reduce_revenue_of_late_jobs, (late, urgent_location, expected_order, expected_late), {
+    can_reduce_revenue_of_late_jobs_impl(late, urgent_location, expected_order, expected_late);
+}}
+
+can_reduce_revenue_of_late_jobs! {
+    case01_no_late_rate: (None, 10., vec!["near", "urgent"], 0.),
+    case02_urgent_first: (Some(500.), 10., vec!["urgent", "near"], 0.),
+    case03_always_late: (Some(500.), 20., vec!["near", "urgent"], 500.),
+}
+
+fn can_reduce_revenue_of_late_jobs_impl(
+    late: Option<f64>,
+    urgent_location: f64,
+    expected_order: Vec<&str>,
+    expected_late: f64,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("near", vec![3., 0.], vec![(0, 100)], 1.),
+                create_delivery_job_with_times("urgent", vec![urgent_location, 0.], vec![(0, 10)], 1.),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeMonetaryCost {
+                rates: MonetaryRates {
+                    distance: Some(1.),
+                    time: None,
+                    overtime: None,
+                    fixed: None,
+                    unassigned: Some(1000.),
+                    late,
+                },
+            }],
+            secondary: Some(vec![MinimizeCost]),
+            tie_break: None,
+        }),
+        config: Some(Config {
+            time_windows: Some(TimeWindowsConfig { soft: true, lateness_penalty: Some(1.), unit: None }),
+            ..Default::default()
+        }),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_ids = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids, expected_order);
+    let monetary = solution.extras.as_ref().and_then(|extras| extras.monetary.clone()).expect("no monetary cost");
+    assert_eq!(monetary.late, expected_late);
+}
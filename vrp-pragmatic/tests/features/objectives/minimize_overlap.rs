@@ -0,0 +1,76 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::collections::{HashMap, HashSet};
+
+/// A grid resolution which makes cell side equal to three degrees of latitude.
+const RESOLUTION: f64 = 3. * 111_320.;
+
+fn get_shared_cells(solution: &Solution) -> usize {
+    solution
+        .tours
+        .iter()
+        .map(|tour| {
+            tour.stops
+                .iter()
+                .filter(|stop| stop.activities.iter().any(|activity| activity.activity_type == "delivery"))
+                .map(|stop| (stop.location.to_lat_lng().0 * 111_320. / RESOLUTION).floor() as i64)
+                .collect::<HashSet<_>>()
+        })
+        .flat_map(|cells| cells.into_iter())
+        .fold(HashMap::<i64, usize>::new(), |mut acc, cell| {
+            *acc.entry(cell).or_insert(0) += 1;
+            acc
+        })
+        .values()
+        .filter(|&&count| count > 1)
+        .count()
+}
+
+parameterized_test! {can_minimize_tour_overlap, (has_overlap_objective, expected), {
+    can_minimize_tour_overlap_impl(has_overlap_objective, expected);
+}}
+
+can_minimize_tour_overlap! {
+    case01: (false, 1),
+    case02: (true, 0),
+}
+
+fn can_minimize_tour_overlap_impl(has_overlap_objective: bool, expected: usize) {
+    let primary = if has_overlap_objective {
+        vec![MinimizeUnassignedJobs, MinimizeOverlap { resolution: RESOLUTION }]
+    } else {
+        vec![MinimizeUnassignedJobs]
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("near1", vec![1., 0.]),
+                create_delivery_job("near2", vec![2., 0.]),
+                create_delivery_job("east1", vec![10., 0.]),
+                create_delivery_job("east2", vec![11., 0.]),
+                create_delivery_job("west1", vec![-8., 0.]),
+                create_delivery_job("west2", vec![-9., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                shifts: vec![create_default_vehicle_shift_with_locations((1.5, 0.), (1.5, 0.))],
+                ..create_vehicle_with_capacity("my_vehicle", vec![4])
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives { primary, secondary: Some(vec![MinimizeCost]), tie_break: None }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(get_shared_cells(&solution), expected);
+}
@@ -0,0 +1,56 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+parameterized_test! {can_minimize_overtime_tours, (primary, expected_overtime_tours), {
+    can_minimize_overtime_tours_impl(primary, expected_overtime_tours);
+}}
+
+can_minimize_overtime_tours! {
+    case01_minimize_overtime_tours: (vec![MinimizeUnassignedJobs, MinimizeOvertimeTours], 1),
+    case02_balance_duration: (vec![MinimizeUnassignedJobs], 2),
+}
+
+fn can_minimize_overtime_tours_impl(primary: Vec<Objective>, expected_overtime_tours: usize) {
+    let shift_end = 35.;
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=8)
+                .map(|idx| create_delivery_job_with_duration(&format!("job{}", idx), vec![1., 0.], 10.))
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                shifts: vec![VehicleShift {
+                    end: shift.end.clone().map(|end| ShiftEnd {
+                        latest: format_time(shift_end),
+                        max_overtime: Some(100.),
+                        ..end
+                    }),
+                    ..shift
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary,
+            secondary: Some(vec![BalanceDuration { options: None }, MinimizeCost]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let overtime_tours =
+        solution.tours.iter().filter(|tour| parse_time(&tour.stops.last().unwrap().time.arrival) > shift_end).count();
+    assert_eq!(overtime_tours, expected_overtime_tours);
+}
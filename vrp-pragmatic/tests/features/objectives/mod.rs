@@ -0,0 +1,13 @@
+mod auto_weights;
+mod compact_tours;
+mod history_affinity;
+mod minimize_deadhead;
+mod minimize_eta_variance;
+mod minimize_intersections;
+mod minimize_long_tours;
+mod minimize_monetary_cost;
+mod minimize_overlap;
+mod minimize_overtime_tours;
+mod target_tours;
+mod weighted_lateness;
+mod weighted_sum;
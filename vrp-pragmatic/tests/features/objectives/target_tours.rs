@@ -0,0 +1,42 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_keep_tours_close_to_target, (target, expected_tours), {
+    can_keep_tours_close_to_target_impl(target, expected_tours);
+}}
+
+can_keep_tours_close_to_target! {
+    case01: (1, 1),
+    case02: (3, 3),
+    case03: (4, 4),
+    case04: (10, 6),
+}
+
+fn can_keep_tours_close_to_target_impl(target: usize, expected_tours: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=6).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: (1..=10).map(|idx| format!("my_vehicle_{}", idx)).collect(),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs, TargetTours { target }],
+            secondary: Some(vec![MinimizeCost]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+}
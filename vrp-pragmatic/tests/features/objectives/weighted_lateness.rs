@@ -0,0 +1,53 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+parameterized_test! {can_keep_high_priority_job_on_time, (high_priority_location, low_priority_location), {
+    can_keep_high_priority_job_on_time_impl(high_priority_location, low_priority_location);
+}}
+
+can_keep_high_priority_job_on_time! {
+    case01: (vec![10., 0.], vec![-10., 0.]),
+    case02: (vec![-10., 0.], vec![10., 0.]),
+}
+
+fn can_keep_high_priority_job_on_time_impl(high_priority_location: Vec<f64>, low_priority_location: Vec<f64>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    priority: Some(1),
+                    ..create_delivery_job_with_times("high", high_priority_location, vec![(0, 10)], 1.)
+                },
+                Job {
+                    priority: Some(2),
+                    ..create_delivery_job_with_times("low", low_priority_location, vec![(0, 10)], 1.)
+                },
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs],
+            secondary: Some(vec![MinimizeWeightedLateness, MinimizeCost]),
+            tie_break: None,
+        }),
+        config: Some(Config {
+            time_windows: Some(TimeWindowsConfig { soft: true, lateness_penalty: Some(1.), unit: None }),
+            ..Default::default()
+        }),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let get_arrival = |job_id: &str| {
+        let stop = solution.tours[0].stops.iter().find(|stop| stop.activities[0].job_id == job_id).unwrap();
+        parse_time(&stop.time.arrival)
+    };
+    assert!(get_arrival("high") <= 10.);
+    assert!(get_arrival("low") > 10.);
+}
@@ -0,0 +1,52 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_balance_cost_and_tours_with_weighted_sum, (normalize, expected_tours), {
+    can_balance_cost_and_tours_with_weighted_sum_impl(normalize, expected_tours);
+}}
+
+can_balance_cost_and_tours_with_weighted_sum! {
+    case01_raw: (false, 2),
+    case02_normalized: (true, 1),
+}
+
+fn can_balance_cost_and_tours_with_weighted_sum_impl(normalize: bool, expected_tours: usize) {
+    // NOTE two tours cost 78 and one tour costs 82: raw cost dominates amount of tours, while
+    // normalized values make the saved tour more important than relatively small extra cost
+    let create_vehicle = |id: &str, location: f64| VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations((location, 0.), (location, 0.))],
+        costs: VehicleCosts { fixed: Some(12.), ..create_default_vehicle_costs() },
+        ..create_default_vehicle(id)
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![4., 5., 6., 14., 15., 16.]
+                .into_iter()
+                .map(|x| create_delivery_job(&format!("job{}", x), vec![x, 0.]))
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle("vehicle_a", 0.), create_vehicle("vehicle_b", 20.)],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs],
+            secondary: Some(vec![WeightedSum {
+                objectives: vec![
+                    WeightedObjective { objective: MinimizeCost, weight: 1. },
+                    WeightedObjective { objective: MinimizeTours, weight: 1. },
+                ],
+                normalize: Some(normalize),
+            }]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+}
@@ -21,6 +21,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                 distance: 4,
                 duration: 6,
                 times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -67,7 +68,10 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                     distance: 4,
                     duration: 6,
                     times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -0,0 +1,42 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_loading_discipline, (discipline, expected_order), {
+    can_use_loading_discipline_impl(discipline, expected_order);
+}}
+
+can_use_loading_discipline! {
+    case01_no_discipline: (None, vec!["job1", "job2", "job1", "job2"]),
+    case02_lifo: (Some(VehicleLoadingDiscipline::Lifo), vec!["job1", "job2", "job2", "job1"]),
+    case03_fifo: (Some(VehicleLoadingDiscipline::Fifo), vec!["job1", "job2", "job1", "job2"]),
+}
+
+fn can_use_loading_discipline_impl(discipline: Option<VehicleLoadingDiscipline>, expected_order: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", vec![1., 0.], vec![3., 0.]),
+                create_pickup_delivery_job("job2", vec![2., 0.], vec![4., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![2],
+                loading_discipline: discipline,
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let order = get_ids_from_tour(&solution.tours[0]).into_iter().flatten().filter(|id| id.starts_with("job"));
+    assert_eq!(order.collect::<Vec<_>>(), to_strings(expected_order));
+}
@@ -28,6 +28,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                 distance: 8,
                 duration: 12,
                 times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -90,7 +91,10 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                     distance: 8,
                     duration: 12,
                     times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0 },
-                }
+                    tightness: None,
+                },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
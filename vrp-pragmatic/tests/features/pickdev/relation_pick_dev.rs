@@ -13,8 +13,9 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
             relations: Some(vec![Relation {
                 type_field: RelationType::Sequence,
                 jobs: to_strings(vec!["job1", "job2", "job1", "job2"]),
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                penalty: None,
             }]),
         },
         fleet: Fleet {
@@ -38,6 +39,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 distance: 50,
                 duration: 54,
                 times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -102,7 +104,10 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                     distance: 50,
                     duration: 54,
                     times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -34,6 +34,7 @@ fn can_follow_priorities() {
                 distance: 20,
                 duration: 23,
                 times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -86,7 +87,10 @@ fn can_follow_priorities() {
                     distance: 20,
                     duration: 23,
                     times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -1 +1,2 @@
 mod basic_priority;
+mod priority_escalation;
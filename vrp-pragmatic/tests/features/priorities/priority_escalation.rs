@@ -0,0 +1,40 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_escalate_priority_of_aging_job, (age, expected_served), {
+    can_escalate_priority_of_aging_job_impl(age, expected_served);
+}}
+
+can_escalate_priority_of_aging_job! {
+    case01_fresh_job: (None, "fresh"),
+    case02_not_enough_cycles: (Some(0), "fresh"),
+    case03_escalated_job: (Some(2), "aging"),
+    case04_escalated_to_max: (Some(10), "aging"),
+}
+
+fn can_escalate_priority_of_aging_job_impl(age: Option<usize>, expected_served: &str) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_priority("fresh", vec![1., 0.], 2),
+                Job { age, ..create_delivery_job_with_priority("aging", vec![2., 0.], 3) },
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![1])],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config {
+            priority_escalation: Some(PriorityEscalation { cycles: 1, max_priority: None }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.as_ref().map_or(0, |unassigned| unassigned.len()), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec![expected_served], vec!["arrival"]]);
+}
@@ -14,8 +14,9 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
             relations: Some(vec![Relation {
                 type_field: RelationType::Any,
                 jobs: to_strings(vec!["job1", "job3"]),
-                vehicle_id: "my_vehicle_1".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
                 shift_index: None,
+                penalty: None,
             }]),
         },
         fleet: Fleet {
@@ -40,6 +41,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 distance: 3,
                 duration: 6,
                 times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -84,7 +86,10 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                     distance: 3,
                     duration: 6,
                     times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
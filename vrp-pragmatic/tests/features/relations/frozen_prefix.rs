@@ -0,0 +1,52 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::collections::HashMap;
+
+fn create_problem(relations: Option<Vec<Relation>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: (1..=5).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_ids(solution: &Solution) -> Vec<String> {
+    get_ids_from_tour(solution.tours.first().unwrap()).into_iter().flatten().collect()
+}
+
+parameterized_test! {can_keep_frozen_prefix_of_tour, (prefix_size, expected_prefix), {
+    can_keep_frozen_prefix_of_tour_impl(prefix_size, expected_prefix);
+}}
+
+can_keep_frozen_prefix_of_tour! {
+    case01: (1, vec!["departure", "job5"]),
+    case02: (2, vec!["departure", "job5", "job2"]),
+    case03: (3, vec!["departure", "job5", "job2", "job4"]),
+}
+
+fn can_keep_frozen_prefix_of_tour_impl(prefix_size: usize, expected_prefix: Vec<&str>) {
+    let init_problem = create_problem(Some(vec![Relation {
+        type_field: RelationType::Strict,
+        jobs: to_strings(vec!["departure", "job5", "job2", "job4", "job1", "job3"]),
+        vehicle_id: Some("my_vehicle_1".to_string()),
+        shift_index: None,
+        penalty: None,
+    }]));
+    let matrix = create_matrix_from_problem(&init_problem);
+    let init_solution = solve_with_metaheuristic(init_problem, Some(vec![matrix.clone()]));
+    let relations = create_frozen_prefix_relations(&init_solution, &HashMap::default(), prefix_size);
+    let problem = create_problem(Some(relations));
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let (init_ids, ids) = (get_job_ids(&init_solution), get_job_ids(&solution));
+    assert!(solution.unassigned.is_none());
+    assert_eq!(ids.len(), init_ids.len());
+    assert_eq!(ids[..prefix_size + 1], to_strings(expected_prefix)[..]);
+    assert_eq!(ids[..prefix_size + 1], init_ids[..prefix_size + 1]);
+    assert_ne!(ids[prefix_size + 1..], init_ids[prefix_size + 1..]);
+}
@@ -19,14 +19,16 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job4", "job2", "job6"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
                 Relation {
                     type_field: RelationType::Any,
                     jobs: to_strings(vec!["job1", "job3"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
             ]),
         },
@@ -45,6 +47,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 distance: 18,
                 duration: 25,
                 times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -129,7 +132,10 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                     distance: 18,
                     duration: 25,
                     times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
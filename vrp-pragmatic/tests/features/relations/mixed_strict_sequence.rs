@@ -19,14 +19,16 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job4", "job2", "job6"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
                 Relation {
                     type_field: RelationType::Sequence,
                     jobs: to_strings(vec!["job1", "job3"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
             ]),
         },
@@ -45,6 +47,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 distance: 22,
                 duration: 29,
                 times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -129,7 +132,10 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                     distance: 22,
                     duration: 29,
                     times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -154,26 +160,30 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job1", "job6"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
                 Relation {
                     type_field: RelationType::Sequence,
                     jobs: to_strings(vec!["job3", "job7"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job2", "job8"]),
-                    vehicle_id: "my_vehicle_2".to_string(),
+                    vehicle_id: Some("my_vehicle_2".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
                 Relation {
                     type_field: RelationType::Sequence,
                     jobs: to_strings(vec!["job4", "job5"]),
-                    vehicle_id: "my_vehicle_2".to_string(),
+                    vehicle_id: Some("my_vehicle_2".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
             ]),
         },
@@ -200,6 +210,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 distance: 26,
                 duration: 34,
                 times: Timing { driving: 26, serving: 8, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![
                 Tour {
@@ -253,7 +264,10 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         distance: 13,
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0 },
+                        tightness: None,
                     },
+                    consumption: None,
+                    day: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -306,7 +320,10 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         distance: 13,
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0 },
+                        tightness: None,
                     },
+                    consumption: None,
+                    day: None,
                 }
             ],
             ..create_empty_solution()
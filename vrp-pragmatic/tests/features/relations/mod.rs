@@ -1,5 +1,8 @@
 mod any_with_new_jobs;
+mod frozen_prefix;
 mod mixed_strict_any;
 mod mixed_strict_sequence;
+mod route_hints;
+mod soft_precedence;
 mod strict_with_new_jobs;
 mod strict_with_old_jobs;
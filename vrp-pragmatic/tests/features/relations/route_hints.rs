@@ -0,0 +1,56 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(relations: Option<Vec<Relation>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: (1..=4).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_ids(solution: &Solution) -> Vec<String> {
+    get_ids_from_tour(solution.tours.first().unwrap())
+        .into_iter()
+        .flatten()
+        .filter(|id| id.starts_with("job"))
+        .collect()
+}
+
+parameterized_test! {can_use_route_hints, (hint, penalty, generations, is_hint_kept), {
+    can_use_route_hints_impl(hint, penalty, generations, is_hint_kept);
+}}
+
+can_use_route_hints! {
+    case01_generation_zero: (vec!["job2", "job1", "job4", "job3"], 0.1, 0, true),
+    case02_same_cost: (vec!["job4", "job3", "job2", "job1"], 0.1, 200, true),
+    case03_high_penalty: (vec!["job2", "job1", "job4", "job3"], 1000., 200, true),
+    case04_low_penalty: (vec!["job2", "job1", "job4", "job3"], 0.1, 200, false),
+}
+
+fn can_use_route_hints_impl(hint: Vec<&str>, penalty: f64, generations: usize, is_hint_kept: bool) {
+    // NOTE simulate a sequence produced by an external tool
+    let hint_problem = create_problem(Some(vec![Relation {
+        type_field: RelationType::Strict,
+        jobs: to_strings(std::iter::once("departure").chain(hint.iter().cloned()).collect()),
+        vehicle_id: Some("my_vehicle_1".to_string()),
+        shift_index: None,
+        penalty: None,
+    }]));
+    let matrix = create_matrix_from_problem(&hint_problem);
+    let hint_solution = solve_with_metaheuristic(hint_problem, Some(vec![matrix.clone()]));
+    assert_eq!(get_job_ids(&hint_solution), to_strings(hint.clone()));
+    let problem = create_problem(Some(create_route_hint_relations(&hint_solution, Some(penalty))));
+
+    let solution = solve_with_init_solution(problem, Some(vec![matrix]), &hint_solution, generations);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_job_ids(&solution) == to_strings(hint), is_hint_kept);
+    if !is_hint_kept {
+        assert!(solution.statistic.distance < hint_solution.statistic.distance);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn get_job_order(solution: &Solution) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+parameterized_test! {can_use_soft_precedence_relation, (penalty, is_order_followed), {
+    can_use_soft_precedence_relation_impl(penalty, is_order_followed);
+}}
+
+can_use_soft_precedence_relation! {
+    case01: (Some(1000.), true),
+    case02: (None, true),
+    case03: (Some(0.1), false),
+}
+
+fn can_use_soft_precedence_relation_impl(penalty: Option<f64>, is_order_followed: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::SoftPrecedence,
+                jobs: to_strings(vec!["job3", "job1"]),
+                vehicle_id: None,
+                shift_index: None,
+                penalty,
+            }]),
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![3],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let order = get_job_order(&solution);
+    let get_position = |job_id: &str| order.iter().position(|id| id == job_id).unwrap();
+    assert_eq!(get_position("job3") < get_position("job1"), is_order_followed);
+}
@@ -22,14 +22,16 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job1", "job6", "job4", "job8"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job2", "job3", "job5", "job7"]),
-                    vehicle_id: "my_vehicle_2".to_string(),
+                    vehicle_id: Some("my_vehicle_2".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
             ]),
         },
@@ -55,6 +57,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                 distance: 42,
                 duration: 52,
                 times: Timing { driving: 42, serving: 10, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![
                 Tour {
@@ -124,7 +127,10 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         distance: 22,
                         duration: 27,
                         times: Timing { driving: 22, serving: 5, waiting: 0, break_time: 0 },
+                        tightness: None,
                     },
+                    consumption: None,
+                    day: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -193,7 +199,10 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         distance: 20,
                         duration: 25,
                         times: Timing { driving: 20, serving: 5, waiting: 0, break_time: 0 },
+                        tightness: None,
                     },
+                    consumption: None,
+                    day: None,
                 }
             ],
             ..create_empty_solution()
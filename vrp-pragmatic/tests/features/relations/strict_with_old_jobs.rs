@@ -20,14 +20,16 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job1", "job6", "job4", "job8", "arrival"]),
-                    vehicle_id: "my_vehicle_1".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
                 Relation {
                     type_field: RelationType::Strict,
                     jobs: to_strings(vec!["departure", "job2", "job3", "job5", "job7", "arrival"]),
-                    vehicle_id: "my_vehicle_2".to_string(),
+                    vehicle_id: Some("my_vehicle_2".to_string()),
                     shift_index: None,
+                    penalty: None,
                 },
             ]),
         },
@@ -53,6 +55,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 distance: 34,
                 duration: 42,
                 times: Timing { driving: 34, serving: 8, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![
                 Tour {
@@ -114,7 +117,10 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         distance: 20,
                         duration: 24,
                         times: Timing { driving: 20, serving: 4, waiting: 0, break_time: 0 },
+                        tightness: None,
                     },
+                    consumption: None,
+                    day: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -175,7 +181,10 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         distance: 14,
                         duration: 18,
                         times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
+                        tightness: None,
                     },
+                    consumption: None,
+                    day: None,
                 }
             ],
             ..create_empty_solution()
@@ -20,11 +20,18 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: None,
@@ -34,6 +41,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                         duration: 2.0,
                         tag: None,
                     }]),
+                    anchors: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -54,6 +62,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                 distance: 16,
                 duration: 20,
                 times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -117,7 +126,10 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                     distance: 16,
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -34,11 +34,18 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: None,
@@ -48,6 +55,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         duration: 2.0,
                         tag: None,
                     }]),
+                    anchors: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -68,6 +76,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -120,7 +129,10 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             unassigned,
             ..create_empty_solution()
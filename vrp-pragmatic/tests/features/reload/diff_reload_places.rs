@@ -19,11 +19,18 @@ fn can_use_reloads_with_different_locations() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.),
                         location: vec![32., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: None,
@@ -41,6 +48,7 @@ fn can_use_reloads_with_different_locations() {
                             tag: Some("far".to_string()),
                         },
                     ]),
+                    anchors: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -61,6 +69,7 @@ fn can_use_reloads_with_different_locations() {
                 distance: 38,
                 duration: 47,
                 times: Timing { driving: 38, serving: 9, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -147,7 +156,10 @@ fn can_use_reloads_with_different_locations() {
                     distance: 38,
                     duration: 47,
                     times: Timing { driving: 38, serving: 9, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -0,0 +1,70 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_with_reloads(max_reloads: Option<usize>) -> VehicleType {
+    let reload = VehicleCargoPlace { times: None, location: vec![0., 0.].to_loc(), duration: 2.0, tag: None };
+
+    VehicleType {
+        shifts: vec![VehicleShift {
+            reloads: Some(vec![reload.clone(), reload.clone(), reload]),
+            ..create_default_vehicle_shift()
+        }],
+        capacity: vec![1],
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            allowed_areas: None,
+            max_time_window_jobs: None,
+            max_reloads,
+            max_hourly_time_window_jobs: None,
+            max_late_stops: None,
+            max_first_leg_distance: None,
+        }),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn get_reloads_count(solution: &Solution) -> usize {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "reload")
+        .count()
+}
+
+parameterized_test! {can_limit_amount_of_reloads, (max_reloads, expected_assigned), {
+    can_limit_amount_of_reloads_impl(max_reloads, expected_assigned);
+}}
+
+can_limit_amount_of_reloads! {
+    case01: (None, 4),
+    case02: (Some(0), 1),
+    case03: (Some(1), 2),
+    case04: (Some(2), 3),
+}
+
+fn can_limit_amount_of_reloads_impl(max_reloads: Option<usize>, expected_assigned: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=4).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_reloads(max_reloads)], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.as_ref().map_or(0, |unassigned| unassigned.len());
+    assert_eq!(4 - unassigned, expected_assigned);
+    assert_eq!(get_reloads_count(&solution), expected_assigned - 1);
+    assert!(solution
+        .unassigned
+        .iter()
+        .flat_map(|unassigned| unassigned.iter())
+        .all(|job| job.reasons.first().map_or(false, |reason| reason.code == 3)));
+}
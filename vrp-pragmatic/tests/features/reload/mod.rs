@@ -1,7 +1,9 @@
 mod avoid_reload;
 mod basic_reload;
 mod diff_reload_places;
+mod max_reloads;
 mod multi_dim_reload;
 mod multi_job_reload;
 mod multi_vehicle_reload;
 mod picks_devs_reload;
+mod reload_handling_time;
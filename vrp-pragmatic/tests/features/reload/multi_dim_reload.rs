@@ -16,11 +16,18 @@ fn can_use_multi_dim_capacity() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: None,
@@ -30,6 +37,7 @@ fn can_use_multi_dim_capacity() {
                         duration: 2.0,
                         tag: None,
                     }]),
+                    anchors: None,
                 }],
                 capacity: vec![1, 1],
                 ..create_default_vehicle_type()
@@ -50,6 +58,7 @@ fn can_use_multi_dim_capacity() {
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -102,7 +111,10 @@ fn can_use_multi_dim_capacity() {
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -23,11 +23,18 @@ fn can_serve_multi_job_and_delivery_with_reload() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![10., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: None,
@@ -37,6 +44,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         duration: 2.0,
                         tag: None,
                     }]),
+                    anchors: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -57,6 +65,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 distance: 14,
                 duration: 22,
                 times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -144,7 +153,10 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                     distance: 14,
                     duration: 22,
                     times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -168,7 +180,14 @@ fn can_properly_handle_load_without_capacity_violation() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003 },
+                costs: VehicleCosts {
+                    fixed: Some(20.0),
+                    distance: 0.002,
+                    time: 0.003,
+                    distance_tiers: None,
+                    max_daily_cost: None,
+                    load_distance: None,
+                },
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![
                         VehicleCargoPlace {
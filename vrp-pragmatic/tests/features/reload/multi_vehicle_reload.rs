@@ -14,11 +14,18 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: None,
@@ -28,6 +35,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         duration: 2.0,
                         tag: None,
                     }]),
+                    anchors: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -48,6 +56,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -100,7 +109,10 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         },
@@ -19,11 +19,18 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![6., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: None,
@@ -33,6 +40,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         duration: 2.0,
                         tag: None,
                     }]),
+                    anchors: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -53,6 +61,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 distance: 6,
                 duration: 12,
                 times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -121,7 +130,10 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                     distance: 6,
                     duration: 12,
                     times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "d3".to_string(),
@@ -0,0 +1,59 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+parameterized_test! {can_use_reload_handling_time, (handling_time, expected_duration), {
+    can_use_reload_handling_time_impl(handling_time, expected_duration);
+}}
+
+can_use_reload_handling_time! {
+    case01_no_handling_time: (None, 2.),
+    case02_longer_handling_time: (Some(10.), 10.),
+    case03_shorter_handling_time: (Some(1.), 2.),
+}
+
+fn can_use_reload_handling_time_impl(handling_time: Option<f64>, expected_duration: f64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleCargoPlace {
+                        location: vec![0., 0.].to_loc(),
+                        duration: 2.,
+                        times: None,
+                        tag: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![1],
+                reload_handling_time: handling_time,
+                maneuvering_time: None,
+                capacity_decay: None,
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.first().expect("no tour");
+    let reload_idx = tour
+        .stops
+        .iter()
+        .position(|stop| stop.activities.iter().any(|activity| activity.activity_type == "reload"))
+        .expect("no reload");
+    let reload = &tour.stops[reload_idx];
+    let next = &tour.stops[reload_idx + 1];
+    let departure = parse_time(&reload.time.departure);
+    assert_eq!(departure - parse_time(&reload.time.arrival), expected_duration);
+    assert_eq!(parse_time(&next.time.arrival) - departure, next.location.to_lat_lng().0);
+    assert_eq!(tour.statistic.times.serving, expected_duration as i64 + 2);
+}
@@ -0,0 +1,94 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+const FRAGILE_RULE: &str = "!(job.tag == 'fragile' && vehicle.type_id == 'construction')";
+
+fn create_job_with_tag(id: &str, location: Vec<f64>, tag: &str) -> Job {
+    let mut job = create_delivery_job(id, location);
+    job.deliveries.as_mut().unwrap()[0].tag = Some(tag.to_string());
+
+    job
+}
+
+fn create_vehicle_at(id: &str, location: (f64, f64)) -> VehicleType {
+    VehicleType {
+        type_id: id.to_string(),
+        vehicle_ids: vec![format!("{}_1", id)],
+        shifts: vec![create_default_vehicle_shift_with_locations(location, location)],
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_problem_with_rules(vehicles: Vec<VehicleType>, rules: Vec<&str>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_tag("job1", vec![1., 0.], "fragile"),
+                create_job_with_tag("job2", vec![2., 0.], "heavy"),
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles, profiles: create_default_profiles() },
+        config: Some(Config {
+            rules: Some(rules.into_iter().map(|rule| rule.to_string()).collect()),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    }
+}
+
+fn get_vehicle_id<'a>(solution: &'a Solution, job_id: &str) -> Option<&'a str> {
+    solution
+        .tours
+        .iter()
+        .find(|tour| tour.stops.iter().flat_map(|stop| stop.activities.iter()).any(|a| a.job_id == job_id))
+        .map(|tour| tour.vehicle_id.as_str())
+}
+
+#[test]
+fn can_assign_jobs_respecting_attribute_rule() {
+    let problem = create_problem_with_rules(
+        vec![create_vehicle_at("construction", (0., 0.)), create_vehicle_at("regular", (10., 0.))],
+        vec![FRAGILE_RULE],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_vehicle_id(&solution, "job1"), Some("regular_1"));
+}
+
+#[test]
+fn can_have_unassigned_due_to_attribute_rule() {
+    let problem = create_problem_with_rules(vec![create_vehicle_at("construction", (0., 0.))], vec![FRAGILE_RULE]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_vehicle_id(&solution, "job2"), Some("construction_1"));
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 125,
+                description: "cannot be assigned due to attribute rule".to_string()
+            }]
+        }])
+    );
+}
+
+#[test]
+fn can_add_attribute_rules_to_problem() {
+    let problem = Problem { config: None, ..create_problem_with_rules(vec![], vec![]) };
+
+    let problem = with_attribute_rules(problem, &[FRAGILE_RULE.to_string()]);
+    let problem = with_attribute_rules(problem, &["job.priority < 3".to_string()]);
+
+    assert_eq!(
+        problem.config.and_then(|config| config.rules),
+        Some(vec![FRAGILE_RULE.to_string(), "job.priority < 3".to_string()])
+    );
+}
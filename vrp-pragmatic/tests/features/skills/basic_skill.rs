@@ -36,6 +36,7 @@ fn can_wait_for_job_start() {
                 distance: 18,
                 duration: 19,
                 times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_with_skill_1".to_string(),
@@ -72,7 +73,10 @@ fn can_wait_for_job_start() {
                     distance: 18,
                     duration: 19,
                     times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
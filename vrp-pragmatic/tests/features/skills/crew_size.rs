@@ -0,0 +1,79 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_job_with_crew_size(id: &str, location: Vec<f64>, crew_size: Option<usize>) -> Job {
+    Job { crew_size, ..create_delivery_job(id, location) }
+}
+
+fn create_vehicle_with_crew_size(id: &str, location: (f64, f64), crew_size: Option<usize>) -> VehicleType {
+    VehicleType {
+        type_id: id.to_string(),
+        vehicle_ids: vec![format!("{}_1", id)],
+        shifts: vec![create_default_vehicle_shift_with_locations(location, location)],
+        crew_size,
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_assign_job_only_to_vehicle_with_required_crew_size() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_crew_size("job1", vec![1., 0.], Some(2)),
+                create_job_with_crew_size("job2", vec![2., 0.], None),
+                create_job_with_crew_size("job3", vec![3., 0.], Some(1)),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_crew_size("one_crew", (0., 0.), None),
+                create_vehicle_with_crew_size("two_crew", (10., 0.), Some(2)),
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.iter().find(|tour| tour.stops.iter().any(|stop| stop.activities[0].job_id == "job1"));
+    assert_eq!(tour.map(|tour| tour.vehicle_id.as_str()), Some("two_crew_1"));
+}
+
+#[test]
+fn can_have_unassigned_due_to_missing_crew_size() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_crew_size("job1", vec![1., 0.], Some(2)),
+                create_job_with_crew_size("job2", vec![2., 0.], None),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_crew_size("one_crew", (0., 0.), Some(1))],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 119,
+                description: "cannot be served by vehicle with required crew size".to_string()
+            }]
+        }])
+    );
+}
@@ -1,2 +1,6 @@
+mod attribute_rules;
 mod basic_skill;
+mod crew_size;
+mod reachable_jobs;
+mod skill_time_windows;
 mod unassigned_due_to_skills;
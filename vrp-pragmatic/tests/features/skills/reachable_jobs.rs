@@ -0,0 +1,45 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::solver::reachable_jobs;
+
+parameterized_test! {can_get_reachable_jobs_by_skills, (vehicle_id, expected), {
+    can_get_reachable_jobs_by_skills_impl(vehicle_id, expected);
+}}
+
+can_get_reachable_jobs_by_skills! {
+    case01: ("vehicle_with_skill_1", vec!["job1", "job2"]),
+    case02: ("vehicle_without_skill_1", vec!["job2"]),
+    case03: ("unknown", vec![]),
+}
+
+fn can_get_reachable_jobs_by_skills_impl(vehicle_id: &str, expected: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_skills("job1", vec![1., 0.], vec!["unique_skill".to_string()]),
+                create_delivery_job("job2", vec![2., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("vehicle_without_skill"),
+                VehicleType {
+                    skills: Some(vec!["unique_skill".to_string()]),
+                    ..create_default_vehicle("vehicle_with_skill")
+                },
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read core problem"));
+    let jobs = core_problem.jobs.all().collect::<Vec<_>>();
+
+    let mut job_ids = reachable_jobs(core_problem, vehicle_id, jobs.as_slice());
+
+    job_ids.sort();
+    assert_eq!(job_ids, expected);
+}
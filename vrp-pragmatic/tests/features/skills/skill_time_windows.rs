@@ -0,0 +1,49 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_job_with_skill_times(id: &str, location: Vec<f64>) -> Job {
+    let mut job = create_delivery_job_with_times(id, location, vec![(100, 200)], 1.);
+    job.deliveries.iter_mut().flat_map(|tasks| tasks.iter_mut()).flat_map(|task| task.places.iter_mut()).for_each(
+        |place| {
+            place.skill_times = Some(vec![SkillTimeWindows {
+                skills: vec!["certified".to_string()],
+                times: vec![vec![format_time(0.), format_time(50.)]],
+            }])
+        },
+    );
+
+    job
+}
+
+parameterized_test! {can_use_skill_time_windows, (skills, expected_departure), {
+    can_use_skill_time_windows_impl(skills, expected_departure);
+}}
+
+can_use_skill_time_windows! {
+    case01_skilled_uses_premium: (Some(vec!["certified"]), 2.),
+    case02_unskilled_uses_standard: (None, 101.),
+    case03_other_skill_uses_standard: (Some(vec!["other"]), 101.),
+}
+
+fn can_use_skill_time_windows_impl(skills: Option<Vec<&str>>, expected_departure: f64) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_job_with_skill_times("job1", vec![1., 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                skills: skills.map(|skills| skills.iter().map(|skill| skill.to_string()).collect()),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let stop = solution.tours[0].stops.iter().find(|stop| stop.activities[0].job_id == "job1").unwrap();
+    assert_eq!(stop.time.departure, format_time(expected_departure));
+}
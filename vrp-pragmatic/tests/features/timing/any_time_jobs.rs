@@ -0,0 +1,64 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_serve_job_without_time_windows, (times, expected_served), {
+    can_serve_job_without_time_windows_impl(times, expected_served);
+}}
+
+can_serve_job_without_time_windows! {
+    case01_empty_times: (Some(vec![]), true),
+    case02_omitted_times: (None, true),
+    case03_bounded_times: (Some(vec![(0., 1000.)]), false),
+}
+
+fn can_serve_job_without_time_windows_impl(times: Option<Vec<(f64, f64)>>, expected_served: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        times: times.map(|times| {
+                            times.into_iter().map(|(start, end)| vec![format_time(start), format_time(end)]).collect()
+                        }),
+                        ..create_job_place(vec![1500., 0.])
+                    }],
+                    demand: Some(vec![1]),
+                    tag: None,
+                    type_durations: None,
+                    variants: None,
+                }]),
+                ..create_job("job1")
+            }],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(5000.),
+                        location: vec![0., 0.].to_loc(),
+                        max_overtime: None,
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if expected_served {
+        assert!(solution.unassigned.is_none());
+        let stop = &solution.tours.first().expect("no tour").stops[1];
+        assert_eq!(stop.time.arrival, format_time(1500.));
+    } else {
+        assert!(solution.tours.is_empty());
+        assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+    }
+}
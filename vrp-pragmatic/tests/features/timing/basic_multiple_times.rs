@@ -30,6 +30,7 @@ fn can_use_multiple_times() {
                 distance: 100,
                 duration: 130,
                 times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0 },
+                tightness: Some(0),
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -98,7 +99,10 @@ fn can_use_multiple_times() {
                     distance: 100,
                     duration: 130,
                     times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0 },
+                    tightness: Some(0),
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
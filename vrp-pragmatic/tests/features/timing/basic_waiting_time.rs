@@ -28,6 +28,7 @@ fn can_wait_for_job_start() {
                 distance: 4,
                 duration: 12,
                 times: Timing { driving: 4, serving: 0, waiting: 8, break_time: 0 },
+                tightness: Some(0),
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -72,7 +73,10 @@ fn can_wait_for_job_start() {
                     distance: 4,
                     duration: 12,
                     times: Timing { driving: 4, serving: 0, waiting: 8, break_time: 0 },
+                    tightness: Some(0),
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -101,6 +105,7 @@ fn can_skip_initial_waiting() {
                 distance: 2,
                 duration: 12,
                 times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                tightness: Some(10),
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -137,7 +142,10 @@ fn can_skip_initial_waiting() {
                     distance: 2,
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                    tightness: Some(10),
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -158,6 +166,8 @@ fn can_consider_latest_departure_time() {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: Some("1970-01-01T00:00:05Z".to_string()),
                         location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                        alternatives: None,
+                        dock_slot: None,
                     },
                     ..create_default_vehicle_shift()
                 }],
@@ -179,6 +189,7 @@ fn can_consider_latest_departure_time() {
                 distance: 2,
                 duration: 16,
                 times: Timing { driving: 2, serving: 10, waiting: 4, break_time: 0 },
+                tightness: Some(10),
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -215,7 +226,10 @@ fn can_consider_latest_departure_time() {
                     distance: 2,
                     duration: 16,
                     times: Timing { driving: 2, serving: 10, waiting: 4, break_time: 0 },
+                    tightness: Some(10),
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
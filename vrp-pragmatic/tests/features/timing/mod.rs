@@ -1,4 +1,11 @@
+mod any_time_jobs;
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod ranked_time_windows;
+mod relative_time_windows;
+mod schedule_fixed_tours;
+mod soft_time_windows;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
+mod time_units;
+mod time_window_tightness;
@@ -0,0 +1,35 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_preferred_time_window_unless_penalty_is_outweighed, (penalty, expected_waiting), {
+    can_use_preferred_time_window_unless_penalty_is_outweighed_impl(penalty, expected_waiting);
+}}
+
+can_use_preferred_time_window_unless_penalty_is_outweighed! {
+    case01: (1000., 189),
+    case02: (10., 0),
+}
+
+fn can_use_preferred_time_window_unless_penalty_is_outweighed_impl(penalty: f64, expected_waiting: i64) {
+    let mut job2 = create_delivery_job_with_times("job2", vec![10., 0.], vec![(0, 50), (200, 250)], 1.);
+    job2.deliveries.iter_mut().flat_map(|tasks| tasks.iter_mut()).flat_map(|task| task.places.iter_mut()).for_each(
+        |place| {
+            place.time_penalties = Some(vec![penalty, 0.]);
+        },
+    );
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 10)], 1.), job2],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.times.waiting, expected_waiting);
+}
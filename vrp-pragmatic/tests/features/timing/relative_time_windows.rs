@@ -0,0 +1,99 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use crate::parse_time;
+
+parameterized_test! {can_resolve_relative_time_windows_per_vehicle_start, (shift_start, relative_times, expected), {
+    can_resolve_relative_time_windows_per_vehicle_start_impl(shift_start, relative_times, expected);
+}}
+
+can_resolve_relative_time_windows_per_vehicle_start! {
+    case01: (0., (20., 30.), 21.),
+    case02: (100., (20., 30.), 121.),
+    case03: (100., (0., 10.), 106.),
+}
+
+fn can_resolve_relative_time_windows_per_vehicle_start_impl(
+    shift_start: f64,
+    relative_times: (f64, f64),
+    expected: f64,
+) {
+    let job = create_delivery_job("job1", vec![5., 0.]);
+    let job = Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                relative_times: Some(vec![vec![relative_times.0, relative_times.1]]),
+                opening_times: None,
+                ..create_job_place(vec![5., 0.])
+            }],
+            ..job.deliveries.unwrap().first().unwrap().clone()
+        }]),
+        ..job
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(shift_start),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_stop = solution.tours[0].stops.iter().find(|stop| stop.activities[0].job_id == "job1").unwrap();
+    assert_eq!(parse_time(&job_stop.time.departure), expected);
+}
+
+#[test]
+fn can_resolve_relative_time_windows_from_shift_start_when_departure_is_rescheduled() {
+    let job1 = create_delivery_job_with_times("job1", vec![10., 0.], vec![(100, 200)], 1.);
+    let job2 = create_delivery_job("job2", vec![5., 0.]);
+    let job2 = Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                relative_times: Some(vec![vec![20., 30.]]),
+                opening_times: None,
+                ..create_job_place(vec![5., 0.])
+            }],
+            ..job2.deliveries.unwrap().first().unwrap().clone()
+        }]),
+        ..job2
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job1, job2], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let stops = &solution.tours[0].stops;
+    assert!(parse_time(&stops[0].time.departure) > parse_time(&stops[0].time.arrival));
+    let job_stop = stops.iter().find(|stop| stop.activities[0].job_id == "job2").unwrap();
+    assert_eq!(parse_time(&job_stop.time.arrival), 20.);
+}
@@ -0,0 +1,178 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+const ZERO_TIME: (&str, &str) = ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z");
+
+fn create_fixed_solution() -> Solution {
+    Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity("departure", "departure", (0., 0.), 2, ZERO_TIME, 0),
+                create_stop_with_activity("job1", "delivery", (5., 0.), 1, ZERO_TIME, 0),
+                create_stop_with_activity("job2", "delivery", (3., 0.), 0, ZERO_TIME, 0),
+                create_stop_with_activity("arrival", "arrival", (0., 0.), 0, ZERO_TIME, 0),
+            ],
+            statistic: Statistic::default(),
+            consumption: None,
+            day: None,
+        }],
+        ..create_empty_solution()
+    }
+}
+
+parameterized_test! {can_schedule_fixed_tour, (job2_tw_end, expected_violations), {
+    can_schedule_fixed_tour_impl(job2_tw_end, expected_violations);
+}}
+
+can_schedule_fixed_tour! {
+    case01: (100, None),
+    case02: (6, Some(vec![Violation::TimeWindow {
+        vehicle_id: "my_vehicle_1".to_string(),
+        shift_index: 0,
+        job_id: "job2".to_string(),
+        reason: "arrival at '1970-01-01T00:00:09Z' is later than time window end at '1970-01-01T00:00:06Z'"
+            .to_string(),
+    }])),
+}
+
+fn can_schedule_fixed_tour_impl(job2_tw_end: i32, expected_violations: Option<Vec<Violation>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![5., 0.], vec![(0, 100)], 2.),
+                create_delivery_job_with_times("job2", vec![3., 0.], vec![(0, job2_tw_end)], 1.),
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let perform_check = expected_violations.is_none();
+
+    let solution = schedule_init_solution(problem, Some(vec![matrix]), &create_fixed_solution(), perform_check);
+
+    // NOTE the order is not optimal: the vehicle drives 5 units to job1, then back 2 units to job2
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 33.,
+                distance: 10,
+                duration: 13,
+                times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
+                tightness: Some(job2_tw_end as i64 - 9),
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        2,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (5., 0.),
+                        1,
+                        ("1970-01-01T00:00:05Z", "1970-01-01T00:00:07Z"),
+                        5
+                    ),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (3., 0.),
+                        0,
+                        ("1970-01-01T00:00:09Z", "1970-01-01T00:00:10Z"),
+                        7
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:13Z", "1970-01-01T00:00:13Z"),
+                        10
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 33.,
+                    distance: 10,
+                    duration: 13,
+                    times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
+                    tightness: Some(job2_tw_end as i64 - 9),
+                },
+                consumption: None,
+                day: None,
+            }],
+            violations: expected_violations,
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_keep_break_which_is_not_on_time_in_fixed_tour() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![5., 0.], vec![(0, 100)], 2.),
+                create_delivery_job_with_times("job2", vec![3., 0.], vec![(0, 100)], 1.),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak {
+                        time: VehicleBreakTime::TimeWindow(vec![format_time(0.), format_time(2.)]),
+                        duration: 2.,
+                        locations: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let mut init_solution = create_fixed_solution();
+    init_solution.tours[0].stops[1].activities.push(Activity {
+        job_id: "break".to_string(),
+        activity_type: "break".to_string(),
+        location: Some(vec![5., 0.].to_loc()),
+        time: Some(Interval { start: ZERO_TIME.0.to_string(), end: ZERO_TIME.1.to_string() }),
+        job_tag: None,
+        distance: None,
+        duration: None,
+    });
+
+    let solution = schedule_init_solution(problem, Some(vec![matrix]), &init_solution, false);
+
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["job1", "break"], vec!["job2"], vec!["arrival"]]
+    );
+    assert_eq!(
+        solution.violations,
+        Some(vec![Violation::Break {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            reason: "arrival at '1970-01-01T00:00:07Z' is later than time window end at '1970-01-01T00:00:02Z'"
+                .to_string(),
+        }])
+    );
+}
@@ -0,0 +1,131 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+parameterized_test! {can_mix_soft_and_hard_time_windows, (config, job_hardness, vip_hardness), {
+    can_mix_soft_and_hard_time_windows_impl(config, job_hardness, vip_hardness);
+}}
+
+can_mix_soft_and_hard_time_windows! {
+    case01: (Some(TimeWindowsConfig { soft: true, lateness_penalty: Some(10.), unit: None }), None, Some(true)),
+    case02: (None, Some(false), None),
+}
+
+fn can_mix_soft_and_hard_time_windows_impl(
+    config: Option<TimeWindowsConfig>,
+    job_hardness: Option<bool>,
+    vip_hardness: Option<bool>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    hard_time_window: job_hardness,
+                    ..create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 5)], 1.)
+                },
+                Job {
+                    hard_time_window: vip_hardness,
+                    ..create_delivery_job_with_times("vip", vec![20., 0.], vec![(0, 5)], 1.)
+                },
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        config: Some(Config { time_windows: config, ..Default::default() }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let job_stop = solution.tours[0].stops.iter().find(|stop| stop.activities[0].job_id == "job1").unwrap();
+    assert!(parse_time(&job_stop.time.arrival) > 5.);
+    let unassigned = solution.unassigned.expect("vip job should be unassigned");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "vip");
+    assert_eq!(unassigned[0].reasons[0].code, 2);
+}
+
+parameterized_test! {can_shed_jobs_when_lateness_exceeds_penalty_limit, (penalty_limit, expected_unassigned), {
+    can_shed_jobs_when_lateness_exceeds_penalty_limit_impl(penalty_limit, expected_unassigned);
+}}
+
+can_shed_jobs_when_lateness_exceeds_penalty_limit! {
+    case01: (None, vec![]),
+    case02: (Some(1000.), vec![]),
+    case03: (Some(100.), vec!["job2"]),
+    case04: (Some(0.), vec!["job1", "job2"]),
+}
+
+fn can_shed_jobs_when_lateness_exceeds_penalty_limit_impl(penalty_limit: Option<f64>, expected_unassigned: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 5)], 1.),
+                create_delivery_job_with_times("job2", vec![20., 0.], vec![(0, 5)], 1.),
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        config: Some(Config {
+            time_windows: Some(TimeWindowsConfig { soft: true, lateness_penalty: Some(10.), unit: None }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution =
+        solve_with_builder(problem, Some(vec![matrix]), 200, true, |builder| builder.with_penalty_limit(penalty_limit));
+
+    let unassigned = solution.unassigned.unwrap_or_default();
+    assert_eq!(unassigned.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), expected_unassigned);
+    assert!(unassigned.iter().all(|job| job.reasons[0].code == 109));
+    let assigned = solution
+        .tours
+        .iter()
+        .flat_map(|tour| get_ids_from_tour(tour).into_iter().flatten())
+        .filter(|id| id.starts_with("job"))
+        .count();
+    assert_eq!(assigned, 2 - expected_unassigned.len());
+}
+
+parameterized_test! {can_use_grace_period_of_soft_time_windows, (grace, penalty_limit, is_assigned), {
+    can_use_grace_period_of_soft_time_windows_impl(grace, penalty_limit, is_assigned);
+}}
+
+can_use_grace_period_of_soft_time_windows! {
+    case01: (None, 0., false),
+    case02: (Some(5.), 0., true),
+    case03: (Some(10.), 0., true),
+    case04: (Some(3.), 0., false),
+    case05: (Some(3.), 19., false),
+    case06: (Some(3.), 20., true),
+}
+
+fn can_use_grace_period_of_soft_time_windows_impl(grace: Option<f64>, penalty_limit: f64, is_assigned: bool) {
+    let mut job = create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 5)], 1.);
+    job.deliveries.iter_mut().flat_map(|tasks| tasks.iter_mut()).flat_map(|task| task.places.iter_mut()).for_each(
+        |place| {
+            place.grace = grace;
+        },
+    );
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        config: Some(Config {
+            time_windows: Some(TimeWindowsConfig { soft: true, lateness_penalty: Some(10.), unit: None }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_builder(problem, Some(vec![matrix]), 100, true, |builder| {
+        builder.with_penalty_limit(Some(penalty_limit))
+    });
+
+    assert_eq!(solution.unassigned.is_none(), is_assigned);
+    assert_eq!(solution.tours.is_empty(), !is_assigned);
+}
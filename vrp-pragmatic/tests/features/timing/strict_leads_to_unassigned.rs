@@ -30,6 +30,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 distance: 80,
                 duration: 80,
                 times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0 },
+                tightness: Some(0),
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -90,7 +91,10 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                     distance: 80,
                     duration: 80,
                     times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0 },
+                    tightness: Some(0),
                 },
+                consumption: None,
+                day: None,
             }],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job5".to_string(),
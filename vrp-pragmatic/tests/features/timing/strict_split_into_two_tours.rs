@@ -36,6 +36,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 distance: 140,
                 duration: 200,
                 times: Timing { driving: 140, serving: 50, waiting: 10, break_time: 0 },
+                tightness: Some(0),
             },
             tours: vec![
                 Tour {
@@ -89,7 +90,10 @@ fn can_split_into_two_tours_because_of_strict_times() {
                         distance: 100,
                         duration: 140,
                         times: Timing { driving: 100, serving: 30, waiting: 10, break_time: 0 },
+                        tightness: Some(0),
                     },
+                    consumption: None,
+                    day: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -134,7 +138,10 @@ fn can_split_into_two_tours_because_of_strict_times() {
                         distance: 40,
                         duration: 60,
                         times: Timing { driving: 40, serving: 20, waiting: 0, break_time: 0 },
+                        tightness: Some(10),
                     },
+                    consumption: None,
+                    day: None,
                 },
             ],
             ..create_empty_solution()
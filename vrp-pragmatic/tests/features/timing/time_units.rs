@@ -0,0 +1,52 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+parameterized_test! {can_convert_time_units, (matrix_unit, tw_unit, relative_times, expected), {
+    can_convert_time_units_impl(matrix_unit, tw_unit, relative_times, expected);
+}}
+
+can_convert_time_units! {
+    case01: (TimeUnit::Seconds, TimeUnit::Minutes, (1., 2.), 61.),
+    case02: (TimeUnit::Minutes, TimeUnit::Minutes, (0., 10.), 301.),
+    case03: (TimeUnit::Minutes, TimeUnit::Seconds, (0., 600.), 301.),
+    case04: (TimeUnit::Hours, TimeUnit::Hours, (0., 10.), 18001.),
+}
+
+fn can_convert_time_units_impl(matrix_unit: TimeUnit, tw_unit: TimeUnit, relative_times: (f64, f64), expected: f64) {
+    let job = create_delivery_job("job1", vec![5., 0.]);
+    let job = Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                relative_times: Some(vec![vec![relative_times.0, relative_times.1]]),
+                opening_times: None,
+                ..create_job_place(vec![5., 0.])
+            }],
+            ..job.deliveries.unwrap().first().unwrap().clone()
+        }]),
+        ..job
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config {
+            time_windows: Some(TimeWindowsConfig { soft: false, lateness_penalty: None, unit: Some(tw_unit) }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = Matrix { time_unit: Some(matrix_unit), ..create_matrix_from_problem(&problem) };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_stop = solution.tours[0].stops.iter().find(|stop| stop.activities[0].job_id == "job1").unwrap();
+    assert_eq!(parse_time(&job_stop.time.departure), expected);
+}
@@ -0,0 +1,45 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_report_time_window_tightness, (times, expected_tightness), {
+    can_report_time_window_tightness_impl(times, expected_tightness);
+}}
+
+can_report_time_window_tightness! {
+    case01_back_to_back_tight: (Some(vec![(1, 1), (2, 2), (3, 4)]), Some((0, 0))),
+    case02_one_tight: (Some(vec![(0, 100), (2, 2), (0, 100)]), Some((0, 0))),
+    case03_loose: (Some(vec![(0, 100), (0, 100), (0, 100)]), Some((95, 97))),
+    case04_no_time_windows: (None, None),
+}
+
+fn can_report_time_window_tightness_impl(times: Option<Vec<(i32, i32)>>, expected_tightness: Option<(i64, i64)>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=3)
+                .map(|idx| {
+                    let id = format!("job{}", idx);
+                    let location = vec![idx as f64, 0.];
+                    match times.as_ref() {
+                        Some(times) => create_delivery_job_with_times(&id, location, vec![times[idx - 1]], 0.),
+                        None => create_delivery_job(&id, location),
+                    }
+                })
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.tightness, solution.tours[0].statistic.tightness);
+    // NOTE with loose time windows, the minimum slack depends on the order of jobs in the tour
+    match (solution.statistic.tightness, expected_tightness) {
+        (Some(tightness), Some((min, max))) => assert!(tightness >= min && tightness <= max),
+        (tightness, expected) => assert_eq!(tightness, expected.map(|(min, _)| min)),
+    }
+}
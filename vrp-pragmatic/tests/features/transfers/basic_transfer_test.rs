@@ -0,0 +1,97 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_problem(leg2_pickup_times: Vec<(i32, i32)>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job_with_params(
+                    "leg1",
+                    vec![1],
+                    (vec![1., 0.], 1., vec![]),
+                    (vec![10., 0.], 1., vec![]),
+                ),
+                Job {
+                    transfer_from: Some("leg1".to_string()),
+                    ..create_pickup_delivery_job_with_params(
+                        "leg2",
+                        vec![1],
+                        (vec![10., 0.], 1., leg2_pickup_times),
+                        (vec![20., 0.], 1., vec![]),
+                    )
+                },
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_vehicle_with_capacity("my_vehicle", vec![2])
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs],
+            secondary: Some(vec![MinimizeTours, MinimizeCost]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    }
+}
+
+/// Returns tour index and activity time of the job activity with the given type.
+fn get_activity_time(solution: &Solution, job_id: &str, activity_type: &str) -> (usize, f64, f64) {
+    solution
+        .tours
+        .iter()
+        .enumerate()
+        .flat_map(|(tour_idx, tour)| tour.stops.iter().map(move |stop| (tour_idx, stop)))
+        .flat_map(|(tour_idx, stop)| stop.activities.iter().map(move |activity| (tour_idx, stop, activity)))
+        .find(|(_, _, activity)| activity.job_id == job_id && activity.activity_type == activity_type)
+        .map(|(tour_idx, stop, activity)| {
+            let time = activity
+                .time
+                .clone()
+                .unwrap_or_else(|| Interval { start: stop.time.arrival.clone(), end: stop.time.departure.clone() });
+            (tour_idx, parse_time(&time.start), parse_time(&time.end))
+        })
+        .expect("cannot find activity")
+}
+
+#[test]
+fn can_schedule_transfer_pickup_after_drop_of_other_vehicle() {
+    let problem = create_problem(vec![]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let (drop_tour, _, drop_departure) = get_activity_time(&solution, "leg1", "delivery");
+    let (pickup_tour, _, pickup_departure) = get_activity_time(&solution, "leg2", "pickup");
+    assert_ne!(drop_tour, pickup_tour);
+    assert_eq!(drop_departure, 12.);
+    // NOTE pickup duration is 1, so the pickup starts right after the drop
+    assert_eq!(pickup_departure - 1., drop_departure);
+}
+
+#[test]
+fn can_skip_transfer_job_when_drop_is_too_late() {
+    let problem = create_problem(vec![(10, 11)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "leg2");
+    assert_eq!(unassigned[0].reasons[0].code, 114);
+    assert_eq!(
+        unassigned[0].reasons[0].description,
+        "cannot be assigned in sync with transfer job served by other tour"
+    );
+}
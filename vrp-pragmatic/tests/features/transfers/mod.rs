@@ -0,0 +1 @@
+mod basic_transfer_test;
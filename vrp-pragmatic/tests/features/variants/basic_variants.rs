@@ -0,0 +1,94 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_delivery_job_with_variants(id: &str, location: Vec<f64>, variants: Vec<(&str, i32, f64)>) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            variants: Some(
+                variants
+                    .into_iter()
+                    .map(|(tag, demand, duration)| JobTaskVariant {
+                        demand: Some(vec![demand]),
+                        duration: Some(duration),
+                        tag: tag.to_string(),
+                    })
+                    .collect(),
+            ),
+            ..create_task(location)
+        }]),
+        ..create_job(id)
+    }
+}
+
+fn get_job_tags(solution: &Solution, job_id: &str) -> Vec<Option<String>> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.job_id == job_id)
+        .map(|activity| activity.job_tag.clone())
+        .collect()
+}
+
+parameterized_test! {can_select_job_variant, (capacity, expected_tag), {
+    can_select_job_variant_impl(capacity, expected_tag);
+}}
+
+can_select_job_variant! {
+    case01_capacity_is_binding: (10, "half"),
+    case02_capacity_is_not_binding: (20, "full"),
+}
+
+fn can_select_job_variant_impl(capacity: i32, expected_tag: &str) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", vec![1., 0.], vec![6]),
+                create_delivery_job_with_variants("job2", vec![2., 0.], vec![("full", 8, 1.), ("half", 4, 10.)]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![capacity])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_job_tags(&solution, "job1"), vec![None]);
+    assert_eq!(get_job_tags(&solution, "job2"), vec![Some(expected_tag.to_string())]);
+}
+
+#[test]
+fn can_report_job_once_when_no_variant_fits() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", vec![1., 0.], vec![1]),
+                create_delivery_job_with_variants("job2", vec![2., 0.], vec![("full", 8, 1.), ("half", 4, 10.)]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.clone().expect("unassigned job expected");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned.first().unwrap().job_id, "job2");
+    assert_eq!(unassigned.first().unwrap().reasons.first().unwrap().code, 3);
+    assert!(get_job_tags(&solution, "job2").is_empty());
+}
@@ -54,6 +54,7 @@ fn can_balance_activities_with_threshold_impl(threshold: Option<f64>, expected_l
         objectives: Some(Objectives {
             primary: vec![BalanceActivities { options: Some(BalanceOptions { threshold, tolerance: None }) }],
             secondary: Some(vec![MinimizeCost]),
+            tie_break: None,
         }),
         ..create_empty_problem()
     };
@@ -28,6 +28,7 @@ fn can_balance_max_load() {
         objectives: Some(Objectives {
             primary: vec![BalanceMaxLoad { options: None }],
             secondary: Some(vec![MinimizeCost]),
+            tie_break: None,
         }),
         ..create_empty_problem()
     };
@@ -0,0 +1,36 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_balance_stops_when_distance_is_not_affected() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (0..6).map(|idx| create_delivery_job(format!("job{}", idx).as_str(), vec![1., 0.])).collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![5],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs, MinimizeTours],
+            secondary: Some(vec![BalanceStops, MinimizeCost]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(solution.statistic.distance, 4);
+    solution.tours.iter().for_each(|tour| {
+        assert_eq!(get_ids_from_tour(tour).iter().flatten().filter(|id| id.starts_with("job")).count(), 3);
+    });
+}
@@ -0,0 +1,70 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::Tour;
+use crate::helpers::*;
+
+fn get_activities_count(tour: &Tour) -> usize {
+    tour.stops
+        .iter()
+        .map(|stop| stop.activities.iter().filter(|activity| activity.activity_type == "delivery").count())
+        .sum()
+}
+
+parameterized_test! {can_balance_activities_with_job_workload, (heavy_workload, expected_counts), {
+    can_balance_activities_with_job_workload_impl(heavy_workload, expected_counts);
+}}
+
+can_balance_activities_with_job_workload! {
+    case01_weighted: (Some(3.), vec![1, 3]),
+    case02_counted: (None, vec![2, 2]),
+}
+
+fn can_balance_activities_with_job_workload_impl(heavy_workload: Option<f64>, expected_counts: Vec<usize>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { workload: heavy_workload, ..create_delivery_job("heavy", vec![1., 0.]) },
+                create_delivery_job("job1", vec![2., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![2., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    vehicle_ids: vec!["my_vehicle1".to_string()],
+                    shifts: vec![create_default_open_vehicle_shift()],
+                    capacity: vec![4],
+                    ..create_default_vehicle_type()
+                },
+                VehicleType {
+                    type_id: "my_vehicle2".to_string(),
+                    vehicle_ids: vec!["my_vehicle2".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((3., 0.), (3., 0.))],
+                    capacity: vec![4],
+                    ..create_default_vehicle_type()
+                },
+            ],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![BalanceActivities { options: None }],
+            secondary: Some(vec![MinimizeCost]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 2);
+    let mut counts = solution.tours.iter().map(get_activities_count).collect::<Vec<_>>();
+    counts.sort();
+    assert_eq!(counts, expected_counts);
+    if heavy_workload.is_some() {
+        let heavy_tour = solution.tours.iter().find(|tour| get_activities_count(tour) == 1).unwrap();
+        assert!(heavy_tour.stops.iter().flat_map(|stop| stop.activities.iter()).any(|a| a.job_id == "heavy"));
+    }
+}
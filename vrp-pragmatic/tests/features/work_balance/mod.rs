@@ -1,2 +1,4 @@
 mod balance_activities;
 mod balance_max_load;
+mod balance_stops;
+mod balance_workload;
@@ -54,15 +54,24 @@ prop_compose! {
        Job {
             id: Uuid::new_v4().to_string(),
             pickups: Some(vec![
-             JobTask { places: vec![pickup], demand: demand.clone(), tag: Some("p1".to_owned())}
+             JobTask { places: vec![pickup], demand: demand.clone(), tag: Some("p1".to_owned()), type_durations: None, variants: None}
             ]),
             deliveries: Some(vec![
-             JobTask { places: vec![delivery], demand: demand.clone(), tag: Some("d1".to_owned())}
+             JobTask { places: vec![delivery], demand: demand.clone(), tag: Some("d1".to_owned()), type_durations: None, variants: None}
             ]),
             replacements: None,
             services: None,
             priority,
             skills,
+            hard_time_window: None,
+            companions: None,
+            transfer_from: None,
+            service_time_distribution: None,
+            corridor: None,
+            crew_size: None,
+            workload: None,
+            vehicle_affinity: None,
+            age: None,
         }
     }
 }
@@ -102,6 +111,15 @@ prop_compose! {
             services,
             priority,
             skills,
+            hard_time_window: None,
+            companions: None,
+            transfer_from: None,
+            service_time_distribution: None,
+            corridor: None,
+            crew_size: None,
+            workload: None,
+            vehicle_affinity: None,
+            age: None,
         }
     }
 }
@@ -117,7 +135,7 @@ prop_compose! {
      demand in demand_proto,
      tag in tags
     ) -> JobTask {
-       JobTask { places: vec![place], demand, tag}
+       JobTask { places: vec![place], demand, tag, type_durations: None, variants: None}
     }
 }
 
@@ -132,7 +150,7 @@ prop_compose! {
      duration in durations,
      times in time_windows
     ) -> JobPlace {
-      JobPlace { times, location, duration}
+      JobPlace { times, location, duration, relative_times: None, opening_times: None, grace: None, time_penalties: None, skill_times: None }
     }
 }
 
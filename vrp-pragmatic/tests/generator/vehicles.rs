@@ -33,6 +33,14 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            range: None,
+            aggregate_capacity: None,
+            consumption: None,
+            crew_size: None,
+            loading_discipline: None,
+            reload_handling_time: None,
+            maneuvering_time: None,
+            capacity_decay: None,
         }
     }
 }
@@ -104,7 +112,7 @@ prop_compose! {
           end: places.1,
           depots,
           breaks,
-          reloads
+          reloads, anchors: None
         }
     }
 }
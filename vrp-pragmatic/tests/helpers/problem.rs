@@ -4,11 +4,26 @@ use crate::format_time;
 use crate::helpers::ToLocation;
 
 pub fn create_job_place(location: Vec<f64>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1. }
+    JobPlace {
+        times: None,
+        location: location.to_loc(),
+        duration: 1.,
+        relative_times: None,
+        opening_times: None,
+        grace: None,
+        time_penalties: None,
+        skill_times: None,
+    }
 }
 
 pub fn create_task(location: Vec<f64>) -> JobTask {
-    JobTask { places: vec![create_job_place(location)], demand: Some(vec![1]), tag: None }
+    JobTask {
+        places: vec![create_job_place(location)],
+        demand: Some(vec![1]),
+        tag: None,
+        type_durations: None,
+        variants: None,
+    }
 }
 
 pub fn create_job(id: &str) -> Job {
@@ -20,6 +35,15 @@ pub fn create_job(id: &str) -> Job {
         services: None,
         priority: None,
         skills: None,
+        hard_time_window: None,
+        companions: None,
+        transfer_from: None,
+        service_time_distribution: None,
+        corridor: None,
+        crew_size: None,
+        workload: None,
+        vehicle_affinity: None,
+        age: None,
     }
 }
 
@@ -45,6 +69,8 @@ pub fn create_delivery_job_with_duration(id: &str, location: Vec<f64>, duration:
             places: vec![JobPlace { duration, ..create_job_place(location) }],
             demand: Some(vec![1]),
             tag: None,
+            type_durations: None,
+            variants: None,
         }]),
         ..create_job(id)
     }
@@ -56,6 +82,8 @@ pub fn create_delivery_job_with_times(id: &str, location: Vec<f64>, times: Vec<(
             places: vec![JobPlace { duration, times: convert_times(&times), ..create_job_place(location) }],
             demand: Some(vec![1]),
             tag: None,
+            type_durations: None,
+            variants: None,
         }]),
         ..create_job(id)
     }
@@ -100,6 +128,8 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand.clone()),
             tag: Some("p1".to_string()),
+            type_durations: None,
+            variants: None,
         }]),
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace {
@@ -109,6 +139,8 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand.clone()),
             tag: Some("d1".to_string()),
+            type_durations: None,
+            variants: None,
         }]),
 
         ..create_job(id)
@@ -118,9 +150,20 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1. }],
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                relative_times: None,
+                opening_times: None,
+                grace: None,
+                time_penalties: None,
+                skill_times: None,
+            }],
             demand: Some(vec![1]),
             tag: None,
+            type_durations: None,
+            variants: None,
         }]),
         ..create_job(id)
     }
@@ -139,6 +182,8 @@ pub fn create_multi_job(
                 places: vec![JobPlace { duration, ..create_job_place(vec![location.0, location.1]) }],
                 demand: Some(demand),
                 tag: Some(format!("{}{}", prefix, i + 1)),
+                type_durations: None,
+                variants: None,
             })
             .collect::<Vec<_>>();
 
@@ -158,30 +203,52 @@ pub fn create_default_vehicle_shift() -> VehicleShift {
 
 pub fn create_default_open_vehicle_shift() -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+        start: ShiftStart {
+            earliest: format_time(0.),
+            latest: None,
+            location: vec![0., 0.].to_loc(),
+            alternatives: None,
+            dock_slot: None,
+        },
         end: None,
         depots: None,
         breaks: None,
         reloads: None,
+        anchors: None,
     }
 }
 
 pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64, f64)) -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![start.0, start.1].to_loc() },
+        start: ShiftStart {
+            earliest: format_time(0.),
+            latest: None,
+            location: vec![start.0, start.1].to_loc(),
+            alternatives: None,
+            dock_slot: None,
+        },
         end: Some(ShiftEnd {
             earliest: None,
             latest: format_time(1000.).to_string(),
             location: vec![end.0, end.1].to_loc(),
+            max_overtime: None,
         }),
         depots: None,
         breaks: None,
         reloads: None,
+        anchors: None,
     }
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts {
+        fixed: Some(10.),
+        distance: 1.,
+        time: 1.,
+        distance_tiers: None,
+        max_daily_cost: None,
+        load_distance: None,
+    }
 }
 
 pub fn create_default_vehicle_type() -> VehicleType {
@@ -202,6 +269,14 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        range: None,
+        aggregate_capacity: None,
+        consumption: None,
+        crew_size: None,
+        loading_discipline: None,
+        reload_handling_time: None,
+        maneuvering_time: None,
+        capacity_decay: None,
     }
 }
 
@@ -229,6 +304,10 @@ pub fn create_matrix(data: Vec<i64>) -> Matrix {
         travel_times: data.clone(),
         distances: data.clone(),
         error_codes: None,
+        default_travel_time: None,
+        default_distance: None,
+        time_unit: None,
+        unreachable_value: None,
     }
 }
 
@@ -57,6 +57,8 @@ fn create_stop_with_activity_impl(
             location: None,
             time: None,
             job_tag,
+            distance: None,
+            duration: None,
         }],
     }
 }
@@ -89,6 +91,8 @@ pub fn create_empty_tour() -> Tour {
         shift_index: 0,
         stops: vec![],
         statistic: Default::default(),
+        consumption: None,
+        day: None,
     }
 }
 
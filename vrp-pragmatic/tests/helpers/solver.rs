@@ -1,7 +1,8 @@
 use crate::checker::CheckerContext;
 use crate::format::problem::{Matrix, PragmaticProblem, Problem};
-use crate::format::solution::{create_solution, Solution};
+use crate::format::solution::{create_solution, read_init_solution, serialize_solution, Extras, Solution};
 use std::cmp::Ordering::Less;
+use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::Problem as CoreProblem;
@@ -9,7 +10,7 @@ use vrp_core::models::Solution as CoreSolution;
 use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
 use vrp_core::solver::selection::NaiveSelection;
 use vrp_core::solver::RefinementContext;
-use vrp_core::solver::{Builder, DominancePopulation};
+use vrp_core::solver::{create_scheduled_solution, Builder, DominancePopulation};
 use vrp_core::utils::DefaultRandom;
 
 /// Runs solver with cheapest insertion heuristic.
@@ -50,10 +51,118 @@ pub fn solve_with_metaheuristic_and_iterations_without_check(
 }
 
 pub fn solve(problem: Problem, matrices: Option<Vec<Matrix>>, generations: usize, perform_check: bool) -> Solution {
+    solve_with_builder(problem, matrices, generations, perform_check, |builder| builder)
+}
+
+/// Runs solver with default metaheuristic, specified amount of generations and builder adjusted
+/// by the given function.
+pub fn solve_with_builder<F: Fn(Builder) -> Builder>(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    generations: usize,
+    perform_check: bool,
+    builder_func: F,
+) -> Solution {
+    get_core_solution(problem, matrices, perform_check, |problem: Arc<CoreProblem>| {
+        let builder = Builder::new(problem)
+            .with_max_generations(Some(generations))
+            .with_selection(Arc::new(NaiveSelection::new(2)));
+
+        let (solution, _, _) = builder_func(builder)
+            .build()
+            .unwrap_or_else(|err| panic!("cannot build solver: {}", err))
+            .solve()
+            .unwrap_or_else(|err| panic!("cannot solve the problem: {}", err));
+
+        solution
+    })
+}
+
+/// Runs solver with default metaheuristic and returns up to `k` solutions which differ at least by
+/// `min_distance` job assignments. Each solution is checked for feasibility.
+pub fn solve_k_diverse(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    generations: usize,
+    k: usize,
+    min_distance: usize,
+) -> Vec<Solution> {
+    let format_problem = problem.clone();
+    let format_matrices = matrices.clone();
+    let core_problem = get_core_problem(problem, matrices);
+
+    Builder::new(core_problem.clone())
+        .with_max_generations(Some(generations))
+        .with_selection(Arc::new(NaiveSelection::new(2)))
+        .build()
+        .unwrap_or_else(|err| panic!("cannot build solver: {}", err))
+        .solve_k_diverse(k, min_distance)
+        .unwrap_or_else(|err| panic!("cannot solve the problem: {}", err))
+        .into_iter()
+        .map(|(core_solution, _)| {
+            let mut format_solution = sort_all_data(create_solution(&core_problem, &core_solution, None));
+            format_solution.extras = None;
+
+            assert_eq!(
+                CheckerContext::new(
+                    core_problem.clone(),
+                    format_problem.clone(),
+                    format_matrices.clone(),
+                    format_solution.clone()
+                )
+                .check()
+                .err(),
+                None
+            );
+
+            format_solution
+        })
+        .collect()
+}
+
+/// Schedules given solution without any optimization: tours and order of activities are kept as is.
+pub fn schedule_init_solution(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    init_solution: &Solution,
+    perform_check: bool,
+) -> Solution {
     get_core_solution(problem, matrices, perform_check, |problem: Arc<CoreProblem>| {
-        let (solution, _, _) = Builder::new(problem)
+        let solution = get_core_init_solution(init_solution, problem.clone());
+
+        create_scheduled_solution(problem, solution)
+    })
+}
+
+/// Runs solver with default metaheuristic and specified amount of generations starting from given
+/// initial solution.
+pub fn solve_with_init_solution(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    init_solution: &Solution,
+    generations: usize,
+) -> Solution {
+    solve_with_init_solution_and_builder(problem, matrices, init_solution, generations, |builder| builder)
+}
+
+/// Runs solver with default metaheuristic and specified amount of generations starting from given
+/// initial solution with builder adjusted by the given function.
+pub fn solve_with_init_solution_and_builder<F: Fn(Builder) -> Builder>(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    init_solution: &Solution,
+    generations: usize,
+    builder_func: F,
+) -> Solution {
+    get_core_solution(problem, matrices, true, |problem: Arc<CoreProblem>| {
+        let solution = get_core_init_solution(init_solution, problem.clone());
+
+        let builder = Builder::new(problem)
             .with_max_generations(Some(generations))
             .with_selection(Arc::new(NaiveSelection::new(2)))
+            .with_init_solutions(vec![solution]);
+
+        let (solution, _, _) = builder_func(builder)
             .build()
             .unwrap_or_else(|err| panic!("cannot build solver: {}", err))
             .solve()
@@ -63,6 +172,15 @@ pub fn solve(problem: Problem, matrices: Option<Vec<Matrix>>, generations: usize
     })
 }
 
+fn get_core_init_solution(init_solution: &Solution, problem: Arc<CoreProblem>) -> CoreSolution {
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    serialize_solution(writer, init_solution).expect("cannot serialize init solution");
+
+    read_init_solution(BufReader::new(buffer.as_bytes()), problem)
+        .unwrap_or_else(|err| panic!("cannot read init solution: {}", err))
+}
+
 fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
     Arc::new(
         if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
@@ -84,7 +202,15 @@ fn get_core_solution<F: Fn(Arc<CoreProblem>) -> CoreSolution>(
 
     let core_solution = solve_func(core_problem.clone());
 
-    let format_solution = sort_all_data(create_solution(&core_problem, &core_solution, None));
+    let mut format_solution = sort_all_data(create_solution(&core_problem, &core_solution, None));
+    // NOTE termination report is not deterministic, so only monetary cost breakdown is kept
+    format_solution.extras = format_solution.extras.and_then(|extras| extras.monetary).map(|monetary| Extras {
+        metrics: None,
+        config: None,
+        termination: None,
+        monetary: Some(monetary),
+        bbox: None,
+    });
 
     if perform_check {
         assert_eq!(
@@ -0,0 +1,78 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+fn create_anchor_stop() -> Stop {
+    create_stop_with_activity("anchor", "anchor", (2., 0.), 1, ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"), 2)
+}
+
+fn create_job_stop() -> Stop {
+    create_stop_with_activity("job1", "delivery", (1., 0.), 1, ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"), 1)
+}
+
+fn test_violations() -> Option<Vec<Violation>> {
+    Some(vec![Violation::Anchor {
+        vehicle_id: "my_vehicle_1".to_string(),
+        shift_index: 0,
+        reason: "some reason".to_string(),
+    }])
+}
+
+fn get_error_msg(prefix: &str) -> Result<(), String> {
+    Err(format!("{} for vehicle 'my_vehicle_1', shift index '0'", prefix))
+}
+
+parameterized_test! {can_check_anchors, (stops, violations, expected_result), {
+    can_check_anchors_impl(stops, violations, expected_result);
+}}
+
+can_check_anchors! {
+    case01: (vec![create_job_stop(), create_anchor_stop()], None, Ok(())),
+    case02: (vec![create_job_stop()], None, get_error_msg("Tour has no anchor visit")),
+    case03: (vec![create_job_stop()], test_violations(), Ok(())),
+    case04: (vec![create_anchor_stop()], None, get_error_msg("Tour without jobs has anchor visit")),
+    case05: (
+        vec![create_anchor_stop(), create_job_stop(), create_anchor_stop()],
+        None,
+        get_error_msg("Tour has '2' anchor visits, expected one")
+    ),
+}
+
+fn can_check_anchors_impl(stops: Vec<Stop>, violations: Option<Vec<Violation>>, expected_result: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    anchors: Some(vec![VehicleCargoPlace {
+                        location: vec![2., 0.].to_loc(),
+                        duration: 1.,
+                        times: None,
+                        tag: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops,
+            statistic: Statistic::default(),
+            consumption: None,
+            day: None,
+        }],
+        violations,
+        ..create_empty_solution()
+    };
+
+    let result = check_anchors(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected_result);
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 use vrp_core::models::examples::create_example_problem;
 
@@ -34,6 +35,8 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 shift_index,
                 stops: vec![],
                 statistic: Statistic::default(),
+                consumption: None,
+                day: None,
             })
             .collect(),
         ..create_empty_solution()
@@ -128,6 +131,8 @@ fn check_jobs_impl(
                 places: vec![],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 tag: Some(format!("{}{}", tgt, idx)),
+                type_durations: None,
+                variants: None,
             })
             .collect()
     };
@@ -146,6 +151,15 @@ fn check_jobs_impl(
                     services: Some(create_tasks("service", &tasks)),
                     priority: None,
                     skills: None,
+                    hard_time_window: None,
+                    companions: None,
+                    transfer_from: None,
+                    service_time_distribution: None,
+                    corridor: None,
+                    crew_size: None,
+                    workload: None,
+                    vehicle_affinity: None,
+                    age: None,
                 })
                 .collect(),
             relations: None,
@@ -163,6 +177,8 @@ fn check_jobs_impl(
                 shift_index,
                 stops: stops.into_iter().map(create_stop).collect(),
                 statistic: Statistic::default(),
+                consumption: None,
+                day: None,
             })
             .collect(),
         unassigned: Some(
@@ -192,6 +208,7 @@ fn can_detect_time_window_violation() {
             distance: 2,
             duration: 3,
             times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+            tightness: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -228,7 +245,10 @@ fn can_detect_time_window_violation() {
                 distance: 2,
                 duration: 3,
                 times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+                tightness: None,
             },
+            consumption: None,
+            day: None,
         }],
         ..create_empty_solution()
     };
@@ -238,3 +258,470 @@ fn can_detect_time_window_violation() {
 
     assert_eq!(result, Err("cannot match activities to jobs: job1:<no tag>".to_owned()));
 }
+
+parameterized_test! {can_detect_jobs_without_companions, (tours, expected_result), {
+    can_detect_jobs_without_companions_impl(tours, expected_result);
+}}
+
+can_detect_jobs_without_companions! {
+    case_01: (vec![("v1", vec!["job1", "job2"])], Ok(())),
+    case_02: (vec![("v1", vec!["job1"]), ("v2", vec!["job2"])], Err(())),
+    case_03: (vec![("v1", vec!["job2", "job3"])], Err(())),
+    case_04: (vec![("v1", vec!["job1", "job3"])], Ok(())),
+}
+
+fn can_detect_jobs_without_companions_impl(tours: Vec<(&str, Vec<&str>)>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                Job { companions: Some(vec!["job1".to_string()]), ..create_delivery_job("job2", vec![2., 0.]) },
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: tours
+            .into_iter()
+            .map(|(id, jobs)| Tour {
+                vehicle_id: id.to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: jobs
+                    .into_iter()
+                    .map(|job_id| create_stop_with_activity(job_id, "delivery", (0., 0.), 0, ("", ""), 0))
+                    .collect(),
+                statistic: Statistic::default(),
+                consumption: None,
+                day: None,
+            })
+            .collect(),
+        ..create_empty_solution()
+    };
+
+    let result = check_jobs_companions(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {can_detect_invalid_transfers, (tours, expected_result), {
+    can_detect_invalid_transfers_impl(tours, expected_result);
+}}
+
+can_detect_invalid_transfers! {
+    case_01: (vec![("v1", vec![("job1", "00:00:02Z")]), ("v2", vec![("job2", "00:00:03Z")])], Ok(())),
+    case_02: (vec![("v1", vec![("job1", "00:00:03Z")]), ("v2", vec![("job2", "00:00:02Z")])], Err(())),
+    case_03: (vec![("v1", vec![("job1", "00:00:02Z"), ("job2", "00:00:03Z")])], Err(())),
+    case_04: (vec![("v2", vec![("job2", "00:00:03Z")])], Err(())),
+    case_05: (vec![("v1", vec![("job1", "00:00:02Z")])], Ok(())),
+}
+
+fn can_detect_invalid_transfers_impl(tours: Vec<(&str, Vec<(&str, &str)>)>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                Job { transfer_from: Some("job1".to_string()), ..create_pickup_job("job2", vec![1., 0.]) },
+            ],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: tours
+            .into_iter()
+            .map(|(id, jobs)| Tour {
+                vehicle_id: id.to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: jobs
+                    .into_iter()
+                    .map(|(job_id, departure)| {
+                        let activity_type = if job_id == "job1" { "delivery" } else { "pickup" };
+                        let departure = format!("1970-01-01T{}", departure);
+                        create_stop_with_activity(
+                            job_id,
+                            activity_type,
+                            (1., 0.),
+                            0,
+                            ("1970-01-01T00:00:01Z", departure.as_str()),
+                            1,
+                        )
+                    })
+                    .collect(),
+                statistic: Statistic::default(),
+                consumption: None,
+                day: None,
+            })
+            .collect(),
+        ..create_empty_solution()
+    };
+
+    let result = check_jobs_transfers(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {can_detect_receiving_rate_violation, (tours, expected_result), {
+    can_detect_receiving_rate_violation_impl(tours, expected_result);
+}}
+
+can_detect_receiving_rate_violation! {
+    case_01: (vec![("v1", vec![("job1", "00:00:01Z")]), ("v2", vec![("job2", "00:00:11Z")])], Ok(())),
+    case_02: (vec![("v1", vec![("job1", "00:00:01Z")]), ("v2", vec![("job2", "00:00:05Z")])], Err(())),
+    case_03: (vec![("v1", vec![("job1", "00:00:01Z"), ("job2", "00:00:05Z")])], Ok(())),
+    case_04: (vec![("v1", vec![("job1", "00:00:01Z")]), ("v2", vec![("job3", "00:00:01Z")])], Ok(())),
+}
+
+fn can_detect_receiving_rate_violation_impl(tours: Vec<(&str, Vec<(&str, &str)>)>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![1., 0.]),
+                create_delivery_job("job3", vec![2., 0.]),
+            ],
+            relations: None,
+        },
+        config: Some(Config {
+            receiving_rates: Some(vec![ReceivingRate { location: vec![1., 0.].to_loc(), interval: 10. }]),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: tours
+            .into_iter()
+            .map(|(id, jobs)| Tour {
+                vehicle_id: id.to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: jobs
+                    .into_iter()
+                    .map(|(job_id, arrival)| {
+                        let location = if job_id == "job3" { (2., 0.) } else { (1., 0.) };
+                        let arrival = format!("1970-01-01T{}", arrival);
+                        create_stop_with_activity(
+                            job_id,
+                            "delivery",
+                            location,
+                            0,
+                            (arrival.as_str(), arrival.as_str()),
+                            1,
+                        )
+                    })
+                    .collect(),
+                statistic: Statistic::default(),
+                consumption: None,
+                day: None,
+            })
+            .collect(),
+        ..create_empty_solution()
+    };
+
+    let result = check_receiving_rates(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {can_detect_jobs_out_of_corridor_order, (jobs, expected_result), {
+    can_detect_jobs_out_of_corridor_order_impl(jobs, expected_result);
+}}
+
+can_detect_jobs_out_of_corridor_order! {
+    case_01: (vec!["job1", "job2", "job3", "job4"], Ok(())),
+    case_02: (vec!["job2", "job1"], Err(())),
+    case_03: (vec!["job1", "job3", "job2", "job4"], Ok(())),
+    case_04: (vec!["job4", "job2"], Ok(())),
+    case_05: (vec!["job3", "job2", "job1"], Err(())),
+}
+
+fn can_detect_jobs_out_of_corridor_order_impl(jobs: Vec<&str>, expected_result: Result<(), ()>) {
+    let create_job = |id: &str, corridor: &str, position: f64| Job {
+        corridor: Some(JobCorridor { id: corridor.to_string(), position }),
+        ..create_delivery_job(id, vec![position, 0.])
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job("job1", "c1", 1.),
+                create_job("job2", "c1", 2.),
+                create_job("job3", "c2", 3.),
+                create_job("job4", "c2", 4.),
+            ],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "v1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            day: None,
+            stops: jobs
+                .into_iter()
+                .map(|job_id| create_stop_with_activity(job_id, "delivery", (0., 0.), 0, ("", ""), 0))
+                .collect(),
+            statistic: Statistic::default(),
+            consumption: None,
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_jobs_corridors(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {can_detect_jobs_with_bigger_crew_size, (vehicle_crew_size, job_crew_size, expected_result), {
+    can_detect_jobs_with_bigger_crew_size_impl(vehicle_crew_size, job_crew_size, expected_result);
+}}
+
+can_detect_jobs_with_bigger_crew_size! {
+    case_01: (None, None, Ok(())),
+    case_02: (None, Some(1), Ok(())),
+    case_03: (None, Some(2), Err(())),
+    case_04: (Some(2), Some(2), Ok(())),
+    case_05: (Some(3), Some(2), Ok(())),
+    case_06: (Some(2), Some(3), Err(())),
+}
+
+fn can_detect_jobs_with_bigger_crew_size_impl(
+    vehicle_crew_size: Option<usize>,
+    job_crew_size: Option<usize>,
+    expected_result: Result<(), ()>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { crew_size: job_crew_size, ..create_delivery_job("job1", vec![1., 0.]) }],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { crew_size: vehicle_crew_size, ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            day: None,
+            stops: vec![create_stop_with_activity("job1", "delivery", (1., 0.), 0, ("", ""), 0)],
+            statistic: Statistic::default(),
+            consumption: None,
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_jobs_crew_sizes(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {can_detect_loading_discipline_violation, (discipline, job_order, expected_result), {
+    can_detect_loading_discipline_violation_impl(discipline, job_order, expected_result);
+}}
+
+can_detect_loading_discipline_violation! {
+    case_01: (None, vec!["job1", "job2", "job1", "job2"], Ok(())),
+    case_02: (Some(VehicleLoadingDiscipline::Lifo), vec!["job1", "job2", "job2", "job1"], Ok(())),
+    case_03: (Some(VehicleLoadingDiscipline::Lifo), vec!["job1", "job2", "job1", "job2"], Err(())),
+    case_04: (Some(VehicleLoadingDiscipline::Fifo), vec!["job1", "job2", "job1", "job2"], Ok(())),
+    case_05: (Some(VehicleLoadingDiscipline::Fifo), vec!["job1", "job2", "job2", "job1"], Err(())),
+    case_06: (Some(VehicleLoadingDiscipline::Lifo), vec!["job1", "job1", "job2", "job2"], Ok(())),
+}
+
+fn can_detect_loading_discipline_violation_impl(
+    discipline: Option<VehicleLoadingDiscipline>,
+    job_order: Vec<&str>,
+    expected_result: Result<(), ()>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", vec![1., 0.], vec![3., 0.]),
+                create_pickup_delivery_job("job2", vec![2., 0.], vec![4., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { loading_discipline: discipline, ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let mut visited = HashSet::new();
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            day: None,
+            stops: job_order
+                .into_iter()
+                .map(|job_id| {
+                    let activity_type = if visited.insert(job_id) { "pickup" } else { "delivery" };
+                    create_stop_with_activity(job_id, activity_type, (1., 0.), 0, ("", ""), 0)
+                })
+                .collect(),
+            statistic: Statistic::default(),
+            consumption: None,
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_loading_disciplines(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {can_detect_departure_outside_dock_slot, (dock_slot, departure, expected_result), {
+    can_detect_departure_outside_dock_slot_impl(dock_slot, departure, expected_result);
+}}
+
+can_detect_departure_outside_dock_slot! {
+    case_01: (None, 5., Ok(())),
+    case_02: (Some((0., 10.)), 5., Ok(())),
+    case_03: (Some((0., 10.)), 10., Ok(())),
+    case_04: (Some((10., 20.)), 5., Err(())),
+    case_05: (Some((0., 4.)), 5., Err(())),
+}
+
+fn can_detect_departure_outside_dock_slot_impl(
+    dock_slot: Option<(f64, f64)>,
+    departure: f64,
+    expected_result: Result<(), ()>,
+) {
+    let mut shift = create_default_vehicle_shift();
+    shift.start.dock_slot = dock_slot.map(|(start, end)| vec![format_time(start), format_time(end)]);
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let (departure, arrival) = (format_time(departure), format_time(departure + 1.));
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            day: None,
+            stops: vec![
+                create_stop_with_activity("departure", "departure", (0., 0.), 1, (&departure, &departure), 0),
+                create_stop_with_activity("job1", "delivery", (1., 0.), 0, (&arrival, &arrival), 1),
+            ],
+            statistic: Statistic::default(),
+            consumption: None,
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_dock_slots(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {can_detect_vehicle_used_on_day_off, (day, expected_result), {
+    can_detect_vehicle_used_on_day_off_impl(day, expected_result);
+}}
+
+can_detect_vehicle_used_on_day_off! {
+    case_01: (0, Ok(())),
+    case_02: (1, Err(())),
+}
+
+fn can_detect_vehicle_used_on_day_off_impl(day: usize, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        config: Some(Config {
+            horizon: Some(PlanningHorizon {
+                days: 2,
+                fleets: None,
+                vehicles: Some(vec![VehicleDays { vehicle_id: "my_vehicle_1".to_string(), days: vec![0] }]),
+            }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: day,
+            day: Some(day),
+            stops: vec![],
+            statistic: Statistic::default(),
+            consumption: None,
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_vehicles(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {can_detect_skill_time_window_violation, (vehicle_skills, departure, expected_result), {
+    can_detect_skill_time_window_violation_impl(vehicle_skills, departure, expected_result);
+}}
+
+can_detect_skill_time_window_violation! {
+    case_01: (Some(vec!["certified"]), 2., Ok(())),
+    case_02: (None, 2., Err(())),
+    case_03: (Some(vec!["other"]), 2., Err(())),
+    case_04: (None, 101., Ok(())),
+}
+
+fn can_detect_skill_time_window_violation_impl(
+    vehicle_skills: Option<Vec<&str>>,
+    departure: f64,
+    expected_result: Result<(), ()>,
+) {
+    let mut job = create_delivery_job_with_times("job1", vec![1., 0.], vec![(100, 200)], 1.);
+    job.deliveries.iter_mut().flat_map(|tasks| tasks.iter_mut()).flat_map(|task| task.places.iter_mut()).for_each(
+        |place| {
+            place.skill_times = Some(vec![SkillTimeWindows {
+                skills: vec!["certified".to_string()],
+                times: vec![vec![format_time(0.), format_time(50.)]],
+            }])
+        },
+    );
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                skills: vehicle_skills.map(|skills| skills.iter().map(|skill| skill.to_string()).collect()),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let arrival = format_time(1.);
+    let departure = format_time(departure);
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            day: None,
+            stops: vec![create_stop_with_activity("job1", "delivery", (1., 0.), 0, (&arrival, &departure), 1)],
+            statistic: Statistic::default(),
+            consumption: None,
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_skill_time_windows(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
@@ -82,15 +82,23 @@ fn can_check_breaks_impl(
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: Some(vec![VehicleBreak { time: break_times, duration: 2.0, locations: None }]),
                     reloads: None,
+                    anchors: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -106,6 +114,8 @@ fn can_check_breaks_impl(
         location: None,
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
+        distance: None,
+        duration: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -114,6 +124,8 @@ fn can_check_breaks_impl(
             location: None,
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
+            distance: None,
+            duration: None,
         });
     }
 
@@ -123,6 +135,7 @@ fn can_check_breaks_impl(
             distance: 4,
             duration: 8,
             times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 2 },
+            tightness: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -169,7 +182,10 @@ fn can_check_breaks_impl(
                 distance: 4,
                 duration: 8,
                 times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 2 },
+                tightness: None,
             },
+            consumption: None,
+            day: None,
         }],
         violations,
         ..create_empty_solution()
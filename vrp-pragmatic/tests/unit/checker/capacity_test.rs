@@ -36,11 +36,18 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: None,
@@ -50,6 +57,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                         duration: 2.0,
                         tag: None,
                     }]),
+                    anchors: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -64,6 +72,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
             distance: 1,
             duration: 2,
             times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+            tightness: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -93,6 +102,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             location: None,
                             time: None,
                             job_tag: None,
+                            distance: None,
+                            duration: None,
                         },
                         Activity {
                             job_id: "job5".to_string(),
@@ -100,6 +111,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             location: None,
                             time: None,
                             job_tag: Some("p1".to_string()),
+                            distance: None,
+                            duration: None,
                         },
                     ],
                 },
@@ -117,6 +130,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                         location: None,
                         time: None,
                         job_tag: None,
+                        distance: None,
+                        duration: None,
                     }],
                 },
                 Stop {
@@ -137,6 +152,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                                 end: "1970-01-01T00:00:09Z".to_string(),
                             }),
                             job_tag: None,
+                            distance: None,
+                            duration: None,
                         },
                         Activity {
                             job_id: "job3".to_string(),
@@ -147,6 +164,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                                 end: "1970-01-01T00:00:10Z".to_string(),
                             }),
                             job_tag: None,
+                            distance: None,
+                            duration: None,
                         },
                     ],
                 },
@@ -181,7 +200,10 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                 distance: 1,
                 duration: 2,
                 times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                tightness: None,
             },
+            consumption: None,
+            day: None,
         }],
         ..create_empty_solution()
     };
@@ -190,3 +212,238 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
 
     assert_eq!(result, expected_result);
 }
+
+parameterized_test! {can_check_depot_inventory, (inventory, expected_result), {
+    can_check_depot_inventory_impl(inventory, expected_result);
+}}
+
+can_check_depot_inventory! {
+    case01: (None, Ok(())),
+    case02: (Some(vec![3]), Ok(())),
+    case03: (Some(vec![2]), Err("Depot inventory violation, expected: not more than [2], got: [3]".to_owned())),
+}
+
+fn can_check_depot_inventory_impl(inventory: Option<Vec<i32>>, expected_result: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                Job {
+                    deliveries: Some(vec![JobTask { demand: Some(vec![2]), ..create_task(vec![2., 0.]) }]),
+                    ..create_job("job2")
+                },
+                create_pickup_job("job3", vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![5],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config { depot_inventory: inventory, ..Default::default() }),
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    3,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    2,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "delivery",
+                    (2., 0.),
+                    0,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                    2,
+                ),
+                create_stop_with_activity(
+                    "job3",
+                    "pickup",
+                    (3., 0.),
+                    1,
+                    ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                    3,
+                ),
+            ],
+            statistic: Statistic {
+                cost: 19.,
+                distance: 3,
+                duration: 6,
+                times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0 },
+                tightness: None,
+            },
+            consumption: None,
+            day: None,
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_depot_inventory(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_aggregate_capacity, (weights, limit, expected_result), {
+    can_check_aggregate_capacity_impl(weights, limit, expected_result);
+}}
+
+can_check_aggregate_capacity! {
+    case01: (vec![1., 1.], 5., Ok(())),
+    case02: (vec![1., 2.], 5., Ok(())),
+    case03: (vec![2., 2.], 5., Err("Aggregate capacity violation in tour 'my_vehicle_1', expected: not more than 5, got: 6".to_owned())),
+}
+
+fn can_check_aggregate_capacity_impl(weights: Vec<f64>, limit: f64, expected_result: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![2, 1])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![5, 5],
+                aggregate_capacity: Some(AggregateCapacity { weights, limit }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                Stop {
+                    load: vec![2, 1],
+                    ..create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    )
+                },
+                Stop {
+                    load: vec![0, 0],
+                    ..create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                        1,
+                    )
+                },
+            ],
+            statistic: Statistic {
+                cost: 13.,
+                distance: 1,
+                duration: 2,
+                times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                tightness: None,
+            },
+            consumption: None,
+            day: None,
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_aggregate_capacity(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_capacity_decay, (duration, expected_result), {
+    can_check_capacity_decay_impl(duration, expected_result);
+}}
+
+can_check_capacity_decay! {
+    case01: (None, Ok(())),
+    case02: (Some(100.), Ok(())),
+    case03: (Some(2.), Ok(())),
+    case04: (Some(1.1), Err("Capacity decay violation in tour 'my_vehicle_1', expected: not more than [1] at '1970-01-01T00:00:01Z', got: [2]".to_owned())),
+    case05: (Some(1.), Err("Capacity decay violation in tour 'my_vehicle_1', expected: not more than [0] at '1970-01-01T00:00:01Z', got: [2]".to_owned())),
+}
+
+fn can_check_capacity_decay_impl(duration: Option<f64>, expected_result: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![2])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![10],
+                capacity_decay: duration.map(|duration| VehicleCapacityDecay::Linear { duration }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                Stop {
+                    load: vec![2],
+                    ..create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    )
+                },
+                Stop {
+                    load: vec![0],
+                    ..create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                        1,
+                    )
+                },
+            ],
+            statistic: Statistic {
+                cost: 13.,
+                distance: 1,
+                duration: 2,
+                times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                tightness: None,
+            },
+            consumption: None,
+            day: None,
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_capacity_decay(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected_result);
+}
@@ -60,10 +60,381 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), String>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, shift_time, allowed_areas: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        shift_time,
+        allowed_areas: None,
+        max_time_window_jobs: None,
+        max_reloads: None,
+        max_hourly_time_window_jobs: None,
+        max_late_stops: None,
+        max_first_leg_distance: None,
+    }));
     let solution = create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() });
 
     let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_check_max_time_window_jobs_limit, (max_tw_jobs, expected_result), {
+    can_check_max_time_window_jobs_limit_impl(max_tw_jobs, expected_result);
+}}
+
+can_check_max_time_window_jobs_limit! {
+    case_01: (2, Ok(())),
+    case_02: (1, Err("max time window jobs limit violation, expected: not more than 1, got: 2, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+}
+
+fn can_check_max_time_window_jobs_limit_impl(max_tw_jobs: usize, expected: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 100)], 1.),
+                create_delivery_job_with_times("job2", vec![2., 0.], vec![(0, 100)], 1.),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        ..create_test_problem(Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            allowed_areas: None,
+            max_time_window_jobs: Some(max_tw_jobs),
+            max_reloads: None,
+            max_hourly_time_window_jobs: None,
+            max_late_stops: None,
+            max_first_leg_distance: None,
+        }))
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            stops: vec![
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    2,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "delivery",
+                    (2., 0.),
+                    1,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                    2,
+                ),
+                create_stop_with_activity(
+                    "job3",
+                    "delivery",
+                    (3., 0.),
+                    0,
+                    ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                    3,
+                ),
+            ],
+            ..create_test_solution(Statistic::default()).tours.first().unwrap().clone()
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_vehicle_range, (range, actual, expected_result), {
+    can_check_vehicle_range_impl(range, actual, expected_result);
+}}
+
+can_check_vehicle_range! {
+    case_01: (Some(10.), 11, Err("vehicle range violation, expected: not more than 10, got: 11, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+    case_02: (Some(10.), 10, Ok(())),
+    case_03: (None, 11, Ok(())),
+}
+
+fn can_check_vehicle_range_impl(range: Option<f64>, actual: i64, expected: Result<(), String>) {
+    let mut problem = create_test_problem(None);
+    problem.fleet.vehicles[0].range = range;
+    let solution = create_test_solution(Statistic { distance: actual, ..Statistic::default() });
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_max_reloads_limit, (max_reloads, expected_result), {
+    can_check_max_reloads_limit_impl(max_reloads, expected_result);
+}}
+
+can_check_max_reloads_limit! {
+    case_01: (2, Ok(())),
+    case_02: (1, Err("max reloads limit violation, expected: not more than 1, got: 2, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+}
+
+fn can_check_max_reloads_limit_impl(max_reloads: usize, expected: Result<(), String>) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        allowed_areas: None,
+        max_time_window_jobs: None,
+        max_reloads: Some(max_reloads),
+        max_hourly_time_window_jobs: None,
+        max_late_stops: None,
+        max_first_leg_distance: None,
+    }));
+    let solution = Solution {
+        tours: vec![Tour {
+            stops: vec![
+                create_stop_with_activity(
+                    "reload",
+                    "reload",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "reload",
+                    "reload",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                    2,
+                ),
+            ],
+            ..create_test_solution(Statistic::default()).tours.first().unwrap().clone()
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_reload_handling_time, (handling_time, expected_result), {
+    can_check_reload_handling_time_impl(handling_time, expected_result);
+}}
+
+can_check_reload_handling_time! {
+    case_01: (None, Ok(())),
+    case_02: (Some(3.), Ok(())),
+    case_03: (Some(4.), Err("reload handling time violation, expected: not less than 4, got: 3, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+}
+
+fn can_check_reload_handling_time_impl(handling_time: Option<f64>, expected: Result<(), String>) {
+    let mut problem = create_test_problem(None);
+    problem.fleet.vehicles[0].reload_handling_time = handling_time;
+    let solution = Solution {
+        tours: vec![Tour {
+            stops: vec![create_stop_with_activity(
+                "reload",
+                "reload",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:04Z"),
+                1,
+            )],
+            ..create_test_solution(Statistic::default()).tours.first().unwrap().clone()
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_max_hourly_time_window_jobs_limit, (max_hourly_tw_jobs, expected_result), {
+    can_check_max_hourly_time_window_jobs_limit_impl(max_hourly_tw_jobs, expected_result);
+}}
+
+can_check_max_hourly_time_window_jobs_limit! {
+    case_01: (2, Ok(())),
+    case_02: (1, Err("max hourly time window jobs limit violation, expected: not more than 1, got: 2, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+}
+
+fn can_check_max_hourly_time_window_jobs_limit_impl(max_hourly_tw_jobs: usize, expected: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 10000)], 1.),
+                create_delivery_job_with_times("job2", vec![2., 0.], vec![(0, 10000)], 1.),
+                create_delivery_job_with_times("job3", vec![3., 0.], vec![(0, 10000)], 1.),
+            ],
+            relations: None,
+        },
+        ..create_test_problem(Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            allowed_areas: None,
+            max_time_window_jobs: None,
+            max_reloads: None,
+            max_hourly_time_window_jobs: Some(max_hourly_tw_jobs),
+            max_late_stops: None,
+            max_first_leg_distance: None,
+        }))
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            stops: vec![
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    2,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "delivery",
+                    (2., 0.),
+                    1,
+                    ("1970-01-01T00:30:00Z", "1970-01-01T00:30:01Z"),
+                    2,
+                ),
+                create_stop_with_activity(
+                    "job3",
+                    "delivery",
+                    (3., 0.),
+                    0,
+                    ("1970-01-01T01:10:00Z", "1970-01-01T01:10:01Z"),
+                    3,
+                ),
+            ],
+            ..create_test_solution(Statistic::default()).tours.first().unwrap().clone()
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_max_late_stops_limit, (max_late_stops, expected_result), {
+    can_check_max_late_stops_limit_impl(max_late_stops, expected_result);
+}}
+
+can_check_max_late_stops_limit! {
+    case_01: (1, Ok(())),
+    case_02: (0, Err("max late stops limit violation, expected: not more than 0, got: 1, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+}
+
+fn can_check_max_late_stops_limit_impl(max_late_stops: usize, expected: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 10)], 1.),
+                create_delivery_job_with_times("job2", vec![2., 0.], vec![(0, 10)], 1.),
+            ],
+            relations: None,
+        },
+        ..create_test_problem(Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            allowed_areas: None,
+            max_time_window_jobs: None,
+            max_reloads: None,
+            max_hourly_time_window_jobs: None,
+            max_late_stops: Some(max_late_stops),
+            max_first_leg_distance: None,
+        }))
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            stops: vec![
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "delivery",
+                    (2., 0.),
+                    0,
+                    ("1970-01-01T00:00:20Z", "1970-01-01T00:00:21Z"),
+                    2,
+                ),
+            ],
+            ..create_test_solution(Statistic::default()).tours.first().unwrap().clone()
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_max_first_leg_distance_limit, (max_first_leg_distance, expected_result), {
+    can_check_max_first_leg_distance_limit_impl(max_first_leg_distance, expected_result);
+}}
+
+can_check_max_first_leg_distance_limit! {
+    case_01: (3., Ok(())),
+    case_02: (2., Err("max first leg distance limit violation, expected: not more than 2, got: 3, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+}
+
+fn can_check_max_first_leg_distance_limit_impl(max_first_leg_distance: f64, expected: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![3., 0.]), create_delivery_job("job2", vec![6., 0.])],
+            relations: None,
+        },
+        ..create_test_problem(Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            allowed_areas: None,
+            max_time_window_jobs: None,
+            max_reloads: None,
+            max_hourly_time_window_jobs: None,
+            max_late_stops: None,
+            max_first_leg_distance: Some(max_first_leg_distance),
+        }))
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    2,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (3., 0.),
+                    1,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                    3,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "delivery",
+                    (6., 0.),
+                    0,
+                    ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
+                    6,
+                ),
+            ],
+            ..create_test_solution(Statistic::default()).tours.first().unwrap().clone()
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected);
+}
@@ -12,8 +12,9 @@ mod single {
         Relation {
             type_field: relation_type,
             jobs: job_ids.iter().map(|id| id.to_string()).collect(),
-            vehicle_id: "my_vehicle_1".to_string(),
+            vehicle_id: Some("my_vehicle_1".to_string()),
             shift_index: None,
+            penalty: None,
         }
     }
 
@@ -21,8 +22,9 @@ mod single {
         Relation {
             type_field: Sequence,
             jobs: vec!["job1".to_string()],
-            vehicle_id: vehicle_id.to_string(),
+            vehicle_id: Some(vehicle_id.to_string()),
             shift_index: None,
+            penalty: None,
         }
     }
 
@@ -30,8 +32,9 @@ mod single {
         Relation {
             type_field: Sequence,
             jobs: vec!["job1".to_string()],
-            vehicle_id: "my_vehicle_1".to_string(),
+            vehicle_id: Some("my_vehicle_1".to_string()),
             shift_index: Some(1),
+            penalty: None,
         }
     }
 
@@ -86,11 +89,18 @@ mod single {
                     profile: "car".to_string(),
                     costs: create_default_vehicle_costs(),
                     shifts: vec![VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: vec![0., 0.].to_loc(),
+                            alternatives: None,
+                            dock_slot: None,
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(1000.).to_string(),
                             location: vec![0., 0.].to_loc(),
+                            max_overtime: None,
                         }),
                         depots: None,
                         breaks: Some(vec![VehicleBreak {
@@ -104,10 +114,19 @@ mod single {
                             duration: 2.0,
                             tag: None,
                         }]),
+                        anchors: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    range: None,
+                    aggregate_capacity: None,
+                    consumption: None,
+                    crew_size: None,
+                    loading_discipline: None,
+                    reload_handling_time: None,
+                    maneuvering_time: None,
+                    capacity_decay: None,
                 }],
                 profiles: create_default_profiles(),
             },
@@ -119,6 +138,7 @@ mod single {
                 distance: 16,
                 duration: 25,
                 times: Timing { driving: 16, serving: 9, waiting: 0, break_time: 2 },
+                tightness: None,
             },
             tours: vec![
                 VehicleTour {
@@ -157,6 +177,8 @@ mod single {
                                     location: None,
                                     time: None,
                                     job_tag: None,
+                                    distance: None,
+                                    duration: None,
                                 },
                                 Activity {
                                     job_id: "break".to_string(),
@@ -164,6 +186,8 @@ mod single {
                                     location: None,
                                     time: None,
                                     job_tag: None,
+                                    distance: None,
+                                    duration: None,
                                 },
                             ],
                         },
@@ -213,7 +237,10 @@ mod single {
                         distance: 16,
                         duration: 25,
                         times: Timing { driving: 16, serving: 9, waiting: 0, break_time: 2 },
+                        tightness: None,
                     },
+                    consumption: None,
+                    day: None,
                 },
                 VehicleTour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -221,6 +248,8 @@ mod single {
                     shift_index: 0,
                     stops: vec![],
                     statistic: Default::default(),
+                    consumption: None,
+                    day: None,
                 },
             ],
             ..create_empty_solution()
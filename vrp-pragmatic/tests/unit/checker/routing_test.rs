@@ -20,6 +20,7 @@ fn create_test_statistic() -> Statistic {
         distance: 4,
         duration: 6,
         times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+        tightness: None,
     }
 }
 
@@ -51,6 +52,8 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                         location: None,
                         time: None,
                         job_tag: None,
+                        distance: None,
+                        duration: None,
                     }],
                 },
                 Stop {
@@ -64,6 +67,8 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                         location: Some(vec![2., 0.].to_loc()),
                         time: None,
                         job_tag: None,
+                        distance: None,
+                        duration: None,
                     }],
                 },
                 create_stop_with_activity(
@@ -76,6 +81,8 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                 ),
             ],
             statistic,
+            consumption: None,
+            day: None,
         }],
         ..create_empty_solution()
     }
@@ -173,3 +180,16 @@ fn can_check_solution_statistic() {
         ))
     );
 }
+
+#[test]
+fn can_check_unreachable_leg() {
+    let problem = create_test_problem();
+    let mut matrix = create_matrix_from_problem(&problem);
+    matrix.distances[1] = 9999;
+    let matrix = Matrix { unreachable_value: Some(9999), ..matrix };
+    let solution = create_test_solution(create_test_statistic(), &[(1., 1), (3., 2), (6., 4)]);
+
+    let result = check_routing(&CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution));
+
+    assert_eq!(result, Err("unreachable leg is used to get 2 stop in the tour: my_vehicle_1".to_string()));
+}
@@ -0,0 +1,74 @@
+use crate::constraints::AnchorModule;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::constraints::ConstraintPipeline;
+use vrp_core::construction::heuristics::{RegistryContext, RouteContext, RouteState, SolutionContext};
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::{Fleet, Job, Single};
+use vrp_core::models::solution::Registry;
+
+fn create_single(id: &str) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(id);
+
+    Arc::new(single)
+}
+
+fn create_anchor(vehicle_id: &str) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(2));
+    single.dimens.set_id("anchor");
+    single.dimens.set_value("type", "anchor".to_string());
+    single.dimens.set_value("vehicle_id", vehicle_id.to_string());
+    single.dimens.set_value("shift_index", 0_usize);
+
+    Arc::new(single)
+}
+
+parameterized_test! {can_keep_anchor_only_in_active_route, (has_job, is_anchor_in_route, expected), {
+    can_keep_anchor_only_in_active_route_impl(has_job, is_anchor_in_route, expected);
+}}
+
+can_keep_anchor_only_in_active_route! {
+    case01: (true, true, (true, false, false)),
+    case02: (true, false, (false, true, false)),
+    case03: (false, true, (false, false, true)),
+}
+
+fn can_keep_anchor_only_in_active_route_impl(has_job: bool, is_anchor_in_route: bool, expected: (bool, bool, bool)) {
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_value("shift_index", 0_usize);
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let anchor = create_anchor("v1");
+    let mut activities = vec![];
+    if has_job {
+        activities.push(create_activity_with_job_at_location(create_single("job1"), 1));
+    }
+    if is_anchor_in_route {
+        activities.push(create_activity_with_job_at_location(anchor.clone(), 2));
+    }
+    let mut solution_ctx = SolutionContext {
+        required: vec![],
+        ignored: if is_anchor_in_route { vec![] } else { vec![Job::Single(anchor.clone())] },
+        unassigned: Default::default(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![RouteContext {
+            route: Arc::new(create_route_with_activities(&fleet, "v1", activities)),
+            state: Arc::new(RouteState::default()),
+        }],
+        registry: RegistryContext::new(Registry::new(&fleet)),
+    };
+
+    ConstraintPipeline::default().add_module(Box::new(AnchorModule::new(0))).accept_solution_state(&mut solution_ctx);
+
+    let anchor = Job::Single(anchor);
+    let is_in_route = solution_ctx.routes.first().unwrap().route.tour.contains(&anchor);
+    let is_required = solution_ctx.required.contains(&anchor);
+    let is_ignored = solution_ctx.ignored.contains(&anchor);
+    assert_eq!((is_in_route, is_required, is_ignored), expected);
+}
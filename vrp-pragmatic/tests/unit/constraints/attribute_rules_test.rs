@@ -0,0 +1,86 @@
+use super::*;
+use vrp_core::models::common::IdDimension;
+
+fn create_job_dimens(tag: Option<&str>, priority: Option<i32>) -> Dimensions {
+    let mut dimens = Dimensions::default();
+    dimens.set_id("job1");
+    if let Some(tag) = tag {
+        dimens.set_value("tag", tag.to_string());
+    }
+    if let Some(priority) = priority {
+        dimens.set_value("priority", priority);
+    }
+
+    dimens
+}
+
+fn create_vehicle_dimens(type_id: &str, skills: Vec<&str>) -> Dimensions {
+    let mut dimens = Dimensions::default();
+    dimens.set_id("v1");
+    dimens.set_value("type_id", type_id.to_string());
+    dimens.set_value("shift_index", 0_usize);
+    dimens.set_value("skills", skills.into_iter().map(|skill| skill.to_string()).collect::<HashSet<_>>());
+
+    dimens
+}
+
+parameterized_test! {can_evaluate_rule, (rule, job, vehicle, expected), {
+    can_evaluate_rule_impl(rule, job, vehicle, expected);
+}}
+
+can_evaluate_rule! {
+    case01_forbidden_combination: ("!(job.tag == 'fragile' && vehicle.type_id == 'construction')", (Some("fragile"), None), "construction", false),
+    case02_other_vehicle: ("!(job.tag == 'fragile' && vehicle.type_id == 'construction')", (Some("fragile"), None), "regular", true),
+    case03_other_job: ("!(job.tag == 'fragile' && vehicle.type_id == 'construction')", (Some("heavy"), None), "construction", true),
+    case04_missing_attribute: ("!(job.tag == 'fragile' && vehicle.type_id == 'construction')", (None, None), "construction", true),
+    case05_not_equal: ("job.tag != \"fragile\"", (Some("fragile"), None), "regular", false),
+    case06_number_compare: ("job.priority <= 2 || vehicle.type_id == 'regular'", (None, Some(3)), "construction", false),
+    case07_number_compare: ("job.priority <= 2 || vehicle.type_id == 'regular'", (None, Some(2)), "construction", true),
+    case08_missing_number: ("job.priority > 1", (None, None), "regular", false),
+    case09_set_has: ("vehicle.skills has 'fridge'", (None, None), "regular", true),
+    case10_set_has_not: ("!(vehicle.skills has 'crane')", (None, None), "regular", true),
+    case11_shift_index: ("vehicle.shift_index == 0 && job.id == 'job1'", (None, None), "regular", true),
+    case12_precedence: ("false && false || true", (None, None), "regular", true),
+    case13_literal: ("true", (None, None), "regular", true),
+}
+
+fn can_evaluate_rule_impl(rule: &str, job: (Option<&str>, Option<i32>), vehicle: &str, expected: bool) {
+    let job = create_job_dimens(job.0, job.1);
+    let vehicle = create_vehicle_dimens(vehicle, vec!["fridge"]);
+
+    let rule = AttributeRule::parse(rule).expect("cannot parse rule");
+
+    assert_eq!(rule.evaluate(&[&job], &vehicle), expected);
+}
+
+#[test]
+fn can_use_job_attributes_in_order() {
+    let sub_job = create_job_dimens(Some("fragile"), None);
+    let job = create_job_dimens(None, Some(3));
+    let vehicle = create_vehicle_dimens("regular", vec![]);
+
+    let rule = AttributeRule::parse("job.tag == 'fragile' && job.priority == 3").expect("cannot parse rule");
+
+    assert!(rule.evaluate(&[&sub_job, &job], &vehicle));
+    assert!(!rule.evaluate(&[&job], &vehicle));
+}
+
+parameterized_test! {can_detect_invalid_rule, (rule, expected), {
+    can_detect_invalid_rule_impl(rule, expected);
+}}
+
+can_detect_invalid_rule! {
+    case01_unknown_identifier: ("tag == 'fragile'", "unknown identifier 'tag'"),
+    case02_unexpected_end: ("job.tag ==", "unexpected end of expression"),
+    case03_unclosed_paren: ("(job.tag == 'a'", "unexpected end of expression"),
+    case04_unterminated_string: ("job.tag == 'a", "unterminated string literal"),
+    case05_extra_token: ("job.tag == 'a' 'b'", "unexpected token 'b'"),
+    case06_unexpected_char: ("job.tag = 'a'", "unexpected character '='"),
+    case07_empty_name: ("vehicle. == 'a'", "unknown identifier 'vehicle.'"),
+}
+
+fn can_detect_invalid_rule_impl(rule: &str, expected: &str) {
+    let result = AttributeRule::parse(rule);
+
+    assert_eq!(result.err(), Some(expected.to_string()));
+}
@@ -5,9 +5,21 @@ use std::sync::Arc;
 use vrp_core::construction::constraints::ConstraintPipeline;
 use vrp_core::construction::heuristics::{RegistryContext, RouteContext, RouteState, SolutionContext};
 use vrp_core::models::common::{IdDimension, Location, ValueDimension};
-use vrp_core::models::problem::{Fleet, Single};
+use vrp_core::models::problem::{Fleet, SimpleActivityCost, Single, TransportCost};
 use vrp_core::models::solution::Registry;
 
+struct ZeroTransportCost {}
+
+impl TransportCost for ZeroTransportCost {
+    fn duration(&self, _: i32, _: Location, _: Location, _: f64) -> f64 {
+        0.
+    }
+
+    fn distance(&self, _: i32, _: Location, _: Location, _: f64) -> f64 {
+        0.
+    }
+}
+
 fn create_single(id: &str) -> Arc<Single> {
     let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
     single.dimens.set_id(id);
@@ -32,6 +44,7 @@ can_remove_orphan_break! {
     case01: (None, 2, true),
     case02: (None, 1, false),
     case03: (Some(2), 2, false),
+    case04: (None, 3, false),
 }
 
 fn can_remove_orphan_break_impl(break_job_loc: Option<Location>, break_activity_loc: Location, break_removed: bool) {
@@ -61,7 +74,8 @@ fn can_remove_orphan_break_impl(break_job_loc: Option<Location>, break_activity_
         registry: RegistryContext::new(Registry::new(&fleet)),
     };
 
-    ConstraintPipeline::default().add_module(Box::new(BreakModule::new(0))).accept_solution_state(&mut solution_ctx);
+    let module = BreakModule::new(Arc::new(SimpleActivityCost::default()), Arc::new(ZeroTransportCost {}), 0);
+    ConstraintPipeline::default().add_module(Box::new(module)).accept_solution_state(&mut solution_ctx);
 
     if break_removed {
         assert_eq!(solution_ctx.unassigned.len(), 1);
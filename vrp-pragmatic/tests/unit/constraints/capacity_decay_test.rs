@@ -0,0 +1,93 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Location, MultiDimLoad};
+use vrp_core::models::problem::{Fleet, SimpleActivityCost};
+
+struct LineTransportCost {}
+
+impl TransportCost for LineTransportCost {
+    fn duration(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+
+    fn distance(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+}
+
+fn create_activity_with_demand(location: Location, pickup: i32, delivery: i32) -> Activity {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_demand(single_demand_as_multi((pickup, 0), (delivery, 0)));
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+fn create_route_ctx(decay_duration: Option<Duration>, activities: Vec<Activity>) -> RouteContext {
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_capacity(MultiDimLoad::new(vec![10]));
+    if let Some(duration) = decay_duration {
+        vehicle.dimens.set_value("capacity_decay", CapacityDecay::Linear { duration });
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+
+    RouteContext {
+        route: Arc::new(create_route_with_activities(&fleet, "v1", activities)),
+        state: Arc::new(RouteState::default()),
+    }
+}
+
+parameterized_test! {can_get_linear_share, (elapsed, expected), {
+    assert_eq!(CapacityDecay::Linear { duration: 100. }.get_share(elapsed), expected);
+}}
+
+can_get_linear_share! {
+    case01_at_departure: (0., 1.),
+    case02_half: (50., 0.5),
+    case03_at_end: (100., 0.),
+    case04_after_end: (150., 0.),
+}
+
+parameterized_test! {can_limit_load_by_decayed_capacity, (decay_duration, target, expected), {
+    can_limit_load_by_decayed_capacity_impl(decay_duration, target, expected);
+}}
+
+can_limit_load_by_decayed_capacity! {
+    case01_delivery_fits_early: (Some(100.), (10, 0, 5), None),
+    case02_delivery_above_decayed: (Some(100.), (60, 0, 5), Some(1)),
+    case03_pickup_fits_early: (Some(100.), (10, 5, 0), None),
+    case04_pickup_above_decayed_on_next_leg: (Some(100.), (40, 5, 0), Some(1)),
+    case05_no_decay: (None, (60, 0, 5), None),
+}
+
+fn can_limit_load_by_decayed_capacity_impl(
+    decay_duration: Option<Duration>,
+    target: (Location, i32, i32),
+    expected: Option<i32>,
+) {
+    let route_ctx = create_route_ctx(decay_duration, vec![create_activity_with_demand(20, 0, 0)]);
+    let target = create_activity_with_demand(target.0, target.1, target.2);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+    let module = CapacityDecayModule::<MultiDimLoad>::new(
+        Arc::new(SimpleActivityCost::default()),
+        Arc::new(LineTransportCost {}),
+        1,
+    );
+
+    let result = module.constraints.first().and_then(|constraint| match constraint {
+        ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(&route_ctx, &activity_ctx),
+        _ => None,
+    });
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
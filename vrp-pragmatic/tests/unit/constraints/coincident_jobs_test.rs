@@ -0,0 +1,49 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::Fleet;
+
+parameterized_test! {can_keep_coincident_jobs_within_one_stop, (target, index, expected), {
+    can_keep_coincident_jobs_within_one_stop_impl(target, index, expected);
+}}
+
+can_keep_coincident_jobs_within_one_stop! {
+    case01: (1, 0, None),
+    case02: (1, 1, None),
+    case03: (1, 2, None),
+    case04: (1, 3, Some(1)),
+    case05: (2, 0, Some(1)),
+    case06: (2, 2, None),
+    case07: (3, 0, None),
+    case08: (3, 1, Some(1)),
+    case09: (3, 3, None),
+}
+
+fn can_keep_coincident_jobs_within_one_stop_impl(target: Location, index: usize, expected: Option<i32>) {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let activities = vec![
+        create_activity_with_job_at_location(Arc::new(create_single_with_location(Some(1))), 1),
+        create_activity_with_job_at_location(Arc::new(create_single_with_location(Some(1))), 1),
+        create_activity_with_job_at_location(Arc::new(create_single_with_location(Some(2))), 2),
+    ];
+    let route_ctx = RouteContext {
+        route: Arc::new(create_route_with_activities(&fleet, "v1", activities)),
+        state: Arc::new(RouteState::default()),
+    };
+    let target = create_activity_with_job_at_location(Arc::new(create_single_with_location(Some(target))), target);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = CoincidentJobsHardActivityConstraint { code: 1 }.evaluate_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
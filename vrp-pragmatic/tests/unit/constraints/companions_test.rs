@@ -0,0 +1,99 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::{RegistryContext, RouteState};
+use vrp_core::models::problem::{Fleet, Single};
+use vrp_core::models::solution::Registry;
+
+fn create_single(id: &str, companions: Option<Vec<&str>>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(id);
+    if let Some(companions) = companions {
+        single.dimens.set_value("companions", companions.into_iter().map(|id| id.to_string()).collect::<Vec<_>>());
+    }
+
+    Arc::new(single)
+}
+
+fn create_solution_ctx(fleet: &Fleet, jobs: Vec<Arc<Single>>) -> SolutionContext {
+    SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![RouteContext {
+            route: Arc::new(create_route_with_activities(
+                fleet,
+                "v1",
+                jobs.into_iter().map(|job| create_activity_with_job_at_location(job, DEFAULT_JOB_LOCATION)).collect(),
+            )),
+            state: Arc::new(RouteState::default()),
+        }],
+        registry: RegistryContext::new(Registry::new(fleet)),
+    }
+}
+
+fn create_test_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+parameterized_test! {can_assign_job_only_with_companion, (route_jobs, companions, expected), {
+    can_assign_job_only_with_companion_impl(route_jobs, companions, expected);
+}}
+
+can_assign_job_only_with_companion! {
+    case01: (vec![], None, None),
+    case02: (vec![], Some(vec!["job1"]), Some(1)),
+    case03: (vec!["job1"], Some(vec!["job1"]), None),
+    case04: (vec!["job2"], Some(vec!["job1"]), Some(1)),
+    case05: (vec!["job2"], Some(vec!["job1", "job2"]), None),
+}
+
+fn can_assign_job_only_with_companion_impl(
+    route_jobs: Vec<&str>,
+    companions: Option<Vec<&str>>,
+    expected: Option<i32>,
+) {
+    let fleet = create_test_fleet();
+    let solution_ctx = create_solution_ctx(&fleet, route_jobs.into_iter().map(|id| create_single(id, None)).collect());
+    let job = Job::Single(create_single("flagged", companions));
+
+    let result = CompanionHardRouteConstraint { code: 1 }.evaluate_job(&solution_ctx, &solution_ctx.routes[0], &job);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_remove_job_without_companion, (route_jobs, is_locked, expected), {
+    can_remove_job_without_companion_impl(route_jobs, is_locked, expected);
+}}
+
+can_remove_job_without_companion! {
+    case01: (vec!["job1"], false, (true, false)),
+    case02: (vec!["job2"], false, (false, true)),
+    case03: (vec![], false, (false, true)),
+    case04: (vec!["job2"], true, (true, false)),
+}
+
+fn can_remove_job_without_companion_impl(route_jobs: Vec<&str>, is_locked: bool, expected: (bool, bool)) {
+    let fleet = create_test_fleet();
+    let flagged = create_single("flagged", Some(vec!["job1"]));
+    let jobs = route_jobs.into_iter().map(|id| create_single(id, None)).chain(std::iter::once(flagged.clone()));
+    let mut solution_ctx = create_solution_ctx(&fleet, jobs.collect());
+    let flagged = Job::Single(flagged);
+    if is_locked {
+        solution_ctx.locked.insert(flagged.clone());
+    }
+
+    ConstraintPipeline::default()
+        .add_module(Box::new(CompanionModule::new(1)))
+        .accept_solution_state(&mut solution_ctx);
+
+    let is_in_route = solution_ctx.routes[0].route.tour.contains(&flagged);
+    let is_required = solution_ctx.required.contains(&flagged);
+    assert_eq!((is_in_route, is_required), expected);
+}
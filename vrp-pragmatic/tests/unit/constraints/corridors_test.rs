@@ -0,0 +1,57 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::Fleet;
+
+fn create_activity_with_corridor(corridor: Option<(&str, f64)>) -> Activity {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    if let Some((id, position)) = corridor {
+        single.dimens.set_value("corridor", (id.to_string(), position));
+    }
+
+    create_activity_with_job_at_location(Arc::new(single), DEFAULT_JOB_LOCATION)
+}
+
+parameterized_test! {can_keep_corridor_order, (corridor, index, expected), {
+    can_keep_corridor_order_impl(corridor, index, expected);
+}}
+
+can_keep_corridor_order! {
+    case01: (Some(("c1", 2.)), 0, Some(1)),
+    case02: (Some(("c1", 2.)), 1, None),
+    case03: (Some(("c1", 2.)), 2, None),
+    case04: (Some(("c1", 2.)), 3, Some(1)),
+    case05: (Some(("c1", 1.)), 0, None),
+    case06: (Some(("c1", 3.)), 3, None),
+    case07: (Some(("c2", 0.)), 3, None),
+    case08: (None, 0, None),
+}
+
+fn can_keep_corridor_order_impl(corridor: Option<(&str, f64)>, index: usize, expected: Option<i32>) {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let activities = vec![
+        create_activity_with_corridor(Some(("c1", 1.))),
+        create_activity_with_corridor(None),
+        create_activity_with_corridor(Some(("c1", 3.))),
+    ];
+    let route_ctx = RouteContext {
+        route: Arc::new(create_route_with_activities(&fleet, "v1", activities)),
+        state: Arc::new(RouteState::default()),
+    };
+    let target = create_activity_with_corridor(corridor);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = CorridorHardActivityConstraint { code: 1 }.evaluate_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
@@ -0,0 +1,74 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::Location;
+use vrp_core::models::problem::Fleet;
+
+struct LineTransportCost {}
+
+impl TransportCost for LineTransportCost {
+    fn duration(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+
+    fn distance(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+}
+
+fn create_activity_with_type(location: Location, activity_type: &str) -> Activity {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_value("type", activity_type.to_string());
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+parameterized_test! {can_limit_first_leg_distance, (max_distance, target, index, expected), {
+    can_limit_first_leg_distance_impl(max_distance, target, index, expected);
+}}
+
+can_limit_first_leg_distance! {
+    case01_first_job_within_limit: (Some(6.), (5, "service"), 0, None),
+    case02_first_job_above_limit: (Some(6.), (8, "delivery"), 0, Some(1)),
+    case03_not_first_job: (Some(6.), (100, "service"), 1, None),
+    case04_break_before_first_job: (Some(15.), (20, "break"), 0, Some(1)),
+    case05_break_within_limit: (Some(15.), (5, "break"), 0, None),
+    case06_no_limit: (None, (8, "delivery"), 0, None),
+}
+
+fn can_limit_first_leg_distance_impl(
+    max_distance: Option<f64>,
+    target: (Location, &str),
+    index: usize,
+    expected: Option<i32>,
+) {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(max_distance) = max_distance {
+        vehicle.dimens.set_value("max_first_leg_distance", max_distance);
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx = RouteContext {
+        route: Arc::new(create_route_with_activities(&fleet, "v1", vec![create_activity_with_type(10, "service")])),
+        state: Arc::new(RouteState::default()),
+    };
+    let target = create_activity_with_type(target.0, target.1);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+    let module = FirstLegDistanceModule::new(Arc::new(LineTransportCost {}), 1);
+
+    let result = module.constraints.first().and_then(|constraint| match constraint {
+        ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(&route_ctx, &activity_ctx),
+        _ => None,
+    });
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
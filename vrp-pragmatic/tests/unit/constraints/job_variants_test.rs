@@ -0,0 +1,81 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::{RegistryContext, RouteState};
+use vrp_core::models::problem::{Fleet, Single};
+use vrp_core::models::solution::Registry;
+
+fn create_variant(id: &str, index: usize) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(id);
+    single.dimens.set_value("variant", index);
+
+    Arc::new(single)
+}
+
+fn create_solution_ctx(fleet: &Fleet, assigned: Vec<Arc<Single>>, unassigned: Vec<Arc<Single>>) -> SolutionContext {
+    let activities = assigned.into_iter().map(|single| create_activity_with_job_at_location(single, 1)).collect();
+
+    SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: unassigned.into_iter().map(|single| (Job::Single(single), 0)).collect(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![RouteContext {
+            route: Arc::new(create_route_with_activities(fleet, "v1", activities)),
+            state: Arc::new(RouteState::default()),
+        }],
+        registry: RegistryContext::new(Registry::new(fleet)),
+    }
+}
+
+fn create_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+#[test]
+fn can_count_group_without_assigned_variants_as_one_unassigned_job() {
+    let fleet = create_fleet();
+    let variants = [create_variant("job1", 0), create_variant("job1", 1), create_variant("job1", 2)];
+    let other = create_variant("job2", 0);
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![], variants.iter().chain(Some(&other)).cloned().collect());
+
+    JobVariantsModule::default().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), 2);
+    assert!(solution_ctx.unassigned.contains_key(&Job::Single(variants[0].clone())));
+    assert!(solution_ctx.unassigned.contains_key(&Job::Single(other)));
+    assert_eq!(solution_ctx.ignored.len(), 2);
+}
+
+#[test]
+fn can_remove_siblings_of_assigned_variant_from_unassigned_jobs() {
+    let fleet = create_fleet();
+    let variants = [create_variant("job1", 0), create_variant("job1", 1), create_variant("job1", 2)];
+    let mut solution_ctx =
+        create_solution_ctx(&fleet, vec![variants[1].clone()], vec![variants[0].clone(), variants[2].clone()]);
+
+    JobVariantsModule::default().accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.unassigned.is_empty());
+    assert_eq!(solution_ctx.ignored.len(), 2);
+}
+
+#[test]
+fn can_release_ignored_variants_when_group_is_unassigned() {
+    let fleet = create_fleet();
+    let variants = [create_variant("job1", 0), create_variant("job1", 1)];
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![], vec![]);
+    solution_ctx.ignored = variants.iter().cloned().map(Job::Single).collect();
+
+    JobVariantsModule::default().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.required.len(), 2);
+    assert!(solution_ctx.ignored.is_empty());
+}
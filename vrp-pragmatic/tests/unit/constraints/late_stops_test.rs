@@ -0,0 +1,94 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Cost, Location};
+use vrp_core::models::problem::{Fleet, SimpleActivityCost};
+
+struct LineTransportCost {}
+
+impl TransportCost for LineTransportCost {
+    fn duration(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+
+    fn distance(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+}
+
+fn create_activity_with_latest(location: Location, latest: Timestamp) -> Activity {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_value("latest", latest);
+
+    create_activity_with_job_at_location(Arc::new(single), location)
+}
+
+fn get_lateness(activity: &Activity) -> Option<(Timestamp, Cost)> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_value::<f64>("latest")).map(|latest| (*latest, 1.))
+}
+
+fn create_route_ctx(max_late_stops: Option<usize>, activities: Vec<Activity>) -> RouteContext {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(max_late_stops) = max_late_stops {
+        vehicle.dimens.set_value("max_late_stops", max_late_stops);
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let mut route = create_route_with_activities(&fleet, "v1", activities);
+
+    // NOTE schedules are updated by transport module which is not used here
+    let start = route.tour.start().unwrap();
+    let init = (start.place.location, start.schedule.departure);
+    route.tour.all_activities_mut().skip(1).fold(init, |(location, departure), activity| {
+        let arrival = departure + (activity.place.location as f64 - location as f64).abs();
+        activity.schedule.arrival = arrival;
+        activity.schedule.departure = arrival.max(activity.place.time.start) + activity.place.duration;
+
+        (activity.place.location, activity.schedule.departure)
+    });
+
+    RouteContext { route: Arc::new(route), state: Arc::new(RouteState::default()) }
+}
+
+parameterized_test! {can_limit_late_stops, (max_late_stops, target, expected), {
+    can_limit_late_stops_impl(max_late_stops, target, expected);
+}}
+
+can_limit_late_stops! {
+    case01_target_in_time: (Some(1), (5, 100.), None),
+    case02_target_is_late: (Some(1), (5, 3.), Some(1)),
+    case03_target_delays_next: (Some(1), (30, 100.), Some(1)),
+    case04_no_limit: (None, (5, 3.), None),
+    case05_late_within_limit: (Some(2), (5, 3.), None),
+}
+
+fn can_limit_late_stops_impl(max_late_stops: Option<usize>, target: (Location, Timestamp), expected: Option<i32>) {
+    let route_ctx = create_route_ctx(
+        max_late_stops,
+        vec![create_activity_with_latest(10, 40.), create_activity_with_latest(20, 15.)],
+    );
+    let target = create_activity_with_latest(target.0, target.1);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+    let module = LateStopsModule::new(
+        Arc::new(SimpleActivityCost::default()),
+        Arc::new(LineTransportCost {}),
+        Arc::new(get_lateness),
+        1,
+    );
+
+    let result = module.constraints.first().and_then(|constraint| match constraint {
+        ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(&route_ctx, &activity_ctx),
+        _ => None,
+    });
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
@@ -0,0 +1,67 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::{Fleet, Multi, Single};
+
+fn create_pickup_delivery_job() -> Arc<Multi> {
+    let create_single = |activity_type: &str| {
+        let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+        single.dimens.set_value("type", activity_type.to_string());
+        Arc::new(single)
+    };
+
+    Multi::bind(Multi::new(vec![create_single("pickup"), create_single("delivery")], Default::default()))
+}
+
+fn create_activity(single: &Arc<Single>) -> Activity {
+    create_activity_with_job_at_location(single.clone(), DEFAULT_JOB_LOCATION)
+}
+
+parameterized_test! {can_keep_loading_discipline, (discipline, is_delivery, index, expected), {
+    can_keep_loading_discipline_impl(discipline, is_delivery, index, expected);
+}}
+
+can_keep_loading_discipline! {
+    case01_lifo_nested: (Some(LoadingDiscipline::Lifo), true, 2, None),
+    case02_lifo_crossing: (Some(LoadingDiscipline::Lifo), true, 3, Some(1)),
+    case03_fifo_nested: (Some(LoadingDiscipline::Fifo), true, 2, Some(1)),
+    case04_fifo_crossing: (Some(LoadingDiscipline::Fifo), true, 3, None),
+    case05_no_discipline: (None, true, 3, None),
+    case06_incomplete_job: (Some(LoadingDiscipline::Lifo), false, 3, None),
+}
+
+fn can_keep_loading_discipline_impl(
+    discipline: Option<LoadingDiscipline>,
+    is_delivery: bool,
+    index: usize,
+    expected: Option<i32>,
+) {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(discipline) = discipline {
+        vehicle.dimens.set_value("loading_discipline", discipline);
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let (job1, job2, job3) = (create_pickup_delivery_job(), create_pickup_delivery_job(), create_pickup_delivery_job());
+    let activities =
+        vec![create_activity(&job1.jobs[0]), create_activity(&job2.jobs[0]), create_activity(&job1.jobs[1])];
+    let route_ctx = RouteContext {
+        route: Arc::new(create_route_with_activities(&fleet, "v1", activities)),
+        state: Arc::new(RouteState::default()),
+    };
+    let target = if is_delivery { create_activity(&job2.jobs[1]) } else { create_activity(&job3.jobs[0]) };
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = LoadingDisciplineHardActivityConstraint { code: 1 }.evaluate_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
@@ -0,0 +1,158 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::{RegistryContext, RouteState};
+use vrp_core::models::common::IdDimension;
+use vrp_core::models::problem::{Fleet, SimpleActivityCost, Single};
+use vrp_core::models::solution::Registry;
+
+struct LineTransportCost {}
+
+impl TransportCost for LineTransportCost {
+    fn duration(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+
+    fn distance(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+}
+
+const LIMITED_LOCATION: Location = 10;
+
+fn create_single(id: &str) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(id);
+
+    Arc::new(single)
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle: &str, jobs: &[(&str, Location)]) -> RouteContext {
+    let activities =
+        jobs.iter().map(|(id, location)| create_activity_with_job_at_location(create_single(id), *location)).collect();
+    let mut route = create_route_with_activities(fleet, vehicle, activities);
+
+    // NOTE schedules are updated by transport module which is not used here
+    let start = route.tour.start().unwrap();
+    let init = (start.place.location, start.schedule.departure);
+    route.tour.all_activities_mut().skip(1).fold(init, |(location, departure), activity| {
+        let arrival = departure + (activity.place.location as f64 - location as f64).abs();
+        activity.schedule.arrival = arrival;
+        activity.schedule.departure = arrival.max(activity.place.time.start) + activity.place.duration;
+
+        (activity.place.location, activity.schedule.departure)
+    });
+
+    RouteContext { route: Arc::new(route), state: Arc::new(RouteState::default()) }
+}
+
+fn create_solution_ctx(
+    fleet: &Fleet,
+    v1_jobs: Vec<(&str, Location)>,
+    v2_jobs: Vec<(&str, Location)>,
+) -> SolutionContext {
+    SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![
+            create_route_ctx(fleet, "v1", v1_jobs.as_slice()),
+            create_route_ctx(fleet, "v2", v2_jobs.as_slice()),
+        ],
+        registry: RegistryContext::new(Registry::new(fleet)),
+    }
+}
+
+fn create_test_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_test_module(interval: Duration) -> ReceivingRateModule {
+    ReceivingRateModule::new(
+        vec![(LIMITED_LOCATION, interval)].into_iter().collect(),
+        Arc::new(SimpleActivityCost::default()),
+        Arc::new(LineTransportCost {}),
+        1,
+    )
+}
+
+fn get_job(route_ctx: &RouteContext, id: &str) -> Job {
+    route_ctx.route.tour.jobs().find(|job| job.dimens().get_id().map_or(false, |job_id| job_id == id)).unwrap()
+}
+
+parameterized_test! {can_remove_job_arriving_too_close_to_other_route, (v2_location, interval, is_locked, expected), {
+    can_remove_job_arriving_too_close_to_other_route_impl(v2_location, interval, is_locked, expected);
+}}
+
+can_remove_job_arriving_too_close_to_other_route! {
+    case01_same_arrival: (10, 5., false, (false, true)),
+    case02_locked: (10, 5., true, (true, false)),
+    case03_other_location: (20, 5., false, (true, false)),
+}
+
+fn can_remove_job_arriving_too_close_to_other_route_impl(
+    v2_location: Location,
+    interval: Duration,
+    is_locked: bool,
+    expected: (bool, bool),
+) {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("job1", 10)], vec![("job2", v2_location)]);
+    let job1 = get_job(&solution_ctx.routes[0], "job1");
+    let job2 = get_job(&solution_ctx.routes[1], "job2");
+    if is_locked {
+        solution_ctx.locked.insert(job2.clone());
+    }
+
+    create_test_module(interval).accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.routes[0].route.tour.contains(&job1));
+    let is_in_route = solution_ctx.routes[1].route.tour.contains(&job2);
+    let is_required = solution_ctx.required.contains(&job2);
+    assert_eq!((is_in_route, is_required), expected);
+}
+
+parameterized_test! {can_evaluate_arrival_at_limited_location, (index, target_location, interval, expected), {
+    can_evaluate_arrival_at_limited_location_impl(index, target_location, interval, expected);
+}}
+
+can_evaluate_arrival_at_limited_location! {
+    case01_delay_next_arrival: (0, 20, 25., Some(1)),
+    case02_delay_next_arrival_in_rate: (0, 20, 5., None),
+    case03_not_limited_target: (1, 20, 25., None),
+    case04_target_arrival: (1, 10, 45., Some(1)),
+    case05_target_arrival_in_rate: (1, 10, 35., None),
+}
+
+fn can_evaluate_arrival_at_limited_location_impl(
+    index: usize,
+    target_location: Location,
+    interval: Duration,
+    expected: Option<i32>,
+) {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("job0", 30), ("job1", 10)], vec![("job2", 10)]);
+    let module = create_test_module(interval);
+    module.update_arrivals(&mut solution_ctx);
+    let route_ctx = &solution_ctx.routes[1];
+    let target = create_activity_with_job_at_location(create_single("job3"), target_location);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = module.constraints.first().and_then(|constraint| match constraint {
+        ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(route_ctx, &activity_ctx),
+        _ => None,
+    });
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
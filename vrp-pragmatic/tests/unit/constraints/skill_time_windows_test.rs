@@ -0,0 +1,52 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::Fleet;
+
+parameterized_test! {can_restrict_skill_time_windows, (vehicle_skills, time, expected), {
+    can_restrict_skill_time_windows_impl(vehicle_skills, time, expected);
+}}
+
+can_restrict_skill_time_windows! {
+    case01_skilled_premium: (Some(vec!["premium", "other"]), (10., 20.), None),
+    case02_unskilled_premium: (Some(vec!["other"]), (10., 20.), Some(1)),
+    case03_no_skills_premium: (None, (10., 20.), Some(1)),
+    case04_unskilled_standard: (None, (50., 100.), None),
+}
+
+fn can_restrict_skill_time_windows_impl(vehicle_skills: Option<Vec<&str>>, time: (f64, f64), expected: Option<i32>) {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(skills) = vehicle_skills {
+        vehicle.dimens.set_value("skills", skills.iter().map(|skill| skill.to_string()).collect::<HashSet<_>>());
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx = RouteContext {
+        route: Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        state: Arc::new(RouteState::default()),
+    };
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_value(
+        "skill_tws",
+        vec![(TimeWindow::new(10., 20.), vec!["premium".to_string()].into_iter().collect::<HashSet<_>>())],
+    );
+    let mut target = create_activity_with_job_at_location(Arc::new(single), DEFAULT_JOB_LOCATION);
+    target.place.time = TimeWindow::new(time.0, time.1);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let result = SkillTimeWindowsModule::new(1).constraints.first().and_then(|constraint| match constraint {
+        ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(&route_ctx, &activity_ctx),
+        _ => None,
+    });
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
@@ -0,0 +1,19 @@
+use super::*;
+
+parameterized_test! {can_get_max_density, (timestamps, span, expected), {
+    can_get_max_density_impl(timestamps, span, expected);
+}}
+
+can_get_max_density! {
+    case01: (vec![], 10., 0),
+    case02: (vec![0.], 10., 1),
+    case03: (vec![0., 5., 9.], 10., 3),
+    case04: (vec![0., 5., 10.], 10., 2),
+    case05: (vec![0., 10., 20., 30.], 10., 1),
+    case06: (vec![0., 8., 12., 15., 19., 30.], 10., 3),
+    case07: (vec![5., 5., 5.], 10., 3),
+}
+
+fn can_get_max_density_impl(timestamps: Vec<Timestamp>, span: Duration, expected: usize) {
+    assert_eq!(get_max_density(timestamps.as_slice(), span), expected);
+}
@@ -0,0 +1,217 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::{RegistryContext, RouteState};
+use vrp_core::models::common::{Location, TimeWindow};
+use vrp_core::models::problem::{Fleet, SimpleActivityCost, Single};
+use vrp_core::models::solution::{Activity, Registry};
+
+struct LineTransportCost {}
+
+impl TransportCost for LineTransportCost {
+    fn duration(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+
+    fn distance(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+}
+
+type JobData<'a> = (&'a str, Option<&'a str>, Location);
+
+fn create_single(id: &str, transfer_from: Option<&str>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(id);
+    if let Some(transfer_from) = transfer_from {
+        single.dimens.set_value("transfer_from", transfer_from.to_string());
+    }
+
+    Arc::new(single)
+}
+
+fn create_activity(job: &JobData) -> Activity {
+    let (id, transfer_from, location) = job;
+    create_activity_with_job_at_location(create_single(id, *transfer_from), *location)
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle: &str, jobs: &[JobData]) -> RouteContext {
+    RouteContext {
+        route: Arc::new(create_route_with_activities(fleet, vehicle, jobs.iter().map(create_activity).collect())),
+        state: Arc::new(RouteState::default()),
+    }
+}
+
+fn create_solution_ctx(fleet: &Fleet, v1_jobs: Vec<JobData>, v2_jobs: Vec<JobData>) -> SolutionContext {
+    SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![
+            create_route_ctx(fleet, "v1", v1_jobs.as_slice()),
+            create_route_ctx(fleet, "v2", v2_jobs.as_slice()),
+        ],
+        registry: RegistryContext::new(Registry::new(fleet)),
+    }
+}
+
+fn create_test_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_test_module() -> TransferModule {
+    TransferModule::new(Arc::new(SimpleActivityCost::default()), Arc::new(LineTransportCost {}), 1)
+}
+
+fn get_job(route_ctx: &RouteContext, id: &str) -> Job {
+    route_ctx.route.tour.jobs().find(|job| get_job_id(job).map_or(false, |job_id| job_id == id)).unwrap()
+}
+
+parameterized_test! {can_assign_second_leg_only_with_first_leg_in_other_route, (v1_jobs, v2_jobs, transfer_from, expected), {
+    can_assign_second_leg_only_with_first_leg_in_other_route_impl(v1_jobs, v2_jobs, transfer_from, expected);
+}}
+
+can_assign_second_leg_only_with_first_leg_in_other_route! {
+    case01: (vec![], vec![], None, None),
+    case02: (vec![("leg1", None, 10)], vec![], Some("leg1"), None),
+    case03: (vec![], vec![("leg1", None, 10)], Some("leg1"), Some(1)),
+    case04: (vec![("job1", None, 10)], vec![], Some("leg1"), Some(1)),
+}
+
+fn can_assign_second_leg_only_with_first_leg_in_other_route_impl(
+    v1_jobs: Vec<JobData>,
+    v2_jobs: Vec<JobData>,
+    transfer_from: Option<&str>,
+    expected: Option<i32>,
+) {
+    let fleet = create_test_fleet();
+    let solution_ctx = create_solution_ctx(&fleet, v1_jobs, v2_jobs);
+    let job = Job::Single(create_single("leg2", transfer_from));
+
+    let result = TransferHardRouteConstraint { code: 1 }.evaluate_job(&solution_ctx, &solution_ctx.routes[1], &job);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_remove_second_leg_without_first_leg, (v1_jobs, v2_jobs, is_locked, expected), {
+    can_remove_second_leg_without_first_leg_impl(v1_jobs, v2_jobs, is_locked, expected);
+}}
+
+can_remove_second_leg_without_first_leg! {
+    case01: (vec![("leg1", None, 10)], vec![], false, (true, false)),
+    case02: (vec![], vec![("leg1", None, 10)], false, (false, true)),
+    case03: (vec![], vec![], false, (false, true)),
+    case04: (vec![], vec![], true, (true, false)),
+}
+
+fn can_remove_second_leg_without_first_leg_impl(
+    v1_jobs: Vec<JobData>,
+    v2_jobs: Vec<JobData>,
+    is_locked: bool,
+    expected: (bool, bool),
+) {
+    let fleet = create_test_fleet();
+    let v2_jobs = v2_jobs.into_iter().chain(std::iter::once(("leg2", Some("leg1"), 10))).collect();
+    let mut solution_ctx = create_solution_ctx(&fleet, v1_jobs, v2_jobs);
+    let leg2 = get_job(&solution_ctx.routes[1], "leg2");
+    if is_locked {
+        solution_ctx.locked.insert(leg2.clone());
+    }
+
+    create_test_module().accept_solution_state(&mut solution_ctx);
+
+    let is_in_route = solution_ctx.routes[1].route.tour.contains(&leg2);
+    let is_required = solution_ctx.required.contains(&leg2);
+    assert_eq!((is_in_route, is_required), expected);
+}
+
+parameterized_test! {can_move_second_leg_start_after_first_leg, (v1_jobs, expected), {
+    can_move_second_leg_start_after_first_leg_impl(v1_jobs, expected);
+}}
+
+can_move_second_leg_start_after_first_leg! {
+    case01: (vec![("leg1", None, 10)], 10.),
+    case02: (vec![("job1", None, 20), ("leg1", None, 10)], 30.),
+    case03: (vec![("leg1", None, 10), ("job1", None, 20)], 10.),
+}
+
+fn can_move_second_leg_start_after_first_leg_impl(v1_jobs: Vec<JobData>, expected: f64) {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_solution_ctx(&fleet, v1_jobs, vec![("leg2", Some("leg1"), 5)]);
+    let time_window = solution_ctx.routes[1].route.tour.get(1).unwrap().place.time.clone();
+
+    accept_solution_state(&mut solution_ctx);
+
+    let leg2 = solution_ctx.routes[1].route.tour.get(1).unwrap();
+    assert_eq!(leg2.schedule.departure - leg2.place.duration, expected);
+    assert_eq!(leg2.place.time, time_window);
+}
+
+#[test]
+fn can_move_second_leg_start_back_when_first_leg_is_served_earlier() {
+    let fleet = create_test_fleet();
+    let mut solution_ctx =
+        create_solution_ctx(&fleet, vec![("job1", None, 20), ("leg1", None, 10)], vec![("leg2", Some("leg1"), 5)]);
+    accept_solution_state(&mut solution_ctx);
+    let job1 = get_job(&solution_ctx.routes[0], "job1");
+    solution_ctx.routes[0].route_mut().tour.remove(&job1);
+
+    accept_solution_state(&mut solution_ctx);
+
+    let leg2 = solution_ctx.routes[1].route.tour.get(1).unwrap();
+    assert_eq!(leg2.schedule.departure - leg2.place.duration, 10.);
+}
+
+fn accept_solution_state(solution_ctx: &mut SolutionContext) {
+    create_test_module().accept_solution_state(solution_ctx);
+    TransferScheduleModule::new(Arc::new(SimpleActivityCost::default()), Arc::new(LineTransportCost {}))
+        .accept_solution_state(solution_ctx);
+}
+
+parameterized_test! {can_evaluate_transfer_activity, (route_index, index, target, tw_end, expected), {
+    can_evaluate_transfer_activity_impl(route_index, index, target, tw_end, expected);
+}}
+
+can_evaluate_transfer_activity! {
+    case01_delay_first_leg: (0, 0, ("job2", None, 20), 1000., Some(1)),
+    case02_keep_first_leg: (0, 1, ("job2", None, 20), 1000., None),
+    case03_wait_first_leg: (1, 0, ("leg3", Some("leg1"), 5), 1000., None),
+    case04_miss_first_leg: (1, 0, ("leg3", Some("leg1"), 5), 8., Some(1)),
+    case05_no_first_leg: (1, 0, ("leg3", Some("job2"), 5), 1000., Some(1)),
+}
+
+fn can_evaluate_transfer_activity_impl(
+    route_index: usize,
+    index: usize,
+    target: JobData,
+    tw_end: f64,
+    expected: Option<i32>,
+) {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("leg1", None, 10)], vec![("leg2", Some("leg1"), 10)]);
+    create_test_module().accept_solution_state(&mut solution_ctx);
+    let route_ctx = &solution_ctx.routes[route_index];
+    let mut target = create_activity(&target);
+    target.place.time = TimeWindow::new(0., tw_end);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = TransferHardActivityConstraint {
+        code: 1,
+        activity: Arc::new(SimpleActivityCost::default()),
+        transport: Arc::new(LineTransportCost {}),
+    }
+    .evaluate_activity(route_ctx, &activity_ctx);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
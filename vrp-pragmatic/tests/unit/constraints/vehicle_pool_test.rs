@@ -0,0 +1,161 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::{RegistryContext, RouteState};
+use vrp_core::models::common::{IdDimension, Location, TimeWindow};
+use vrp_core::models::problem::{Fleet, SimpleActivityCost, Single};
+use vrp_core::models::solution::Registry;
+
+struct LineTransportCost {}
+
+impl TransportCost for LineTransportCost {
+    fn duration(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+
+    fn distance(&self, _: i32, from: Location, to: Location, _: f64) -> f64 {
+        (to as f64 - from as f64).abs()
+    }
+}
+
+fn create_single(id: &str) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(id);
+
+    Arc::new(single)
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle: &str, jobs: &[(&str, Location)]) -> RouteContext {
+    let activities =
+        jobs.iter().map(|(id, location)| create_activity_with_job_at_location(create_single(id), *location)).collect();
+    let mut route = create_route_with_activities(fleet, vehicle, activities);
+
+    // NOTE schedules are updated by transport module which is not used here
+    let start = route.tour.start().unwrap();
+    let init = (start.place.location, start.schedule.departure);
+    route.tour.all_activities_mut().skip(1).fold(init, |(location, departure), activity| {
+        let arrival = departure + (activity.place.location as f64 - location as f64).abs();
+        activity.schedule.arrival = arrival;
+        activity.schedule.departure = arrival.max(activity.place.time.start) + activity.place.duration;
+
+        (activity.place.location, activity.schedule.departure)
+    });
+
+    RouteContext { route: Arc::new(route), state: Arc::new(RouteState::default()) }
+}
+
+fn create_solution_ctx(
+    fleet: &Fleet,
+    v1_jobs: Vec<(&str, Location)>,
+    v2_jobs: Vec<(&str, Location)>,
+) -> SolutionContext {
+    SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![
+            create_route_ctx(fleet, "v1", v1_jobs.as_slice()),
+            create_route_ctx(fleet, "v2", v2_jobs.as_slice()),
+        ],
+        registry: RegistryContext::new(Registry::new(fleet)),
+    }
+}
+
+fn create_test_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_test_module(size: usize) -> VehiclePoolModule {
+    VehiclePoolModule::new(size, Arc::new(SimpleActivityCost::default()), Arc::new(LineTransportCost {}), 1)
+}
+
+fn get_job(route_ctx: &RouteContext, id: &str) -> Job {
+    route_ctx.route.tour.jobs().find(|job| job.dimens().get_id().into_iter().any(|job_id| job_id == id)).unwrap()
+}
+
+parameterized_test! {can_remove_jobs_of_route_exceeding_pool, (size, is_locked, expected), {
+    can_remove_jobs_of_route_exceeding_pool_impl(size, is_locked, expected);
+}}
+
+can_remove_jobs_of_route_exceeding_pool! {
+    case01_exceeded: (1, false, (false, true)),
+    case02_locked: (1, true, (true, false)),
+    case03_not_exceeded: (2, false, (true, false)),
+}
+
+fn can_remove_jobs_of_route_exceeding_pool_impl(size: usize, is_locked: bool, expected: (bool, bool)) {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("job1", 10)], vec![("job2", 20)]);
+    let job1 = get_job(&solution_ctx.routes[0], "job1");
+    let job2 = get_job(&solution_ctx.routes[1], "job2");
+    if is_locked {
+        solution_ctx.locked.insert(job2.clone());
+    }
+
+    create_test_module(size).accept_solution_state(&mut solution_ctx);
+
+    assert!(solution_ctx.routes[0].route.tour.contains(&job1));
+    let is_in_route = solution_ctx.routes[1].route.tour.contains(&job2);
+    let is_required = solution_ctx.required.contains(&job2);
+    assert_eq!((is_in_route, is_required), expected);
+}
+
+parameterized_test! {can_evaluate_route_activity_overlap, (size, time_start, expected), {
+    can_evaluate_route_activity_overlap_impl(size, time_start, expected);
+}}
+
+can_evaluate_route_activity_overlap! {
+    case01_overlap: (1, 0., Some(1)),
+    case02_overlap_in_pool: (2, 0., None),
+    case03_after_other_route_end: (1, 100., None),
+}
+
+fn can_evaluate_route_activity_overlap_impl(size: usize, time_start: f64, expected: Option<i32>) {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_solution_ctx(&fleet, vec![("job1", 10)], vec![]);
+    let module = create_test_module(size);
+    module.update_intervals(&mut solution_ctx);
+    let route_ctx = &solution_ctx.routes[1];
+    let mut target = create_activity_with_job_at_location(create_single("job2"), 5);
+    target.place.time = TimeWindow::new(time_start, 1000.);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let result = module.constraints.first().and_then(|constraint| match constraint {
+        ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(route_ctx, &activity_ctx),
+        _ => None,
+    });
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_get_max_active_routes, (intervals, expected), {
+    can_get_max_active_routes_impl(intervals, expected);
+}}
+
+can_get_max_active_routes! {
+    case01_no_intervals: (vec![], 0),
+    case02_overlap: (vec![(0., 10.), (5., 15.)], 2),
+    case03_touch: (vec![(0., 5.), (5., 15.)], 1),
+    case04_outside: (vec![(0., 1.), (20., 30.)], 0),
+}
+
+fn can_get_max_active_routes_impl(intervals: Vec<(f64, f64)>, expected: usize) {
+    let fleet = create_test_fleet();
+    let intervals =
+        intervals.into_iter().map(|(start, end)| (fleet.actors[0].clone(), start, end)).collect::<Intervals>();
+
+    let result = get_max_active(&intervals, &fleet.actors[1], 2., 18.);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,40 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_problem(escalation: Option<PriorityEscalation>, priority: Option<i32>, age: Option<usize>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![Job { priority, age, ..create_delivery_job("job1", vec![1., 0.]) }], relations: None },
+        config: Some(Config { priority_escalation: escalation, ..Default::default() }),
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_escalate_priorities, (escalation, priority, age, expected), {
+    can_escalate_priorities_impl(escalation, priority, age, expected);
+}}
+
+can_escalate_priorities! {
+    case01_no_escalation: (None, Some(5), Some(10), Some(5)),
+    case02_no_age: (Some((2, None)), Some(5), None, Some(5)),
+    case03_young_job: (Some((2, None)), Some(5), Some(1), Some(5)),
+    case04_one_level: (Some((2, None)), Some(5), Some(3), Some(4)),
+    case05_many_levels: (Some((1, None)), Some(5), Some(3), Some(2)),
+    case06_limited_by_default_max: (Some((1, None)), Some(5), Some(10), Some(1)),
+    case07_limited_by_max: (Some((1, Some(3))), Some(5), Some(10), Some(3)),
+    case08_already_above_max: (Some((1, Some(3))), Some(2), Some(10), Some(2)),
+    case09_no_priority: (Some((1, None)), None, Some(10), None),
+}
+
+fn can_escalate_priorities_impl(
+    escalation: Option<(usize, Option<i32>)>,
+    priority: Option<i32>,
+    age: Option<usize>,
+    expected: Option<i32>,
+) {
+    let escalation = escalation.map(|(cycles, max_priority)| PriorityEscalation { cycles, max_priority });
+    let problem = create_problem(escalation, priority, age);
+
+    let problem = escalate_priorities(problem);
+
+    assert_eq!(problem.plan.jobs[0].priority, expected);
+}
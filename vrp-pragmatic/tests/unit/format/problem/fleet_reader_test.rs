@@ -3,6 +3,7 @@ use crate::format::problem::*;
 use crate::format_time;
 use crate::helpers::*;
 use vrp_core::models::common::{Distance, Timestamp};
+use vrp_core::models::problem::UNREACHABLE_VALUE;
 
 fn matrix(profile: Option<&str>, timestamp: Option<f64>, fill_value: i64, size: usize) -> Matrix {
     Matrix {
@@ -11,6 +12,10 @@ fn matrix(profile: Option<&str>, timestamp: Option<f64>, fill_value: i64, size:
         travel_times: vec![fill_value; size],
         distances: vec![fill_value; size],
         error_codes: None,
+        default_travel_time: None,
+        default_distance: None,
+        time_unit: None,
+        unreachable_value: None,
     }
 }
 
@@ -21,6 +26,10 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
         travel_times: vec![1; 4],
         distances: vec![2; 3],
         error_codes: None,
+        default_travel_time: None,
+        default_distance: None,
+        time_unit: None,
+        unreachable_value: None,
     }
 }
 
@@ -161,3 +170,40 @@ fn can_create_transport_costs_positive_cases_impl(
         assert_eq!(result, distance);
     });
 }
+
+#[test]
+fn can_use_defaults_for_missing_matrix_cells() {
+    let problem = create_problem(&["car"]);
+    let matrix = Matrix {
+        travel_times: vec![0, -1, 1, 0],
+        distances: vec![0, -1, 2, 0],
+        default_travel_time: Some(100),
+        default_distance: Some(200),
+        ..matrix(Some("car"), None, 0, 4)
+    };
+
+    let transport = create_transport_costs(&problem, &[matrix]).unwrap();
+
+    assert_eq!(transport.duration(0, 0, 1, 0.), 100.);
+    assert_eq!(transport.distance(0, 0, 1, 0.), 200.);
+    assert_eq!(transport.duration(0, 1, 0, 0.), 1.);
+    assert_eq!(transport.distance(0, 1, 0, 0.), 2.);
+}
+
+#[test]
+fn can_mark_unreachable_matrix_cells_by_sentinel_value() {
+    let problem = create_problem(&["car"]);
+    let matrix = Matrix {
+        travel_times: vec![0, -1, 1, 0],
+        distances: vec![0, 3, 2, 0],
+        unreachable_value: Some(-1),
+        ..matrix(Some("car"), None, 0, 4)
+    };
+
+    let transport = create_transport_costs(&problem, &[matrix]).unwrap();
+
+    assert!(transport.is_unreachable(0, 0, 1, 0.));
+    assert_eq!(transport.distance(0, 0, 1, 0.), UNREACHABLE_VALUE);
+    assert!(!transport.is_unreachable(0, 1, 0, 0.));
+    assert_eq!(transport.distance(0, 1, 0, 0.), 2.);
+}
@@ -0,0 +1,113 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_horizon(days: usize, fleets: Option<Vec<DayFleet>>) -> Problem {
+    Problem {
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle1"), create_default_vehicle("vehicle2")],
+            profiles: create_default_profiles(),
+        },
+        config: Some(Config { horizon: Some(PlanningHorizon { days, fleets, vehicles: None }), ..Default::default() }),
+        ..create_empty_problem()
+    }
+}
+
+fn get_shift_starts(problem: &Problem, type_id: &str) -> Vec<String> {
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .find(|vehicle| vehicle.type_id == type_id)
+        .unwrap()
+        .shifts
+        .iter()
+        .map(|shift| shift.start.earliest.clone())
+        .collect()
+}
+
+parameterized_test! {can_expand_horizon, (fleets, expected_days), {
+    can_expand_horizon_impl(fleets, expected_days);
+}}
+
+can_expand_horizon! {
+    case01: (None, (vec![0, 1, 2], vec![0, 1, 2])),
+    case02: (Some(vec![DayFleet { day: 1, type_ids: vec!["vehicle1".to_string()] }]), (vec![0, 1, 2], vec![0, 2])),
+    case03: (Some(vec![
+        DayFleet { day: 0, type_ids: vec!["vehicle2".to_string()] },
+        DayFleet { day: 2, type_ids: vec!["vehicle1".to_string(), "vehicle2".to_string()] },
+    ]), (vec![1, 2], vec![0, 1, 2])),
+}
+
+fn can_expand_horizon_impl(fleets: Option<Vec<DayFleet>>, expected_days: (Vec<usize>, Vec<usize>)) {
+    let get_expected_starts =
+        |days: Vec<usize>| days.into_iter().map(|day| format_time(day as f64 * 86400.)).collect::<Vec<_>>();
+
+    let problem = expand_horizon(create_problem_with_horizon(3, fleets));
+
+    assert_eq!(get_shift_starts(&problem, "vehicle1"), get_expected_starts(expected_days.0.clone()));
+    assert_eq!(get_shift_starts(&problem, "vehicle2"), get_expected_starts(expected_days.1.clone()));
+    problem.fleet.vehicles.iter().zip(vec![expected_days.0, expected_days.1]).for_each(|(vehicle, expected_days)| {
+        let days = (0..vehicle.shifts.len())
+            .map(|shift_index| get_shift_day(&problem, vehicle, shift_index).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(days, expected_days);
+    });
+}
+
+#[test]
+fn can_move_shift_times_within_horizon() {
+    let mut problem = create_problem_with_horizon(2, None);
+    problem.fleet.vehicles[0].shifts[0].breaks = Some(vec![
+        VehicleBreak {
+            time: VehicleBreakTime::TimeWindow(vec![format_time(10.), format_time(20.)]),
+            duration: 5.,
+            locations: None,
+        },
+        VehicleBreak { time: VehicleBreakTime::TimeOffset(vec![10., 20.]), duration: 5., locations: None },
+    ]);
+    problem.fleet.vehicles[0].shifts[0].reloads = Some(vec![VehicleCargoPlace {
+        location: vec![0., 0.].to_loc(),
+        duration: 2.,
+        times: Some(vec![vec![format_time(30.), format_time(40.)]]),
+        tag: None,
+    }]);
+
+    let problem = expand_horizon(problem);
+
+    let shift = problem.fleet.vehicles[0].shifts.last().unwrap();
+    assert_eq!(shift.end.as_ref().unwrap().latest, format_time(86400. + 1000.));
+    let breaks = shift.breaks.as_ref().unwrap();
+    assert!(matches!(&breaks[0].time, VehicleBreakTime::TimeWindow(tw)
+        if *tw == vec![format_time(86400. + 10.), format_time(86400. + 20.)]));
+    assert!(matches!(&breaks[1].time, VehicleBreakTime::TimeOffset(offset) if *offset == vec![10., 20.]));
+    assert_eq!(
+        shift.reloads.as_ref().unwrap()[0].times,
+        Some(vec![vec![format_time(86400. + 30.), format_time(86400. + 40.)]])
+    );
+}
+
+#[test]
+fn can_keep_problem_without_horizon() {
+    let problem = Problem {
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+
+    let problem = expand_horizon(problem);
+
+    assert_eq!(problem.fleet.vehicles[0].shifts.len(), 1);
+    assert_eq!(get_shift_day(&problem, &problem.fleet.vehicles[0], 0), None);
+}
+
+#[test]
+fn can_check_vehicle_working_days() {
+    let mut problem = create_problem_with_horizon(3, None);
+    problem.config.as_mut().and_then(|config| config.horizon.as_mut()).unwrap().vehicles =
+        Some(vec![VehicleDays { vehicle_id: "vehicle1_1".to_string(), days: vec![0, 2] }]);
+
+    assert!(is_vehicle_working_day(&problem, "vehicle1_1", 0));
+    assert!(!is_vehicle_working_day(&problem, "vehicle1_1", 1));
+    assert!(is_vehicle_working_day(&problem, "vehicle1_1", 2));
+    assert!((0..3).all(|day| is_vehicle_working_day(&problem, "vehicle2_1", day)));
+}
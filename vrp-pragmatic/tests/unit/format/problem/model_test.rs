@@ -64,3 +64,85 @@ fn can_deserialize_matrix() {
     assert_eq!(matrix.distances.len(), 16);
     assert_eq!(matrix.travel_times.len(), 16);
 }
+
+fn split_problem_json(problem: &str) -> (String, String) {
+    let mut jobs = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(problem).unwrap();
+    let fleet = jobs.remove("fleet").unwrap();
+
+    (fleet.to_string(), serde_json::Value::Object(jobs).to_string())
+}
+
+#[test]
+fn can_deserialize_split_problem() {
+    let (fleet, jobs) = split_problem_json(SIMPLE_PROBLEM);
+
+    let problem =
+        deserialize_split_problem(BufReader::new(fleet.as_bytes()), BufReader::new(jobs.as_bytes())).ok().unwrap();
+
+    let expected = deserialize_problem(BufReader::new(SIMPLE_PROBLEM.as_bytes())).ok().unwrap();
+    assert_eq!(serde_json::to_value(&problem).unwrap(), serde_json::to_value(&expected).unwrap());
+}
+
+parameterized_test! {can_detect_invalid_split_problem, (fleet, jobs, expected_input), {
+    can_detect_invalid_split_problem_impl(fleet, jobs, expected_input);
+}}
+
+can_detect_invalid_split_problem! {
+    case01_invalid_fleet: (r#"{"vehicles": []}"#, r#"{"plan": {"jobs": []}}"#, "fleet"),
+    case02_invalid_jobs: (r#"{"vehicles": [], "profiles": []}"#, r#"{"jobs": []}"#, "jobs"),
+}
+
+fn can_detect_invalid_split_problem_impl(fleet: &str, jobs: &str, expected_input: &str) {
+    let result = deserialize_split_problem(BufReader::new(fleet.as_bytes()), BufReader::new(jobs.as_bytes()));
+
+    let errors = result.err().unwrap();
+    assert_eq!(errors.first().unwrap().code, "E0000");
+    assert!(errors.first().unwrap().action.starts_with(&format!("check input {} json", expected_input)));
+}
+
+#[test]
+fn can_deserialize_split_matrices() {
+    let distances = vec![
+        r#"{"profile": "truck", "distances": [0, 2, 2, 0]}"#,
+        r#"{"profile": "car", "distances": [0, 1, 1, 0], "defaultDistance": 10}"#,
+    ];
+    let durations = vec![
+        r#"{"profile": "car", "travelTimes": [0, 3, 3, 0]}"#,
+        r#"{"profile": "truck", "durations": [0, 4, 4, 0]}"#,
+    ];
+
+    let matrices = deserialize_split_matrices(
+        distances.into_iter().map(|json| BufReader::new(json.as_bytes())).collect(),
+        durations.into_iter().map(|json| BufReader::new(json.as_bytes())).collect(),
+    )
+    .ok()
+    .unwrap();
+
+    assert_eq!(matrices.len(), 2);
+    let truck = matrices.iter().find(|matrix| matrix.profile.as_deref() == Some("truck")).unwrap();
+    assert_eq!(truck.distances, vec![0, 2, 2, 0]);
+    assert_eq!(truck.travel_times, vec![0, 4, 4, 0]);
+    let car = matrices.iter().find(|matrix| matrix.profile.as_deref() == Some("car")).unwrap();
+    assert_eq!(car.distances, vec![0, 1, 1, 0]);
+    assert_eq!(car.travel_times, vec![0, 3, 3, 0]);
+    assert_eq!(car.default_distance, Some(10));
+}
+
+parameterized_test! {can_detect_unpaired_split_matrices, (distances, durations), {
+    can_detect_unpaired_split_matrices_impl(distances, durations);
+}}
+
+can_detect_unpaired_split_matrices! {
+    case01_different_size: (vec![r#"{"profile": "car", "distances": [0]}"#], vec![]),
+    case02_different_profile: (vec![r#"{"profile": "car", "distances": [0]}"#], vec![r#"{"profile": "truck", "travelTimes": [0]}"#]),
+    case03_missing_durations: (vec![r#"{"profile": "car", "distances": [0]}"#], vec![r#"{"profile": "car", "distances": [0]}"#]),
+}
+
+fn can_detect_unpaired_split_matrices_impl(distances: Vec<&str>, durations: Vec<&str>) {
+    let result = deserialize_split_matrices(
+        distances.into_iter().map(|json| BufReader::new(json.as_bytes())).collect(),
+        durations.into_iter().map(|json| BufReader::new(json.as_bytes())).collect(),
+    );
+
+    assert_eq!(result.err().map(|errors| errors.first().unwrap().code.clone()), Some("E0001".to_string()));
+}
@@ -7,6 +7,7 @@ use std::iter::FromIterator;
 use std::sync::Arc;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Jobs, Multi, Place, Single};
+use vrp_core::solver::{DurationDistribution, DURATION_DISTRIBUTION_KEY};
 
 fn get_job(index: usize, jobs: &Jobs) -> vrp_core::models::problem::Job {
     jobs.all().collect::<Vec<_>>().get(index).unwrap().clone()
@@ -69,14 +70,30 @@ fn can_read_complex_problem() {
                             ]),
                             location: vec![52.48325, 13.4436].to_loc(),
                             duration: 100.0,
+                            relative_times: None,
+                            opening_times: None,
+                            grace: None,
+                            time_penalties: None,
+                            skill_times: None,
                         }],
                         demand: Some(vec![0, 1]),
                         tag: Some("my_delivery".to_string()),
+                        type_durations: None,
+                        variants: None,
                     }]),
                     replacements: None,
                     services: None,
                     priority: None,
                     skills: Some(vec!["unique".to_string()]),
+                    hard_time_window: None,
+                    companions: None,
+                    transfer_from: None,
+                    service_time_distribution: None,
+                    corridor: None,
+                    crew_size: None,
+                    workload: None,
+                    vehicle_affinity: None,
+                    age: None,
                 },
                 Job {
                     id: "pickup_delivery_job".to_string(),
@@ -88,9 +105,16 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48300, 13.4420].to_loc(),
                             duration: 110.0,
+                            relative_times: None,
+                            opening_times: None,
+                            grace: None,
+                            time_penalties: None,
+                            skill_times: None,
                         }],
                         demand: Some(vec![2]),
                         tag: None,
+                        type_durations: None,
+                        variants: None,
                     }]),
                     deliveries: Some(vec![JobTask {
                         places: vec![JobPlace {
@@ -100,14 +124,30 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48325, 13.4436].to_loc(),
                             duration: 120.0,
+                            relative_times: None,
+                            opening_times: None,
+                            grace: None,
+                            time_penalties: None,
+                            skill_times: None,
                         }],
                         demand: Some(vec![2]),
                         tag: None,
+                        type_durations: None,
+                        variants: None,
                     }]),
                     replacements: None,
                     services: None,
                     priority: None,
                     skills: None,
+                    hard_time_window: None,
+                    companions: None,
+                    transfer_from: None,
+                    service_time_distribution: None,
+                    corridor: None,
+                    crew_size: None,
+                    workload: None,
+                    vehicle_affinity: None,
+                    age: None,
                 },
                 Job {
                     id: "pickup_job".to_string(),
@@ -120,15 +160,31 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48321, 13.4438].to_loc(),
                             duration: 90.0,
+                            relative_times: None,
+                            opening_times: None,
+                            grace: None,
+                            time_penalties: None,
+                            skill_times: None,
                         }],
                         demand: Some(vec![3]),
                         tag: None,
+                        type_durations: None,
+                        variants: None,
                     }]),
                     deliveries: None,
                     replacements: None,
                     services: None,
                     priority: None,
                     skills: Some(vec!["unique2".to_string()]),
+                    hard_time_window: None,
+                    companions: None,
+                    transfer_from: None,
+                    service_time_distribution: None,
+                    corridor: None,
+                    crew_size: None,
+                    workload: None,
+                    vehicle_affinity: None,
+                    age: None,
                 },
             ],
             relations: Option::None,
@@ -138,17 +194,27 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: "car".to_string(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts {
+                    fixed: Some(100.),
+                    distance: 1.,
+                    time: 2.,
+                    distance_tiers: None,
+                    max_daily_cost: None,
+                    load_distance: None,
+                },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: None,
                         location: vec![52.4862, 13.45148].to_loc(),
+                        alternatives: None,
+                        dock_slot: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "1970-01-01T00:01:40Z".to_string(),
                         location: vec![52.4862, 13.45148].to_loc(),
+                        max_overtime: None,
                     }),
                     depots: None,
                     breaks: Some(vec![VehicleBreak {
@@ -160,10 +226,28 @@ fn can_read_complex_problem() {
                         locations: Some(vec![vec![52.48315, 13.4330].to_loc()]),
                     }]),
                     reloads: None,
+                    anchors: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), shift_time: Some(100.), allowed_areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    shift_time: Some(100.),
+                    allowed_areas: None,
+                    max_time_window_jobs: None,
+                    max_reloads: None,
+                    max_hourly_time_window_jobs: None,
+                    max_late_stops: None,
+                    max_first_leg_distance: None,
+                }),
+                range: None,
+                aggregate_capacity: None,
+                consumption: None,
+                crew_size: None,
+                loading_discipline: None,
+                reload_handling_time: None,
+                maneuvering_time: None,
+                capacity_decay: None,
             }],
             profiles: create_default_profiles(),
         },
@@ -176,6 +260,10 @@ fn can_read_complex_problem() {
         travel_times: vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
         distances: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
         error_codes: Option::None,
+        default_travel_time: None,
+        default_distance: None,
+        time_unit: None,
+        unreachable_value: None,
     };
 
     let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
@@ -310,3 +398,137 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+parameterized_test! {can_create_approximation_matrices_with_coordinate_system, (coordinate_system, expected), {
+    can_create_approximation_matrices_with_coordinate_system_impl(coordinate_system, expected);
+}}
+
+can_create_approximation_matrices_with_coordinate_system! {
+    case01_default: (None, 5078),
+    case02_geographic: (Some(CoordinateSystem::Geographic), 5078),
+    case03_projected: (Some(CoordinateSystem::Projected), 5000),
+}
+
+fn can_create_approximation_matrices_with_coordinate_system_impl(
+    coordinate_system: Option<CoordinateSystem>,
+    expected: i64,
+) {
+    let locations = match coordinate_system {
+        Some(CoordinateSystem::Projected) => (vec![5_800_000., 390_000.], vec![5_804_000., 393_000.]),
+        _ => (vec![52.52599, 13.45413], vec![52.5165, 13.3808]),
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", locations.0), create_delivery_job("job2", locations.1)],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![], profiles: create_default_profiles() },
+        config: Some(Config { coordinate_system, ..Default::default() }),
+        ..create_empty_problem()
+    };
+
+    let matrices = create_approx_matrices(&problem);
+
+    assert_eq!(matrices.len(), 1);
+    assert_eq!(matrices[0].distances, &[0, expected, expected, 0]);
+}
+
+parameterized_test! {can_read_opening_times, (times, opening_times, unit, expected), {
+    can_read_opening_times_impl(times, opening_times, unit, expected);
+}}
+
+can_read_opening_times! {
+    case01_only_opening: (None, vec![("1970-01-01T09:00:00Z", 10800.)], None, vec![(32400., 43200.)]),
+    case02_multiple_openings: (
+        None,
+        vec![("1970-01-01T09:00:00Z", 3600.), ("1970-01-01T14:00:00Z", 1800.)],
+        None,
+        vec![(32400., 36000.), (50400., 52200.)]
+    ),
+    case03_with_times: (
+        Some(vec!["1970-01-01T00:00:00Z", "1970-01-01T01:00:00Z"]),
+        vec![("1970-01-01T09:00:00Z", 10800.)],
+        None,
+        vec![(0., 3600.), (32400., 43200.)]
+    ),
+    case04_with_unit: (None, vec![("1970-01-01T09:00:00Z", 180.)], Some(TimeUnit::Minutes), vec![(32400., 43200.)]),
+}
+
+fn can_read_opening_times_impl(
+    times: Option<Vec<&str>>,
+    opening_times: Vec<(&str, f64)>,
+    unit: Option<TimeUnit>,
+    expected: Vec<(f64, f64)>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        times: times.map(|times| vec![times.iter().map(|time| time.to_string()).collect()]),
+                        opening_times: Some(
+                            opening_times
+                                .into_iter()
+                                .map(|(open, duration)| OpeningTime { open: open.to_string(), duration })
+                                .collect(),
+                        ),
+                        ..create_job_place(vec![1., 0.])
+                    }],
+                    demand: Some(vec![1]),
+                    tag: None,
+                    type_durations: None,
+                    variants: None,
+                }]),
+                ..create_job("job1")
+            }],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        config: unit.map(|unit| Config {
+            time_windows: Some(TimeWindowsConfig { soft: false, lateness_penalty: None, unit: Some(unit) }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = Matrix { time_unit: unit, ..create_matrix_from_problem(&problem) };
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let job = get_single_job(0, problem.jobs.as_ref());
+    assert_time_spans(&get_single_place(job.as_ref()).times, expected);
+    assert_eq!(job.dimens.get_value::<bool>("has_tws").cloned(), Some(true));
+}
+
+#[test]
+fn can_read_service_time_distribution() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    service_time_distribution: Some(ServiceTimeDistribution::Normal { mean: 10., std_dev: 2. }),
+                    corridor: None,
+                    ..create_delivery_job("job1", vec![1., 0.])
+                },
+                create_delivery_job("job2", vec![2., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let get_distribution = |index: usize| {
+        get_single_job(index, problem.jobs.as_ref())
+            .dimens
+            .get_value::<DurationDistribution>(DURATION_DISTRIBUTION_KEY)
+            .cloned()
+    };
+    match get_distribution(0) {
+        Some(DurationDistribution::Normal { mean, std_dev }) => assert_eq!((mean, std_dev), (10., 2.)),
+        _ => unreachable!(),
+    }
+    assert!(get_distribution(1).is_none());
+}
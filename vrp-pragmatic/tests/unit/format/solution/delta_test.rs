@@ -0,0 +1,93 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_tour(vehicle_id: &str, job_ids: Vec<&str>) -> Tour {
+    Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "my_vehicle".to_string(),
+        shift_index: 0,
+        stops: job_ids
+            .into_iter()
+            .enumerate()
+            .map(|(idx, job_id)| create_stop_with_activity(job_id, "delivery", (idx as f64, 0.), 0, ("", ""), 0))
+            .collect(),
+        statistic: Statistic::default(),
+        consumption: None,
+        day: None,
+    }
+}
+
+fn create_solution(tours: Vec<Tour>, unassigned: Vec<&str>) -> Solution {
+    Solution {
+        tours,
+        unassigned: if unassigned.is_empty() {
+            None
+        } else {
+            Some(
+                unassigned
+                    .into_iter()
+                    .map(|job_id| UnassignedJob { job_id: job_id.to_string(), reasons: vec![] })
+                    .collect(),
+            )
+        },
+        ..create_empty_solution()
+    }
+}
+
+fn create_key(vehicle_id: &str) -> TourKey {
+    TourKey { vehicle_id: vehicle_id.to_string(), shift_index: 0, day: None }
+}
+
+fn create_baseline_and_solution() -> (Solution, Solution) {
+    let baseline = create_solution(
+        vec![create_tour("v1", vec!["job1", "job2"]), create_tour("v2", vec!["job3"]), create_tour("v4", vec!["job5"])],
+        vec!["job4"],
+    );
+    let solution = create_solution(
+        vec![create_tour("v1", vec!["job2", "job1"]), create_tour("v2", vec!["job3"]), create_tour("v3", vec!["job4"])],
+        vec!["job5"],
+    );
+
+    (baseline, solution)
+}
+
+#[test]
+fn can_create_delta_with_changed_tours_only() {
+    let (baseline, solution) = create_baseline_and_solution();
+
+    let delta = create_solution_delta(&baseline, &solution);
+
+    assert_eq!(delta.tours, vec![create_key("v1"), create_key("v2"), create_key("v3")]);
+    assert_eq!(delta.changed_tours.iter().map(|tour| tour.vehicle_id.as_str()).collect::<Vec<_>>(), vec!["v1", "v3"]);
+    assert_eq!(
+        delta.job_changes,
+        vec![
+            JobChange { job_id: "job4".to_string(), from: None, to: Some(create_key("v3")) },
+            JobChange { job_id: "job5".to_string(), from: Some(create_key("v4")), to: None },
+        ]
+    );
+}
+
+#[test]
+fn can_reconstruct_solution_from_delta() {
+    let (baseline, solution) = create_baseline_and_solution();
+    let delta = create_solution_delta(&baseline, &solution);
+    let mut buffer = Vec::new();
+    serialize_solution_delta(BufWriter::new(&mut buffer), &delta).unwrap();
+    let delta = deserialize_solution_delta(BufReader::new(buffer.as_slice())).unwrap();
+
+    let result = apply_solution_delta(&baseline, &delta);
+
+    assert_eq!(result, Ok(solution));
+}
+
+#[test]
+fn can_detect_missing_tour_when_applying_delta() {
+    let (baseline, solution) = create_baseline_and_solution();
+    let delta = create_solution_delta(&baseline, &solution);
+    let other_baseline = create_solution(vec![create_tour("v1", vec!["job1", "job2"])], vec![]);
+
+    let result = apply_solution_delta(&other_baseline, &delta);
+
+    assert_eq!(result, Err("cannot find tour of vehicle 'v2' with shift index 0".to_string()));
+}
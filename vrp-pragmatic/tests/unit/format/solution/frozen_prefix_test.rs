@@ -0,0 +1,56 @@
+use super::*;
+use crate::format::solution::{Statistic, Tour};
+use crate::helpers::*;
+
+fn create_tour(vehicle_id: &str, job_ids: &[&str]) -> Tour {
+    let time = ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z");
+    let stops = std::iter::once(create_stop_with_activity("departure", "departure", (0., 0.), 0, time, 0))
+        .chain(job_ids.iter().map(|job_id| create_stop_with_activity(job_id, "delivery", (1., 0.), 0, time, 1)))
+        .chain(std::iter::once(create_stop_with_activity("arrival", "arrival", (0., 0.), 0, time, 2)))
+        .collect();
+
+    Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "my_vehicle".to_string(),
+        shift_index: 0,
+        stops,
+        statistic: Statistic::default(),
+        consumption: None,
+        day: None,
+    }
+}
+
+parameterized_test! {can_create_frozen_prefix_relations, (prefix_sizes, default_size, expected), {
+    can_create_frozen_prefix_relations_impl(prefix_sizes, default_size, expected);
+}}
+
+can_create_frozen_prefix_relations! {
+    case01_default: (vec![], 2, vec![
+        ("v1", vec!["departure", "job1", "job2"]), ("v2", vec!["departure", "job4", "job5"])
+    ]),
+    case02_per_vehicle: (vec![("v1", 1), ("v2", 0)], 2, vec![("v1", vec!["departure", "job1"])]),
+    case03_more_than_tour: (vec![("v2", 5)], 0, vec![("v2", vec!["departure", "job4", "job5"])]),
+    case04_none: (vec![], 0, vec![]),
+}
+
+fn can_create_frozen_prefix_relations_impl(
+    prefix_sizes: Vec<(&str, usize)>,
+    default_size: usize,
+    expected: Vec<(&str, Vec<&str>)>,
+) {
+    let solution = Solution {
+        tours: vec![create_tour("v1", &["job1", "job2", "job3"]), create_tour("v2", &["job4", "job5"])],
+        ..create_empty_solution()
+    };
+    let prefix_sizes = prefix_sizes.into_iter().map(|(id, size)| (id.to_string(), size)).collect();
+
+    let relations = create_frozen_prefix_relations(&solution, &prefix_sizes, default_size);
+
+    assert_eq!(relations.len(), expected.len());
+    relations.iter().zip(expected.iter()).for_each(|(relation, (vehicle_id, jobs))| {
+        assert!(matches!(relation.type_field, RelationType::Strict));
+        assert_eq!(relation.vehicle_id.as_deref(), Some(*vehicle_id));
+        assert_eq!(relation.shift_index, Some(0));
+        assert_eq!(relation.jobs, to_strings(jobs.clone()));
+    });
+}
@@ -89,6 +89,7 @@ fn can_read_basic_init_solution() {
             distance: 8,
             duration: 14,
             times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 2 },
+            tightness: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -138,6 +139,8 @@ fn can_read_basic_init_solution() {
                                 end: "1970-01-01T00:00:06Z".to_string(),
                             }),
                             job_tag: Some("p2".to_owned()),
+                            distance: None,
+                            duration: None,
                         },
                         Activity {
                             job_id: "break".to_string(),
@@ -148,6 +151,8 @@ fn can_read_basic_init_solution() {
                                 end: "1970-01-01T00:00:08Z".to_string(),
                             }),
                             job_tag: None,
+                            distance: None,
+                            duration: None,
                         },
                     ],
                 },
@@ -174,9 +179,19 @@ fn can_read_basic_init_solution() {
                 distance: 8,
                 duration: 14,
                 times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 2 },
+                tightness: None,
             },
+            consumption: None,
+            day: None,
         }],
         unassigned: create_unassigned_jobs(&["job3"]),
+        extras: Some(Extras {
+            metrics: None,
+            config: None,
+            termination: None,
+            monetary: None,
+            bbox: Some(BoundingBox { min_lat: 0., min_lng: 0., max_lat: 4., max_lng: 0. }),
+        }),
         ..create_empty_solution()
     };
 
@@ -197,6 +212,8 @@ fn can_handle_error_in_init_solution() {
             shift_index: 0,
             stops: vec![],
             statistic: Default::default(),
+            consumption: None,
+            day: None,
         }],
         unassigned: None,
         violations: None,
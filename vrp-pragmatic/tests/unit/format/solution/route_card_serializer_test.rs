@@ -0,0 +1,83 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn serialize_route_card(solution: &Solution) -> String {
+    let mut buffer = Vec::new();
+    serialize_solution_as_route_card(BufWriter::new(&mut buffer), solution).unwrap();
+
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn can_serialize_tour_as_route_card() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    let route_card = serialize_route_card(&solution);
+
+    assert_eq!(
+        route_card,
+        "Vehicle: my_vehicle_1 (shift 0)\n\
+         Shift: 1970-01-01T00:00:00Z - 1970-01-01T00:00:22Z\n\
+         Stops:\n  \
+         1. 0, 0 | eta: 1970-01-01T00:00:00Z | departure: 1970-01-01T00:00:00Z | departure (departure)\n  \
+         2. 10, 0 | eta: 1970-01-01T00:00:10Z | departure: 1970-01-01T00:00:11Z | job2 (delivery)\n  \
+         3. 5, 0 | eta: 1970-01-01T00:00:16Z | departure: 1970-01-01T00:00:17Z | job1 (delivery)\n  \
+         4. 0, 0 | eta: 1970-01-01T00:00:22Z | departure: 1970-01-01T00:00:22Z | arrival (arrival)\n\
+         Total distance: 20 m\n\
+         Total duration: 22 s\n"
+    );
+}
+
+#[test]
+fn can_serialize_stops_in_tour_order_with_schedule_times() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                capacity: vec![2],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+    assert_eq!(solution.tours.len(), 2);
+
+    let route_card = serialize_route_card(&solution);
+
+    let blocks = route_card.split("\n\n").collect::<Vec<_>>();
+    assert_eq!(blocks.len(), solution.tours.len());
+    blocks.iter().zip(solution.tours.iter()).for_each(|(block, tour)| {
+        assert!(block.starts_with(format!("Vehicle: {} ", tour.vehicle_id).as_str()));
+
+        let stops = block.lines().filter(|line| line.starts_with("  ")).collect::<Vec<_>>();
+        assert_eq!(stops.len(), tour.stops.len());
+        stops.iter().zip(tour.stops.iter()).for_each(|(line, stop)| {
+            let activities = stop.activities.iter().map(|activity| activity.job_id.as_str()).collect::<Vec<_>>();
+            let expected = format!("| eta: {} | departure: {} | ", stop.time.arrival, stop.time.departure);
+
+            assert!(line.contains(expected.as_str()));
+            assert!(activities.iter().all(|job_id| line.contains(job_id)));
+        });
+    });
+}
@@ -0,0 +1,48 @@
+use super::*;
+use crate::format::solution::{Statistic, Tour};
+use crate::helpers::*;
+
+fn create_tour(vehicle_id: &str, activities: &[(&str, &str)]) -> Tour {
+    let time = ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z");
+    let stops = std::iter::once(create_stop_with_activity("departure", "departure", (0., 0.), 0, time, 0))
+        .chain(
+            activities
+                .iter()
+                .map(|(job_id, activity_type)| create_stop_with_activity(job_id, activity_type, (1., 0.), 0, time, 1)),
+        )
+        .chain(std::iter::once(create_stop_with_activity("arrival", "arrival", (0., 0.), 0, time, 2)))
+        .collect();
+
+    Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "my_vehicle".to_string(),
+        shift_index: 0,
+        stops,
+        statistic: Statistic::default(),
+        consumption: None,
+        day: None,
+    }
+}
+
+#[test]
+fn can_create_route_hint_relations() {
+    let solution = Solution {
+        tours: vec![
+            create_tour("v1", &[("job3", "delivery"), ("break", "break"), ("job1", "pickup"), ("job2", "delivery")]),
+            create_tour("v2", &[("job4", "pickup"), ("job5", "service"), ("job4", "delivery")]),
+            create_tour("v3", &[("job6", "delivery")]),
+        ],
+        ..create_empty_solution()
+    };
+
+    let relations = create_route_hint_relations(&solution, Some(10.));
+
+    assert_eq!(relations.len(), 2);
+    relations.iter().zip(vec![vec!["job3", "job1", "job2"], vec!["job4", "job5"]]).for_each(|(relation, jobs)| {
+        assert!(matches!(relation.type_field, RelationType::SoftPrecedence));
+        assert_eq!(relation.vehicle_id, None);
+        assert_eq!(relation.shift_index, None);
+        assert_eq!(relation.penalty, Some(10.));
+        assert_eq!(relation.jobs, to_strings(jobs));
+    });
+}
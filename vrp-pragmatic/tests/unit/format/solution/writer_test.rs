@@ -1,6 +1,14 @@
 use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::solution::Registry;
+use vrp_core::models::Solution as CoreSolution;
+use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::termination::{TerminationReason, TerminationReport};
+use vrp_core::solver::{DominancePopulation, RefinementContext};
+use vrp_core::utils::DefaultRandom;
 
 #[test]
 fn can_create_solution() {
@@ -24,6 +32,7 @@ fn can_create_solution() {
                 distance: 20,
                 duration: 22,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -68,7 +77,10 @@ fn can_create_solution() {
                     distance: 20,
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
@@ -97,6 +109,7 @@ fn can_merge_activities_in_one_stop() {
                 distance: 10,
                 duration: 12,
                 times: Timing { driving: 10, serving: 2, waiting: 0, break_time: 0 },
+                tightness: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -128,7 +141,9 @@ fn can_merge_activities_in_one_stop() {
                                     start: "1970-01-01T00:00:05Z".to_string(),
                                     end: "1970-01-01T00:00:06Z".to_string()
                                 }),
-                                job_tag: None
+                                job_tag: None,
+                                distance: None,
+                                duration: None
                             },
                             Activity {
                                 job_id: "job1".to_string(),
@@ -138,7 +153,9 @@ fn can_merge_activities_in_one_stop() {
                                     start: "1970-01-01T00:00:06Z".to_string(),
                                     end: "1970-01-01T00:00:07Z".to_string()
                                 }),
-                                job_tag: None
+                                job_tag: None,
+                                distance: None,
+                                duration: None
                             }
                         ]
                     },
@@ -156,9 +173,119 @@ fn can_merge_activities_in_one_stop() {
                     distance: 10,
                     duration: 12,
                     times: Timing { driving: 10, serving: 2, waiting: 0, break_time: 0 },
+                    tightness: None,
                 },
+                consumption: None,
+                day: None,
             }],
             ..create_empty_solution()
         }
     );
 }
+
+parameterized_test! {can_write_termination_report, (reason, expected), {
+    can_write_termination_report_impl(reason, expected);
+}}
+
+can_write_termination_report! {
+    case01: (TerminationReason::MaxGenerations, "max_generations"),
+    case02: (TerminationReason::MaxTime, "max_time"),
+    case03: (TerminationReason::Stagnation, "stagnation"),
+    case04: (TerminationReason::CustomTermination, "custom_termination"),
+    case05: (TerminationReason::Cancelled, "cancelled"),
+}
+
+fn can_write_termination_report_impl(reason: TerminationReason, expected: &str) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![5., 0.])], relations: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+    let mut extras = core_problem.extras.as_ref().clone();
+    extras.insert(
+        "termination".to_string(),
+        Arc::new(TerminationReport { reason, generations: 42, duration: 1.5, phase_timings: None }),
+    );
+    let core_solution = CoreSolution {
+        registry: Registry::new(&core_problem.fleet),
+        routes: vec![],
+        unassigned: vec![],
+        extras: Arc::new(extras),
+    };
+
+    let solution = create_solution(&core_problem, &core_solution, None);
+
+    let extras = solution.extras.expect("no extras");
+    assert!(extras.metrics.is_none());
+    assert_eq!(extras.termination, Some(Termination { reason: expected.to_string(), generations: 42, duration: 1.5 }));
+}
+
+#[test]
+fn can_write_bounding_box() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![5., -2.]), create_delivery_job("job2", vec![1., 3.])],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().ok().unwrap());
+    let refinement_ctx =
+        RefinementContext::new(core_problem.clone(), Box::new(DominancePopulation::new(core_problem.clone(), 1)), None);
+    let insertion_ctx = InsertionContext::new(core_problem.clone(), Arc::new(DefaultRandom::default()));
+    let core_solution = RecreateWithCheapest::default()
+        .run(&refinement_ctx, insertion_ctx)
+        .solution
+        .to_solution(core_problem.extras.clone());
+
+    let solution = create_solution(&core_problem, &core_solution, None);
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.extras.and_then(|extras| extras.bbox),
+        Some(BoundingBox { min_lat: 0., min_lng: -2., max_lat: 5., max_lng: 3. })
+    );
+}
+
+#[test]
+fn can_write_cumulative_stats() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![5., 0.]),
+                create_delivery_job("job2", vec![5., 0.]),
+                create_delivery_job("job3", vec![10., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        config: Some(Config { cumulative_stats: Some(true), ..Default::default() }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    let tour = solution.tours.first().expect("no tour");
+    let stats = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .map(|activity| (activity.distance, activity.duration))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        stats,
+        vec![
+            (Some(0), Some(0)),
+            (Some(10), Some(11)),
+            (Some(15), Some(17)),
+            (Some(15), Some(18)),
+            (Some(20), Some(23))
+        ]
+    );
+    assert_eq!(stats.last(), Some(&(Some(tour.statistic.distance), Some(tour.statistic.duration))));
+}
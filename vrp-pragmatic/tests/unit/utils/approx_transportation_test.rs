@@ -15,16 +15,26 @@ fn can_calculate_distance_between_two_locations() {
     let l1 = Location::Coordinate { lat: 52.52599, lng: 13.45413 };
     let l2 = Location::Coordinate { lat: 52.5165, lng: 13.3808 };
 
-    let distance = get_distance(&l1, &l2);
+    let distance = get_haversine_distance(&l1, &l2);
 
     assert_eq!(distance.round(), 5078.);
 }
 
+#[test]
+fn can_calculate_euclidean_distance_between_two_locations() {
+    let l1 = Location::Coordinate { lat: 5_800_000., lng: 390_000. };
+    let l2 = Location::Coordinate { lat: 5_804_000., lng: 393_000. };
+
+    let distance = get_euclidean_distance(&l1, &l2);
+
+    assert_eq!(distance, 5000.);
+}
+
 #[test]
 fn can_use_approximated_with_matrix_costs() {
     let locations = get_test_locations();
     let speed = 10.;
-    let approx_data = get_approx_transportation(&locations, &[speed]);
+    let approx_data = get_approx_transportation(&locations, &[speed], CoordinateSystem::Geographic);
     assert_eq!(approx_data.len(), 1);
 
     let (durations, distances) = approx_data.first().unwrap();
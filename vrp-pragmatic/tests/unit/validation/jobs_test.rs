@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 
 fn assert_result(code: &str, action: &str, result: Option<FormatError>) {
@@ -47,6 +48,15 @@ fn can_detect_empty_job() {
                 services: None,
                 priority: None,
                 skills: None,
+                hard_time_window: None,
+                companions: None,
+                transfer_from: None,
+                service_time_distribution: None,
+                corridor: None,
+                crew_size: None,
+                workload: None,
+                vehicle_affinity: None,
+                age: None,
             }],
             relations: None,
         },
@@ -81,3 +91,527 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_invalid_opening_times, (times, opening, expected), {
+    can_detect_invalid_opening_times_impl(times, opening, expected);
+}}
+
+can_detect_invalid_opening_times! {
+    case01: (None, ("1970-01-01T09:00:00Z", 10800.), None),
+    case02: (Some(("1970-01-01T00:00:00Z", "1970-01-01T01:00:00Z")), ("1970-01-01T09:00:00Z", 10800.), None),
+    case03: (None, ("1970-01-01T09:00:00Z", -1.), Some("job1")),
+    case04: (None, ("09:00", 10800.), Some("job1")),
+    case05: (Some(("1970-01-01T10:00:00Z", "1970-01-01T13:00:00Z")), ("1970-01-01T09:00:00Z", 10800.), Some("job1")),
+}
+
+fn can_detect_invalid_opening_times_impl(times: Option<(&str, &str)>, opening: (&str, f64), expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        times: times.map(|(start, end)| vec![vec![start.to_string(), end.to_string()]]),
+                        opening_times: Some(vec![OpeningTime { open: opening.0.to_string(), duration: opening.1 }]),
+                        ..create_job_place(vec![1., 0.])
+                    }],
+                    demand: Some(vec![1]),
+                    tag: None,
+                    type_durations: None,
+                    variants: None,
+                }]),
+                ..create_job("job1")
+            }],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1103_time_window_correctness(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1103", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+#[test]
+fn can_accept_empty_time_windows() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace { times: Some(vec![]), ..create_job_place(vec![1., 0.]) }],
+                    demand: Some(vec![1]),
+                    tag: None,
+                    type_durations: None,
+                    variants: None,
+                }]),
+                ..create_job("job1")
+            }],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1103_time_window_correctness(&ValidationContext::new(&problem, None));
+
+    assert!(result.is_ok());
+}
+
+parameterized_test! {can_detect_invalid_type_durations, (type_ids, duration, expected), {
+    can_detect_invalid_type_durations_impl(type_ids, duration, expected);
+}}
+
+can_detect_invalid_type_durations! {
+    case01: (vec!["my_vehicle"], 10., None),
+    case02: (vec!["my_vehicle"], 0., None),
+    case03: (vec!["my_vehicle"], -1., Some("job1")),
+    case04: (vec!["unknown"], 10., Some("job1")),
+    case05: (vec![], 10., Some("job1")),
+}
+
+fn can_detect_invalid_type_durations_impl(type_ids: Vec<&str>, duration: f64, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![create_job_place(vec![1., 0.])],
+                    demand: Some(vec![1]),
+                    tag: None,
+                    type_durations: Some(vec![VehicleTypeDuration {
+                        type_ids: type_ids.into_iter().map(|type_id| type_id.to_string()).collect(),
+                        duration,
+                    }]),
+                    variants: None,
+                }]),
+                ..create_job("job1")
+            }],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1108_type_durations_are_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1108", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+fn create_variant(tag: &str, demand: i32, duration: Option<f64>) -> JobTaskVariant {
+    JobTaskVariant { demand: Some(vec![demand]), duration, tag: tag.to_string() }
+}
+
+parameterized_test! {can_detect_invalid_task_variants, (variants, has_two_tasks, expected), {
+    can_detect_invalid_task_variants_impl(variants, has_two_tasks, expected);
+}}
+
+can_detect_invalid_task_variants! {
+    case01: (vec![create_variant("a", 1, None), create_variant("b", 2, Some(1.))], false, None),
+    case02: (vec![create_variant("a", 1, None), create_variant("a", 2, None)], false, Some("job1")),
+    case03: (vec![create_variant("a", -1, None)], false, Some("job1")),
+    case04: (vec![create_variant("a", 1, Some(-1.))], false, Some("job1")),
+    case05: (vec![], false, Some("job1")),
+    case06: (vec![create_variant("a", 1, None)], true, Some("job1")),
+}
+
+fn can_detect_invalid_task_variants_impl(variants: Vec<JobTaskVariant>, has_two_tasks: bool, expected: Option<&str>) {
+    let task = JobTask {
+        places: vec![create_job_place(vec![1., 0.])],
+        demand: Some(vec![1]),
+        tag: None,
+        type_durations: None,
+        variants: Some(variants),
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(if has_two_tasks { vec![task.clone(), task] } else { vec![task] }),
+                ..create_job("job1")
+            }],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1109_task_variants_are_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1109", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_companions, (companions, has_nested, expected), {
+    can_detect_invalid_companions_impl(companions, has_nested, expected);
+}}
+
+can_detect_invalid_companions! {
+    case01: (vec!["job2"], false, None),
+    case02: (vec![], false, None),
+    case03: (vec!["job1"], false, Some("job1")),
+    case04: (vec!["unknown"], false, Some("job1")),
+    case05: (vec!["job2"], true, Some("job1, job2")),
+}
+
+fn can_detect_invalid_companions_impl(companions: Vec<&str>, has_nested: bool, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    companions: Some(companions.into_iter().map(|id| id.to_string()).collect()),
+                    ..create_delivery_job("job1", vec![1., 0.])
+                },
+                Job {
+                    companions: if has_nested { Some(vec!["job1".to_string()]) } else { None },
+                    ..create_delivery_job("job2", vec![2., 0.])
+                },
+            ],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1110_companions_are_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1110", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_time_window_grace, (grace, soft_policy, hard_time_window, expected), {
+    can_detect_invalid_time_window_grace_impl(grace, soft_policy, hard_time_window, expected);
+}}
+
+can_detect_invalid_time_window_grace! {
+    case01: (Some(300.), true, None, None),
+    case02: (None, false, None, None),
+    case03: (Some(-1.), true, None, Some("job1")),
+    case04: (Some(300.), false, None, Some("job1")),
+    case05: (Some(300.), true, Some(true), Some("job1")),
+    case06: (Some(300.), false, Some(false), None),
+}
+
+fn can_detect_invalid_time_window_grace_impl(
+    grace: Option<f64>,
+    soft_policy: bool,
+    hard_time_window: Option<bool>,
+    expected: Option<&str>,
+) {
+    let mut job = create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 5)], 1.);
+    job.hard_time_window = hard_time_window;
+    job.deliveries.iter_mut().flat_map(|tasks| tasks.iter_mut()).flat_map(|task| task.places.iter_mut()).for_each(
+        |place| {
+            place.grace = grace;
+        },
+    );
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        config: Some(Config {
+            time_windows: Some(TimeWindowsConfig { soft: soft_policy, lateness_penalty: None, unit: None }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+
+    let result = check_e1111_time_window_grace_is_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1111", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_transfers, (transfer_from, pickup_location, has_nested, expected), {
+    can_detect_invalid_transfers_impl(transfer_from, pickup_location, has_nested, expected);
+}}
+
+can_detect_invalid_transfers! {
+    case01: (Some("job1"), (1., 0.), false, None),
+    case02: (None, (1., 0.), false, None),
+    case03: (Some("job2"), (1., 0.), false, Some("job2")),
+    case04: (Some("unknown"), (1., 0.), false, Some("job2")),
+    case05: (Some("job1"), (2., 0.), false, Some("job2")),
+    case06: (Some("job1"), (1., 0.), true, Some("job1, job2")),
+}
+
+fn can_detect_invalid_transfers_impl(
+    transfer_from: Option<&str>,
+    pickup_location: (f64, f64),
+    has_nested: bool,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    transfer_from: if has_nested { Some("job2".to_string()) } else { None },
+                    ..create_pickup_delivery_job("job1", vec![0., 0.], vec![1., 0.])
+                },
+                Job {
+                    transfer_from: transfer_from.map(|id| id.to_string()),
+                    ..create_pickup_delivery_job("job2", vec![pickup_location.0, pickup_location.1], vec![3., 0.])
+                },
+            ],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1112_transfers_are_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1112", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_receiving_rates, (rates, expected), {
+    can_detect_invalid_receiving_rates_impl(rates, expected);
+}}
+
+can_detect_invalid_receiving_rates! {
+    case01: (vec![((1., 0.), 10.)], None),
+    case02: (vec![((1., 0.), 10.), ((2., 0.), 5.)], None),
+    case03: (vec![((1., 0.), 0.)], Some("0")),
+    case04: (vec![((1., 0.), -1.)], Some("0")),
+    case05: (vec![((1., 0.), 10.), ((1., 0.), 5.)], Some("1")),
+}
+
+fn can_detect_invalid_receiving_rates_impl(rates: Vec<((f64, f64), f64)>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        config: Some(Config {
+            receiving_rates: Some(
+                rates
+                    .into_iter()
+                    .map(|((lat, lng), interval)| ReceivingRate { location: vec![lat, lng].to_loc(), interval })
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+
+    let result = check_e1113_receiving_rates_are_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1113", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_service_time_distribution, (distribution, expected), {
+    can_detect_invalid_service_time_distribution_impl(distribution, expected);
+}}
+
+can_detect_invalid_service_time_distribution! {
+    case01: (Some(ServiceTimeDistribution::Normal { mean: 10., std_dev: 2. }), None),
+    case02: (Some(ServiceTimeDistribution::Uniform { min: 5., max: 10. }), None),
+    case03: (None, None),
+    case04: (Some(ServiceTimeDistribution::Normal { mean: 10., std_dev: -2. }), Some("job1")),
+    case05: (Some(ServiceTimeDistribution::Uniform { min: -1., max: 10. }), Some("job1")),
+    case06: (Some(ServiceTimeDistribution::Uniform { min: 10., max: 5. }), Some("job1")),
+}
+
+fn can_detect_invalid_service_time_distribution_impl(
+    distribution: Option<ServiceTimeDistribution>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { service_time_distribution: distribution, ..create_delivery_job("job1", vec![1., 0.]) }],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1114_service_time_distributions_are_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1114", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_time_window_penalties, (times, penalties, expected), {
+    can_detect_invalid_time_window_penalties_impl(times, penalties, expected);
+}}
+
+can_detect_invalid_time_window_penalties! {
+    case01: (vec![(0, 10), (20, 30)], Some(vec![0., 10.]), None),
+    case02: (vec![(0, 10), (20, 30)], None, None),
+    case03: (vec![(0, 10), (20, 30)], Some(vec![10.]), Some("job1")),
+    case04: (vec![(0, 10), (20, 30)], Some(vec![0., -1.]), Some("job1")),
+    case05: (vec![], Some(vec![10.]), Some("job1")),
+}
+
+fn can_detect_invalid_time_window_penalties_impl(
+    times: Vec<(i32, i32)>,
+    penalties: Option<Vec<f64>>,
+    expected: Option<&str>,
+) {
+    let mut job = if times.is_empty() {
+        create_delivery_job("job1", vec![1., 0.])
+    } else {
+        create_delivery_job_with_times("job1", vec![1., 0.], times, 1.)
+    };
+    job.deliveries.iter_mut().flat_map(|tasks| tasks.iter_mut()).flat_map(|task| task.places.iter_mut()).for_each(
+        |place| {
+            place.time_penalties = penalties.clone();
+        },
+    );
+    let problem = Problem { plan: Plan { jobs: vec![job], relations: None }, ..create_empty_problem() };
+
+    let result = check_e1115_time_window_penalties_are_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1115", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_crew_size, (crew_size, expected), {
+    can_detect_invalid_crew_size_impl(crew_size, expected);
+}}
+
+can_detect_invalid_crew_size! {
+    case01: (None, None),
+    case02: (Some(2), None),
+    case03: (Some(0), Some("job1")),
+}
+
+fn can_detect_invalid_crew_size_impl(crew_size: Option<usize>, expected: Option<&str>) {
+    let job = Job { crew_size, ..create_delivery_job("job1", vec![1., 0.]) };
+    let problem = Problem { plan: Plan { jobs: vec![job], relations: None }, ..create_empty_problem() };
+
+    let result = check_e1116_crew_sizes_are_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1116", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_skill_time_windows, (skills, times, expected), {
+    can_detect_invalid_skill_time_windows_impl(skills, times, expected);
+}}
+
+can_detect_invalid_skill_time_windows! {
+    case01: (vec!["premium"], vec![(0, 10), (20, 30)], None),
+    case02: (vec![], vec![(0, 10)], Some("job1")),
+    case03: (vec!["premium"], vec![(10, 0)], Some("job1")),
+    case04: (vec!["premium"], vec![(0, 20), (10, 30)], Some("job1")),
+    case05: (vec!["premium"], vec![], Some("job1")),
+}
+
+fn can_detect_invalid_skill_time_windows_impl(skills: Vec<&str>, times: Vec<(i32, i32)>, expected: Option<&str>) {
+    let mut job = create_delivery_job("job1", vec![1., 0.]);
+    job.deliveries.iter_mut().flat_map(|tasks| tasks.iter_mut()).flat_map(|task| task.places.iter_mut()).for_each(
+        |place| {
+            place.skill_times = Some(vec![SkillTimeWindows {
+                skills: skills.iter().map(|skill| skill.to_string()).collect(),
+                times: times
+                    .iter()
+                    .map(|&(start, end)| vec![format_time(start as f64), format_time(end as f64)])
+                    .collect(),
+            }]);
+        },
+    );
+    let problem = Problem { plan: Plan { jobs: vec![job], relations: None }, ..create_empty_problem() };
+
+    let result = check_e1117_skill_time_windows_are_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1117", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_workload, (workload, expected), {
+    can_detect_invalid_workload_impl(workload, expected);
+}}
+
+can_detect_invalid_workload! {
+    case01: (None, None),
+    case02: (Some(2.5), None),
+    case03: (Some(0.), None),
+    case04: (Some(-1.), Some("job1")),
+}
+
+fn can_detect_invalid_workload_impl(workload: Option<f64>, expected: Option<&str>) {
+    let job = Job { workload, ..create_delivery_job("job1", vec![1., 0.]) };
+    let problem = Problem { plan: Plan { jobs: vec![job], relations: None }, ..create_empty_problem() };
+
+    let result = check_e1118_workloads_are_correct(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1118", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_priority_escalation, (escalation, expected), {
+    can_detect_invalid_priority_escalation_impl(escalation, expected);
+}}
+
+can_detect_invalid_priority_escalation! {
+    case01: (None, None),
+    case02: (Some((2, None)), None),
+    case03: (Some((2, Some(2))), None),
+    case04: (Some((0, None)), Some("E1119".to_string())),
+    case05: (Some((2, Some(0))), Some("E1119".to_string())),
+}
+
+fn can_detect_invalid_priority_escalation_impl(escalation: Option<(usize, Option<i32>)>, expected: Option<String>) {
+    let problem = Problem {
+        config: Some(Config {
+            priority_escalation: escalation.map(|(cycles, max_priority)| PriorityEscalation { cycles, max_priority }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+
+    let result = check_e1119_priority_escalation_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_attribute_rules, (rules, expected), {
+    can_detect_invalid_attribute_rules_impl(rules, expected);
+}}
+
+can_detect_invalid_attribute_rules! {
+    case01: (None, None),
+    case02: (Some(vec!["job.tag != 'fragile'"]), None),
+    case03: (Some(vec!["job.tag != 'fragile'", "job.tag =="]), Some("E1120".to_string())),
+}
+
+fn can_detect_invalid_attribute_rules_impl(rules: Option<Vec<&str>>, expected: Option<String>) {
+    let problem = Problem {
+        config: Some(Config {
+            rules: rules.map(|rules| rules.into_iter().map(|rule| rule.to_string()).collect()),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+
+    let result = check_e1120_attribute_rules_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
@@ -1,6 +1,6 @@
 use super::*;
 use crate::format::problem::Objective::*;
-use crate::helpers::create_empty_problem;
+use crate::helpers::*;
 
 fn min_cost() -> Objective {
     MinimizeCost
@@ -25,10 +25,10 @@ parameterized_test! {can_detect_empty_objective, (objectives, expected), {
 }}
 
 can_detect_empty_objective! {
-    case01: (Some(Objectives { primary: vec![], secondary: None }), Some(())),
-    case02: (Some(Objectives { primary: vec![min_cost() ], secondary: None}), None),
-    case03: (Some(Objectives { primary: vec![], secondary: Some(vec![]) }), Some(())),
-    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]) }), None),
+    case01: (Some(Objectives { primary: vec![], secondary: None, tie_break: None }), Some(())),
+    case02: (Some(Objectives { primary: vec![min_cost() ], secondary: None, tie_break: None }), None),
+    case03: (Some(Objectives { primary: vec![], secondary: Some(vec![]), tie_break: None }), Some(())),
+    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]), tie_break: None }), None),
 }
 
 fn can_detect_empty_objective_impl(objectives: Option<Objectives>, expected: Option<()>) {
@@ -46,17 +46,24 @@ parameterized_test! {can_detect_duplicates, (objectives, expected), {
 }}
 
 can_detect_duplicates! {
-    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None}), None),
-    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]) }), None),
-    case03: (Some(Objectives { primary: vec![min_cost()], secondary: Some(vec![min_cost() ]) }), Some("minimize-cost".to_owned())),
+    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None, tie_break: None }), None),
+    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]), tie_break: None }), None),
+    case03: (Some(Objectives { primary: vec![min_cost()], secondary: Some(vec![min_cost() ]), tie_break: None }), Some("minimize-cost".to_owned())),
     case04: (Some(Objectives {
             primary: vec![min_cost()],
-            secondary: Some(vec![min_cost() ]) }),
+            secondary: Some(vec![min_cost() ]),
+            tie_break: None }),
         Some("minimize-cost".to_owned())),
     case05: (Some(Objectives {
             primary: vec![min_cost(), balance_dist(), balance_dist()],
-            secondary: Some(vec![min_cost() ]) }),
+            secondary: Some(vec![min_cost() ]),
+            tie_break: None }),
         Some("balance-distance,minimize-cost".to_owned())),
+    case06: (Some(Objectives {
+            primary: vec![min_cost()],
+            secondary: None,
+            tie_break: Some(vec![min_cost()]) }),
+        Some("minimize-cost".to_owned())),
 }
 
 fn can_detect_duplicates_impl(objectives: Option<Objectives>, expected: Option<String>) {
@@ -74,10 +81,10 @@ parameterized_test! {can_detect_missing_cost_objective, (objectives, expected),
 }}
 
 can_detect_missing_cost_objective! {
-    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None}), None),
-    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]) }), None),
-    case03: (Some(Objectives { primary: vec![balance_dist()], secondary: None }), Some(())),
-    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![balance_dist() ]) }), Some(())),
+    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None, tie_break: None }), None),
+    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]), tie_break: None }), None),
+    case03: (Some(Objectives { primary: vec![balance_dist()], secondary: None, tie_break: None }), Some(())),
+    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![balance_dist() ]), tie_break: None }), Some(())),
 }
 
 fn can_detect_missing_cost_objective_impl(objectives: Option<Objectives>, expected: Option<()>) {
@@ -89,3 +96,222 @@ fn can_detect_missing_cost_objective_impl(objectives: Option<Objectives>, expect
 
     assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1602".to_string()));
 }
+
+parameterized_test! {can_detect_weighted_lateness_with_hard_time_windows, (is_soft_policy, hard_time_window, expected), {
+    can_detect_weighted_lateness_with_hard_time_windows_impl(is_soft_policy, hard_time_window, expected);
+}}
+
+can_detect_weighted_lateness_with_hard_time_windows! {
+    case01: (true, None, None),
+    case02: (false, Some(false), None),
+    case03: (false, None, Some(())),
+    case04: (true, Some(true), Some(())),
+}
+
+fn can_detect_weighted_lateness_with_hard_time_windows_impl(
+    is_soft_policy: bool,
+    hard_time_window: Option<bool>,
+    expected: Option<()>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { hard_time_window, ..create_delivery_job("job1", vec![1., 0.]) }],
+            relations: None,
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeWeightedLateness],
+            secondary: Some(vec![min_cost()]),
+            tie_break: None,
+        }),
+        config: Some(Config {
+            time_windows: Some(TimeWindowsConfig { soft: is_soft_policy, lateness_penalty: None, unit: None }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1603_weighted_lateness_with_hard_time_windows(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1603".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_overlap_objective, (resolution, use_index, expected), {
+    can_detect_invalid_overlap_objective_impl(resolution, use_index, expected);
+}}
+
+can_detect_invalid_overlap_objective! {
+    case01: (100., false, None),
+    case02: (0., false, Some(())),
+    case03: (-100., false, Some(())),
+    case04: (100., true, Some(())),
+}
+
+fn can_detect_invalid_overlap_objective_impl(resolution: f64, use_index: bool, expected: Option<()>) {
+    let job =
+        if use_index { create_delivery_job_with_index("job1", 0) } else { create_delivery_job("job1", vec![1., 0.]) };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeOverlap { resolution }],
+            secondary: Some(vec![min_cost()]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1604_overlap_objective_is_correct(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1604".to_string()));
+}
+
+fn weighted(objective: Objective, weight: f64) -> WeightedObjective {
+    WeightedObjective { objective, weight }
+}
+
+parameterized_test! {can_detect_invalid_weighted_sum_objective, (objectives, expected), {
+    can_detect_invalid_weighted_sum_objective_impl(objectives, expected);
+}}
+
+can_detect_invalid_weighted_sum_objective! {
+    case01: (vec![weighted(min_cost(), 1.), weighted(MinimizeTours, 1.)], None),
+    case02: (vec![], Some(())),
+    case03: (vec![weighted(min_cost(), 1.), weighted(MinimizeTours, 0.)], Some(())),
+    case04: (vec![weighted(min_cost(), 1.), weighted(MaximizeTours, 1.)], Some(())),
+    case05: (vec![weighted(WeightedSum { objectives: vec![weighted(min_cost(), 1.)], normalize: None }, 1.)], Some(())),
+}
+
+fn can_detect_invalid_weighted_sum_objective_impl(objectives: Vec<WeightedObjective>, expected: Option<()>) {
+    let problem = Problem {
+        objectives: Some(Objectives {
+            primary: vec![WeightedSum { objectives, normalize: Some(true) }],
+            secondary: None,
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1605_weighted_sum_objective_is_correct(&objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1605".to_string()));
+}
+
+#[test]
+fn can_use_cost_objective_inside_weighted_sum() {
+    let problem = Problem {
+        objectives: Some(Objectives {
+            primary: vec![WeightedSum {
+                objectives: vec![weighted(min_cost(), 1.), weighted(MinimizeTours, 1.)],
+                normalize: None,
+            }],
+            secondary: None,
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = validate_objectives(&ctx);
+
+    assert!(result.is_ok());
+}
+
+parameterized_test! {can_detect_overtime_objective_without_overtime, (max_overtime, expected), {
+    can_detect_overtime_objective_without_overtime_impl(max_overtime, expected);
+}}
+
+can_detect_overtime_objective_without_overtime! {
+    case01: (Some(3600.), None),
+    case02: (None, Some(())),
+}
+
+fn can_detect_overtime_objective_without_overtime_impl(max_overtime: Option<f64>, expected: Option<()>) {
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: shift.end.clone().map(|end| ShiftEnd { max_overtime, ..end }),
+                    ..shift
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeOvertimeTours],
+            secondary: Some(vec![min_cost()]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1606_overtime_objective_without_overtime(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1606".to_string()));
+}
+
+parameterized_test! {can_detect_intersections_objective_without_coordinates, (use_index, expected), {
+    can_detect_intersections_objective_without_coordinates_impl(use_index, expected);
+}}
+
+can_detect_intersections_objective_without_coordinates! {
+    case01: (false, None),
+    case02: (true, Some(())),
+}
+
+fn can_detect_intersections_objective_without_coordinates_impl(use_index: bool, expected: Option<()>) {
+    let job =
+        if use_index { create_delivery_job_with_index("job1", 0) } else { create_delivery_job("job1", vec![1., 0.]) };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeIntersections],
+            secondary: Some(vec![min_cost()]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1607_intersections_objective_without_coordinates(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1607".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_monetary_cost_objective, (distance, fixed, expected), {
+    can_detect_invalid_monetary_cost_objective_impl(distance, fixed, expected);
+}}
+
+can_detect_invalid_monetary_cost_objective! {
+    case01: (Some(1.), Some(100.), None),
+    case02: (None, None, None),
+    case03: (Some(-1.), Some(100.), Some(())),
+    case04: (Some(1.), Some(-100.), Some(())),
+}
+
+fn can_detect_invalid_monetary_cost_objective_impl(distance: Option<f64>, fixed: Option<f64>, expected: Option<()>) {
+    let rates = MonetaryRates { distance, time: None, overtime: None, fixed, unassigned: None, late: None };
+    let problem = Problem {
+        objectives: Some(Objectives {
+            primary: vec![MinimizeMonetaryCost { rates }],
+            secondary: Some(vec![min_cost()]),
+            tie_break: None,
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1608_monetary_cost_objective_is_correct(&objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1608".to_string()));
+}
@@ -47,8 +47,9 @@ fn can_detect_relation_errors_impl(
             relations: Some(vec![Relation {
                 type_field: RelationType::Strict,
                 jobs: job_ids,
-                vehicle_id,
+                vehicle_id: Some(vehicle_id),
                 shift_index,
+                penalty: None,
             }]),
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
@@ -92,8 +93,9 @@ fn can_detect_multi_place_time_window_jobs_impl(relation_type: RelationType, exp
             relations: Some(vec![Relation {
                 type_field: relation_type,
                 jobs: vec!["job1".to_string(), "job2".to_string(), "job3".to_string()],
-                vehicle_id: "vehicle_1".to_string(),
+                vehicle_id: Some("vehicle_1".to_string()),
                 shift_index: None,
+                penalty: None,
             }]),
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
@@ -133,8 +135,9 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
                     .map(|(job_id, vehicle_id)| Relation {
                         type_field: RelationType::Any,
                         jobs: vec![job_id.to_string()],
-                        vehicle_id: vehicle_id.to_string(),
+                        vehicle_id: Some(vehicle_id.to_string()),
                         shift_index: None,
+                        penalty: None,
                     })
                     .collect(),
             ),
@@ -157,3 +160,55 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
         _ => panic!(format!("{:?} vs {}", result, expected.is_some())),
     }
 }
+
+parameterized_test! {can_detect_relation_vehicle_id_errors, (relation_type, job_ids, vehicle_id, shift_index, penalty, expected), {
+    can_detect_relation_vehicle_id_errors_impl(
+        relation_type,
+        job_ids.iter().map(|id| id.to_string()).collect(),
+        vehicle_id.map(|id: &str| id.to_string()),
+        shift_index,
+        penalty,
+        expected,
+    );
+}}
+
+can_detect_relation_vehicle_id_errors! {
+    case01: (RelationType::Strict, vec!["job1"], None, None, None, Some("E1207")),
+    case02: (RelationType::Sequence, vec!["job1"], None, None, None, Some("E1207")),
+    case03: (RelationType::Any, vec!["job1"], None, None, None, Some("E1207")),
+
+    case04: (RelationType::SoftPrecedence, vec!["job1", "job2"], None, None, None, None),
+    case05: (RelationType::SoftPrecedence, vec!["job1", "job2"], None, None, Some(100.), None),
+    case06: (RelationType::SoftPrecedence, vec!["job1", "job2"], Some("vehicle_1"), None, None, Some("E1208")),
+    case07: (RelationType::SoftPrecedence, vec!["job1", "job2"], None, Some(0), None, Some("E1208")),
+    case08: (RelationType::SoftPrecedence, vec!["job1", "job2"], None, None, Some(-1.), Some("E1208")),
+    case09: (RelationType::SoftPrecedence, vec!["job1", "break"], None, None, None, Some("E1208")),
+}
+
+fn can_detect_relation_vehicle_id_errors_impl(
+    relation_type: RelationType,
+    job_ids: Vec<String>,
+    vehicle_id: Option<String>,
+    shift_index: Option<usize>,
+    penalty: Option<f64>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: Some(vec![Relation {
+                type_field: relation_type,
+                jobs: job_ids,
+                vehicle_id,
+                shift_index,
+                penalty,
+            }]),
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
@@ -78,6 +78,11 @@ fn can_detect_limit_areas_with_indices() {
                             Location::new_coordinate(-5., 5.),
                         ],
                     }]),
+                    max_time_window_jobs: None,
+                    max_reloads: None,
+                    max_hourly_time_window_jobs: None,
+                    max_late_stops: None,
+                    max_first_leg_distance: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -111,6 +116,10 @@ fn can_detect_index_mismatch() {
         travel_times: vec![1; 4],
         distances: vec![1; 4],
         error_codes: None,
+        default_travel_time: None,
+        default_distance: None,
+        time_unit: None,
+        unreachable_value: None,
     }];
     let ctx = ValidationContext::new(&problem, Some(&matrices));
 
@@ -118,3 +127,39 @@ fn can_detect_index_mismatch() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+parameterized_test! {can_detect_ambiguous_time_units, (tw_unit, matrix_units, expected), {
+    can_detect_ambiguous_time_units_impl(tw_unit, matrix_units, expected);
+}}
+
+can_detect_ambiguous_time_units! {
+    case01: (None, vec![None, None], None),
+    case02: (Some(TimeUnit::Minutes), vec![Some(TimeUnit::Seconds), Some(TimeUnit::Minutes)], None),
+    case03: (Some(TimeUnit::Minutes), vec![], None),
+    case04: (Some(TimeUnit::Minutes), vec![None], Some("E1506")),
+    case05: (None, vec![Some(TimeUnit::Seconds)], Some("E1506")),
+    case06: (Some(TimeUnit::Seconds), vec![Some(TimeUnit::Seconds), None], Some("E1506")),
+}
+
+fn can_detect_ambiguous_time_units_impl(
+    tw_unit: Option<TimeUnit>,
+    matrix_units: Vec<Option<TimeUnit>>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        config: tw_unit.map(|unit| Config {
+            time_windows: Some(TimeWindowsConfig { soft: false, lateness_penalty: None, unit: Some(unit) }),
+            ..Default::default()
+        }),
+        ..create_empty_problem()
+    };
+    let matrices = matrix_units
+        .into_iter()
+        .map(|time_unit| Matrix { time_unit, ..create_matrix(vec![0, 1, 1, 0]) })
+        .collect::<Vec<_>>();
+    let ctx = ValidationContext::new(&problem, Some(&matrices));
+
+    let result = check_e1506_ambiguous_time_units(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
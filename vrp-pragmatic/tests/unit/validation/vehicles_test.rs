@@ -33,6 +33,11 @@ fn can_detect_invalid_area_impl(allowed_shapes: Option<Vec<Vec<Location>>>, expe
                     allowed_areas: allowed_shapes.map(|shapes| {
                         shapes.into_iter().map(|shape| AreaLimit { priority: None, outer_shape: shape }).collect()
                     }),
+                    max_time_window_jobs: None,
+                    max_reloads: None,
+                    max_hourly_time_window_jobs: None,
+                    max_late_stops: None,
+                    max_first_leg_distance: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -90,3 +95,511 @@ fn can_detect_invalid_depots_impl(depots: &[(f64, Option<Vec<Vec<String>>>)], ex
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_depot_inventory, (inventory, expected), {
+    can_detect_invalid_depot_inventory_impl(inventory, expected);
+}}
+
+can_detect_invalid_depot_inventory! {
+    case01: (None, None),
+    case02: (Some(vec![10]), None),
+    case03: (Some(vec![0]), None),
+    case04: (Some(vec![]), Some("E1307".to_string())),
+    case05: (Some(vec![-1]), Some("E1307".to_string())),
+    case06: (Some(vec![10, 10]), Some("E1307".to_string())),
+}
+
+fn can_detect_invalid_depot_inventory_impl(inventory: Option<Vec<i32>>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![10], ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        config: Some(Config { depot_inventory: inventory, ..Default::default() }),
+        ..create_empty_problem()
+    };
+
+    let result = check_e1307_depot_inventory_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_distance_tiers, (tiers, expected), {
+    can_detect_invalid_distance_tiers_impl(tiers, expected);
+}}
+
+can_detect_invalid_distance_tiers! {
+    case01: (None, None),
+    case02: (Some(vec![(10., 2.)]), None),
+    case03: (Some(vec![(0., 0.), (10., 2.)]), None),
+    case04: (Some(vec![]), Some("E1308".to_string())),
+    case05: (Some(vec![(-1., 2.)]), Some("E1308".to_string())),
+    case06: (Some(vec![(10., -2.)]), Some("E1308".to_string())),
+    case07: (Some(vec![(10., 2.), (10., 3.)]), Some("E1308".to_string())),
+}
+
+fn can_detect_invalid_distance_tiers_impl(tiers: Option<Vec<(f64, f64)>>, expected: Option<String>) {
+    let distance_tiers = tiers
+        .map(|tiers| tiers.into_iter().map(|(threshold, distance)| DistanceTier { threshold, distance }).collect());
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { distance_tiers, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1308_vehicle_distance_tiers_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_range, (range, expected), {
+    can_detect_invalid_range_impl(range, expected);
+}}
+
+can_detect_invalid_range! {
+    case01: (None, None),
+    case02: (Some(100.), None),
+    case03: (Some(0.), Some("E1309".to_string())),
+    case04: (Some(-1.), Some("E1309".to_string())),
+}
+
+fn can_detect_invalid_range_impl(range: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet { vehicles: vec![VehicleType { range, ..create_default_vehicle_type() }], profiles: vec![] },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_range_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_aggregate_capacity, (weights, limit, expected), {
+    can_detect_invalid_aggregate_capacity_impl(weights, limit, expected);
+}}
+
+can_detect_invalid_aggregate_capacity! {
+    case01: (vec![1., 2.], 10., None),
+    case02: (vec![1.], 10., Some("E1310".to_string())),
+    case03: (vec![1., -1.], 10., Some("E1310".to_string())),
+    case04: (vec![1., 1.], -1., Some("E1310".to_string())),
+}
+
+fn can_detect_invalid_aggregate_capacity_impl(weights: Vec<f64>, limit: f64, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![10, 10],
+                aggregate_capacity: Some(AggregateCapacity { weights, limit }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_aggregate_capacity_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_max_daily_cost, (max_daily_cost, expected), {
+    can_detect_invalid_max_daily_cost_impl(max_daily_cost, expected);
+}}
+
+can_detect_invalid_max_daily_cost! {
+    case01: (None, None),
+    case02: (Some(100.), None),
+    case03: (Some(0.), None),
+    case04: (Some(-1.), Some("E1311".to_string())),
+}
+
+fn can_detect_invalid_max_daily_cost_impl(max_daily_cost: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { max_daily_cost, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1311_vehicle_max_daily_cost_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_anchors, (shift_anchors, config_anchors, expected), {
+    can_detect_invalid_anchors_impl(shift_anchors, config_anchors, expected);
+}}
+
+can_detect_invalid_anchors! {
+    case01: (None, None, None),
+    case02: (Some(vec![None]), None, None),
+    case03: (Some(vec![Some((0., 100.))]), None, None),
+    case04: (None, Some(vec![None]), None),
+    case05: (Some(vec![]), None, Some("E1312".to_string())),
+    case06: (Some(vec![Some((2000., 3000.))]), None, Some("E1312".to_string())),
+    case07: (None, Some(vec![]), Some("E1312".to_string())),
+}
+
+fn can_detect_invalid_anchors_impl(
+    shift_anchors: Option<Vec<Option<(f64, f64)>>>,
+    config_anchors: Option<Vec<Option<(f64, f64)>>>,
+    expected: Option<String>,
+) {
+    let create_anchors = |anchors: Vec<Option<(f64, f64)>>| {
+        anchors
+            .into_iter()
+            .map(|times| VehicleCargoPlace {
+                location: vec![1., 0.].to_loc(),
+                duration: 1.,
+                times: times.map(|(start, end)| vec![vec![format_time(start), format_time(end)]]),
+                tag: None,
+            })
+            .collect::<Vec<_>>()
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    anchors: shift_anchors.map(create_anchors),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        config: config_anchors.map(|anchors| Config { anchors: Some(create_anchors(anchors)), ..Default::default() }),
+        ..create_empty_problem()
+    };
+
+    let result = check_e1312_vehicle_anchors_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_vehicle_pool, (vehicle_pool, expected), {
+    can_detect_invalid_vehicle_pool_impl(vehicle_pool, expected);
+}}
+
+can_detect_invalid_vehicle_pool! {
+    case01: (None, None),
+    case02: (Some(1), None),
+    case03: (Some(0), Some("E1313".to_string())),
+}
+
+fn can_detect_invalid_vehicle_pool_impl(vehicle_pool: Option<usize>, expected: Option<String>) {
+    let problem = Problem { config: Some(Config { vehicle_pool, ..Default::default() }), ..create_empty_problem() };
+
+    let result = check_e1313_vehicle_pool_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_max_overtime, (max_overtime, expected), {
+    can_detect_invalid_max_overtime_impl(max_overtime, expected);
+}}
+
+can_detect_invalid_max_overtime! {
+    case01: (None, None),
+    case02: (Some(3600.), None),
+    case03: (Some(0.), None),
+    case04: (Some(-1.), Some("E1314".to_string())),
+}
+
+fn can_detect_invalid_max_overtime_impl(max_overtime: Option<f64>, expected: Option<String>) {
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: shift.end.clone().map(|end| ShiftEnd { max_overtime, ..end }),
+                    ..shift
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1314_vehicle_max_overtime_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_load_distance_costs, (load_distance, expected), {
+    can_detect_invalid_load_distance_costs_impl(load_distance, expected);
+}}
+
+can_detect_invalid_load_distance_costs! {
+    case01: (None, None),
+    case02: (Some(vec![0.5]), None),
+    case03: (Some(vec![0.]), None),
+    case04: (Some(vec![-1.]), Some("E1315".to_string())),
+    case05: (Some(vec![]), Some("E1315".to_string())),
+    case06: (Some(vec![1., 1.]), Some("E1315".to_string())),
+}
+
+fn can_detect_invalid_load_distance_costs_impl(load_distance: Option<Vec<f64>>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { load_distance, ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1315_vehicle_load_distance_costs_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_consumption, (distance, load, speed, emission_factor, expected), {
+    can_detect_invalid_consumption_impl(distance, load, speed, emission_factor, expected);
+}}
+
+can_detect_invalid_consumption! {
+    case01: (0.2, None, None, 2.5, None),
+    case02: (0.2, Some(vec![0.01]), Some(0.1), 2.5, None),
+    case03: (-0.2, None, None, 2.5, Some("E1316".to_string())),
+    case04: (0.2, None, None, -2.5, Some("E1316".to_string())),
+    case05: (0.2, None, Some(-0.1), 2.5, Some("E1316".to_string())),
+    case06: (0.2, Some(vec![-0.01]), None, 2.5, Some("E1316".to_string())),
+    case07: (0.2, Some(vec![0.01, 0.01]), None, 2.5, Some("E1316".to_string())),
+}
+
+fn can_detect_invalid_consumption_impl(
+    distance: f64,
+    load: Option<Vec<f64>>,
+    speed: Option<f64>,
+    emission_factor: f64,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                consumption: Some(VehicleConsumption { distance, load, speed, emission_factor }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1316_vehicle_consumption_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_planning_horizon, (days, fleets, expected), {
+    can_detect_invalid_planning_horizon_impl(days, fleets, expected);
+}}
+
+can_detect_invalid_planning_horizon! {
+    case01: (2, None, None),
+    case02: (2, Some(vec![(1, "my_vehicle")]), None),
+    case03: (0, None, Some("E1317".to_string())),
+    case04: (2, Some(vec![(2, "my_vehicle")]), Some("E1317".to_string())),
+    case05: (2, Some(vec![(1, "unknown")]), Some("E1317".to_string())),
+    case06: (2, Some(vec![(1, "my_vehicle"), (1, "my_vehicle")]), Some("E1317".to_string())),
+}
+
+fn can_detect_invalid_planning_horizon_impl(days: usize, fleets: Option<Vec<(usize, &str)>>, expected: Option<String>) {
+    let fleets = fleets.map(|fleets| {
+        fleets
+            .into_iter()
+            .map(|(day, type_id)| DayFleet { day, type_ids: vec![type_id.to_string()] })
+            .collect::<Vec<_>>()
+    });
+    let problem = create_problem_with_horizon(PlanningHorizon { days, fleets, vehicles: None });
+
+    let result = check_e1317_planning_horizon_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_vehicle_days, (vehicles, expected), {
+    can_detect_invalid_vehicle_days_impl(vehicles, expected);
+}}
+
+can_detect_invalid_vehicle_days! {
+    case01: (vec![("my_vehicle_1", vec![0, 1])], None),
+    case02: (vec![("my_vehicle_1", vec![2])], Some("E1317".to_string())),
+    case03: (vec![("unknown", vec![1])], Some("E1317".to_string())),
+    case04: (vec![("my_vehicle_1", vec![0]), ("my_vehicle_1", vec![1])], Some("E1317".to_string())),
+}
+
+fn can_detect_invalid_vehicle_days_impl(vehicles: Vec<(&str, Vec<usize>)>, expected: Option<String>) {
+    let vehicles = vehicles
+        .into_iter()
+        .map(|(vehicle_id, days)| VehicleDays { vehicle_id: vehicle_id.to_string(), days })
+        .collect::<Vec<_>>();
+    let problem = create_problem_with_horizon(PlanningHorizon { days: 2, fleets: None, vehicles: Some(vehicles) });
+
+    let result = check_e1317_planning_horizon_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+fn create_problem_with_horizon(horizon: PlanningHorizon) -> Problem {
+    Problem {
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: vec![] },
+        config: Some(Config { horizon: Some(horizon), ..Default::default() }),
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_detect_invalid_crew_size, (crew_size, expected), {
+    can_detect_invalid_crew_size_impl(crew_size, expected);
+}}
+
+can_detect_invalid_crew_size! {
+    case01: (None, None),
+    case02: (Some(1), None),
+    case03: (Some(2), None),
+    case04: (Some(0), Some("E1318".to_string())),
+}
+
+fn can_detect_invalid_crew_size_impl(crew_size: Option<usize>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet { vehicles: vec![VehicleType { crew_size, ..create_default_vehicle_type() }], profiles: vec![] },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1318_vehicle_crew_size_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_dock_slot, (latest, dock_slot, expected), {
+    can_detect_invalid_dock_slot_impl(latest, dock_slot, expected);
+}}
+
+can_detect_invalid_dock_slot! {
+    case01_no_slot: (None, None, None),
+    case02_within_shift: (None, Some((10., 20.)), None),
+    case03_within_departure: (Some(30.), Some((10., 20.)), None),
+    case04_intersects_departure: (Some(15.), Some((10., 20.)), None),
+    case05_after_departure: (Some(5.), Some((10., 20.)), Some("E1319".to_string())),
+    case06_after_shift: (None, Some((2000., 2010.)), Some("E1319".to_string())),
+    case07_invalid_slot: (None, Some((20., 10.)), Some("E1319".to_string())),
+}
+
+fn can_detect_invalid_dock_slot_impl(latest: Option<f64>, dock_slot: Option<(f64, f64)>, expected: Option<String>) {
+    let mut shift = create_default_vehicle_shift();
+    shift.start.latest = latest.map(format_time);
+    shift.start.dock_slot = dock_slot.map(|(start, end)| vec![format_time(start), format_time(end)]);
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1319_vehicle_dock_slot_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_max_first_leg_distance, (max_first_leg_distance, expected), {
+    can_detect_invalid_max_first_leg_distance_impl(max_first_leg_distance, expected);
+}}
+
+can_detect_invalid_max_first_leg_distance! {
+    case01: (None, None),
+    case02: (Some(0.), None),
+    case03: (Some(100.), None),
+    case04: (Some(-1.), Some("E1320".to_string())),
+}
+
+fn can_detect_invalid_max_first_leg_distance_impl(max_first_leg_distance: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: None,
+                    max_time_window_jobs: None,
+                    max_reloads: None,
+                    max_hourly_time_window_jobs: None,
+                    max_late_stops: None,
+                    max_first_leg_distance,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1320_vehicle_max_first_leg_distance_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_maneuvering_time, (maneuvering_time, expected), {
+    can_detect_invalid_maneuvering_time_impl(maneuvering_time, expected);
+}}
+
+can_detect_invalid_maneuvering_time! {
+    case01: (None, None),
+    case02: (Some(0.), None),
+    case03: (Some(30.), None),
+    case04: (Some(-1.), Some("E1321".to_string())),
+}
+
+fn can_detect_invalid_maneuvering_time_impl(maneuvering_time: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { maneuvering_time, ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1321_vehicle_maneuvering_time_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_capacity_decay, (duration, expected), {
+    can_detect_invalid_capacity_decay_impl(duration, expected);
+}}
+
+can_detect_invalid_capacity_decay! {
+    case01: (None, None),
+    case02: (Some(3600.), None),
+    case03: (Some(0.), Some("E1322".to_string())),
+    case04: (Some(-1.), Some("E1322".to_string())),
+}
+
+fn can_detect_invalid_capacity_decay_impl(duration: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity_decay: duration.map(|duration| VehicleCapacityDecay::Linear { duration }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1322_vehicle_capacity_decay_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}